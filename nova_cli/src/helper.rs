@@ -1,6 +1,9 @@
 use nova_vm::ecmascript::{
     builtins::{create_builtin_function, ArgumentsList, Behaviour, BuiltinFunctionArgs},
-    execution::{agent::ExceptionType, Agent, JsResult},
+    execution::{
+        agent::{CoverageReport, CpuProfile, ExceptionType, JsError},
+        Agent, JsResult,
+    },
     types::{
         InternalMethods, IntoValue, Object, OrdinaryObject, PropertyDescriptor, PropertyKey,
         String, Value,
@@ -8,6 +11,7 @@ use nova_vm::ecmascript::{
 };
 use nova_vm::engine::context::GcScope;
 use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
 
 /// Initialize the global object with the built-in functions.
 pub fn initialize_global_object(agent: &mut Agent, global: Object, mut gc: GcScope) {
@@ -197,15 +201,101 @@ pub fn initialize_global_object_with_internals(agent: &mut Agent, global: Object
         .unwrap();
 }
 
+/// Print an uncaught exception. If the exception is an Error thrown from
+/// source code, the source line it was thrown from is printed as well.
+///
+/// The source text is named by the name of its Script; other source texts,
+/// such as eval code, are printed as `<eval>`.
+pub fn print_uncaught_exception(agent: &mut Agent, error: JsError, mut gc: GcScope) {
+    let Some(stack) = error.stack(agent, gc.nogc()) else {
+        eprintln!(
+            "Uncaught exception: {}",
+            error
+                .value()
+                .string_repr(agent, gc.reborrow())
+                .as_str(agent)
+        );
+        return;
+    };
+    eprintln!("Uncaught exception: {}", stack);
+
+    let Some(frame) = error
+        .stack_frames(agent)
+        .iter()
+        .find(|frame| !frame.is_native())
+    else {
+        return;
+    };
+    let offset = frame.source_offset().unwrap();
+    let frame_source = frame.source_text(agent).unwrap();
+    let source_path = frame.script_name(agent).unwrap_or("<eval>");
+    let message = stack.lines().next().unwrap().to_string();
+    set_report_hook();
+    let report = OxcDiagnostic::error(message)
+        .with_label(Span::new(offset, offset))
+        .with_source_code(miette::NamedSource::new(
+            source_path,
+            frame_source.to_string(),
+        ));
+    eprint!("{:?}", report);
+}
+
+/// The `file:` URL of a source file, or the path itself if the file cannot
+/// be found.
+pub fn script_url(path: &str) -> std::string::String {
+    std::fs::canonicalize(path).map_or_else(
+        |_| path.to_owned(),
+        |path| format!("file://{}", path.display()),
+    )
+}
+
+/// Write the CPU profile to `CPU.<pid>.cpuprofile` and its collapsed stacks
+/// to `CPU.<pid>.folded` in the directory.
+pub fn write_cpu_profile(profile: &CpuProfile, directory: &str) -> std::io::Result<()> {
+    let directory = std::path::Path::new(directory);
+    std::fs::create_dir_all(directory)?;
+    let name = format!("CPU.{}", std::process::id());
+    let mut file = std::io::BufWriter::new(std::fs::File::create(
+        directory.join(format!("{name}.cpuprofile")),
+    )?);
+    profile.write_cpuprofile(&mut file)?;
+    std::io::Write::flush(&mut file)?;
+    let mut file = std::io::BufWriter::new(std::fs::File::create(
+        directory.join(format!("{name}.folded")),
+    )?);
+    profile.write_collapsed_stacks(&mut file)?;
+    std::io::Write::flush(&mut file)
+}
+
+/// Write the code coverage to `lcov.info` and `coverage-<pid>.json` in the
+/// directory.
+pub fn write_coverage(coverage: &CoverageReport, directory: &str) -> std::io::Result<()> {
+    let directory = std::path::Path::new(directory);
+    std::fs::create_dir_all(directory)?;
+    let mut file = std::io::BufWriter::new(std::fs::File::create(directory.join("lcov.info"))?);
+    coverage.write_lcov(&mut file)?;
+    std::io::Write::flush(&mut file)?;
+    let mut file = std::io::BufWriter::new(std::fs::File::create(
+        directory.join(format!("coverage-{}.json", std::process::id())),
+    )?);
+    coverage.write_precise_coverage(&mut file)?;
+    std::io::Write::flush(&mut file)
+}
+
+/// Set the hook used for printing diagnostics.
+fn set_report_hook() {
+    // This seems to be needed for color and Unicode output.
+    // Note: The hook can only be set once.
+    let _ = miette::set_hook(Box::new(|_| {
+        Box::new(oxc_diagnostics::GraphicalReportHandler::new())
+    }));
+}
+
 /// Exit the program with parse errors.
 pub fn exit_with_parse_errors(errors: Vec<OxcDiagnostic>, source_path: &str, source: &str) -> ! {
     assert!(!errors.is_empty());
 
-    // This seems to be needed for color and Unicode output.
-    miette::set_hook(Box::new(|_| {
-        Box::new(oxc_diagnostics::GraphicalReportHandler::new())
-    }))
-    .unwrap();
+    set_report_hook();
 
     eprintln!("Parse errors:");
 
@@ -0,0 +1,1054 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Chrome DevTools Protocol inspector for `nova_cli eval --inspect`.
+//!
+//! The inspector listens on localhost. A background thread answers the
+//! front-ends' discovery requests (`/json/list` and `/json/version`), and the
+//! first WebSocket connection becomes the inspector session. The session
+//! lives on the thread of the Agent, and handles the front-end's messages
+//! while waiting for it to start the evaluation, while paused in the
+//! debugger, and at the Agent's interrupt safe points while running. Messages
+//! that need garbage collection, such as evaluations, wait for the next pause
+//! if they arrive while running.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io::{self, ErrorKind, Read, Write},
+    net::{TcpListener, TcpStream},
+    ops::ControlFlow,
+    sync::mpsc::{self, Receiver, Sender, TryRecvError},
+    thread,
+    time::Duration,
+};
+
+use nova_vm::{
+    ecmascript::{
+        builtins::Array,
+        execution::{
+            agent::{
+                BreakpointId, DebugAction, DebugCallFrame, DebugScopeKind, DebugSourceId,
+                InterruptHandle, PauseReason, SampleInterval,
+            },
+            Agent, JsResult,
+        },
+        scripts_and_modules::script::{parse_script, script_evaluation, Script},
+        types::{Function, InternalMethods, IntoValue, Number, Object, String as JsString, Value},
+    },
+    engine::{
+        context::{GcScope, NoGcScope},
+        Global,
+    },
+};
+use sonic_rs::{json, JsonValueMutTrait, JsonValueTrait, Value as Json};
+
+use crate::helper::script_url;
+use tungstenite::{Message, WebSocket};
+
+/// Id of the only execution context reported to the front-end.
+const EXECUTION_CONTEXT_ID: u32 = 1;
+
+thread_local! {
+    /// The inspector session of the Agent running on this thread.
+    static SESSION: RefCell<Option<Session>> = const { RefCell::new(None) };
+}
+
+/// How the session continues after handling a message.
+enum Flow {
+    Stay,
+    /// The front-end asked to start the evaluation.
+    Run,
+    /// The front-end asked to resume the paused execution.
+    Resume(DebugAction),
+}
+
+/// A script reported to the front-end. Its index is its scriptId.
+struct ScriptInfo {
+    source: DebugSourceId,
+    url: String,
+}
+
+/// A breakpoint set by the front-end.
+struct BreakpointInfo {
+    id: String,
+    /// URL of the scripts the breakpoint applies to, or None if it was set
+    /// in a single script.
+    url: Option<String>,
+    /// 0-based line of the breakpoint.
+    line: u32,
+    /// The breakpoint's engine breakpoints, by scriptId.
+    locations: Vec<(usize, BreakpointId)>,
+}
+
+/// What a remote object id sent to the front-end refers to.
+enum RemoteRef {
+    Value(Global<Value>),
+    /// A scope of a paused call frame, by the indexes of the frame and the
+    /// scope.
+    Scope(usize, usize),
+}
+
+struct Session {
+    incoming: Receiver<Option<Json>>,
+    outgoing: Sender<String>,
+    closed: bool,
+    /// Messages received while running that wait for the next pause.
+    deferred: VecDeque<Json>,
+    debugger_enabled: bool,
+    breakpoints_active: bool,
+    skip_all_pauses: bool,
+    is_paused: bool,
+    scripts: Vec<ScriptInfo>,
+    breakpoints: Vec<BreakpointInfo>,
+    next_breakpoint_id: u32,
+    /// Remote objects sent to the front-end, indexed by their objectId. They
+    /// are released when the execution resumes.
+    remote_objects: Vec<RemoteRef>,
+    /// Interval between the samples of CPU profiles.
+    sampling_interval: Duration,
+    /// True if the front-end started the CPU profiler.
+    is_profiling: bool,
+}
+
+/// Listens for a DevTools front-end on the port of localhost, and blocks
+/// until one connects.
+pub fn start(port: u16, interrupt_handle: InterruptHandle) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    let address = listener.local_addr()?;
+    let target_id = format!("nova-{}", std::process::id());
+    let websocket_url = format!("ws://{address}/{target_id}");
+    eprintln!("Debugger listening on {websocket_url}");
+    let targets = json!([{
+        "description": "nova_cli instance",
+        "devtoolsFrontendUrl": format!(
+            "devtools://devtools/bundled/js_app.html?experiments=true&v8only=true&ws={address}/{target_id}"
+        ),
+        "id": target_id,
+        "title": "nova_cli",
+        "type": "node",
+        "url": "",
+        "webSocketDebuggerUrl": websocket_url,
+    }]);
+    let (socket_sender, socket_receiver) = mpsc::channel();
+    thread::spawn(move || accept_connections(listener, targets, socket_sender));
+    let socket = socket_receiver
+        .recv()
+        .map_err(|_| io::Error::from(ErrorKind::ConnectionAborted))?;
+    eprintln!("Debugger attached.");
+
+    let (incoming_sender, incoming) = mpsc::channel();
+    let (outgoing, outgoing_receiver) = mpsc::channel();
+    thread::spawn(move || {
+        exchange_messages(socket, incoming_sender, outgoing_receiver, interrupt_handle)
+    });
+    SESSION.set(Some(Session {
+        incoming,
+        outgoing,
+        closed: false,
+        deferred: VecDeque::new(),
+        debugger_enabled: false,
+        breakpoints_active: true,
+        skip_all_pauses: false,
+        is_paused: false,
+        scripts: Vec::new(),
+        breakpoints: Vec::new(),
+        next_breakpoint_id: 1,
+        remote_objects: Vec::new(),
+        sampling_interval: Duration::from_micros(1000),
+        is_profiling: false,
+    }));
+    Ok(())
+}
+
+/// Handles the front-end's messages until it starts the evaluation.
+pub fn wait_for_debugger(agent: &mut Agent, mut gc: GcScope) {
+    // Functions compiled while the debugger is enabled keep their bindings
+    // visible to it.
+    agent.enable_debugger();
+    with_session(|session| {
+        while let Some(flow) = session.message_loop(agent, gc.reborrow()) {
+            if matches!(flow, Flow::Run) {
+                break;
+            }
+        }
+    });
+}
+
+/// Reports a parsed script to the front-end, and sets the breakpoints on
+/// its URL in it.
+pub fn script_parsed(agent: &mut Agent, script: &Script, path: &str) {
+    let source = agent.debug_source_id(script);
+    let url = script_url(path);
+    with_session(|session| {
+        let script_id = session.add_script(agent, source, url);
+        for index in 0..session.breakpoints.len() {
+            if session.breakpoints[index].url.as_ref() == Some(&session.scripts[script_id].url) {
+                session.resolve_breakpoint(agent, index, script_id);
+            }
+        }
+    });
+}
+
+/// Reports the paused execution to the front-end, and handles its messages
+/// until it resumes the execution.
+pub fn debugger_paused(agent: &mut Agent, reason: PauseReason, gc: GcScope) -> DebugAction {
+    with_session(|session| session.paused(agent, reason, gc)).unwrap_or(DebugAction::Continue)
+}
+
+/// Handles the front-end's messages until it disconnects.
+pub fn wait_for_disconnect(agent: &mut Agent, mut gc: GcScope) {
+    with_session(|session| {
+        if !session.closed {
+            eprintln!("Waiting for the debugger to disconnect...");
+        }
+        while session.message_loop(agent, gc.reborrow()).is_some() {}
+    });
+    SESSION.set(None);
+}
+
+/// Runs the closure with the session. Does nothing if there is no session, or
+/// if the session is already in use further up the stack.
+fn with_session<R>(f: impl FnOnce(&mut Session) -> R) -> Option<R> {
+    SESSION.with(|session| {
+        let mut session = session.try_borrow_mut().ok()?;
+        Some(f(session.as_mut()?))
+    })
+}
+
+/// Interrupt callback that handles the messages received while running.
+fn handle_messages_while_running(agent: &mut Agent) {
+    with_session(|session| {
+        while let Ok(message) = session.incoming.try_recv() {
+            let Some(message) = message else {
+                session.closed = true;
+                break;
+            };
+            let method = message["method"].as_str().unwrap_or_default();
+            match session.handle_common(agent, method, &message["params"]) {
+                Some(result) => session.respond(&message["id"], result),
+                None => session.deferred.push_back(message),
+            }
+        }
+    });
+}
+
+/// Answers discovery requests, and sends the first WebSocket connection to
+/// the session.
+fn accept_connections(
+    listener: TcpListener,
+    targets: Json,
+    sessions: Sender<WebSocket<TcpStream>>,
+) {
+    let mut is_attached = false;
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        let Some((request, request_length)) = peek_request_head(&stream) else {
+            continue;
+        };
+        let is_upgrade = request.lines().any(|line| {
+            let line = line.to_ascii_lowercase();
+            line.starts_with("upgrade:") && line.contains("websocket")
+        });
+        if is_upgrade {
+            if is_attached {
+                // Only one front-end can be attached at a time.
+                let _ = stream.write_all(b"HTTP/1.1 409 Conflict\r\nContent-Length: 0\r\n\r\n");
+                continue;
+            }
+            let Ok(socket) = tungstenite::accept(stream) else {
+                continue;
+            };
+            is_attached = true;
+            if sessions.send(socket).is_err() {
+                return;
+            }
+            continue;
+        }
+        // Consume the request so that closing the stream does not reset the
+        // connection.
+        let mut head = vec![0; request_length];
+        let _ = stream.read_exact(&mut head);
+        let path = request.split_whitespace().nth(1).unwrap_or("/");
+        let (status, body) = match path {
+            "/json" | "/json/list" => ("200 OK", targets.to_string()),
+            "/json/version" => (
+                "200 OK",
+                json!({
+                    "Browser": concat!("nova/", env!("CARGO_PKG_VERSION")),
+                    "Protocol-Version": "1.3",
+                })
+                .to_string(),
+            ),
+            _ => ("404 Not Found", String::new()),
+        };
+        let _ = write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Type: application/json; charset=UTF-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+    }
+}
+
+/// Returns the head of the HTTP request on the stream and its length in
+/// bytes, without consuming it.
+fn peek_request_head(stream: &TcpStream) -> Option<(String, usize)> {
+    stream.set_read_timeout(Some(Duration::from_secs(5))).ok()?;
+    let mut buffer = [0; 4096];
+    loop {
+        let length = stream.peek(&mut buffer).ok()?;
+        if length == 0 {
+            return None;
+        }
+        let head = &buffer[..length];
+        let end = head
+            .windows(4)
+            .position(|window| window == b"\r\n\r\n")
+            .map(|position| position + 4);
+        if let Some(end) = end.or((length == buffer.len()).then_some(length)) {
+            return Some((String::from_utf8_lossy(&head[..end]).into_owned(), end));
+        }
+        thread::sleep(Duration::from_millis(1));
+    }
+}
+
+/// Moves messages between the WebSocket and the session until either side
+/// closes. Interrupts the Agent when a message is received, so that it is
+/// handled while running.
+fn exchange_messages(
+    mut socket: WebSocket<TcpStream>,
+    incoming: Sender<Option<Json>>,
+    outgoing: Receiver<String>,
+    interrupt_handle: InterruptHandle,
+) {
+    if socket
+        .get_ref()
+        .set_read_timeout(Some(Duration::from_millis(10)))
+        .is_err()
+    {
+        let _ = incoming.send(None);
+        return;
+    }
+    'exchange: loop {
+        loop {
+            match outgoing.try_recv() {
+                Ok(text) => {
+                    if socket.send(Message::text(text)).is_err() {
+                        break 'exchange;
+                    }
+                }
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    let _ = socket.close(None);
+                    let _ = socket.flush();
+                    return;
+                }
+            }
+        }
+        match socket.read() {
+            Ok(Message::Text(text)) => {
+                let Ok(message) = sonic_rs::from_str(text.as_str()) else {
+                    continue;
+                };
+                if incoming.send(Some(message)).is_err() {
+                    return;
+                }
+                interrupt_handle.request_interrupt(handle_messages_while_running);
+            }
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(tungstenite::Error::Io(error))
+                if matches!(error.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+            Err(_) => break,
+        }
+    }
+    let _ = incoming.send(None);
+}
+
+/// Returns the call frames of the paused execution reported to the
+/// front-end, innermost first.
+fn paused_call_frames(agent: &Agent) -> Vec<DebugCallFrame> {
+    let mut frames = agent.debug_call_frames();
+    frames.retain(|frame| !frame.is_native());
+    frames
+}
+
+impl Session {
+    fn send(&self, message: Json) {
+        let _ = self.outgoing.send(message.to_string());
+    }
+
+    fn send_event(&self, method: &str, params: Json) {
+        self.send(json!({ "method": method, "params": params }));
+    }
+
+    fn respond(&self, id: &Json, result: Result<Json, String>) {
+        match result {
+            Ok(result) => self.send(json!({ "id": id, "result": result })),
+            Err(message) => self.send(json!({
+                "id": id,
+                "error": { "code": -32000, "message": message },
+            })),
+        }
+    }
+
+    /// Handles messages until one starts or resumes the execution. Returns
+    /// None once the front-end has disconnected.
+    fn message_loop(&mut self, agent: &mut Agent, mut gc: GcScope) -> Option<Flow> {
+        loop {
+            if self.closed {
+                return None;
+            }
+            let message = match self.deferred.pop_front() {
+                Some(message) => message,
+                None => match self.incoming.recv() {
+                    Ok(Some(message)) => message,
+                    Ok(None) | Err(_) => {
+                        self.closed = true;
+                        return None;
+                    }
+                },
+            };
+            match self.handle(agent, message, gc.reborrow()) {
+                Flow::Stay => {}
+                flow => return Some(flow),
+            }
+        }
+    }
+
+    fn handle(&mut self, agent: &mut Agent, message: Json, gc: GcScope) -> Flow {
+        let id = &message["id"];
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let mut flow = Flow::Stay;
+        let result = match method {
+            "Runtime.evaluate" => self.evaluate(agent, params, None, gc),
+            "Debugger.evaluateOnCallFrame" => {
+                let frame = params["callFrameId"]
+                    .as_str()
+                    .and_then(|id| id.parse::<usize>().ok());
+                match frame {
+                    Some(frame) if self.is_paused => self.evaluate(agent, params, Some(frame), gc),
+                    _ => Err("Could not find call frame with given id".to_owned()),
+                }
+            }
+            "Runtime.getProperties" => self.get_properties(agent, params, gc.nogc()),
+            "Runtime.runIfWaitingForDebugger" => {
+                flow = Flow::Run;
+                Ok(json!({}))
+            }
+            "Debugger.resume" | "Debugger.stepOver" | "Debugger.stepInto" | "Debugger.stepOut"
+                if self.is_paused =>
+            {
+                flow = Flow::Resume(match method {
+                    "Debugger.stepOver" => DebugAction::StepOver,
+                    "Debugger.stepInto" => DebugAction::StepIn,
+                    "Debugger.stepOut" => DebugAction::StepOut,
+                    _ => DebugAction::Continue,
+                });
+                Ok(json!({}))
+            }
+            _ => self
+                .handle_common(agent, method, params)
+                .expect("method needs garbage collection"),
+        };
+        self.respond(id, result);
+        flow
+    }
+
+    /// Handles a method that does not need garbage collection. Returns None
+    /// if the method needs it.
+    fn handle_common(
+        &mut self,
+        agent: &mut Agent,
+        method: &str,
+        params: &Json,
+    ) -> Option<Result<Json, String>> {
+        Some(match method {
+            "Runtime.evaluate" | "Debugger.evaluateOnCallFrame" | "Runtime.getProperties" => {
+                return None;
+            }
+            "Runtime.enable" => {
+                self.send_event(
+                    "Runtime.executionContextCreated",
+                    json!({
+                        "context": {
+                            "id": EXECUTION_CONTEXT_ID,
+                            "origin": "",
+                            "name": "nova_cli",
+                            "uniqueId": EXECUTION_CONTEXT_ID.to_string(),
+                            "auxData": { "isDefault": true },
+                        },
+                    }),
+                );
+                Ok(json!({}))
+            }
+            "Runtime.disable"
+            | "Runtime.releaseObject"
+            | "Runtime.releaseObjectGroup"
+            | "Runtime.runIfWaitingForDebugger"
+            | "Debugger.setAsyncCallStackDepth"
+            | "Debugger.setBlackboxPatterns"
+            | "Profiler.enable"
+            | "Profiler.disable" => Ok(json!({})),
+            "Debugger.enable" => {
+                if !self.debugger_enabled {
+                    self.debugger_enabled = true;
+                    for script_id in 0..self.scripts.len() {
+                        self.send_script_parsed(agent, script_id);
+                    }
+                }
+                Ok(json!({ "debuggerId": format!("nova-{}", std::process::id()) }))
+            }
+            "Debugger.disable" => {
+                self.debugger_enabled = false;
+                for breakpoint in std::mem::take(&mut self.breakpoints) {
+                    for (_, id) in breakpoint.locations {
+                        agent.remove_breakpoint(id);
+                    }
+                }
+                agent.set_pause_on_exceptions(false);
+                Ok(json!({}))
+            }
+            "Profiler.setSamplingInterval" => match params["interval"].as_u64() {
+                Some(interval) if interval > 0 => {
+                    self.sampling_interval = Duration::from_micros(interval);
+                    Ok(json!({}))
+                }
+                _ => Err("Invalid sampling interval".to_owned()),
+            },
+            "Profiler.start" => {
+                if agent.is_profiling() && !self.is_profiling {
+                    Err("The CPU profiler is already running".to_owned())
+                } else {
+                    agent.start_profiling(SampleInterval::Time(self.sampling_interval));
+                    self.is_profiling = true;
+                    Ok(json!({}))
+                }
+            }
+            "Profiler.stop" => self.stop_profiling(agent),
+            "Debugger.setBreakpointByUrl" => self.set_breakpoint_by_url(agent, params),
+            "Debugger.setBreakpoint" => self.set_breakpoint(agent, params),
+            "Debugger.removeBreakpoint" => {
+                let id = params["breakpointId"].as_str().unwrap_or_default();
+                if let Some(index) = self.breakpoints.iter().position(|bp| bp.id == id) {
+                    for (_, id) in self.breakpoints.remove(index).locations {
+                        agent.remove_breakpoint(id);
+                    }
+                }
+                Ok(json!({}))
+            }
+            "Debugger.setBreakpointsActive" => {
+                self.breakpoints_active = params["active"].as_bool().unwrap_or(true);
+                Ok(json!({}))
+            }
+            "Debugger.setSkipAllPauses" => {
+                self.skip_all_pauses = params["skip"].as_bool().unwrap_or(false);
+                Ok(json!({}))
+            }
+            "Debugger.setPauseOnExceptions" => {
+                // Note: Caught and uncaught exceptions are not told apart.
+                let state = params["state"].as_str().unwrap_or("none");
+                agent.set_pause_on_exceptions(state != "none");
+                Ok(json!({}))
+            }
+            "Debugger.pause" => {
+                if !self.is_paused {
+                    agent.debug_break();
+                }
+                Ok(json!({}))
+            }
+            "Debugger.getScriptSource" => match self.script_by_id(params) {
+                Some(script_id) => Ok(json!({
+                    "scriptSource": agent.debug_source_text(self.scripts[script_id].source),
+                })),
+                None => Err("No script for id".to_owned()),
+            },
+            "Debugger.resume" | "Debugger.stepOver" | "Debugger.stepInto" | "Debugger.stepOut" => {
+                Err("Can only perform operation while paused.".to_owned())
+            }
+            _ => Err(format!("'{method}' wasn't found")),
+        })
+    }
+
+    fn script_by_id(&self, params: &Json) -> Option<usize> {
+        let script_id = params["scriptId"].as_str()?.parse::<usize>().ok()?;
+        (script_id < self.scripts.len()).then_some(script_id)
+    }
+
+    /// Adds a script, reporting it to the front-end. Returns its scriptId.
+    fn add_script(&mut self, agent: &Agent, source: DebugSourceId, url: String) -> usize {
+        self.scripts.push(ScriptInfo { source, url });
+        let script_id = self.scripts.len() - 1;
+        if self.debugger_enabled {
+            self.send_script_parsed(agent, script_id);
+        }
+        script_id
+    }
+
+    /// Returns the scriptId of the source code, adding a script for code
+    /// evaluated by `eval` or `Function` when it is first seen.
+    fn script_id(&mut self, agent: &Agent, source: DebugSourceId) -> usize {
+        match self
+            .scripts
+            .iter()
+            .position(|script| script.source == source)
+        {
+            Some(script_id) => script_id,
+            None => self.add_script(agent, source, String::new()),
+        }
+    }
+
+    fn send_script_parsed(&self, agent: &Agent, script_id: usize) {
+        let script = &self.scripts[script_id];
+        let source_text = agent.debug_source_text(script.source);
+        let end_line = source_text.lines().count().saturating_sub(1);
+        let end_column = source_text.lines().last().map_or(0, str::len);
+        self.send_event(
+            "Debugger.scriptParsed",
+            json!({
+                "scriptId": script_id.to_string(),
+                "url": script.url,
+                "startLine": 0,
+                "startColumn": 0,
+                "endLine": end_line,
+                "endColumn": end_column,
+                "executionContextId": EXECUTION_CONTEXT_ID,
+                "hash": "",
+                "length": source_text.len(),
+            }),
+        );
+    }
+
+    fn add_breakpoint(&mut self, url: Option<String>, line: u32) -> usize {
+        let id = self.next_breakpoint_id.to_string();
+        self.next_breakpoint_id += 1;
+        self.breakpoints.push(BreakpointInfo {
+            id,
+            url,
+            line,
+            locations: Vec::new(),
+        });
+        self.breakpoints.len() - 1
+    }
+
+    /// Sets the breakpoint in the script. Returns its location.
+    fn resolve_breakpoint(&mut self, agent: &mut Agent, index: usize, script_id: usize) -> Json {
+        let breakpoint = &mut self.breakpoints[index];
+        let id = agent.set_source_breakpoint(self.scripts[script_id].source, breakpoint.line + 1);
+        breakpoint.locations.push((script_id, id));
+        let location = json!({
+            "scriptId": script_id.to_string(),
+            "lineNumber": breakpoint.line,
+            "columnNumber": 0,
+        });
+        let params = json!({ "breakpointId": breakpoint.id, "location": location });
+        self.send_event("Debugger.breakpointResolved", params);
+        location
+    }
+
+    /// Stops the CPU profiler started by the front-end, and returns the
+    /// profile with the call frames' scripts as reported to the front-end.
+    fn stop_profiling(&mut self, agent: &mut Agent) -> Result<Json, String> {
+        if !self.is_profiling {
+            return Err("No recording profiles found".to_owned());
+        }
+        self.is_profiling = false;
+        let profile = agent
+            .stop_profiling()
+            .ok_or("No recording profiles found")?;
+        let mut bytes = Vec::new();
+        profile
+            .write_cpuprofile(&mut bytes)
+            .map_err(|error| error.to_string())?;
+        let mut json: Json = sonic_rs::from_slice(&bytes).map_err(|error| error.to_string())?;
+        for (node, node_json) in profile.nodes.iter().zip(
+            json["nodes"]
+                .as_array_mut()
+                .ok_or("Invalid profile")?
+                .iter_mut(),
+        ) {
+            if let Some(source) = node.debug_source {
+                let script_id = self.script_id(agent, source);
+                let call_frame = &mut node_json["callFrame"];
+                call_frame["scriptId"] = json!(script_id.to_string());
+                call_frame["url"] = json!(self.scripts[script_id].url);
+            }
+        }
+        Ok(json!({ "profile": json }))
+    }
+
+    fn set_breakpoint_by_url(&mut self, agent: &mut Agent, params: &Json) -> Result<Json, String> {
+        let Some(url) = params["url"].as_str() else {
+            return Err("Only breakpoints by url are supported".to_owned());
+        };
+        let line = params["lineNumber"]
+            .as_u64()
+            .and_then(|line| u32::try_from(line).ok())
+            .ok_or("Invalid lineNumber")?;
+        let index = self.add_breakpoint(Some(url.to_owned()), line);
+        let mut locations = vec![];
+        for script_id in 0..self.scripts.len() {
+            if self.scripts[script_id].url == url {
+                locations.push(self.resolve_breakpoint(agent, index, script_id));
+            }
+        }
+        Ok(json!({ "breakpointId": self.breakpoints[index].id, "locations": locations }))
+    }
+
+    fn set_breakpoint(&mut self, agent: &mut Agent, params: &Json) -> Result<Json, String> {
+        let location = &params["location"];
+        let script_id = self.script_by_id(location).ok_or("No script for id")?;
+        let line = location["lineNumber"]
+            .as_u64()
+            .and_then(|line| u32::try_from(line).ok())
+            .ok_or("Invalid lineNumber")?;
+        let index = self.add_breakpoint(None, line);
+        let location = self.resolve_breakpoint(agent, index, script_id);
+        Ok(json!({ "breakpointId": self.breakpoints[index].id, "actualLocation": location }))
+    }
+
+    fn paused(&mut self, agent: &mut Agent, reason: PauseReason, mut gc: GcScope) -> DebugAction {
+        if self.closed || !self.debugger_enabled || self.skip_all_pauses {
+            return DebugAction::Continue;
+        }
+        let mut hit_breakpoints = vec![];
+        if let PauseReason::Breakpoint(id) = reason {
+            if !self.breakpoints_active {
+                return DebugAction::Continue;
+            }
+            hit_breakpoints.extend(
+                self.breakpoints
+                    .iter()
+                    .filter(|breakpoint| {
+                        breakpoint
+                            .locations
+                            .iter()
+                            .any(|&(_, location)| location == id)
+                    })
+                    .map(|breakpoint| breakpoint.id.clone()),
+            );
+        }
+        let frames = paused_call_frames(agent);
+        if frames.is_empty() {
+            return DebugAction::Continue;
+        }
+        let call_frames = frames
+            .iter()
+            .enumerate()
+            .map(|(index, frame)| self.call_frame(agent, index, frame, gc.nogc()))
+            .collect::<Vec<_>>();
+        let mut params = json!({
+            "callFrames": call_frames,
+            "reason": "other",
+            "hitBreakpoints": hit_breakpoints,
+        });
+        if let PauseReason::Exception(exception) = reason {
+            params["reason"] = json!("exception");
+            params["data"] = self.remote_object(agent, exception, gc.nogc());
+        }
+        self.send_event("Debugger.paused", params);
+        self.is_paused = true;
+        let action = loop {
+            match self.message_loop(agent, gc.reborrow()) {
+                Some(Flow::Resume(action)) => break action,
+                Some(_) => {}
+                None => break DebugAction::Continue,
+            }
+        };
+        self.is_paused = false;
+        for remote_object in self.remote_objects.drain(..) {
+            if let RemoteRef::Value(value) = remote_object {
+                value.take(agent);
+            }
+        }
+        self.send_event("Debugger.resumed", json!({}));
+        action
+    }
+
+    fn call_frame(
+        &mut self,
+        agent: &mut Agent,
+        index: usize,
+        frame: &DebugCallFrame,
+        gc: NoGcScope,
+    ) -> Json {
+        let source = frame.source_id(agent).unwrap();
+        let script_id = self.script_id(agent, source);
+        let (line, column) = frame.line_and_column(agent).unwrap_or((1, 1));
+        let scope_kinds = frame
+            .scopes(agent)
+            .iter()
+            .map(|scope| scope.kind)
+            .collect::<Vec<_>>();
+        let mut is_local = true;
+        let scope_chain = scope_kinds
+            .into_iter()
+            .enumerate()
+            .map(|(scope, kind)| {
+                let kind = match kind {
+                    DebugScopeKind::Function if is_local => {
+                        is_local = false;
+                        "local"
+                    }
+                    DebugScopeKind::Function => "closure",
+                    DebugScopeKind::Block => "block",
+                    DebugScopeKind::With => "with",
+                    DebugScopeKind::Global => "global",
+                };
+                self.remote_objects.push(RemoteRef::Scope(index, scope));
+                json!({
+                    "type": kind,
+                    "object": {
+                        "type": "object",
+                        "className": "Object",
+                        "description": "Object",
+                        "objectId": (self.remote_objects.len() - 1).to_string(),
+                    },
+                })
+            })
+            .collect::<Vec<_>>();
+        let this = frame.this_value(agent).unwrap_or(Value::Undefined);
+        json!({
+            "callFrameId": index.to_string(),
+            "functionName": frame.function_name(agent).unwrap_or_default(),
+            "location": {
+                "scriptId": script_id.to_string(),
+                "lineNumber": line - 1,
+                "columnNumber": column - 1,
+            },
+            "url": self.scripts[script_id].url,
+            "scopeChain": scope_chain,
+            "this": self.remote_object(agent, this, gc),
+        })
+    }
+
+    /// Returns the CDP RemoteObject describing the value.
+    fn remote_object(&mut self, agent: &mut Agent, value: Value, gc: NoGcScope) -> Json {
+        let description = |agent: &mut Agent| {
+            value
+                .unbind()
+                .try_string_repr(agent, gc)
+                .as_str(agent)
+                .to_owned()
+        };
+        match value {
+            Value::Undefined => json!({ "type": "undefined" }),
+            Value::Null => json!({ "type": "object", "subtype": "null", "value": null }),
+            Value::Boolean(value) => json!({ "type": "boolean", "value": value }),
+            _ if value.is_string() => json!({
+                "type": "string",
+                "value": JsString::try_from(value).unwrap().as_str(agent),
+            }),
+            _ if value.is_number() => {
+                let number = Number::try_from(value).unwrap().into_f64(agent);
+                let description = description(agent);
+                if number.is_finite() && !(number == 0.0 && number.is_sign_negative()) {
+                    let value = if number.trunc() == number && number.abs() < 2f64.powi(53) {
+                        json!(number as i64)
+                    } else {
+                        json!(number)
+                    };
+                    json!({ "type": "number", "value": value, "description": description })
+                } else {
+                    json!({
+                        "type": "number",
+                        "unserializableValue": description,
+                        "description": description,
+                    })
+                }
+            }
+            _ if value.is_bigint() => {
+                let description = format!("{}n", description(agent));
+                json!({
+                    "type": "bigint",
+                    "unserializableValue": description,
+                    "description": description,
+                })
+            }
+            _ if value.is_symbol() => {
+                json!({ "type": "symbol", "description": description(agent) })
+            }
+            _ => {
+                self.remote_objects
+                    .push(RemoteRef::Value(Global::new(agent, value.unbind())));
+                let object_id = (self.remote_objects.len() - 1).to_string();
+                if Function::try_from(value).is_ok() {
+                    json!({
+                        "type": "function",
+                        "className": "Function",
+                        "description": description(agent),
+                        "objectId": object_id,
+                    })
+                } else if let Ok(array) = Array::try_from(value) {
+                    json!({
+                        "type": "object",
+                        "subtype": "array",
+                        "className": "Array",
+                        "description": format!("Array({})", array.len(agent)),
+                        "objectId": object_id,
+                    })
+                } else if let Value::Error(_) = value {
+                    json!({
+                        "type": "object",
+                        "subtype": "error",
+                        "className": "Error",
+                        "description": description(agent),
+                        "objectId": object_id,
+                    })
+                } else {
+                    json!({
+                        "type": "object",
+                        "className": "Object",
+                        "description": "Object",
+                        "objectId": object_id,
+                    })
+                }
+            }
+        }
+    }
+
+    fn evaluate(
+        &mut self,
+        agent: &mut Agent,
+        params: &Json,
+        frame: Option<usize>,
+        mut gc: GcScope,
+    ) -> Result<Json, String> {
+        let expression = params["expression"].as_str().unwrap_or_default();
+        let result = match frame {
+            Some(frame) => {
+                let frames = paused_call_frames(agent);
+                let frame = frames
+                    .get(frame)
+                    .ok_or("Could not find call frame with given id")?;
+                frame.evaluate(agent, expression, gc.reborrow())
+            }
+            None => evaluate_script(agent, expression, gc.reborrow())?,
+        };
+        Ok(match result {
+            Ok(value) => json!({ "result": self.remote_object(agent, value, gc.nogc()) }),
+            Err(error) => {
+                let exception = self.remote_object(agent, error.value(), gc.nogc());
+                json!({
+                    "result": exception,
+                    "exceptionDetails": {
+                        "exceptionId": 1,
+                        "text": "Uncaught",
+                        "lineNumber": 0,
+                        "columnNumber": 0,
+                        "exception": exception,
+                    },
+                })
+            }
+        })
+    }
+
+    fn get_properties(
+        &mut self,
+        agent: &mut Agent,
+        params: &Json,
+        gc: NoGcScope,
+    ) -> Result<Json, String> {
+        let remote_object = params["objectId"]
+            .as_str()
+            .and_then(|id| id.parse::<usize>().ok())
+            .and_then(|id| self.remote_objects.get(id))
+            .ok_or("Could not find object with given id")?;
+        let mut properties = vec![];
+        let object = match *remote_object {
+            RemoteRef::Value(ref value) => Object::try_from(value.get(agent, gc)).ok(),
+            RemoteRef::Scope(frame, scope) => {
+                let frames = paused_call_frames(agent);
+                let scope = frames
+                    .get(frame)
+                    .and_then(|frame| frame.scopes(agent).into_iter().nth(scope))
+                    .ok_or("Could not find object with given id")?;
+                let bindings = scope
+                    .bindings
+                    .iter()
+                    .map(|&(name, value)| (name.to_owned(), value))
+                    .collect::<Vec<_>>();
+                let object = scope.binding_object;
+                for (name, value) in bindings {
+                    let value = match value {
+                        Some(value) => self.remote_object(agent, value, gc),
+                        None => json!({ "type": "undefined", "description": "<uninitialized>" }),
+                    };
+                    properties.push(json!({
+                        "name": name,
+                        "value": value,
+                        "writable": true,
+                        "configurable": false,
+                        "enumerable": true,
+                        "isOwn": true,
+                    }));
+                }
+                object
+            }
+        };
+        if let Some(object) = object {
+            self.add_own_properties(agent, object, &mut properties, gc);
+        }
+        Ok(json!({ "result": properties }))
+    }
+
+    /// Describes the own properties of the object that can be read without
+    /// calling into JavaScript.
+    fn add_own_properties(
+        &mut self,
+        agent: &mut Agent,
+        object: Object,
+        properties: &mut Vec<Json>,
+        gc: NoGcScope,
+    ) {
+        let ControlFlow::Continue(keys) = object.try_own_property_keys(agent, gc) else {
+            return;
+        };
+        for key in keys {
+            let ControlFlow::Continue(Some(descriptor)) =
+                object.try_get_own_property(agent, key, gc)
+            else {
+                continue;
+            };
+            let name = key
+                .convert_to_value(agent, gc)
+                .try_string_repr(agent, gc)
+                .as_str(agent)
+                .to_owned();
+            let mut property = json!({
+                "name": name,
+                "configurable": descriptor.configurable.unwrap_or(false),
+                "enumerable": descriptor.enumerable.unwrap_or(false),
+                "isOwn": true,
+            });
+            if let Some(value) = descriptor.value {
+                property["value"] = self.remote_object(agent, value, gc);
+                property["writable"] = json!(descriptor.writable.unwrap_or(false));
+            }
+            if let Some(get) = descriptor.get {
+                property["get"] = self.remote_object(agent, get.into_value(), gc);
+            }
+            if let Some(set) = descriptor.set {
+                property["set"] = self.remote_object(agent, set.into_value(), gc);
+            }
+            properties.push(property);
+        }
+    }
+}
+
+/// Evaluates the source text as a Script in the current Realm.
+fn evaluate_script(
+    agent: &mut Agent,
+    source_text: &str,
+    mut gc: GcScope,
+) -> Result<JsResult<Value>, String> {
+    let realm = agent.current_realm_id();
+    let source_text = JsString::from_str(agent, source_text, gc.nogc());
+    let script = parse_script(agent, source_text, realm, false, None, gc.nogc())
+        .map_err(|errors| errors[0].to_string())?;
+    Ok(script_evaluation(agent, script, gc.reborrow()))
+}
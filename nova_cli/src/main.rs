@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 mod helper;
+mod inspector;
 mod theme;
 
 use std::{cell::RefCell, collections::VecDeque, fmt::Debug};
@@ -10,14 +11,15 @@ use clap::{Parser as ClapParser, Subcommand};
 use cliclack::{input, intro, set_theme};
 use helper::{
     exit_with_parse_errors, initialize_global_object, initialize_global_object_with_internals,
+    print_uncaught_exception, script_url, write_coverage, write_cpu_profile,
 };
 use nova_vm::{
     ecmascript::{
         execution::{
-            agent::{GcAgent, HostHooks, Job, Options},
+            agent::{DebugAction, GcAgent, HostHooks, Job, Options, PauseReason, SampleInterval},
             Agent,
         },
-        scripts_and_modules::script::{parse_script, script_evaluation},
+        scripts_and_modules::script::{disassemble_script, parse_script, script_evaluation},
         types::{Object, String as JsString},
     },
     engine::context::GcScope,
@@ -44,6 +46,15 @@ enum Command {
         path: String,
     },
 
+    /// Compiles a file without evaluating it, and prints its bytecode
+    Disasm {
+        #[arg(short, long)]
+        no_strict: bool,
+
+        /// The path of the file to disassemble
+        path: String,
+    },
+
     /// Evaluates a file
     Eval {
         #[arg(short, long)]
@@ -57,6 +68,57 @@ enum Command {
         #[arg(long)]
         expose_internals: bool,
 
+        /// Maximum size of the heap in bytes
+        #[arg(long)]
+        max_heap_bytes: Option<usize>,
+
+        /// Terminate the evaluation after executing this many instructions
+        #[arg(long)]
+        fuel: Option<u64>,
+
+        /// Terminate each file's evaluation after this many milliseconds
+        #[arg(long)]
+        timeout: Option<u64>,
+
+        /// Listen for a Chrome DevTools Protocol front-end on this port of
+        /// localhost, and wait for it to start the evaluation
+        #[arg(
+            long,
+            value_name = "PORT",
+            num_args = 0..=1,
+            require_equals = true,
+            default_missing_value = "9229"
+        )]
+        inspect: Option<u16>,
+
+        /// Profile the evaluation, and write the CPU profile to
+        /// `CPU.<pid>.cpuprofile` and its collapsed stacks to
+        /// `CPU.<pid>.folded`
+        #[arg(long)]
+        cpu_prof: bool,
+
+        /// Directory to write the CPU profile to
+        #[arg(long, value_name = "DIR", default_value = ".")]
+        cpu_prof_dir: String,
+
+        /// Interval between the samples of the CPU profile in microseconds
+        #[arg(long, value_name = "MICROSECONDS", default_value_t = 1000)]
+        cpu_prof_interval: u64,
+
+        /// Sample the CPU profile after each given number of executed
+        /// instructions instead of at a time interval
+        #[arg(
+            long,
+            value_name = "INSTRUCTIONS",
+            conflicts_with = "cpu_prof_interval"
+        )]
+        cpu_prof_instructions: Option<u64>,
+
+        /// Collect code coverage, and write it to `lcov.info` and
+        /// `coverage-<pid>.json` in this directory
+        #[arg(long, value_name = "DIR")]
+        coverage: Option<String>,
+
         /// The files to evaluate
         #[arg(required = true)]
         paths: Vec<String>,
@@ -99,6 +161,15 @@ impl HostHooks for CliHostHooks {
     fn enqueue_promise_job(&self, job: Job) {
         self.promise_job_queue.borrow_mut().push_back(job);
     }
+
+    fn host_debugger_paused(
+        &self,
+        agent: &mut Agent,
+        reason: PauseReason,
+        gc: GcScope,
+    ) -> DebugAction {
+        inspector::debugger_paused(agent, reason, gc)
+    }
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -126,11 +197,52 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             println!("{:?}", result.program);
         }
+        Command::Disasm { no_strict, path } => {
+            let host_hooks: &CliHostHooks = &*Box::leak(Box::default());
+            let mut agent = GcAgent::new(Options::default(), host_hooks);
+            let create_global_object: Option<
+                for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>,
+            > = None;
+            let create_global_this_value: Option<
+                for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>,
+            > = None;
+            let realm = agent.create_realm(
+                create_global_object,
+                create_global_this_value,
+                Some(initialize_global_object),
+            );
+            agent.run_in_realm(&realm, |agent, gc| -> std::io::Result<()> {
+                let realm = agent.current_realm_id();
+                let file = std::fs::read_to_string(&path)?;
+                let source_text = JsString::from_string(agent, file, gc.nogc());
+                let script =
+                    match parse_script(agent, source_text, realm, !no_strict, None, gc.nogc()) {
+                        Ok(script) => script,
+                        Err(errors) => {
+                            // Borrow the string data from the Agent
+                            let source_text = source_text.as_str(agent);
+                            exit_with_parse_errors(errors, &path, source_text)
+                        }
+                    };
+                print!("{}", disassemble_script(agent, script, gc.nogc()));
+                Ok(())
+            })?;
+            agent.remove_realm(realm);
+        }
         Command::Eval {
             verbose,
             no_strict,
             nogc,
             expose_internals,
+            max_heap_bytes,
+            fuel,
+            timeout,
+            inspect,
+            cpu_prof,
+            cpu_prof_dir,
+            cpu_prof_interval,
+            cpu_prof_instructions,
+            coverage,
             paths,
         } => {
             let host_hooks: &CliHostHooks = &*Box::leak(Box::default());
@@ -138,6 +250,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Options {
                     disable_gc: nogc,
                     print_internals: verbose,
+                    max_heap_bytes,
+                    fuel,
+                    ..Default::default()
                 },
                 host_hooks,
             );
@@ -158,6 +273,23 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 create_global_this_value,
                 initialize_global,
             );
+            let interrupt_handle = agent.interrupt_handle();
+            let _ = ctrlc::set_handler(move || interrupt_handle.terminate_execution());
+            if let Some(port) = inspect {
+                inspector::start(port, agent.interrupt_handle())?;
+                agent.run_in_realm(&realm, inspector::wait_for_debugger);
+            }
+            if cpu_prof {
+                agent.start_profiling(match cpu_prof_instructions {
+                    Some(instructions) => SampleInterval::Instructions(instructions),
+                    None => {
+                        SampleInterval::Time(std::time::Duration::from_micros(cpu_prof_interval))
+                    }
+                });
+            }
+            if coverage.is_some() {
+                agent.start_coverage();
+            }
             let mut is_first = true;
             for path in paths {
                 if is_first {
@@ -165,6 +297,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 } else {
                     agent.gc();
                 }
+                if let Some(timeout) = timeout {
+                    let interrupt_handle = agent.interrupt_handle();
+                    std::thread::spawn(move || {
+                        std::thread::sleep(std::time::Duration::from_millis(timeout));
+                        interrupt_handle.terminate_execution();
+                    });
+                }
                 agent.run_in_realm(
                     &realm,
                     |agent, mut gc| -> Result<(), Box<dyn std::error::Error>> {
@@ -186,6 +325,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 exit_with_parse_errors(errors, &path, source_text)
                             }
                         };
+                        script.set_name(agent, &path);
+                        if inspect.is_some() {
+                            inspector::script_parsed(agent, &script, &path);
+                        }
+                        if cpu_prof {
+                            agent.set_profiled_script_url(&script, &script_url(&path));
+                        }
+                        if coverage.is_some() {
+                            agent.set_covered_script_url(&script, &script_url(&path));
+                        }
                         let mut result = script_evaluation(agent, script, gc.reborrow());
 
                         if result.is_ok() {
@@ -204,13 +353,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 }
                             }
                             Err(error) => {
-                                eprintln!(
-                                    "Uncaught exception: {}",
-                                    error
-                                        .value()
-                                        .string_repr(agent, gc.reborrow())
-                                        .as_str(agent)
-                                );
+                                print_uncaught_exception(agent, error, gc);
+                                if let Some(profile) = agent.stop_profiling() {
+                                    write_cpu_profile(&profile, &cpu_prof_dir)?;
+                                }
+                                if let (Some(report), Some(directory)) =
+                                    (agent.take_coverage(), &coverage)
+                                {
+                                    write_coverage(&report, directory)?;
+                                }
                                 std::process::exit(1);
                             }
                         }
@@ -218,6 +369,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                     },
                 )?;
             }
+            if let Some(profile) = agent.stop_profiling() {
+                write_cpu_profile(&profile, &cpu_prof_dir)?;
+            }
+            if let (Some(report), Some(directory)) = (agent.take_coverage(), &coverage) {
+                write_coverage(&report, directory)?;
+            }
+            if inspect.is_some() {
+                agent.run_in_realm(&realm, inspector::wait_for_disconnect);
+            }
             agent.remove_realm(realm);
         }
         Command::Repl {
@@ -230,6 +390,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                 Options {
                     disable_gc,
                     print_internals,
+                    ..Default::default()
                 },
                 host_hooks,
             );
@@ -254,10 +415,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             println!("\n");
             let mut placeholder = "Enter a line of Javascript".to_string();
 
-            // Register a signal handler for Ctrl+C
-            let _ = ctrlc::set_handler(|| {
-                std::process::exit(0);
-            });
+            // Register a signal handler for Ctrl+C that terminates the
+            // running script.
+            let interrupt_handle = agent.interrupt_handle();
+            let _ = ctrlc::set_handler(move || interrupt_handle.terminate_execution());
             loop {
                 intro("Nova Repl")?;
                 let input: String = input("").placeholder(&placeholder).interact()?;
@@ -279,19 +440,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 exit_with_parse_errors(errors, "<stdin>", &placeholder);
                             }
                         };
+                    script.set_name(agent, "<stdin>");
                     let result = script_evaluation(agent, script, gc.reborrow());
                     match result {
                         Ok(result) => {
                             println!("{:?}\n", result);
                         }
                         Err(error) => {
-                            eprintln!(
-                                "Uncaught exception: {}",
-                                error
-                                    .value()
-                                    .string_repr(agent, gc.reborrow())
-                                    .as_str(agent)
-                            );
+                            print_uncaught_exception(agent, error, gc);
                         }
                     }
                 });
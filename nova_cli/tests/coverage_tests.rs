@@ -0,0 +1,49 @@
+use std::process::Command;
+
+#[test]
+fn coverage_writes_reports() {
+    let directory = std::env::temp_dir().join(format!("nova_coverage_{}", std::process::id()));
+    let path = directory.join("covered.js");
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(
+        &path,
+        "function called() {
+    return 1;
+}
+function uncalled() {
+    return 2;
+}
+called();
+",
+    )
+    .unwrap();
+    let status = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .arg("eval")
+        .arg("--coverage")
+        .arg(&directory)
+        .arg(&path)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let url = format!("file://{}", std::fs::canonicalize(&path).unwrap().display());
+    let lcov = std::fs::read_to_string(directory.join("lcov.info")).unwrap();
+    assert!(lcov.starts_with(&format!("TN:\nSF:{url}\n")));
+    assert!(lcov.contains("FNDA:1,called@1:1\nFNDA:0,uncalled@4:1\n"));
+    assert!(lcov.contains("DA:2,1\n"));
+    assert!(lcov.contains("DA:5,0\n"));
+
+    let json = std::fs::read_dir(&directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .unwrap();
+    let coverage: sonic_rs::Value =
+        sonic_rs::from_str(&std::fs::read_to_string(json).unwrap()).unwrap();
+    let script = &coverage["result"][0];
+    assert_eq!(script["url"], url);
+    assert_eq!(script["functions"][1]["functionName"], "called");
+    assert_eq!(script["functions"][1]["ranges"][0]["count"], 1);
+    assert_eq!(script["functions"][2]["ranges"][0]["count"], 0);
+    let _ = std::fs::remove_dir_all(&directory);
+}
@@ -0,0 +1,58 @@
+use std::process::Command;
+
+use sonic_rs::{JsonContainerTrait, JsonValueTrait};
+
+#[test]
+fn cpu_prof_writes_profiles() {
+    let directory = std::env::temp_dir().join(format!("nova_cpu_prof_{}", std::process::id()));
+    let path = directory.join("hot.js");
+    std::fs::create_dir_all(&directory).unwrap();
+    std::fs::write(
+        &path,
+        "function hot(n) {
+    let sum = 0;
+    for (let i = 0; i < n; i++) sum += i;
+    return sum;
+}
+hot(1000);
+",
+    )
+    .unwrap();
+    let status = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .args(["eval", "--cpu-prof", "--cpu-prof-instructions", "5"])
+        .arg("--cpu-prof-dir")
+        .arg(&directory)
+        .arg(&path)
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let read_output = |extension: &str| {
+        let entry = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .find(|path| path.extension().is_some_and(|ext| ext == extension))
+            .unwrap();
+        std::fs::read_to_string(entry).unwrap()
+    };
+    let url = format!("file://{}", std::fs::canonicalize(&path).unwrap().display());
+    let cpuprofile: sonic_rs::Value = sonic_rs::from_str(&read_output("cpuprofile")).unwrap();
+    let hot = cpuprofile["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|node| node["callFrame"]["functionName"] == "hot")
+        .unwrap();
+    assert_eq!(hot["callFrame"]["url"], url);
+    assert!(hot["hitCount"].as_u64().unwrap() > 0);
+    assert_eq!(
+        cpuprofile["samples"].as_array().unwrap().len(),
+        cpuprofile["timeDeltas"].as_array().unwrap().len()
+    );
+
+    let folded = read_output("folded");
+    assert!(folded
+        .lines()
+        .any(|line| line.starts_with(&format!("<anonymous> ({url}:1:1);hot ({url}:1:1) "))));
+    let _ = std::fs::remove_dir_all(&directory);
+}
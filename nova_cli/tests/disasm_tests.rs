@@ -0,0 +1,27 @@
+use std::process::Command;
+
+#[test]
+fn disasm_prints_bytecode() {
+    let path = std::env::temp_dir().join(format!("nova_disasm_{}.js", std::process::id()));
+    std::fs::write(
+        &path,
+        "const double = (x) => x * 2;
+console.log(double(2));
+",
+    )
+    .unwrap();
+    let output = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .arg("disasm")
+        .arg(&path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    // The file is compiled but not evaluated.
+    assert!(!stdout.contains("\n4\n"));
+    assert!(stdout.starts_with("== script ==\n"));
+    assert!(stdout.contains("  ; 1: const double = (x) => x * 2;\n"));
+    assert!(stdout.contains("== script > arrow #0 double (x) => (line 1) =="));
+    assert!(stdout.contains("ApplyStringOrNumericBinaryOperator(Multiplication)"));
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,171 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpStream,
+    process::{Child, Command, Stdio},
+};
+
+use sonic_rs::{json, JsonContainerTrait, JsonValueTrait, Value as Json};
+use tungstenite::{stream::MaybeTlsStream, Message, WebSocket};
+
+const SOURCE: &str = "function add(a, b) {
+    let sum = a + b;
+    return sum;
+}
+let result = add(1, 2);
+debugger;
+result;
+";
+
+/// A DevTools front-end attached to a `nova_cli eval --inspect` process.
+struct Client {
+    socket: WebSocket<MaybeTlsStream<TcpStream>>,
+    next_id: u64,
+}
+
+impl Client {
+    fn send(&mut self, method: &str, params: Json) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        let message = json!({ "id": id, "method": method, "params": params });
+        self.socket
+            .send(Message::text(message.to_string()))
+            .unwrap();
+        id
+    }
+
+    fn receive(&mut self) -> Json {
+        loop {
+            if let Message::Text(text) = self.socket.read().unwrap() {
+                return sonic_rs::from_str(text.as_str()).unwrap();
+            }
+        }
+    }
+
+    /// Calls the method, and returns its result.
+    fn call(&mut self, method: &str, params: Json) -> Json {
+        let id = self.send(method, params);
+        loop {
+            let message = self.receive();
+            if message["id"] == id {
+                assert!(message["error"].is_null(), "{method} failed: {message}");
+                return message["result"].clone();
+            }
+        }
+    }
+
+    /// Waits for the event, and returns its params.
+    fn event(&mut self, method: &str) -> Json {
+        loop {
+            let message = self.receive();
+            if message["method"] == method {
+                return message["params"].clone();
+            }
+        }
+    }
+}
+
+fn http_get(address: &str, path: &str) -> String {
+    let mut stream = TcpStream::connect(address).unwrap();
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: {address}\r\n\r\n").unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+fn spawn_inspected(path: &std::path::Path) -> (Child, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_nova_cli"))
+        .args(["eval", "--inspect=0"])
+        .arg(path)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stderr = BufReader::new(child.stderr.take().unwrap());
+    let mut line = String::new();
+    stderr.read_line(&mut line).unwrap();
+    let url = line
+        .trim()
+        .strip_prefix("Debugger listening on ")
+        .unwrap_or_else(|| panic!("unexpected output: {line}"))
+        .to_owned();
+    // Forward the rest of the output for failing tests.
+    std::thread::spawn(move || {
+        for line in stderr.lines() {
+            eprintln!("{}", line.unwrap());
+        }
+    });
+    (child, url)
+}
+
+#[test]
+fn inspector_breakpoints_and_evaluation() {
+    let path = std::env::temp_dir().join(format!("nova_inspector_{}.js", std::process::id()));
+    std::fs::write(&path, SOURCE).unwrap();
+    let url = format!("file://{}", std::fs::canonicalize(&path).unwrap().display());
+    let (mut child, websocket_url) = spawn_inspected(&path);
+
+    let address = websocket_url
+        .strip_prefix("ws://")
+        .unwrap()
+        .split('/')
+        .next()
+        .unwrap();
+    let targets = http_get(address, "/json/list");
+    assert!(targets.starts_with("HTTP/1.1 200 OK"));
+    assert!(targets.contains(&websocket_url));
+
+    let (socket, _) = tungstenite::connect(&websocket_url).unwrap();
+    let mut client = Client { socket, next_id: 1 };
+    client.call("Runtime.enable", json!({}));
+    client.call("Debugger.enable", json!({}));
+    let breakpoint = client.call(
+        "Debugger.setBreakpointByUrl",
+        json!({ "url": url, "lineNumber": 2 }),
+    );
+    client.call("Profiler.enable", json!({}));
+    client.call("Profiler.setSamplingInterval", json!({ "interval": 10 }));
+    client.call("Profiler.start", json!({}));
+    client.call("Runtime.runIfWaitingForDebugger", json!({}));
+
+    let paused = client.event("Debugger.paused");
+    assert_eq!(
+        paused["hitBreakpoints"],
+        json!([breakpoint["breakpointId"]])
+    );
+    let frame = &paused["callFrames"][0];
+    assert_eq!(frame["functionName"], "add");
+    assert_eq!(frame["location"]["lineNumber"], 2);
+    assert_eq!(frame["url"], url);
+    let evaluated = client.call(
+        "Debugger.evaluateOnCallFrame",
+        json!({ "callFrameId": frame["callFrameId"], "expression": "sum * 10" }),
+    );
+    assert_eq!(evaluated["result"]["value"], 30);
+    let local_scope = &frame["scopeChain"][0];
+    assert_eq!(local_scope["type"], "local");
+    let properties = client.call(
+        "Runtime.getProperties",
+        json!({ "objectId": local_scope["object"]["objectId"] }),
+    );
+    let sum = properties["result"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|property| property["name"] == "sum")
+        .unwrap();
+    assert_eq!(sum["value"]["value"], 3);
+    client.call("Debugger.resume", json!({}));
+
+    let paused = client.event("Debugger.paused");
+    assert_eq!(paused["callFrames"][0]["location"]["lineNumber"], 5);
+    let evaluated = client.call("Runtime.evaluate", json!({ "expression": "result" }));
+    assert_eq!(evaluated["result"]["value"], 3);
+    let profile = client.call("Profiler.stop", json!({}))["profile"].clone();
+    assert_eq!(profile["nodes"][0]["callFrame"]["functionName"], "(root)");
+    client.call("Debugger.resume", json!({}));
+
+    client.socket.close(None).unwrap();
+    while client.socket.read().is_ok() {}
+    assert!(child.wait().unwrap().success());
+    let _ = std::fs::remove_file(&path);
+}
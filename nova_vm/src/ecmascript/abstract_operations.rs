@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod operations_on_disposable_objects;
 pub(crate) mod operations_on_iterator_objects;
 pub(crate) mod operations_on_objects;
 pub(crate) mod testing_and_comparison;
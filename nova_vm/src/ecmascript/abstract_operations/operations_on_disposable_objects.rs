@@ -0,0 +1,486 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [3.3 Operations on Disposable Objects](https://tc39.es/proposal-explicit-resource-management/#sec-operations-on-disposable-objects)
+//!
+//! A DisposeCapability Record is represented by a [`DisposableStack`] heap
+//! object: its resource list is the \[\[DisposableResourceStack]].
+//!
+//! The asynchronous variant of DisposeResources awaits once per resource.
+//! Instead of suspending a VM, its state is kept in the [`AsyncDisposal`]
+//! record of the DisposableStack, which is used as the handler of the
+//! awaited promises' reactions and resumed from the PromiseReactionJob.
+
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_objects::{call_function, get_method},
+        builtins::{
+            control_abstraction_objects::promise_objects::{
+                promise_abstract_operations::{
+                    promise_capability_records::PromiseCapability,
+                    promise_reaction_records::{PromiseReactionHandler, PromiseReactionType},
+                },
+                promise_prototype::inner_promise_then,
+            },
+            disposable_stack::{
+                data::{AsyncDisposal, DisposableResource, DisposeHint, DisposeMethod},
+                DisposableStack,
+            },
+            error::Error,
+            fundamental_objects::error_objects::suppressed_error_constructors::install_suppressed_error_properties,
+            ordinary::ordinary_object_create_with_intrinsics,
+            promise::Promise,
+            ArgumentsList,
+        },
+        execution::{
+            agent::{ExceptionType, JsError},
+            Agent, JsResult, ProtoIntrinsics,
+        },
+        types::{Object, PropertyKey, Value},
+    },
+    engine::context::GcScope,
+    heap::WellKnownSymbolIndexes,
+};
+
+/// ### [3.3.1 AddDisposableResource ( disposeCapability, V, hint \[ , method \] )](https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource)
+///
+/// The abstract operation AddDisposableResource takes arguments
+/// disposeCapability (a DisposeCapability Record), V (an ECMAScript language
+/// value), and hint (either sync-dispose or async-dispose) and optional
+/// argument method (a function object) and returns either a normal completion
+/// containing unused or a throw completion.
+pub(crate) fn add_disposable_resource(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    v: Value,
+    hint: DisposeHint,
+    method: Option<DisposeMethod>,
+    gc: GcScope,
+) -> JsResult<()> {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    let resource = if let Some(method) = method {
+        // 2. Else,
+        // a. Assert: V is undefined.
+        // b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+        // NOTE: The adopt closure captures the value that it is called with:
+        // it is kept as the resource value instead.
+        DisposableResource {
+            value: v,
+            hint,
+            method,
+        }
+    } else {
+        // 1. If method is not present, then
+        // a. If V is either null or undefined and hint is sync-dispose, return unused.
+        if (v.is_null() || v.is_undefined()) && hint == DisposeHint::SyncDispose {
+            return Ok(());
+        }
+        // b. NOTE: When V is either null or undefined and hint is
+        //    async-dispose, we record that a resource was evaluated to
+        //    ensure we will still perform an Await when resources are later
+        //    disposed.
+        // c. Let resource be ? CreateDisposableResource(V, hint).
+        let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+        let resource = create_disposable_resource(agent, v, hint, gc)?;
+        // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+        let dispose_capability = dispose_capability.get(agent);
+        agent[dispose_capability].resources.push(resource);
+        // 4. Return unused.
+        return Ok(());
+    };
+    // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+    agent[dispose_capability].resources.push(resource);
+    // 4. Return unused.
+    Ok(())
+}
+
+/// ### [3.3.2 CreateDisposableResource ( V, hint \[ , method \] )](https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource)
+///
+/// The abstract operation CreateDisposableResource takes arguments V (an
+/// ECMAScript language value) and hint (either sync-dispose or async-dispose)
+/// and returns either a normal completion containing a DisposableResource
+/// Record or a throw completion. The method argument is always handled by
+/// the caller.
+fn create_disposable_resource(
+    agent: &mut Agent,
+    v: Value,
+    hint: DisposeHint,
+    mut gc: GcScope,
+) -> JsResult<DisposableResource> {
+    // 1. If method is not present, then
+    // a. If V is either null or undefined, then
+    if v.is_null() || v.is_undefined() {
+        // i. Set V to undefined.
+        // ii. Set method to undefined.
+        return Ok(DisposableResource {
+            value: Value::Undefined,
+            hint,
+            method: DisposeMethod::None,
+        });
+    }
+    // b. Else,
+    // i. If V is not an Object, throw a TypeError exception.
+    if Object::try_from(v).is_err() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Disposable resource is not an object",
+            gc.nogc(),
+        ));
+    }
+    let scoped_v = v.scope(agent, gc.nogc());
+    // ii. Set method to ? GetDisposeMethod(V, hint).
+    let method = get_dispose_method(agent, v, hint, gc.reborrow())?;
+    // iii. If method is undefined, throw a TypeError exception.
+    let Some(method) = method else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Disposable resource has no dispose method",
+            gc.nogc(),
+        ));
+    };
+    // 3. Return the DisposableResource Record { [[ResourceValue]]: V, [[Hint]]: hint, [[DisposeMethod]]: method }.
+    Ok(DisposableResource {
+        value: scoped_v.get(agent),
+        hint,
+        method,
+    })
+}
+
+/// ### [3.3.3 GetDisposeMethod ( V, hint )](https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod)
+///
+/// The abstract operation GetDisposeMethod takes arguments V (an Object) and
+/// hint (either sync-dispose or async-dispose) and returns either a normal
+/// completion containing either a function object or undefined, or a throw
+/// completion.
+fn get_dispose_method(
+    agent: &mut Agent,
+    v: Value,
+    hint: DisposeHint,
+    mut gc: GcScope,
+) -> JsResult<Option<DisposeMethod>> {
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::AsyncDispose {
+        let scoped_v = v.scope(agent, gc.nogc());
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        let method = get_method(
+            agent,
+            v,
+            PropertyKey::Symbol(WellKnownSymbolIndexes::AsyncDispose.into()),
+            gc.reborrow(),
+        )?;
+        if let Some(method) = method {
+            return Ok(Some(DisposeMethod::Call(method.unbind())));
+        }
+        // b. If method is undefined, then
+        // i. Set method to ? GetMethod(V, @@dispose).
+        let method = get_method(
+            agent,
+            scoped_v.get(agent),
+            PropertyKey::Symbol(WellKnownSymbolIndexes::Dispose.into()),
+            gc,
+        )?;
+        // ii. If method is not undefined, then
+        // 1. Let closure be a new Abstract Closure with no parameters that
+        //    captures method and performs the following steps when called:
+        //    ...
+        // 2. NOTE: This function is not observable to user code. It is used
+        //    to ensure that a Promise returned from a synchronous @@dispose
+        //    method will not be awaited and that any exception thrown will
+        //    not be thrown synchronously.
+        // 3. Return CreateBuiltinFunction(closure, 0, "", « »).
+        return Ok(method.map(|method| DisposeMethod::SyncAsAsync(method.unbind())));
+    }
+    // 2. Else,
+    // a. Let method be ? GetMethod(V, @@dispose).
+    let method = get_method(
+        agent,
+        v,
+        PropertyKey::Symbol(WellKnownSymbolIndexes::Dispose.into()),
+        gc,
+    )?;
+    // 3. Return method.
+    Ok(method.map(|method| DisposeMethod::Call(method.unbind())))
+}
+
+/// ### [3.3.4 Dispose ( V, hint, method )](https://tc39.es/proposal-explicit-resource-management/#sec-dispose)
+///
+/// Calls the dispose method of a resource. The returned value is the result
+/// of the call, which is to be awaited for an async-dispose resource.
+fn dispose(agent: &mut Agent, resource: DisposableResource, gc: GcScope) -> JsResult<Value> {
+    let DisposableResource { value, method, .. } = resource;
+    match method {
+        // 1. If method is undefined, let result be undefined.
+        DisposeMethod::None => Ok(Value::Undefined),
+        // 2. Else, let result be ? Call(method, V).
+        DisposeMethod::Call(method) => call_function(agent, method, value, None, gc),
+        DisposeMethod::Adopt(on_dispose) => call_function(
+            agent,
+            on_dispose,
+            Value::Undefined,
+            Some(ArgumentsList(&[value])),
+            gc,
+        ),
+        DisposeMethod::SyncAsAsync(method) => {
+            // a. Let O be the this value.
+            // b. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+            // c. Let result be Completion(Call(method, O)).
+            // d. IfAbruptRejectPromise(result, promiseCapability).
+            call_function(agent, method, value, None, gc)?;
+            // e. Perform ? Call(promiseCapability.[[Resolve]], undefined, « undefined »).
+            // f. Return promiseCapability.[[Promise]].
+            Ok(Value::Undefined)
+        }
+    }
+}
+
+/// Combine the completion of a dispose method call with the completion that
+/// DisposeResources was called with.
+///
+/// > 1. If completion is a throw completion, then
+/// >    a. Set result to result.[[Value]].
+/// >    b. Let suppressed be completion.[[Value]].
+/// >    c. Let error be a newly created SuppressedError object.
+/// >    d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error", result).
+/// >    e. Perform CreateNonEnumerableDataPropertyOrThrow(error, "suppressed", suppressed).
+/// >    f. Set completion to ThrowCompletion(error).
+/// > 2. Else,
+/// >    a. Set completion to result.
+fn suppress_error(
+    agent: &mut Agent,
+    completion: Option<Value>,
+    result: Value,
+    gc: GcScope,
+) -> Value {
+    let Some(suppressed) = completion else {
+        return result;
+    };
+    let error = ordinary_object_create_with_intrinsics(
+        agent,
+        Some(ProtoIntrinsics::SuppressedError),
+        None,
+        gc.nogc(),
+    );
+    let error = Error::try_from(error.unbind()).unwrap();
+    let scoped_error = error.scope(agent, gc.nogc());
+    install_suppressed_error_properties(agent, error, result, suppressed, gc);
+    scoped_error.get(agent).into()
+}
+
+/// ### [3.3.5 DisposeResources ( disposeCapability, completion )](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+///
+/// The abstract operation DisposeResources takes arguments disposeCapability
+/// (a DisposeCapability Record) and completion (either a normal completion
+/// containing an ECMAScript language value or an abrupt completion) and
+/// returns either a normal completion containing an ECMAScript language value
+/// or an abrupt completion.
+///
+/// This is the synchronous variant, used when no resource of the
+/// disposeCapability has the async-dispose hint.
+pub(crate) fn dispose_resources(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    completion: JsResult<()>,
+    mut gc: GcScope,
+) -> JsResult<()> {
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    let mut completion = completion
+        .err()
+        .map(|err| err.value().scope(agent, gc.nogc()));
+    // 3. For each element resource of
+    //    disposeCapability.[[DisposableResourceStack]], in reverse list
+    //    order, do
+    // 4. NOTE: After disposeCapability has been disposed, it will never be
+    //    used again. The contents of disposeCapability.[[DisposableResourceStack]]
+    //    can be discarded in implementations, such as by garbage collection,
+    //    at this point.
+    // 5. Set disposeCapability.[[DisposableResourceStack]] to a new empty List.
+    loop {
+        let stack = dispose_capability.get(agent);
+        let Some(resource) = agent[stack].resources.pop() else {
+            break;
+        };
+        debug_assert_eq!(resource.hint, DisposeHint::SyncDispose);
+        // c. If method is not undefined, then
+        // i. Let result be Completion(Call(method, value)).
+        let result = dispose(agent, resource, gc.reborrow());
+        // iii. If result is a throw completion, then
+        if let Err(err) = result {
+            let previous = completion.as_ref().map(|completion| completion.get(agent));
+            let error = suppress_error(agent, previous, err.value(), gc.reborrow());
+            completion = Some(error.scope(agent, gc.nogc()));
+        }
+    }
+    // 6. Return ? completion.
+    match completion {
+        Some(error) => Err(JsError::new(error.get(agent))),
+        None => Ok(()),
+    }
+}
+
+/// ### [3.3.5 DisposeResources ( disposeCapability, completion )](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+///
+/// This is the asynchronous variant, used by `await using` declarations and
+/// AsyncDisposableStack objects. The returned promise is fulfilled with
+/// undefined after all resources were disposed of, or rejected with the
+/// final thrown value.
+pub(crate) fn dispose_resources_async<'a>(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    completion: Option<Value>,
+    mut gc: GcScope<'a, '_>,
+) -> Promise<'a> {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    let promise_capability = PromiseCapability::new(agent);
+    let promise = promise_capability.promise().bind(gc.nogc());
+    let scoped_promise = promise.scope(agent, gc.nogc());
+    let realm = agent.current_realm_id();
+    // 1. Let needsAwait be false.
+    // 2. Let hasAwaited be false.
+    let previous = agent[dispose_capability].disposal.replace(AsyncDisposal {
+        realm,
+        completion,
+        needs_await: false,
+        has_awaited: false,
+        promise_capability,
+    });
+    assert!(previous.is_none());
+    continue_async_disposal(agent, dispose_capability.unbind(), gc.reborrow());
+    scoped_promise.get(agent).bind(gc.into_nogc())
+}
+
+/// Resume the asynchronous DisposeResources of a DisposeCapability after the
+/// promise it awaited settled.
+pub(crate) fn resume_async_disposal(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    reaction_type: PromiseReactionType,
+    value: Value,
+    mut gc: GcScope,
+) {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    if reaction_type == PromiseReactionType::Reject {
+        // ii. If result is a normal completion and hint is async-dispose, then
+        //     1. Set result to Completion(Await(result.[[Value]])).
+        // iii. If result is a throw completion, then
+        let scoped_dispose_capability = dispose_capability.scope(agent, gc.nogc());
+        let previous = agent[dispose_capability]
+            .disposal
+            .as_ref()
+            .unwrap()
+            .completion;
+        let error = suppress_error(agent, previous, value, gc.reborrow());
+        let dispose_capability = scoped_dispose_capability.get(agent);
+        agent[dispose_capability]
+            .disposal
+            .as_mut()
+            .unwrap()
+            .completion = Some(error);
+        continue_async_disposal(agent, dispose_capability, gc);
+    } else {
+        continue_async_disposal(agent, dispose_capability.unbind(), gc);
+    }
+}
+
+/// Run the steps of the asynchronous DisposeResources until the next Await
+/// or until all resources have been disposed of.
+fn continue_async_disposal(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    mut gc: GcScope,
+) {
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    // 3. For each element resource of
+    //    disposeCapability.[[DisposableResourceStack]], in reverse list
+    //    order, do
+    loop {
+        let stack = dispose_capability.get(agent);
+        let Some(resource) = agent[stack].resources.pop() else {
+            break;
+        };
+        let disposal = agent[stack].disposal.as_mut().unwrap();
+        // b. If hint is sync-dispose and needsAwait is true and hasAwaited
+        //    is false, then
+        if resource.hint == DisposeHint::SyncDispose
+            && disposal.needs_await
+            && !disposal.has_awaited
+        {
+            // i. Perform ! Await(undefined).
+            // ii. Set needsAwait to false.
+            disposal.needs_await = false;
+            // The resource is disposed of after the Await.
+            agent[stack].resources.push(resource);
+            await_value(agent, stack, Value::Undefined, gc);
+            return;
+        }
+        // c. If method is not undefined, then
+        if let DisposeMethod::None = resource.method {
+            // d. Else,
+            // i. Assert: hint is async-dispose.
+            debug_assert_eq!(resource.hint, DisposeHint::AsyncDispose);
+            // ii. Set needsAwait to true.
+            // iii. NOTE: This can only indicate a case where either null or
+            //      undefined was the initial value of an Identifier in an
+            //      AwaitUsingDeclaration.
+            disposal.needs_await = true;
+            continue;
+        }
+        // i. Let result be Completion(Call(method, value)).
+        let result = dispose(agent, resource, gc.reborrow());
+        let stack = dispose_capability.get(agent);
+        match result {
+            // ii. If result is a normal completion and hint is
+            //     async-dispose, then
+            Ok(value) if resource.hint == DisposeHint::AsyncDispose => {
+                // 1. Set result to Completion(Await(result.[[Value]])).
+                // 2. Set hasAwaited to true.
+                agent[stack].disposal.as_mut().unwrap().has_awaited = true;
+                await_value(agent, stack, value, gc);
+                return;
+            }
+            Ok(_) => {}
+            // iii. If result is a throw completion, then
+            Err(err) => {
+                let previous = agent[stack].disposal.as_ref().unwrap().completion;
+                let error = suppress_error(agent, previous, err.value(), gc.reborrow());
+                let stack = dispose_capability.get(agent);
+                let disposal = agent[stack].disposal.as_mut().unwrap();
+                disposal.completion = Some(error);
+                if let DisposeMethod::SyncAsAsync(_) = resource.method {
+                    // The rejected promise of the closure is awaited.
+                    disposal.has_awaited = true;
+                    await_value(agent, stack, Value::Undefined, gc);
+                    return;
+                }
+            }
+        }
+    }
+    let stack = dispose_capability.get(agent);
+    let disposal = agent[stack].disposal.as_mut().unwrap();
+    // 4. If needsAwait is true and hasAwaited is false, then
+    if disposal.needs_await && !disposal.has_awaited {
+        // a. Perform ! Await(undefined).
+        disposal.needs_await = false;
+        await_value(agent, stack, Value::Undefined, gc);
+        return;
+    }
+    let AsyncDisposal {
+        completion,
+        promise_capability,
+        ..
+    } = agent[stack].disposal.take().unwrap();
+    // 6. Return ? completion.
+    match completion {
+        Some(error) => promise_capability.reject(agent, error),
+        None => promise_capability.resolve(agent, Value::Undefined, gc),
+    }
+}
+
+/// Perform Await(value) on behalf of the asynchronous DisposeResources.
+fn await_value(agent: &mut Agent, dispose_capability: DisposableStack, value: Value, gc: GcScope) {
+    let handler = PromiseReactionHandler::DisposableStack(dispose_capability.unbind());
+    // 2. Let promise be ? PromiseResolve(%Promise%, value).
+    let promise = Promise::resolve(agent, value, gc);
+    // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+    inner_promise_then(agent, promise.unbind(), handler, handler, None);
+}
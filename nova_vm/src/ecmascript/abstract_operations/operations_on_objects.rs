@@ -7,8 +7,11 @@
 use ahash::AHashSet;
 
 use super::{
-    operations_on_iterator_objects::{get_iterator, if_abrupt_close_iterator, iterator_close},
-    testing_and_comparison::{is_callable, require_object_coercible, same_value},
+    operations_on_iterator_objects::{
+        get_iterator, get_iterator_from_method, if_abrupt_close_iterator, iterator_close,
+        IteratorRecord,
+    },
+    testing_and_comparison::{is_callable, is_constructor, require_object_coercible, same_value},
     type_conversion::{
         to_length, to_object, to_property_key, to_property_key_simple, try_to_length,
     },
@@ -40,7 +43,7 @@ use crate::{
         },
     },
     engine::{instanceof_operator, Vm},
-    heap::{Heap, ObjectEntry},
+    heap::{Heap, ObjectEntry, WellKnownSymbolIndexes},
     SmallInteger,
 };
 
@@ -1298,6 +1301,69 @@ pub(crate) fn is_prototype_of_loop(
     }
 }
 
+/// ### [7.3.22 SpeciesConstructor ( O, defaultConstructor )](https://tc39.es/ecma262/#sec-speciesconstructor)
+///
+/// The abstract operation SpeciesConstructor takes arguments O (an Object)
+/// and defaultConstructor (a constructor) and returns either a normal
+/// completion containing a constructor or a throw completion. It is used to
+/// retrieve the constructor that should be used to create new objects that
+/// are derived from O. defaultConstructor is the constructor to use if a
+/// constructor %Symbol.species% property cannot be found starting from O.
+pub(crate) fn species_constructor<'a>(
+    agent: &mut Agent,
+    o: Object,
+    default_constructor: Function,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<Function<'a>> {
+    let default_constructor = default_constructor.bind(gc.nogc());
+    let scoped_default_constructor = default_constructor.scope(agent, gc.nogc());
+    // 1. Let C be ? Get(O, "constructor").
+    let c = get(
+        agent,
+        o,
+        BUILTIN_STRING_MEMORY.constructor.into(),
+        gc.reborrow(),
+    )?
+    .unbind()
+    .bind(gc.nogc());
+    // 2. If C is undefined, return defaultConstructor.
+    if c.is_undefined() {
+        return Ok(scoped_default_constructor.get(agent).bind(gc.into_nogc()));
+    }
+    // 3. If C is not an Object, throw a TypeError exception.
+    let Ok(c) = Object::try_from(c) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "constructor is not an object",
+            gc.nogc(),
+        ));
+    };
+    // 4. Let S be ? Get(C, %Symbol.species%).
+    let s = get(
+        agent,
+        c.unbind(),
+        WellKnownSymbolIndexes::Species.into(),
+        gc.reborrow(),
+    )?
+    .unbind();
+    let gc = gc.into_nogc();
+    let s = s.bind(gc);
+    // 5. If S is either undefined or null, return defaultConstructor.
+    if s.is_undefined() || s.is_null() {
+        return Ok(scoped_default_constructor.get(agent).bind(gc));
+    }
+    // 6. If IsConstructor(S) is true, return S.
+    if let Some(s) = is_constructor(agent, s) {
+        return Ok(s);
+    }
+    // 7. Throw a TypeError exception.
+    Err(agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "%Symbol.species% is not a constructor",
+        gc,
+    ))
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) enum EnumPropKind {
     Key,
@@ -1681,14 +1747,18 @@ pub(crate) fn copy_data_properties(
     // Reserve space in the target's vectors.
     {
         let new_size = agent[target]
-            .keys
+            .values
             .len()
             .checked_add(u32::try_from(keys.len()).unwrap())
             .unwrap();
+        agent
+            .heap
+            .remembered_sets
+            .objects
+            .remember(target.get_index());
         let Heap {
             elements, objects, ..
         } = &mut agent.heap;
-        objects[target].keys.reserve(elements, new_size);
         objects[target].values.reserve(elements, new_size);
     }
 
@@ -2013,6 +2083,7 @@ pub(crate) fn initialize_instance_elements(
                 private_environment: outer_priv_env,
                 is_strict_mode: true,
                 source_code,
+                instruction: None,
             }),
             function: Some(f.unbind()),
             realm: agent[constructor].realm,
@@ -2068,6 +2139,115 @@ pub(crate) struct GroupByRecord<'a, K: 'static + Rootable + Copy + Into<Value>>
     pub(crate) elements: Vec<Scoped<'a, Value>>,
 }
 
+/// The Iterator Record used by GroupBy to iterate over items.
+enum GroupByIterator<'a> {
+    /// An Iterator Record created by GetIterator.
+    Iterator(IteratorRecord),
+    /// Fast path for Arrays that are iterated using the intrinsic
+    /// %Array.prototype.values%: elements are read directly in the same way
+    /// that %ArrayIteratorPrototype%.next would read them, without creating
+    /// the ArrayIterator or its iterator result objects.
+    Array(Scoped<'a, Array<'static>>),
+}
+
+impl<'a> GroupByIterator<'a> {
+    /// ### [7.4.3 GetIterator ( obj, kind )](https://tc39.es/ecma262/#sec-getiterator)
+    ///
+    /// Note: This version is for "sync" kind.
+    fn new(agent: &mut Agent, items: Value, mut gc: GcScope<'_, 'a>) -> JsResult<Self> {
+        let Value::Array(array) = items else {
+            return Ok(Self::Iterator(get_iterator(
+                agent,
+                items,
+                false,
+                gc.reborrow(),
+            )?));
+        };
+        let array = array.scope(agent, gc.nogc());
+        // 1. Let method be ? GetMethod(obj, %Symbol.iterator%).
+        let method = get_method(
+            agent,
+            items,
+            WellKnownSymbolIndexes::Iterator.into(),
+            gc.reborrow(),
+        )?
+        .map(|method| method.unbind());
+        let intrinsic_array_iterator = agent
+            .current_realm()
+            .intrinsics()
+            .array_prototype_values()
+            .into_function()
+            .unbind();
+        match method {
+            // Optimisation: Iterate an Array directly if it uses the Array
+            // values iterator. Note: Like the VmIterator::from_value fast
+            // path, this deliberately does not check if
+            // %ArrayIteratorPrototype%.next has been replaced.
+            Some(method) if method == intrinsic_array_iterator => Ok(Self::Array(array)),
+            // 3. Return ? GetIteratorFromMethod(obj, method).
+            Some(method) => Ok(Self::Iterator(get_iterator_from_method(
+                agent,
+                array.get(agent).into_value(),
+                method,
+                gc.reborrow(),
+            )?)),
+            // 2. If method is undefined, throw a TypeError exception.
+            None => Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Iterator method cannot be undefined",
+                gc.nogc(),
+            )),
+        }
+    }
+
+    /// ### [7.4.10 IteratorStepValue ( iteratorRecord )](https://tc39.es/ecma262/#sec-iteratorstepvalue)
+    ///
+    /// `k` is the number of values that have been returned so far.
+    fn step_value(&mut self, agent: &mut Agent, k: usize, gc: GcScope) -> JsResult<Option<Value>> {
+        match self {
+            Self::Iterator(iterator_record) => iterator_step_value(agent, iterator_record, gc),
+            Self::Array(array) => {
+                let array = array.get(agent).bind(gc.nogc());
+                if k >= array.len(agent) as usize {
+                    return Ok(None);
+                }
+                if let Some(value) = array.as_slice(agent)[k] {
+                    return Ok(Some(value));
+                }
+                // Holes and accessor properties require a full [[Get]].
+                get(agent, array.unbind(), k.try_into().unwrap(), gc).map(Some)
+            }
+        }
+    }
+
+    /// ### [7.4.11 IteratorClose ( iteratorRecord, completion )](https://tc39.es/ecma262/#sec-iteratorclose)
+    fn close<T>(&self, agent: &mut Agent, completion: JsResult<T>, gc: GcScope) -> JsResult<T> {
+        match self {
+            Self::Iterator(iterator_record) => {
+                iterator_close(agent, iterator_record, completion, gc)
+            }
+            // Note: ArrayIterators do not have a return method.
+            Self::Array(_) => completion,
+        }
+    }
+
+    /// ### [7.4.13 IfAbruptCloseIterator ( value, iteratorRecord )](https://tc39.es/ecma262/#sec-ifabruptcloseiterator)
+    fn if_abrupt_close<T>(
+        &self,
+        agent: &mut Agent,
+        value: JsResult<T>,
+        gc: GcScope,
+    ) -> JsResult<T> {
+        match self {
+            Self::Iterator(iterator_record) => {
+                if_abrupt_close_iterator(agent, value, iterator_record, gc)
+            }
+            // Note: ArrayIterators do not have a return method.
+            Self::Array(_) => value,
+        }
+    }
+}
+
 /// ### [7.3.35 GroupBy ( items, callback, keyCoercion )](https://tc39.es/ecma262/#sec-groupby)
 ///
 /// The abstract operation GroupBy takes arguments items (an ECMAScript language value), callback
@@ -2099,7 +2279,7 @@ pub(crate) fn group_by_property<'a, 'b>(
     let mut groups: Vec<GroupByRecord<'b, PropertyKey<'static>>> = vec![];
 
     // 4. Let iteratorRecord be ? GetIterator(iterable).
-    let mut iterator_record = get_iterator(agent, items, false, gc.reborrow())?;
+    let mut iterator_record = GroupByIterator::new(agent, items, gc.reborrow())?;
 
     // 5. Let k be 0.
     let mut k = 0;
@@ -2117,11 +2297,11 @@ pub(crate) fn group_by_property<'a, 'b>(
             );
 
             // ii. Return ? IteratorClose(iteratorRecord, error).
-            return iterator_close(agent, &iterator_record, Err(error), gc.reborrow());
+            return iterator_record.close(agent, Err(error), gc.reborrow());
         }
 
         // b. Let next be ? IteratorStepValue(iteratorRecord).
-        let next = iterator_step_value(agent, &mut iterator_record, gc.reborrow())?;
+        let next = iterator_record.step_value(agent, k, gc.reborrow())?;
 
         // c. If next is DONE, then
         //   i. Return groups.
@@ -2130,7 +2310,7 @@ pub(crate) fn group_by_property<'a, 'b>(
         };
 
         // d. Let value be next.
-        let value = next;
+        let value = next.scope(agent, gc.nogc());
 
         // 𝔽(k)
         let fk = Number::try_from(k).unwrap().into_value();
@@ -2140,22 +2320,28 @@ pub(crate) fn group_by_property<'a, 'b>(
             agent,
             callback_fn.get(agent),
             Value::Undefined,
-            Some(ArgumentsList(&[value, fk])),
+            Some(ArgumentsList(&[next, fk])),
             gc.reborrow(),
         );
 
         // f. IfAbruptCloseIterator(key, iteratorRecord).
-        let key = if_abrupt_close_iterator(agent, key, &iterator_record, gc.reborrow())?;
+        let key = iterator_record.if_abrupt_close(agent, key, gc.reborrow())?;
 
         // g. If keyCoercion is property, then
         // i. Set key to Completion(ToPropertyKey(key)).
         let key = to_property_key(agent, key, gc.reborrow()).map(|pk| pk.unbind());
 
         // ii. IfAbruptCloseIterator(key, iteratorRecord).
-        let key = if_abrupt_close_iterator(agent, key, &iterator_record, gc.reborrow())?;
+        let key = iterator_record.if_abrupt_close(agent, key, gc.reborrow())?;
 
         // i. Perform AddValueToKeyedGroup(groups, key, value).
-        add_value_to_keyed_group(agent, &mut groups, key.unbind(), value, gc.nogc())?;
+        add_value_to_keyed_group(
+            agent,
+            &mut groups,
+            key.unbind(),
+            value.get(agent),
+            gc.nogc(),
+        )?;
 
         // j. Set k to k + 1.
         k += 1;
@@ -2193,7 +2379,7 @@ pub(crate) fn group_by_collection<'a>(
     let mut groups: Vec<GroupByRecord<'a, Value>> = vec![];
 
     // 4. Let iteratorRecord be ? GetIterator(iterable).
-    let mut iterator_record = get_iterator(agent, items, false, gc.reborrow())?;
+    let mut iterator_record = GroupByIterator::new(agent, items, gc.reborrow())?;
 
     // 5. Let k be 0.
     let mut k = 0;
@@ -2211,11 +2397,11 @@ pub(crate) fn group_by_collection<'a>(
             );
 
             // ii. Return ? IteratorClose(iteratorRecord, error).
-            return iterator_close(agent, &iterator_record, Err(error), gc.reborrow());
+            return iterator_record.close(agent, Err(error), gc.reborrow());
         }
 
         // b. Let next be ? IteratorStepValue(iteratorRecord).
-        let next = iterator_step_value(agent, &mut iterator_record, gc.reborrow())?;
+        let next = iterator_record.step_value(agent, k, gc.reborrow())?;
 
         // c. If next is DONE, then
         //   i. Return groups.
@@ -2224,7 +2410,7 @@ pub(crate) fn group_by_collection<'a>(
         };
 
         // d. Let value be next.
-        let value = next;
+        let value = next.scope(agent, gc.nogc());
 
         // 𝔽(k)
         let fk = Number::try_from(k).unwrap().into_value();
@@ -2234,12 +2420,12 @@ pub(crate) fn group_by_collection<'a>(
             agent,
             callback_fn.get(agent),
             Value::Undefined,
-            Some(ArgumentsList(&[value, fk])),
+            Some(ArgumentsList(&[next, fk])),
             gc.reborrow(),
         );
 
         // f. IfAbruptCloseIterator(key, iteratorRecord).
-        let key = if_abrupt_close_iterator(agent, key, &iterator_record, gc.reborrow())?;
+        let key = iterator_record.if_abrupt_close(agent, key, gc.reborrow())?;
 
         // h. Else,
         // i. Assert: keyCoercion is collection.
@@ -2247,7 +2433,7 @@ pub(crate) fn group_by_collection<'a>(
         let key = canonicalize_keyed_collection_key(agent, key);
 
         // i. Perform AddValueToKeyedGroup(groups, key, value).
-        add_value_to_keyed_group(agent, &mut groups, key, value, gc.nogc())?;
+        add_value_to_keyed_group(agent, &mut groups, key, value.get(agent), gc.nogc())?;
 
         // j. Set k to k + 1.
         k += 1;
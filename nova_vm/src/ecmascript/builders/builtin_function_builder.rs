@@ -594,7 +594,7 @@ impl
             }
         }
 
-        let (keys, values) = agent.heap.elements.create_with_stuff(properties);
+        let (shape, values) = agent.heap.create_with_stuff(properties);
 
         let prototype = Some(
             agent
@@ -612,7 +612,7 @@ impl
         *slot = Some(ObjectHeapData {
             extensible: true,
             prototype,
-            keys,
+            shape,
             values,
         });
 
@@ -671,7 +671,7 @@ impl
             }
         }
 
-        let (keys, values) = agent.heap.elements.create_with_stuff(properties);
+        let (shape, values) = agent.heap.create_with_stuff(properties);
 
         let slot = agent
             .heap
@@ -682,7 +682,7 @@ impl
         *slot = Some(ObjectHeapData {
             extensible: true,
             prototype: prototype.0,
-            keys,
+            shape,
             values,
         });
 
@@ -262,7 +262,7 @@ impl<P> OrdinaryObjectBuilder<'_, P, CreatorProperties> {
 
 impl OrdinaryObjectBuilder<'_, NoPrototype, NoProperties> {
     pub fn build(self) -> OrdinaryObject<'static> {
-        let (keys, values) = self.agent.heap.elements.create_with_stuff(vec![]);
+        let (shape, values) = self.agent.heap.create_with_stuff(vec![]);
         let slot = self
             .agent
             .heap
@@ -273,7 +273,7 @@ impl OrdinaryObjectBuilder<'_, NoPrototype, NoProperties> {
         *slot = Some(ObjectHeapData {
             extensible: self.extensible,
             prototype: None,
-            keys,
+            shape,
             values,
         });
         self.this
@@ -282,7 +282,7 @@ impl OrdinaryObjectBuilder<'_, NoPrototype, NoProperties> {
 
 impl<T: IntoObject<'static>> OrdinaryObjectBuilder<'_, CreatorPrototype<T>, NoProperties> {
     pub fn build(self) -> OrdinaryObject<'static> {
-        let (keys, values) = self.agent.heap.elements.create_with_stuff(vec![]);
+        let (shape, values) = self.agent.heap.create_with_stuff(vec![]);
         let slot = self
             .agent
             .heap
@@ -293,7 +293,7 @@ impl<T: IntoObject<'static>> OrdinaryObjectBuilder<'_, CreatorPrototype<T>, NoPr
         *slot = Some(ObjectHeapData {
             extensible: self.extensible,
             prototype: Some(self.prototype.0.into_object()),
-            keys,
+            shape,
             values,
         });
         self.this
@@ -314,11 +314,7 @@ impl OrdinaryObjectBuilder<'_, NoPrototype, CreatorProperties> {
                 panic!("Duplicate key found: {:?}", slice[index].0);
             }
         }
-        let (keys, values) = self
-            .agent
-            .heap
-            .elements
-            .create_with_stuff(self.properties.0);
+        let (shape, values) = self.agent.heap.create_with_stuff(self.properties.0);
         let slot = self
             .agent
             .heap
@@ -329,7 +325,7 @@ impl OrdinaryObjectBuilder<'_, NoPrototype, CreatorProperties> {
         *slot = Some(ObjectHeapData {
             extensible: self.extensible,
             prototype: None,
-            keys,
+            shape,
             values,
         });
         self.this
@@ -350,11 +346,7 @@ impl<T: IntoObject<'static>> OrdinaryObjectBuilder<'_, CreatorPrototype<T>, Crea
                 panic!("Duplicate key found: {:?}", slice[index].0);
             }
         }
-        let (keys, values) = self
-            .agent
-            .heap
-            .elements
-            .create_with_stuff(self.properties.0);
+        let (shape, values) = self.agent.heap.create_with_stuff(self.properties.0);
         let slot = self
             .agent
             .heap
@@ -365,7 +357,7 @@ impl<T: IntoObject<'static>> OrdinaryObjectBuilder<'_, CreatorPrototype<T>, Crea
         *slot = Some(ObjectHeapData {
             extensible: self.extensible,
             prototype: Some(self.prototype.0.into_object()),
-            keys,
+            shape,
             values,
         });
         self.this
@@ -20,6 +20,8 @@ pub(crate) mod control_abstraction_objects;
 pub(crate) mod data_view;
 #[cfg(feature = "date")]
 pub mod date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod disposable_stack;
 mod ecmascript_function;
 pub(crate) mod embedder_object;
 pub mod error;
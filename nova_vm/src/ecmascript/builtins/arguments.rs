@@ -25,97 +25,449 @@
 //! #### Note 5
 //!
 //! ECMAScript implementations of arguments exotic objects have historically contained an accessor property named "caller". Prior to ECMAScript 2017, this specification included the definition of a throwing "caller" property on ordinary arguments objects. Since implementations do not contain this extension any longer, ECMAScript 2017 dropped the requirement for a throwing "caller" accessor.
+use core::ops::{Index, IndexMut};
 
-use crate::engine::context::NoGcScope;
-use crate::engine::unwrap_try;
+use oxc_ast::ast::FormalParameters;
+use oxc_ecmascript::{BoundNames, IsSimpleParameterList};
+
+use crate::engine::context::{GcScope, NoGcScope};
+use crate::engine::rootable::{HeapRootData, HeapRootRef, Rootable};
+use crate::engine::{unwrap_try, Scoped, TryResult};
 use crate::{
     ecmascript::{
         abstract_operations::operations_on_objects::{
             try_create_data_property_or_throw, try_define_property_or_throw,
         },
-        execution::{agent::Agent, ProtoIntrinsics},
+        builtins::ordinary::{
+            ordinary_define_own_property, ordinary_delete, ordinary_get, ordinary_get_own_property,
+            ordinary_set, ordinary_try_get, ordinary_try_set,
+        },
+        execution::{agent::Agent, DeclarativeEnvironmentIndex, JsResult, ProtoIntrinsics},
         types::{
-            IntoFunction, IntoValue, Number, Object, PropertyDescriptor, PropertyKey, Value,
+            Function, InternalMethods, InternalSlots, IntoFunction, IntoObject, IntoValue, Number,
+            Object, OrdinaryObject, PropertyDescriptor, PropertyKey, String, Value,
             BUILTIN_STRING_MEMORY,
         },
     },
-    heap::WellKnownSymbolIndexes,
+    heap::{
+        indexes::{BaseIndex, MappedArgumentsIndex},
+        CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WellKnownSymbolIndexes,
+        WorkQueues,
+    },
 };
 
+use self::data::MappedArgumentsHeapData;
+
 use super::ordinary::ordinary_object_create_with_intrinsics;
 
-// 10.4.4.1 [[GetOwnProperty]] ( P )
-
-// The [[GetOwnProperty]] internal method of an arguments exotic object args takes argument P (a property key) and returns a normal completion containing either a Property Descriptor or undefined. It performs the following steps when called:
-
-//     1. Let desc be OrdinaryGetOwnProperty(args, P).
-//     2. If desc is undefined, return undefined.
-//     3. Let map be args.[[ParameterMap]].
-//     4. Let isMapped be ! HasOwnProperty(map, P).
-//     5. If isMapped is true, then
-//         a. Set desc.[[Value]] to ! Get(map, P).
-//     6. Return desc.
-
-// 10.4.4.2 [[DefineOwnProperty]] ( P, Desc )
-
-// The [[DefineOwnProperty]] internal method of an arguments exotic object args takes arguments P (a property key) and Desc (a Property Descriptor) and returns a normal completion containing a Boolean. It performs the following steps when called:
-
-//     1. Let map be args.[[ParameterMap]].
-//     2. Let isMapped be ! HasOwnProperty(map, P).
-//     3. Let newArgDesc be Desc.
-//     4. If isMapped is true and IsDataDescriptor(Desc) is true, then
-//         a. If Desc does not have a [[Value]] field, Desc has a [[Writable]] field, and Desc.[[Writable]] is false, then
-//             i. Set newArgDesc to a copy of Desc.
-//             ii. Set newArgDesc.[[Value]] to ! Get(map, P).
-//     5. Let allowed be ! OrdinaryDefineOwnProperty(args, P, newArgDesc).
-//     6. If allowed is false, return false.
-//     7. If isMapped is true, then
-//         a. If IsAccessorDescriptor(Desc) is true, then
-//             i. Perform ! map.[[Delete]](P).
-//         b. Else,
-//             i. If Desc has a [[Value]] field, then
-//                 1. Assert: The following Set will succeed, since formal parameters mapped by arguments objects are always writable.
-//                 2. Perform ! Set(map, P, Desc.[[Value]], false).
-//             ii. If Desc has a [[Writable]] field and Desc.[[Writable]] is false, then
-//                 1. Perform ! map.[[Delete]](P).
-//     8. Return true.
-
-// 10.4.4.3 [[Get]] ( P, Receiver )
-
-// The [[Get]] internal method of an arguments exotic object args takes arguments P (a property key) and Receiver (an ECMAScript language value) and returns either a normal completion containing an ECMAScript language value or a throw completion. It performs the following steps when called:
-
-//     1. Let map be args.[[ParameterMap]].
-//     2. Let isMapped be ! HasOwnProperty(map, P).
-//     3. If isMapped is false, then
-//         a. Return ? OrdinaryGet(args, P, Receiver).
-//     4. Else,
-//         a. Assert: map contains a formal parameter mapping for P.
-//         b. Return ! Get(map, P).
-
-// 10.4.4.4 [[Set]] ( P, V, Receiver )
-
-// The [[Set]] internal method of an arguments exotic object args takes arguments P (a property key), V (an ECMAScript language value), and Receiver (an ECMAScript language value) and returns either a normal completion containing a Boolean or a throw completion. It performs the following steps when called:
-
-//     1. If SameValue(args, Receiver) is false, then
-//         a. Let isMapped be false.
-//     2. Else,
-//         a. Let map be args.[[ParameterMap]].
-//         b. Let isMapped be ! HasOwnProperty(map, P).
-//     3. If isMapped is true, then
-//         a. Assert: The following Set will succeed, since formal parameters mapped by arguments objects are always writable.
-//         b. Perform ! Set(map, P, V, false).
-//     4. Return ? OrdinarySet(args, P, V, Receiver).
-
-// 10.4.4.5 [[Delete]] ( P )
-
-// The [[Delete]] internal method of an arguments exotic object args takes argument P (a property key) and returns either a normal completion containing a Boolean or a throw completion. It performs the following steps when called:
-
-//     1. Let map be args.[[ParameterMap]].
-//     2. Let isMapped be ! HasOwnProperty(map, P).
-//     3. Let result be ? OrdinaryDelete(args, P).
-//     4. If result is true and isMapped is true, then
-//         a. Perform ! map.[[Delete]](P).
-//     5. Return result.
+pub(crate) mod data;
+
+/// An arguments exotic object, created by CreateMappedArgumentsObject for
+/// non-strict functions with a simple parameter list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct MappedArgumentsObject<'a>(pub(crate) MappedArgumentsIndex<'a>);
+
+impl MappedArgumentsObject<'_> {
+    /// Unbind this MappedArgumentsObject from its current lifetime. This is
+    /// necessary to use the MappedArgumentsObject as a parameter in a call
+    /// that can perform garbage collection.
+    pub fn unbind(self) -> MappedArgumentsObject<'static> {
+        unsafe { core::mem::transmute::<Self, MappedArgumentsObject<'static>>(self) }
+    }
+
+    // Bind this MappedArgumentsObject to the garbage collection lifetime.
+    // This enables Rust's borrow checker to verify that your
+    // MappedArgumentsObjects cannot not be invalidated by garbage collection
+    // being performed.
+    //
+    // This function is best called with the form
+    // ```rs
+    // let arguments = arguments.bind(&gc);
+    // ```
+    // to make sure that the unbound MappedArgumentsObject cannot be used
+    // after binding.
+    pub const fn bind<'gc>(self, _: NoGcScope<'gc, '_>) -> MappedArgumentsObject<'gc> {
+        unsafe { core::mem::transmute::<Self, MappedArgumentsObject<'gc>>(self) }
+    }
+
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, MappedArgumentsObject<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(BaseIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    /// Returns the slot of the formal parameter binding that P is mapped to.
+    ///
+    /// This is equivalent to `! HasOwnProperty(map, P)`.
+    fn get_mapped_slot(self, agent: &Agent, property_key: PropertyKey) -> Option<u32> {
+        let PropertyKey::Integer(index) = property_key else {
+            return None;
+        };
+        let index = usize::try_from(index.into_i64()).ok()?;
+        agent[self].parameter_map.get(index).copied().flatten()
+    }
+
+    /// ### `! Get(map, P)`
+    fn get_mapped_value(self, agent: &mut Agent, slot: u32, gc: NoGcScope) -> Value {
+        // The getter made by MakeArgGetter performs
+        // env.GetBindingValue(name, false).
+        let env = agent[self].environment;
+        // Note: Mapped parameter bindings are initialized before any code can
+        // access the arguments object.
+        env.get_slot_value(agent, slot, gc).unwrap()
+    }
+
+    /// ### `! Set(map, P, V, false)`
+    fn set_mapped_value(self, agent: &mut Agent, slot: u32, value: Value, gc: NoGcScope) {
+        // The setter made by MakeArgSetter performs
+        // ! env.SetMutableBinding(name, value, false).
+        let env = agent[self].environment;
+        env.set_slot_value(agent, slot, value, false, gc).unwrap();
+    }
+
+    /// ### `! map.[[Delete]](P)`
+    fn unmap(self, agent: &mut Agent, property_key: PropertyKey) {
+        let PropertyKey::Integer(index) = property_key else {
+            unreachable!()
+        };
+        agent[self].parameter_map[index.into_i64() as usize] = None;
+    }
+}
+
+impl IntoValue for MappedArgumentsObject<'_> {
+    fn into_value(self) -> Value {
+        self.into()
+    }
+}
+
+impl<'a> IntoObject<'a> for MappedArgumentsObject<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl From<MappedArgumentsObject<'_>> for Value {
+    fn from(value: MappedArgumentsObject) -> Self {
+        Value::MappedArguments(value.unbind())
+    }
+}
+
+impl<'a> From<MappedArgumentsObject<'a>> for Object<'a> {
+    fn from(value: MappedArgumentsObject) -> Self {
+        Object::MappedArguments(value.unbind())
+    }
+}
+
+impl<'a> InternalSlots<'a> for MappedArgumentsObject<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::Object;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self]
+            .object_index
+            .replace(backing_object.unbind())
+            .is_none());
+    }
+}
+
+impl<'a> InternalMethods<'a> for MappedArgumentsObject<'a> {
+    /// ### [10.4.4.1 \[\[GetOwnProperty\]\] ( P )](https://tc39.es/ecma262/#sec-arguments-exotic-objects-getownproperty-p)
+    fn try_get_own_property(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope,
+    ) -> TryResult<Option<PropertyDescriptor>> {
+        // 1. Let desc be OrdinaryGetOwnProperty(args, P).
+        let backing_object = self.get_backing_object(agent).unwrap();
+        let Some(mut desc) = ordinary_get_own_property(agent, backing_object, property_key) else {
+            // 2. If desc is undefined, return undefined.
+            return TryResult::Continue(None);
+        };
+        // 3. Let map be args.[[ParameterMap]].
+        // 4. Let isMapped be ! HasOwnProperty(map, P).
+        // 5. If isMapped is true, then
+        if let Some(slot) = self.get_mapped_slot(agent, property_key) {
+            // a. Set desc.[[Value]] to ! Get(map, P).
+            desc.value = Some(self.get_mapped_value(agent, slot, gc));
+        }
+        // 6. Return desc.
+        TryResult::Continue(Some(desc))
+    }
+
+    /// ### [10.4.4.2 \[\[DefineOwnProperty\]\] ( P, Desc )](https://tc39.es/ecma262/#sec-arguments-exotic-objects-defineownproperty-p-desc)
+    fn try_define_own_property(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        property_descriptor: PropertyDescriptor,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        // 1. Let map be args.[[ParameterMap]].
+        // 2. Let isMapped be ! HasOwnProperty(map, P).
+        let mapped_slot = self.get_mapped_slot(agent, property_key);
+        // 3. Let newArgDesc be Desc.
+        let mut new_arg_desc = property_descriptor.clone();
+        // 4. If isMapped is true and IsDataDescriptor(Desc) is true, then
+        if let Some(slot) = mapped_slot {
+            if property_descriptor.is_data_descriptor()
+                // a. If Desc does not have a [[Value]] field, Desc has a
+                //    [[Writable]] field, and Desc.[[Writable]] is false, then
+                && property_descriptor.value.is_none()
+                && property_descriptor.writable == Some(false)
+            {
+                // i. Set newArgDesc to a copy of Desc.
+                // ii. Set newArgDesc.[[Value]] to ! Get(map, P).
+                new_arg_desc.value = Some(self.get_mapped_value(agent, slot, gc));
+            }
+        }
+        // 5. Let allowed be ! OrdinaryDefineOwnProperty(args, P, newArgDesc).
+        let backing_object = self.get_backing_object(agent).unwrap();
+        let allowed =
+            ordinary_define_own_property(agent, backing_object, property_key, new_arg_desc, gc);
+        // 6. If allowed is false, return false.
+        if !allowed {
+            return TryResult::Continue(false);
+        }
+        // 7. If isMapped is true, then
+        if let Some(slot) = mapped_slot {
+            // a. If IsAccessorDescriptor(Desc) is true, then
+            if property_descriptor.is_accessor_descriptor() {
+                // i. Perform ! map.[[Delete]](P).
+                self.unmap(agent, property_key);
+            } else {
+                // b. Else,
+                // i. If Desc has a [[Value]] field, then
+                if let Some(value) = property_descriptor.value {
+                    // 1. Assert: The following Set will succeed, since formal
+                    //    parameters mapped by arguments objects are always
+                    //    writable.
+                    // 2. Perform ! Set(map, P, Desc.[[Value]], false).
+                    self.set_mapped_value(agent, slot, value, gc);
+                }
+                // ii. If Desc has a [[Writable]] field and Desc.[[Writable]]
+                //     is false, then
+                if property_descriptor.writable == Some(false) {
+                    // 1. Perform ! map.[[Delete]](P).
+                    self.unmap(agent, property_key);
+                }
+            }
+        }
+        // 8. Return true.
+        TryResult::Continue(true)
+    }
+
+    /// ### [10.4.4.3 \[\[Get\]\] ( P, Receiver )](https://tc39.es/ecma262/#sec-arguments-exotic-objects-get-p-receiver)
+    fn try_get(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        receiver: Value,
+        gc: NoGcScope,
+    ) -> TryResult<Value> {
+        // 1. Let map be args.[[ParameterMap]].
+        // 2. Let isMapped be ! HasOwnProperty(map, P).
+        match self.get_mapped_slot(agent, property_key) {
+            // 3. If isMapped is false, then
+            None => {
+                // a. Return ? OrdinaryGet(args, P, Receiver).
+                let backing_object = self.get_backing_object(agent).unwrap();
+                ordinary_try_get(agent, backing_object, property_key, receiver, gc)
+            }
+            // 4. Else,
+            Some(slot) => {
+                // a. Assert: map contains a formal parameter mapping for P.
+                // b. Return ! Get(map, P).
+                TryResult::Continue(self.get_mapped_value(agent, slot, gc))
+            }
+        }
+    }
+
+    fn internal_get(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        receiver: Value,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        let property_key = property_key.bind(gc.nogc());
+        match self.get_mapped_slot(agent, property_key) {
+            None => {
+                let backing_object = self.get_backing_object(agent).unwrap();
+                ordinary_get(agent, backing_object, property_key.unbind(), receiver, gc)
+            }
+            Some(slot) => Ok(self.get_mapped_value(agent, slot, gc.nogc())),
+        }
+    }
+
+    /// ### [10.4.4.4 \[\[Set\]\] ( P, V, Receiver )](https://tc39.es/ecma262/#sec-arguments-exotic-objects-set-p-v-receiver)
+    fn try_set(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        // 1. If SameValue(args, Receiver) is false, then
+        //     a. Let isMapped be false.
+        // 2. Else,
+        //     a. Let map be args.[[ParameterMap]].
+        //     b. Let isMapped be ! HasOwnProperty(map, P).
+        // 3. If isMapped is true, then
+        if receiver == self.into_value() {
+            if let Some(slot) = self.get_mapped_slot(agent, property_key) {
+                // a. Assert: The following Set will succeed, since formal
+                //    parameters mapped by arguments objects are always
+                //    writable.
+                // b. Perform ! Set(map, P, V, false).
+                self.set_mapped_value(agent, slot, value, gc);
+            }
+        }
+        // 4. Return ? OrdinarySet(args, P, V, Receiver).
+        ordinary_try_set(agent, self.into_object(), property_key, value, receiver, gc)
+    }
+
+    fn internal_set(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        gc: GcScope,
+    ) -> JsResult<bool> {
+        let property_key = property_key.bind(gc.nogc());
+        if receiver == self.into_value() {
+            if let Some(slot) = self.get_mapped_slot(agent, property_key) {
+                self.set_mapped_value(agent, slot, value, gc.nogc());
+            }
+        }
+        ordinary_set(
+            agent,
+            self.into_object(),
+            property_key.unbind(),
+            value,
+            receiver,
+            gc,
+        )
+    }
+
+    /// ### [10.4.4.5 \[\[Delete\]\] ( P )](https://tc39.es/ecma262/#sec-arguments-exotic-objects-delete-p)
+    fn try_delete(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope,
+    ) -> TryResult<bool> {
+        // 1. Let map be args.[[ParameterMap]].
+        // 2. Let isMapped be ! HasOwnProperty(map, P).
+        let is_mapped = self.get_mapped_slot(agent, property_key).is_some();
+        // 3. Let result be ? OrdinaryDelete(args, P).
+        let backing_object = self.get_backing_object(agent).unwrap();
+        let result = ordinary_delete(agent, backing_object, property_key, gc);
+        // 4. If result is true and isMapped is true, then
+        if result && is_mapped {
+            // a. Perform ! map.[[Delete]](P).
+            self.unmap(agent, property_key);
+        }
+        // 5. Return result.
+        TryResult::Continue(result)
+    }
+}
+
+impl Index<MappedArgumentsObject<'_>> for Agent {
+    type Output = MappedArgumentsHeapData;
+
+    fn index(&self, index: MappedArgumentsObject) -> &Self::Output {
+        &self.heap.mapped_arguments[index]
+    }
+}
+
+impl IndexMut<MappedArgumentsObject<'_>> for Agent {
+    fn index_mut(&mut self, index: MappedArgumentsObject) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .mapped_arguments
+            .remember(index.get_index());
+        &mut self.heap.mapped_arguments[index]
+    }
+}
+
+impl Index<MappedArgumentsObject<'_>> for Vec<Option<MappedArgumentsHeapData>> {
+    type Output = MappedArgumentsHeapData;
+
+    fn index(&self, index: MappedArgumentsObject) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("MappedArgumentsObject out of bounds")
+            .as_ref()
+            .expect("MappedArgumentsObject slot empty")
+    }
+}
+
+impl IndexMut<MappedArgumentsObject<'_>> for Vec<Option<MappedArgumentsHeapData>> {
+    fn index_mut(&mut self, index: MappedArgumentsObject) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("MappedArgumentsObject out of bounds")
+            .as_mut()
+            .expect("MappedArgumentsObject slot empty")
+    }
+}
+
+impl Rootable for MappedArgumentsObject<'static> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::MappedArguments(value))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::MappedArguments(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for MappedArgumentsObject<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.mapped_arguments.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.mapped_arguments.shift_index(&mut self.0);
+    }
+}
+
+impl CreateHeapData<MappedArgumentsHeapData, MappedArgumentsObject<'static>> for Heap {
+    fn create(&mut self, data: MappedArgumentsHeapData) -> MappedArgumentsObject<'static> {
+        self.mapped_arguments.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<MappedArgumentsHeapData>>();
+        MappedArgumentsObject(MappedArgumentsIndex::last(&self.mapped_arguments))
+    }
+}
 
 /// ### [10.4.4.6 CreateUnmappedArgumentsObject ( argumentsList )](https://tc39.es/ecma262/#sec-createunmappedargumentsobject)
 ///
@@ -221,60 +573,149 @@ pub(crate) fn create_unmapped_arguments_object<'a>(
     Object::Arguments(obj)
 }
 
-// 10.4.4.7 CreateMappedArgumentsObject ( func, formals, argumentsList, env )
-
-// The abstract operation CreateMappedArgumentsObject takes arguments func (an Object), formals (a Parse Node), argumentsList (a List of ECMAScript language values), and env (an Environment Record) and returns an arguments exotic object. It performs the following steps when called:
-
-//     1. Assert: formals does not contain a rest parameter, any binding patterns, or any initializers. It may contain duplicate identifiers.
-//     2. Let len be the number of elements in argumentsList.
-//     3. Let obj be MakeBasicObject(« [[Prototype]], [[Extensible]], [[ParameterMap]] »).
-//     4. Set obj.[[GetOwnProperty]] as specified in 10.4.4.1.
-//     5. Set obj.[[DefineOwnProperty]] as specified in 10.4.4.2.
-//     6. Set obj.[[Get]] as specified in 10.4.4.3.
-//     7. Set obj.[[Set]] as specified in 10.4.4.4.
-//     8. Set obj.[[Delete]] as specified in 10.4.4.5.
-//     9. Set obj.[[Prototype]] to %Object.prototype%.
-//     10. Let map be OrdinaryObjectCreate(null).
-//     11. Set obj.[[ParameterMap]] to map.
-//     12. Let parameterNames be the BoundNames of formals.
-//     13. Let numberOfParameters be the number of elements in parameterNames.
-//     14. Let index be 0.
-//     15. Repeat, while index < len,
-//         a. Let val be argumentsList[index].
-//         b. Perform ! CreateDataPropertyOrThrow(obj, ! ToString(𝔽(index)), val).
-//         c. Set index to index + 1.
-//     16. Perform ! DefinePropertyOrThrow(obj, "length", PropertyDescriptor { [[Value]]: 𝔽(len), [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
-//     17. Let mappedNames be a new empty List.
-//     18. Set index to numberOfParameters - 1.
-//     19. Repeat, while index ≥ 0,
-//         a. Let name be parameterNames[index].
-//         b. If mappedNames does not contain name, then
-//             i. Append name to mappedNames.
-//             ii. If index < len, then
-//                 1. Let g be MakeArgGetter(name, env).
-//                 2. Let p be MakeArgSetter(name, env).
-//                 3. Perform ! map.[[DefineOwnProperty]](! ToString(𝔽(index)), PropertyDescriptor { [[Set]]: p, [[Get]]: g, [[Enumerable]]: false, [[Configurable]]: true }).
-//         c. Set index to index - 1.
-//     20. Perform ! DefinePropertyOrThrow(obj, @@iterator, PropertyDescriptor { [[Value]]: %Array.prototype.values%, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
-//     21. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor { [[Value]]: func, [[Writable]]: true, [[Enumerable]]: false, [[Configurable]]: true }).
-//     22. Return obj.
-
-// 10.4.4.7.1 MakeArgGetter ( name, env )
-
-// The abstract operation MakeArgGetter takes arguments name (a String) and env (an Environment Record) and returns a function object. It creates a built-in function object that when executed returns the value bound for name in env. It performs the following steps when called:
-
-//     1. Let getterClosure be a new Abstract Closure with no parameters that captures name and env and performs the following steps when called:
-//         a. Return env.GetBindingValue(name, false).
-//     2. Let getter be CreateBuiltinFunction(getterClosure, 0, "", « »).
-//     3. NOTE: getter is never directly accessible to ECMAScript code.
-//     4. Return getter.
-
-// 10.4.4.7.2 MakeArgSetter ( name, env )
-
-// The abstract operation MakeArgSetter takes arguments name (a String) and env (an Environment Record) and returns a function object. It creates a built-in function object that when executed sets the value bound for name in env. It performs the following steps when called:
-
-//     1. Let setterClosure be a new Abstract Closure with parameters (value) that captures name and env and performs the following steps when called:
-//         a. Return ! env.SetMutableBinding(name, value, false).
-//     2. Let setter be CreateBuiltinFunction(setterClosure, 1, "", « »).
-//     3. NOTE: setter is never directly accessible to ECMAScript code.
-//     4. Return setter.
+/// ### [10.4.4.7 CreateMappedArgumentsObject ( func, formals, argumentsList, env )](https://tc39.es/ecma262/#sec-createmappedargumentsobject)
+///
+/// The abstract operation CreateMappedArgumentsObject takes arguments func
+/// (an Object), formals (a Parse Node), argumentsList (a List of ECMAScript
+/// language values), and env (an Environment Record) and returns an
+/// arguments exotic object.
+///
+/// The \[\[ParameterMap]] is not an object: Each mapped index refers directly
+/// to the slot of its formal parameter binding in env, which takes the place
+/// of the getter and setter functions made by MakeArgGetter and
+/// MakeArgSetter.
+pub(crate) fn create_mapped_arguments_object<'a>(
+    agent: &mut Agent,
+    func: Function,
+    formals: &FormalParameters,
+    arguments_list: &[Value],
+    env: DeclarativeEnvironmentIndex,
+    gc: NoGcScope<'a, '_>,
+) -> Object<'a> {
+    // 1. Assert: formals does not contain a rest parameter, any binding
+    //    patterns, or any initializers. It may contain duplicate identifiers.
+    debug_assert!(formals.is_simple_parameter_list());
+    // 2. Let len be the number of elements in argumentsList.
+    let len = arguments_list.len();
+    // 3. Let obj be MakeBasicObject(« [[Prototype]], [[Extensible]], [[ParameterMap]] »).
+    // 4. Set obj.[[GetOwnProperty]] as specified in 10.4.4.1.
+    // 5. Set obj.[[DefineOwnProperty]] as specified in 10.4.4.2.
+    // 6. Set obj.[[Get]] as specified in 10.4.4.3.
+    // 7. Set obj.[[Set]] as specified in 10.4.4.4.
+    // 8. Set obj.[[Delete]] as specified in 10.4.4.5.
+    // 9. Set obj.[[Prototype]] to %Object.prototype%.
+    // Note: The properties are defined on the backing object before the
+    // arguments exotic object is created, as nothing is mapped yet.
+    let obj =
+        ordinary_object_create_with_intrinsics(agent, Some(ProtoIntrinsics::Object), None, gc);
+    let Object::Object(obj) = obj else {
+        unreachable!()
+    };
+    // 10. Let map be OrdinaryObjectCreate(null).
+    // 11. Set obj.[[ParameterMap]] to map.
+    // 12. Let parameterNames be the BoundNames of formals.
+    let mut parameter_names = Vec::with_capacity(formals.parameters_count());
+    formals.bound_names(&mut |identifier| {
+        parameter_names.push(identifier.name);
+    });
+    // 13. Let numberOfParameters be the number of elements in parameterNames.
+    let number_of_parameters = parameter_names.len();
+    // 14. Let index be 0.
+    // 15. Repeat, while index < len,
+    for (index, &val) in arguments_list.iter().enumerate() {
+        // a. Let val be argumentsList[index].
+        // b. Perform ! CreateDataPropertyOrThrow(obj, ! ToString(𝔽(index)), val).
+        debug_assert!(index < u32::MAX as usize);
+        let index = index as u32;
+        let key = PropertyKey::Integer(index.into());
+        unwrap_try(try_create_data_property_or_throw(agent, obj, key, val, gc)).unwrap();
+        // c. Set index to index + 1.
+    }
+    // 16. Perform ! DefinePropertyOrThrow(obj, "length", PropertyDescriptor {
+    //     [[Value]]: 𝔽(len), [[Writable]]: true, [[Enumerable]]: false,
+    //     [[Configurable]]: true }).
+    let len_value = Number::from_f64(agent, len as f64, gc).into_value();
+    unwrap_try(try_define_property_or_throw(
+        agent,
+        obj,
+        PropertyKey::from(BUILTIN_STRING_MEMORY.length),
+        PropertyDescriptor {
+            value: Some(len_value),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+        gc,
+    ))
+    .unwrap();
+    // 17. Let mappedNames be a new empty List.
+    let mut mapped_names = Vec::with_capacity(number_of_parameters);
+    let mut parameter_map = vec![None; number_of_parameters.min(len)].into_boxed_slice();
+    // 18. Set index to numberOfParameters - 1.
+    // 19. Repeat, while index ≥ 0,
+    for (index, &name) in parameter_names.iter().enumerate().rev() {
+        // a. Let name be parameterNames[index].
+        // b. If mappedNames does not contain name, then
+        if !mapped_names.contains(&name) {
+            // i. Append name to mappedNames.
+            mapped_names.push(name);
+            // ii. If index < len, then
+            if index < len {
+                // 1. Let g be MakeArgGetter(name, env).
+                // 2. Let p be MakeArgSetter(name, env).
+                // 3. Perform ! map.[[DefineOwnProperty]](! ToString(𝔽(index)),
+                //    PropertyDescriptor { [[Set]]: p, [[Get]]: g,
+                //    [[Enumerable]]: false, [[Configurable]]: true }).
+                let name = String::from_str(agent, &name, gc);
+                parameter_map[index] = Some(env.get_slot(agent, name).unwrap());
+            }
+        }
+        // c. Set index to index - 1.
+    }
+    // 20. Perform ! DefinePropertyOrThrow(obj, @@iterator, PropertyDescriptor {
+    //     [[Value]]: %Array.prototype.values%, [[Writable]]: true,
+    //     [[Enumerable]]: false, [[Configurable]]: true }).
+    let array_prototype_values = agent
+        .current_realm()
+        .intrinsics()
+        .array_prototype_values()
+        .into_value();
+    unwrap_try(try_define_property_or_throw(
+        agent,
+        obj,
+        PropertyKey::Symbol(WellKnownSymbolIndexes::Iterator.into()),
+        PropertyDescriptor {
+            value: Some(array_prototype_values),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+        gc,
+    ))
+    .unwrap();
+    // 21. Perform ! DefinePropertyOrThrow(obj, "callee", PropertyDescriptor {
+    //     [[Value]]: func, [[Writable]]: true, [[Enumerable]]: false,
+    //     [[Configurable]]: true }).
+    unwrap_try(try_define_property_or_throw(
+        agent,
+        obj,
+        PropertyKey::from(BUILTIN_STRING_MEMORY.callee),
+        PropertyDescriptor {
+            value: Some(func.into_value()),
+            writable: Some(true),
+            enumerable: Some(false),
+            configurable: Some(true),
+            ..Default::default()
+        },
+        gc,
+    ))
+    .unwrap();
+    // 22. Return obj.
+    Object::MappedArguments(agent.heap.create(MappedArgumentsHeapData {
+        object_index: Some(obj.unbind()),
+        environment: env,
+        parameter_map,
+    }))
+}
@@ -0,0 +1,44 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{execution::DeclarativeEnvironmentIndex, types::OrdinaryObject},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+#[derive(Debug, Clone)]
+pub struct MappedArgumentsHeapData {
+    pub(crate) object_index: Option<OrdinaryObject<'static>>,
+    /// The Environment Record of the function that the formal parameter
+    /// bindings live in.
+    pub(crate) environment: DeclarativeEnvironmentIndex,
+    /// ### \[\[ParameterMap]]
+    ///
+    /// Slot of the formal parameter binding in the environment that each
+    /// array index property is mapped to. Indexes past the end of the map and
+    /// indexes whose slot is None are not mapped.
+    pub(crate) parameter_map: Box<[Option<u32>]>,
+}
+
+impl HeapMarkAndSweep for MappedArgumentsHeapData {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            environment,
+            parameter_map: _,
+        } = self;
+        object_index.mark_values(queues);
+        environment.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            environment,
+            parameter_map: _,
+        } = self;
+        object_index.sweep_values(compactions);
+        environment.sweep_values(compactions);
+    }
+}
@@ -383,6 +383,10 @@ impl<'a> InternalMethods<'a> for Array<'a> {
                 if !length_writable {
                     return TryResult::Continue(false);
                 }
+                if !agent.can_allocate(elements.reserve_bytes(index + 1)) {
+                    return TryResult::Break(());
+                }
+                agent.heap.remembered_sets.arrays.remember(self.get_index());
                 let Heap {
                     elements, arrays, ..
                 } = &mut agent.heap;
@@ -440,6 +444,15 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         if property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.length) {
             array_set_length(agent, self, property_descriptor, gc)
         } else {
+            if let PropertyKey::Integer(index) = property_key {
+                if let Ok(index) = u32::try_from(index.into_i64()) {
+                    // Note: Elements are stored densely, so defining an index
+                    // past the end of the Array may allocate a large elements
+                    // vector.
+                    let bytes = agent[self].elements.reserve_bytes(index.saturating_add(1));
+                    agent.reserve_heap_bytes(bytes, gc.nogc())?;
+                }
+            }
             Ok(unwrap_try(self.try_define_own_property(
                 agent,
                 property_key.unbind(),
@@ -708,6 +721,7 @@ impl Index<Array<'_>> for Agent {
 
 impl IndexMut<Array<'_>> for Agent {
     fn index_mut(&mut self, index: Array) -> &mut Self::Output {
+        self.heap.remembered_sets.arrays.remember(index.get_index());
         &mut self.heap.arrays[index]
     }
 }
@@ -758,6 +772,7 @@ impl Rootable for Array<'static> {
 impl CreateHeapData<ArrayHeapData, Array<'static>> for Heap {
     fn create(&mut self, data: ArrayHeapData) -> Array<'static> {
         self.arrays.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ArrayHeapData>>();
         Array::from(ArrayIndex::last(&self.arrays))
     }
 }
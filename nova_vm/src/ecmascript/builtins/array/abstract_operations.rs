@@ -58,6 +58,9 @@ pub(crate) fn array_create<'a>(
     } else {
         None
     };
+    let capacity_bytes = SealableElementsVector::default()
+        .reserve_bytes(u32::try_from(capacity).unwrap_or(u32::MAX));
+    agent.reserve_heap_bytes(capacity_bytes, gc)?;
     // 3. Let A be MakeBasicObject(« [[Prototype]], [[Extensible]] »).
     // 5. Set A.[[DefineOwnProperty]] as specified in 10.4.2.1.
     let mut elements = agent
@@ -210,8 +213,12 @@ pub(crate) fn array_set_length(
     }
     let gc = gc.into_nogc();
     let a = a.get(agent).bind(gc);
+    // Note: Elements are stored densely, so growing the length of the Array
+    // may allocate a large elements vector.
+    agent.reserve_heap_bytes(agent[a].elements.reserve_bytes(new_len), gc)?;
     // 6. Set newLenDesc.[[Value]] to newLen.
     // 7. Let oldLenDesc be OrdinaryGetOwnProperty(A, "length").
+    agent.heap.remembered_sets.arrays.remember(a.get_index());
     let Heap {
         arrays, elements, ..
     } = &mut agent.heap;
@@ -314,8 +321,12 @@ pub(crate) fn array_try_set_length(
     if !Number::same_value_zero(agent, number_len, new_len.into()) {
         return TryResult::Break(());
     }
+    if !agent.can_allocate(agent[a].elements.reserve_bytes(new_len)) {
+        return TryResult::Break(());
+    }
     // 6. Set newLenDesc.[[Value]] to newLen.
     // 7. Let oldLenDesc be OrdinaryGetOwnProperty(A, "length").
+    agent.heap.remembered_sets.arrays.remember(a.get_index());
     let Heap {
         arrays, elements, ..
     } = &mut agent.heap;
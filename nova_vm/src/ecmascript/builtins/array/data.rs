@@ -68,6 +68,12 @@ impl SealableElementsVector {
         }
     }
 
+    /// Returns the number of bytes that reserving room for `new_len` elements
+    /// allocates onto the heap.
+    pub(crate) fn reserve_bytes(&self, new_len: u32) -> usize {
+        ElementsVector::from(*self).reserve_bytes(new_len)
+    }
+
     pub fn reserve(&mut self, elements: &mut ElementArrays, new_len: u32) {
         let mut elements_vector: ElementsVector = (*self).into();
         elements_vector.reserve(elements, new_len);
@@ -27,7 +27,8 @@ use crate::{
 use abstract_operations::detach_array_buffer;
 pub(crate) use abstract_operations::{
     allocate_array_buffer, array_buffer_byte_length, clone_array_buffer, get_value_from_buffer,
-    is_detached_buffer, is_fixed_length_array_buffer, set_value_in_buffer, DetachKey, Ordering,
+    is_detached_buffer, is_fixed_length_array_buffer, numeric_to_raw_bytes, raw_bytes_to_numeric,
+    set_value_in_buffer, DetachKey, Ordering,
 };
 use core::ops::{Index, IndexMut};
 pub use data::*;
@@ -110,20 +111,30 @@ impl<'a> ArrayBuffer<'a> {
     ///
     /// `new_byte_length` must be a safe integer.
     pub(crate) fn resize(self, agent: &mut Agent, new_byte_length: usize) {
+        let growth = new_byte_length.saturating_sub(self.byte_length(agent));
+        agent.heap.alloc_counter += growth;
         agent[self].resize(new_byte_length);
     }
 
-    /// Copy data from `source` ArrayBuffer to this ArrayBuffer.
+    /// Copy `count` bytes of data from `source` ArrayBuffer, starting at
+    /// `source_index`, to this ArrayBuffer, starting at `target_index`.
     ///
-    /// `self` and `source` must be different ArrayBuffers.
+    /// `self` and `source` may be the same ArrayBuffer, in which case the
+    /// copied ranges are allowed to overlap.
     pub(crate) fn copy_array_buffer_data(
         self,
         agent: &mut Agent,
+        target_index: usize,
         source: ArrayBuffer,
-        first: usize,
+        source_index: usize,
         count: usize,
     ) {
-        debug_assert_ne!(self, source);
+        if self == source {
+            agent[self]
+                .get_data_block_mut()
+                .copy_within::<u8>(target_index, source_index, count);
+            return;
+        }
         let array_buffers = &mut *agent.heap.array_buffers;
         let (source_data, target_data) = if self.get_index() > source.get_index() {
             let (before, after) = array_buffers.split_at_mut(self.get_index());
@@ -140,7 +151,7 @@ impl<'a> ArrayBuffer<'a> {
         };
         let source_data = source_data.buffer.get_data_block();
         let target_data = target_data.buffer.get_data_block_mut();
-        target_data.copy_data_block_bytes(0, source_data, first, count);
+        target_data.copy_data_block_bytes(target_index, source_data, source_index, count);
     }
 
     pub(crate) const fn _def() -> Self {
@@ -209,6 +220,10 @@ impl Index<ArrayBuffer<'_>> for Agent {
 
 impl IndexMut<ArrayBuffer<'_>> for Agent {
     fn index_mut(&mut self, index: ArrayBuffer) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .array_buffers
+            .remember(index.get_index());
         &mut self.heap.array_buffers[index]
     }
 }
@@ -287,6 +302,7 @@ impl HeapMarkAndSweep for ArrayBuffer<'static> {
 impl CreateHeapData<ArrayBufferHeapData, ArrayBuffer<'static>> for Heap {
     fn create(&mut self, data: ArrayBufferHeapData) -> ArrayBuffer<'static> {
         self.array_buffers.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ArrayBufferHeapData>>();
         ArrayBuffer::from(ArrayBufferIndex::last(&self.array_buffers))
     }
 }
@@ -61,6 +61,19 @@ pub(crate) fn allocate_array_buffer<'a>(
             ));
         }
         // b. Append [[ArrayBufferMaxByteLength]] to slots.
+        // 8. a. If it is not possible to create a Data Block block consisting
+        //       of maxByteLength bytes, throw a RangeError exception.
+        if agent
+            .options
+            .max_array_buffer_byte_length
+            .is_some_and(|limit| max_byte_length.unwrap() > limit as u64)
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Maximum byte length exceeds the maximum ArrayBuffer byte length",
+                gc,
+            ));
+        }
     }
     // 4. Let obj be ? OrdinaryCreateFromConstructor(constructor, "%ArrayBuffer.prototype%", slots).
     // 5. Let block be ? CreateByteDataBlock(byteLength).
@@ -395,6 +395,10 @@ impl<'a> Index<BoundFunction<'a>> for Agent {
 
 impl<'a> IndexMut<BoundFunction<'a>> for Agent {
     fn index_mut(&mut self, index: BoundFunction<'a>) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .bound_functions
+            .remember(index.get_index());
         &mut self.heap.bound_functions[index]
     }
 }
@@ -422,6 +426,7 @@ impl<'a> IndexMut<BoundFunction<'a>> for Vec<Option<BoundFunctionHeapData>> {
 impl CreateHeapData<BoundFunctionHeapData, BoundFunction<'static>> for Heap {
     fn create(&mut self, data: BoundFunctionHeapData) -> BoundFunction<'static> {
         self.bound_functions.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<BoundFunctionHeapData>>();
         BoundFunction(BoundFunctionIndex::last(&self.bound_functions))
     }
 }
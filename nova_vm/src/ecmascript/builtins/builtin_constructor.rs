@@ -177,6 +177,10 @@ impl Index<BuiltinConstructorFunction<'_>> for Agent {
 
 impl IndexMut<BuiltinConstructorFunction<'_>> for Agent {
     fn index_mut(&mut self, index: BuiltinConstructorFunction) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .builtin_constructors
+            .remember(index.get_index());
         &mut self.heap.builtin_constructors[index]
     }
 }
@@ -565,6 +569,7 @@ impl Rootable for BuiltinConstructorFunction<'_> {
 impl CreateHeapData<BuiltinConstructorHeapData, BuiltinConstructorFunction<'static>> for Heap {
     fn create(&mut self, data: BuiltinConstructorHeapData) -> BuiltinConstructorFunction<'static> {
         self.builtin_constructors.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<BuiltinConstructorHeapData>>();
         BuiltinConstructorIndex::last(&self.builtin_constructors).into()
     }
 }
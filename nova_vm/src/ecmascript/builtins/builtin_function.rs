@@ -211,6 +211,10 @@ impl Index<BuiltinFunction<'_>> for Agent {
 
 impl IndexMut<BuiltinFunction<'_>> for Agent {
     fn index_mut(&mut self, index: BuiltinFunction) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .builtin_functions
+            .remember(index.get_index());
         &mut self.heap.builtin_functions[index]
     }
 }
@@ -601,6 +605,7 @@ pub fn create_builtin_function<'a>(
 impl CreateHeapData<BuiltinFunctionHeapData, BuiltinFunction<'static>> for Heap {
     fn create(&mut self, data: BuiltinFunctionHeapData) -> BuiltinFunction<'static> {
         self.builtin_functions.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<BuiltinFunctionHeapData>>();
         BuiltinFunctionIndex::last(&self.builtin_functions).into()
     }
 }
@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::engine::context::GcScope;
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ordinary::ordinary_create_from_constructor, ArgumentsList, Behaviour, Builtin,
+            BuiltinIntrinsicConstructor,
+        },
+        execution::{agent::ExceptionType, Agent, JsResult, ProtoIntrinsics, RealmIdentifier},
+        types::{Function, IntoObject, Object, String, Value, BUILTIN_STRING_MEMORY},
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct AsyncDisposableStackConstructor;
+impl Builtin for AsyncDisposableStackConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.AsyncDisposableStack;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for AsyncDisposableStackConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::AsyncDisposableStack;
+}
+
+impl AsyncDisposableStackConstructor {
+    /// ### [12.4.1.1 AsyncDisposableStack ( )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack)
+    fn constructor(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        new_target: Option<Object>,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Cannot call AsyncDisposableStack as a function",
+                gc.nogc(),
+            ));
+        };
+        // 2. Let asyncDisposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]], [[DisposeCapability]] »).
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        let new_target = Function::try_from(new_target).unwrap();
+        let async_disposable_stack = ordinary_create_from_constructor(
+            agent,
+            new_target,
+            ProtoIntrinsics::AsyncDisposableStack,
+            gc,
+        )?;
+        // 5. Return asyncDisposableStack.
+        Ok(async_disposable_stack.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: RealmIdentifier) {
+        let intrinsics = agent.get_realm(realm).intrinsics();
+        let async_disposable_stack_prototype = intrinsics.async_disposable_stack_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<AsyncDisposableStackConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(async_disposable_stack_prototype.into_object())
+        .build();
+    }
+}
@@ -66,10 +66,18 @@ impl AwaitReactionIdentifier {
         let execution_context = agent[self].execution_context.take().unwrap();
         agent.execution_context_stack.push(execution_context);
 
-        // 3. d. Resume the suspended evaluation of asyncContext using NormalCompletion(v) as the result of the operation that suspended it.
-        // 5. d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
+        // Note: Nothing refers to the AwaitReaction while the function runs,
+        // so its contents are rooted and a new AwaitReaction is created if
+        // the function awaits again.
         let vm = agent[self].vm.take().unwrap();
         let async_function = agent[self].async_function.unwrap();
+        let return_promise_capability = agent[self].return_promise_capability;
+        let must_be_unresolved = return_promise_capability.must_be_unresolved();
+        let scoped_async_function = async_function.scope(agent, gc.nogc());
+        let scoped_promise = return_promise_capability.promise().scope(agent, gc.nogc());
+
+        // 3. d. Resume the suspended evaluation of asyncContext using NormalCompletion(v) as the result of the operation that suspended it.
+        // 5. d. Resume the suspended evaluation of asyncContext using ThrowCompletion(reason) as the result of the operation that suspended it.
         let execution_result = match reaction_type {
             PromiseReactionType::Fulfill => vm.resume(
                 agent,
@@ -84,6 +92,8 @@ impl AwaitReactionIdentifier {
                 gc.reborrow(),
             ),
         };
+        let return_promise_capability =
+            PromiseCapability::from_promise(scoped_promise.get(agent), must_be_unresolved);
 
         match execution_result {
             ExecutionResult::Return(result) => {
@@ -94,9 +104,7 @@ impl AwaitReactionIdentifier {
                 //       i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « undefined »).
                 //    f. Else if result is a return completion, then
                 //       i. Perform ! Call(promiseCapability.[[Resolve]], undefined, « result.[[Value]] »).
-                agent[self]
-                    .return_promise_capability
-                    .resolve(agent, result, gc);
+                return_promise_capability.resolve(agent, result, gc);
             }
             ExecutionResult::Throw(err) => {
                 // [27.7.5.2 AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )](https://tc39.es/ecma262/#sec-asyncblockstart)
@@ -104,19 +112,21 @@ impl AwaitReactionIdentifier {
                 agent.execution_context_stack.pop();
                 // 2. g. i. Assert: result is a throw completion.
                 //       ii. Perform ! Call(promiseCapability.[[Reject]], undefined, « result.[[Value]] »).
-                agent[self]
-                    .return_promise_capability
-                    .reject(agent, err.value());
+                return_promise_capability.reject(agent, err.value());
             }
             ExecutionResult::Await { vm, awaited_value } => {
                 // [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
                 // 8. Remove asyncContext from the execution context stack and restore the execution context that is at the top of the execution context stack as the running execution context.
-                agent[self].vm = Some(vm);
-                agent[self].execution_context = Some(agent.execution_context_stack.pop().unwrap());
+                let execution_context = agent.execution_context_stack.pop().unwrap();
 
                 // `handler` corresponds to the `fulfilledClosure` and `rejectedClosure` functions,
                 // which resume execution of the function.
-                let handler = PromiseReactionHandler::Await(self);
+                let handler = PromiseReactionHandler::Await(agent.heap.create(AwaitReaction {
+                    vm: Some(vm),
+                    async_function: Some(scoped_async_function.get(agent)),
+                    execution_context: Some(execution_context),
+                    return_promise_capability,
+                }));
                 // 2. Let promise be ? PromiseResolve(%Promise%, value).
                 let promise = Promise::resolve(agent, awaited_value, gc.reborrow())
                     .unbind()
@@ -139,6 +149,10 @@ impl Index<AwaitReactionIdentifier> for Agent {
 
 impl IndexMut<AwaitReactionIdentifier> for Agent {
     fn index_mut(&mut self, index: AwaitReactionIdentifier) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .await_reactions
+            .remember(index.into_index());
         &mut self.heap.await_reactions[index]
     }
 }
@@ -187,6 +201,7 @@ pub(crate) struct AwaitReaction {
 impl CreateHeapData<AwaitReaction, AwaitReactionIdentifier> for Heap {
     fn create(&mut self, data: AwaitReaction) -> AwaitReactionIdentifier {
         self.await_reactions.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<AwaitReaction>>();
         AwaitReactionIdentifier::last(&self.await_reactions)
     }
 }
@@ -389,6 +389,7 @@ impl<'a> InternalMethods<'a> for AsyncGenerator<'a> {}
 impl CreateHeapData<AsyncGeneratorHeapData, AsyncGenerator<'static>> for Heap {
     fn create(&mut self, data: AsyncGeneratorHeapData) -> AsyncGenerator<'static> {
         self.async_generators.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<AsyncGeneratorHeapData>>();
         AsyncGenerator(AsyncGeneratorIndex::last(&self.async_generators))
     }
 }
@@ -403,6 +404,10 @@ impl Index<AsyncGenerator<'_>> for Agent {
 
 impl IndexMut<AsyncGenerator<'_>> for Agent {
     fn index_mut(&mut self, index: AsyncGenerator) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .async_generators
+            .remember(index.get_index());
         &mut self.heap.async_generators[index]
     }
 }
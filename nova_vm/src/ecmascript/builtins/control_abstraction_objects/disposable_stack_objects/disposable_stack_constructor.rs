@@ -0,0 +1,73 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::engine::context::GcScope;
+use crate::{
+    ecmascript::{
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            ordinary::ordinary_create_from_constructor, ArgumentsList, Behaviour, Builtin,
+            BuiltinIntrinsicConstructor,
+        },
+        execution::{agent::ExceptionType, Agent, JsResult, ProtoIntrinsics, RealmIdentifier},
+        types::{Function, IntoObject, Object, String, Value, BUILTIN_STRING_MEMORY},
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct DisposableStackConstructor;
+impl Builtin for DisposableStackConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.DisposableStack;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for DisposableStackConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::DisposableStack;
+}
+
+impl DisposableStackConstructor {
+    /// ### [12.3.1.1 DisposableStack ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack)
+    fn constructor(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        new_target: Option<Object>,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Cannot call DisposableStack as a function",
+                gc.nogc(),
+            ));
+        };
+        // 2. Let disposableStack be ? OrdinaryCreateFromConstructor(NewTarget, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+        // 3. Set disposableStack.[[DisposableState]] to pending.
+        // 4. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+        let new_target = Function::try_from(new_target).unwrap();
+        let disposable_stack = ordinary_create_from_constructor(
+            agent,
+            new_target,
+            ProtoIntrinsics::DisposableStack,
+            gc,
+        )?;
+        // 5. Return disposableStack.
+        Ok(disposable_stack.into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: RealmIdentifier) {
+        let intrinsics = agent.get_realm(realm).intrinsics();
+        let disposable_stack_prototype = intrinsics.disposable_stack_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<DisposableStackConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(disposable_stack_prototype.into_object())
+        .build();
+    }
+}
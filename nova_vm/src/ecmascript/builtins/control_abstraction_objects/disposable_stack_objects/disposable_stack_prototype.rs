@@ -0,0 +1,387 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::engine::context::{GcScope, NoGcScope};
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_disposable_objects::{add_disposable_resource, dispose_resources},
+            testing_and_comparison::is_callable,
+        },
+        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        builtins::{
+            disposable_stack::{
+                data::{DisposableStackHeapData, DisposableState, DisposeHint, DisposeMethod},
+                DisposableStack,
+            },
+            ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsic,
+        },
+        execution::{agent::ExceptionType, Agent, JsResult, RealmIdentifier},
+        types::{IntoValue, PropertyKey, String, Value, BUILTIN_STRING_MEMORY},
+    },
+    heap::{CreateHeapData, IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
+};
+
+pub(crate) struct DisposableStackPrototype;
+
+struct DisposableStackPrototypeAdopt;
+impl Builtin for DisposableStackPrototypeAdopt {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.adopt;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::adopt);
+}
+struct DisposableStackPrototypeDefer;
+impl Builtin for DisposableStackPrototypeDefer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.defer;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::defer);
+}
+struct DisposableStackPrototypeDispose;
+impl Builtin for DisposableStackPrototypeDispose {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.dispose;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::dispose);
+}
+impl BuiltinIntrinsic for DisposableStackPrototypeDispose {
+    const INDEX: IntrinsicFunctionIndexes =
+        IntrinsicFunctionIndexes::DisposableStackPrototypeDispose;
+}
+struct DisposableStackPrototypeGetDisposed;
+impl Builtin for DisposableStackPrototypeGetDisposed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_disposed;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.disposed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::get_disposed);
+}
+impl BuiltinGetter for DisposableStackPrototypeGetDisposed {}
+struct DisposableStackPrototypeMove;
+impl Builtin for DisposableStackPrototypeMove {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#move;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::r#move);
+}
+struct DisposableStackPrototypeUse;
+impl Builtin for DisposableStackPrototypeUse {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#use;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::r#use);
+}
+
+impl DisposableStackPrototype {
+    /// ### [12.3.3.1 DisposableStack.prototype.adopt ( value, onDispose )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt)
+    fn adopt(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        stack_adopt(agent, this_value, arguments, false, gc)
+    }
+
+    /// ### [12.3.3.2 DisposableStack.prototype.defer ( onDispose )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer)
+    fn defer(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        stack_defer(agent, this_value, arguments, false, gc)
+    }
+
+    /// ### [12.3.3.3 DisposableStack.prototype.dispose ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose)
+    fn dispose(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let disposable_stack =
+            require_disposable_stack_internal_slot(agent, this_value, false, gc.nogc())?;
+        // 3. If disposableStack.[[DisposableState]] is disposed, return undefined.
+        if agent[disposable_stack].state == DisposableState::Disposed {
+            return Ok(Value::Undefined);
+        }
+        // 4. Set disposableStack.[[DisposableState]] to disposed.
+        agent[disposable_stack].state = DisposableState::Disposed;
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]], NormalCompletion(undefined)).
+        dispose_resources(agent, disposable_stack.unbind(), Ok(()), gc)?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [12.3.3.4 get DisposableStack.prototype.disposed](https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed)
+    fn get_disposed(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        stack_get_disposed(agent, this_value, false, gc.into_nogc())
+    }
+
+    /// ### [12.3.3.5 DisposableStack.prototype.move ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move)
+    fn r#move(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        stack_move(agent, this_value, false, gc.into_nogc())
+    }
+
+    /// ### [12.3.3.6 DisposableStack.prototype.use ( value )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use)
+    fn r#use(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        stack_use(agent, this_value, arguments, false, gc)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: RealmIdentifier) {
+        let intrinsics = agent.get_realm(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.disposable_stack_prototype();
+        let disposable_stack_constructor = intrinsics.disposable_stack();
+        let disposable_stack_prototype_dispose = intrinsics.disposable_stack_prototype_dispose();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(9)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<DisposableStackPrototypeAdopt>()
+            .with_constructor_property(disposable_stack_constructor)
+            .with_builtin_function_property::<DisposableStackPrototypeDefer>()
+            .with_builtin_intrinsic_function_property::<DisposableStackPrototypeDispose>()
+            .with_builtin_function_getter_property::<DisposableStackPrototypeGetDisposed>()
+            .with_builtin_function_property::<DisposableStackPrototypeMove>()
+            .with_builtin_function_property::<DisposableStackPrototypeUse>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::Dispose.into())
+                    .with_value(disposable_stack_prototype_dispose.into_value())
+                    .with_enumerable(DisposableStackPrototypeDispose::ENUMERABLE)
+                    .with_configurable(DisposableStackPrototypeDispose::CONFIGURABLE)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbolIndexes::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.DisposableStack.into_value())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// Perform ? RequireInternalSlot(O, \[\[DisposableState]]) or
+/// ? RequireInternalSlot(O, \[\[AsyncDisposableState]]).
+#[inline(always)]
+pub(crate) fn require_disposable_stack_internal_slot<'a>(
+    agent: &mut Agent,
+    value: Value,
+    is_async: bool,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<DisposableStack<'a>> {
+    match value {
+        Value::DisposableStack(disposable_stack)
+            if agent[disposable_stack].is_async == is_async =>
+        {
+            Ok(disposable_stack.bind(gc))
+        }
+        _ if is_async => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not an AsyncDisposableStack",
+            gc,
+        )),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a DisposableStack",
+            gc,
+        )),
+    }
+}
+
+/// Throw a ReferenceError if the DisposableStack has already been disposed.
+fn require_pending(
+    agent: &mut Agent,
+    disposable_stack: DisposableStack,
+    gc: NoGcScope,
+) -> JsResult<()> {
+    if agent[disposable_stack].state == DisposableState::Disposed {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::ReferenceError,
+            "DisposableStack has already been disposed",
+            gc,
+        ));
+    }
+    Ok(())
+}
+
+const fn hint(is_async: bool) -> DisposeHint {
+    if is_async {
+        DisposeHint::AsyncDispose
+    } else {
+        DisposeHint::SyncDispose
+    }
+}
+
+/// Shared steps of `DisposableStack.prototype.adopt` and
+/// `AsyncDisposableStack.prototype.adopt`.
+pub(crate) fn stack_adopt(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    is_async: bool,
+    gc: GcScope,
+) -> JsResult<Value> {
+    let value = arguments.get(0);
+    let on_dispose = arguments.get(1);
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack =
+        require_disposable_stack_internal_slot(agent, this_value, is_async, gc.nogc())?;
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+    require_pending(agent, disposable_stack, gc.nogc())?;
+    // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+    let Some(on_dispose) = is_callable(on_dispose, gc.nogc()) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "onDispose is not callable",
+            gc.nogc(),
+        ));
+    };
+    // 5. Let closure be a new Abstract Closure with no parameters that
+    //    captures value and onDispose and performs the following steps when
+    //    called:
+    //    a. Return ? Call(onDispose, undefined, « value »).
+    // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+    let scoped_value = value.scope(agent, gc.nogc());
+    // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, F).
+    add_disposable_resource(
+        agent,
+        disposable_stack.unbind(),
+        value,
+        hint(is_async),
+        Some(DisposeMethod::Adopt(on_dispose.unbind())),
+        gc,
+    )?;
+    // 8. Return value.
+    Ok(scoped_value.get(agent))
+}
+
+/// Shared steps of `DisposableStack.prototype.defer` and
+/// `AsyncDisposableStack.prototype.defer`.
+pub(crate) fn stack_defer(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    is_async: bool,
+    gc: GcScope,
+) -> JsResult<Value> {
+    let on_dispose = arguments.get(0);
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack =
+        require_disposable_stack_internal_slot(agent, this_value, is_async, gc.nogc())?;
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+    require_pending(agent, disposable_stack, gc.nogc())?;
+    // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+    let Some(on_dispose) = is_callable(on_dispose, gc.nogc()) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "onDispose is not callable",
+            gc.nogc(),
+        ));
+    };
+    // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], undefined, sync-dispose, onDispose).
+    add_disposable_resource(
+        agent,
+        disposable_stack.unbind(),
+        Value::Undefined,
+        hint(is_async),
+        Some(DisposeMethod::Call(on_dispose.unbind())),
+        gc,
+    )?;
+    // 6. Return undefined.
+    Ok(Value::Undefined)
+}
+
+/// Shared steps of `get DisposableStack.prototype.disposed` and
+/// `get AsyncDisposableStack.prototype.disposed`.
+pub(crate) fn stack_get_disposed(
+    agent: &mut Agent,
+    this_value: Value,
+    is_async: bool,
+    gc: NoGcScope,
+) -> JsResult<Value> {
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack = require_disposable_stack_internal_slot(agent, this_value, is_async, gc)?;
+    // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+    // 4. Otherwise, return false.
+    Ok((agent[disposable_stack].state == DisposableState::Disposed).into())
+}
+
+/// Shared steps of `DisposableStack.prototype.move` and
+/// `AsyncDisposableStack.prototype.move`.
+pub(crate) fn stack_move(
+    agent: &mut Agent,
+    this_value: Value,
+    is_async: bool,
+    gc: NoGcScope,
+) -> JsResult<Value> {
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack = require_disposable_stack_internal_slot(agent, this_value, is_async, gc)?;
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+    require_pending(agent, disposable_stack, gc)?;
+    // 4. Let newDisposableStack be ? OrdinaryCreateFromConstructor(%DisposableStack%, "%DisposableStack.prototype%", « [[DisposableState]], [[DisposeCapability]] »).
+    // NOTE: The prototype of the intrinsic constructor is always the
+    // intrinsic prototype, so the object is created directly.
+    // 5. Set newDisposableStack.[[DisposableState]] to pending.
+    let mut data = DisposableStackHeapData::new(is_async);
+    // 6. Set newDisposableStack.[[DisposeCapability]] to disposableStack.[[DisposeCapability]].
+    // 7. Set disposableStack.[[DisposeCapability]] to NewDisposeCapability().
+    core::mem::swap(&mut data.resources, &mut agent[disposable_stack].resources);
+    // 8. Set disposableStack.[[DisposableState]] to disposed.
+    agent[disposable_stack].state = DisposableState::Disposed;
+    let new_disposable_stack = agent.heap.create(data).bind(gc);
+    // 9. Return newDisposableStack.
+    Ok(new_disposable_stack.into_value())
+}
+
+/// Shared steps of `DisposableStack.prototype.use` and
+/// `AsyncDisposableStack.prototype.use`.
+pub(crate) fn stack_use(
+    agent: &mut Agent,
+    this_value: Value,
+    arguments: ArgumentsList,
+    is_async: bool,
+    gc: GcScope,
+) -> JsResult<Value> {
+    let value = arguments.get(0);
+    // 1. Let disposableStack be the this value.
+    // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+    let disposable_stack =
+        require_disposable_stack_internal_slot(agent, this_value, is_async, gc.nogc())?;
+    // 3. If disposableStack.[[DisposableState]] is disposed, throw a ReferenceError exception.
+    require_pending(agent, disposable_stack, gc.nogc())?;
+    let scoped_value = value.scope(agent, gc.nogc());
+    // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]], value, sync-dispose).
+    add_disposable_resource(
+        agent,
+        disposable_stack.unbind(),
+        value,
+        hint(is_async),
+        None,
+        gc,
+    )?;
+    // 5. Return value.
+    Ok(scoped_value.get(agent))
+}
@@ -13,7 +13,8 @@ use crate::{
             Agent, ExecutionContext, JsResult, ProtoIntrinsics,
         },
         types::{
-            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+            Function, InternalMethods, InternalSlots, IntoObject, IntoValue, Object,
+            OrdinaryObject, Value,
         },
     },
     engine::{
@@ -26,6 +27,16 @@ use crate::{
     },
 };
 
+/// Returns the Executable of the generator function evaluated in the
+/// execution context. The Executable may have been moved by garbage collection
+/// while the generator was running.
+fn generator_executable(agent: &Agent, execution_context: &ExecutionContext) -> Executable {
+    let Some(Function::ECMAScriptFunction(function)) = execution_context.function else {
+        unreachable!()
+    };
+    function.get_executable(agent)
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Generator<'a>(pub(crate) GeneratorIndex<'a>);
 
@@ -135,6 +146,7 @@ impl Generator<'_> {
         // execution context.
         // GeneratorYield 6 is the same.
         let execution_context = agent.execution_context_stack.pop().unwrap();
+        let executable = generator_executable(agent, &execution_context);
 
         // 10. Assert: When we return here, genContext has already been removed
         // from the execution context stack and methodContext is the currently
@@ -258,16 +270,21 @@ impl Generator<'_> {
         // execution context.
         agent.execution_context_stack.push(execution_context);
 
+        let saved = self.scope(agent, gc.nogc());
+
         // 10. Resume the suspended evaluation of genContext using NormalCompletion(value) as the
         // result of the operation that suspended it. Let result be the value returned by the
         // resumed computation.
         let execution_result = vm.resume_throw(agent, executable, value, gc.reborrow());
 
+        let generator = saved.get(agent).bind(gc.nogc());
+
         // GeneratorStart: 4.f. Remove acGenContext from the execution context stack and restore the
         // execution context that is at the top of the execution context stack as the running
         // execution context.
         // GeneratorYield 6 is the same.
         let execution_context = agent.execution_context_stack.pop().unwrap();
+        let executable = generator_executable(agent, &execution_context);
 
         // 11. Assert: When we return here, genContext has already been removed
         // from the execution context stack and methodContext is the currently
@@ -275,7 +292,7 @@ impl Generator<'_> {
         // 12. Return ? result.
         match execution_result {
             ExecutionResult::Return(result) => {
-                agent[self].generator_state = Some(GeneratorState::Completed);
+                agent[generator].generator_state = Some(GeneratorState::Completed);
                 Ok(create_iter_result_object(
                     agent,
                     result,
@@ -284,11 +301,11 @@ impl Generator<'_> {
                 ))
             }
             ExecutionResult::Throw(err) => {
-                agent[self].generator_state = Some(GeneratorState::Completed);
+                agent[generator].generator_state = Some(GeneratorState::Completed);
                 Err(err)
             }
             ExecutionResult::Yield { vm, yielded_value } => {
-                agent[self].generator_state =
+                agent[generator].generator_state =
                     Some(GeneratorState::Suspended(SuspendedGeneratorState {
                         vm_or_args: VmOrArguments::Vm(vm),
                         executable,
@@ -362,6 +379,7 @@ impl<'a> InternalMethods<'a> for Generator<'a> {}
 impl CreateHeapData<GeneratorHeapData, Generator<'static>> for Heap {
     fn create(&mut self, data: GeneratorHeapData) -> Generator<'static> {
         self.generators.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<GeneratorHeapData>>();
         Generator(GeneratorIndex::last(&self.generators))
     }
 }
@@ -376,6 +394,10 @@ impl Index<Generator<'_>> for Agent {
 
 impl IndexMut<Generator<'_>> for Agent {
     fn index_mut(&mut self, index: Generator) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .generators
+            .remember(index.get_index());
         &mut self.heap.generators[index]
     }
 }
@@ -473,7 +495,7 @@ impl HeapMarkAndSweep for SuspendedGeneratorState {
         } = self;
         match vm_or_args {
             VmOrArguments::Vm(vm) => vm.sweep_values(compactions),
-            VmOrArguments::Arguments(args) => args.as_ref().sweep_values(compactions),
+            VmOrArguments::Arguments(args) => args.sweep_values(compactions),
         }
         executable.sweep_values(compactions);
         execution_context.sweep_values(compactions);
@@ -65,6 +65,10 @@ impl PromiseCapability {
         self.promise
     }
 
+    pub(crate) fn must_be_unresolved(&self) -> bool {
+        self.must_be_unresolved
+    }
+
     fn is_already_resolved(self, agent: &Agent) -> bool {
         // If `self.must_be_unresolved` is true, then `alreadyResolved`
         // corresponds with the `is_resolved` flag in PromiseState::Pending.
@@ -4,6 +4,8 @@
 
 //! ## [27.2.2 Promise Jobs](https://tc39.es/ecma262/#sec-promise-jobs)
 
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::abstract_operations::operations_on_disposable_objects::resume_async_disposal;
 use crate::engine::context::GcScope;
 use crate::engine::Global;
 use crate::{
@@ -21,7 +23,9 @@ use crate::{
 
 use super::{
     promise_capability_records::PromiseCapability,
-    promise_reaction_records::{PromiseReaction, PromiseReactionHandler, PromiseReactionType},
+    promise_reaction_records::{
+        PromiseReaction, PromiseReactionHandler, PromiseReactionRecord, PromiseReactionType,
+    },
     promise_resolving_functions::{PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType},
 };
 
@@ -119,9 +123,22 @@ impl PromiseReactionJob {
         let Self { reaction, argument } = self;
         let reaction = reaction.take(agent);
         let argument = argument.take(agent).bind(gc.nogc());
+        // Note: Nothing refers to the reaction while its handler runs, so the
+        // promise of its capability is rooted instead.
+        let PromiseReactionRecord {
+            reaction_type,
+            capability,
+            handler,
+        } = agent[reaction];
+        let scoped_capability = capability.map(|capability| {
+            (
+                capability.promise().scope(agent, gc.nogc()),
+                capability.must_be_unresolved(),
+            )
+        });
         // The following are substeps of point 1 in NewPromiseReactionJob.
-        let handler_result = match agent[reaction].handler {
-            PromiseReactionHandler::Empty => match agent[reaction].reaction_type {
+        let handler_result = match handler {
+            PromiseReactionHandler::Empty => match reaction_type {
                 PromiseReactionType::Fulfill => {
                     // d.i.1. Let handlerResult be NormalCompletion(argument).
                     Ok(argument)
@@ -143,23 +160,40 @@ impl PromiseReactionJob {
                 gc.reborrow(),
             ),
             PromiseReactionHandler::Await(await_reaction) => {
-                assert!(agent[reaction].capability.is_none());
-                let reaction_type = agent[reaction].reaction_type;
+                assert!(capability.is_none());
                 await_reaction.resume(agent, reaction_type, argument, gc.reborrow());
                 // [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
                 // 5. f. Return undefined.
                 Ok(Value::Undefined)
             }
             PromiseReactionHandler::AsyncGenerator(async_generator) => {
-                assert!(agent[reaction].capability.is_none());
-                let reaction_type = agent[reaction].reaction_type;
+                assert!(capability.is_none());
                 async_generator.resume_await(agent, reaction_type, argument, gc.reborrow());
                 Ok(Value::Undefined)
             }
+            PromiseReactionHandler::ArrayFromAsync(array_from_async) => {
+                assert!(capability.is_none());
+                array_from_async.resume(agent, reaction_type, argument, gc.reborrow());
+                Ok(Value::Undefined)
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            PromiseReactionHandler::DisposableStack(disposable_stack) => {
+                assert!(capability.is_none());
+                resume_async_disposal(
+                    agent,
+                    disposable_stack,
+                    reaction_type,
+                    argument,
+                    gc.reborrow(),
+                );
+                Ok(Value::Undefined)
+            }
         };
 
         // f. If promiseCapability is undefined, then
-        let Some(promise_capability) = agent[reaction].capability else {
+        let Some(promise_capability) = scoped_capability.map(|(promise, must_be_unresolved)| {
+            PromiseCapability::from_promise(promise.get(agent), must_be_unresolved)
+        }) else {
             // i. Assert: handlerResult is not an abrupt completion.
             handler_result.unwrap();
             // ii. Return empty.
@@ -209,6 +243,16 @@ pub(crate) fn new_promise_reaction_job(
                 .unwrap()
                 .realm,
         ),
+        // Like await continuations, the fromAsyncClosure of Array.fromAsync
+        // is resumed in the realm that it was created in.
+        PromiseReactionHandler::ArrayFromAsync(array_from_async) => {
+            Some(agent[array_from_async].realm)
+        }
+        // The same holds for an asynchronous DisposeResources.
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        PromiseReactionHandler::DisposableStack(disposable_stack) => {
+            Some(agent[disposable_stack].disposal.as_ref().unwrap().realm)
+        }
         // 2. Let handlerRealm be null.
         PromiseReactionHandler::AsyncGenerator(_) | PromiseReactionHandler::Empty => None,
     };
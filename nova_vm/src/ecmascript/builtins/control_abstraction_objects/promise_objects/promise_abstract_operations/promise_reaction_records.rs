@@ -9,6 +9,7 @@ use crate::{
         builtins::{
             async_generator_objects::AsyncGenerator,
             control_abstraction_objects::async_function_objects::await_reaction::AwaitReactionIdentifier,
+            indexed_collections::array_objects::array_from_async_reaction::ArrayFromAsyncReactionIdentifier,
         },
         execution::Agent,
         types::Function,
@@ -18,6 +19,8 @@ use crate::{
 };
 
 use super::promise_capability_records::PromiseCapability;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 
 /// \[\[Type\]\]
 ///
@@ -44,6 +47,9 @@ pub(crate) enum PromiseReactionHandler {
     JobCallback(Function<'static>),
     Await(AwaitReactionIdentifier),
     AsyncGenerator(AsyncGenerator<'static>),
+    ArrayFromAsync(ArrayFromAsyncReactionIdentifier),
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack(DisposableStack<'static>),
     Empty,
 }
 
@@ -89,6 +95,10 @@ impl Index<PromiseReaction> for Agent {
 
 impl IndexMut<PromiseReaction> for Agent {
     fn index_mut(&mut self, index: PromiseReaction) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .promise_reaction_records
+            .remember(index.get_index());
         &mut self.heap.promise_reaction_records[index]
     }
 }
@@ -152,15 +162,37 @@ impl Rootable for PromiseReaction {
 impl HeapMarkAndSweep for PromiseReactionRecord {
     fn mark_values(&self, queues: &mut crate::heap::WorkQueues) {
         self.capability.mark_values(queues);
-        if let PromiseReactionHandler::JobCallback(_) = self.handler {
-            todo!();
-        }
+        self.handler.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &crate::heap::CompactionLists) {
         self.capability.sweep_values(compactions);
-        if let PromiseReactionHandler::JobCallback(_) = self.handler {
-            todo!();
+        self.handler.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for PromiseReactionHandler {
+    fn mark_values(&self, queues: &mut crate::heap::WorkQueues) {
+        match self {
+            Self::JobCallback(function) => function.mark_values(queues),
+            Self::Await(await_reaction) => await_reaction.mark_values(queues),
+            Self::AsyncGenerator(async_generator) => async_generator.mark_values(queues),
+            Self::ArrayFromAsync(reaction) => reaction.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Self::DisposableStack(disposable_stack) => disposable_stack.mark_values(queues),
+            Self::Empty => {}
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &crate::heap::CompactionLists) {
+        match self {
+            Self::JobCallback(function) => function.sweep_values(compactions),
+            Self::Await(await_reaction) => await_reaction.sweep_values(compactions),
+            Self::AsyncGenerator(async_generator) => async_generator.sweep_values(compactions),
+            Self::ArrayFromAsync(reaction) => reaction.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Self::DisposableStack(disposable_stack) => disposable_stack.sweep_values(compactions),
+            Self::Empty => {}
         }
     }
 }
@@ -168,6 +200,7 @@ impl HeapMarkAndSweep for PromiseReactionRecord {
 impl CreateHeapData<PromiseReactionRecord, PromiseReaction> for Heap {
     fn create(&mut self, data: PromiseReactionRecord) -> PromiseReaction {
         self.promise_reaction_records.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<PromiseReactionRecord>>();
         PromiseReaction(BaseIndex::last(&self.promise_reaction_records))
     }
 }
@@ -294,6 +294,10 @@ impl Index<BuiltinPromiseResolvingFunction<'_>> for Agent {
 
 impl IndexMut<BuiltinPromiseResolvingFunction<'_>> for Agent {
     fn index_mut(&mut self, index: BuiltinPromiseResolvingFunction) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .promise_resolving_functions
+            .remember(index.get_index());
         &mut self.heap.promise_resolving_functions[index]
     }
 }
@@ -353,6 +357,7 @@ impl CreateHeapData<PromiseResolvingFunctionHeapData, BuiltinPromiseResolvingFun
         data: PromiseResolvingFunctionHeapData,
     ) -> BuiltinPromiseResolvingFunction<'static> {
         self.promise_resolving_functions.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<PromiseResolvingFunctionHeapData>>();
         BuiltinPromiseResolvingFunction(BaseIndex::last(&self.promise_resolving_functions))
     }
 }
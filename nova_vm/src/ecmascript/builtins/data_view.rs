@@ -158,6 +158,10 @@ impl Index<DataView<'_>> for Agent {
 
 impl IndexMut<DataView<'_>> for Agent {
     fn index_mut(&mut self, index: DataView) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .data_views
+            .remember(index.get_index());
         &mut self.heap.data_views[index]
     }
 }
@@ -226,6 +230,7 @@ impl Rootable for DataView<'_> {
 impl CreateHeapData<DataViewHeapData, DataView<'static>> for Heap {
     fn create(&mut self, data: DataViewHeapData) -> DataView<'static> {
         self.data_views.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<DataViewHeapData>>();
         DataView::from(DataViewIndex::last(&self.data_views))
     }
 }
@@ -141,6 +141,7 @@ impl Index<Date<'_>> for Agent {
 
 impl IndexMut<Date<'_>> for Agent {
     fn index_mut(&mut self, index: Date) -> &mut Self::Output {
+        self.heap.remembered_sets.dates.remember(index.get_index());
         &mut self.heap.dates[index]
     }
 }
@@ -201,6 +202,7 @@ impl HeapMarkAndSweep for Date<'static> {
 impl CreateHeapData<DateHeapData, Date<'static>> for Heap {
     fn create(&mut self, data: DateHeapData) -> Date<'static> {
         self.dates.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<DateHeapData>>();
         Date(DateIndex::last(&self.dates))
     }
 }
@@ -0,0 +1,236 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+pub(crate) mod data;
+
+use core::ops::{Index, IndexMut};
+
+use crate::{
+    ecmascript::{
+        execution::{Agent, ProtoIntrinsics},
+        types::{
+            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, OrdinaryObject, Value,
+        },
+    },
+    engine::{
+        context::NoGcScope,
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+        Scoped,
+    },
+    heap::{
+        indexes::DisposableStackIndex, CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep,
+        WorkQueues,
+    },
+};
+
+use self::data::DisposableStackHeapData;
+
+/// ### [12 DisposableStack Objects](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects)
+///
+/// Both DisposableStack and AsyncDisposableStack objects are represented by
+/// this type: the `is_async` flag of the heap data tells them apart. The
+/// DisposeCapability of `using` and `await using` declarations is also kept
+/// in an object of this type that is never exposed to ECMAScript code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct DisposableStack<'a>(pub(crate) DisposableStackIndex<'a>);
+
+impl DisposableStack<'_> {
+    /// Unbind this DisposableStack from its current lifetime. This is necessary to use
+    /// the DisposableStack as a parameter in a call that can perform garbage
+    /// collection.
+    pub fn unbind(self) -> DisposableStack<'static> {
+        unsafe { core::mem::transmute::<Self, DisposableStack<'static>>(self) }
+    }
+
+    // Bind this DisposableStack to the garbage collection lifetime. This enables Rust's
+    // borrow checker to verify that your DisposableStacks cannot not be
+    // invalidated by garbage collection being performed.
+    //
+    // This function is best called with the form
+    // ```rs
+    // let disposable_stack = disposable_stack.bind(&gc);
+    // ```
+    // to make sure that the unbound DisposableStack cannot be used after binding.
+    pub const fn bind<'gc>(self, _: NoGcScope<'gc, '_>) -> DisposableStack<'gc> {
+        unsafe { core::mem::transmute::<DisposableStack, DisposableStack<'gc>>(self) }
+    }
+
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, DisposableStack<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
+    pub(crate) const fn _def() -> Self {
+        Self(DisposableStackIndex::from_u32_index(0))
+    }
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+}
+
+impl IntoValue for DisposableStack<'_> {
+    fn into_value(self) -> Value {
+        self.into()
+    }
+}
+
+impl From<DisposableStack<'_>> for Value {
+    fn from(value: DisposableStack) -> Self {
+        Value::DisposableStack(value.unbind())
+    }
+}
+
+impl<'a> IntoObject<'a> for DisposableStack<'a> {
+    fn into_object(self) -> Object<'a> {
+        self.into()
+    }
+}
+
+impl<'a> From<DisposableStack<'a>> for Object<'a> {
+    fn from(value: DisposableStack) -> Self {
+        Object::DisposableStack(value.unbind())
+    }
+}
+
+impl TryFrom<Value> for DisposableStack<'_> {
+    type Error = ();
+
+    fn try_from(value: Value) -> Result<Self, ()> {
+        match value {
+            Value::DisposableStack(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> TryFrom<Object<'a>> for DisposableStack<'a> {
+    type Error = ();
+
+    fn try_from(value: Object<'a>) -> Result<Self, ()> {
+        match value {
+            Object::DisposableStack(idx) => Ok(idx),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'a> InternalSlots<'a> for DisposableStack<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::DisposableStack;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        agent[self].object_index
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(agent[self]
+            .object_index
+            .replace(backing_object.unbind())
+            .is_none());
+    }
+
+    fn internal_prototype(self, agent: &Agent) -> Option<Object<'static>> {
+        if let Some(object_index) = self.get_backing_object(agent) {
+            object_index.internal_prototype(agent)
+        } else {
+            let intrinsic = if agent[self].is_async {
+                ProtoIntrinsics::AsyncDisposableStack
+            } else {
+                ProtoIntrinsics::DisposableStack
+            };
+            Some(
+                agent
+                    .current_realm()
+                    .intrinsics()
+                    .get_intrinsic_default_proto(intrinsic),
+            )
+        }
+    }
+}
+
+impl<'a> InternalMethods<'a> for DisposableStack<'a> {}
+
+impl Index<DisposableStack<'_>> for Agent {
+    type Output = DisposableStackHeapData;
+
+    fn index(&self, index: DisposableStack) -> &Self::Output {
+        &self.heap.disposable_stacks[index]
+    }
+}
+
+impl IndexMut<DisposableStack<'_>> for Agent {
+    fn index_mut(&mut self, index: DisposableStack) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .disposable_stacks
+            .remember(index.get_index());
+        &mut self.heap.disposable_stacks[index]
+    }
+}
+
+impl Index<DisposableStack<'_>> for Vec<Option<DisposableStackHeapData>> {
+    type Output = DisposableStackHeapData;
+
+    fn index(&self, index: DisposableStack) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("DisposableStack out of bounds")
+            .as_ref()
+            .expect("DisposableStack slot empty")
+    }
+}
+
+impl IndexMut<DisposableStack<'_>> for Vec<Option<DisposableStackHeapData>> {
+    fn index_mut(&mut self, index: DisposableStack) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("DisposableStack out of bounds")
+            .as_mut()
+            .expect("DisposableStack slot empty")
+    }
+}
+
+impl Rootable for DisposableStack<'_> {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::DisposableStack(value.unbind()))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        match heap_data {
+            HeapRootData::DisposableStack(object) => Some(object),
+            _ => None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for DisposableStack<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.disposable_stacks.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.disposable_stacks.shift_index(&mut self.0);
+    }
+}
+
+impl CreateHeapData<DisposableStackHeapData, DisposableStack<'static>> for Heap {
+    fn create(&mut self, data: DisposableStackHeapData) -> DisposableStack<'static> {
+        self.disposable_stacks.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<DisposableStackHeapData>>();
+        DisposableStack(DisposableStackIndex::last(&self.disposable_stacks))
+    }
+}
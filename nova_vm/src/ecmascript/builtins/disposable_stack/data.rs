@@ -0,0 +1,198 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        builtins::control_abstraction_objects::promise_objects::promise_abstract_operations::promise_capability_records::PromiseCapability,
+        execution::RealmIdentifier,
+        types::{Function, OrdinaryObject, Value},
+    },
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// \[\[DisposableState]]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposableState {
+    Pending,
+    Disposed,
+}
+
+/// \[\[Hint]]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    SyncDispose,
+    AsyncDispose,
+}
+
+/// \[\[DisposeMethod]]
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum DisposeMethod {
+    /// The resource of an `await using` declaration was null or undefined.
+    /// Nothing is called, but disposal will still perform an Await.
+    None,
+    /// Call the method with the resource value as the this value.
+    Call(Function<'static>),
+    /// Call the function with undefined as the this value and the resource
+    /// value as the only argument. This is the closure created by
+    /// `DisposableStack.prototype.adopt` and
+    /// `AsyncDisposableStack.prototype.adopt`.
+    Adopt(Function<'static>),
+    /// Call the `@@dispose` method with the resource value as the this value
+    /// and produce a promise from the result. This is the closure created by
+    /// GetDisposeMethod when an async-dispose resource has no
+    /// `@@asyncDispose` method.
+    SyncAsAsync(Function<'static>),
+}
+
+/// ### [3.1 DisposableResource Records](https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records)
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposableResource {
+    /// \[\[ResourceValue]]
+    pub(crate) value: Value,
+    /// \[\[Hint]]
+    pub(crate) hint: DisposeHint,
+    /// \[\[DisposeMethod]]
+    pub(crate) method: DisposeMethod,
+}
+
+/// State of an asynchronous DisposeResources that is waiting for a promise
+/// to settle.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncDisposal {
+    /// The realm that DisposeResources was started in.
+    pub(crate) realm: RealmIdentifier,
+    /// The thrown value of the completion, or None if the completion is a
+    /// normal completion.
+    pub(crate) completion: Option<Value>,
+    pub(crate) needs_await: bool,
+    pub(crate) has_awaited: bool,
+    /// The promise that settles once all resources have been disposed.
+    pub(crate) promise_capability: PromiseCapability,
+}
+
+#[derive(Debug, Clone)]
+pub struct DisposableStackHeapData {
+    pub(crate) object_index: Option<OrdinaryObject<'static>>,
+    /// True for AsyncDisposableStack objects and `await using` declarations.
+    pub(crate) is_async: bool,
+    /// \[\[DisposableState]]
+    pub(crate) state: DisposableState,
+    /// \[\[DisposeCapability]].\[\[DisposableResourceStack]]
+    pub(crate) resources: Vec<DisposableResource>,
+    pub(crate) disposal: Option<AsyncDisposal>,
+}
+
+impl DisposableStackHeapData {
+    pub(crate) fn new(is_async: bool) -> Self {
+        Self {
+            object_index: None,
+            is_async,
+            state: DisposableState::Pending,
+            resources: Vec::new(),
+            disposal: None,
+        }
+    }
+}
+
+impl HeapMarkAndSweep for DisposeMethod {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            DisposeMethod::None => {}
+            DisposeMethod::Call(function)
+            | DisposeMethod::Adopt(function)
+            | DisposeMethod::SyncAsAsync(function) => function.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            DisposeMethod::None => {}
+            DisposeMethod::Call(function)
+            | DisposeMethod::Adopt(function)
+            | DisposeMethod::SyncAsAsync(function) => function.sweep_values(compactions),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for DisposableResource {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            value,
+            hint: _,
+            method,
+        } = self;
+        value.mark_values(queues);
+        method.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            value,
+            hint: _,
+            method,
+        } = self;
+        value.sweep_values(compactions);
+        method.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for AsyncDisposal {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            realm,
+            completion,
+            needs_await: _,
+            has_awaited: _,
+            promise_capability,
+        } = self;
+        realm.mark_values(queues);
+        completion.mark_values(queues);
+        promise_capability.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            realm,
+            completion,
+            needs_await: _,
+            has_awaited: _,
+            promise_capability,
+        } = self;
+        realm.sweep_values(compactions);
+        completion.sweep_values(compactions);
+        promise_capability.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for DisposableStackHeapData {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            is_async: _,
+            state: _,
+            resources,
+            disposal,
+        } = self;
+        object_index.mark_values(queues);
+        for resource in resources.iter() {
+            resource.mark_values(queues);
+        }
+        disposal.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            is_async: _,
+            state: _,
+            resources,
+            disposal,
+        } = self;
+        object_index.sweep_values(compactions);
+        for resource in resources.iter_mut() {
+            resource.sweep_values(compactions);
+        }
+        disposal.sweep_values(compactions);
+    }
+}
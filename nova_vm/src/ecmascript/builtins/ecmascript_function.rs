@@ -19,9 +19,9 @@ use crate::{
                 get_active_script_or_module,
                 ExceptionType::{self, SyntaxError},
             },
-            new_function_environment, Agent, ECMAScriptCodeEvaluationState, EnvironmentIndex,
-            ExecutionContext, FunctionEnvironmentIndex, JsResult, PrivateEnvironmentIndex,
-            ProtoIntrinsics, RealmIdentifier, ThisBindingStatus,
+            get_this_environment, new_function_environment, Agent, ECMAScriptCodeEvaluationState,
+            EnvironmentIndex, ExecutionContext, FunctionEnvironmentIndex, JsResult,
+            PrivateEnvironmentIndex, ProtoIntrinsics, RealmIdentifier, ThisBindingStatus,
         },
         scripts_and_modules::{source_code::SourceCode, ScriptOrModule},
         syntax_directed_operations::function_definitions::{
@@ -263,6 +263,10 @@ impl Index<ECMAScriptFunction<'_>> for Agent {
 
 impl IndexMut<ECMAScriptFunction<'_>> for Agent {
     fn index_mut(&mut self, index: ECMAScriptFunction) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .ecmascript_functions
+            .remember(index.get_index());
         &mut self.heap.ecmascript_functions[index]
     }
 }
@@ -626,6 +630,11 @@ impl<'a> InternalMethods<'a> for ECMAScriptFunction<'a> {
         // 8. Let result be Completion(OrdinaryCallEvaluateBody(F, argumentsList)).
         let result =
             ordinary_call_evaluate_body(agent, self_fn.unbind(), arguments_list, gc.reborrow());
+        // The constructorEnv may have been moved by garbage collection during
+        // the evaluation: Find it again through calleeContext.
+        let EnvironmentIndex::Function(constructor_env) = get_this_environment(agent) else {
+            unreachable!();
+        };
         // 9. Remove calleeContext from the execution context stack and restore
         //    callerContext as the running execution context.
         agent.execution_context_stack.pop();
@@ -697,6 +706,7 @@ pub(crate) fn prepare_for_ordinary_call<'a>(
             private_environment,
             is_strict_mode,
             source_code,
+            instruction: None,
         }),
         // 3. Set the Function of calleeContext to F.
         function: Some(f.into_function().unbind()),
@@ -804,8 +814,10 @@ pub(crate) fn evaluate_body(
                 .statements
                 .is_empty()
                 && unsafe { heap_data.formal_parameters.as_ref() }.is_simple_parameter_list()
+                && agent.coverage.is_none()
             {
                 // Optimisation: Empty body and only simple parameters means no code will effectively run.
+                // Code coverage counts the call in the Vm.
                 return Ok(Value::Undefined);
             }
             // FunctionBody : FunctionStatementList
@@ -1193,6 +1205,7 @@ impl HeapMarkAndSweep for ECMAScriptFunction<'static> {
 impl CreateHeapData<ECMAScriptFunctionHeapData, ECMAScriptFunction<'static>> for Heap {
     fn create(&mut self, data: ECMAScriptFunctionHeapData) -> ECMAScriptFunction<'static> {
         self.ecmascript_functions.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ECMAScriptFunctionHeapData>>();
         ECMAScriptFunction(ECMAScriptFunctionIndex::last(&self.ecmascript_functions))
     }
 }
@@ -130,6 +130,10 @@ impl Index<EmbedderObject<'_>> for Agent {
 
 impl IndexMut<EmbedderObject<'_>> for Agent {
     fn index_mut(&mut self, index: EmbedderObject) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .embedder_objects
+            .remember(index.get_index());
         &mut self.heap.embedder_objects[index]
     }
 }
@@ -7,16 +7,19 @@ mod data;
 use core::ops::{Index, IndexMut};
 
 pub(crate) use data::ErrorHeapData;
+pub use data::StackFrame;
 
 use crate::engine::context::{GcScope, NoGcScope};
 use crate::engine::rootable::{HeapRootData, HeapRootRef, Rootable};
 use crate::engine::{unwrap_try, Scoped, TryResult};
 use crate::{
     ecmascript::{
-        execution::{agent::ExceptionType, Agent, JsResult, ProtoIntrinsics},
+        execution::{agent::ExceptionType, Agent, ExecutionContext, JsResult, ProtoIntrinsics},
+        scripts_and_modules::ScriptOrModule,
         types::{
-            InternalMethods, InternalSlots, IntoObject, IntoValue, Object, ObjectHeapData,
-            OrdinaryObject, PropertyDescriptor, PropertyKey, String, Value, BUILTIN_STRING_MEMORY,
+            Function, InternalMethods, InternalSlots, IntoObject, IntoValue, Object,
+            ObjectHeapData, OrdinaryObject, PropertyDescriptor, PropertyKey, String, Value,
+            BUILTIN_STRING_MEMORY,
         },
     },
     heap::{
@@ -67,6 +70,194 @@ impl<'a> Error<'a> {
     }
 }
 
+impl Error<'_> {
+    /// Returns the call sites captured when this Error was created, innermost
+    /// first.
+    pub fn stack_frames(self, agent: &Agent) -> &[StackFrame] {
+        &agent[self].stack
+    }
+
+    /// Format the captured stack of this Error.
+    ///
+    /// The first line contains the Error's kind and message, followed by one
+    /// line per captured call site.
+    pub fn stack_string(self, agent: &mut Agent, gc: NoGcScope) -> std::string::String {
+        let message =
+            unwrap_try(self.try_get_own_property(agent, BUILTIN_STRING_MEMORY.message.into(), gc))
+                .and_then(|descriptor| descriptor.value)
+                .and_then(|value| String::try_from(value).ok());
+        let mut result = std::string::String::from(exception_type_name(agent[self].kind));
+        if let Some(message) = message {
+            if !message.is_empty_string() {
+                result.push_str(": ");
+                result.push_str(message.as_str(agent));
+            }
+        }
+        for frame in agent[self].stack.iter() {
+            result.push_str("\n    at ");
+            frame.write_to(agent, &mut result);
+        }
+        result
+    }
+}
+
+/// Maximum number of call sites captured into the stack of an Error.
+const STACK_TRACE_LIMIT: usize = 10;
+
+/// Capture the call sites currently on the execution context stack, innermost
+/// first. The `skip` innermost execution contexts are not included: this is
+/// used to hide the Error constructors from the stacks of the Errors they
+/// create.
+pub(crate) fn capture_stack_trace(agent: &Agent, skip: usize) -> Box<[StackFrame]> {
+    agent
+        .execution_context_stack
+        .iter()
+        .rev()
+        .skip(skip)
+        .filter_map(|context| StackFrame::from_execution_context(agent, context))
+        .take(STACK_TRACE_LIMIT)
+        .collect()
+}
+
+fn exception_type_name(kind: ExceptionType) -> &'static str {
+    match kind {
+        ExceptionType::Error => "Error",
+        ExceptionType::AggregateError => "AggregateError",
+        ExceptionType::EvalError => "EvalError",
+        ExceptionType::RangeError => "RangeError",
+        ExceptionType::ReferenceError => "ReferenceError",
+        ExceptionType::SyntaxError => "SyntaxError",
+        ExceptionType::TypeError => "TypeError",
+        ExceptionType::UriError => "URIError",
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ExceptionType::SuppressedError => "SuppressedError",
+    }
+}
+
+impl StackFrame {
+    pub(crate) fn from_execution_context(
+        agent: &Agent,
+        context: &ExecutionContext,
+    ) -> Option<Self> {
+        // The position of the instruction being executed, if the call site is
+        // in bytecode.
+        let instruction_position = context.ecmascript_code.as_ref().and_then(|code| {
+            let (executable, ip) = code.instruction?;
+            let offset = executable.get_source_offset(agent, ip as usize)?;
+            Some((code.source_code, offset))
+        });
+        let Some(function) = context.function else {
+            // Script or Module code: The initial execution context of a Realm
+            // has no code and is not a call site.
+            let code = context.ecmascript_code.as_ref()?;
+            return Some(Self {
+                function_name: None,
+                script_or_module: context.script_or_module,
+                source_position: instruction_position.or(Some((code.source_code, 0))),
+            });
+        };
+        let frame = match function {
+            Function::ECMAScriptFunction(f) => {
+                let data = &agent[f].ecmascript_function;
+                Self {
+                    function_name: agent[f].name,
+                    script_or_module: Some(data.script_or_module),
+                    source_position: instruction_position
+                        .or(Some((data.source_code, data.source_text.start))),
+                }
+            }
+            Function::BuiltinConstructorFunction(f) => Self {
+                function_name: None,
+                script_or_module: context.script_or_module,
+                source_position: Some((agent[f].source_code, agent[f].source_text.start)),
+            },
+            Function::BoundFunction(f) => Self {
+                function_name: agent[f].name,
+                script_or_module: None,
+                source_position: None,
+            },
+            Function::BuiltinFunction(f) => Self {
+                function_name: agent[f].initial_name,
+                script_or_module: None,
+                source_position: None,
+            },
+            Function::BuiltinPromiseResolvingFunction(_)
+            | Function::BuiltinGeneratorFunction
+            | Function::BuiltinPromiseCollectorFunction
+            | Function::BuiltinProxyRevokerFunction => Self {
+                function_name: None,
+                script_or_module: None,
+                source_position: None,
+            },
+        };
+        Some(frame)
+    }
+
+    /// Name of the function executing at this call site. None if the call
+    /// site is in Script or Module code, or if the function is anonymous.
+    pub fn function_name<'a>(&'a self, agent: &'a Agent) -> Option<&'a str> {
+        self.function_name
+            .as_ref()
+            .map(|name| name.as_str(agent))
+            .filter(|name| !name.is_empty())
+    }
+
+    /// The Script or Module that the code executing at this call site
+    /// originates from.
+    pub fn script_or_module(&self) -> Option<ScriptOrModule> {
+        self.script_or_module
+    }
+
+    /// Name of the source code that this call site is in, as given by the
+    /// host with [`Script::set_name`]. None for builtin functions, eval code
+    /// and unnamed Scripts.
+    ///
+    /// [`Script::set_name`]: crate::ecmascript::scripts_and_modules::script::Script::set_name
+    pub fn script_name<'a>(&self, agent: &'a Agent) -> Option<&'a str> {
+        let (source_code, _) = self.source_position?;
+        source_code.get_name(agent)
+    }
+
+    /// Returns true if this call site is inside a builtin function.
+    pub fn is_native(&self) -> bool {
+        self.source_position.is_none()
+    }
+
+    /// The 1-based line and column of this call site in its source code.
+    pub fn line_and_column(&self, agent: &Agent) -> Option<(u32, u32)> {
+        let (source_code, offset) = self.source_position?;
+        Some(source_code.get_line_and_column(agent, offset))
+    }
+
+    /// The byte offset of this call site into its source code.
+    pub fn source_offset(&self) -> Option<u32> {
+        self.source_position.map(|(_, offset)| offset)
+    }
+
+    /// The source code text that this call site is in.
+    pub fn source_text<'a>(&self, agent: &'a Agent) -> Option<&'a str> {
+        self.source_position
+            .map(|(source_code, _)| source_code.get_source_text(agent))
+    }
+
+    fn write_to(&self, agent: &Agent, output: &mut std::string::String) {
+        use core::fmt::Write;
+        let location = if self.is_native() {
+            "native".to_string()
+        } else {
+            let (line, column) = self.line_and_column(agent).unwrap();
+            let name = self.script_name(agent).unwrap_or("<anonymous>");
+            format!("{}:{}:{}", name, line, column)
+        };
+        match self.function_name(agent) {
+            Some(name) => write!(output, "{} ({})", name, location),
+            None if self.is_native() => write!(output, "<anonymous> ({})", location),
+            None => write!(output, "{}", location),
+        }
+        .unwrap();
+    }
+}
+
 impl IntoValue for Error<'_> {
     fn into_value(self) -> Value {
         self.into()
@@ -148,23 +339,22 @@ impl<'a> InternalSlots<'a> for Error<'a> {
                 configurable: true,
             },
         });
-        let (keys, values) =
+        let (shape, values) =
             if let (Some(message_entry), Some(cause_entry)) = (message_entry, cause_entry) {
                 agent
                     .heap
-                    .elements
                     .create_object_entries(&[message_entry, cause_entry])
             } else if let Some(message_entry) = message_entry {
-                agent.heap.elements.create_object_entries(&[message_entry])
+                agent.heap.create_object_entries(&[message_entry])
             } else if let Some(cause_entry) = cause_entry {
-                agent.heap.elements.create_object_entries(&[cause_entry])
+                agent.heap.create_object_entries(&[cause_entry])
             } else {
-                agent.heap.elements.create_object_entries(&[])
+                agent.heap.create_object_entries(&[])
             };
         let backing_object = agent.heap.create(ObjectHeapData {
             extensible: true,
             prototype: Some(prototype),
-            keys,
+            shape,
             values,
         });
         self.set_backing_object(agent, backing_object);
@@ -184,6 +374,8 @@ impl<'a> InternalSlots<'a> for Error<'a> {
                 ExceptionType::SyntaxError => ProtoIntrinsics::SyntaxError,
                 ExceptionType::TypeError => ProtoIntrinsics::TypeError,
                 ExceptionType::UriError => ProtoIntrinsics::UriError,
+                #[cfg(feature = "proposal-explicit-resource-management")]
+                ExceptionType::SuppressedError => ProtoIntrinsics::SuppressedError,
             };
             Some(
                 agent
@@ -477,6 +669,7 @@ impl HeapMarkAndSweep for Error<'static> {
 impl CreateHeapData<ErrorHeapData, Error<'static>> for Heap {
     fn create(&mut self, data: ErrorHeapData) -> Error<'static> {
         self.errors.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ErrorHeapData>>();
         Error(ErrorIndex::last(&self.errors))
     }
 }
@@ -491,6 +684,7 @@ impl Index<Error<'_>> for Agent {
 
 impl IndexMut<Error<'_>> for Agent {
     fn index_mut(&mut self, index: Error) -> &mut Self::Output {
+        self.heap.remembered_sets.errors.remember(index.get_index());
         &mut self.heap.errors[index]
     }
 }
@@ -5,18 +5,20 @@
 use crate::{
     ecmascript::{
         execution::agent::ExceptionType,
+        scripts_and_modules::{source_code::SourceCode, ScriptOrModule},
         types::{OrdinaryObject, String, Value},
     },
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct ErrorHeapData {
     pub(crate) object_index: Option<OrdinaryObject<'static>>,
     pub(crate) kind: ExceptionType,
     pub(crate) message: Option<String<'static>>,
     pub(crate) cause: Option<Value>,
-    // TODO: stack? name?
+    /// Call sites captured when the Error was created, innermost first.
+    pub(crate) stack: Box<[StackFrame]>,
 }
 
 impl ErrorHeapData {
@@ -24,16 +26,32 @@ impl ErrorHeapData {
         kind: ExceptionType,
         message: Option<String<'static>>,
         cause: Option<Value>,
+        stack: Box<[StackFrame]>,
     ) -> Self {
         Self {
             object_index: None,
             kind,
             message,
             cause,
+            stack,
         }
     }
 }
 
+/// A single call site of an Error's captured stack.
+#[derive(Debug, Clone, Copy)]
+pub struct StackFrame {
+    /// Name of the executing function, or None if Script or Module code was
+    /// executing.
+    pub(crate) function_name: Option<String<'static>>,
+    /// The Script or Module that the executing code originates from. None for
+    /// builtin functions.
+    pub(crate) script_or_module: Option<ScriptOrModule>,
+    /// The source code and byte offset into it that the call site refers to.
+    /// None for builtin functions.
+    pub(crate) source_position: Option<(SourceCode, u32)>,
+}
+
 impl HeapMarkAndSweep for ErrorHeapData {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
@@ -41,11 +59,15 @@ impl HeapMarkAndSweep for ErrorHeapData {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
 
         object_index.mark_values(queues);
         message.mark_values(queues);
         cause.mark_values(queues);
+        for frame in stack.iter() {
+            frame.mark_values(queues);
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -54,9 +76,41 @@ impl HeapMarkAndSweep for ErrorHeapData {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
         object_index.sweep_values(compactions);
         message.sweep_values(compactions);
         cause.sweep_values(compactions);
+        for frame in stack.iter_mut() {
+            frame.sweep_values(compactions);
+        }
+    }
+}
+
+impl HeapMarkAndSweep for StackFrame {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            function_name,
+            script_or_module,
+            source_position,
+        } = self;
+        function_name.mark_values(queues);
+        script_or_module.mark_values(queues);
+        if let Some((source_code, _)) = source_position {
+            source_code.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            function_name,
+            script_or_module,
+            source_position,
+        } = self;
+        function_name.sweep_values(compactions);
+        script_or_module.sweep_values(compactions);
+        if let Some((source_code, _)) = source_position {
+            source_code.sweep_values(compactions);
+        }
     }
 }
@@ -120,6 +120,10 @@ impl Index<FinalizationRegistry<'_>> for Agent {
 
 impl IndexMut<FinalizationRegistry<'_>> for Agent {
     fn index_mut(&mut self, index: FinalizationRegistry) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .finalization_registrys
+            .remember(index.get_index());
         &mut self.heap.finalization_registrys[index]
     }
 }
@@ -170,6 +174,7 @@ impl Rootable for FinalizationRegistry<'_> {
 impl CreateHeapData<FinalizationRegistryHeapData, FinalizationRegistry<'static>> for Heap {
     fn create(&mut self, data: FinalizationRegistryHeapData) -> FinalizationRegistry<'static> {
         self.finalization_registrys.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<FinalizationRegistryHeapData>>();
         FinalizationRegistry(FinalizationRegistryIndex::last(
             &self.finalization_registrys,
         ))
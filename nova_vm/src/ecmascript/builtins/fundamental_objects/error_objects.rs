@@ -8,3 +8,7 @@ pub(crate) mod error_constructor;
 pub(crate) mod error_prototype;
 pub(crate) mod native_error_constructors;
 pub(crate) mod native_error_prototypes;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod suppressed_error_constructors;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) mod suppressed_error_prototypes;
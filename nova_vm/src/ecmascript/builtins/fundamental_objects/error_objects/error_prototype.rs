@@ -4,11 +4,17 @@
 
 use crate::{
     ecmascript::{
-        abstract_operations::{operations_on_objects::get, type_conversion::to_string},
-        builders::ordinary_object_builder::OrdinaryObjectBuilder,
+        abstract_operations::{
+            operations_on_objects::{create_data_property_or_throw, get},
+            type_conversion::to_string,
+        },
+        builders::{
+            builtin_function_builder::BuiltinFunctionBuilder,
+            ordinary_object_builder::OrdinaryObjectBuilder,
+        },
         builtins::{ArgumentsList, Builtin},
         execution::{agent::ExceptionType, Agent, JsResult, RealmIdentifier},
-        types::{Object, PropertyKey, String, Value, BUILTIN_STRING_MEMORY},
+        types::{IntoFunction, Object, PropertyKey, String, Value, BUILTIN_STRING_MEMORY},
     },
     engine::context::GcScope,
 };
@@ -26,7 +32,82 @@ impl Builtin for ErrorPrototypeToString {
         crate::ecmascript::builtins::Behaviour::Regular(ErrorPrototype::to_string);
 }
 
+struct ErrorPrototypeGetStack;
+
+impl Builtin for ErrorPrototypeGetStack {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_stack;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: crate::ecmascript::builtins::Behaviour =
+        crate::ecmascript::builtins::Behaviour::Regular(ErrorPrototype::get_stack);
+}
+
+struct ErrorPrototypeSetStack;
+
+impl Builtin for ErrorPrototypeSetStack {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.set_stack;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: crate::ecmascript::builtins::Behaviour =
+        crate::ecmascript::builtins::Behaviour::Regular(ErrorPrototype::set_stack);
+}
+
 impl ErrorPrototype {
+    /// ### get Error.prototype.stack
+    ///
+    /// Non-standard: Returns the call sites captured when the Error was
+    /// created, formatted as a string. Returns undefined if the this value
+    /// is not an Error.
+    fn get_stack(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        let gc = gc.into_nogc();
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc,
+            ));
+        };
+        let Object::Error(error) = o else {
+            return Ok(Value::Undefined);
+        };
+        let stack = error.stack_string(agent, gc);
+        Ok(String::from_string(agent, stack, gc).into_value())
+    }
+
+    /// ### set Error.prototype.stack
+    ///
+    /// Non-standard: Defines an own "stack" data property on the this value,
+    /// shadowing the captured stack.
+    fn set_stack(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
+    ) -> JsResult<Value> {
+        let Ok(o) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "'this' is not an object",
+                gc.nogc(),
+            ));
+        };
+        create_data_property_or_throw(
+            agent,
+            o,
+            BUILTIN_STRING_MEMORY.stack.into(),
+            arguments.get(0),
+            gc,
+        )?;
+        Ok(Value::Undefined)
+    }
+
     /// ### [20.5.3.4 Error.prototype.toString ( )](https://tc39.es/ecma262/#sec-error.prototype.tostring)
     fn to_string(
         agent: &mut Agent,
@@ -101,9 +182,15 @@ impl ErrorPrototype {
         let object_prototype = intrinsics.object_prototype();
         let this = intrinsics.error_prototype();
         let error_constructor = intrinsics.error();
+        let stack_getter = BuiltinFunctionBuilder::new::<ErrorPrototypeGetStack>(agent, realm)
+            .build()
+            .into_function();
+        let stack_setter = BuiltinFunctionBuilder::new::<ErrorPrototypeSetStack>(agent, realm)
+            .build()
+            .into_function();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(4)
+            .with_property_capacity(5)
             .with_prototype(object_prototype)
             .with_constructor_property(error_constructor)
             .with_property(|builder| {
@@ -120,6 +207,13 @@ impl ErrorPrototype {
                     .with_value(BUILTIN_STRING_MEMORY.Error.into())
                     .build()
             })
+            .with_property(|builder| {
+                builder
+                    .with_enumerable(false)
+                    .with_key(BUILTIN_STRING_MEMORY.stack.into())
+                    .with_getter_and_setter_functions(stack_getter, stack_setter)
+                    .build()
+            })
             .with_builtin_function_property::<ErrorPrototypeToString>()
             .build();
     }
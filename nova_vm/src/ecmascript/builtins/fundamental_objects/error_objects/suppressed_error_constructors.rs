@@ -0,0 +1,132 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::engine::context::GcScope;
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_objects::define_property_or_throw, type_conversion::to_string,
+        },
+        builders::builtin_function_builder::BuiltinFunctionBuilder,
+        builtins::{
+            error::Error, ordinary::ordinary_create_from_constructor, ArgumentsList, Behaviour,
+            Builtin, BuiltinIntrinsicConstructor,
+        },
+        execution::{Agent, JsResult, ProtoIntrinsics, RealmIdentifier},
+        types::{
+            Function, IntoObject, IntoValue, Object, PropertyDescriptor, PropertyKey, String,
+            Value, BUILTIN_STRING_MEMORY,
+        },
+    },
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct SuppressedErrorConstructor;
+impl Builtin for SuppressedErrorConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.SuppressedError;
+
+    const LENGTH: u8 = 3;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for SuppressedErrorConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::SuppressedError;
+}
+
+impl SuppressedErrorConstructor {
+    /// ### [11.1.1.1 SuppressedError ( error, suppressed, message )](https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror)
+    fn constructor(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope,
+    ) -> JsResult<Value> {
+        let error = arguments.get(0).scope(agent, gc.nogc());
+        let suppressed = arguments.get(1).scope(agent, gc.nogc());
+        let message = arguments.get(2);
+        // 1. If NewTarget is undefined, let newTarget be the active function object; else let newTarget be NewTarget.
+        let new_target = new_target.map_or_else(
+            || agent.running_execution_context().function.unwrap(),
+            |new_target| Function::try_from(new_target).unwrap(),
+        );
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget, "%SuppressedError.prototype%", « [[ErrorData]] »).
+        let o = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::SuppressedError,
+            gc.reborrow(),
+        )?;
+        let o = Error::try_from(o.unbind()).unwrap().scope(agent, gc.nogc());
+        // 3. If message is not undefined, then
+        if !message.is_undefined() {
+            // a. Let messageString be ? ToString(message).
+            let message = to_string(agent, message, gc.reborrow())?
+                .unbind()
+                .bind(gc.nogc());
+            // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", messageString).
+            let o = o.get(agent);
+            agent[o].message = Some(message.unbind());
+        }
+        // 4. Perform CreateNonEnumerableDataPropertyOrThrow(O, "error", error).
+        // 5. Perform CreateNonEnumerableDataPropertyOrThrow(O, "suppressed", suppressed).
+        install_suppressed_error_properties(
+            agent,
+            o.get(agent),
+            error.get(agent),
+            suppressed.get(agent),
+            gc,
+        );
+        // 6. Return O.
+        Ok(o.get(agent).into_value())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: RealmIdentifier) {
+        let intrinsics = agent.get_realm(realm).intrinsics();
+        let suppressed_error_prototype = intrinsics.suppressed_error_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<SuppressedErrorConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(suppressed_error_prototype.into_object())
+        .build();
+    }
+}
+
+/// Perform CreateNonEnumerableDataPropertyOrThrow for the "error" and
+/// "suppressed" properties of a newly created SuppressedError object.
+pub(crate) fn install_suppressed_error_properties(
+    agent: &mut Agent,
+    o: Error,
+    error: Value,
+    suppressed: Value,
+    mut gc: GcScope,
+) {
+    let o = o.scope(agent, gc.nogc());
+    let error = error.scope(agent, gc.nogc());
+    let suppressed = suppressed.scope(agent, gc.nogc());
+    for (key, value) in [
+        (BUILTIN_STRING_MEMORY.error, error),
+        (BUILTIN_STRING_MEMORY.suppressed, suppressed),
+    ] {
+        let property_descriptor = PropertyDescriptor {
+            configurable: Some(true),
+            enumerable: Some(false),
+            writable: Some(true),
+            value: Some(value.get(agent)),
+            ..Default::default()
+        };
+        // The properties are defined on a new, extensible object and cannot
+        // fail to be defined.
+        define_property_or_throw(
+            agent,
+            o.get(agent),
+            PropertyKey::from(key),
+            property_descriptor,
+            gc.reborrow(),
+        )
+        .unwrap();
+    }
+}
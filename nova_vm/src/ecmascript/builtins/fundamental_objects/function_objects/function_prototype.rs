@@ -501,16 +501,15 @@ fn create_throw_type_error_backing_object(
             configurable: false,
         },
     };
-    let (keys, values) = agent
+    let (shape, values) = agent
         .heap
-        .elements
         .create_object_entries(&[length_entry, name_entry]);
 
     agent.heap.create(ObjectHeapData {
         // The value of the [[Extensible]] internal slot of this function is false.
         extensible: false,
         prototype: Some(prototype),
-        keys,
+        shape,
         values,
     })
 }
@@ -616,7 +616,7 @@ impl ObjectConstructor {
         );
         // 3. Assert: obj is an extensible ordinary object with no own properties.
         let obj = OrdinaryObject::try_from(obj).unwrap();
-        debug_assert!(agent[obj].keys.is_empty());
+        debug_assert!(agent[obj].values.is_empty());
         // 4. Let closure be a new Abstract Closure with parameters (key,
         //    value) that captures obj and performs the following steps when
         //    called:
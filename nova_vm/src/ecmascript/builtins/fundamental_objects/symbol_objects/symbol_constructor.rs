@@ -110,8 +110,26 @@ impl SymbolConstructor {
         let intrinsics = agent.get_realm(realm).intrinsics();
         let symbol_prototype = intrinsics.symbol_prototype();
 
-        BuiltinFunctionBuilder::new_intrinsic_constructor::<SymbolConstructor>(agent, realm)
-            .with_property_capacity(16)
+        let mut property_capacity = 16;
+        if cfg!(feature = "proposal-explicit-resource-management") {
+            property_capacity += 2;
+        }
+
+        let builder =
+            BuiltinFunctionBuilder::new_intrinsic_constructor::<SymbolConstructor>(agent, realm)
+                .with_property_capacity(property_capacity);
+
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let builder = builder.with_property(|builder| {
+            builder
+                .with_key(BUILTIN_STRING_MEMORY.asyncDispose.into())
+                .with_value_readonly(WellKnownSymbolIndexes::AsyncDispose.into())
+                .with_enumerable(false)
+                .with_configurable(false)
+                .build()
+        });
+
+        let builder = builder
             .with_property(|builder| {
                 builder
                     .with_key(BUILTIN_STRING_MEMORY.asyncIterator.into())
@@ -120,7 +138,19 @@ impl SymbolConstructor {
                     .with_configurable(false)
                     .build()
             })
-            .with_builtin_function_property::<SymbolFor>()
+            .with_builtin_function_property::<SymbolFor>();
+
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let builder = builder.with_property(|builder| {
+            builder
+                .with_key(BUILTIN_STRING_MEMORY.dispose.into())
+                .with_value_readonly(WellKnownSymbolIndexes::Dispose.into())
+                .with_enumerable(false)
+                .with_configurable(false)
+                .build()
+        });
+
+        builder
             .with_property(|builder| {
                 builder
                     .with_key(BUILTIN_STRING_MEMORY.hasInstance.into())
@@ -11,7 +11,7 @@ use crate::ecmascript::abstract_operations::type_conversion::{
     is_trimmable_whitespace, to_int32, to_int32_number, to_number_primitive, to_string,
 };
 use crate::ecmascript::types::Primitive;
-use crate::engine::context::GcScope;
+use crate::engine::context::{GcScope, NoGcScope};
 use crate::{
     ecmascript::{
         abstract_operations::type_conversion::to_number,
@@ -120,21 +120,27 @@ impl Builtin for GlobalObjectEncodeURIComponent {
 impl BuiltinIntrinsic for GlobalObjectEncodeURIComponent {
     const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::EncodeURIComponent;
 }
+#[cfg(feature = "annex-b-global")]
 struct GlobalObjectEscape;
+#[cfg(feature = "annex-b-global")]
 impl Builtin for GlobalObjectEscape {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.escape;
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(GlobalObject::escape);
 }
+#[cfg(feature = "annex-b-global")]
 impl BuiltinIntrinsic for GlobalObjectEscape {
     const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::Escape;
 }
+#[cfg(feature = "annex-b-global")]
 struct GlobalObjectUnescape;
+#[cfg(feature = "annex-b-global")]
 impl Builtin for GlobalObjectUnescape {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.unescape;
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(GlobalObject::unescape);
 }
+#[cfg(feature = "annex-b-global")]
 impl BuiltinIntrinsic for GlobalObjectUnescape {
     const INDEX: IntrinsicFunctionIndexes = IntrinsicFunctionIndexes::Unescape;
 }
@@ -279,6 +285,7 @@ pub fn perform_eval(
             is_strict_mode: strict_eval,
             // The code running inside eval is defined inside the eval source.
             source_code,
+            instruction: None,
         }
     } else {
         // 17. Else,
@@ -297,6 +304,7 @@ pub fn perform_eval(
             is_strict_mode: strict_eval,
             // The code running inside eval is defined inside the eval source.
             source_code,
+            instruction: None,
         }
     };
 
@@ -705,6 +713,222 @@ pub fn eval_declaration_instantiation(
     Ok(())
 }
 
+/// Uppercase hexadecimal digits by their values.
+const HEX_DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+
+/// Pushes the prefix and the value as an uppercase hexadecimal number
+/// padded with zeroes to the number of digits.
+fn push_hex_escape(r: &mut std::string::String, prefix: &str, value: u16, digits: u32) {
+    r.push_str(prefix);
+    for shift in (0..digits).rev() {
+        let nibble = (value >> (shift * 4)) & 0xF;
+        r.push(char::from(HEX_DIGITS[nibble as usize]));
+    }
+}
+
+/// ### [19.2.6.5 Encode ( string, extraUnescaped )](https://tc39.es/ecma262/#sec-encode)
+///
+/// The abstract operation Encode takes arguments string (a String) and
+/// extraUnescaped (a String) and returns either a normal completion containing
+/// a String or a throw completion. It performs URI encoding and escaping,
+/// interpreting string as a sequence of UTF-16 encoded code points as
+/// described in 6.1.4. If a character is identified as unreserved in RFC 2396
+/// or appears in extraUnescaped, it is not escaped.
+fn encode<'gc>(
+    agent: &mut Agent,
+    string: String<'gc>,
+    extra_unescaped: &'static str,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<String<'gc>> {
+    let input = string.as_str(agent);
+    // 2. Let R be the empty String.
+    // 3. Let alwaysUnescaped be the string-concatenation of the ASCII word
+    //    characters and "-.!~*'()".
+    // 4. Let unescapedSet be the string-concatenation of alwaysUnescaped and
+    //    extraUnescaped.
+    let is_unescaped = |c: char| {
+        c.is_ascii_alphanumeric() || "_-.!~*'()".contains(c) || extra_unescaped.contains(c)
+    };
+    // OPTIMIZATION: If nothing needs escaping then R is equal to string.
+    if input.chars().all(is_unescaped) {
+        return Ok(string);
+    }
+    let mut r = std::string::String::with_capacity(input.len() * 3);
+    // 1. Let len be the length of string.
+    // 5. Let k be 0.
+    // 6. Repeat, while k < len,
+    // a. Let C be the code unit at index k within string.
+    for c in input.chars() {
+        // b. If unescapedSet contains C, then
+        if is_unescaped(c) {
+            // i. Set k to k + 1.
+            // ii. Set R to the string-concatenation of R and C.
+            r.push(c);
+            continue;
+        }
+        // c. Else,
+        // i. Let cp be CodePointAt(string, k).
+        // ii. If cp.[[IsUnpairedSurrogate]] is true, throw a URIError
+        //     exception.
+        // NOTE: Our strings are currently always valid UTF-8, so they can
+        // never contain unpaired surrogates.
+        // iii. Set k to k + cp.[[CodeUnitCount]].
+        // iv. Let Octets be the List of octets resulting by applying the
+        //     UTF-8 transformation to cp.[[CodePoint]].
+        let mut octets = [0u8; 4];
+        // v. For each element octet of Octets, do
+        for octet in c.encode_utf8(&mut octets).bytes() {
+            // 1. Let hex be the String representation of octet, formatted as
+            //    an uppercase hexadecimal number.
+            // 2. Set R to the string-concatenation of R, "%", and
+            //    StringPad(hex, 2, "0", start).
+            push_hex_escape(&mut r, "%", octet.into(), 2);
+        }
+    }
+    // 7. Return R.
+    Ok(String::from_string(agent, r, gc))
+}
+
+/// ### [19.2.6.6 Decode ( string, preserveEscapeSet )](https://tc39.es/ecma262/#sec-decode)
+///
+/// The abstract operation Decode takes arguments string (a String) and
+/// preserveEscapeSet (a String) and returns either a normal completion
+/// containing a String or a throw completion. It performs URI unescaping and
+/// decoding, preserving any escape sequences that correspond to Basic Latin
+/// characters in preserveEscapeSet.
+fn decode<'gc>(
+    agent: &mut Agent,
+    string: String<'gc>,
+    preserve_escape_set: &'static str,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<String<'gc>> {
+    let input = string.as_str(agent);
+    // OPTIMIZATION: Without any "%" there is nothing to decode.
+    if !input.contains('%') {
+        return Ok(string);
+    }
+    let bytes = input.as_bytes();
+    // 1. Let len be the length of string.
+    let len = bytes.len();
+    // 2. Let R be the empty String.
+    let mut r = std::string::String::with_capacity(len);
+    let parse_hex_octet = |k: usize| -> Option<u8> {
+        // NOTE: "%" and hexadecimal digits are ASCII, so UTF-8 byte offsets
+        // work the same as UTF-16 code unit offsets here.
+        let hex = input.get(k + 1..k + 3)?;
+        if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        u8::from_str_radix(hex, 16).ok()
+    };
+    // 3. Let k be 0.
+    let mut k = 0;
+    // 4. Repeat, while k < len,
+    while k < len {
+        // a. Let C be the code unit at index k within string.
+        // b. Let S be C.
+        // c. If C is the code unit 0x0025 (PERCENT SIGN), then
+        if bytes[k] != b'%' {
+            let next = input[k + 1..]
+                .find('%')
+                .map_or(len, |offset| k + 1 + offset);
+            r.push_str(&input[k..next]);
+            k = next;
+            continue;
+        }
+        // i. If k + 3 > len, throw a URIError exception.
+        // ii. Let escape be the substring of string from k to k + 3.
+        // iii. Let B be ParseHexOctet(string, k + 1).
+        // iv. If B is not an integer, throw a URIError exception.
+        let Some(b) = parse_hex_octet(k) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::UriError,
+                "Invalid percent-encoding in URI",
+                gc,
+            ));
+        };
+        let escape = &input[k..k + 3];
+        // v. Set k to k + 2.
+        k += 2;
+        // vi. Let n be the number of leading 1 bits in B.
+        let n = b.leading_ones() as usize;
+        // vii. If n = 0, then
+        if n == 0 {
+            // 1. Let asciiChar be the code unit whose numeric value is B.
+            let ascii_char = b as char;
+            // 2. If preserveEscapeSet contains asciiChar, let S be escape.
+            //    Otherwise, let S be asciiChar.
+            if preserve_escape_set.contains(ascii_char) {
+                r.push_str(escape);
+            } else {
+                r.push(ascii_char);
+            }
+        } else {
+            // viii. Else,
+            // 1. If n = 1 or n > 4, throw a URIError exception.
+            if n == 1 || n > 4 {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::UriError,
+                    "Invalid UTF-8 sequence in URI",
+                    gc,
+                ));
+            }
+            // 2. Let Octets be « B ».
+            let mut octets = [b, 0, 0, 0];
+            // 3. Let j be 1.
+            // 4. Repeat, while j < n,
+            for octet in octets.iter_mut().take(n).skip(1) {
+                // a. Set k to k + 1.
+                k += 1;
+                // b. If k + 3 > len, throw a URIError exception.
+                // c. If the code unit at index k within string is not the
+                //    code unit 0x0025 (PERCENT SIGN), throw a URIError
+                //    exception.
+                // d. Let continuationByte be ParseHexOctet(string, k + 1).
+                // e. If continuationByte is not an integer, throw a URIError
+                //    exception.
+                let continuation_byte = if bytes.get(k) == Some(&b'%') {
+                    parse_hex_octet(k)
+                } else {
+                    None
+                };
+                let Some(continuation_byte) = continuation_byte else {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::UriError,
+                        "Invalid UTF-8 sequence in URI",
+                        gc,
+                    ));
+                };
+                // f. Append continuationByte to Octets.
+                *octet = continuation_byte;
+                // g. Set k to k + 2.
+                k += 2;
+                // h. Set j to j + 1.
+            }
+            // 5. Assert: The length of Octets is n.
+            // 6. If Octets does not contain a valid UTF-8 encoding of a
+            //    Unicode code point, throw a URIError exception.
+            // 7. Let V be the code point obtained by applying the UTF-8
+            //    transformation to Octets, that is, from a List of octets
+            //    into a 21-bit value.
+            // 8. Let S be UTF16EncodeCodePoint(V).
+            let Ok(s) = core::str::from_utf8(&octets[..n]) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::UriError,
+                    "Invalid UTF-8 sequence in URI",
+                    gc,
+                ));
+            };
+            r.push_str(s);
+        }
+        // d. Set R to the string-concatenation of R and S.
+        // e. Set k to k + 1.
+        k += 1;
+    }
+    // 5. Return R.
+    Ok(String::from_string(agent, r, gc))
+}
+
 impl GlobalObject {
     /// ### [19.2.1 eval ( x )](https://tc39.es/ecma262/#sec-eval-x)
     ///
@@ -1009,53 +1233,243 @@ impl GlobalObject {
         }
     }
 
+    /// ### [19.2.6.1 decodeURI ( encodedURI )](https://tc39.es/ecma262/#sec-decodeuri-encodeduri)
+    ///
+    /// This function computes a new version of a URI in which each escape
+    /// sequence and UTF-8 encoding of the sort that might be introduced by the
+    /// encodeURI function is replaced with the UTF-16 encoding of the code
+    /// point that it represents. Escape sequences that could not have been
+    /// introduced by encodeURI are not replaced.
     fn decode_uri(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let encoded_uri = arguments.get(0);
+        // 1. Let uriString be ? ToString(encodedURI).
+        let uri_string = to_string(agent, encoded_uri, gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
+        // 2. Let preserveEscapeSet be ";/?:@&=+$,#".
+        // 3. Return ? Decode(uriString, preserveEscapeSet).
+        decode(agent, uri_string, ";/?:@&=+$,#", gc.nogc()).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.2 decodeURIComponent ( encodedURIComponent )](https://tc39.es/ecma262/#sec-decodeuricomponent-encodeduricomponent)
+    ///
+    /// This function computes a new version of a URI in which each escape
+    /// sequence and UTF-8 encoding of the sort that might be introduced by the
+    /// encodeURIComponent function is replaced with the UTF-16 encoding of the
+    /// code point that it represents.
     fn decode_uri_component(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let encoded_uri_component = arguments.get(0);
+        // 1. Let componentString be ? ToString(encodedURIComponent).
+        let component_string = to_string(agent, encoded_uri_component, gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
+        // 2. Let preserveEscapeSet be the empty String.
+        // 3. Return ? Decode(componentString, preserveEscapeSet).
+        decode(agent, component_string, "", gc.nogc()).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.3 encodeURI ( uri )](https://tc39.es/ecma262/#sec-encodeuri-uri)
+    ///
+    /// This function computes a new version of a UTF-16 encoded URI in which
+    /// each instance of certain code points is replaced by one, two, three, or
+    /// four escape sequences representing the UTF-8 encoding of the code
+    /// point.
     fn encode_uri(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let uri = arguments.get(0);
+        // 1. Let uriString be ? ToString(uri).
+        let uri_string = to_string(agent, uri, gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
+        // 2. Let extraUnescaped be ";/?:@&=+$,#".
+        // 3. Return ? Encode(uriString, extraUnescaped).
+        encode(agent, uri_string, ";/?:@&=+$,#", gc.nogc()).map(|s| s.into_value())
     }
+
+    /// ### [19.2.6.4 encodeURIComponent ( uriComponent )](https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent)
+    ///
+    /// This function computes a new version of a UTF-16 encoded URI in which
+    /// each instance of certain code points is replaced by one, two, three, or
+    /// four escape sequences representing the UTF-8 encoding of the code
+    /// point.
     fn encode_uri_component(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let uri_component = arguments.get(0);
+        // 1. Let componentString be ? ToString(uriComponent).
+        let component_string = to_string(agent, uri_component, gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
+        // 2. Let extraUnescaped be the empty String.
+        // 3. Return ? Encode(componentString, extraUnescaped).
+        encode(agent, component_string, "", gc.nogc()).map(|s| s.into_value())
     }
+
+    /// ### [B.2.1.1 escape ( string )](https://tc39.es/ecma262/#sec-escape-string)
+    ///
+    /// This function is a property of the global object. It computes a new
+    /// version of a String value in which certain code units have been
+    /// replaced by a hexadecimal escape sequence.
+    #[cfg(feature = "annex-b-global")]
     fn escape(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let string = arguments.get(0);
+        // 1. Set string to ? ToString(string).
+        let string = to_string(agent, string, gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
+        let input = string.as_str(agent);
+        // 3. Let R be the empty String.
+        // 4. Let unescapedSet be the string-concatenation of the ASCII word
+        //    characters and "@*+-./".
+        let is_unescaped = |c: char| c.is_ascii_alphanumeric() || "_@*+-./".contains(c);
+        // OPTIMIZATION: If every code unit is in the unescaped set, R is
+        // equal to string.
+        if input.chars().all(is_unescaped) {
+            return Ok(string.into_value());
+        }
+        let mut r = std::string::String::with_capacity(input.len() * 3);
+        // 2. Let len be the length of string.
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        // a. Let C be the code unit at index k within string.
+        for c in input.chars() {
+            // b. If unescapedSet contains C, then
+            if is_unescaped(c) {
+                // i. Let S be C.
+                r.push(c);
+                continue;
+            }
+            // c. Else,
+            let mut units = [0u16; 2];
+            for &n in c.encode_utf16(&mut units).iter() {
+                // i. Let n be the numeric value of C.
+                if n < 256 {
+                    // ii. If n < 256, then
+                    // 1. Let hex be the String representation of n, formatted
+                    //    as an uppercase hexadecimal number.
+                    // 2. Let S be the string-concatenation of "%" and
+                    //    StringPad(hex, 2, "0", start).
+                    push_hex_escape(&mut r, "%", n, 2);
+                } else {
+                    // iii. Else,
+                    // 1. Let hex be the String representation of n, formatted
+                    //    as an uppercase hexadecimal number.
+                    // 2. Let S be the string-concatenation of "%u" and
+                    //    StringPad(hex, 4, "0", start).
+                    push_hex_escape(&mut r, "%u", n, 4);
+                }
+            }
+            // d. Set R to the string-concatenation of R and S.
+            // e. Set k to k + 1.
+        }
+        // 7. Return R.
+        Ok(String::from_string(agent, r, gc.nogc()).into_value())
     }
+
+    /// ### [B.2.1.2 unescape ( string )](https://tc39.es/ecma262/#sec-unescape-string)
+    ///
+    /// This function is a property of the global object. It computes a new
+    /// version of a String value in which each escape sequence of the sort
+    /// that might be introduced by the escape function is replaced with the
+    /// code unit that it represents.
+    #[cfg(feature = "annex-b-global")]
     fn unescape(
-        _agent: &mut Agent,
+        agent: &mut Agent,
         _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let string = arguments.get(0);
+        // 1. Set string to ? ToString(string).
+        let string = to_string(agent, string, gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
+        let input = string.as_str(agent);
+        // OPTIMIZATION: Without any "%" there is nothing to unescape.
+        if !input.contains('%') {
+            return Ok(string.into_value());
+        }
+        let units = input.encode_utf16().collect::<Vec<u16>>();
+        // 2. Let len be the length of string.
+        let len = units.len();
+        // 3. Let R be the empty String.
+        let mut r = Vec::with_capacity(len);
+        let hex_digits = |units: &[u16]| -> Option<u16> {
+            units.iter().try_fold(0u16, |acc, &unit| {
+                let digit = char::from_u32(unit as u32)?.to_digit(16)?;
+                Some((acc << 4) | digit as u16)
+            })
+        };
+        // 4. Let k be 0.
+        let mut k = 0;
+        // 5. Repeat, while k < len,
+        while k < len {
+            // a. Let C be the code unit at index k within string.
+            let mut c = units[k];
+            // b. If C is the code unit 0x0025 (PERCENT SIGN), then
+            if c == u16::from(b'%') {
+                // i. Let hexDigits be the empty String.
+                // ii. Let optionalAdvance be 0.
+                // iii. If k + 5 < len and the code unit at index k + 1 within
+                //      string is the code unit 0x0075 (LATIN SMALL LETTER U),
+                //      then
+                if k + 5 < len && units[k + 1] == u16::from(b'u') {
+                    // 1. Set hexDigits to the substring of string from k + 2
+                    //    to k + 6.
+                    // 2. Set optionalAdvance to 5.
+                    if let Some(n) = hex_digits(&units[k + 2..k + 6]) {
+                        // vi. If parseResult is a Parse Node, then
+                        // 1. Let n be the MV of parseResult.
+                        // 2. Set C to the code unit whose numeric value is n.
+                        // 3. Set k to k + optionalAdvance.
+                        c = n;
+                        k += 5;
+                    }
+                } else if k + 2 < len {
+                    // iv. Else if k + 3 ≤ len, then
+                    // 1. Set hexDigits to the substring of string from k + 1
+                    //    to k + 3.
+                    // 2. Set optionalAdvance to 2.
+                    if let Some(n) = hex_digits(&units[k + 1..k + 3]) {
+                        c = n;
+                        k += 2;
+                    }
+                }
+                // v. Let parseResult be ParseText(hexDigits, HexDigits[~Sep]).
+            }
+            // c. Set R to the string-concatenation of R and C.
+            r.push(c);
+            // d. Set k to k + 1.
+            k += 1;
+        }
+        // 6. Return R.
+        // NOTE: Our strings are currently always valid UTF-8, so a lone
+        // surrogate produced by a "%uXXXX" escape has to be replaced with
+        // U+FFFD REPLACEMENT CHARACTER.
+        let r = std::string::String::from_utf16_lossy(&r);
+        Ok(String::from_string(agent, r, gc.nogc()).into_value())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: RealmIdentifier) {
@@ -1079,7 +1493,9 @@ impl GlobalObject {
             agent, realm,
         )
         .build();
+        #[cfg(feature = "annex-b-global")]
         BuiltinFunctionBuilder::new_intrinsic_function::<GlobalObjectEscape>(agent, realm).build();
+        #[cfg(feature = "annex-b-global")]
         BuiltinFunctionBuilder::new_intrinsic_function::<GlobalObjectUnescape>(agent, realm)
             .build();
     }
@@ -6,6 +6,7 @@ use crate::ecmascript::abstract_operations::operations_on_iterator_objects::get_
 use crate::ecmascript::abstract_operations::operations_on_iterator_objects::if_abrupt_close_iterator;
 use crate::ecmascript::abstract_operations::operations_on_iterator_objects::iterator_close;
 use crate::ecmascript::abstract_operations::operations_on_iterator_objects::iterator_step_value;
+use crate::ecmascript::abstract_operations::operations_on_iterator_objects::IteratorRecord;
 use crate::ecmascript::abstract_operations::operations_on_objects::call_function;
 use crate::ecmascript::abstract_operations::operations_on_objects::construct;
 use crate::ecmascript::abstract_operations::operations_on_objects::create_data_property_or_throw;
@@ -23,7 +24,13 @@ use crate::ecmascript::abstract_operations::type_conversion::to_object;
 use crate::ecmascript::builders::builtin_function_builder::BuiltinFunctionBuilder;
 
 use crate::ecmascript::builtins::array_create;
+use crate::ecmascript::builtins::indexed_collections::array_objects::array_from_async_reaction::array_from_async_start;
+use crate::ecmascript::builtins::indexed_collections::array_objects::array_from_async_reaction::ArrayFromAsyncReaction;
+use crate::ecmascript::builtins::indexed_collections::array_objects::array_from_async_reaction::ArrayFromAsyncSource;
+use crate::ecmascript::builtins::indexed_collections::array_objects::array_from_async_reaction::ArrayFromAsyncState;
 use crate::ecmascript::builtins::ordinary::get_prototype_from_constructor;
+use crate::ecmascript::builtins::promise::Promise;
+use crate::ecmascript::builtins::promise_objects::promise_abstract_operations::promise_capability_records::PromiseCapability;
 use crate::ecmascript::builtins::ArgumentsList;
 use crate::ecmascript::builtins::Behaviour;
 use crate::ecmascript::builtins::Builtin;
@@ -47,6 +54,7 @@ use crate::ecmascript::types::Value;
 use crate::ecmascript::types::BUILTIN_STRING_MEMORY;
 use crate::engine::context::GcScope;
 use crate::engine::unwrap_try;
+use crate::engine::Scoped;
 use crate::heap::IntrinsicConstructorIndexes;
 use crate::heap::WellKnownSymbolIndexes;
 use crate::SmallInteger;
@@ -68,6 +76,12 @@ impl Builtin for ArrayFrom {
     const LENGTH: u8 = 1;
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
 }
+struct ArrayFromAsync;
+impl Builtin for ArrayFromAsync {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ArrayConstructor::from_async);
+    const LENGTH: u8 = 1;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromAsync;
+}
 struct ArrayIsArray;
 impl Builtin for ArrayIsArray {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(ArrayConstructor::is_array);
@@ -168,8 +182,7 @@ impl ArrayConstructor {
                     int_len as usize,
                     proto.map(|p| p.get(agent)),
                     gc.nogc(),
-                )
-                .unwrap();
+                )?;
                 // e. Perform ! Set(array, "length", intLen, true).
                 debug_assert_eq!(agent[array].elements.len(), int_len);
                 array
@@ -228,9 +241,10 @@ impl ArrayConstructor {
         arguments: ArgumentsList,
         mut gc: GcScope,
     ) -> JsResult<Value> {
-        let items = arguments.get(0);
+        let this_value = this_value.scope(agent, gc.nogc());
+        let items = arguments.get(0).scope(agent, gc.nogc());
         let mapfn = arguments.get(1);
-        let this_arg = arguments.get(2);
+        let this_arg = arguments.get(2).scope(agent, gc.nogc());
 
         // 1. Let C be the this value.
         // 2. If mapfn is undefined, then
@@ -255,7 +269,7 @@ impl ArrayConstructor {
         // 4. Let usingIterator be ? GetMethod(items, @@iterator).
         let using_iterator = get_method(
             agent,
-            items,
+            items.get(agent),
             WellKnownSymbolIndexes::Iterator.into(),
             gc.reborrow(),
         )?;
@@ -264,7 +278,7 @@ impl ArrayConstructor {
         if let Some(using_iterator) = using_iterator {
             let mut using_iterator = using_iterator.unbind().bind(gc.nogc());
             // a. If IsConstructor(C) is true, then
-            let a = if let Some(c) = is_constructor(agent, this_value) {
+            let a = if let Some(c) = is_constructor(agent, this_value.get(agent)) {
                 let scoped_using_iterator = using_iterator.scope(agent, gc.nogc());
                 // i. Let A be ? Construct(C).
                 let a = construct(agent, c, None, None, gc.reborrow())?
@@ -283,8 +297,12 @@ impl ArrayConstructor {
             let a = a.scope(agent, gc.nogc());
 
             // c. Let iteratorRecord be ? GetIteratorFromMethod(items, usingIterator).
-            let mut iterator_record =
-                get_iterator_from_method(agent, items, using_iterator.unbind(), gc.reborrow())?;
+            let mut iterator_record = get_iterator_from_method(
+                agent,
+                items.get(agent),
+                using_iterator.unbind(),
+                gc.reborrow(),
+            )?;
 
             // d. Let k be 0.
             let mut k = 0;
@@ -335,7 +353,7 @@ impl ArrayConstructor {
                     let mapped_value = call_function(
                         agent,
                         mapping.get(agent),
-                        this_arg,
+                        this_arg.get(agent),
                         Some(ArgumentsList(&[next, fk])),
                         gc.reborrow(),
                     );
@@ -379,7 +397,7 @@ impl ArrayConstructor {
 
         // 6. NOTE: items is not an Iterable so assume it is an array-like object.
         // 7. Let arrayLike be ! ToObject(items).
-        let array_like = to_object(agent, items, gc.nogc())
+        let array_like = to_object(agent, items.get(agent), gc.nogc())
             .unwrap()
             .scope(agent, gc.nogc());
 
@@ -388,7 +406,7 @@ impl ArrayConstructor {
         let len_value = Value::try_from(len).unwrap();
 
         // 9. If IsConstructor(C) is true, then
-        let a = if let Some(c) = is_constructor(agent, this_value) {
+        let a = if let Some(c) = is_constructor(agent, this_value.get(agent)) {
             // a. Let A be ? Construct(C, « 𝔽(len) »).
             construct(
                 agent,
@@ -428,7 +446,7 @@ impl ArrayConstructor {
                 call_function(
                     agent,
                     mapping.get(agent),
-                    this_arg,
+                    this_arg.get(agent),
                     Some(ArgumentsList(&[k_value, fk])),
                     gc.reborrow(),
                 )?
@@ -459,6 +477,203 @@ impl ArrayConstructor {
         Ok(a.get(agent).into_value())
     }
 
+    /// ### [2.1.1.1 Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+    fn from_async(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
+    ) -> JsResult<Value> {
+        let async_items = arguments.get(0);
+        let mapfn = arguments.get(1);
+        let this_arg = arguments.get(2);
+
+        // 1. Let C be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        let promise_capability = PromiseCapability::new(agent);
+        let promise = promise_capability.promise().scope(agent, gc.nogc());
+        // 3. Let fromAsyncClosure be a new Abstract Closure with no
+        //    parameters that captures C, mapfn, and thisArg and performs the
+        //    following steps when called:
+        // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+        let result = Self::from_async_closure(
+            agent,
+            this_value,
+            async_items,
+            mapfn,
+            this_arg,
+            &promise,
+            gc.reborrow(),
+        );
+        match result {
+            Ok(data) => array_from_async_start(agent, data, gc.reborrow()),
+            Err(error) => {
+                PromiseCapability::from_promise(promise.get(agent), true)
+                    .reject(agent, error.value());
+            }
+        }
+        // 5. Return promiseCapability.[[Promise]].
+        Ok(promise.get(agent).into_value())
+    }
+
+    /// The steps of the fromAsyncClosure of Array.fromAsync up to the start of
+    /// its loop.
+    fn from_async_closure(
+        agent: &mut Agent,
+        c: Value,
+        async_items: Value,
+        mapfn: Value,
+        this_arg: Value,
+        promise: &Scoped<Promise<'static>>,
+        mut gc: GcScope,
+    ) -> JsResult<ArrayFromAsyncReaction> {
+        let this_arg = this_arg.scope(agent, gc.nogc());
+        let async_items = async_items.scope(agent, gc.nogc());
+        // a. If mapfn is undefined, let mapping be false.
+        let mapping = if mapfn.is_undefined() {
+            None
+        } else {
+            // b. Else,
+            // i. If IsCallable(mapfn) is false, throw a TypeError exception.
+            let Some(mapfn) = is_callable(mapfn, gc.nogc()) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "The map function of Array.fromAsync is not callable",
+                    gc.nogc(),
+                ));
+            };
+            // ii. Let mapping be true.
+            Some(mapfn.scope(agent, gc.nogc()))
+        };
+        // c. Let usingAsyncIterator be ? GetMethod(asyncItems, @@asyncIterator).
+        let using_async_iterator = get_method(
+            agent,
+            async_items.get(agent),
+            WellKnownSymbolIndexes::AsyncIterator.into(),
+            gc.reborrow(),
+        )?
+        .map(|f| f.unbind());
+        // d. If usingAsyncIterator is undefined, then
+        let using_sync_iterator = if using_async_iterator.is_none() {
+            // i. Let usingSyncIterator be ? GetMethod(asyncItems, @@iterator).
+            get_method(
+                agent,
+                async_items.get(agent),
+                WellKnownSymbolIndexes::Iterator.into(),
+                gc.reborrow(),
+            )?
+            .map(|f| f.unbind())
+        } else {
+            None
+        };
+        // e. Let iteratorRecord be undefined.
+        let source = if let Some(using_async_iterator) = using_async_iterator {
+            // f. If usingAsyncIterator is not undefined, then
+            // i. Set iteratorRecord to ? GetIteratorFromMethod(asyncItems, usingAsyncIterator).
+            Some(ArrayFromAsyncSource::AsyncIterator(
+                get_iterator_from_method(
+                    agent,
+                    async_items.get(agent),
+                    using_async_iterator,
+                    gc.reborrow(),
+                )?,
+            ))
+        } else if let Some(using_sync_iterator) = using_sync_iterator {
+            // g. Else if usingSyncIterator is not undefined, then
+            // i. Set iteratorRecord to
+            //    ? CreateAsyncFromSyncIterator(GetIteratorFromMethod(asyncItems, usingSyncIterator)).
+            Some(ArrayFromAsyncSource::SyncIterator(
+                get_iterator_from_method(
+                    agent,
+                    async_items.get(agent),
+                    using_sync_iterator,
+                    gc.reborrow(),
+                )?,
+            ))
+        } else {
+            None
+        };
+
+        // h. If iteratorRecord is not undefined, then
+        let (a, source) = if let Some(source) = source {
+            let (ArrayFromAsyncSource::AsyncIterator(iterator_record)
+            | ArrayFromAsyncSource::SyncIterator(iterator_record)) = source
+            else {
+                unreachable!()
+            };
+            let iterator = iterator_record.iterator.scope(agent, gc.nogc());
+            let next_method = iterator_record.next_method.scope(agent, gc.nogc());
+            // i. If IsConstructor(C) is true, then
+            let a = if let Some(c) = is_constructor(agent, c) {
+                // 1. Let A be ? Construct(C).
+                construct(agent, c, None, None, gc.reborrow())?.unbind()
+            } else {
+                // ii. Else,
+                // 1. Let A be ! ArrayCreate(0).
+                array_create(agent, 0, 0, None, gc.nogc())
+                    .unwrap()
+                    .into_object()
+                    .unbind()
+            };
+            let iterator_record = IteratorRecord {
+                iterator: iterator.get(agent),
+                next_method: next_method.get(agent),
+                done: false,
+            };
+            let source = match source {
+                ArrayFromAsyncSource::AsyncIterator(_) => {
+                    ArrayFromAsyncSource::AsyncIterator(iterator_record)
+                }
+                _ => ArrayFromAsyncSource::SyncIterator(iterator_record),
+            };
+            (a, source)
+        } else {
+            // i. Else,
+            // i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable
+            //    so assume it is an array-like object.
+            // ii. Let arrayLike be ! ToObject(asyncItems).
+            let array_like =
+                to_object(agent, async_items.get(agent), gc.nogc())?.scope(agent, gc.nogc());
+            // iii. Let len be ? LengthOfArrayLike(arrayLike).
+            let len = length_of_array_like(agent, array_like.get(agent), gc.reborrow())?;
+            // iv. If IsConstructor(C) is true, then
+            let a = if let Some(c) = is_constructor(agent, c) {
+                // 1. Let A be ? Construct(C, « 𝔽(len) »).
+                construct(
+                    agent,
+                    c,
+                    Some(ArgumentsList(&[Value::try_from(len).unwrap()])),
+                    None,
+                    gc.reborrow(),
+                )?
+                .unbind()
+            } else {
+                // v. Else,
+                // 1. Let A be ? ArrayCreate(len).
+                array_create(agent, len as usize, len as usize, None, gc.nogc())?
+                    .into_object()
+                    .unbind()
+            };
+            let source = ArrayFromAsyncSource::ArrayLike {
+                array_like: array_like.get(agent),
+                len: len as usize,
+            };
+            (a, source)
+        };
+
+        // iii. Let k be 0.
+        Ok(ArrayFromAsyncReaction {
+            realm: agent.current_realm_id(),
+            promise_capability: PromiseCapability::from_promise(promise.get(agent), true),
+            target: a,
+            mapping: mapping.map(|mapfn| mapfn.get(agent)),
+            this_arg: this_arg.get(agent),
+            k: 0,
+            source,
+            state: ArrayFromAsyncState::Start,
+        })
+    }
+
     /// ### [23.1.2.2 Array.isArray ( arg )](https://tc39.es/ecma262/#sec-array.isarray)
     fn is_array(
         agent: &mut Agent,
@@ -551,9 +766,10 @@ impl ArrayConstructor {
         let array_prototype = intrinsics.array_prototype();
 
         BuiltinFunctionBuilder::new_intrinsic_constructor::<ArrayConstructor>(agent, realm)
-            .with_property_capacity(5)
+            .with_property_capacity(6)
             .with_prototype(function_prototype)
             .with_builtin_function_property::<ArrayFrom>()
+            .with_builtin_function_property::<ArrayFromAsync>()
             .with_builtin_function_property::<ArrayIsArray>()
             .with_builtin_function_property::<ArrayOf>()
             .with_prototype_property(array_prototype.into_object())
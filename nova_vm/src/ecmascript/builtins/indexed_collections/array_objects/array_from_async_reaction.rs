@@ -0,0 +1,661 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [2.1.1.1 Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+//!
+//! The fromAsyncClosure of Array.fromAsync is an async abstract closure that
+//! awaits once per element. Instead of suspending a VM, its state is kept in
+//! an [`ArrayFromAsyncReaction`] heap record that is used as the handler of
+//! the awaited promises' reactions, and the closure is resumed from the
+//! PromiseReactionJob.
+
+use core::{
+    marker::PhantomData,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    ecmascript::{
+        abstract_operations::{
+            operations_on_iterator_objects::{
+                iterator_close, iterator_complete, iterator_step_value, iterator_value,
+                IteratorRecord,
+            },
+            operations_on_objects::{
+                call, call_function, create_data_property_or_throw, get, get_method, set,
+            },
+        },
+        builtins::{
+            control_abstraction_objects::promise_objects::{
+                promise_abstract_operations::{
+                    promise_capability_records::PromiseCapability,
+                    promise_reaction_records::{PromiseReactionHandler, PromiseReactionType},
+                },
+                promise_prototype::inner_promise_then,
+            },
+            promise::Promise,
+            ArgumentsList,
+        },
+        execution::{
+            agent::{ExceptionType, JsError},
+            Agent, RealmIdentifier,
+        },
+        types::{Function, IntoValue, Number, Object, PropertyKey, Value, BUILTIN_STRING_MEMORY},
+    },
+    engine::{
+        context::{GcScope, NoGcScope},
+        rootable::{HeapRootData, HeapRootRef, Rootable},
+        Scoped,
+    },
+    heap::{CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ArrayFromAsyncReactionIdentifier(u32, PhantomData<ArrayFromAsyncReaction>);
+
+impl ArrayFromAsyncReactionIdentifier {
+    pub(crate) const fn from_index(value: usize) -> Self {
+        assert!(value <= u32::MAX as usize);
+        Self(value as u32, PhantomData)
+    }
+
+    pub(crate) const fn from_u32(value: u32) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn last(reactions: &[Option<ArrayFromAsyncReaction>]) -> Self {
+        let index = reactions.len() - 1;
+        Self::from_index(index)
+    }
+
+    pub(crate) const fn into_index(self) -> usize {
+        self.0 as usize
+    }
+
+    pub(crate) const fn into_u32(self) -> u32 {
+        self.0
+    }
+
+    pub(crate) fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, ArrayFromAsyncReactionIdentifier> {
+        Scoped::new(agent, self, gc)
+    }
+
+    /// Resume the fromAsyncClosure after the promise it awaited settled.
+    pub(crate) fn resume(
+        self,
+        agent: &mut Agent,
+        reaction_type: PromiseReactionType,
+        value: Value,
+        mut gc: GcScope,
+    ) {
+        let value = value.bind(gc.nogc());
+        let reaction = self.scope(agent, gc.nogc());
+        let ArrayFromAsyncReaction { source, state, .. } = agent[self];
+        match (state, reaction_type) {
+            (ArrayFromAsyncState::Start, _) => unreachable!(),
+            (ArrayFromAsyncState::AwaitingClose(error), _) => {
+                // [7.4.13 AsyncIteratorClose ( iteratorRecord, completion )](https://tc39.es/ecma262/#sec-asynciteratorclose)
+                // 5. If completion is a throw completion, return ? completion.
+                reject(agent, &reaction, JsError::new(error));
+            }
+            (ArrayFromAsyncState::AwaitingNextResult, PromiseReactionType::Fulfill) => {
+                on_next_result(agent, &reaction, value.unbind(), gc.reborrow());
+            }
+            (ArrayFromAsyncState::AwaitingValue, PromiseReactionType::Fulfill) => {
+                map_value(agent, &reaction, value.unbind(), gc.reborrow());
+            }
+            (ArrayFromAsyncState::AwaitingMappedValue, PromiseReactionType::Fulfill) => {
+                define_value(agent, &reaction, value.unbind(), gc.reborrow());
+            }
+            (ArrayFromAsyncState::AwaitingValue, PromiseReactionType::Reject)
+                if matches!(source, ArrayFromAsyncSource::SyncIterator(_)) =>
+            {
+                // NOTE: CreateAsyncFromSyncIterator closes the sync iterator
+                // if the awaited value rejects.
+                close_and_reject(
+                    agent,
+                    &reaction,
+                    JsError::new(value.unbind()),
+                    gc.reborrow(),
+                );
+            }
+            (ArrayFromAsyncState::AwaitingMappedValue, PromiseReactionType::Reject) => {
+                // IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+                close_and_reject(
+                    agent,
+                    &reaction,
+                    JsError::new(value.unbind()),
+                    gc.reborrow(),
+                );
+            }
+            (_, PromiseReactionType::Reject) => {
+                reject(agent, &reaction, JsError::new(value.unbind()));
+            }
+        }
+    }
+}
+
+impl Index<ArrayFromAsyncReactionIdentifier> for Agent {
+    type Output = ArrayFromAsyncReaction;
+
+    fn index(&self, index: ArrayFromAsyncReactionIdentifier) -> &Self::Output {
+        &self.heap.array_from_async_reactions[index]
+    }
+}
+
+impl IndexMut<ArrayFromAsyncReactionIdentifier> for Agent {
+    fn index_mut(&mut self, index: ArrayFromAsyncReactionIdentifier) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .array_from_async_reactions
+            .remember(index.into_index());
+        &mut self.heap.array_from_async_reactions[index]
+    }
+}
+
+impl Index<ArrayFromAsyncReactionIdentifier> for Vec<Option<ArrayFromAsyncReaction>> {
+    type Output = ArrayFromAsyncReaction;
+
+    fn index(&self, index: ArrayFromAsyncReactionIdentifier) -> &Self::Output {
+        self.get(index.into_index())
+            .expect("ArrayFromAsyncReactionIdentifier out of bounds")
+            .as_ref()
+            .expect("ArrayFromAsyncReactionIdentifier slot empty")
+    }
+}
+
+impl IndexMut<ArrayFromAsyncReactionIdentifier> for Vec<Option<ArrayFromAsyncReaction>> {
+    fn index_mut(&mut self, index: ArrayFromAsyncReactionIdentifier) -> &mut Self::Output {
+        self.get_mut(index.into_index())
+            .expect("ArrayFromAsyncReactionIdentifier out of bounds")
+            .as_mut()
+            .expect("ArrayFromAsyncReactionIdentifier slot empty")
+    }
+}
+
+impl Rootable for ArrayFromAsyncReactionIdentifier {
+    type RootRepr = HeapRootRef;
+
+    fn to_root_repr(value: Self) -> Result<Self::RootRepr, HeapRootData> {
+        Err(HeapRootData::ArrayFromAsyncReaction(value))
+    }
+
+    fn from_root_repr(value: &Self::RootRepr) -> Result<Self, HeapRootRef> {
+        Err(*value)
+    }
+
+    fn from_heap_ref(heap_ref: HeapRootRef) -> Self::RootRepr {
+        heap_ref
+    }
+
+    fn from_heap_data(heap_data: HeapRootData) -> Option<Self> {
+        if let HeapRootData::ArrayFromAsyncReaction(data) = heap_data {
+            Some(data)
+        } else {
+            None
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncReactionIdentifier {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.array_from_async_reactions.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let self_index = self.into_u32();
+        *self = Self::from_u32(
+            self_index
+                - compactions
+                    .array_from_async_reactions
+                    .get_shift_for_index(self_index),
+        );
+    }
+}
+
+/// The values that Array.fromAsync reads its elements from.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ArrayFromAsyncSource {
+    /// asyncItems is an AsyncIterable.
+    AsyncIterator(IteratorRecord),
+    /// asyncItems is an Iterable. The spec wraps the iterator with
+    /// CreateAsyncFromSyncIterator; we step it directly and await each value.
+    SyncIterator(IteratorRecord),
+    /// asyncItems is neither an AsyncIterable nor an Iterable.
+    ArrayLike {
+        array_like: Object<'static>,
+        len: usize,
+    },
+}
+
+/// The Await that the fromAsyncClosure is currently suspended on.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ArrayFromAsyncState {
+    /// Not yet started.
+    Start,
+    /// Awaiting the result of calling the async iterator's next method.
+    AwaitingNextResult,
+    /// Awaiting an element value of a sync iterator or an array-like.
+    AwaitingValue,
+    /// Awaiting the return value of mapfn.
+    AwaitingMappedValue,
+    /// Awaiting the result of the async iterator's return method; the
+    /// contained error is the completion that the closure returns after.
+    AwaitingClose(Value),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ArrayFromAsyncReaction {
+    /// The realm that the fromAsyncClosure was created in.
+    pub(crate) realm: RealmIdentifier,
+    pub(crate) promise_capability: PromiseCapability,
+    /// A
+    pub(crate) target: Object<'static>,
+    /// mapfn, if mapping is true.
+    pub(crate) mapping: Option<Function<'static>>,
+    pub(crate) this_arg: Value,
+    pub(crate) k: usize,
+    pub(crate) source: ArrayFromAsyncSource,
+    pub(crate) state: ArrayFromAsyncState,
+}
+
+impl CreateHeapData<ArrayFromAsyncReaction, ArrayFromAsyncReactionIdentifier> for Heap {
+    fn create(&mut self, data: ArrayFromAsyncReaction) -> ArrayFromAsyncReactionIdentifier {
+        self.array_from_async_reactions.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ArrayFromAsyncReaction>>();
+        ArrayFromAsyncReactionIdentifier::last(&self.array_from_async_reactions)
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncSource {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::AsyncIterator(iterator_record) | Self::SyncIterator(iterator_record) => {
+                iterator_record.mark_values(queues)
+            }
+            Self::ArrayLike { array_like, len: _ } => array_like.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::AsyncIterator(iterator_record) | Self::SyncIterator(iterator_record) => {
+                iterator_record.sweep_values(compactions)
+            }
+            Self::ArrayLike { array_like, len: _ } => array_like.sweep_values(compactions),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncReaction {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            realm,
+            promise_capability,
+            target,
+            mapping,
+            this_arg,
+            k: _,
+            source,
+            state,
+        } = self;
+        realm.mark_values(queues);
+        promise_capability.mark_values(queues);
+        target.mark_values(queues);
+        mapping.mark_values(queues);
+        this_arg.mark_values(queues);
+        source.mark_values(queues);
+        if let ArrayFromAsyncState::AwaitingClose(error) = state {
+            error.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            realm,
+            promise_capability,
+            target,
+            mapping,
+            this_arg,
+            k: _,
+            source,
+            state,
+        } = self;
+        realm.sweep_values(compactions);
+        promise_capability.sweep_values(compactions);
+        target.sweep_values(compactions);
+        mapping.sweep_values(compactions);
+        this_arg.sweep_values(compactions);
+        source.sweep_values(compactions);
+        if let ArrayFromAsyncState::AwaitingClose(error) = state {
+            error.sweep_values(compactions);
+        }
+    }
+}
+
+/// Run the fromAsyncClosure of Array.fromAsync from the first iteration of
+/// its loop, up to its first Await.
+pub(crate) fn array_from_async_start(agent: &mut Agent, data: ArrayFromAsyncReaction, gc: GcScope) {
+    let reaction = agent.heap.create(data).scope(agent, gc.nogc());
+    step(agent, &reaction, gc);
+}
+
+/// Perform the steps at the start of one iteration of the fromAsyncClosure's
+/// loop.
+fn step(agent: &mut Agent, reaction: &Scoped<ArrayFromAsyncReactionIdentifier>, mut gc: GcScope) {
+    let ArrayFromAsyncReaction { k, source, .. } = agent[reaction.get(agent)];
+    match source {
+        ArrayFromAsyncSource::AsyncIterator(iterator_record) => {
+            // NOTE: The actual max size of an array is u32::MAX
+            // 1. If k ≥ 2**53 - 1, then
+            if k >= u32::MAX as usize {
+                // a. Let error be ThrowCompletion(a newly created TypeError object).
+                let error = agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Maximum array size of 2**53-1 exceeded",
+                    gc.nogc(),
+                );
+                // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                close_and_reject(agent, reaction, error, gc);
+                return;
+            }
+            // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+            let next_result = call(
+                agent,
+                iterator_record.next_method,
+                iterator_record.iterator.into_value(),
+                None,
+                gc.reborrow(),
+            );
+            match next_result {
+                // 4. Set nextResult to ? Await(nextResult).
+                Ok(next_result) => await_value(
+                    agent,
+                    reaction,
+                    ArrayFromAsyncState::AwaitingNextResult,
+                    next_result.unbind(),
+                    gc,
+                ),
+                Err(error) => reject(agent, reaction, error),
+            }
+        }
+        ArrayFromAsyncSource::SyncIterator(mut iterator_record) => {
+            if k >= u32::MAX as usize {
+                let error = agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Maximum array size of 2**53-1 exceeded",
+                    gc.nogc(),
+                );
+                close_and_reject(agent, reaction, error, gc);
+                return;
+            }
+            // %AsyncFromSyncIteratorPrototype%.next: Let result be
+            // Completion(IteratorNext(syncIteratorRecord)), and then
+            // AsyncFromSyncIteratorContinuation(result, promiseCapability).
+            let next = iterator_step_value(agent, &mut iterator_record, gc.reborrow());
+            match next {
+                // If done is true, finish the loop.
+                Ok(None) => finish(agent, reaction, gc),
+                // Let valueWrapper be Completion(PromiseResolve(%Promise%, value)).
+                Ok(Some(value)) => await_value(
+                    agent,
+                    reaction,
+                    ArrayFromAsyncState::AwaitingValue,
+                    value.unbind(),
+                    gc,
+                ),
+                Err(error) => reject(agent, reaction, error),
+            }
+        }
+        ArrayFromAsyncSource::ArrayLike { array_like, len } => {
+            // vii. Repeat, while k < len,
+            if k >= len {
+                finish(agent, reaction, gc);
+                return;
+            }
+            // 1. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::try_from(k).unwrap();
+            // 2. Let kValue be ? Get(arrayLike, Pk).
+            match get(agent, array_like, pk, gc.reborrow()) {
+                // 3. Set kValue to ? Await(kValue).
+                Ok(k_value) => await_value(
+                    agent,
+                    reaction,
+                    ArrayFromAsyncState::AwaitingValue,
+                    k_value.unbind(),
+                    gc,
+                ),
+                Err(error) => reject(agent, reaction, error),
+            }
+        }
+    }
+}
+
+/// Continue the async iterator loop after nextResult was awaited.
+fn on_next_result(
+    agent: &mut Agent,
+    reaction: &Scoped<ArrayFromAsyncReactionIdentifier>,
+    next_result: Value,
+    mut gc: GcScope,
+) {
+    // 5. If nextResult is not an Object, throw a TypeError exception.
+    let Ok(next_result) = Object::try_from(next_result) else {
+        let error = agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "The iterator result was not an object",
+            gc.nogc(),
+        );
+        reject(agent, reaction, error);
+        return;
+    };
+    let scoped_next_result = next_result.scope(agent, gc.nogc());
+    // 6. Let done be ? IteratorComplete(nextResult).
+    let done = match iterator_complete(agent, next_result.unbind(), gc.reborrow()) {
+        Ok(done) => done,
+        Err(error) => {
+            reject(agent, reaction, error);
+            return;
+        }
+    };
+    // 7. If done is true,
+    if done {
+        finish(agent, reaction, gc);
+        return;
+    }
+    // 8. Let nextValue be ? IteratorValue(nextResult).
+    match iterator_value(agent, scoped_next_result.get(agent), gc.reborrow()) {
+        Ok(next_value) => map_value(agent, reaction, next_value.unbind(), gc),
+        Err(error) => reject(agent, reaction, error),
+    }
+}
+
+/// Apply mapfn, if any, to the current element.
+fn map_value(
+    agent: &mut Agent,
+    reaction: &Scoped<ArrayFromAsyncReactionIdentifier>,
+    value: Value,
+    mut gc: GcScope,
+) {
+    let ArrayFromAsyncReaction {
+        mapping,
+        this_arg,
+        k,
+        ..
+    } = agent[reaction.get(agent)];
+    // 9. If mapping is true, then
+    let Some(mapfn) = mapping else {
+        // 10. Else, let mappedValue be nextValue.
+        define_value(agent, reaction, value, gc);
+        return;
+    };
+    // a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+    let fk = Number::try_from(k).unwrap().into_value();
+    let mapped_value = call_function(
+        agent,
+        mapfn,
+        this_arg,
+        Some(ArgumentsList(&[value, fk])),
+        gc.reborrow(),
+    );
+    match mapped_value {
+        // c. Set mappedValue to Await(mappedValue).
+        Ok(mapped_value) => await_value(
+            agent,
+            reaction,
+            ArrayFromAsyncState::AwaitingMappedValue,
+            mapped_value.unbind(),
+            gc,
+        ),
+        // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        Err(error) => close_and_reject(agent, reaction, error, gc),
+    }
+}
+
+/// Define the (mapped) current element on A and continue the loop.
+fn define_value(
+    agent: &mut Agent,
+    reaction: &Scoped<ArrayFromAsyncReactionIdentifier>,
+    mapped_value: Value,
+    mut gc: GcScope,
+) {
+    let ArrayFromAsyncReaction { target, k, .. } = agent[reaction.get(agent)];
+    // 2. Let Pk be ! ToString(𝔽(k)).
+    let pk = PropertyKey::try_from(k).unwrap();
+    // 11. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+    let define_status =
+        create_data_property_or_throw(agent, target, pk, mapped_value, gc.reborrow());
+    if let Err(error) = define_status {
+        // 12. If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+        close_and_reject(agent, reaction, error, gc);
+        return;
+    }
+    // 13. Set k to k + 1.
+    let reaction_id = reaction.get(agent);
+    agent[reaction_id].k += 1;
+    step(agent, reaction, gc);
+}
+
+/// Set the length of A and resolve the closure's promise with it.
+fn finish(agent: &mut Agent, reaction: &Scoped<ArrayFromAsyncReactionIdentifier>, mut gc: GcScope) {
+    let ArrayFromAsyncReaction { target, k, .. } = agent[reaction.get(agent)];
+    // a. Perform ? Set(A, "length", 𝔽(k), true).
+    let result = set(
+        agent,
+        target,
+        BUILTIN_STRING_MEMORY.length.into(),
+        Number::try_from(k).unwrap().into_value(),
+        true,
+        gc.reborrow(),
+    );
+    if let Err(error) = result {
+        reject(agent, reaction, error);
+        return;
+    }
+    // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+    let ArrayFromAsyncReaction {
+        promise_capability,
+        target,
+        ..
+    } = agent[reaction.get(agent)];
+    promise_capability.resolve(agent, target.into_value(), gc);
+}
+
+/// Reject the closure's promise with the given error.
+fn reject(agent: &mut Agent, reaction: &Scoped<ArrayFromAsyncReactionIdentifier>, error: JsError) {
+    let promise_capability = agent[reaction.get(agent)].promise_capability;
+    promise_capability.reject(agent, error.value());
+}
+
+/// Close the iterator, if any, with the given error as the completion, and
+/// reject the closure's promise with the error.
+fn close_and_reject(
+    agent: &mut Agent,
+    reaction: &Scoped<ArrayFromAsyncReactionIdentifier>,
+    error: JsError,
+    mut gc: GcScope,
+) {
+    match agent[reaction.get(agent)].source {
+        ArrayFromAsyncSource::AsyncIterator(iterator_record) => {
+            // ### [7.4.13 AsyncIteratorClose ( iteratorRecord, completion )](https://tc39.es/ecma262/#sec-asynciteratorclose)
+            let error = error.value().scope(agent, gc.nogc());
+            // 2. Let iterator be iteratorRecord.[[Iterator]].
+            let iterator = iterator_record.iterator.into_value();
+            // 3. Let innerResult be Completion(GetMethod(iterator, "return")).
+            let inner_result = get_method(
+                agent,
+                iterator,
+                BUILTIN_STRING_MEMORY.r#return.into(),
+                gc.reborrow(),
+            );
+            // 4. If innerResult is a normal completion, then
+            if let Ok(Some(r#return)) = inner_result {
+                // b. If return is undefined, return ? completion.
+                // c. Set innerResult to Completion(Call(return, iterator)).
+                let iterator = agent[reaction.get(agent)].source;
+                let ArrayFromAsyncSource::AsyncIterator(iterator_record) = iterator else {
+                    unreachable!()
+                };
+                let inner_result = call_function(
+                    agent,
+                    r#return.unbind(),
+                    iterator_record.iterator.into_value(),
+                    None,
+                    gc.reborrow(),
+                );
+                // d. If innerResult is a normal completion, set innerResult to Completion(Await(innerResult.[[Value]])).
+                if let Ok(inner_result) = inner_result {
+                    let error = error.get(agent);
+                    await_value(
+                        agent,
+                        reaction,
+                        ArrayFromAsyncState::AwaitingClose(error),
+                        inner_result.unbind(),
+                        gc,
+                    );
+                    return;
+                }
+            }
+            // 5. If completion is a throw completion, return ? completion.
+            reject(agent, reaction, JsError::new(error.get(agent)));
+        }
+        ArrayFromAsyncSource::SyncIterator(iterator_record) => {
+            // NOTE: %AsyncFromSyncIteratorPrototype%.return calls the sync
+            // iterator's return method; as completion is a throw completion,
+            // its result does not matter.
+            let error = iterator_close::<()>(agent, &iterator_record, Err(error), gc.reborrow())
+                .unwrap_err();
+            reject(agent, reaction, error);
+        }
+        ArrayFromAsyncSource::ArrayLike { .. } => reject(agent, reaction, error),
+    }
+}
+
+/// ### [27.7.5.3 Await ( value )](https://tc39.es/ecma262/#await)
+fn await_value(
+    agent: &mut Agent,
+    reaction: &Scoped<ArrayFromAsyncReactionIdentifier>,
+    state: ArrayFromAsyncState,
+    value: Value,
+    mut gc: GcScope,
+) {
+    let reaction_id = reaction.get(agent);
+    agent[reaction_id].state = state;
+    // 2. Let promise be ? PromiseResolve(%Promise%, value).
+    let promise = Promise::resolve(agent, value, gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 3. Let fulfilledClosure be a new Abstract Closure with parameters (v)
+    //    that captures asyncContext and performs the following steps when
+    //    called:
+    // 5. Let rejectedClosure be a new Abstract Closure with parameters
+    //    (reason) that captures asyncContext and performs the following
+    //    steps when called:
+    let handler = PromiseReactionHandler::ArrayFromAsync(reaction.get(agent));
+    // 7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+    inner_promise_then(agent, promise, handler, handler, None);
+}
@@ -147,6 +147,10 @@ impl Index<ArrayIterator<'_>> for Agent {
 
 impl IndexMut<ArrayIterator<'_>> for Agent {
     fn index_mut(&mut self, index: ArrayIterator) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .array_iterators
+            .remember(index.get_index());
         &mut self.heap.array_iterators[index]
     }
 }
@@ -187,6 +191,7 @@ impl TryFrom<HeapRootData> for ArrayIterator<'_> {
 impl CreateHeapData<ArrayIteratorHeapData, ArrayIterator<'static>> for Heap {
     fn create(&mut self, data: ArrayIteratorHeapData) -> ArrayIterator<'static> {
         self.array_iterators.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ArrayIteratorHeapData>>();
         ArrayIterator(ArrayIteratorIndex::last(&self.array_iterators))
     }
 }
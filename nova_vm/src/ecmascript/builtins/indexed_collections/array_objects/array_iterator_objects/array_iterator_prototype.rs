@@ -175,19 +175,31 @@ impl ArrayIteratorPrototype {
         let len: i64 = match array {
             // i. If array has a [[TypedArrayName]] internal slot, then
             #[cfg(feature = "array-buffer")]
-            Object::Int8Array(array)
-            | Object::Uint8Array(array)
-            | Object::Uint8ClampedArray(array)
-            | Object::Int16Array(array)
-            | Object::Uint16Array(array)
-            | Object::Int32Array(array)
-            | Object::Uint32Array(array)
-            | Object::BigInt64Array(array)
-            | Object::BigUint64Array(array)
-            | Object::Float32Array(array)
-            | Object::Float64Array(array) => handle_typed_array!(array.into()),
+            Object::Int8Array(array) => handle_typed_array!(TypedArray::Int8Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::Uint8Array(array) => handle_typed_array!(TypedArray::Uint8Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::Uint8ClampedArray(array) => {
+                handle_typed_array!(TypedArray::Uint8ClampedArray(array))
+            }
+            #[cfg(feature = "array-buffer")]
+            Object::Int16Array(array) => handle_typed_array!(TypedArray::Int16Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::Uint16Array(array) => handle_typed_array!(TypedArray::Uint16Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::Int32Array(array) => handle_typed_array!(TypedArray::Int32Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::Uint32Array(array) => handle_typed_array!(TypedArray::Uint32Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::BigInt64Array(array) => handle_typed_array!(TypedArray::BigInt64Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::BigUint64Array(array) => handle_typed_array!(TypedArray::BigUint64Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::Float32Array(array) => handle_typed_array!(TypedArray::Float32Array(array)),
+            #[cfg(feature = "array-buffer")]
+            Object::Float64Array(array) => handle_typed_array!(TypedArray::Float64Array(array)),
             #[cfg(feature = "proposal-float16array")]
-            Object::Float16Array(array) => handle_typed_array!(array.into()),
+            Object::Float16Array(array) => handle_typed_array!(TypedArray::Float16Array(array)),
             // ii. Else,
             //     1. Let len be ? LengthOfArrayLike(array).
             Object::Array(array) => array.len(agent).into(),
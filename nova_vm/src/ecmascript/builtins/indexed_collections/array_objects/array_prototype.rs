@@ -40,7 +40,7 @@ use crate::{
             Value, BUILTIN_STRING_MEMORY,
         },
     },
-    heap::{Heap, IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
+    heap::{heap_gc::gc_safepoint, Heap, IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
     SmallInteger,
 };
 
@@ -814,6 +814,7 @@ impl ArrayPrototype {
                     len
                 };
 
+                agent.use_fuel(final_end.saturating_sub(k) as u64, gc.nogc())?;
                 let data = array.as_mut_slice(agent);
                 data[k..final_end].fill(Some(value));
                 return Ok(value.into_value());
@@ -856,6 +857,7 @@ impl ArrayPrototype {
 
         // 11. Repeat, while k < final,
         while k < final_end {
+            agent.use_fuel(1, gc.nogc())?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Perform ? Set(O, Pk, value, true).
@@ -908,7 +910,7 @@ impl ArrayPrototype {
         mut gc: GcScope,
     ) -> JsResult<Value> {
         let callback_fn = arguments.get(0);
-        let this_arg = arguments.get(1);
+        let this_arg = arguments.get(1).scope(agent, gc.nogc());
 
         // 1. Let O be ? ToObject(this value).
         let o = to_object(agent, this_value, gc.nogc())?.scope(agent, gc.nogc());
@@ -931,8 +933,10 @@ impl ArrayPrototype {
         let mut k = 0;
         // 6. Let to be 0.
         let mut to: u32 = 0;
+        let mut scoped_k_value = Value::Undefined.scope(agent, gc.nogc());
         // 7. Repeat, while k < len,
         while k < len {
+            gc_safepoint(agent, gc.reborrow());
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::from(SmallInteger::try_from(k).unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -941,11 +945,12 @@ impl ArrayPrototype {
             if k_present {
                 // i. Let kValue be ? Get(O, Pk).
                 let k_value = get(agent, o.get(agent), pk, gc.reborrow())?;
+                scoped_k_value.replace(agent, k_value);
                 // ii. Let selected be ToBoolean(? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »)).
                 let result = call_function(
                     agent,
                     callback_fn.get(agent),
-                    this_arg,
+                    this_arg.get(agent),
                     Some(ArgumentsList(&[
                         k_value,
                         k.try_into().unwrap(),
@@ -961,7 +966,7 @@ impl ArrayPrototype {
                         agent,
                         a.get(agent),
                         to.into(),
-                        k_value,
+                        scoped_k_value.get(agent),
                         gc.reborrow(),
                     )?;
                     // 2. Set to to to + 1.
@@ -1325,22 +1330,20 @@ impl ArrayPrototype {
                 0
             };
             let data = &array.as_slice(agent)[k..];
-            let mut found_hole = false;
-            for element_k in data {
-                if let Some(element_k) = element_k {
-                    if same_value_zero(agent, search_element, *element_k) {
-                        return Ok(true.into());
-                    }
-                } else {
-                    // A hole would require looking through the prototype
-                    // chain. We're not going to do that.
-                    found_hole = true;
-                    break;
-                }
-            }
-            if !found_hole {
+            let position = data.iter().position(|element_k| match element_k {
+                Some(element_k) => same_value_zero(agent, search_element, *element_k),
+                // A hole would require looking through the prototype
+                // chain. We're not going to do that.
+                None => true,
+            });
+            let found = position.map(|position| data[position].is_some());
+            let scanned = position.map_or(data.len(), |position| position + 1);
+            agent.use_fuel(scanned as u64, gc.nogc())?;
+            match found {
+                Some(true) => return Ok(true.into()),
                 // No holes found so we can trust the result.
-                return Ok(false.into());
+                None => return Ok(false.into()),
+                Some(false) => {}
             }
         };
         // 1. Let O be ? ToObject(this value).
@@ -1384,6 +1387,7 @@ impl ArrayPrototype {
         };
         // 10. Repeat, while k < len,
         while k < len {
+            agent.use_fuel(1, gc.nogc())?;
             // a. Let elementK be ? Get(O, ! ToString(𝔽(k))).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             let element_k = get(agent, o.get(agent), pk, gc.reborrow())?;
@@ -1450,22 +1454,20 @@ impl ArrayPrototype {
                 0
             };
             let data = &array.as_slice(agent)[k..];
-            let mut found_hole = false;
-            for (index, element_k) in data.iter().enumerate() {
-                if let Some(element_k) = element_k {
-                    if is_strictly_equal(agent, search_element, *element_k) {
-                        return Ok((k as u32 + index as u32).into());
-                    }
-                } else {
-                    // A hole would require looking through the prototype
-                    // chain. We're not going to do that.
-                    found_hole = true;
-                    break;
-                }
-            }
-            if !found_hole {
+            let position = data.iter().position(|element_k| match element_k {
+                Some(element_k) => is_strictly_equal(agent, search_element, *element_k),
+                // A hole would require looking through the prototype
+                // chain. We're not going to do that.
+                None => true,
+            });
+            let found = position.map(|position| data[position].is_some());
+            let scanned = position.map_or(data.len(), |position| position + 1);
+            agent.use_fuel(scanned as u64, gc.nogc())?;
+            match (found, position) {
+                (Some(true), Some(index)) => return Ok((k as u32 + index as u32).into()),
                 // No holes found so we can trust the result.
-                return Ok((-1).into());
+                (None, _) => return Ok((-1).into()),
+                _ => {}
             }
         };
         // 1. Let O be ? ToObject(this value).
@@ -1509,6 +1511,7 @@ impl ArrayPrototype {
         };
         // 10. Repeat, while k < len,
         while k < len {
+            agent.use_fuel(1, gc.nogc())?;
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -1567,6 +1570,7 @@ impl ArrayPrototype {
         // 7. Repeat, while k < len,
         // b. Let element be ? Get(O, ! ToString(𝔽(k))).
         {
+            agent.use_fuel(1, gc.nogc())?;
             let element = get(agent, o.get(agent), 0.into(), gc.reborrow())?;
             // c. If element is neither undefined nor null, then
             if !element.is_undefined() && !element.is_null() {
@@ -1577,6 +1581,8 @@ impl ArrayPrototype {
             }
         }
         for k in 1..len {
+            gc_safepoint(agent, gc.reborrow());
+            agent.use_fuel(1, gc.nogc())?;
             // a. If k > 0, set R to the string-concatenation of R and sep.
             r.push_str(separator.get(agent).as_str(agent));
             // b. Let element be ? Get(O, ! ToString(𝔽(k))).
@@ -1785,7 +1791,7 @@ impl ArrayPrototype {
         mut gc: GcScope,
     ) -> JsResult<Value> {
         let callback_fn = arguments.get(0);
-        let this_arg = arguments.get(1);
+        let this_arg = arguments.get(1).scope(agent, gc.nogc());
 
         // 1. Let O be ? ToObject(this value).
         let o = to_object(agent, this_value, gc.nogc())?.scope(agent, gc.nogc());
@@ -1808,6 +1814,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 6. Repeat, while k < len,
         while k < len {
+            gc_safepoint(agent, gc.reborrow());
             // a. Let Pk be ! ToString(𝔽(k)).
             let pk = PropertyKey::Integer(k.try_into().unwrap());
             // b. Let kPresent be ? HasProperty(O, Pk).
@@ -1820,7 +1827,7 @@ impl ArrayPrototype {
                 let mapped_value = call_function(
                     agent,
                     callback_fn.get(agent),
-                    this_arg,
+                    this_arg.get(agent),
                     Some(ArgumentsList(&[
                         k_value,
                         k.try_into().unwrap(),
@@ -1975,6 +1982,15 @@ impl ArrayPrototype {
         }
         if let Object::Array(array) = o.get(agent) {
             // Fast path: Reserve enough room in the array.
+            let bytes = agent[array]
+                .elements
+                .reserve_bytes(len as u32 + arg_count as u32);
+            agent.reserve_heap_bytes(bytes, gc.nogc())?;
+            agent
+                .heap
+                .remembered_sets
+                .arrays
+                .remember(array.get_index());
             let Heap {
                 arrays, elements, ..
             } = &mut agent.heap;
@@ -2904,9 +2920,9 @@ impl ArrayPrototype {
         //       a. Return ? CompareArrayElements(x, y, comparator).
         // 5. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare,
         //     skip-holes).
-        let sorted_list: Vec<Value> = sort_indexed_properties::<true, false>(
+        let sorted_list = sort_indexed_properties::<true, false>(
             agent,
-            obj.get(agent),
+            obj.clone(),
             len,
             comparator,
             gc.reborrow(),
@@ -2922,7 +2938,7 @@ impl ArrayPrototype {
                 agent,
                 obj.get(agent),
                 j.try_into().unwrap(),
-                sorted_list[j],
+                sorted_list[j].get(agent),
                 true,
                 gc.reborrow(),
             )?;
@@ -3006,6 +3022,7 @@ impl ArrayPrototype {
         let mut k = 0;
         // 14. Repeat, while k < actualDeleteCount,
         while k < actual_delete_count {
+            agent.use_fuel(1, gc.nogc())?;
             //     a. Let from be ! ToString(𝔽(actualStart + k)).
             let from = (actual_start + k).try_into().unwrap();
             //     b. If ? HasProperty(O, from) is true, then
@@ -3040,6 +3057,7 @@ impl ArrayPrototype {
                 k = actual_start;
                 //     b. Repeat, while k < (len - actualDeleteCount),
                 while k < (len as usize - actual_delete_count) {
+                    agent.use_fuel(1, gc.nogc())?;
                     //     i. Let from be ! ToString(𝔽(k + actualDeleteCount)).
                     let from = (k + actual_delete_count).try_into().unwrap();
                     //     ii. Let to be ! ToString(𝔽(k + itemCount)).
@@ -3062,6 +3080,7 @@ impl ArrayPrototype {
                 k = len as usize;
                 //     d. Repeat, while k > (len - actualDeleteCount + itemCount),
                 while k > (len as usize - actual_delete_count + item_count) {
+                    agent.use_fuel(1, gc.nogc())?;
                     //     i. Perform ? DeletePropertyOrThrow(O, ! ToString(𝔽(k - 1))).
                     delete_property_or_throw(
                         agent,
@@ -3079,6 +3098,7 @@ impl ArrayPrototype {
                 k = len as usize - actual_delete_count;
                 //     b. Repeat, while k > actualStart,
                 while k > actual_start {
+                    agent.use_fuel(1, gc.nogc())?;
                     //     i. Let from be ! ToString(𝔽(k + actualDeleteCount - 1)).
                     let from = (k + actual_delete_count - 1).try_into().unwrap();
                     //     ii. Let to be ! ToString(𝔽(k + itemCount - 1)).
@@ -3104,6 +3124,7 @@ impl ArrayPrototype {
         k = actual_start;
         // 19. For each element E of items, do
         for e in items {
+            agent.use_fuel(1, gc.nogc())?;
             //     a. Perform ? Set(O, ! ToString(𝔽(k)), E, true).
             set(
                 agent,
@@ -3223,26 +3244,20 @@ impl ArrayPrototype {
         //     called:
         //       a. Return ? CompareArrayElements(x, y, comparator).
         // 6. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
-        let sorted_list: Vec<Value> = sort_indexed_properties::<false, false>(
-            agent,
-            o.get(agent),
-            len,
-            comparator,
-            gc.reborrow(),
-        )?;
+        let sorted_list =
+            sort_indexed_properties::<false, false>(agent, o, len, comparator, gc.reborrow())?;
         // 7. Let j be 0.
         // 8. Repeat, while j < len,
         //      a. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(j)), sortedList[j]).
         //      b. Set j to j + 1.
         // Fast path: Copy sorted items directly into array.
+        let sorted_list = sorted_list
+            .iter()
+            .map(|value| Some(value.get(agent)))
+            .collect::<Vec<Option<Value>>>();
         let a = a.get(agent);
         let slice = a.as_mut_slice(agent);
-        slice.copy_from_slice(
-            &sorted_list
-                .into_iter()
-                .map(Some)
-                .collect::<Vec<Option<Value>>>()[..],
-        );
+        slice.copy_from_slice(&sorted_list[..]);
         // 9. Return A.
         Ok(a.into())
     }
@@ -3319,6 +3334,11 @@ impl ArrayPrototype {
                 && array.length_writable(agent)
             {
                 // Fast path: Reserve enough room in the array and set array length.
+                agent
+                    .heap
+                    .remembered_sets
+                    .arrays
+                    .remember(array.get_index());
                 let Heap {
                     arrays, elements, ..
                 } = &mut agent.heap;
@@ -3690,7 +3710,7 @@ fn is_concat_spreadable(agent: &mut Agent, o: Value, mut gc: GcScope) -> JsResul
 /// the time that this operation visits them. Elements that are deleted after
 /// traversal begins and before being visited are still visited and are either
 /// looked up from the prototype or are undefined.
-fn find_via_predicate(
+pub(crate) fn find_via_predicate(
     agent: &mut Agent,
     o: Scoped<'_, Object<'static>>,
     len: i64,
@@ -3721,6 +3741,7 @@ fn find_via_predicate(
         // b. NOTE: If O is a TypedArray, the following invocation of Get will return a normal completion.
         // c. Let kValue be ? Get(O, Pk).
         let k_value = get(agent, o.get(agent), pk, gc.reborrow())?;
+        let scoped_k_value = k_value.scope(agent, gc.nogc());
 
         // d. Let testResult be ? Call(predicate, thisArg, « kValue, 𝔽(k), O »).
         let test_result = call_function(
@@ -3728,6 +3749,7 @@ fn find_via_predicate(
             predicate.get(agent),
             this_arg,
             Some(ArgumentsList(&[
+                k_value,
                 Number::try_from(k).unwrap().into_value(),
                 o.get(agent).into_value(),
             ])),
@@ -3735,7 +3757,7 @@ fn find_via_predicate(
         )?;
         // e. If ToBoolean(testResult) is true, return the Record { [[Index]]: 𝔽(k), [[Value]]: kValue }.
         if to_boolean(agent, test_result) {
-            Ok(Some((k, k_value)))
+            Ok(Some((k, scoped_k_value.get(agent))))
         } else {
             Ok(None)
         }
@@ -3943,25 +3965,28 @@ fn flatten_into_array(
 /// > The above conditions are necessary and sufficient to ensure that
 /// > comparator divides the set S into equivalence classes and that these
 /// > equivalence classes are totally ordered.
-fn sort_indexed_properties<const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
+fn sort_indexed_properties<'scope, const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
     agent: &mut Agent,
-    obj: Object,
+    obj: Scoped<'scope, Object<'static>>,
     len: usize,
-    comparator: Option<Scoped<'_, Function<'static>>>,
-    mut gc: GcScope,
-) -> JsResult<Vec<Value>> {
+    comparator: Option<Scoped<'scope, Function<'static>>>,
+    mut gc: GcScope<'_, 'scope>,
+) -> JsResult<Vec<Scoped<'scope, Value>>> {
+    // Note: The items are scoped as getters and the comparator may trigger
+    // garbage collection.
     // 1. Let items be a new empty List.
     let mut items = Vec::with_capacity(len);
     // 2. Let k be 0.
     let mut k = 0;
     // 3. Repeat, while k < len,
     while k < len {
+        agent.use_fuel(1, gc.nogc())?;
         // a. Let Pk be ! ToString(𝔽(k)).
         let pk: PropertyKey<'static> = k.try_into().unwrap();
         // b. If holes is skip-holes, then
         let k_read = if SKIP_HOLES {
             // i. Let kRead be ? HasProperty(obj, Pk).
-            has_property(agent, obj, pk, gc.reborrow())?
+            has_property(agent, obj.get(agent), pk, gc.reborrow())?
         } else {
             // c. Else,
             // i. Assert: holes is read-through-holes.
@@ -3971,9 +3996,9 @@ fn sort_indexed_properties<const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
         // d. If kRead is true, then
         if k_read {
             // i. Let kValue be ? Get(obj, Pk).
-            let k_value = get(agent, obj, pk, gc.reborrow())?;
+            let k_value = get(agent, obj.get(agent), pk, gc.reborrow())?;
             // ii. Append kValue to items.
-            items.push(k_value);
+            items.push(k_value.scope(agent, gc.nogc()));
         }
         // e. Set k to k + 1.
         k += 1;
@@ -3991,7 +4016,17 @@ fn sort_indexed_properties<const SKIP_HOLES: bool, const TYPED_ARRAY: bool>(
                 // This is dangerous but we don't have much of a choice.
                 return Ordering::Equal;
             }
-            let result = compare_array_elements(agent, *a, *b, comparator.clone(), gc.reborrow());
+            if let Err(err) = agent.use_fuel(1, gc.nogc()) {
+                error = Some(err);
+                return Ordering::Equal;
+            }
+            let result = compare_array_elements(
+                agent,
+                a.get(agent),
+                b.get(agent),
+                comparator.clone(),
+                gc.reborrow(),
+            );
             let Ok(result) = result else {
                 error = Some(result.unwrap_err());
                 return Ordering::Equal;
@@ -5,7 +5,9 @@
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_objects::{get, length_of_array_like, set, try_set},
+            operations_on_objects::{
+                construct, get, length_of_array_like, set, species_constructor, try_set,
+            },
             type_conversion::{to_big_int, to_index, to_number},
         },
         builtins::{
@@ -20,12 +22,12 @@ use crate::{
                 data::{TypedArrayArrayLength, TypedArrayHeapData},
                 TypedArray,
             },
-            ArrayBuffer,
+            ArgumentsList, ArrayBuffer,
         },
         execution::{agent::ExceptionType, Agent, JsResult, ProtoIntrinsics},
         types::{
-            BigInt, Function, InternalSlots, IntoFunction, IntoNumeric, IntoObject, Number,
-            Numeric, Object, PropertyKey, U8Clamped, Value, Viewable,
+            BigInt, Function, InternalSlots, IntoFunction, IntoNumeric, IntoObject, IntoValue,
+            Number, Numeric, Object, PropertyKey, U8Clamped, Value, Viewable,
         },
     },
     engine::{
@@ -649,6 +651,290 @@ fn typed_array_set_element_internal<O: Viewable>(
     }
 }
 
+/// ### [23.2.4.1 TypedArraySpeciesCreate ( exemplar, argumentList )](https://tc39.es/ecma262/#typedarray-species-create)
+///
+/// The abstract operation TypedArraySpeciesCreate takes arguments exemplar (a
+/// TypedArray) and argumentList (a List of ECMAScript language values) and
+/// returns either a normal completion containing a TypedArray or a throw
+/// completion. It is used to specify the creation of a new TypedArray using a
+/// constructor function that is derived from exemplar. Unlike
+/// ArraySpeciesCreate, which can create non-Array objects through the use of
+/// %Symbol.species%, this operation enforces that the constructor function
+/// creates an actual TypedArray.
+///
+/// This variant is used when argumentList is « 𝔽(length) ».
+pub(crate) fn typed_array_species_create_with_length<'a>(
+    agent: &mut Agent,
+    exemplar: TypedArray,
+    length: usize,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<TypedArray<'a>> {
+    let exemplar = exemplar.bind(gc.nogc());
+    let exemplar_is_bigint = is_big_int_typed_array(exemplar);
+    // 1. Let defaultConstructor be the intrinsic object associated with the
+    //    constructor name exemplar.[[TypedArrayName]] in Table 71.
+    let default_constructor = intrinsic_default_constructor(agent, exemplar);
+    // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
+    let constructor = species_constructor(
+        agent,
+        exemplar.into_object().unbind(),
+        default_constructor,
+        gc.reborrow(),
+    )?
+    .unbind()
+    .bind(gc.nogc());
+    // 3. Let result be ? TypedArrayCreateFromConstructor(constructor, argumentList).
+    let result = typed_array_create_from_constructor_with_length(
+        agent,
+        constructor.unbind(),
+        length,
+        gc.reborrow(),
+    )?
+    .unbind();
+    let gc = gc.into_nogc();
+    let result = result.bind(gc);
+    // 4. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+    // 5. If result.[[ContentType]] is not exemplar.[[ContentType]], throw a TypeError exception.
+    if is_big_int_typed_array(result) != exemplar_is_bigint {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray species constructor returned a TypedArray of different content type",
+            gc,
+        ));
+    }
+    // 6. Return result.
+    Ok(result)
+}
+
+/// ### [23.2.4.1 TypedArraySpeciesCreate ( exemplar, argumentList )](https://tc39.es/ecma262/#typedarray-species-create)
+///
+/// The abstract operation TypedArraySpeciesCreate takes arguments exemplar (a
+/// TypedArray) and argumentList (a List of ECMAScript language values) and
+/// returns either a normal completion containing a TypedArray or a throw
+/// completion.
+///
+/// This variant is used when argumentList is « buffer, 𝔽(byteOffset) » or
+/// « buffer, 𝔽(byteOffset), 𝔽(length) ».
+pub(crate) fn typed_array_species_create_with_buffer<'a>(
+    agent: &mut Agent,
+    exemplar: TypedArray,
+    buffer: ArrayBuffer,
+    byte_offset: usize,
+    length: Option<usize>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<TypedArray<'a>> {
+    let exemplar = exemplar.bind(gc.nogc());
+    let buffer = buffer.bind(gc.nogc()).scope(agent, gc.nogc());
+    let exemplar_is_bigint = is_big_int_typed_array(exemplar);
+    // 1. Let defaultConstructor be the intrinsic object associated with the
+    //    constructor name exemplar.[[TypedArrayName]] in Table 71.
+    let default_constructor = intrinsic_default_constructor(agent, exemplar);
+    // 2. Let constructor be ? SpeciesConstructor(exemplar, defaultConstructor).
+    let constructor = species_constructor(
+        agent,
+        exemplar.into_object().unbind(),
+        default_constructor,
+        gc.reborrow(),
+    )?
+    .unbind()
+    .bind(gc.nogc());
+    // 3. Let result be ? TypedArrayCreateFromConstructor(constructor, argumentList).
+    let result = typed_array_create_from_constructor_with_buffer(
+        agent,
+        constructor.unbind(),
+        buffer.get(agent),
+        byte_offset,
+        length,
+        gc.reborrow(),
+    )?
+    .unbind();
+    let gc = gc.into_nogc();
+    let result = result.bind(gc);
+    // 4. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+    // 5. If result.[[ContentType]] is not exemplar.[[ContentType]], throw a TypeError exception.
+    if is_big_int_typed_array(result) != exemplar_is_bigint {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray species constructor returned a TypedArray of different content type",
+            gc,
+        ));
+    }
+    // 6. Return result.
+    Ok(result)
+}
+
+/// ### [23.2.4.2 TypedArrayCreateFromConstructor ( constructor, argumentList )](https://tc39.es/ecma262/#sec-typedarraycreatefromconstructor)
+///
+/// The abstract operation TypedArrayCreateFromConstructor takes arguments
+/// constructor (a constructor) and argumentList (a List of ECMAScript
+/// language values) and returns either a normal completion containing a
+/// TypedArray or a throw completion. It is used to specify the creation of a
+/// new TypedArray using a constructor function.
+///
+/// This variant is used when argumentList is « 𝔽(length) ».
+pub(crate) fn typed_array_create_from_constructor_with_length<'a>(
+    agent: &mut Agent,
+    constructor: Function,
+    length: usize,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<TypedArray<'a>> {
+    let constructor = constructor.bind(gc.nogc());
+    // 1. Let newTypedArray be ? Construct(constructor, argumentList).
+    let new_typed_array = construct(
+        agent,
+        constructor.unbind(),
+        Some(ArgumentsList(&[Value::try_from(length as i64).unwrap()])),
+        None,
+        gc.reborrow(),
+    )?
+    .unbind();
+    let gc = gc.into_nogc();
+    let new_typed_array = new_typed_array.bind(gc);
+    // 2. Let taRecord be ? ValidateTypedArray(newTypedArray, seq-cst).
+    let ta_record =
+        validate_typed_array(agent, new_typed_array.into_value(), Ordering::SeqCst, gc)?;
+    let o = ta_record.object;
+    // 3. If the number of elements in argumentList is 1 and argumentList[0] is a Number, then
+    // a. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    // NOTE: ValidateTypedArray already checked this.
+    // b. Let length be TypedArrayLength(taRecord).
+    let new_length = match o {
+        TypedArray::Int8Array(_) | TypedArray::Uint8Array(_) | TypedArray::Uint8ClampedArray(_) => {
+            typed_array_length::<u8>(agent, &ta_record, gc)
+        }
+        TypedArray::Int16Array(_) | TypedArray::Uint16Array(_) => {
+            typed_array_length::<u16>(agent, &ta_record, gc)
+        }
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => typed_array_length::<f16>(agent, &ta_record, gc),
+        TypedArray::Int32Array(_) | TypedArray::Uint32Array(_) | TypedArray::Float32Array(_) => {
+            typed_array_length::<u32>(agent, &ta_record, gc)
+        }
+        TypedArray::BigInt64Array(_)
+        | TypedArray::BigUint64Array(_)
+        | TypedArray::Float64Array(_) => typed_array_length::<u64>(agent, &ta_record, gc),
+    };
+    // c. If length < ℝ(argumentList[0]), throw a TypeError exception.
+    if new_length < length {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray constructor returned a TypedArray that is too short",
+            gc,
+        ));
+    }
+    // 4. Return newTypedArray.
+    Ok(o)
+}
+
+/// ### [23.2.4.2 TypedArrayCreateFromConstructor ( constructor, argumentList )](https://tc39.es/ecma262/#sec-typedarraycreatefromconstructor)
+///
+/// The abstract operation TypedArrayCreateFromConstructor takes arguments
+/// constructor (a constructor) and argumentList (a List of ECMAScript
+/// language values) and returns either a normal completion containing a
+/// TypedArray or a throw completion.
+///
+/// This variant is used when argumentList is « buffer, 𝔽(byteOffset) » or
+/// « buffer, 𝔽(byteOffset), 𝔽(length) ».
+pub(crate) fn typed_array_create_from_constructor_with_buffer<'a>(
+    agent: &mut Agent,
+    constructor: Function,
+    buffer: ArrayBuffer,
+    byte_offset: usize,
+    length: Option<usize>,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<TypedArray<'a>> {
+    let constructor = constructor.bind(gc.nogc());
+    let buffer = buffer.bind(gc.nogc());
+    let byte_offset = Value::try_from(byte_offset as i64).unwrap();
+    // 1. Let newTypedArray be ? Construct(constructor, argumentList).
+    let new_typed_array = if let Some(length) = length {
+        let length = Value::try_from(length as i64).unwrap();
+        construct(
+            agent,
+            constructor.unbind(),
+            Some(ArgumentsList(&[buffer.into_value(), byte_offset, length])),
+            None,
+            gc.reborrow(),
+        )?
+    } else {
+        construct(
+            agent,
+            constructor.unbind(),
+            Some(ArgumentsList(&[buffer.into_value(), byte_offset])),
+            None,
+            gc.reborrow(),
+        )?
+    }
+    .unbind();
+    let gc = gc.into_nogc();
+    let new_typed_array = new_typed_array.bind(gc);
+    // 2. Let taRecord be ? ValidateTypedArray(newTypedArray, seq-cst).
+    let ta_record =
+        validate_typed_array(agent, new_typed_array.into_value(), Ordering::SeqCst, gc)?;
+    // 3. If the number of elements in argumentList is 1 and argumentList[0] is a Number, then
+    // NOTE: argumentList has more than 1 element.
+    // 4. Return newTypedArray.
+    Ok(ta_record.object)
+}
+
+/// ### [23.2.4.3 TypedArrayCreateSameType ( exemplar, argumentList )](https://tc39.es/ecma262/#sec-typedarray-create-same-type)
+///
+/// The abstract operation TypedArrayCreateSameType takes arguments exemplar
+/// (a TypedArray) and argumentList (a List of ECMAScript language values) and
+/// returns either a normal completion containing a TypedArray or a throw
+/// completion. It is used to specify the creation of a new TypedArray object
+/// using a constructor function that is derived from exemplar. Unlike
+/// TypedArraySpeciesCreate, which can construct custom TypedArray subclasses
+/// through the use of %Symbol.species%, this operation always uses one of the
+/// built-in TypedArray constructors.
+///
+/// This variant is used when argumentList is « 𝔽(length) ».
+pub(crate) fn typed_array_create_same_type<'a>(
+    agent: &mut Agent,
+    exemplar: TypedArray,
+    length: usize,
+    gc: GcScope<'a, '_>,
+) -> JsResult<TypedArray<'a>> {
+    // 1. Let constructor be the intrinsic object associated with the
+    //    constructor name exemplar.[[TypedArrayName]] in Table 71.
+    let constructor = intrinsic_default_constructor(agent, exemplar);
+    // 2. Let result be ? TypedArrayCreateFromConstructor(constructor, argumentList).
+    // 3. Assert: result has [[TypedArrayName]] and [[ContentType]] internal slots.
+    // 4. Assert: result.[[ContentType]] is exemplar.[[ContentType]].
+    // 5. Return result.
+    typed_array_create_from_constructor_with_length(agent, constructor, length, gc)
+}
+
+/// Returns the intrinsic TypedArray constructor associated with the
+/// \[\[TypedArrayName]] of the given TypedArray in Table 71.
+fn intrinsic_default_constructor(agent: &Agent, exemplar: TypedArray) -> Function<'static> {
+    let intrinsics = agent.current_realm().intrinsics();
+    match exemplar {
+        TypedArray::Int8Array(_) => intrinsics.int8_array(),
+        TypedArray::Uint8Array(_) => intrinsics.uint8_array(),
+        TypedArray::Uint8ClampedArray(_) => intrinsics.uint8_clamped_array(),
+        TypedArray::Int16Array(_) => intrinsics.int16_array(),
+        TypedArray::Uint16Array(_) => intrinsics.uint16_array(),
+        TypedArray::Int32Array(_) => intrinsics.int32_array(),
+        TypedArray::Uint32Array(_) => intrinsics.uint32_array(),
+        TypedArray::BigInt64Array(_) => intrinsics.big_int64_array(),
+        TypedArray::BigUint64Array(_) => intrinsics.big_uint64_array(),
+        #[cfg(feature = "proposal-float16array")]
+        TypedArray::Float16Array(_) => intrinsics.float16_array(),
+        TypedArray::Float32Array(_) => intrinsics.float32_array(),
+        TypedArray::Float64Array(_) => intrinsics.float64_array(),
+    }
+    .into_function()
+}
+
+/// Returns true if the \[\[ContentType]] of the given TypedArray is bigint.
+pub(crate) fn is_big_int_typed_array(o: TypedArray) -> bool {
+    matches!(
+        o,
+        TypedArray::BigInt64Array(_) | TypedArray::BigUint64Array(_)
+    )
+}
+
 /// ### [23.2.4.4 ValidateTypedArray ( O, order )](https://tc39.es/ecma262/#sec-validatetypedarray)
 ///
 /// The abstract operation ValidateTypedArray takes arguments O (an ECMAScript
@@ -784,7 +1070,7 @@ pub(crate) fn initialize_typed_array_from_typed_array<O: Viewable, Src: Viewable
     }
 
     // 9. Let elementLength be TypedArrayLength(srcRecord).
-    let element_length = typed_array_length::<O>(agent, &src_record, gc);
+    let element_length = typed_array_length::<Src>(agent, &src_record, gc);
 
     // 10. Let byteLength be elementSize × elementLength.
     let byte_length = element_size * element_length;
@@ -888,6 +1174,8 @@ pub(crate) fn initialize_typed_array_from_array_buffer<T: Viewable>(
     let scoped_o = o.scope(agent, gc.nogc());
     let buffer = buffer.bind(gc.nogc());
     let scoped_buffer = buffer.scope(agent, gc.nogc());
+    // Note: An explicitly passed undefined length is the same as no length.
+    let length = length.filter(|length| !length.is_undefined());
     // 1. Let elementSize be TypedArrayElementSize(O).
     let element_size = size_of::<T>();
 
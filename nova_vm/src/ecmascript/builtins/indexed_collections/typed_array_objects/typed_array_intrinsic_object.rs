@@ -5,11 +5,16 @@
 use crate::{
     ecmascript::{
         abstract_operations::{
-            operations_on_objects::{call_function, try_get},
-            testing_and_comparison::{is_array, is_callable, same_value_zero},
+            operations_on_iterator_objects::{get_iterator_from_method, iterator_to_list},
+            operations_on_objects::{
+                call_function, get, get_method, invoke, length_of_array_like, try_get,
+            },
+            testing_and_comparison::{
+                is_array, is_callable, is_constructor, is_strictly_equal, same_value_zero,
+            },
             type_conversion::{
-                to_boolean, to_integer_or_infinity, to_string, try_to_integer_or_infinity,
-                try_to_string,
+                to_big_int, to_boolean, to_integer_or_infinity, to_number, to_object, to_string,
+                try_to_integer_or_infinity, try_to_string, IntegerOrInfinity,
             },
         },
         builders::{
@@ -17,9 +22,12 @@ use crate::{
             ordinary_object_builder::OrdinaryObjectBuilder,
         },
         builtins::{
-            array_buffer::{get_value_from_buffer, is_detached_buffer, Ordering},
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::{
-                ArrayIterator, CollectionIteratorKind,
+            array_buffer::{
+                get_value_from_buffer, numeric_to_raw_bytes, raw_bytes_to_numeric, Ordering,
+            },
+            indexed_collections::array_objects::{
+                array_iterator_objects::array_iterator::{ArrayIterator, CollectionIteratorKind},
+                array_prototype::find_via_predicate,
             },
             typed_array::TypedArray,
             ArgumentsList, Behaviour, Builtin, BuiltinGetter, BuiltinIntrinsic,
@@ -27,24 +35,89 @@ use crate::{
         },
         execution::{agent::ExceptionType, Agent, JsResult, RealmIdentifier},
         types::{
-            IntoObject, IntoValue, Number, Object, PropertyKey, String, U8Clamped, Value,
-            BUILTIN_STRING_MEMORY,
+            BigInt, Function, IntoNumeric, IntoObject, IntoValue, Number, Numeric, Object,
+            PropertyKey, String, U8Clamped, Value, Viewable, BUILTIN_STRING_MEMORY,
         },
     },
     engine::{
         context::{GcScope, NoGcScope},
-        unwrap_try, TryResult,
+        unwrap_try, Scoped, TryResult,
     },
     heap::{IntrinsicConstructorIndexes, IntrinsicFunctionIndexes, WellKnownSymbolIndexes},
     SmallInteger,
 };
 
+use super::abstract_operations::is_big_int_typed_array;
 use super::abstract_operations::is_typed_array_out_of_bounds;
 use super::abstract_operations::make_typed_array_with_buffer_witness_record;
+use super::abstract_operations::try_typed_array_set_element_generic;
 use super::abstract_operations::typed_array_byte_length;
+use super::abstract_operations::typed_array_create_from_constructor_with_length;
+use super::abstract_operations::typed_array_create_same_type;
 use super::abstract_operations::typed_array_length;
+use super::abstract_operations::typed_array_set_element_generic;
+use super::abstract_operations::typed_array_species_create_with_buffer;
+use super::abstract_operations::typed_array_species_create_with_length;
 use super::abstract_operations::validate_typed_array;
 
+/// Evaluates the body with `$T` bound to the Viewable element type of the
+/// TypedArray.
+macro_rules! with_typed_array_viewable {
+    ($array:expr, $T:ident => $body:expr) => {
+        match $array {
+            TypedArray::Int8Array(_) => {
+                type $T = i8;
+                $body
+            }
+            TypedArray::Uint8Array(_) => {
+                type $T = u8;
+                $body
+            }
+            TypedArray::Uint8ClampedArray(_) => {
+                type $T = U8Clamped;
+                $body
+            }
+            TypedArray::Int16Array(_) => {
+                type $T = i16;
+                $body
+            }
+            TypedArray::Uint16Array(_) => {
+                type $T = u16;
+                $body
+            }
+            TypedArray::Int32Array(_) => {
+                type $T = i32;
+                $body
+            }
+            TypedArray::Uint32Array(_) => {
+                type $T = u32;
+                $body
+            }
+            TypedArray::BigInt64Array(_) => {
+                type $T = i64;
+                $body
+            }
+            TypedArray::BigUint64Array(_) => {
+                type $T = u64;
+                $body
+            }
+            #[cfg(feature = "proposal-float16array")]
+            TypedArray::Float16Array(_) => {
+                type $T = f16;
+                $body
+            }
+            TypedArray::Float32Array(_) => {
+                type $T = f32;
+                $body
+            }
+            TypedArray::Float64Array(_) => {
+                type $T = f64;
+                $body
+            }
+        }
+    };
+}
+
 pub struct TypedArrayIntrinsicObject;
 
 impl Builtin for TypedArrayIntrinsicObject {
@@ -66,7 +139,7 @@ struct TypedArrayOf;
 impl Builtin for TypedArrayOf {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(TypedArrayIntrinsicObject::of);
     const LENGTH: u8 = 0;
-    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromCodePoint;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.of;
 }
 struct TypedArrayGetSpecies;
 impl Builtin for TypedArrayGetSpecies {
@@ -92,13 +165,160 @@ impl TypedArrayIntrinsicObject {
         ))
     }
 
+    /// ### [23.2.2.1 %TypedArray%.from ( source \[ , mapper \[ , thisArg \] \] )](https://tc39.es/ecma262/#sec-%typedarray%.from)
     fn from(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        let source = arguments.get(0);
+        let mapper = arguments.get(1);
+        let this_arg = arguments.get(2);
+        // 1. Let C be the this value.
+        // 2. If IsConstructor(C) is false, throw a TypeError exception.
+        let Some(c) = is_constructor(agent, this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected this to be a constructor",
+                gc.nogc(),
+            ));
+        };
+        let c = c.scope(agent, gc.nogc());
+        // 3. If mapper is undefined, then
+        let mapper = if mapper.is_undefined() {
+            // a. Let mapping be false.
+            None
+        } else {
+            // 4. Else,
+            // a. If IsCallable(mapper) is false, throw a TypeError exception.
+            let Some(mapper) = is_callable(mapper, gc.nogc()) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Mapper is not a function",
+                    gc.nogc(),
+                ));
+            };
+            // b. Let mapping be true.
+            Some(mapper.scope(agent, gc.nogc()))
+        };
+        // 5. Let usingIterator be ? GetMethod(source, %Symbol.iterator%).
+        let using_iterator = get_method(
+            agent,
+            source,
+            WellKnownSymbolIndexes::Iterator.to_property_key(),
+            gc.reborrow(),
+        )?
+        .map(|using_iterator| using_iterator.unbind());
+        // 6. If usingIterator is not undefined, then
+        if let Some(using_iterator) = using_iterator {
+            // a. Let values be ? IteratorToList(? GetIteratorFromMethod(source, usingIterator)).
+            let iterator_record =
+                get_iterator_from_method(agent, source, using_iterator, gc.reborrow())?;
+            let values = iterator_to_list(agent, &iterator_record, gc.reborrow())?;
+            let values = values
+                .into_iter()
+                .map(|value| value.scope(agent, gc.nogc()))
+                .collect::<Vec<_>>();
+            // b. Let len be the number of elements in values.
+            let len = values.len();
+            // c. Let targetObj be ? TypedArrayCreateFromConstructor(C, « 𝔽(len) »).
+            let target_obj = typed_array_create_from_constructor_with_length(
+                agent,
+                c.get(agent),
+                len,
+                gc.reborrow(),
+            )?
+            .unbind()
+            .scope(agent, gc.nogc());
+            // d. Let k be 0.
+            // e. Repeat, while k < len,
+            for (k, k_value) in values.iter().enumerate() {
+                // i. Let Pk be ! ToString(𝔽(k)).
+                // ii. Let kValue be the first element of values.
+                // iii. Remove the first element from values.
+                let k_value = k_value.get(agent);
+                // iv. If mapping is true, then
+                let mapped_value = if let Some(mapper) = &mapper {
+                    // 1. Let mappedValue be ? Call(mapper, thisArg, « kValue, 𝔽(k) »).
+                    call_function(
+                        agent,
+                        mapper.get(agent),
+                        this_arg,
+                        Some(ArgumentsList(&[
+                            k_value,
+                            Number::try_from(k).unwrap().into_value(),
+                        ])),
+                        gc.reborrow(),
+                    )?
+                } else {
+                    // v. Else, let mappedValue be kValue.
+                    k_value
+                };
+                // vi. Perform ? Set(targetObj, Pk, mappedValue, true).
+                typed_array_set_element_generic(
+                    agent,
+                    target_obj.get(agent),
+                    k as i64,
+                    mapped_value,
+                    gc.reborrow(),
+                )?;
+                // vii. Set k to k + 1.
+            }
+            // f. Assert: values is now an empty List.
+            // g. Return targetObj.
+            return Ok(target_obj.get(agent).into_value());
+        }
+        // 7. NOTE: source is not an Iterable so assume it is already an array-like object.
+        // 8. Let arrayLike be ! ToObject(source).
+        let array_like = to_object(agent, source, gc.nogc())?.scope(agent, gc.nogc());
+        // 9. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = length_of_array_like(agent, array_like.get(agent), gc.reborrow())? as usize;
+        // 10. Let targetObj be ? TypedArrayCreateFromConstructor(C, « 𝔽(len) »).
+        let target_obj = typed_array_create_from_constructor_with_length(
+            agent,
+            c.get(agent),
+            len,
+            gc.reborrow(),
+        )?
+        .unbind()
+        .scope(agent, gc.nogc());
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk: PropertyKey = k.try_into().unwrap();
+            // b. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = get(agent, array_like.get(agent), pk, gc.reborrow())?;
+            // c. If mapping is true, then
+            let mapped_value = if let Some(mapper) = &mapper {
+                // i. Let mappedValue be ? Call(mapper, thisArg, « kValue, 𝔽(k) »).
+                call_function(
+                    agent,
+                    mapper.get(agent),
+                    this_arg,
+                    Some(ArgumentsList(&[
+                        k_value,
+                        Number::try_from(k).unwrap().into_value(),
+                    ])),
+                    gc.reborrow(),
+                )?
+            } else {
+                // d. Else, let mappedValue be kValue.
+                k_value
+            };
+            // e. Perform ? Set(targetObj, Pk, mappedValue, true).
+            typed_array_set_element_generic(
+                agent,
+                target_obj.get(agent),
+                k as i64,
+                mapped_value,
+                gc.reborrow(),
+            )?;
+            // f. Set k to k + 1.
+        }
+        // 13. Return targetObj.
+        Ok(target_obj.get(agent).into_value())
     }
 
     fn is_array(
@@ -110,13 +330,47 @@ impl TypedArrayIntrinsicObject {
         is_array(agent, arguments.get(0), gc.nogc()).map(Value::Boolean)
     }
 
+    /// ### [23.2.2.2 %TypedArray%.of ( ...items )](https://tc39.es/ecma262/#sec-%typedarray%.of)
     fn of(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _arguments: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        // 1. Let len be the number of elements in items.
+        let len = arguments.len();
+        // 2. Let C be the this value.
+        // 3. If IsConstructor(C) is false, throw a TypeError exception.
+        let Some(c) = is_constructor(agent, this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected this to be a constructor",
+                gc.nogc(),
+            ));
+        };
+        // 4. Let newObj be ? TypedArrayCreateFromConstructor(C, « 𝔽(len) »).
+        let new_obj =
+            typed_array_create_from_constructor_with_length(agent, c.unbind(), len, gc.reborrow())?
+                .unbind()
+                .scope(agent, gc.nogc());
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. Let kValue be items[k].
+            let k_value = arguments.get(k);
+            // b. Let Pk be ! ToString(𝔽(k)).
+            // c. Perform ? Set(newObj, Pk, kValue, true).
+            typed_array_set_element_generic(
+                agent,
+                new_obj.get(agent),
+                k as i64,
+                k_value,
+                gc.reborrow(),
+            )?;
+            // d. Set k to k + 1.
+        }
+        // 7. Return newObj.
+        Ok(new_obj.get(agent).into_value())
     }
 
     fn get_species(
@@ -566,13 +820,123 @@ impl TypedArrayPrototype {
         Ok(Value::try_from(o.byte_offset(agent) as i64).unwrap())
     }
 
+    /// ### [23.2.3.6 %TypedArray%.prototype.copyWithin ( target, start \[ , end \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.copywithin)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.copyWithin as defined in 23.1.3.4.
     fn copy_within(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        let o = o.scope(agent, gc.nogc());
+        // 4. Let relativeTarget be ? ToIntegerOrInfinity(target).
+        let relative_target = to_integer_or_infinity(agent, arguments.get(0), gc.reborrow())?;
+        // 5. If relativeTarget = -∞, let targetIndex be 0.
+        // 6. Else if relativeTarget < 0, let targetIndex be max(len + relativeTarget, 0).
+        // 7. Else, let targetIndex be min(relativeTarget, len).
+        let target_index = relative_to_absolute_index(relative_target, len);
+        // 8. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start = to_integer_or_infinity(agent, arguments.get(1), gc.reborrow())?;
+        // 9. If relativeStart = -∞, let startIndex be 0.
+        // 10. Else if relativeStart < 0, let startIndex be max(len + relativeStart, 0).
+        // 11. Else, let startIndex be min(relativeStart, len).
+        let start_index = relative_to_absolute_index(relative_start, len);
+        // 12. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = arguments.get(2);
+        // 13. If relativeEnd = -∞, let endIndex be 0.
+        // 14. Else if relativeEnd < 0, let endIndex be max(len + relativeEnd, 0).
+        // 15. Else, let endIndex be min(relativeEnd, len).
+        let end_index = if end.is_undefined() {
+            len
+        } else {
+            let relative_end = to_integer_or_infinity(agent, end, gc.reborrow())?;
+            relative_to_absolute_index(relative_end, len)
+        };
+        let gc = gc.into_nogc();
+        let o = o.get(agent).bind(gc);
+        // 16. Let count be min(endIndex - startIndex, len - targetIndex).
+        let count = end_index
+            .saturating_sub(start_index)
+            .min(len - target_index);
+        // 17. If count > 0, then
+        if count > 0 {
+            // a. NOTE: The copying must be performed in a manner that preserves the bit-level encoding of the source data.
+            // b. Let buffer be O.[[ViewedArrayBuffer]].
+            let buffer = o.get_viewed_array_buffer(agent, gc);
+            // c. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // d. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            // e. Set len to TypedArrayLength(taRecord).
+            // f. Let elementSize be TypedArrayElementSize(O).
+            let Some((len, element_size)) = with_typed_array_viewable!(o, T => {
+                typed_array_length_in_bounds::<T>(agent, o, gc)
+                    .map(|len| (len, core::mem::size_of::<T>()))
+            }) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "TypedArray out of bounds",
+                    gc,
+                ));
+            };
+            // g. Let byteOffset be O.[[ByteOffset]].
+            let byte_offset = o.byte_offset(agent);
+            // h. Let bufferByteLimit be (len × elementSize) + byteOffset.
+            let buffer_byte_limit = len * element_size + byte_offset;
+            // i. Let toByteIndex be (targetIndex × elementSize) + byteOffset.
+            let to_byte_index = target_index * element_size + byte_offset;
+            // j. Let fromByteIndex be (startIndex × elementSize) + byteOffset.
+            let from_byte_index = start_index * element_size + byte_offset;
+            // k. Let countBytes be count × elementSize.
+            let count_bytes = count * element_size;
+            // l. If fromByteIndex < toByteIndex and toByteIndex < fromByteIndex + countBytes, then
+            //    i. Let direction be -1.
+            //    ii. Set fromByteIndex to fromByteIndex + countBytes - 1.
+            //    iii. Set toByteIndex to toByteIndex + countBytes - 1.
+            // m. Else,
+            //    i. Let direction be 1.
+            // n. Repeat, while countBytes > 0,
+            //    i. If fromByteIndex < bufferByteLimit and toByteIndex < bufferByteLimit, then
+            //       1. Let value be GetValueFromBuffer(buffer, fromByteIndex, uint8, true, unordered).
+            //       2. Perform SetValueInBuffer(buffer, toByteIndex, uint8, value, true, unordered).
+            //       3. Set fromByteIndex to fromByteIndex + direction.
+            //       4. Set toByteIndex to toByteIndex + direction.
+            //       5. Set countBytes to countBytes - 1.
+            //    ii. Else,
+            //       1. Set countBytes to 0.
+            // NOTE: A backwards copy stops immediately if the last byte is
+            // past the limit, while a forwards copy stops when it reaches the
+            // limit. The copy itself is an overlapping memmove either way.
+            let count_bytes = if from_byte_index < to_byte_index
+                && to_byte_index < from_byte_index + count_bytes
+            {
+                if to_byte_index + count_bytes > buffer_byte_limit {
+                    0
+                } else {
+                    count_bytes
+                }
+            } else {
+                count_bytes
+                    .min(buffer_byte_limit.saturating_sub(from_byte_index.max(to_byte_index)))
+            };
+            buffer.copy_array_buffer_data(
+                agent,
+                to_byte_index,
+                buffer,
+                from_byte_index,
+                count_bytes,
+            );
+        }
+        // 18. Return O.
+        Ok(o.into_value())
     }
 
     /// ### [23.2.3.7 %TypedArray%.prototype.entries ( )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.entries)
@@ -672,58 +1036,242 @@ impl TypedArrayPrototype {
         Ok(true.into())
     }
 
+    /// ### [23.2.3.9 %TypedArray%.prototype.fill ( value \[ , start \[ , end \] \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.fill)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.fill as defined in 23.1.3.7.
     fn fill(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        let is_big_int = is_big_int_typed_array(o);
+        let o = o.scope(agent, gc.nogc());
+        let value = if is_big_int {
+            // 4. If O.[[ContentType]] is bigint, set value to ? ToBigInt(value).
+            to_big_int(agent, arguments.get(0), gc.reborrow())?.into_value()
+        } else {
+            // 5. Otherwise, set value to ? ToNumber(value).
+            to_number(agent, arguments.get(0), gc.reborrow())?.into_value()
+        };
+        let value = value.scope(agent, gc.nogc());
+        // 6. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start = to_integer_or_infinity(agent, arguments.get(1), gc.reborrow())?;
+        // 7. If relativeStart = -∞, let startIndex be 0.
+        // 8. Else if relativeStart < 0, let startIndex be max(len + relativeStart, 0).
+        // 9. Else, let startIndex be min(relativeStart, len).
+        let start_index = relative_to_absolute_index(relative_start, len);
+        // 10. If end is undefined, let relativeEnd be len; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = arguments.get(2);
+        // 11. If relativeEnd = -∞, let endIndex be 0.
+        // 12. Else if relativeEnd < 0, let endIndex be max(len + relativeEnd, 0).
+        // 13. Else, let endIndex be min(relativeEnd, len).
+        let end_index = if end.is_undefined() {
+            len
+        } else {
+            let relative_end = to_integer_or_infinity(agent, end, gc.reborrow())?;
+            relative_to_absolute_index(relative_end, len)
+        };
+        let gc = gc.into_nogc();
+        let o = o.get(agent).bind(gc);
+        let value = Numeric::try_from(value.get(agent)).unwrap().bind(gc);
+        // 14. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 15. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        // 16. Set len to TypedArrayLength(taRecord).
+        // 17. Set endIndex to min(endIndex, len).
+        // 18. Let k be startIndex.
+        // 19. Repeat, while k < endIndex,
+        //     a. Let Pk be ! ToString(𝔽(k)).
+        //     b. Perform ! Set(O, Pk, value, true).
+        //     c. Set k to k + 1.
+        with_typed_array_viewable!(o, T => {
+            fill_typed_array::<T>(agent, o, value, start_index, end_index, gc)
+        })?;
+        // 20. Return O.
+        Ok(o.into_value())
     }
 
+    /// ### [23.2.3.10 %TypedArray%.prototype.filter ( callback \[ , thisArg \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.filter)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.filter as defined in 23.1.3.8.
     fn filter(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let callback = arguments.get(0).bind(gc.nogc());
+        let this_arg = arguments.get(1).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.nogc(),
+            ));
+        };
+        let callback = callback.scope(agent, gc.nogc());
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 5. Let kept be a new empty List.
+        let mut kept = Vec::new();
+        // 6. Let captured be 0.
+        // 7. Let k be 0.
+        // 8. Repeat, while k < len,
+        for k in 0..len {
+            let o = scoped_o.get(agent).bind(gc.nogc());
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk: PropertyKey = k.try_into().unwrap();
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
+            let scoped_k_value = k_value.scope(agent, gc.nogc());
+            // c. Let selected be ToBoolean(? Call(callback, thisArg, « kValue, 𝔽(k), O »)).
+            let call = call_function(
+                agent,
+                callback.get(agent),
+                this_arg,
+                Some(ArgumentsList(&[
+                    k_value,
+                    Number::try_from(k).unwrap().into_value(),
+                    o.into_value(),
+                ])),
+                gc.reborrow(),
+            )?;
+            let selected = to_boolean(agent, call);
+            // d. If selected is true, then
+            if selected {
+                // i. Append kValue to kept.
+                // ii. Set captured to captured + 1.
+                kept.push(scoped_k_value);
+            }
+            // e. Set k to k + 1.
+        }
+        let captured = kept.len();
+        // 9. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(captured) »).
+        let a = typed_array_species_create_with_length(
+            agent,
+            scoped_o.get(agent),
+            captured,
+            gc.reborrow(),
+        )?
+        .unbind()
+        .scope(agent, gc.nogc());
+        // 10. Let n be 0.
+        // 11. For each element e of kept, do
+        for (n, e) in kept.iter().enumerate() {
+            // a. Perform ! Set(A, ! ToString(𝔽(n)), e, true).
+            // b. Set n to n + 1.
+            typed_array_set_element_generic(
+                agent,
+                a.get(agent),
+                n as i64,
+                e.get(agent),
+                gc.reborrow(),
+            )?;
+        }
+        // 12. Return A.
+        Ok(a.get(agent).into_value())
     }
 
+    /// ### [23.2.3.11 %TypedArray%.prototype.find ( predicate \[ , thisArg \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.find)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.find as defined in 23.1.3.9.
     fn find(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        // 3. Let len be TypedArrayLength(taRecord).
+        let (o, len) = validate_typed_array_for_find(agent, this_value, gc.nogc())?;
+        // 4. Let findRec be ? FindViaPredicate(O, len, ascending, predicate, thisArg).
+        let find_rec =
+            find_via_predicate(agent, o, len, true, arguments.get(0), arguments.get(1), gc)?;
+        // 5. Return findRec.[[Value]].
+        Ok(find_rec.1)
     }
 
+    /// ### [23.2.3.12 %TypedArray%.prototype.findIndex ( predicate \[ , thisArg \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.findindex)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.findIndex as defined in 23.1.3.10.
     fn find_index(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        // 3. Let len be TypedArrayLength(taRecord).
+        let (o, len) = validate_typed_array_for_find(agent, this_value, gc.nogc())?;
+        // 4. Let findRec be ? FindViaPredicate(O, len, ascending, predicate, thisArg).
+        let find_rec =
+            find_via_predicate(agent, o, len, true, arguments.get(0), arguments.get(1), gc)?;
+        // 5. Return findRec.[[Index]].
+        Ok(Number::try_from(find_rec.0).unwrap().into_value())
     }
 
+    /// ### [23.2.3.13 %TypedArray%.prototype.findLast ( predicate \[ , thisArg \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlast)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.findLast as defined in 23.1.3.11.
     fn find_last(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        // 3. Let len be TypedArrayLength(taRecord).
+        let (o, len) = validate_typed_array_for_find(agent, this_value, gc.nogc())?;
+        // 4. Let findRec be ? FindViaPredicate(O, len, descending, predicate, thisArg).
+        let find_rec =
+            find_via_predicate(agent, o, len, false, arguments.get(0), arguments.get(1), gc)?;
+        // 5. Return findRec.[[Value]].
+        Ok(find_rec.1)
     }
 
+    /// ### [23.2.3.14 %TypedArray%.prototype.findLastIndex ( predicate \[ , thisArg \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.findlastindex)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.findLastIndex as defined in 23.1.3.12.
     fn find_last_index(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        // 3. Let len be TypedArrayLength(taRecord).
+        let (o, len) = validate_typed_array_for_find(agent, this_value, gc.nogc())?;
+        // 4. Let findRec be ? FindViaPredicate(O, len, descending, predicate, thisArg).
+        let find_rec =
+            find_via_predicate(agent, o, len, false, arguments.get(0), arguments.get(1), gc)?;
+        // 5. Return findRec.[[Index]].
+        Ok(Number::try_from(find_rec.0).unwrap().into_value())
     }
 
     // ### [ 23.2.3.15 %TypedArray%.prototype.forEach ( callback [ , thisArg ] )](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.foreach)
@@ -899,43 +1447,100 @@ impl TypedArrayPrototype {
         Ok(false.into())
     }
 
-    fn index_of(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
-    ) -> JsResult<Value> {
-        todo!()
-    }
-
-    /// ### [23.2.3.18 %TypedArray%.prototype.join ( separator )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.join)
+    /// ### [23.2.3.17 %TypedArray%.prototype.indexOf ( searchElement \[ , fromIndex \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.indexof)
     ///
     /// The interpretation and use of the arguments of this method are the
-    /// same as for Array.prototype.join as defined in 23.1.3.18.
-    ///
-    /// This method is not generic. The this value must be an object with a
-    /// `[[TypedArrayName]]` internal slot.
-    fn join(
+    /// same as for Array.prototype.indexOf as defined in 23.1.3.17.
+    fn index_of(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
         mut gc: GcScope,
     ) -> JsResult<Value> {
-        let separator = arguments.get(0);
+        let search_element = arguments.get(0);
+        let from_index = arguments.get(1);
         // 1. Let O be the this value.
-        let o = this_value;
         // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
-        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, gc.nogc())?;
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
         let mut o = ta_record.object;
         // 3. Let len be TypedArrayLength(taRecord).
-        let (len, element_size) = match o {
-            TypedArray::Int8Array(_) => (
-                typed_array_length::<i8>(agent, &ta_record, gc.nogc()),
-                core::mem::size_of::<i8>(),
-            ),
-            TypedArray::Uint8Array(_) => (
-                typed_array_length::<u8>(agent, &ta_record, gc.nogc()),
-                core::mem::size_of::<u8>(),
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        // 4. If len = 0, return -1𝔽.
+        if len == 0 {
+            return Ok((-1).into());
+        }
+        // 5. Let n be ? ToIntegerOrInfinity(fromIndex).
+        let n = if let TryResult::Continue(n) =
+            try_to_integer_or_infinity(agent, from_index, gc.nogc())
+        {
+            n?
+        } else {
+            let scoped_o = o.scope(agent, gc.nogc());
+            let result = to_integer_or_infinity(agent, from_index, gc.reborrow());
+            o = scoped_o.get(agent).bind(gc.nogc());
+            result?
+        };
+        // 6. Assert: If fromIndex is undefined, then n is 0.
+        // 7. If n = +∞, return -1𝔽.
+        if n.is_pos_infinity() {
+            return Ok((-1).into());
+        }
+        // 8. Else if n = -∞, set n to 0.
+        // 9. If n ≥ 0, then
+        //    a. Let k be n.
+        // 10. Else,
+        //     a. Let k be len + n.
+        //     b. If k < 0, set k to 0.
+        let k = relative_to_absolute_index(n, len);
+        // 11. Repeat, while k < len,
+        //     a. Let kPresent be ! HasProperty(O, ! ToString(𝔽(k))).
+        //     b. If kPresent is true, then
+        //        i. Let elementK be ! Get(O, ! ToString(𝔽(k))).
+        //        ii. If IsStrictlyEqual(searchElement, elementK) is true, return 𝔽(k).
+        //     c. Set k to k + 1.
+        let o = o.unbind();
+        let gc = gc.into_nogc();
+        let o = o.bind(gc);
+        let result = with_typed_array_viewable!(o, T => {
+            search_typed_array::<T, true, false>(agent, o, search_element, k..len, gc)
+        });
+        if let Some(k) = result {
+            return Ok(Number::try_from(k).unwrap().into_value());
+        }
+        // 12. Return -1𝔽.
+        Ok((-1).into())
+    }
+
+    /// ### [23.2.3.18 %TypedArray%.prototype.join ( separator )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.join)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.join as defined in 23.1.3.18.
+    ///
+    /// This method is not generic. The this value must be an object with a
+    /// `[[TypedArrayName]]` internal slot.
+    fn join(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
+    ) -> JsResult<Value> {
+        let separator = arguments.get(0);
+        // 1. Let O be the this value.
+        let o = this_value;
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, o, Ordering::SeqCst, gc.nogc())?;
+        let mut o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let (len, element_size) = match o {
+            TypedArray::Int8Array(_) => (
+                typed_array_length::<i8>(agent, &ta_record, gc.nogc()),
+                core::mem::size_of::<i8>(),
+            ),
+            TypedArray::Uint8Array(_) => (
+                typed_array_length::<u8>(agent, &ta_record, gc.nogc()),
+                core::mem::size_of::<u8>(),
             ),
             TypedArray::Uint8ClampedArray(_) => (
                 typed_array_length::<U8Clamped>(agent, &ta_record, gc.nogc()),
@@ -1016,80 +1621,14 @@ impl TypedArrayPrototype {
         let offset = o.byte_offset(agent);
         let viewed_array_buffer = o.get_viewed_array_buffer(agent, gc.nogc());
         // Note: Above ToString might have detached the ArrayBuffer or shrunk its length.
-        let (is_invalid_typed_array, after_len) = if recheck_buffer {
-            let is_detached = is_detached_buffer(agent, viewed_array_buffer);
-            let ta_record = make_typed_array_with_buffer_witness_record(
-                agent,
-                o,
-                Ordering::Unordered,
-                gc.nogc(),
-            );
-            match o {
-                TypedArray::Int8Array(_) => (
-                    is_detached || is_typed_array_out_of_bounds::<i8>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<i8>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Uint8Array(_) => (
-                    is_detached || is_typed_array_out_of_bounds::<u8>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<u8>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Uint8ClampedArray(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<U8Clamped>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<U8Clamped>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Int16Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<i16>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<i16>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Uint16Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<u16>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<u16>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Int32Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<i32>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<i32>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Uint32Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<u32>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<u32>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::BigInt64Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<i64>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<i64>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::BigUint64Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<u64>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<u64>(agent, &ta_record, gc.nogc()),
-                ),
-                #[cfg(feature = "proposal-float16array")]
-                TypedArray::Float16Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<f16>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<f16>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Float32Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<f32>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<f32>(agent, &ta_record, gc.nogc()),
-                ),
-                TypedArray::Float64Array(_) => (
-                    is_detached
-                        || is_typed_array_out_of_bounds::<f64>(agent, &ta_record, gc.nogc()),
-                    typed_array_length::<f64>(agent, &ta_record, gc.nogc()),
-                ),
-            }
+        let after_len = if recheck_buffer {
+            with_typed_array_viewable!(o, T => typed_array_length_in_bounds::<T>(agent, o, gc.nogc()))
+                .unwrap_or(0)
         } else {
             // Note: Growable SharedArrayBuffers are a thing, and can change the
             // length at any point in time but they can never shrink the buffer.
             // Hence the TypedArray or any of its indexes rae never invalidated.
-            (false, len)
+            len
         };
         for k in 0..len {
             // a. If k > 0, set R to the string-concatenation of R and sep.
@@ -1097,7 +1636,7 @@ impl TypedArrayPrototype {
                 r.push_str(sep);
             }
             // c. If element is not undefined, then
-            if is_invalid_typed_array || k >= after_len {
+            if k >= after_len {
                 // Note: element is undefined if the ViewedArrayBuffer was
                 // detached by ToString call, or was shrunk to less than len.
                 continue;
@@ -1242,13 +1781,84 @@ impl TypedArrayPrototype {
         )
     }
 
+    /// ### [23.2.3.20 %TypedArray%.prototype.lastIndexOf ( searchElement \[ , fromIndex \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.lastindexof)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.lastIndexOf as defined in 23.1.3.20.
     fn last_index_of(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let search_element = arguments.get(0);
+        let from_index = arguments.get(1);
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let mut o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        // 4. If len = 0, return -1𝔽.
+        if len == 0 {
+            return Ok((-1).into());
+        }
+        // 5. If fromIndex is present, let n be ? ToIntegerOrInfinity(fromIndex); else let n be len - 1.
+        let n = if arguments.len() > 1 {
+            let n = if let TryResult::Continue(n) =
+                try_to_integer_or_infinity(agent, from_index, gc.nogc())
+            {
+                n?
+            } else {
+                let scoped_o = o.scope(agent, gc.nogc());
+                let result = to_integer_or_infinity(agent, from_index, gc.reborrow());
+                o = scoped_o.get(agent).bind(gc.nogc());
+                result?
+            };
+            // 6. If n = -∞, return -1𝔽.
+            if n.is_neg_infinity() {
+                return Ok((-1).into());
+            }
+            n.into_i64()
+        } else {
+            len as i64 - 1
+        };
+        // 7. If n ≥ 0, then
+        let k = if n >= 0 {
+            // a. Let k be min(n, len - 1).
+            n.min(len as i64 - 1)
+        } else {
+            // 8. Else,
+            // a. Let k be len + n.
+            len as i64 + n
+        };
+        // 9. Repeat, while k ≥ 0,
+        //    a. Let kPresent be ! HasProperty(O, ! ToString(𝔽(k))).
+        //    b. If kPresent is true, then
+        //       i. Let elementK be ! Get(O, ! ToString(𝔽(k))).
+        //       ii. If IsStrictlyEqual(searchElement, elementK) is true, return 𝔽(k).
+        //    c. Set k to k - 1.
+        if k >= 0 {
+            let o = o.unbind();
+            let gc = gc.into_nogc();
+            let o = o.bind(gc);
+            let result = with_typed_array_viewable!(o, T => {
+                search_typed_array::<T, false, false>(
+                    agent,
+                    o,
+                    search_element,
+                    0..(k as usize + 1),
+                    gc,
+                )
+            });
+            if let Some(k) = result {
+                return Ok(Number::try_from(k).unwrap().into_value());
+            }
+        }
+        // 10. Return -1𝔽.
+        Ok((-1).into())
     }
 
     /// ### [23.2.3.21 get %TypedArray%.prototype.length](https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length)
@@ -1321,58 +1931,472 @@ impl TypedArrayPrototype {
         Ok(Value::try_from(length).unwrap())
     }
 
+    /// ### [23.2.3.22 %TypedArray%.prototype.map ( callback \[ , thisArg \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.map)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.map as defined in 23.1.3.21.
     fn map(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let callback = arguments.get(0).bind(gc.nogc());
+        let this_arg = arguments.get(1).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.nogc(),
+            ));
+        };
+        let callback = callback.scope(agent, gc.nogc());
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 5. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(len) »).
+        let a = typed_array_species_create_with_length(agent, o.unbind(), len, gc.reborrow())?
+            .unbind()
+            .scope(agent, gc.nogc());
+        // 6. Let k be 0.
+        // 7. Repeat, while k < len,
+        for k in 0..len {
+            let o = scoped_o.get(agent).bind(gc.nogc());
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk: PropertyKey = k.try_into().unwrap();
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
+            // c. Let mappedValue be ? Call(callback, thisArg, « kValue, 𝔽(k), O »).
+            let mapped_value = call_function(
+                agent,
+                callback.get(agent),
+                this_arg,
+                Some(ArgumentsList(&[
+                    k_value,
+                    Number::try_from(k).unwrap().into_value(),
+                    o.into_value(),
+                ])),
+                gc.reborrow(),
+            )?;
+            // d. Perform ? Set(A, Pk, mappedValue, true).
+            typed_array_set_element_generic(
+                agent,
+                a.get(agent),
+                k as i64,
+                mapped_value,
+                gc.reborrow(),
+            )?;
+            // e. Set k to k + 1.
+        }
+        // 8. Return A.
+        Ok(a.get(agent).into_value())
     }
 
+    /// ### [23.2.3.23 %TypedArray%.prototype.reduce ( callback \[ , initialValue \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduce)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.reduce as defined in 23.1.3.24.
     fn reduce(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let callback = arguments.get(0).bind(gc.nogc());
+        let initial_value = if arguments.len() >= 2 {
+            Some(arguments.get(1))
+        } else {
+            None
+        };
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.nogc(),
+            ));
+        };
+        // 5. If len = 0 and initialValue is not present, throw a TypeError exception.
+        if len == 0 && initial_value.is_none() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Reduce of empty array with no initial value",
+                gc.nogc(),
+            ));
+        }
+        let callback = callback.scope(agent, gc.nogc());
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 6. Let k be 0.
+        let mut k = 0;
+        // 7. Let accumulator be undefined.
+        // 8. If initialValue is present, then
+        let mut accumulator = if let Some(initial_value) = initial_value {
+            // a. Set accumulator to initialValue.
+            initial_value
+        } else {
+            // 9. Else,
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Set accumulator to ! Get(O, Pk).
+            // c. Set k to k + 1.
+            k += 1;
+            unwrap_try(try_get(
+                agent,
+                o,
+                PropertyKey::from(SmallInteger::zero()),
+                gc.nogc(),
+            ))
+        };
+        // 10. Repeat, while k < len,
+        while k < len {
+            let o = scoped_o.get(agent).bind(gc.nogc());
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk: PropertyKey = k.try_into().unwrap();
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
+            // c. Set accumulator to ? Call(callback, undefined, « accumulator, kValue, 𝔽(k), O »).
+            accumulator = call_function(
+                agent,
+                callback.get(agent),
+                Value::Undefined,
+                Some(ArgumentsList(&[
+                    accumulator,
+                    k_value,
+                    Number::try_from(k).unwrap().into_value(),
+                    o.into_value(),
+                ])),
+                gc.reborrow(),
+            )?;
+            // d. Set k to k + 1.
+            k += 1;
+        }
+        // 11. Return accumulator.
+        Ok(accumulator)
     }
 
+    /// ### [23.2.3.24 %TypedArray%.prototype.reduceRight ( callback \[ , initialValue \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.reduceright)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.reduceRight as defined in 23.1.3.25.
     fn reduce_right(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let callback = arguments.get(0).bind(gc.nogc());
+        let initial_value = if arguments.len() >= 2 {
+            Some(arguments.get(1))
+        } else {
+            None
+        };
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        // 4. If IsCallable(callback) is false, throw a TypeError exception.
+        let Some(callback) = is_callable(callback, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback is not callable",
+                gc.nogc(),
+            ));
+        };
+        // 5. If len = 0 and initialValue is not present, throw a TypeError exception.
+        if len == 0 && initial_value.is_none() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Reduce of empty array with no initial value",
+                gc.nogc(),
+            ));
+        }
+        let callback = callback.scope(agent, gc.nogc());
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 6. Let k be len - 1.
+        let mut k = len as i64 - 1;
+        // 7. Let accumulator be undefined.
+        // 8. If initialValue is present, then
+        let mut accumulator = if let Some(initial_value) = initial_value {
+            // a. Set accumulator to initialValue.
+            initial_value
+        } else {
+            // 9. Else,
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::Integer(k.try_into().unwrap());
+            // b. Set accumulator to ! Get(O, Pk).
+            // c. Set k to k - 1.
+            k -= 1;
+            unwrap_try(try_get(agent, o, pk, gc.nogc()))
+        };
+        // 10. Repeat, while k ≥ 0,
+        while k >= 0 {
+            let o = scoped_o.get(agent).bind(gc.nogc());
+            // a. Let Pk be ! ToString(𝔽(k)).
+            let pk = PropertyKey::Integer(k.try_into().unwrap());
+            // b. Let kValue be ! Get(O, Pk).
+            let k_value = unwrap_try(try_get(agent, o, pk, gc.nogc()));
+            // c. Set accumulator to ? Call(callback, undefined, « accumulator, kValue, 𝔽(k), O »).
+            accumulator = call_function(
+                agent,
+                callback.get(agent),
+                Value::Undefined,
+                Some(ArgumentsList(&[
+                    accumulator,
+                    k_value,
+                    Number::try_from(k).unwrap().into_value(),
+                    o.into_value(),
+                ])),
+                gc.reborrow(),
+            )?;
+            // d. Set k to k - 1.
+            k -= 1;
+        }
+        // 11. Return accumulator.
+        Ok(accumulator)
     }
 
+    /// ### [23.2.3.26 %TypedArray%.prototype.reverse ( )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.reverse)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.reverse as defined in 23.1.3.26.
     fn reverse(
-        _agent: &mut Agent,
-        _this_value: Value,
+        agent: &mut Agent,
+        this_value: Value,
         _: ArgumentsList,
-        _gc: GcScope,
+        gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let gc = gc.into_nogc();
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc)?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        // 4. Let middle be floor(len / 2).
+        // 5. Let lower be 0.
+        // 6. Repeat, while lower ≠ middle,
+        //    a. Let upper be len - lower - 1.
+        //    b. Let upperP be ! ToString(𝔽(upper)).
+        //    c. Let lowerP be ! ToString(𝔽(lower)).
+        //    d. Let lowerValue be ! Get(O, lowerP).
+        //    e. Let upperValue be ! Get(O, upperP).
+        //    f. Perform ! Set(O, lowerP, upperValue, true).
+        //    g. Perform ! Set(O, upperP, lowerValue, true).
+        //    h. Set lower to lower + 1.
+        with_typed_array_viewable!(o, T => {
+            let len = typed_array_length::<T>(agent, &ta_record, gc);
+            let mut elements = read_typed_array_elements::<T>(agent, o, len, gc);
+            elements.reverse();
+            write_typed_array_elements::<T>(agent, o, &elements, gc);
+        });
+        // 7. Return O.
+        Ok(o.into_value())
     }
 
+    /// ### [23.2.3.27 %TypedArray%.prototype.set ( source \[ , offset \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.set)
+    ///
+    /// This method sets multiple values in this TypedArray, reading the
+    /// values from source. The details differ based upon the type of source.
+    /// The optional offset value indicates the first element index in this
+    /// TypedArray where values are written. If omitted, it is assumed to be 0.
     fn set(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        let source = arguments.get(0);
+        let offset = arguments.get(1);
+        // 1. Let target be the this value.
+        // 2. Perform ? RequireInternalSlot(target, [[TypedArrayName]]).
+        // 3. Assert: target has a [[ViewedArrayBuffer]] internal slot.
+        let mut target = require_internal_slot_typed_array(agent, this_value, gc.nogc())?;
+        // 4. Let targetOffset be ? ToIntegerOrInfinity(offset).
+        let target_offset = if let TryResult::Continue(target_offset) =
+            try_to_integer_or_infinity(agent, offset, gc.nogc())
+        {
+            target_offset?
+        } else {
+            let scoped_target = target.scope(agent, gc.nogc());
+            let result = to_integer_or_infinity(agent, offset, gc.reborrow());
+            target = scoped_target.get(agent).bind(gc.nogc());
+            result?
+        };
+        // 5. If targetOffset < 0, throw a RangeError exception.
+        if target_offset.is_negative() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Offset must be non-negative",
+                gc.nogc(),
+            ));
+        }
+        // 6. If source is an Object that has a [[TypedArrayName]] internal slot, then
+        if let Ok(source) = TypedArray::try_from(source) {
+            // a. Perform ? SetTypedArrayFromTypedArray(target, targetOffset, source).
+            set_typed_array_from_typed_array(agent, target, target_offset, source, gc.nogc())?;
+        } else {
+            // 7. Else,
+            // a. Perform ? SetTypedArrayFromArrayLike(target, targetOffset, source).
+            set_typed_array_from_array_like(
+                agent,
+                target.unbind(),
+                target_offset,
+                source,
+                gc.reborrow(),
+            )?;
+        }
+        // 8. Return undefined.
+        Ok(Value::Undefined)
     }
 
+    /// ### [23.2.3.28 %TypedArray%.prototype.slice ( start, end )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.slice)
+    ///
+    /// The interpretation and use of the arguments of this method are the
+    /// same as for Array.prototype.slice as defined in 23.1.3.28.
     fn slice(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!()
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let srcArrayLength be TypedArrayLength(taRecord).
+        let src_array_length = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        let o = o.scope(agent, gc.nogc());
+        // 4. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start = to_integer_or_infinity(agent, arguments.get(0), gc.reborrow())?;
+        // 5. If relativeStart = -∞, let startIndex be 0.
+        // 6. Else if relativeStart < 0, let startIndex be max(srcArrayLength + relativeStart, 0).
+        // 7. Else, let startIndex be min(relativeStart, srcArrayLength).
+        let start_index = relative_to_absolute_index(relative_start, src_array_length);
+        // 8. If end is undefined, let relativeEnd be srcArrayLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
+        let end = arguments.get(1);
+        // 9. If relativeEnd = -∞, let endIndex be 0.
+        // 10. Else if relativeEnd < 0, let endIndex be max(srcArrayLength + relativeEnd, 0).
+        // 11. Else, let endIndex be min(relativeEnd, srcArrayLength).
+        let end_index = if end.is_undefined() {
+            src_array_length
+        } else {
+            let relative_end = to_integer_or_infinity(agent, end, gc.reborrow())?;
+            relative_to_absolute_index(relative_end, src_array_length)
+        };
+        // 12. Let countBytes be max(endIndex - startIndex, 0).
+        let count = end_index.saturating_sub(start_index);
+        // 13. Let A be ? TypedArraySpeciesCreate(O, « 𝔽(countBytes) »).
+        let a = typed_array_species_create_with_length(agent, o.get(agent), count, gc.reborrow())?
+            .unbind();
+        let gc = gc.into_nogc();
+        let a = a.bind(gc);
+        let o = o.get(agent).bind(gc);
+        // 14. If countBytes > 0, then
+        if count > 0 {
+            // a. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+            // b. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+            let Some(len) = with_typed_array_viewable!(o, T => {
+                typed_array_length_in_bounds::<T>(agent, o, gc)
+            }) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "TypedArray out of bounds",
+                    gc,
+                ));
+            };
+            // c. Set endIndex to min(endIndex, TypedArrayLength(taRecord)).
+            let end_index = end_index.min(len);
+            // d. Set countBytes to max(endIndex - startIndex, 0).
+            let count = end_index.saturating_sub(start_index);
+            // e. Let srcType be TypedArrayElementType(O).
+            let src_type = with_typed_array_viewable!(o, T => T::PROTO);
+            // f. Let targetType be TypedArrayElementType(A).
+            let target_type = with_typed_array_viewable!(a, T => T::PROTO);
+            // g. If srcType is targetType, then
+            if src_type == target_type {
+                // i. NOTE: The transfer must be performed in a manner that preserves the bit-level encoding of the source data.
+                // ii. Let srcBuffer be O.[[ViewedArrayBuffer]].
+                let src_buffer = o.get_viewed_array_buffer(agent, gc);
+                // iii. Let targetBuffer be A.[[ViewedArrayBuffer]].
+                let target_buffer = a.get_viewed_array_buffer(agent, gc);
+                // iv. Let elementSize be TypedArrayElementSize(O).
+                let element_size = with_typed_array_viewable!(o, T => core::mem::size_of::<T>());
+                // v. Let srcByteOffset be O.[[ByteOffset]].
+                let src_byte_offset = o.byte_offset(agent);
+                // vi. Let srcByteIndex be (startIndex × elementSize) + srcByteOffset.
+                let src_byte_index = start_index * element_size + src_byte_offset;
+                // vii. Let targetByteIndex be A.[[ByteOffset]].
+                let target_byte_index = a.byte_offset(agent);
+                // viii. Let endByteIndex be targetByteIndex + (countBytes × elementSize).
+                let count_bytes = count * element_size;
+                // ix. Repeat, while targetByteIndex < endByteIndex,
+                //     1. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, uint8, true, unordered).
+                //     2. Perform SetValueInBuffer(targetBuffer, targetByteIndex, uint8, value, true, unordered).
+                //     3. Set srcByteIndex to srcByteIndex + 1.
+                //     4. Set targetByteIndex to targetByteIndex + 1.
+                if count_bytes == 0 {
+                    // NOTE: O may have shrunk so that srcByteIndex is past
+                    // the end of its buffer; there is nothing to copy.
+                } else if src_buffer == target_buffer && src_byte_index < target_byte_index {
+                    // NOTE: A species constructor may have returned a view
+                    // into the same buffer: the byte-by-byte forward copy
+                    // is then observably different from a memmove.
+                    let data_block = agent[src_buffer].get_data_block_mut();
+                    for i in 0..count_bytes {
+                        let value = data_block
+                            .get_offset_by_byte::<u8>(src_byte_index + i)
+                            .unwrap();
+                        data_block.set_offset_by_byte::<u8>(target_byte_index + i, value);
+                    }
+                } else {
+                    target_buffer.copy_array_buffer_data(
+                        agent,
+                        target_byte_index,
+                        src_buffer,
+                        src_byte_index,
+                        count_bytes,
+                    );
+                }
+            } else {
+                // h. Else,
+                // i. Let n be 0.
+                // ii. Let k be startIndex.
+                // iii. Repeat, while k < endIndex,
+                for (n, k) in (start_index..end_index).enumerate() {
+                    // 1. Let Pk be ! ToString(𝔽(k)).
+                    let pk: PropertyKey = k.try_into().unwrap();
+                    // 2. Let kValue be ! Get(O, Pk).
+                    let k_value = unwrap_try(try_get(agent, o, pk, gc));
+                    // 3. Perform ! Set(A, ! ToString(𝔽(n)), kValue, true).
+                    unwrap_try(try_typed_array_set_element_generic(
+                        agent, a, n as i64, k_value, gc,
+                    ));
+                    // 4. Set k to k + 1.
+                    // 5. Set n to n + 1.
+                }
+            }
+        }
+        // 15. Return A.
+        Ok(a.into_value())
     }
 
     /// ### [23.2.3.28 get %TypedArray%.prototype.some](https://tc39.es/ecma262/multipage/indexed-collections.html#sec-%typedarray%.prototype.some)
@@ -1455,49 +2479,275 @@ impl TypedArrayPrototype {
         Ok(false.into())
     }
 
+    /// ### [23.2.3.29 %TypedArray%.prototype.sort ( comparator )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.sort)
+    ///
+    /// This is a distinct method that, except as described below, implements
+    /// the same requirements as those of Array.prototype.sort as defined in
+    /// 23.1.3.30. The implementation of this method may be optimized with the
+    /// knowledge that the this value is an object that has a fixed length and
+    /// whose integer-indexed properties are not sparse.
     fn sort(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        let comparator = arguments.get(0).bind(gc.nogc());
+        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
+        let comparator = if comparator.is_undefined() {
+            None
+        } else if let Some(comparator) = is_callable(comparator, gc.nogc()) {
+            Some(comparator.scope(agent, gc.nogc()))
+        } else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The comparison function must be either a function or undefined",
+                gc.nogc(),
+            ));
+        };
+        // 2. Let obj be the this value.
+        // 3. Let taRecord be ? ValidateTypedArray(obj, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let obj = ta_record.object;
+        let scoped_obj = obj.scope(agent, gc.nogc());
+        // 4. Let len be TypedArrayLength(taRecord).
+        // 5. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.30.
+        // 6. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparator and performs the following steps when called:
+        //    a. Return ? CompareTypedArrayElements(x, y, comparator).
+        // 7. Let sortedList be ? SortIndexedProperties(obj, len, SortCompare, read-through-holes).
+        // 8. Let j be 0.
+        // 9. Repeat, while j < len,
+        //    a. Perform ! Set(obj, ! ToString(𝔽(j)), sortedList[j], true).
+        //    b. Set j to j + 1.
+        with_typed_array_viewable!(obj, T => {
+            let len = typed_array_length::<T>(agent, &ta_record, gc.nogc());
+            let mut sorted_list = read_typed_array_elements::<T>(agent, obj, len, gc.nogc());
+            sort_typed_array_elements::<T>(agent, &mut sorted_list, comparator, gc.reborrow())?;
+            let obj = scoped_obj.get(agent).bind(gc.nogc());
+            write_typed_array_elements::<T>(agent, obj, &sorted_list, gc.nogc());
+        });
+        // 10. Return obj.
+        Ok(scoped_obj.get(agent).into_value())
     }
 
+    /// ### [23.2.3.30 %TypedArray%.prototype.subarray ( start, end )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray)
+    ///
+    /// This method returns a new TypedArray whose element type is the
+    /// element type of this TypedArray and whose ArrayBuffer is the
+    /// ArrayBuffer of this TypedArray, referencing the elements in the
+    /// interval from start (inclusive) to end (exclusive). If either start or
+    /// end is negative, it refers to an index from the end of the array, as
+    /// opposed to from the beginning.
     fn subarray(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        let end = arguments.get(1);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[TypedArrayName]]).
+        // 3. Assert: O has a [[ViewedArrayBuffer]] internal slot.
+        let o = require_internal_slot_typed_array(agent, this_value, gc.nogc())?;
+        // 4. Let buffer be O.[[ViewedArrayBuffer]].
+        let buffer = o.get_viewed_array_buffer(agent, gc.nogc());
+        // 5. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+        // 6. If IsTypedArrayOutOfBounds(srcRecord) is true, then
+        //    a. Let srcLength be 0.
+        // 7. Else,
+        //    a. Let srcLength be TypedArrayLength(srcRecord).
+        let src_length = with_typed_array_viewable!(o, T => {
+            typed_array_length_in_bounds::<T>(agent, o, gc.nogc())
+        })
+        .unwrap_or(0);
+        // 12. Let elementSize be TypedArrayElementSize(O).
+        let element_size = with_typed_array_viewable!(o, T => core::mem::size_of::<T>());
+        // 13. Let srcByteOffset be O.[[ByteOffset]].
+        let src_byte_offset = o.byte_offset(agent);
+        let is_auto_length = o.array_length(agent).is_none();
+        let o = o.scope(agent, gc.nogc());
+        let buffer = buffer.scope(agent, gc.nogc());
+        // 8. Let relativeStart be ? ToIntegerOrInfinity(start).
+        let relative_start = to_integer_or_infinity(agent, arguments.get(0), gc.reborrow())?;
+        // 9. If relativeStart = -∞, let startIndex be 0.
+        // 10. Else if relativeStart < 0, let startIndex be max(srcLength + relativeStart, 0).
+        // 11. Else, let startIndex be min(relativeStart, srcLength).
+        let start_index = relative_to_absolute_index(relative_start, src_length);
+        // 14. Let beginByteOffset be srcByteOffset + (startIndex × elementSize).
+        let begin_byte_offset = src_byte_offset + start_index * element_size;
+        // 15. If O.[[ArrayLength]] is auto and end is undefined, then
+        let new_length = if is_auto_length && end.is_undefined() {
+            // a. Let argumentsList be « buffer, 𝔽(beginByteOffset) ».
+            None
+        } else {
+            // 16. Else,
+            // a. If end is undefined, let relativeEnd be srcLength; else let relativeEnd be ? ToIntegerOrInfinity(end).
+            // b. If relativeEnd = -∞, let endIndex be 0.
+            // c. Else if relativeEnd < 0, let endIndex be max(srcLength + relativeEnd, 0).
+            // d. Else, let endIndex be min(relativeEnd, srcLength).
+            let end_index = if end.is_undefined() {
+                src_length
+            } else {
+                let relative_end = to_integer_or_infinity(agent, end, gc.reborrow())?;
+                relative_to_absolute_index(relative_end, src_length)
+            };
+            // e. Let newLength be max(endIndex - startIndex, 0).
+            // f. Let argumentsList be « buffer, 𝔽(beginByteOffset), 𝔽(newLength) ».
+            Some(end_index.saturating_sub(start_index))
+        };
+        // 17. Return ? TypedArraySpeciesCreate(O, argumentsList).
+        typed_array_species_create_with_buffer(
+            agent,
+            o.get(agent),
+            buffer.get(agent),
+            begin_byte_offset,
+            new_length,
+            gc,
+        )
+        .map(|result| result.into_value())
     }
 
+    /// ### [23.2.3.31 %TypedArray%.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.tolocalestring)
+    ///
+    /// This is a distinct method that implements the same algorithm as
+    /// Array.prototype.toLocaleString as defined in 23.1.3.32 except that
+    /// TypedArrayLength is called in place of performing a \[\[Get]] of
+    /// "length".
     fn to_locale_string(
-        _agent: &mut Agent,
-        _this_value: Value,
+        agent: &mut Agent,
+        this_value: Value,
         _: ArgumentsList,
-        _gc: GcScope,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        // 1. Let array be ? ToObject(this value).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let array = ta_record.object;
+        // 2. Let len be ? LengthOfArrayLike(array).
+        let len = with_typed_array_viewable!(array, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        let array = array.scope(agent, gc.nogc());
+        // 3. Let separator be the implementation-defined list-separator String appropriate for the host environment's current locale (such as ", ").
+        let separator = ",";
+        // 4. Let R be the empty String.
+        let mut r = std::string::String::new();
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..len {
+            // a. If k > 0, set R to the string-concatenation of R and separator.
+            if k > 0 {
+                r.push_str(separator);
+            }
+            // b. Let element be ? Get(array, ! ToString(𝔽(k))).
+            let pk: PropertyKey = k.try_into().unwrap();
+            let element = unwrap_try(try_get(agent, array.get(agent), pk, gc.nogc()));
+            // c. If element is neither undefined nor null, then
+            if !element.is_undefined() && !element.is_null() {
+                // i. Let S be ? ToString(? Invoke(element, "toLocaleString")).
+                let s = invoke(
+                    agent,
+                    element,
+                    BUILTIN_STRING_MEMORY.toLocaleString.to_property_key(),
+                    None,
+                    gc.reborrow(),
+                )?;
+                let s = to_string(agent, s, gc.reborrow())?;
+                // ii. Set R to the string-concatenation of R and S.
+                r.push_str(s.as_str(agent));
+            }
+            // d. Set k to k + 1.
+        }
+        // 7. Return R.
+        Ok(String::from_string(agent, r, gc.into_nogc()).into_value())
     }
 
+    /// ### [23.2.3.32 %TypedArray%.prototype.toReversed ( )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.toreversed)
     fn to_reversed(
-        _agent: &mut Agent,
-        _this_value: Value,
+        agent: &mut Agent,
+        this_value: Value,
         _: ArgumentsList,
-        _gc: GcScope,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let length be TypedArrayLength(taRecord).
+        let length = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 4. Let A be ? TypedArrayCreateSameType(O, « 𝔽(length) »).
+        let a = typed_array_create_same_type(agent, o.unbind(), length, gc.reborrow())?.unbind();
+        let gc = gc.into_nogc();
+        let a = a.bind(gc);
+        let o = scoped_o.get(agent).bind(gc);
+        // 5. Let k be 0.
+        // 6. Repeat, while k < length,
+        //    a. Let from be ! ToString(𝔽(length - k - 1)).
+        //    b. Let Pk be ! ToString(𝔽(k)).
+        //    c. Let fromValue be ! Get(O, from).
+        //    d. Perform ! Set(A, Pk, fromValue, true).
+        //    e. Set k to k + 1.
+        with_typed_array_viewable!(o, T => {
+            let mut elements = read_typed_array_elements::<T>(agent, o, length, gc);
+            elements.reverse();
+            write_typed_array_elements::<T>(agent, a, &elements, gc);
+        });
+        // 7. Return A.
+        Ok(a.into_value())
     }
 
+    /// ### [23.2.3.33 %TypedArray%.prototype.toSorted ( comparator )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.tosorted)
     fn to_sorted(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        let comparator = arguments.get(0).bind(gc.nogc());
+        // 1. If comparator is not undefined and IsCallable(comparator) is false, throw a TypeError exception.
+        let comparator = if comparator.is_undefined() {
+            None
+        } else if let Some(comparator) = is_callable(comparator, gc.nogc()) {
+            Some(comparator.scope(agent, gc.nogc()))
+        } else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The comparison function must be either a function or undefined",
+                gc.nogc(),
+            ));
+        };
+        // 2. Let O be the this value.
+        // 3. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 4. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        let scoped_o = o.scope(agent, gc.nogc());
+        // 5. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = typed_array_create_same_type(agent, o.unbind(), len, gc.reborrow())?
+            .unbind()
+            .scope(agent, gc.nogc());
+        let o = scoped_o.get(agent).bind(gc.nogc());
+        // 6. NOTE: The following closure performs a numeric comparison rather than the string comparison used in 23.1.3.34.
+        // 7. Let SortCompare be a new Abstract Closure with parameters (x, y) that captures comparator and performs the following steps when called:
+        //    a. Return ? CompareTypedArrayElements(x, y, comparator).
+        // 8. Let sortedList be ? SortIndexedProperties(O, len, SortCompare, read-through-holes).
+        // 9. Let j be 0.
+        // 10. Repeat, while j < len,
+        //     a. Perform ! Set(A, ! ToString(𝔽(j)), sortedList[j], true).
+        //     b. Set j to j + 1.
+        with_typed_array_viewable!(o, T => {
+            let mut sorted_list = read_typed_array_elements::<T>(agent, o, len, gc.nogc());
+            sort_typed_array_elements::<T>(agent, &mut sorted_list, comparator, gc.reborrow())?;
+            let a = a.get(agent).bind(gc.nogc());
+            write_typed_array_elements::<T>(agent, a, &sorted_list, gc.nogc());
+        });
+        // 11. Return A.
+        Ok(a.get(agent).into_value())
     }
 
     fn to_spliced(
@@ -1526,13 +2776,90 @@ impl TypedArrayPrototype {
         )
     }
 
+    /// ### [23.2.3.36 %TypedArray%.prototype.with ( index, value )](https://tc39.es/ecma262/#sec-%typedarray%.prototype.with)
     fn with(
-        _agent: &mut Agent,
-        _this_value: Value,
-        _: ArgumentsList,
-        _gc: GcScope,
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        todo!();
+        // 1. Let O be the this value.
+        // 2. Let taRecord be ? ValidateTypedArray(O, seq-cst).
+        let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc.nogc())?;
+        let o = ta_record.object;
+        // 3. Let len be TypedArrayLength(taRecord).
+        let len = with_typed_array_viewable!(o, T => {
+            typed_array_length::<T>(agent, &ta_record, gc.nogc())
+        });
+        let is_big_int = is_big_int_typed_array(o);
+        let o = o.scope(agent, gc.nogc());
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = to_integer_or_infinity(agent, arguments.get(0), gc.reborrow())?;
+        // 5. If relativeIndex ≥ 0, let actualIndex be relativeIndex.
+        // 6. Else, let actualIndex be len + relativeIndex.
+        let actual_index = if relative_index.is_negative() {
+            (len as i64).saturating_add(relative_index.into_i64())
+        } else {
+            relative_index.into_i64()
+        };
+        let numeric_value = if is_big_int {
+            // 7. If O.[[ContentType]] is bigint, let numericValue be ? ToBigInt(value).
+            to_big_int(agent, arguments.get(1), gc.reborrow())?.into_value()
+        } else {
+            // 8. Else, let numericValue be ? ToNumber(value).
+            to_number(agent, arguments.get(1), gc.reborrow())?.into_value()
+        };
+        let numeric_value = numeric_value.scope(agent, gc.nogc());
+        // 9. If IsValidIntegerIndex(O, 𝔽(actualIndex)) is false, throw a RangeError exception.
+        let current_len = {
+            let o = o.get(agent).bind(gc.nogc());
+            with_typed_array_viewable!(o, T => {
+                typed_array_length_in_bounds::<T>(agent, o, gc.nogc())
+            })
+            .unwrap_or(0)
+        };
+        if actual_index < 0 || actual_index as u64 >= current_len as u64 {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Index out of bounds",
+                gc.nogc(),
+            ));
+        }
+        let actual_index = actual_index as usize;
+        // 10. Let A be ? TypedArrayCreateSameType(O, « 𝔽(len) »).
+        let a = typed_array_create_same_type(agent, o.get(agent), len, gc.reborrow())?.unbind();
+        let gc = gc.into_nogc();
+        let a = a.bind(gc);
+        let o = o.get(agent).bind(gc);
+        let numeric_value = Numeric::try_from(numeric_value.get(agent))
+            .unwrap()
+            .bind(gc);
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        //     a. Let Pk be ! ToString(𝔽(k)).
+        //     b. If k = actualIndex, let fromValue be numericValue.
+        //     c. Else, let fromValue be ! Get(O, Pk).
+        //     d. Perform ! Set(A, Pk, fromValue, true).
+        //     e. Set k to k + 1.
+        with_typed_array_viewable!(o, T => {
+            let mut elements =
+                read_typed_array_elements::<T>(agent, o, len.min(current_len), gc);
+            // NOTE: Get(O, Pk) returns undefined for indexes past the
+            // current end of O, and setting undefined into a Number typed
+            // array writes NaN.
+            let undefined_value = if T::IS_BIGINT {
+                numeric_to_raw::<T>(agent, BigInt::zero().into_numeric())
+            } else {
+                numeric_to_raw::<T>(agent, Number::nan().into_numeric())
+            };
+            elements.resize(len, undefined_value);
+            if actual_index < len {
+                elements[actual_index] = numeric_to_raw::<T>(agent, numeric_value);
+            }
+            write_typed_array_elements::<T>(agent, a, &elements, gc);
+        });
+        // 13. Return A.
+        Ok(a.into_value())
     }
 
     /// ### [23.2.3.38 get %TypedArray%.prototype \[ %Symbol.toStringTag% \]](https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-%symbol.tostringtag%)
@@ -1653,3 +2980,487 @@ pub(crate) fn require_internal_slot_typed_array<'a>(
         )
     })
 }
+
+/// Returns the current length of the TypedArray, or None if the TypedArray is
+/// out of bounds.
+fn typed_array_length_in_bounds<T: Viewable>(
+    agent: &Agent,
+    o: TypedArray,
+    gc: NoGcScope,
+) -> Option<usize> {
+    let ta_record = make_typed_array_with_buffer_witness_record(agent, o, Ordering::SeqCst, gc);
+    if is_typed_array_out_of_bounds::<T>(agent, &ta_record, gc) {
+        None
+    } else {
+        Some(typed_array_length::<T>(agent, &ta_record, gc))
+    }
+}
+
+/// Converts a relative index into an index in the interval from 0 to len
+/// (inclusive): negative indexes count from the end, and indexes past the end
+/// are clamped to len.
+fn relative_to_absolute_index(relative: IntegerOrInfinity, len: usize) -> usize {
+    let relative = relative.into_i64();
+    if relative < 0 {
+        (len as i64).saturating_add(relative).max(0) as usize
+    } else {
+        (relative as u64).min(len as u64) as usize
+    }
+}
+
+/// Performs the ValidateTypedArray and TypedArrayLength steps shared by the
+/// find, findIndex, findLast and findLastIndex methods.
+fn validate_typed_array_for_find<'a>(
+    agent: &mut Agent,
+    this_value: Value,
+    gc: NoGcScope<'_, 'a>,
+) -> JsResult<(Scoped<'a, Object<'static>>, i64)> {
+    let ta_record = validate_typed_array(agent, this_value, Ordering::SeqCst, gc)?;
+    let o = ta_record.object;
+    let len = with_typed_array_viewable!(o, T => typed_array_length::<T>(agent, &ta_record, gc));
+    Ok((o.into_object().scope(agent, gc), len as i64))
+}
+
+/// Converts a Numeric into the raw element value as stored in a Data Block.
+#[inline]
+fn numeric_to_raw<T: Viewable>(agent: &mut Agent, value: Numeric) -> T {
+    numeric_to_raw_bytes::<T>(agent, value, cfg!(target_endian = "little"))
+}
+
+/// Converts a raw element value as stored in a Data Block into a Numeric.
+#[inline]
+fn raw_to_numeric<'a, T: Viewable>(
+    agent: &mut Agent,
+    value: T,
+    gc: NoGcScope<'a, '_>,
+) -> Numeric<'a> {
+    raw_bytes_to_numeric::<T>(agent, value, cfg!(target_endian = "little"), gc)
+}
+
+/// Reads the first `len` elements of the TypedArray as raw element values.
+/// The TypedArray must not be out of bounds.
+fn read_typed_array_elements<T: Viewable>(
+    agent: &Agent,
+    o: TypedArray,
+    len: usize,
+    gc: NoGcScope,
+) -> Vec<T> {
+    let byte_offset = o.byte_offset(agent);
+    let data_block = agent[o.get_viewed_array_buffer(agent, gc)].get_data_block();
+    (0..len)
+        .map(|k| {
+            data_block
+                .get_offset_by_byte::<T>(byte_offset + k * core::mem::size_of::<T>())
+                .unwrap()
+        })
+        .collect()
+}
+
+/// Writes raw element values into the TypedArray starting from index 0, as
+/// if by `! Set(O, Pk, value, true)`: elements that are past the current end
+/// of the TypedArray are not written.
+fn write_typed_array_elements<T: Viewable>(
+    agent: &mut Agent,
+    o: TypedArray,
+    elements: &[T],
+    gc: NoGcScope,
+) {
+    let Some(len) = typed_array_length_in_bounds::<T>(agent, o, gc) else {
+        return;
+    };
+    let byte_offset = o.byte_offset(agent);
+    let buffer = o.get_viewed_array_buffer(agent, gc);
+    let data_block = agent[buffer].get_data_block_mut();
+    for (k, element) in elements.iter().take(len).enumerate() {
+        data_block.set_offset_by_byte::<T>(byte_offset + k * core::mem::size_of::<T>(), *element);
+    }
+}
+
+/// Performs steps 14 to 19 of %TypedArray%.prototype.fill: writes the value
+/// into the elements in the range from start_index to end_index, clamped to
+/// the current length of the TypedArray.
+fn fill_typed_array<T: Viewable>(
+    agent: &mut Agent,
+    o: TypedArray,
+    value: Numeric,
+    start_index: usize,
+    end_index: usize,
+    gc: NoGcScope,
+) -> JsResult<()> {
+    // 14. Set taRecord to MakeTypedArrayWithBufferWitnessRecord(O, seq-cst).
+    // 15. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    // 16. Set len to TypedArrayLength(taRecord).
+    let Some(len) = typed_array_length_in_bounds::<T>(agent, o, gc) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    };
+    // 17. Set endIndex to min(endIndex, len).
+    let end_index = end_index.min(len);
+    let value = numeric_to_raw::<T>(agent, value);
+    let byte_offset = o.byte_offset(agent);
+    let buffer = o.get_viewed_array_buffer(agent, gc);
+    let data_block = agent[buffer].get_data_block_mut();
+    // 18. Let k be startIndex.
+    // 19. Repeat, while k < endIndex,
+    for k in start_index..end_index {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        // b. Perform ! Set(O, Pk, value, true).
+        // c. Set k to k + 1.
+        data_block.set_offset_by_byte::<T>(byte_offset + k * core::mem::size_of::<T>(), value);
+    }
+    Ok(())
+}
+
+/// Searches the elements of the TypedArray within the given range for the
+/// search element and returns the index of the first match, going in
+/// ascending or descending order. Elements are compared using
+/// IsStrictlyEqual, or SameValueZero if `SAME_VALUE_ZERO` is set. Elements
+/// past the current end of the TypedArray are skipped.
+fn search_typed_array<T: Viewable, const ASCENDING: bool, const SAME_VALUE_ZERO: bool>(
+    agent: &mut Agent,
+    o: TypedArray,
+    search_element: Value,
+    range: core::ops::Range<usize>,
+    gc: NoGcScope,
+) -> Option<usize> {
+    // Only values of the TypedArray's content type can be equal to its
+    // elements.
+    let search_element = if T::IS_BIGINT {
+        BigInt::try_from(search_element).ok()?.into_numeric()
+    } else {
+        Number::try_from(search_element).ok()?.into_numeric()
+    };
+    let len = typed_array_length_in_bounds::<T>(agent, o, gc)?;
+    let range = range.start..range.end.min(len);
+    // If the search element changes when converted into the element type,
+    // then no element can be equal to it. Otherwise the elements can be
+    // compared directly in their raw form.
+    let raw_search_element = numeric_to_raw::<T>(agent, search_element);
+    let round_tripped = raw_to_numeric::<T>(agent, raw_search_element, gc);
+    let is_representable = if SAME_VALUE_ZERO {
+        same_value_zero(agent, search_element, round_tripped)
+    } else {
+        is_strictly_equal(agent, search_element, round_tripped)
+    };
+    if !is_representable {
+        return None;
+    }
+    let byte_offset = o.byte_offset(agent);
+    let data_block = agent[o.get_viewed_array_buffer(agent, gc)].get_data_block();
+    let is_match = |k: &usize| {
+        data_block
+            .get_offset_by_byte::<T>(byte_offset + k * core::mem::size_of::<T>())
+            .unwrap()
+            .is_same_value_zero(raw_search_element)
+    };
+    if ASCENDING {
+        range.into_iter().find(is_match)
+    } else {
+        range.into_iter().rev().find(is_match)
+    }
+}
+
+/// Sorts raw element values using CompareTypedArrayElements.
+fn sort_typed_array_elements<T: Viewable>(
+    agent: &mut Agent,
+    elements: &mut [T],
+    comparator: Option<Scoped<'_, Function<'static>>>,
+    mut gc: GcScope,
+) -> JsResult<()> {
+    if let Some(comparator) = comparator {
+        merge_sort_by(elements, &mut |x, y| {
+            compare_typed_array_elements(agent, x, y, &comparator, gc.reborrow())
+        })
+    } else {
+        // NOTE: Without a comparator, CompareTypedArrayElements is a total
+        // order on the raw element values.
+        elements.sort_by(|x, y| x.ecmascript_cmp(*y));
+        Ok(())
+    }
+}
+
+/// ### [23.2.4.7 CompareTypedArrayElements ( x, y, comparator )](https://tc39.es/ecma262/#sec-comparetypedarrayelements)
+///
+/// The abstract operation CompareTypedArrayElements takes arguments x (a
+/// Number or a BigInt), y (a Number or a BigInt), and comparator (a function
+/// object or undefined) and returns either a normal completion containing a
+/// Number or an abrupt completion.
+///
+/// This variant is used when comparator is a function object.
+fn compare_typed_array_elements<T: Viewable>(
+    agent: &mut Agent,
+    x: T,
+    y: T,
+    comparator: &Scoped<'_, Function<'static>>,
+    mut gc: GcScope,
+) -> JsResult<core::cmp::Ordering> {
+    // 1. Assert: x is a Number and y is a Number, or x is a BigInt and y is a BigInt.
+    let x = raw_to_numeric::<T>(agent, x, gc.nogc()).into_value();
+    let y = raw_to_numeric::<T>(agent, y, gc.nogc()).into_value();
+    // 2. If comparator is not undefined, then
+    // a. Let v be ? ToNumber(? Call(comparator, undefined, « x, y »)).
+    let v = call_function(
+        agent,
+        comparator.get(agent),
+        Value::Undefined,
+        Some(ArgumentsList(&[x, y])),
+        gc.reborrow(),
+    )?;
+    let v = to_number(agent, v, gc.reborrow())?.into_f64(agent);
+    // b. If v is NaN, return +0𝔽.
+    // c. Return v.
+    if v > 0.0 {
+        Ok(core::cmp::Ordering::Greater)
+    } else if v < 0.0 {
+        Ok(core::cmp::Ordering::Less)
+    } else {
+        Ok(core::cmp::Ordering::Equal)
+    }
+}
+
+/// Stable merge sort with a fallible comparison function. Unlike
+/// `slice::sort_by`, this neither panics nor stops early when the comparison
+/// function is not a consistent total order, which a user-provided
+/// comparator is not guaranteed to be.
+fn merge_sort_by<T: Copy>(
+    elements: &mut [T],
+    compare: &mut impl FnMut(T, T) -> JsResult<core::cmp::Ordering>,
+) -> JsResult<()> {
+    let len = elements.len();
+    if len < 2 {
+        return Ok(());
+    }
+    let mut buffer = elements.to_vec();
+    let mut width = 1;
+    while width < len {
+        let mut start = 0;
+        while start < len {
+            let middle = (start + width).min(len);
+            let end = (start + 2 * width).min(len);
+            let (mut i, mut j, mut k) = (start, middle, start);
+            while i < middle && j < end {
+                if compare(elements[j], elements[i])? == core::cmp::Ordering::Less {
+                    buffer[k] = elements[j];
+                    j += 1;
+                } else {
+                    buffer[k] = elements[i];
+                    i += 1;
+                }
+                k += 1;
+            }
+            buffer[k..k + (middle - i)].copy_from_slice(&elements[i..middle]);
+            k += middle - i;
+            buffer[k..end].copy_from_slice(&elements[j..end]);
+            start = end;
+        }
+        elements.copy_from_slice(&buffer);
+        width *= 2;
+    }
+    Ok(())
+}
+
+/// ### [23.2.3.27.1 SetTypedArrayFromTypedArray ( target, targetOffset, source )](https://tc39.es/ecma262/#sec-settypedarrayfromtypedarray)
+///
+/// The abstract operation SetTypedArrayFromTypedArray takes arguments target
+/// (a TypedArray), targetOffset (a non-negative integer or +∞), and source (a
+/// TypedArray) and returns either a normal completion containing unused or a
+/// throw completion. It sets multiple values in target, starting at index
+/// targetOffset, reading the values from source.
+fn set_typed_array_from_typed_array(
+    agent: &mut Agent,
+    target: TypedArray,
+    target_offset: IntegerOrInfinity,
+    source: TypedArray,
+    gc: NoGcScope,
+) -> JsResult<()> {
+    with_typed_array_viewable!(target, Target => {
+        with_typed_array_viewable!(source, Src => {
+            set_typed_array_from_typed_array_generic::<Target, Src>(
+                agent,
+                target,
+                target_offset,
+                source,
+                gc,
+            )
+        })
+    })
+}
+
+fn set_typed_array_from_typed_array_generic<Target: Viewable, Src: Viewable>(
+    agent: &mut Agent,
+    target: TypedArray,
+    target_offset: IntegerOrInfinity,
+    source: TypedArray,
+    gc: NoGcScope,
+) -> JsResult<()> {
+    // 1. Let targetBuffer be target.[[ViewedArrayBuffer]].
+    let target_buffer = target.get_viewed_array_buffer(agent, gc);
+    // 2. Let targetRecord be MakeTypedArrayWithBufferWitnessRecord(target, seq-cst).
+    // 3. If IsTypedArrayOutOfBounds(targetRecord) is true, throw a TypeError exception.
+    // 4. Let targetLength be TypedArrayLength(targetRecord).
+    let Some(target_length) = typed_array_length_in_bounds::<Target>(agent, target, gc) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    };
+    // 5. Let srcBuffer be source.[[ViewedArrayBuffer]].
+    let src_buffer = source.get_viewed_array_buffer(agent, gc);
+    // 6. Let srcRecord be MakeTypedArrayWithBufferWitnessRecord(source, seq-cst).
+    // 7. If IsTypedArrayOutOfBounds(srcRecord) is true, throw a TypeError exception.
+    // 8. Let srcLength be TypedArrayLength(srcRecord).
+    let Some(src_length) = typed_array_length_in_bounds::<Src>(agent, source, gc) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    };
+    // 9. Let targetType be TypedArrayElementType(target).
+    // 10. Let targetElementSize be TypedArrayElementSize(target).
+    let target_element_size = core::mem::size_of::<Target>();
+    // 11. Let srcType be TypedArrayElementType(source).
+    // 12. Let srcElementSize be TypedArrayElementSize(source).
+    let src_element_size = core::mem::size_of::<Src>();
+    // 13. Let targetByteOffset be target.[[ByteOffset]].
+    let target_byte_offset = target.byte_offset(agent);
+    // 14. Let srcByteOffset be source.[[ByteOffset]].
+    let src_byte_offset = source.byte_offset(agent);
+    // 15. If targetOffset = +∞, throw a RangeError exception.
+    // 16. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+    if target_offset.is_pos_infinity()
+        || src_length as i64 + target_offset.into_i64() > target_length as i64
+    {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Source is too large",
+            gc,
+        ));
+    }
+    let target_offset = target_offset.into_i64() as usize;
+    // 17. If target.[[ContentType]] is not source.[[ContentType]], throw a TypeError exception.
+    if Target::IS_BIGINT != Src::IS_BIGINT {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot mix BigInt and other types",
+            gc,
+        ));
+    }
+    // 18. If IsSharedArrayBuffer(srcBuffer) is true, IsSharedArrayBuffer(targetBuffer) is true, and srcBuffer.[[ArrayBufferData]] is targetBuffer.[[ArrayBufferData]], let sameSharedArrayBuffer be true; otherwise, let sameSharedArrayBuffer be false.
+    // 19. If SameValue(srcBuffer, targetBuffer) is true or sameSharedArrayBuffer is true, then
+    //     a. Let srcByteLength be TypedArrayByteLength(srcRecord).
+    //     b. Set srcBuffer to ? CloneArrayBuffer(srcBuffer, srcByteOffset, srcByteLength).
+    //     c. Let srcByteIndex be 0.
+    // 20. Else,
+    //     a. Let srcByteIndex be srcByteOffset.
+    // NOTE: Instead of cloning the source buffer, an overlapping copy or
+    // reading all source elements before writing any is used below.
+    let src_byte_index = src_byte_offset;
+    // 21. Let targetByteIndex be (targetOffset × targetElementSize) + targetByteOffset.
+    let target_byte_index = target_offset * target_element_size + target_byte_offset;
+    // 22. Let limit be targetByteIndex + (targetElementSize × srcLength).
+    // 23. If srcType is targetType, then
+    if Target::PROTO == Src::PROTO {
+        // a. NOTE: The transfer must be performed in a manner that preserves the bit-level encoding of the source data.
+        // b. Repeat, while targetByteIndex < limit,
+        //    i. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, uint8, true, unordered).
+        //    ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, uint8, value, true, unordered).
+        //    iii. Set srcByteIndex to srcByteIndex + 1.
+        //    iv. Set targetByteIndex to targetByteIndex + 1.
+        target_buffer.copy_array_buffer_data(
+            agent,
+            target_byte_index,
+            src_buffer,
+            src_byte_index,
+            src_length * src_element_size,
+        );
+    } else {
+        // 24. Else,
+        //     a. Repeat, while targetByteIndex < limit,
+        //        i. Let value be GetValueFromBuffer(srcBuffer, srcByteIndex, srcType, true, unordered).
+        //        ii. Perform SetValueInBuffer(targetBuffer, targetByteIndex, targetType, value, true, unordered).
+        //        iii. Set srcByteIndex to srcByteIndex + srcElementSize.
+        //        iv. Set targetByteIndex to targetByteIndex + targetElementSize.
+        let values = read_typed_array_elements::<Src>(agent, source, src_length, gc);
+        for (k, value) in values.into_iter().enumerate() {
+            let value = raw_to_numeric::<Src>(agent, value, gc);
+            let value = numeric_to_raw::<Target>(agent, value);
+            agent[target_buffer]
+                .get_data_block_mut()
+                .set_offset_by_byte::<Target>(target_byte_index + k * target_element_size, value);
+        }
+    }
+    // 25. Return unused.
+    Ok(())
+}
+
+/// ### [23.2.3.27.2 SetTypedArrayFromArrayLike ( target, targetOffset, source )](https://tc39.es/ecma262/#sec-settypedarrayfromarraylike)
+///
+/// The abstract operation SetTypedArrayFromArrayLike takes arguments target
+/// (a TypedArray), targetOffset (a non-negative integer or +∞), and source (an
+/// ECMAScript language value, but not a TypedArray) and returns either a
+/// normal completion containing unused or a throw completion. It sets
+/// multiple values in target, starting at index targetOffset, reading the
+/// values from source.
+fn set_typed_array_from_array_like(
+    agent: &mut Agent,
+    target: TypedArray,
+    target_offset: IntegerOrInfinity,
+    source: Value,
+    mut gc: GcScope,
+) -> JsResult<()> {
+    let target = target.bind(gc.nogc());
+    // 1. Let targetRecord be MakeTypedArrayWithBufferWitnessRecord(target, seq-cst).
+    // 2. If IsTypedArrayOutOfBounds(targetRecord) is true, throw a TypeError exception.
+    // 3. Let targetLength be TypedArrayLength(targetRecord).
+    let Some(target_length) = with_typed_array_viewable!(target, T => {
+        typed_array_length_in_bounds::<T>(agent, target, gc.nogc())
+    }) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc.nogc(),
+        ));
+    };
+    let target = target.scope(agent, gc.nogc());
+    // 4. Let src be ? ToObject(source).
+    let src = to_object(agent, source, gc.nogc())?.scope(agent, gc.nogc());
+    // 5. Let srcLength be ? LengthOfArrayLike(src).
+    let src_length = length_of_array_like(agent, src.get(agent), gc.reborrow())?;
+    // 6. If targetOffset = +∞, throw a RangeError exception.
+    // 7. If srcLength + targetOffset > targetLength, throw a RangeError exception.
+    if target_offset.is_pos_infinity()
+        || src_length + target_offset.into_i64() > target_length as i64
+    {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Source is too large",
+            gc.nogc(),
+        ));
+    }
+    let target_offset = target_offset.into_i64();
+    // 8. Let k be 0.
+    // 9. Repeat, while k < srcLength,
+    for k in 0..src_length {
+        // a. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::Integer(k.try_into().unwrap());
+        // b. Let value be ? Get(src, Pk).
+        let value = get(agent, src.get(agent), pk, gc.reborrow())?;
+        // c. Let targetIndex be 𝔽(targetOffset + k).
+        // d. Perform ? TypedArraySetElement(target, targetIndex, value).
+        typed_array_set_element_generic(
+            agent,
+            target.get(agent),
+            target_offset + k,
+            value,
+            gc.reborrow(),
+        )?;
+        // e. Set k to k + 1.
+    }
+    // 10. Return unused.
+    Ok(())
+}
@@ -7,7 +7,7 @@ use core::hash::Hasher;
 use ahash::AHasher;
 
 use crate::ecmascript::abstract_operations::operations_on_objects::{
-    create_array_from_scoped_list, group_by_property, try_get,
+    create_array_from_scoped_list, group_by_collection, try_get,
 };
 use crate::ecmascript::abstract_operations::testing_and_comparison::same_value;
 use crate::ecmascript::builtins::map::data::MapHeapData;
@@ -56,7 +56,7 @@ impl BuiltinIntrinsicConstructor for MapConstructor {
 struct MapGroupBy;
 impl Builtin for MapGroupBy {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(MapConstructor::group_by);
-    const LENGTH: u8 = 0;
+    const LENGTH: u8 = 2;
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.groupBy;
 }
 struct MapGetSpecies;
@@ -159,7 +159,7 @@ impl MapConstructor {
         let items = arguments.get(0);
         let callback_fn = arguments.get(1);
         // 1. Let groups be ? GroupBy(items, callback, collection).
-        let groups = group_by_property(agent, items, callback_fn, gc.reborrow())?;
+        let groups = group_by_collection(agent, items, callback_fn, gc.reborrow())?;
         // 2. Let map be ! Construct(%Map%).
         let mut map_data = MapHeapData::default();
         map_data.reserve(groups.len());
@@ -170,13 +170,13 @@ impl MapConstructor {
         // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
         let mut keys_and_elements = Vec::with_capacity(groups.len());
         for g in groups {
-            let key = g.key.get(agent); // Get the key BEFORE mutable borrow
-            let key = key.convert_to_value(agent, gc);
+            let key = g.key.get(agent).bind(gc);
             // a. Let elements be CreateArrayFromList(g.[[Elements]]).
             let elements = create_array_from_scoped_list(agent, g.elements, gc);
             keys_and_elements.push((key, elements));
         }
 
+        agent.heap.remembered_sets.maps.remember(map.get_index());
         let Heap {
             maps,
             bigints,
@@ -289,6 +289,7 @@ pub fn add_entries_from_iterable_map_constructor<'a>(
                             .into_function(),
                     )
                 {
+                    agent.heap.remembered_sets.maps.remember(target.get_index());
                     let Heap {
                         elements,
                         arrays,
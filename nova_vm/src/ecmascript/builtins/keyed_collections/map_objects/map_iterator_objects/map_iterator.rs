@@ -147,6 +147,10 @@ impl Index<MapIterator<'_>> for Agent {
 
 impl IndexMut<MapIterator<'_>> for Agent {
     fn index_mut(&mut self, index: MapIterator) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .map_iterators
+            .remember(index.get_index());
         &mut self.heap.map_iterators[index]
     }
 }
@@ -187,6 +191,7 @@ impl TryFrom<HeapRootData> for MapIterator<'_> {
 impl CreateHeapData<MapIteratorHeapData, MapIterator<'static>> for Heap {
     fn create(&mut self, data: MapIteratorHeapData) -> MapIterator<'static> {
         self.map_iterators.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<MapIteratorHeapData>>();
         MapIterator(MapIteratorIndex::last(&self.map_iterators))
     }
 }
@@ -136,6 +136,7 @@ impl MapPrototype {
         let gc = gc.into_nogc();
         let m = require_map_data_internal_slot(agent, this_value, gc)?;
 
+        agent.heap.remembered_sets.maps.remember(m.get_index());
         let Heap {
             bigints,
             numbers,
@@ -344,6 +345,7 @@ impl MapPrototype {
         let gc = gc.into_nogc();
         let m = require_map_data_internal_slot(agent, this_value, gc)?;
 
+        agent.heap.remembered_sets.maps.remember(m.get_index());
         let Heap {
             bigints,
             numbers,
@@ -405,6 +407,7 @@ impl MapPrototype {
         let gc = gc.into_nogc();
         let m = require_map_data_internal_slot(agent, this_value, gc)?;
 
+        agent.heap.remembered_sets.maps.remember(m.get_index());
         let Heap {
             bigints,
             numbers,
@@ -126,6 +126,7 @@ impl SetConstructor {
                 // iterator. We can fast-path this.
                 let set = scoped_set.get(agent).bind(gc.nogc());
                 let iterable = scoped_iterable.get(agent).bind(gc.nogc());
+                agent.heap.remembered_sets.sets.remember(set.get_index());
                 let Heap {
                     elements,
                     arrays,
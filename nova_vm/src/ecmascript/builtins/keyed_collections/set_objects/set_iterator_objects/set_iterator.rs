@@ -147,6 +147,10 @@ impl Index<SetIterator<'_>> for Agent {
 
 impl IndexMut<SetIterator<'_>> for Agent {
     fn index_mut(&mut self, index: SetIterator) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .set_iterators
+            .remember(index.get_index());
         &mut self.heap.set_iterators[index]
     }
 }
@@ -187,6 +191,7 @@ impl TryFrom<HeapRootData> for SetIterator<'_> {
 impl CreateHeapData<SetIteratorHeapData, SetIterator<'static>> for Heap {
     fn create(&mut self, data: SetIteratorHeapData) -> SetIterator<'static> {
         self.set_iterators.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<SetIteratorHeapData>>();
         SetIterator(SetIteratorIndex::last(&self.set_iterators))
     }
 }
@@ -99,6 +99,7 @@ impl SetPrototype {
         let gc = gc.into_nogc();
         let s = require_set_data_internal_slot(agent, this_value, gc)?;
 
+        agent.heap.remembered_sets.sets.remember(s.get_index());
         let Heap {
             bigints,
             numbers,
@@ -186,6 +187,7 @@ impl SetPrototype {
         let gc = gc.into_nogc();
         let s = require_set_data_internal_slot(agent, this_value, gc)?;
 
+        agent.heap.remembered_sets.sets.remember(s.get_index());
         let Heap {
             bigints,
             numbers,
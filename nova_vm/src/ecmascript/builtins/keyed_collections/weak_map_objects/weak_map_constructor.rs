@@ -174,6 +174,11 @@ pub fn add_entries_from_iterable_weak_map_constructor<'a>(
                             .into_function(),
                     )
                 {
+                    agent
+                        .heap
+                        .remembered_sets
+                        .weak_maps
+                        .remember(target.get_index());
                     let Heap {
                         elements,
                         arrays,
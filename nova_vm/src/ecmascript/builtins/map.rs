@@ -141,6 +141,7 @@ impl Index<Map<'_>> for Agent {
 
 impl IndexMut<Map<'_>> for Agent {
     fn index_mut(&mut self, index: Map) -> &mut Self::Output {
+        self.heap.remembered_sets.maps.remember(index.get_index());
         &mut self.heap.maps[index]
     }
 }
@@ -191,6 +192,7 @@ impl Rootable for Map<'_> {
 impl CreateHeapData<MapHeapData, Map<'static>> for Heap {
     fn create(&mut self, data: MapHeapData) -> Map<'static> {
         self.maps.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<MapHeapData>>();
         Map(MapIndex::last(&self.maps))
     }
 }
@@ -79,6 +79,10 @@ impl Index<Module<'_>> for Agent {
 
 impl IndexMut<Module<'_>> for Agent {
     fn index_mut(&mut self, index: Module) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .modules
+            .remember(index.get_index());
         &mut self.heap.modules[index]
     }
 }
@@ -2,6 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use num_bigint::Sign;
 use num_bigint::ToBigInt;
 use num_traits::Pow;
 
@@ -184,12 +185,38 @@ impl BigIntConstructor {
             ));
         };
         let bigint = to_big_int(agent, arguments.get(1), gc.reborrow())?;
+        if bits == 0 {
+            return Ok(BigInt::zero().into_value());
+        }
         match bigint {
-            BigInt::BigInt(_) => todo!(),
-            BigInt::SmallBigInt(int) => {
-                let int = int.into_i64();
-                let modulo = int % 2i64.pow(bits);
-                Ok(BigInt::from(SmallBigInt::try_from(modulo).unwrap()).into_value())
+            BigInt::SmallBigInt(int) if bits < 64 => {
+                // Note: 2^bits fits in an i128, and the non-negative
+                // remainder is always below 2^64.
+                let modulo = (int.into_i64() as i128).rem_euclid(1i128 << bits);
+                Ok(BigInt::from_u64(agent, modulo as u64).into_value())
+            }
+            BigInt::SmallBigInt(int) if !int.into_i64().is_negative() => {
+                // The value is already smaller than 2^bits.
+                Ok(bigint.into_value())
+            }
+            BigInt::BigInt(heap_bigint)
+                if agent[heap_bigint].data.sign() != Sign::Minus
+                    && agent[heap_bigint].data.bits() <= bits as u64 =>
+            {
+                // The value is already smaller than 2^bits.
+                Ok(bigint.into_value())
+            }
+            _ => {
+                let value = match bigint {
+                    BigInt::BigInt(heap_bigint) => agent[heap_bigint].data.clone(),
+                    BigInt::SmallBigInt(int) => num_bigint::BigInt::from(int.into_i64()),
+                };
+                let divisor = num_bigint::BigInt::from(1) << bits;
+                let mut modulo = value % &divisor;
+                if modulo.sign() == Sign::Minus {
+                    modulo += divisor;
+                }
+                Ok(BigInt::from_num_bigint(agent, modulo).into_value())
             }
         }
     }
@@ -430,18 +430,43 @@ impl NumberPrototype {
         (flt.len() as i32) - 1
     }
 
+    /// ### [21.1.3.6 Number.prototype.toString ( \[ radix \] )](https://tc39.es/ecma262/#sec-number.prototype.tostring)
     fn to_string(
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        gc: GcScope,
+        mut gc: GcScope,
     ) -> JsResult<Value> {
-        let x = this_number_value(agent, this_value, gc.nogc())?;
+        // 1. Let x be ? ThisNumberValue(this value).
+        let mut x = this_number_value(agent, this_value, gc.nogc())?;
         let radix = arguments.get(0);
+        // 2. If radix is undefined, let radixMV be 10.
         if radix.is_undefined() || radix == Value::from(10u8) {
+            return Ok(Number::to_string_radix_10(agent, x, gc.nogc()).into_value());
+        }
+        // 3. Else, let radixMV be ? ToIntegerOrInfinity(radix).
+        let radix_mv = if let Value::Integer(radix) = radix {
+            radix.into_i64()
+        } else {
+            let x_root = x.scope(agent, gc.nogc());
+            let radix_mv = to_integer_or_infinity(agent, radix, gc.reborrow())?.into_i64();
+            x = x_root.get(agent).bind(gc.nogc());
+            radix_mv
+        };
+        // 4. If radixMV is not in the inclusive interval from 2 to 36, throw a
+        //    RangeError exception.
+        if !(2..=36).contains(&radix_mv) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "radix must be an integer at least 2 and no greater than 36",
+                gc.nogc(),
+            ));
+        }
+        // 5. Return Number::toString(x, radixMV).
+        if radix_mv == 10 {
             Ok(Number::to_string_radix_10(agent, x, gc.nogc()).into_value())
         } else {
-            todo!();
+            Ok(Number::to_string_radix_n(agent, x, radix_mv as u32, gc.nogc()).into_value())
         }
     }
 
@@ -31,18 +31,24 @@ use crate::{
 
 #[cfg(feature = "date")]
 use super::date::data::DateHeapData;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use super::disposable_stack::data::DisposableStackHeapData;
 #[cfg(feature = "regexp")]
 use super::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
 use super::shared_array_buffer::data::SharedArrayBufferHeapData;
 use super::{
     async_generator_objects::AsyncGeneratorHeapData,
-    control_abstraction_objects::generator_objects::GeneratorHeapData, error::ErrorHeapData,
+    control_abstraction_objects::generator_objects::GeneratorHeapData,
+    error::{capture_stack_trace, ErrorHeapData},
     finalization_registry::data::FinalizationRegistryHeapData,
     indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIteratorHeapData,
     keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIteratorHeapData,
-    map::data::MapHeapData, module::Module, primitive_objects::PrimitiveObjectHeapData,
-    promise::data::PromiseHeapData, ArrayHeapData,
+    map::data::MapHeapData,
+    module::Module,
+    primitive_objects::PrimitiveObjectHeapData,
+    promise::data::PromiseHeapData,
+    ArrayHeapData,
 };
 #[cfg(feature = "array-buffer")]
 use super::{
@@ -69,6 +75,10 @@ impl Index<OrdinaryObject<'_>> for Agent {
 
 impl IndexMut<OrdinaryObject<'_>> for Agent {
     fn index_mut(&mut self, index: OrdinaryObject<'_>) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .objects
+            .remember(index.get_index());
         &mut self.heap.objects[index]
     }
 }
@@ -1095,18 +1105,15 @@ pub(crate) fn ordinary_own_property_keys<'a>(
     object: OrdinaryObject<'a>,
     _: NoGcScope<'a, '_>,
 ) -> Vec<PropertyKey<'a>> {
-    let object_keys = agent[object].keys;
+    let object_keys = agent[object].shape.keys(&agent.heap.object_shapes);
     // 1. Let keys be a new empty List.
     let mut integer_keys = vec![];
-    let mut keys = Vec::with_capacity(object_keys.len() as usize);
+    let mut keys = Vec::with_capacity(object_keys.len());
     let mut symbol_keys = vec![];
 
     // 3. For each own property key P of O such that P is a String and P is not an array index, in
     //    ascending chronological order of property creation, do
-    for key in agent[object_keys].iter() {
-        // SAFETY: Keys are all PropertyKeys reinterpreted as Values without
-        // conversion.
-        let key = unsafe { PropertyKey::from_value_unchecked(key.unwrap()) };
+    for &key in object_keys.iter() {
         match key {
             PropertyKey::Integer(integer_key) => {
                 let key_value = integer_key.into_i64();
@@ -1138,7 +1145,7 @@ pub(crate) fn ordinary_own_property_keys<'a>(
         keys.extend(symbol_keys.iter().map(|key| PropertyKey::Symbol(*key)));
     }
 
-    debug_assert_eq!(keys.len() as u32, object_keys.len());
+    debug_assert_eq!(keys.len(), object_keys.len());
 
     // 5. Return keys.
     keys
@@ -1199,14 +1206,34 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
             .into_object(),
         ProtoIntrinsics::Error => agent
             .heap
-            .create(ErrorHeapData::new(ExceptionType::Error, None, None))
+            .create(ErrorHeapData::new(
+                ExceptionType::Error,
+                None,
+                None,
+                capture_stack_trace(agent, 1),
+            ))
             .into_object(),
         ProtoIntrinsics::EvalError => agent
             .heap
-            .create(ErrorHeapData::new(ExceptionType::EvalError, None, None))
+            .create(ErrorHeapData::new(
+                ExceptionType::EvalError,
+                None,
+                None,
+                capture_stack_trace(agent, 1),
+            ))
             .into_object(),
         #[cfg(feature = "date")]
         ProtoIntrinsics::Date => agent.heap.create(DateHeapData::new_invalid()).into_object(),
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ProtoIntrinsics::DisposableStack => agent
+            .heap
+            .create(DisposableStackHeapData::new(false))
+            .into_object(),
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ProtoIntrinsics::AsyncDisposableStack => agent
+            .heap
+            .create(DisposableStackHeapData::new(true))
+            .into_object(),
         ProtoIntrinsics::Function => todo!(),
         ProtoIntrinsics::Number => agent
             .heap
@@ -1225,7 +1252,12 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
             .into(),
         ProtoIntrinsics::RangeError => agent
             .heap
-            .create(ErrorHeapData::new(ExceptionType::RangeError, None, None))
+            .create(ErrorHeapData::new(
+                ExceptionType::RangeError,
+                None,
+                None,
+                capture_stack_trace(agent, 1),
+            ))
             .into_object(),
         ProtoIntrinsics::ReferenceError => agent
             .heap
@@ -1233,6 +1265,7 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
                 ExceptionType::ReferenceError,
                 None,
                 None,
+                capture_stack_trace(agent, 1),
             ))
             .into_object(),
         ProtoIntrinsics::String => agent
@@ -1249,15 +1282,30 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
             .into_object(),
         ProtoIntrinsics::SyntaxError => agent
             .heap
-            .create(ErrorHeapData::new(ExceptionType::SyntaxError, None, None))
+            .create(ErrorHeapData::new(
+                ExceptionType::SyntaxError,
+                None,
+                None,
+                capture_stack_trace(agent, 1),
+            ))
             .into_object(),
         ProtoIntrinsics::TypeError => agent
             .heap
-            .create(ErrorHeapData::new(ExceptionType::TypeError, None, None))
+            .create(ErrorHeapData::new(
+                ExceptionType::TypeError,
+                None,
+                None,
+                capture_stack_trace(agent, 1),
+            ))
             .into_object(),
         ProtoIntrinsics::UriError => agent
             .heap
-            .create(ErrorHeapData::new(ExceptionType::UriError, None, None))
+            .create(ErrorHeapData::new(
+                ExceptionType::UriError,
+                None,
+                None,
+                capture_stack_trace(agent, 1),
+            ))
             .into_object(),
         ProtoIntrinsics::AggregateError => agent
             .heap
@@ -1265,6 +1313,17 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
                 ExceptionType::AggregateError,
                 None,
                 None,
+                capture_stack_trace(agent, 1),
+            ))
+            .into_object(),
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ProtoIntrinsics::SuppressedError => agent
+            .heap
+            .create(ErrorHeapData::new(
+                ExceptionType::SuppressedError,
+                None,
+                None,
+                capture_stack_trace(agent, 1),
             ))
             .into_object(),
         ProtoIntrinsics::AsyncFunction => todo!(),
@@ -1472,6 +1531,12 @@ pub(crate) fn get_prototype_from_constructor<'a>(
             ProtoIntrinsics::DataView => Some(intrinsics.data_view().into_function()),
             #[cfg(feature = "date")]
             ProtoIntrinsics::Date => Some(intrinsics.date().into_function()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::DisposableStack => Some(intrinsics.disposable_stack().into_function()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::AsyncDisposableStack => {
+                Some(intrinsics.async_disposable_stack().into_function())
+            }
             ProtoIntrinsics::Error => Some(intrinsics.error().into_function()),
             ProtoIntrinsics::EvalError => Some(intrinsics.eval_error().into_function()),
             ProtoIntrinsics::FinalizationRegistry => {
@@ -1526,6 +1591,8 @@ pub(crate) fn get_prototype_from_constructor<'a>(
                 Some(intrinsics.uint8_clamped_array().into_function())
             }
             ProtoIntrinsics::UriError => Some(intrinsics.uri_error().into_function()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::SuppressedError => Some(intrinsics.suppressed_error().into_function()),
             #[cfg(feature = "weak-refs")]
             ProtoIntrinsics::WeakMap => Some(intrinsics.weak_map().into_function()),
             #[cfg(feature = "weak-refs")]
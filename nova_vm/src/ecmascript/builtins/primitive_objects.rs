@@ -102,6 +102,10 @@ impl Index<PrimitiveObject<'_>> for Agent {
 
 impl IndexMut<PrimitiveObject<'_>> for Agent {
     fn index_mut(&mut self, index: PrimitiveObject) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .primitive_objects
+            .remember(index.get_index());
         &mut self.heap.primitive_objects[index]
     }
 }
@@ -733,6 +737,7 @@ impl HeapMarkAndSweep for PrimitiveObject<'static> {
 impl CreateHeapData<PrimitiveObjectHeapData, PrimitiveObject<'static>> for Heap {
     fn create(&mut self, data: PrimitiveObjectHeapData) -> PrimitiveObject<'static> {
         self.primitive_objects.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<PrimitiveObjectHeapData>>();
         PrimitiveObject(PrimitiveObjectIndex::last(&self.primitive_objects))
     }
 }
@@ -131,6 +131,7 @@ impl<'a> InternalMethods<'a> for Promise<'a> {}
 impl CreateHeapData<PromiseHeapData, Promise<'static>> for Heap {
     fn create(&mut self, data: PromiseHeapData) -> Promise<'static> {
         self.promises.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<PromiseHeapData>>();
         Promise(PromiseIndex::last(&self.promises))
     }
 }
@@ -145,6 +146,10 @@ impl Index<Promise<'_>> for Agent {
 
 impl IndexMut<Promise<'_>> for Agent {
     fn index_mut(&mut self, index: Promise) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .promises
+            .remember(index.get_index());
         &mut self.heap.promises[index]
     }
 }
@@ -1672,6 +1672,7 @@ impl Index<Proxy<'_>> for Agent {
 
 impl IndexMut<Proxy<'_>> for Agent {
     fn index_mut(&mut self, index: Proxy) -> &mut Self::Output {
+        self.heap.remembered_sets.proxys.remember(index.get_index());
         &mut self.heap.proxys[index]
     }
 }
@@ -1712,6 +1713,7 @@ impl TryFrom<HeapRootData> for Proxy<'_> {
 impl CreateHeapData<ProxyHeapData, Proxy<'static>> for Heap {
     fn create(&mut self, data: ProxyHeapData) -> Proxy<'static> {
         self.proxys.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ProxyHeapData>>();
         Proxy(ProxyIndex::last(&self.proxys))
     }
 }
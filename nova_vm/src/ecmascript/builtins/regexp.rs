@@ -127,7 +127,7 @@ impl<'a> InternalSlots<'a> for RegExp<'a> {
         assert!(self.get_backing_object(agent).is_none());
         let prototype = self.internal_prototype(agent);
         let last_index = agent[self].last_index;
-        let (keys, values) = agent.heap.elements.create_object_entries(&[ObjectEntry {
+        let (shape, values) = agent.heap.create_object_entries(&[ObjectEntry {
             key: BUILTIN_STRING_MEMORY.lastIndex.into(),
             value: ObjectEntryPropertyDescriptor::Data {
                 value: last_index
@@ -141,7 +141,7 @@ impl<'a> InternalSlots<'a> for RegExp<'a> {
         let backing_object = agent.heap.create(ObjectHeapData {
             extensible: true,
             prototype,
-            keys,
+            shape,
             values,
         });
         self.set_backing_object(agent, backing_object);
@@ -399,6 +399,10 @@ impl Index<RegExp<'_>> for Agent {
 
 impl IndexMut<RegExp<'_>> for Agent {
     fn index_mut(&mut self, index: RegExp) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .regexps
+            .remember(index.get_index());
         &mut self.heap.regexps[index]
     }
 }
@@ -439,6 +443,7 @@ impl TryFrom<HeapRootData> for RegExp<'_> {
 impl CreateHeapData<RegExpHeapData, RegExp<'static>> for Heap {
     fn create(&mut self, data: RegExpHeapData) -> RegExp<'static> {
         self.regexps.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<RegExpHeapData>>();
         RegExp(RegExpIndex::last(&self.regexps))
     }
 }
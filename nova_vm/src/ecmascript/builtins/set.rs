@@ -151,6 +151,7 @@ impl Index<Set<'_>> for Agent {
 
 impl IndexMut<Set<'_>> for Agent {
     fn index_mut(&mut self, index: Set) -> &mut Self::Output {
+        self.heap.remembered_sets.sets.remember(index.get_index());
         &mut self.heap.sets[index]
     }
 }
@@ -191,6 +192,7 @@ impl TryFrom<HeapRootData> for Set<'_> {
 impl CreateHeapData<SetHeapData, Set<'static>> for Heap {
     fn create(&mut self, data: SetHeapData) -> Set<'static> {
         self.sets.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<SetHeapData>>();
         Set(SetIndex::last(&self.sets))
     }
 }
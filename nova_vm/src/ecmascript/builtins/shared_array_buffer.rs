@@ -98,6 +98,10 @@ impl Index<SharedArrayBuffer<'_>> for Agent {
 
 impl IndexMut<SharedArrayBuffer<'_>> for Agent {
     fn index_mut(&mut self, index: SharedArrayBuffer) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .shared_array_buffers
+            .remember(index.get_index());
         &mut self.heap.shared_array_buffers[index]
     }
 }
@@ -166,6 +170,7 @@ impl HeapMarkAndSweep for SharedArrayBuffer<'static> {
 impl CreateHeapData<SharedArrayBufferHeapData, SharedArrayBuffer<'static>> for Heap {
     fn create(&mut self, data: SharedArrayBufferHeapData) -> SharedArrayBuffer<'static> {
         self.shared_array_buffers.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<SharedArrayBufferHeapData>>();
         SharedArrayBuffer(SharedArrayBufferIndex::last(&self.shared_array_buffers))
     }
 }
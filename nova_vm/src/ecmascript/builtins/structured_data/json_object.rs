@@ -98,8 +98,11 @@ impl JSONObject {
         let reviver = arguments.get(1);
 
         // 1. Let jsonString be ? ToString(text).
-        let json_string = to_string(agent, text, gc.reborrow())?;
+        let json_string = to_string(agent, text, gc.reborrow())?
+            .unbind()
+            .bind(gc.nogc());
 
+        agent.use_fuel(json_string.len(agent) as u64, gc.nogc())?;
         // 2. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification.
         let json_value = match sonic_rs::from_str::<sonic_rs::Value>(json_string.as_str(agent)) {
             Ok(value) => value,
@@ -1111,6 +1111,10 @@ impl StringPrototype {
             return Ok(s.into_value());
         }
 
+        let byte_length = s.len(agent).saturating_mul(n as usize);
+        agent.reserve_heap_bytes(byte_length, gc.nogc())?;
+        agent.use_fuel(byte_length as u64, gc.nogc())?;
+
         // 6. Return the String value that is made from n copies of S appended together.
         Ok(Value::from_string(
             agent,
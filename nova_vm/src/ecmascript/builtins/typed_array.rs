@@ -292,6 +292,10 @@ impl Index<TypedArray<'_>> for Agent {
 
 impl IndexMut<TypedArray<'_>> for Agent {
     fn index_mut(&mut self, index: TypedArray) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .typed_arrays
+            .remember(index.get_index());
         &mut self.heap.typed_arrays[index]
     }
 }
@@ -343,7 +347,7 @@ impl<'a> InternalSlots<'a> for TypedArray<'a> {
                 TypedArray::Int32Array(_) => intrinsics.int32_array_prototype(),
                 TypedArray::Uint32Array(_) => intrinsics.uint32_array_prototype(),
                 TypedArray::BigInt64Array(_) => intrinsics.big_int64_array_prototype(),
-                TypedArray::BigUint64Array(_) => intrinsics.big_int64_array_prototype(),
+                TypedArray::BigUint64Array(_) => intrinsics.big_uint64_array_prototype(),
                 #[cfg(feature = "proposal-float16array")]
                 TypedArray::Float16Array(_) => intrinsics.float16_array_prototype(),
                 TypedArray::Float32Array(_) => intrinsics.float32_array_prototype(),
@@ -846,6 +850,7 @@ impl TryFrom<HeapRootData> for TypedArray<'_> {
 impl CreateHeapData<TypedArrayHeapData, TypedArray<'static>> for Heap {
     fn create(&mut self, data: TypedArrayHeapData) -> TypedArray<'static> {
         self.typed_arrays.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<TypedArrayHeapData>>();
         // TODO: The type should be checked based on data or something equally stupid
         TypedArray::Uint8Array(TypedArrayIndex::last(&self.typed_arrays))
     }
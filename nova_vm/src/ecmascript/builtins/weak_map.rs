@@ -128,6 +128,10 @@ impl Index<WeakMap<'_>> for Agent {
 
 impl IndexMut<WeakMap<'_>> for Agent {
     fn index_mut(&mut self, index: WeakMap) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .weak_maps
+            .remember(index.get_index());
         &mut self.heap.weak_maps[index]
     }
 }
@@ -168,6 +172,7 @@ impl TryFrom<HeapRootData> for WeakMap<'_> {
 impl CreateHeapData<WeakMapHeapData, WeakMap<'static>> for Heap {
     fn create(&mut self, data: WeakMapHeapData) -> WeakMap<'static> {
         self.weak_maps.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<WeakMapHeapData>>();
         // TODO: The type should be checked based on data or something equally stupid
         WeakMap(WeakMapIndex::last(&self.weak_maps))
     }
@@ -116,6 +116,10 @@ impl Index<WeakRef<'_>> for Agent {
 
 impl IndexMut<WeakRef<'_>> for Agent {
     fn index_mut(&mut self, index: WeakRef) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .weak_refs
+            .remember(index.get_index());
         &mut self.heap.weak_refs[index]
     }
 }
@@ -156,6 +160,7 @@ impl TryFrom<HeapRootData> for WeakRef<'_> {
 impl CreateHeapData<WeakRefHeapData, WeakRef<'static>> for Heap {
     fn create(&mut self, data: WeakRefHeapData) -> WeakRef<'static> {
         self.weak_refs.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<WeakRefHeapData>>();
         // TODO: The type should be checked based on data or something equally stupid
         WeakRef(WeakRefIndex::last(&self.weak_refs))
     }
@@ -127,6 +127,10 @@ impl Index<WeakSet<'_>> for Agent {
 
 impl IndexMut<WeakSet<'_>> for Agent {
     fn index_mut(&mut self, index: WeakSet) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .weak_sets
+            .remember(index.get_index());
         &mut self.heap.weak_sets[index]
     }
 }
@@ -167,6 +171,7 @@ impl TryFrom<HeapRootData> for WeakSet<'_> {
 impl CreateHeapData<WeakSetHeapData, WeakSet<'static>> for Heap {
     fn create(&mut self, data: WeakSetHeapData) -> WeakSet<'static> {
         self.weak_sets.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<WeakSetHeapData>>();
         // TODO: The type should be checked based on data or something equally stupid
         WeakSet(WeakSetIndex::last(&self.weak_sets))
     }
@@ -14,8 +14,8 @@ pub(crate) use environments::{
     get_this_environment, new_class_field_initializer_environment,
     new_class_static_element_environment, new_declarative_environment, new_function_environment,
     DeclarativeEnvironmentIndex, EnvironmentIndex, Environments, FunctionEnvironmentIndex,
-    GlobalEnvironment, GlobalEnvironmentIndex, ModuleEnvironmentIndex, ObjectEnvironmentIndex,
-    PrivateEnvironmentIndex, ThisBindingStatus,
+    GlobalEnvironment, GlobalEnvironmentIndex, ModuleEnvironmentIndex, ObjectEnvironment,
+    ObjectEnvironmentIndex, PrivateEnvironmentIndex, ThisBindingStatus,
 };
 pub(crate) use execution_context::*;
 #[cfg(test)]
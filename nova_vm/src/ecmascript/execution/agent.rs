@@ -15,17 +15,92 @@ use super::{
 use crate::{
     ecmascript::{
         abstract_operations::type_conversion::to_string,
-        builtins::{control_abstraction_objects::promise_objects::promise_abstract_operations::promise_jobs::{PromiseReactionJob, PromiseResolveThenableJob}, error::ErrorHeapData, promise::Promise},
+        builtins::{control_abstraction_objects::promise_objects::promise_abstract_operations::promise_jobs::{PromiseReactionJob, PromiseResolveThenableJob}, error::{capture_stack_trace, ErrorHeapData, StackFrame}, promise::Promise},
         scripts_and_modules::ScriptOrModule,
         types::{Function, IntoValue, Object, Reference, String, Symbol, Value},
-    }, engine::{context::{GcScope, NoGcScope}, rootable::HeapRootData, TryResult, Vm}, heap::{heap_gc::heap_gc, CreateHeapData, HeapMarkAndSweep, PrimitiveHeapIndexable}, Heap
+    }, engine::{context::{GcScope, NoGcScope}, rootable::HeapRootData, TryResult, Vm}, heap::{heap_gc::heap_gc, heap_snapshot::write_heap_snapshot, CreateHeapData, HeapStatistics, HeapMarkAndSweep, PrimitiveHeapIndexable}, Heap
 };
 use core::{any::Any, cell::RefCell, ptr::NonNull};
+use std::sync::Arc;
+
+mod coverage;
+mod debugger;
+mod fuel;
+mod interrupt_handle;
+mod profiler;
+
+pub(crate) use coverage::CoverageCounters;
+pub use coverage::{BlockCoverage, CoverageReport, FunctionCoverage, ScriptCoverage};
+pub(crate) use debugger::Debugger;
+pub use debugger::{
+    BreakpointId, DebugAction, DebugCallFrame, DebugScope, DebugScopeKind, DebugSourceId,
+    PauseReason,
+};
+pub use interrupt_handle::InterruptHandle;
+use interrupt_handle::InterruptRequests;
+pub(crate) use profiler::Profiler;
+pub use profiler::{CpuProfile, ProfileNode, ProfileSample, SampleInterval};
 
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Options {
     pub disable_gc: bool,
     pub print_internals: bool,
+    /// Heap size in bytes at which the first full garbage collection is due,
+    /// and the minimum heap size for later full collections.
+    pub gc_initial_threshold: usize,
+    /// Factor by which the heap can grow after a full garbage collection
+    /// before the next full collection is due. For example, with a growth
+    /// factor of 2.0 a heap of 10 MiB after collection is fully collected
+    /// again once it has grown to 20 MiB.
+    pub gc_growth_factor: f64,
+    /// Number of bytes that can be allocated between minor garbage
+    /// collections, which only collect data allocated since the previous
+    /// collection. If zero, every garbage collection is a full collection.
+    pub gc_nursery_size: usize,
+    /// Maximum number of heap items traced by a single step of incremental
+    /// marking. Full garbage collections mark the heap in steps, between which
+    /// the program runs for as long as it would between minor collections,
+    /// and only pause the program as a whole for rescanning the roots and
+    /// compacting the heap. If zero, full collections are not incremental.
+    pub gc_incremental_step: usize,
+    /// Maximum size of the heap in bytes. When the heap grows past this, a
+    /// full garbage collection is performed. If the heap is still too large
+    /// after it, a RangeError is thrown. The heap may then grow by up to an
+    /// eighth of the limit so that the error can be handled, until a full
+    /// collection finds the heap within the limit again. If None, the heap
+    /// size is not limited.
+    ///
+    /// Note: With garbage collection disabled, only large single allocations
+    /// such as ArrayBuffers are checked against the limit.
+    pub max_heap_bytes: Option<usize>,
+    /// Maximum byte length of a single ArrayBuffer or SharedArrayBuffer,
+    /// including the maximum byte length of resizable ArrayBuffers. Creating
+    /// a larger buffer throws a RangeError. If None, the byte length is only
+    /// limited by [`Options::max_heap_bytes`].
+    pub max_array_buffer_byte_length: Option<usize>,
+    /// Amount of fuel the Agent starts with. Each executed bytecode
+    /// instruction consumes one unit of fuel, as do the elements or bytes
+    /// processed by a few builtins, such as `Array.prototype.sort`, whose work
+    /// is proportional to their input. When the fuel runs out,
+    /// [`HostHooks::host_out_of_fuel`] is called. If None, execution is not
+    /// metered.
+    pub fuel: Option<u64>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            disable_gc: false,
+            print_internals: false,
+            gc_initial_threshold: 4 * 1024 * 1024,
+            gc_growth_factor: 2.0,
+            gc_nursery_size: 1024 * 1024,
+            gc_incremental_step: 16 * 1024,
+            max_heap_bytes: None,
+            max_array_buffer_byte_length: None,
+            fuel: None,
+        }
+    }
 }
 
 pub type JsResult<T> = core::result::Result<T, JsError>;
@@ -46,6 +121,24 @@ impl JsError {
     pub fn to_string<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> String<'gc> {
         to_string(agent, self.0, gc).unwrap()
     }
+
+    /// Returns the call sites captured when the thrown Error was created,
+    /// innermost first. Empty if the thrown value is not an Error.
+    pub fn stack_frames(self, agent: &Agent) -> &[StackFrame] {
+        match self.0 {
+            Value::Error(error) => error.stack_frames(agent),
+            _ => &[],
+        }
+    }
+
+    /// Returns the formatted stack of the thrown Error, or None if the thrown
+    /// value is not an Error.
+    pub fn stack(self, agent: &mut Agent, gc: NoGcScope) -> Option<std::string::String> {
+        match self.0 {
+            Value::Error(error) => Some(error.stack_string(agent, gc)),
+            _ => None,
+        }
+    }
 }
 
 impl HeapMarkAndSweep for JsError {
@@ -133,6 +226,28 @@ pub trait HostHooks: core::fmt::Debug {
         // The default implementation of HostPromiseRejectionTracker is to return unused.
     }
 
+    /// Called when the Agent runs out of the fuel set with [`Options::fuel`]
+    /// or [`Agent::set_fuel`]. To resume the execution, the host adds fuel
+    /// with [`Agent::set_fuel`] and returns true. Otherwise the execution is
+    /// terminated.
+    fn host_out_of_fuel(&self, _agent: &mut Agent) -> bool {
+        // By default, running out of fuel terminates the execution.
+        false
+    }
+
+    /// Called when the execution pauses in the debugger enabled with
+    /// [`Agent::enable_debugger`]. The host can inspect the paused execution
+    /// through [`Agent::debug_call_frames`], and returns how it resumes.
+    fn host_debugger_paused(
+        &self,
+        _agent: &mut Agent,
+        _reason: PauseReason,
+        _gc: GcScope,
+    ) -> DebugAction {
+        // By default, the execution continues.
+        DebugAction::Continue
+    }
+
     /// Get access to the Host data, useful to share state between calls of built-in functions.
     ///
     /// Note: This will panic if not implemented manually.
@@ -145,7 +260,6 @@ pub trait HostHooks: core::fmt::Debug {
 /// collection on the Agent heap.
 pub struct GcAgent {
     agent: Agent,
-    realm_roots: Vec<Option<RealmIdentifier>>,
 }
 
 /// ECMAScript Realm root
@@ -155,7 +269,7 @@ pub struct GcAgent {
 #[must_use]
 #[repr(transparent)]
 pub struct RealmRoot {
-    /// Defines an index in the GcAgent::realm_roots vector that contains the
+    /// Defines an index in the Agent::realm_roots vector that contains the
     /// RealmIdentifier of this Realm.
     index: u8,
 }
@@ -164,12 +278,12 @@ impl GcAgent {
     pub fn new(options: Options, host_hooks: &'static dyn HostHooks) -> Self {
         Self {
             agent: Agent::new(options, host_hooks),
-            realm_roots: Vec::with_capacity(1),
         }
     }
 
     fn root_realm(&mut self, identifier: RealmIdentifier) -> RealmRoot {
         let index = if let Some((index, deleted_entry)) = self
+            .agent
             .realm_roots
             .iter_mut()
             .enumerate()
@@ -178,8 +292,8 @@ impl GcAgent {
             *deleted_entry = Some(identifier);
             index
         } else {
-            self.realm_roots.push(Some(identifier));
-            self.realm_roots.len() - 1
+            self.agent.realm_roots.push(Some(identifier));
+            self.agent.realm_roots.len() - 1
         };
         // Agent's Realm creation should've already popped the context that
         // created this Realm. The context stack should now be empty.
@@ -224,13 +338,15 @@ impl GcAgent {
         let error_message = "Cannot remove a non-existing Realm";
         // After this removal, the Realm can be collected by GC.
         let _ = self
+            .agent
             .realm_roots
             .get_mut(index as usize)
             .expect(error_message)
             .take()
             .expect(error_message);
-        while !self.realm_roots.is_empty() && self.realm_roots.last().unwrap().is_none() {
-            let _ = self.realm_roots.pop();
+        while !self.agent.realm_roots.is_empty() && self.agent.realm_roots.last().unwrap().is_none()
+        {
+            let _ = self.agent.realm_roots.pop();
         }
     }
 
@@ -241,6 +357,7 @@ impl GcAgent {
         let index = realm.index;
         let error_message = "Attempted to run in non-existing Realm";
         let realm = *self
+            .agent
             .realm_roots
             .get(index as usize)
             .expect(error_message)
@@ -251,9 +368,16 @@ impl GcAgent {
         assert!(self.agent.execution_context_stack.is_empty());
         assert!(self.agent.vm_stack.is_empty());
         self.agent.stack_refs.borrow_mut().clear();
+        self.agent.end_termination();
         result
     }
 
+    /// Returns a handle for interrupting or terminating the execution of the
+    /// agent from another thread.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle(self.agent.interrupts.clone())
+    }
+
     pub fn gc(&mut self) {
         if self.agent.options.disable_gc {
             // GC is disabled; no-op
@@ -261,7 +385,19 @@ impl GcAgent {
         }
         let (mut gc, mut scope) = unsafe { GcScope::create_root() };
         let gc = GcScope::new(&mut gc, &mut scope);
-        heap_gc(&mut self.agent, &mut self.realm_roots, gc);
+        heap_gc(&mut self.agent, gc);
+    }
+
+    /// Returns the memory usage statistics of the heap.
+    pub fn heap_statistics(&self) -> HeapStatistics {
+        self.agent.heap.statistics()
+    }
+
+    /// Writes a snapshot of all heap data reachable from the roots of the
+    /// agent in the V8 `.heapsnapshot` JSON format. The snapshot can be
+    /// loaded into the Memory tab of Chrome DevTools.
+    pub fn write_heap_snapshot(&self, writer: &mut impl std::io::Write) -> std::io::Result<()> {
+        write_heap_snapshot(&self.agent, writer)
     }
 }
 
@@ -281,12 +417,43 @@ pub struct Agent {
     pub(crate) stack_refs: RefCell<Vec<HeapRootData>>,
     /// Temporary storage for on-stack VMs.
     pub(crate) vm_stack: Vec<NonNull<Vm>>,
+    /// Realms rooted by the embedder through [`GcAgent`]. Other Realms are
+    /// kept alive only by references from the heap or the execution context
+    /// stack.
+    pub(crate) realm_roots: Vec<Option<RealmIdentifier>>,
+    /// Interrupt requests made through [`InterruptHandle`]s.
+    pub(crate) interrupts: Arc<InterruptRequests>,
+    /// True if the execution is being terminated through an
+    /// [`InterruptHandle`].
+    pub(crate) terminating: bool,
+    /// Remaining fuel, or None if execution is not metered.
+    pub(crate) fuel: Option<u64>,
+    /// State of the debugger, or None if it is not enabled.
+    pub(crate) debugger: Option<Box<Debugger>>,
+    /// State of the CPU profiler, or None if it is not running.
+    pub(crate) profiler: Option<Box<Profiler>>,
+    /// Code coverage counters, or None if coverage is not being collected.
+    pub(crate) coverage: Option<Box<CoverageCounters>>,
 }
 
 impl Agent {
     pub(crate) fn new(options: Options, host_hooks: &'static dyn HostHooks) -> Self {
+        let mut heap = Heap::new();
+        heap.gc_major_threshold = options.gc_initial_threshold;
+        heap.gc_allocation_budget = if options.gc_nursery_size == 0 {
+            options.gc_initial_threshold
+        } else {
+            options.gc_nursery_size
+        };
+        heap.gc_live_bytes = heap.estimated_size();
+        if let Some(max_heap_bytes) = options.max_heap_bytes {
+            heap.gc_allocation_budget = heap
+                .gc_allocation_budget
+                .min(max_heap_bytes.saturating_sub(heap.gc_live_bytes));
+        }
+        let fuel = options.fuel;
         Self {
-            heap: Heap::new(),
+            heap,
             options,
             symbol_id: 0,
             global_symbol_registry: AHashMap::default(),
@@ -294,7 +461,68 @@ impl Agent {
             execution_context_stack: Vec::new(),
             stack_refs: RefCell::new(Vec::with_capacity(64)),
             vm_stack: Vec::with_capacity(16),
+            realm_roots: Vec::with_capacity(1),
+            interrupts: Arc::default(),
+            terminating: false,
+            fuel,
+            debugger: None,
+            profiler: None,
+            coverage: None,
+        }
+    }
+
+    /// Returns true if enough has been allocated since the last garbage
+    /// collection that the next one is due.
+    pub(crate) fn gc_is_due(&self) -> bool {
+        !self.options.disable_gc && self.heap.allocated_bytes() >= self.heap.gc_allocation_budget
+    }
+
+    /// Returns true if `bytes` can be allocated onto the heap without growing
+    /// it past [`Options::max_heap_bytes`], and the grace given after an out
+    /// of memory error.
+    pub(crate) fn can_allocate(&self, bytes: usize) -> bool {
+        match self.options.max_heap_bytes {
+            Some(max_heap_bytes) => {
+                self.heap.used_bytes().saturating_add(bytes)
+                    <= max_heap_bytes.saturating_add(self.heap.gc_heap_limit_grace)
+            }
+            None => true,
+        }
+    }
+
+    /// Throws a RangeError if allocating `bytes` onto the heap would grow it
+    /// past [`Options::max_heap_bytes`].
+    ///
+    /// The heap size is only an estimate until the next full garbage
+    /// collection, which cannot be performed here. A full collection is thus
+    /// made due at the next safepoint.
+    pub(crate) fn reserve_heap_bytes(&mut self, bytes: usize, gc: NoGcScope) -> JsResult<()> {
+        if self.can_allocate(bytes) {
+            Ok(())
+        } else {
+            let err = self.throw_out_of_memory(gc);
+            self.heap.gc_allocation_budget = 0;
+            Err(err)
+        }
+    }
+
+    /// Creates the RangeError thrown when the heap has grown past
+    /// [`Options::max_heap_bytes`].
+    ///
+    /// The heap is allowed to grow by an eighth of the limit past its current
+    /// size until a full garbage collection finds the heap within the limit
+    /// again, so that the program can handle the error and release memory.
+    pub(crate) fn throw_out_of_memory(&mut self, gc: NoGcScope) -> JsError {
+        if let Some(max_heap_bytes) = self.options.max_heap_bytes {
+            if self.heap.gc_heap_limit_grace == 0 {
+                self.heap.gc_heap_limit_grace =
+                    self.heap.used_bytes().saturating_sub(max_heap_bytes) + max_heap_bytes / 8;
+            }
+            let limit = max_heap_bytes.saturating_add(self.heap.gc_heap_limit_grace);
+            self.heap.gc_allocation_budget =
+                self.heap.allocated_bytes() + limit.saturating_sub(self.heap.used_bytes());
         }
+        self.throw_exception_with_static_message(ExceptionType::RangeError, "Out of memory", gc)
     }
 
     fn get_created_realm_root(&mut self) -> RealmIdentifier {
@@ -391,8 +619,9 @@ impl Agent {
         gc: NoGcScope,
     ) -> Value {
         let message = String::from_static_str(self, message, gc).unbind();
+        let stack = capture_stack_trace(self, 0);
         self.heap
-            .create(ErrorHeapData::new(kind, Some(message), None))
+            .create(ErrorHeapData::new(kind, Some(message), None, stack))
             .into_value()
     }
 
@@ -416,9 +645,10 @@ impl Agent {
         gc: NoGcScope,
     ) -> JsError {
         let message = String::from_string(self, message, gc).unbind();
+        let stack = capture_stack_trace(self, 0);
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message), None))
+                .create(ErrorHeapData::new(kind, Some(message), None, stack))
                 .into_value(),
         )
     }
@@ -428,9 +658,15 @@ impl Agent {
         kind: ExceptionType,
         message: String,
     ) -> JsError {
+        let stack = capture_stack_trace(self, 0);
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message.unbind()), None))
+                .create(ErrorHeapData::new(
+                    kind,
+                    Some(message.unbind()),
+                    None,
+                    stack,
+                ))
                 .into_value(),
         )
     }
@@ -567,6 +803,8 @@ pub enum ExceptionType {
     SyntaxError,
     TypeError,
     UriError,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedError,
 }
 
 impl TryFrom<u16> for ExceptionType {
@@ -582,6 +820,8 @@ impl TryFrom<u16> for ExceptionType {
             5 => Ok(Self::SyntaxError),
             6 => Ok(Self::TypeError),
             7 => Ok(Self::UriError),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            8 => Ok(Self::SuppressedError),
             _ => Err(()),
         }
     }
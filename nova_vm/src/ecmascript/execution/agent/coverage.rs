@@ -0,0 +1,650 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Code coverage collection.
+//!
+//! Once coverage is started with [`Agent::start_coverage`], the interpreter
+//! counts the calls of each function and the executions of each basic block
+//! of bytecode. The compiler records the blocks: Each starts at a jump target
+//! or after a jump. [`Agent::take_coverage`] maps the counts back to the
+//! source code.
+//!
+//! Like V8's precise coverage, collecting coverage keeps the bytecode of all
+//! executed code alive, so that its counts are not lost when it is garbage
+//! collected. Functions that were never called are found by parsing the
+//! source code again when the coverage is taken.
+
+use std::io::{self, Write};
+
+use ahash::{AHashMap, AHashSet};
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    ast::{ArrowFunctionExpression, Expression, Function as FunctionNode, Statement},
+    visit::walk,
+    Visit,
+};
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use super::{Agent, GcAgent};
+use crate::{
+    ecmascript::{
+        scripts_and_modules::{
+            script::Script,
+            source_code::{LineStarts, SourceCode},
+        },
+        syntax_directed_operations::function_names::FunctionNames,
+        types::Function,
+    },
+    engine::Executable,
+    heap::{heap_snapshot::write_json_string, CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// Marks instruction offsets that do not start a basic block.
+const NOT_A_BLOCK_START: u32 = u32::MAX;
+
+/// Execution counts of the basic blocks of an Executable.
+#[derive(Debug)]
+struct ExecutableCounters {
+    executable: Executable,
+    /// Index of the Executable's source code in the coverage's sources.
+    source: u32,
+    /// Start offset of the function whose code the Executable is, None for
+    /// the top-level code, or None if the Executable is not a function's
+    /// code.
+    function: Option<Option<u32>>,
+    /// Index of the block starting at each instruction offset, or
+    /// [`NOT_A_BLOCK_START`].
+    block_of_ip: Box<[u32]>,
+    counts: Box<[u64]>,
+}
+
+/// Calls of a function.
+#[derive(Debug)]
+struct FunctionCalls {
+    name: std::string::String,
+    end: u32,
+    count: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CoverageCounters {
+    /// Source codes of the executed code, and the URLs set for them.
+    sources: Vec<(SourceCode, std::string::String)>,
+    executables: Vec<ExecutableCounters>,
+    /// Index of each Executable's counters.
+    lookup: AHashMap<Executable, u32>,
+    /// Calls of the functions by their source code and start offset. The
+    /// top-level code of a source code has no start offset.
+    calls: AHashMap<(u32, Option<u32>), FunctionCalls>,
+}
+
+impl HeapMarkAndSweep for CoverageCounters {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        for (source, _) in self.sources.iter() {
+            source.mark_values(queues);
+        }
+        for counters in self.executables.iter() {
+            counters.executable.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        for (source, _) in self.sources.iter_mut() {
+            source.sweep_values(compactions);
+        }
+        self.lookup.clear();
+        for (index, counters) in self.executables.iter_mut().enumerate() {
+            counters.executable.sweep_values(compactions);
+            self.lookup.insert(counters.executable, index as u32);
+        }
+    }
+}
+
+impl CoverageCounters {
+    fn source(&mut self, source_code: SourceCode) -> u32 {
+        let index = match self
+            .sources
+            .iter()
+            .position(|&(source, _)| source == source_code)
+        {
+            Some(index) => index,
+            None => {
+                self.sources.push((source_code, std::string::String::new()));
+                self.sources.len() - 1
+            }
+        };
+        u32::try_from(index).unwrap()
+    }
+}
+
+impl Agent {
+    /// Starts collecting code coverage. Does nothing if it is already being
+    /// collected.
+    pub fn start_coverage(&mut self) {
+        if self.coverage.is_none() {
+            self.coverage = Some(Box::default());
+        }
+    }
+
+    /// Stops collecting code coverage, discarding the counts.
+    pub fn stop_coverage(&mut self) {
+        self.coverage = None;
+    }
+
+    /// Sets the URL that the coverage reports for the Script's code. Does
+    /// nothing if coverage is not being collected.
+    pub fn set_covered_script_url(&mut self, script: &Script, url: &str) {
+        let source_code = script.source_code;
+        if let Some(coverage) = self.coverage.as_deref_mut() {
+            let source = coverage.source(source_code);
+            coverage.sources[source as usize].1 = url.to_owned();
+        }
+    }
+
+    /// Counts the execution of the instruction at the offset, if it starts a
+    /// basic block. The frame caches the counters of the Executable between
+    /// the instructions executed by one Vm.
+    #[inline]
+    pub(crate) fn cover_instruction(
+        &mut self,
+        frame: &mut Option<u32>,
+        executable: Executable,
+        ip: u32,
+    ) {
+        let index = match *frame {
+            Some(index) => index,
+            None => {
+                let index = self.enter_covered_executable(executable, ip);
+                *frame = Some(index);
+                index
+            }
+        };
+        let counters = &mut self.coverage.as_deref_mut().unwrap().executables[index as usize];
+        let block = counters.block_of_ip[ip as usize];
+        if block != NOT_A_BLOCK_START {
+            counters.counts[block as usize] += 1;
+        }
+    }
+
+    /// Returns the index of the Executable's counters, and counts the call
+    /// of the function if the execution starts at the first instruction.
+    #[cold]
+    pub(crate) fn enter_covered_executable(&mut self, executable: Executable, ip: u32) -> u32 {
+        let context = self.running_execution_context();
+        let source_code = context.ecmascript_code.as_ref().unwrap().source_code;
+        let function = context.function;
+        let mut coverage = self.coverage.take().unwrap();
+        let source = coverage.source(source_code);
+        let index = match coverage.lookup.get(&executable) {
+            Some(&index) => index,
+            None => {
+                // Class field initializers and other Executables running in
+                // a function's context are not the function's code.
+                let function = match function {
+                    None => Some((None, source_code.get_source_text(self).len() as u32, None)),
+                    Some(Function::ECMAScriptFunction(f))
+                        if self[f].compiled_bytecode == Some(executable) =>
+                    {
+                        let span = self[f].ecmascript_function.source_text;
+                        Some((Some(span.start), span.end, self[f].name))
+                    }
+                    _ => None,
+                };
+                if let Some((start, end, name)) = function {
+                    coverage
+                        .calls
+                        .entry((source, start))
+                        .or_insert_with(|| FunctionCalls {
+                            name: name
+                                .map(|name| name.as_str(self).to_owned())
+                                .unwrap_or_default(),
+                            end,
+                            count: 0,
+                        });
+                }
+                let instruction_count = executable.get_instructions(self).len();
+                let mut block_of_ip = vec![NOT_A_BLOCK_START; instruction_count];
+                let block_starts = executable.get_block_starts(self);
+                for (block, &start) in block_starts.iter().enumerate() {
+                    block_of_ip[start as usize] = block as u32;
+                }
+                coverage.executables.push(ExecutableCounters {
+                    executable,
+                    source,
+                    function: function.map(|(start, _, _)| start),
+                    block_of_ip: block_of_ip.into_boxed_slice(),
+                    counts: vec![0; block_starts.len()].into_boxed_slice(),
+                });
+                let index = coverage.executables.len() as u32 - 1;
+                coverage.lookup.insert(executable, index);
+                index
+            }
+        };
+        // Calls start at the first instruction of the function's code.
+        let counters = &coverage.executables[index as usize];
+        if let (0, Some(start)) = (ip, counters.function) {
+            coverage.calls.get_mut(&(source, start)).unwrap().count += 1;
+        }
+        self.coverage = Some(coverage);
+        index
+    }
+
+    /// Returns the code coverage collected since coverage was started or
+    /// last taken, and resets the counts. Returns None if coverage is not
+    /// being collected.
+    pub fn take_coverage(&mut self) -> Option<CoverageReport> {
+        let mut coverage = self.coverage.take()?;
+        let mut scripts = Vec::with_capacity(coverage.sources.len());
+        for (source, &(source_code, ref url)) in coverage.sources.iter().enumerate() {
+            let source = source as u32;
+            let source_text = source_code.get_source_text(self);
+            let mut script = ScriptCoverage {
+                url: url.clone(),
+                source_text: source_text.to_owned(),
+                functions: Vec::new(),
+                lines: Vec::new(),
+            };
+            let line_starts = LineStarts::new(source_text);
+            let mut functions = FunctionFinder::default();
+            functions.find(source_text);
+
+            // The top-level code comes first, followed by the functions in
+            // source order.
+            let mut ranges = vec![(None, source_text.len() as u32, std::string::String::new())];
+            ranges.extend(
+                functions
+                    .functions
+                    .iter()
+                    .map(|(start, end, name)| (Some(*start), *end, name.clone())),
+            );
+            for (&(calls_source, start), calls) in coverage.calls.iter() {
+                if calls_source == source && !ranges.iter().any(|range| range.0 == start) {
+                    ranges.push((start, calls.end, calls.name.clone()));
+                }
+            }
+            ranges[1..].sort_by_key(|range| range.0);
+            for (start, end, name) in ranges {
+                let calls = coverage.calls.get(&(source, start));
+                let start_offset = start.unwrap_or(0);
+                script.functions.push(FunctionCoverage {
+                    name: calls.map_or(name, |calls| calls.name.clone()),
+                    start_offset,
+                    end_offset: end,
+                    line: line_starts.line(start_offset),
+                    count: calls.map_or(0, |calls| calls.count),
+                    blocks: Vec::new(),
+                });
+            }
+
+            // Returns the index of the innermost function containing the
+            // offset.
+            let innermost_function = |functions: &[FunctionCoverage], offset: u32| {
+                functions
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, function)| {
+                        function.start_offset <= offset && offset < function.end_offset
+                    })
+                    .min_by_key(|(_, function)| function.end_offset - function.start_offset)
+                    .map_or(0, |(index, _)| index)
+            };
+            let mut lines = AHashMap::<u32, u64>::default();
+            for counters in coverage.executables.iter_mut() {
+                if counters.source != source {
+                    continue;
+                }
+                let executable = counters.executable;
+                let block_starts = executable.get_block_starts(self);
+                let instruction_count = executable.get_instructions(self).len() as u32;
+                let positions = self[executable].source_positions.iter().collect::<Vec<_>>();
+                let mut seen_offsets = AHashSet::default();
+                for (block, &block_start) in block_starts.iter().enumerate() {
+                    let block_end = block_starts
+                        .get(block + 1)
+                        .copied()
+                        .unwrap_or(instruction_count);
+                    let count = core::mem::take(&mut counters.counts[block]);
+                    let offsets = executable
+                        .get_source_offset(self, block_start as usize)
+                        .into_iter()
+                        .chain(
+                            positions
+                                .iter()
+                                .filter(|&&(ip, _)| block_start < ip && ip < block_end)
+                                .map(|&(_, offset)| offset),
+                        )
+                        .collect::<Vec<_>>();
+                    for &offset in offsets.iter() {
+                        let line = lines.entry(line_starts.line(offset)).or_default();
+                        *line = (*line).max(count);
+                    }
+                    // The block spans the statements and expressions whose
+                    // code starts in it. Positions seen in earlier blocks
+                    // belong to enclosing code that continues in the block,
+                    // like a conditional expression after its branches.
+                    let spans = offsets
+                        .into_iter()
+                        .filter(|&offset| seen_offsets.insert(offset))
+                        .filter_map(|offset| Some((offset, *functions.node_ends.get(&offset)?)))
+                        .collect::<Vec<_>>();
+                    let (Some(min), Some(end)) = (
+                        spans.iter().map(|&(start, _)| start).min(),
+                        spans.iter().map(|&(_, end)| end).max(),
+                    ) else {
+                        continue;
+                    };
+                    let function = match counters.function {
+                        Some(None) => 0,
+                        Some(Some(start)) => script.functions[1..]
+                            .iter()
+                            .position(|function| function.start_offset == start)
+                            .map_or(0, |index| index + 1),
+                        None => innermost_function(&script.functions, min),
+                    };
+                    script.functions[function].blocks.push(BlockCoverage {
+                        start_offset: min,
+                        end_offset: end,
+                        count,
+                    });
+                }
+            }
+            for function in script.functions.iter_mut() {
+                // Blocks are sorted like in V8, with each block before the
+                // blocks that it contains. Blocks that overlap the end of an
+                // enclosing block are cut off at its end so that they nest.
+                function.blocks.sort_by(|a, b| {
+                    a.start_offset
+                        .cmp(&b.start_offset)
+                        .then(b.end_offset.cmp(&a.end_offset))
+                });
+                let mut enclosing_ends = vec![function.end_offset];
+                for block in function.blocks.iter_mut() {
+                    while enclosing_ends.len() > 1
+                        && *enclosing_ends.last().unwrap() <= block.start_offset
+                    {
+                        enclosing_ends.pop();
+                    }
+                    block.end_offset = block.end_offset.min(*enclosing_ends.last().unwrap());
+                    enclosing_ends.push(block.end_offset);
+                }
+                let line = lines.entry(function.line).or_default();
+                *line = (*line).max(function.count);
+            }
+            // The statements of functions that were never compiled have no
+            // blocks, and their lines were never executed.
+            for &offset in functions.statements.iter() {
+                let function = innermost_function(&script.functions, offset);
+                if script.functions[function].blocks.is_empty() {
+                    lines.entry(line_starts.line(offset)).or_default();
+                }
+            }
+            script.lines = lines.into_iter().collect();
+            script.lines.sort_unstable();
+            scripts.push(script);
+        }
+        for calls in coverage.calls.values_mut() {
+            calls.count = 0;
+        }
+        self.coverage = Some(coverage);
+        Some(CoverageReport { scripts })
+    }
+}
+
+impl GcAgent {
+    /// Starts collecting code coverage. Does nothing if it is already being
+    /// collected.
+    pub fn start_coverage(&mut self) {
+        self.agent.start_coverage();
+    }
+
+    /// Stops collecting code coverage, discarding the counts.
+    pub fn stop_coverage(&mut self) {
+        self.agent.stop_coverage();
+    }
+
+    /// Returns the code coverage collected since coverage was started or
+    /// last taken, and resets the counts. Returns None if coverage is not
+    /// being collected.
+    pub fn take_coverage(&mut self) -> Option<CoverageReport> {
+        self.agent.take_coverage()
+    }
+}
+
+/// Collects the functions and statements of a parsed source text.
+#[derive(Default)]
+struct FunctionFinder {
+    /// Names of the functions, as the compiler gives them.
+    names: FunctionNames,
+    /// Start and end offsets and names of the functions.
+    functions: Vec<(u32, u32, std::string::String)>,
+    /// Start offsets of the statements, other than declarations of
+    /// functions and classes.
+    statements: Vec<u32>,
+    /// End offset of the longest statement or expression starting at each
+    /// offset.
+    node_ends: AHashMap<u32, u32>,
+}
+
+impl FunctionFinder {
+    fn add_node(&mut self, span: Span) {
+        let end = self.node_ends.entry(span.start).or_default();
+        *end = (*end).max(span.end);
+    }
+
+    fn add_function(&mut self, span: Span) {
+        let name = self.names.get(span.start).unwrap_or_default().to_owned();
+        self.functions.push((span.start, span.end, name));
+    }
+
+    fn find(&mut self, source_text: &str) {
+        // The source type of the source code is not known: Module code fails
+        // to parse as Script code.
+        for source_type in [SourceType::cjs(), SourceType::mjs()] {
+            let allocator = Allocator::default();
+            let result = Parser::new(&allocator, source_text, source_type).parse();
+            if result.errors.is_empty() && !result.panicked {
+                self.names = FunctionNames::new(&result.program);
+                self.visit_program(&result.program);
+                return;
+            }
+        }
+    }
+}
+
+impl<'a> Visit<'a> for FunctionFinder {
+    fn visit_function(&mut self, it: &FunctionNode<'a>, flags: ScopeFlags) {
+        self.add_function(it.span);
+        walk::walk_function(self, it, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.add_function(it.span);
+        walk::walk_arrow_function_expression(self, it);
+    }
+
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        if !matches!(
+            it,
+            Statement::FunctionDeclaration(_)
+                | Statement::ClassDeclaration(_)
+                | Statement::BlockStatement(_)
+                | Statement::EmptyStatement(_)
+        ) {
+            self.statements.push(it.span().start);
+        }
+        // Block statements have no code of their own.
+        if !matches!(it, Statement::BlockStatement(_)) {
+            self.add_node(it.span());
+        }
+        walk::walk_statement(self, it);
+    }
+
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        self.add_node(it.span());
+        walk::walk_expression(self, it);
+    }
+}
+
+/// Code coverage taken with [`Agent::take_coverage`].
+#[derive(Debug, Clone)]
+pub struct CoverageReport {
+    /// Coverage of the source codes that were executed.
+    pub scripts: Vec<ScriptCoverage>,
+}
+
+/// Code coverage of a Script, or of code evaluated by `eval` or `Function`.
+#[derive(Debug, Clone)]
+pub struct ScriptCoverage {
+    /// URL set with [`Agent::set_covered_script_url`], or empty.
+    pub url: std::string::String,
+    pub source_text: std::string::String,
+    /// Coverage of the functions. The first is the top-level code of the
+    /// source text, and the others follow in source order.
+    pub functions: Vec<FunctionCoverage>,
+    /// Execution counts of the 1-based lines with code, sorted by line.
+    pub lines: Vec<(u32, u64)>,
+}
+
+/// Code coverage of a function.
+#[derive(Debug, Clone)]
+pub struct FunctionCoverage {
+    /// Name of the function. Empty for the top-level code and functions
+    /// without a name.
+    pub name: std::string::String,
+    /// Byte offsets of the start and end of the function in its source text.
+    pub start_offset: u32,
+    pub end_offset: u32,
+    /// The 1-based line where the function starts.
+    pub line: u32,
+    /// Number of calls of the function.
+    pub count: u64,
+    /// Coverage of the basic blocks of the function's code, sorted by start
+    /// offset and then by descending end offset. The blocks nest. Empty if
+    /// the function was never compiled.
+    pub blocks: Vec<BlockCoverage>,
+}
+
+/// Code coverage of a basic block. The block spans the statements and
+/// expressions whose code starts in it.
+#[derive(Debug, Clone, Copy)]
+pub struct BlockCoverage {
+    /// Byte offsets of the start and end of the block in its source text.
+    pub start_offset: u32,
+    pub end_offset: u32,
+    /// Number of executions of the block.
+    pub count: u64,
+}
+
+impl CoverageReport {
+    /// Writes the coverage in the LCOV tracefile format, as read by
+    /// `genhtml` and most coverage services. Source codes without a URL are
+    /// skipped.
+    pub fn write_lcov(&self, writer: &mut impl Write) -> io::Result<()> {
+        for script in self.scripts.iter().filter(|script| !script.url.is_empty()) {
+            writeln!(writer, "TN:")?;
+            writeln!(writer, "SF:{}", script.url)?;
+            // Function names identify the functions in the tracefile, so
+            // they include the 1-based line and column where the function
+            // starts.
+            let names = script.functions[1..]
+                .iter()
+                .map(|function| {
+                    let start = function.start_offset as usize;
+                    let line_start = script.source_text[..start]
+                        .rfind('\n')
+                        .map_or(0, |index| index + 1);
+                    let column = script.source_text[line_start..start].chars().count() + 1;
+                    let name = if function.name.is_empty() {
+                        "(anonymous)"
+                    } else {
+                        &function.name
+                    };
+                    format!("{}@{}:{}", name, function.line, column)
+                })
+                .collect::<Vec<_>>();
+            for (function, name) in script.functions[1..].iter().zip(names.iter()) {
+                writeln!(writer, "FN:{},{}", function.line, name)?;
+            }
+            for (function, name) in script.functions[1..].iter().zip(names.iter()) {
+                writeln!(writer, "FNDA:{},{}", function.count, name)?;
+            }
+            let functions_hit = script.functions[1..]
+                .iter()
+                .filter(|function| function.count > 0)
+                .count();
+            writeln!(writer, "FNF:{}", names.len())?;
+            writeln!(writer, "FNH:{}", functions_hit)?;
+            for &(line, count) in script.lines.iter() {
+                writeln!(writer, "DA:{},{}", line, count)?;
+            }
+            let lines_hit = script.lines.iter().filter(|&&(_, count)| count > 0).count();
+            writeln!(writer, "LF:{}", script.lines.len())?;
+            writeln!(writer, "LH:{}", lines_hit)?;
+            writeln!(writer, "end_of_record")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the coverage in the JSON format of V8's precise block coverage,
+    /// as returned by `Profiler.takePreciseCoverage` and written by Node.js
+    /// into `NODE_V8_COVERAGE` directories. Offsets are converted to UTF-16
+    /// code units.
+    pub fn write_precise_coverage(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(b"{\"result\":[")?;
+        for (index, script) in self.scripts.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",\n")?;
+            }
+            // UTF-16 offset of each byte offset.
+            let mut utf16_offsets = Vec::with_capacity(script.source_text.len() + 1);
+            let mut utf16_offset = 0;
+            for char in script.source_text.chars() {
+                for _ in 0..char.len_utf8() {
+                    utf16_offsets.push(utf16_offset);
+                }
+                utf16_offset += char.len_utf16() as u32;
+            }
+            utf16_offsets.push(utf16_offset);
+            let utf16 = |offset: u32| utf16_offsets[(offset as usize).min(utf16_offsets.len() - 1)];
+
+            write!(writer, "{{\"scriptId\":\"{}\",\"url\":", index + 1)?;
+            write_json_string(writer, &script.url)?;
+            writer.write_all(b",\"functions\":[")?;
+            for (index, function) in script.functions.iter().enumerate() {
+                if index > 0 {
+                    writer.write_all(b",")?;
+                }
+                writer.write_all(b"{\"functionName\":")?;
+                write_json_string(writer, &function.name)?;
+                write!(
+                    writer,
+                    ",\"ranges\":[{{\"startOffset\":{},\"endOffset\":{},\"count\":{}}}",
+                    utf16(function.start_offset),
+                    utf16(function.end_offset),
+                    function.count
+                )?;
+                // Like in V8, blocks are only listed where their count
+                // differs from the function's.
+                for block in function
+                    .blocks
+                    .iter()
+                    .filter(|block| block.count != function.count)
+                {
+                    write!(
+                        writer,
+                        ",{{\"startOffset\":{},\"endOffset\":{},\"count\":{}}}",
+                        utf16(block.start_offset),
+                        utf16(block.end_offset),
+                        block.count
+                    )?;
+                }
+                writer.write_all(b"],\"isBlockCoverage\":true}")?;
+            }
+            writer.write_all(b"]}")?;
+        }
+        writer.write_all(b"]}\n")
+    }
+}
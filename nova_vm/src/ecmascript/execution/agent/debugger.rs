@@ -0,0 +1,576 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Debugging of the code running in an Agent.
+//!
+//! Once the debugger is enabled with [`Agent::enable_debugger`], the
+//! interpreter pauses at `debugger` statements, at breakpoints set with
+//! [`Agent::set_breakpoint`], after the steps requested by the host, and
+//! optionally when an exception is thrown. When paused, it calls
+//! [`HostHooks::host_debugger_paused`]. The host can then inspect the paused
+//! execution through [`Agent::debug_call_frames`], and decides how the
+//! execution resumes by returning a [`DebugAction`].
+//!
+//! The interpreter pauses at most once per line: Breakpoints and steps take
+//! effect at the first instruction executed on a line.
+//!
+//! Functions compiled while the debugger is enabled keep all of their
+//! bindings in Environment Records, where the debugger can see them. Bindings
+//! that do not escape functions compiled before it was enabled are not
+//! listed in their frame's scopes.
+//!
+//! [`HostHooks::host_debugger_paused`]: super::HostHooks::host_debugger_paused
+
+use super::{Agent, GcAgent, JsError, JsResult};
+use crate::{
+    ecmascript::{
+        builtins::{error::StackFrame, global_object::perform_eval},
+        execution::{
+            environments::ThisBindingStatus, DeclarativeEnvironmentIndex, EnvironmentIndex,
+            ExecutionContext,
+        },
+        scripts_and_modules::{script::Script, source_code::SourceCode},
+        types::{Function, Object, String, Value},
+    },
+    engine::{context::GcScope, Executable},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// Identifies the source code of a Script, or of code evaluated by `eval` or
+/// `Function`, while the debugger is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DebugSourceId(u32);
+
+/// Identifies a breakpoint set with [`Agent::set_breakpoint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BreakpointId(u32);
+
+/// The reason the execution paused in the debugger.
+#[derive(Debug, Clone, Copy)]
+pub enum PauseReason {
+    /// A `debugger` statement was executed.
+    DebuggerStatement,
+    /// A line with a breakpoint was reached.
+    Breakpoint(BreakpointId),
+    /// A step requested by the host, or a pause requested with
+    /// [`Agent::debug_break`], completed.
+    Step,
+    /// An exception was thrown, and the debugger pauses on exceptions. The
+    /// execution is paused at the instruction that threw it.
+    Exception(Value),
+}
+
+/// How the execution resumes after pausing in the debugger.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugAction {
+    /// Run until the next breakpoint or `debugger` statement.
+    Continue,
+    /// Pause at the next line executed, including lines of called
+    /// functions.
+    StepIn,
+    /// Pause at the next line executed in the paused function or its
+    /// callers.
+    StepOver,
+    /// Pause once the paused function has returned to its caller.
+    StepOut,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum StepKind {
+    In,
+    Over,
+    Out,
+}
+
+#[derive(Debug)]
+struct Breakpoint {
+    id: BreakpointId,
+    source: DebugSourceId,
+    line: u32,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Debugger {
+    /// Source codes that have been given a [`DebugSourceId`], indexed by it.
+    sources: Vec<SourceCode>,
+    breakpoints: Vec<Breakpoint>,
+    next_breakpoint_id: u32,
+    pause_on_exceptions: bool,
+    /// The step requested by the host, and the depth of the execution
+    /// context stack when it was requested.
+    step: Option<(StepKind, usize)>,
+    /// True while an exception the debugger has seen is propagating out of
+    /// the frames that do not catch it, so that it pauses only once.
+    exception_unwinding: bool,
+    /// True while the host handles a pause. Code the host runs during it
+    /// does not pause.
+    paused: bool,
+}
+
+impl HeapMarkAndSweep for Debugger {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        for source in self.sources.iter() {
+            source.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        for source in self.sources.iter_mut() {
+            source.sweep_values(compactions);
+        }
+    }
+}
+
+impl Debugger {
+    pub(super) fn source_id(&mut self, source_code: SourceCode) -> DebugSourceId {
+        let index = match self
+            .sources
+            .iter()
+            .position(|&source| source == source_code)
+        {
+            Some(index) => index,
+            None => {
+                self.sources.push(source_code);
+                self.sources.len() - 1
+            }
+        };
+        DebugSourceId(u32::try_from(index).unwrap())
+    }
+}
+
+/// Line tracking of a frame executing while the debugger is enabled.
+pub(crate) struct DebugFrame {
+    /// (instruction offset, line) entries of the frame's Executable.
+    lines: Box<[(u32, u32)]>,
+    /// Line of the last instruction executed in the frame.
+    line: Option<u32>,
+}
+
+impl DebugFrame {
+    fn new(agent: &Agent, executable: Executable, source_code: SourceCode) -> Self {
+        Self {
+            lines: executable.get_line_table(agent, source_code),
+            line: None,
+        }
+    }
+
+    /// Track the instruction at the given offset being executed. Returns
+    /// true if it is the first instruction executed on its line.
+    fn enter_instruction(&mut self, ip: u32) -> bool {
+        let index = self.lines.partition_point(|&(start, _)| start <= ip);
+        let Some(&(start, line)) = index.checked_sub(1).map(|index| &self.lines[index]) else {
+            return false;
+        };
+        // Jumping back to the start of the line, as loops on a single line
+        // do, enters the line again.
+        let new_line = start == ip || self.line != Some(line);
+        self.line = Some(line);
+        new_line
+    }
+}
+
+impl Agent {
+    /// Enables the debugger. Does nothing if it is already enabled.
+    pub fn enable_debugger(&mut self) {
+        if self.debugger.is_none() {
+            self.debugger = Some(Box::default());
+        }
+    }
+
+    /// Disables the debugger, removing all breakpoints.
+    pub fn disable_debugger(&mut self) {
+        self.debugger = None;
+    }
+
+    /// Returns true if the debugger is enabled.
+    pub fn is_debugger_enabled(&self) -> bool {
+        self.debugger.is_some()
+    }
+
+    fn debugger_mut(&mut self) -> &mut Debugger {
+        self.debugger.get_or_insert_with(Box::default)
+    }
+
+    /// Returns the identifier of the Script's source code, enabling the
+    /// debugger if it is not enabled.
+    pub fn debug_source_id(&mut self, script: &Script) -> DebugSourceId {
+        let source_code = script.source_code;
+        self.debugger_mut().source_id(source_code)
+    }
+
+    /// Returns the text of the source code. The debugger must be enabled.
+    pub fn debug_source_text(&self, source: DebugSourceId) -> &str {
+        let debugger = self.debugger.as_deref().unwrap();
+        debugger.sources[source.0 as usize].get_source_text(self)
+    }
+
+    /// Sets a breakpoint on a 1-based line of the Script, enabling the
+    /// debugger if it is not enabled.
+    pub fn set_breakpoint(&mut self, script: &Script, line: u32) -> BreakpointId {
+        let source = self.debug_source_id(script);
+        self.set_source_breakpoint(source, line)
+    }
+
+    /// Sets a breakpoint on a 1-based line of the source code. The debugger
+    /// must be enabled.
+    pub fn set_source_breakpoint(&mut self, source: DebugSourceId, line: u32) -> BreakpointId {
+        let debugger = self.debugger.as_deref_mut().unwrap();
+        let id = BreakpointId(debugger.next_breakpoint_id);
+        debugger.next_breakpoint_id += 1;
+        debugger.breakpoints.push(Breakpoint { id, source, line });
+        id
+    }
+
+    /// Removes a breakpoint. Returns false if it did not exist.
+    pub fn remove_breakpoint(&mut self, id: BreakpointId) -> bool {
+        let Some(debugger) = self.debugger.as_deref_mut() else {
+            return false;
+        };
+        let length = debugger.breakpoints.len();
+        debugger
+            .breakpoints
+            .retain(|breakpoint| breakpoint.id != id);
+        debugger.breakpoints.len() != length
+    }
+
+    /// Sets whether the debugger pauses when an exception is thrown, enabling
+    /// the debugger if it is not enabled.
+    pub fn set_pause_on_exceptions(&mut self, pause_on_exceptions: bool) {
+        self.debugger_mut().pause_on_exceptions = pause_on_exceptions;
+    }
+
+    /// Requests the execution to pause at the next instruction executed,
+    /// enabling the debugger if it is not enabled. This can be used with
+    /// [`InterruptHandle::request_interrupt`] to pause a running script.
+    ///
+    /// [`InterruptHandle::request_interrupt`]: super::InterruptHandle::request_interrupt
+    pub fn debug_break(&mut self) {
+        self.debugger_mut().step = Some((StepKind::In, usize::MAX));
+    }
+
+    /// Returns the call frames of the paused execution, innermost first.
+    pub fn debug_call_frames(&self) -> Vec<DebugCallFrame> {
+        self.execution_context_stack
+            .iter()
+            .enumerate()
+            .rev()
+            .filter_map(|(index, context)| {
+                let frame = StackFrame::from_execution_context(self, context)?;
+                Some(DebugCallFrame {
+                    context_index: index,
+                    frame,
+                })
+            })
+            .collect()
+    }
+
+    /// Called by the interpreter before each instruction while the debugger
+    /// is enabled. Pauses if a step completes or a breakpoint is reached.
+    pub(crate) fn debug_instruction(
+        &mut self,
+        frame: &mut Option<DebugFrame>,
+        executable: Executable,
+        ip: u32,
+        gc: GcScope,
+    ) {
+        // Note: The SourceCode is reread for each instruction, as garbage
+        // collection may change its index.
+        let source_code = self
+            .running_execution_context()
+            .ecmascript_code
+            .as_ref()
+            .unwrap()
+            .source_code;
+        let frame = match frame {
+            Some(frame) => frame,
+            None => frame.insert(DebugFrame::new(self, executable, source_code)),
+        };
+        let new_line = frame.enter_instruction(ip);
+        let depth = self.execution_context_stack.len();
+        let debugger = self.debugger.as_deref_mut().unwrap();
+        debugger.exception_unwinding = false;
+        let step_completed = match debugger.step {
+            Some((StepKind::In, step_depth)) => new_line || depth < step_depth,
+            Some((StepKind::Over, step_depth)) => {
+                depth < step_depth || (new_line && depth == step_depth)
+            }
+            Some((StepKind::Out, step_depth)) => depth < step_depth,
+            None => false,
+        };
+        let reason = if step_completed {
+            PauseReason::Step
+        } else if new_line {
+            let Some(source) = debugger
+                .sources
+                .iter()
+                .position(|&source| source == source_code)
+            else {
+                return;
+            };
+            let Some(breakpoint) = debugger.breakpoints.iter().find(|breakpoint| {
+                breakpoint.source.0 as usize == source && Some(breakpoint.line) == frame.line
+            }) else {
+                return;
+            };
+            PauseReason::Breakpoint(breakpoint.id)
+        } else {
+            return;
+        };
+        self.debugger_pause(reason, gc);
+    }
+
+    /// Called by the interpreter when an instruction throws while the
+    /// debugger is enabled. Returns the error, which garbage collection
+    /// during the pause may have moved.
+    pub(crate) fn debug_exception(&mut self, error: JsError, mut gc: GcScope) -> JsError {
+        if self.is_terminating() {
+            return error;
+        }
+        let debugger = self.debugger.as_deref_mut().unwrap();
+        if debugger.exception_unwinding {
+            // The exception was thrown by a function called from this frame.
+            return error;
+        }
+        debugger.exception_unwinding = true;
+        if !debugger.pause_on_exceptions {
+            return error;
+        }
+        let exception = error.value().scope(self, gc.nogc());
+        self.debugger_pause(PauseReason::Exception(error.value()), gc.reborrow());
+        JsError::new(exception.get(self))
+    }
+
+    /// Pauses the execution in the debugger.
+    pub(crate) fn debugger_pause(&mut self, reason: PauseReason, gc: GcScope) {
+        let depth = self.execution_context_stack.len();
+        let debugger = self.debugger.as_deref_mut().unwrap();
+        if debugger.paused {
+            return;
+        }
+        debugger.paused = true;
+        debugger.step = None;
+        let host_hooks = self.host_hooks;
+        let action = host_hooks.host_debugger_paused(self, reason, gc);
+        // Note: The host may have disabled the debugger while paused.
+        if let Some(debugger) = self.debugger.as_deref_mut() {
+            debugger.paused = false;
+            debugger.step = match action {
+                DebugAction::Continue => None,
+                DebugAction::StepIn => Some((StepKind::In, depth)),
+                DebugAction::StepOver => Some((StepKind::Over, depth)),
+                DebugAction::StepOut => Some((StepKind::Out, depth)),
+            };
+        }
+    }
+}
+
+impl GcAgent {
+    /// Enables the debugger. Does nothing if it is already enabled.
+    pub fn enable_debugger(&mut self) {
+        self.agent.enable_debugger();
+    }
+
+    /// Sets whether the debugger pauses when an exception is thrown, enabling
+    /// the debugger if it is not enabled.
+    pub fn set_pause_on_exceptions(&mut self, pause_on_exceptions: bool) {
+        self.agent.set_pause_on_exceptions(pause_on_exceptions);
+    }
+}
+
+/// A call frame of an execution paused in the debugger. It is only valid
+/// until the execution resumes or garbage collection runs, so it must be
+/// looked up again after evaluating code in the frame.
+#[derive(Debug, Clone, Copy)]
+pub struct DebugCallFrame {
+    context_index: usize,
+    frame: StackFrame,
+}
+
+/// The kind of an Environment Record in a call frame's scope chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DebugScopeKind {
+    /// A block, catch clause, or other Declarative Environment Record.
+    Block,
+    /// The Function Environment Record of a function call.
+    Function,
+    /// The Object Environment Record of a `with` statement.
+    With,
+    /// The Global Environment Record.
+    Global,
+}
+
+/// An Environment Record in a call frame's scope chain.
+#[derive(Debug)]
+pub struct DebugScope<'a> {
+    pub kind: DebugScopeKind,
+    /// Names and values of the declarative bindings of the Environment
+    /// Record in order of creation. The value is None if the binding is not
+    /// yet initialized.
+    pub bindings: Vec<(&'a str, Option<Value>)>,
+    /// Object whose properties are bindings of the Environment Record, for
+    /// `with` statements and the global object.
+    pub binding_object: Option<Object<'static>>,
+}
+
+impl DebugCallFrame {
+    fn context<'a>(&self, agent: &'a Agent) -> &'a ExecutionContext {
+        &agent.execution_context_stack[self.context_index]
+    }
+
+    /// Name of the function executing in this frame. None if Script or
+    /// Module code is executing, or if the function is anonymous.
+    pub fn function_name<'a>(&'a self, agent: &'a Agent) -> Option<&'a str> {
+        self.frame.function_name(agent)
+    }
+
+    /// The function executing in this frame. None if Script or Module code
+    /// is executing.
+    pub fn function(&self, agent: &Agent) -> Option<Function<'static>> {
+        self.context(agent).function
+    }
+
+    /// Returns true if this frame is inside a builtin function.
+    pub fn is_native(&self) -> bool {
+        self.frame.is_native()
+    }
+
+    /// The 1-based line and column of the instruction executing in this
+    /// frame.
+    pub fn line_and_column(&self, agent: &Agent) -> Option<(u32, u32)> {
+        self.frame.line_and_column(agent)
+    }
+
+    /// Returns true if the code executing in this frame originates from the
+    /// Script.
+    pub fn is_in_script(&self, agent: &Agent, script: &Script) -> bool {
+        !self.is_native()
+            && self
+                .context(agent)
+                .ecmascript_code
+                .as_ref()
+                .is_some_and(|code| code.source_code == script.source_code)
+    }
+
+    /// The identifier of the source code executing in this frame, or None if
+    /// the frame is native. The debugger must be enabled.
+    pub fn source_id(&self, agent: &mut Agent) -> Option<DebugSourceId> {
+        if self.is_native() {
+            return None;
+        }
+        let source_code = self.context(agent).ecmascript_code.as_ref()?.source_code;
+        Some(
+            agent
+                .debugger
+                .as_deref_mut()
+                .unwrap()
+                .source_id(source_code),
+        )
+    }
+
+    /// Evaluates the source text as if by a direct `eval` in this frame. The
+    /// source text is evaluated by an indirect `eval` if the frame is native.
+    ///
+    /// Note: The evaluation does not pause in the debugger.
+    pub fn evaluate(&self, agent: &mut Agent, source_text: &str, gc: GcScope) -> JsResult<Value> {
+        let context = self.context(agent).clone();
+        let direct = !self.is_native();
+        let strict_caller = direct
+            && context
+                .ecmascript_code
+                .as_ref()
+                .is_some_and(|code| code.is_strict_mode);
+        let source_text = String::from_str(agent, source_text, gc.nogc());
+        agent.execution_context_stack.push(context);
+        let result = perform_eval(agent, source_text.into_value(), direct, strict_caller, gc);
+        agent.execution_context_stack.pop();
+        result
+    }
+
+    /// The `this` value of this frame. None if the frame is native, or if
+    /// `this` is not yet initialized in a derived class constructor.
+    pub fn this_value(&self, agent: &Agent) -> Option<Value> {
+        if self.is_native() {
+            return None;
+        }
+        let mut env = Some(
+            self.context(agent)
+                .ecmascript_code
+                .as_ref()?
+                .lexical_environment,
+        );
+        while let Some(current) = env {
+            match current {
+                EnvironmentIndex::Function(index) => {
+                    let env_rec = &agent[index];
+                    if env_rec.this_binding_status != ThisBindingStatus::Lexical {
+                        return env_rec.this_value;
+                    }
+                }
+                EnvironmentIndex::Global(index) => {
+                    return Some(agent[index].global_this_value.into_value());
+                }
+                EnvironmentIndex::Declarative(_) | EnvironmentIndex::Object(_) => {}
+            }
+            env = current.get_outer_env(agent);
+        }
+        None
+    }
+
+    /// The scope chain of this frame, innermost first. Empty if the frame is
+    /// native.
+    pub fn scopes<'a>(&self, agent: &'a Agent) -> Vec<DebugScope<'a>> {
+        let mut scopes = vec![];
+        if self.is_native() {
+            return scopes;
+        }
+        let Some(code) = self.context(agent).ecmascript_code.as_ref() else {
+            return scopes;
+        };
+        let mut env = Some(code.lexical_environment);
+        while let Some(current) = env {
+            scopes.push(match current {
+                EnvironmentIndex::Declarative(index) => DebugScope {
+                    kind: DebugScopeKind::Block,
+                    bindings: declarative_bindings(agent, index),
+                    binding_object: None,
+                },
+                EnvironmentIndex::Function(index) => DebugScope {
+                    kind: DebugScopeKind::Function,
+                    bindings: declarative_bindings(agent, agent[index].declarative_environment),
+                    binding_object: None,
+                },
+                EnvironmentIndex::Object(index) => DebugScope {
+                    kind: DebugScopeKind::With,
+                    bindings: vec![],
+                    binding_object: Some(agent[index].binding_object),
+                },
+                EnvironmentIndex::Global(index) => {
+                    let env_rec = &agent[index];
+                    DebugScope {
+                        kind: DebugScopeKind::Global,
+                        bindings: declarative_bindings(agent, env_rec.declarative_record),
+                        binding_object: Some(agent[env_rec.object_record].binding_object),
+                    }
+                }
+            });
+            env = current.get_outer_env(agent);
+        }
+        scopes
+    }
+}
+
+fn declarative_bindings(
+    agent: &Agent,
+    index: DeclarativeEnvironmentIndex,
+) -> Vec<(&str, Option<Value>)> {
+    let env_rec = &agent[index];
+    let mut names = vec![""; env_rec.bindings.len()];
+    for (name, &slot) in env_rec.names.iter() {
+        names[slot as usize] = name.as_str(agent);
+    }
+    names
+        .into_iter()
+        .zip(env_rec.bindings.iter().map(|binding| binding.value))
+        .collect()
+}
@@ -0,0 +1,98 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Deterministic metering of execution.
+//!
+//! An Agent with fuel consumes one unit of it for each bytecode instruction
+//! it executes. The `fill`, `includes`, `indexOf`, `join`, `sort` and `splice`
+//! methods of `Array.prototype` also consume one unit per element they
+//! process, and `JSON.parse` and `String.prototype.repeat` one unit per byte
+//! of the string they parse or create. Other builtins are not metered apart
+//! from the functions they call. The same program thus always consumes the
+//! same amount of fuel.
+//!
+//! When the fuel runs out, [`HostHooks::host_out_of_fuel`] is called. The
+//! host can add fuel and resume the execution, or let it be terminated like
+//! through [`InterruptHandle::terminate_execution`].
+//!
+//! [`HostHooks::host_out_of_fuel`]: super::HostHooks::host_out_of_fuel
+//! [`InterruptHandle::terminate_execution`]: super::InterruptHandle::terminate_execution
+
+use super::{Agent, ExceptionType, GcAgent, JsError, JsResult};
+use crate::engine::context::NoGcScope;
+
+impl Agent {
+    /// Returns the remaining fuel, or None if execution is not metered.
+    pub fn fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    /// Sets the remaining fuel. If None, execution is not metered.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.fuel = fuel;
+    }
+
+    /// Consumes `units` of fuel. Returns false if the fuel ran out and the
+    /// host did not add more, in which case the execution is terminating.
+    #[inline]
+    #[must_use]
+    pub(crate) fn consume_fuel(&mut self, units: u64) -> bool {
+        match &mut self.fuel {
+            Some(fuel) if *fuel >= units => {
+                *fuel -= units;
+                true
+            }
+            Some(_) => self.refuel(units),
+            None => true,
+        }
+    }
+
+    #[cold]
+    fn refuel(&mut self, units: u64) -> bool {
+        self.fuel = Some(0);
+        if !self.terminating {
+            let host_hooks = self.host_hooks;
+            if host_hooks.host_out_of_fuel(self) {
+                match &mut self.fuel {
+                    Some(fuel) if *fuel >= units => {
+                        *fuel -= units;
+                        return true;
+                    }
+                    Some(_) => {}
+                    None => return true,
+                }
+            }
+            self.start_termination();
+        }
+        false
+    }
+
+    /// Consumes `units` of fuel, and throws the exception terminating the
+    /// execution if the fuel ran out.
+    pub(crate) fn use_fuel(&mut self, units: u64, gc: NoGcScope) -> JsResult<()> {
+        if self.consume_fuel(units) {
+            Ok(())
+        } else {
+            Err(self.throw_out_of_fuel(gc))
+        }
+    }
+
+    /// Creates the exception that unwinds an execution that ran out of fuel.
+    pub(crate) fn throw_out_of_fuel(&mut self, gc: NoGcScope) -> JsError {
+        self.throw_exception_with_static_message(ExceptionType::Error, "Out of fuel", gc)
+    }
+}
+
+impl GcAgent {
+    /// Returns the remaining fuel, or None if execution is not metered.
+    pub fn fuel(&self) -> Option<u64> {
+        self.agent.fuel()
+    }
+
+    /// Sets the remaining fuel, and enables metering of execution if it was
+    /// not enabled through [`Options::fuel`](super::Options::fuel).
+    pub fn set_fuel(&mut self, fuel: u64) {
+        self.agent.set_fuel(Some(fuel));
+    }
+}
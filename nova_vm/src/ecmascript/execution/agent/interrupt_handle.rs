@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Interrupting a running Agent from another thread.
+//!
+//! The interpreter checks for interrupt requests at safe points: When a
+//! function or script starts executing, and on loop back-edges. An
+//! [`InterruptHandle`] can request a callback to be run on the Agent at the
+//! next safe point, or request that execution be terminated.
+//!
+//! Termination unwinds the execution as an exception that `catch` and
+//! `finally` blocks do not see. Until control returns to the embedder from
+//! [`GcAgent::run_in_realm`], every safe point throws the termination again,
+//! so that builtins that catch exceptions cannot swallow it either.
+//!
+//! [`GcAgent::run_in_realm`]: super::GcAgent::run_in_realm
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+use super::{Agent, ExceptionType, JsError};
+use crate::engine::context::NoGcScope;
+
+type InterruptCallback = Box<dyn FnOnce(&mut Agent) + Send>;
+
+/// Interrupt requests shared between an Agent and its InterruptHandles.
+#[derive(Default)]
+pub(crate) struct InterruptRequests {
+    /// True if any request may be pending. This is the only field read at
+    /// every safe point.
+    pending: AtomicBool,
+    /// True if termination of the execution has been requested.
+    terminate: AtomicBool,
+    /// Callbacks to run at the next safe point, in request order.
+    callbacks: Mutex<Vec<InterruptCallback>>,
+}
+
+impl core::fmt::Debug for InterruptRequests {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("InterruptRequests")
+            .field("pending", &self.pending)
+            .field("terminate", &self.terminate)
+            .finish_non_exhaustive()
+    }
+}
+
+impl InterruptRequests {
+    #[inline]
+    pub(crate) fn is_pending(&self) -> bool {
+        self.pending.load(Ordering::Acquire)
+    }
+
+    /// Takes the requested callbacks and the termination request, if any.
+    fn take(&self) -> (Vec<InterruptCallback>, bool) {
+        self.pending.store(false, Ordering::Release);
+        let callbacks = core::mem::take(&mut *self.callbacks.lock().unwrap());
+        let terminate = self.terminate.swap(false, Ordering::AcqRel);
+        (callbacks, terminate)
+    }
+}
+
+/// A thread-safe handle for interrupting the execution of a
+/// [`GcAgent`](super::GcAgent).
+///
+/// ```no_run
+/// # use nova_vm::ecmascript::execution::{agent::{GcAgent, Options}, DefaultHostHooks};
+/// # use std::time::Duration;
+/// let agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+/// let handle = agent.interrupt_handle();
+/// // Limit the script to one second of execution.
+/// std::thread::spawn(move || {
+///     std::thread::sleep(Duration::from_secs(1));
+///     handle.terminate_execution();
+/// });
+/// ```
+#[derive(Debug, Clone)]
+pub struct InterruptHandle(pub(crate) Arc<InterruptRequests>);
+
+impl InterruptHandle {
+    /// Requests the termination of the running execution at the next safe
+    /// point. If the Agent is not executing, the next execution is
+    /// terminated when it reaches its first safe point.
+    pub fn terminate_execution(&self) {
+        self.0.terminate.store(true, Ordering::Release);
+        self.0.pending.store(true, Ordering::Release);
+    }
+
+    /// Withdraws a termination request that has not yet been acted on.
+    pub fn cancel_termination(&self) {
+        self.0.terminate.store(false, Ordering::Release);
+    }
+
+    /// Requests the callback to be run on the Agent's thread at the next
+    /// safe point. The callback may request termination through a handle.
+    pub fn request_interrupt(&self, callback: impl FnOnce(&mut Agent) + Send + 'static) {
+        self.0.callbacks.lock().unwrap().push(Box::new(callback));
+        self.0.pending.store(true, Ordering::Release);
+    }
+}
+
+impl Agent {
+    /// Handles the interrupt requests made through InterruptHandles at a
+    /// safe point: Runs the requested callbacks, and returns true if the
+    /// execution is terminating.
+    #[inline]
+    pub(crate) fn handle_interrupts(&mut self) -> bool {
+        if !self.interrupts.is_pending() {
+            return false;
+        }
+        self.handle_interrupt_requests()
+    }
+
+    #[cold]
+    fn handle_interrupt_requests(&mut self) -> bool {
+        if self.terminating {
+            return true;
+        }
+        let (callbacks, terminate) = self.interrupts.take();
+        for callback in callbacks {
+            callback(self);
+        }
+        if terminate || self.interrupts.terminate.swap(false, Ordering::AcqRel) {
+            self.start_termination();
+        }
+        self.terminating
+    }
+
+    /// Starts terminating the execution.
+    pub(super) fn start_termination(&mut self) {
+        self.terminating = true;
+        // Keep every safe point throwing until the execution has unwound.
+        self.interrupts.pending.store(true, Ordering::Release);
+    }
+
+    /// Returns true if the execution is being terminated through an
+    /// [`InterruptHandle`]. The exception unwinding the execution is then not
+    /// an exception thrown by the program, and should not be handled as one.
+    pub fn is_terminating(&self) -> bool {
+        self.terminating
+    }
+
+    /// Ends the termination of the execution once it has fully unwound.
+    pub(crate) fn end_termination(&mut self) {
+        if self.terminating {
+            self.terminating = false;
+            // Requests made during the termination remain pending.
+            let requests = &self.interrupts;
+            requests.pending.store(false, Ordering::Release);
+            if requests.terminate.load(Ordering::Acquire)
+                || !requests.callbacks.lock().unwrap().is_empty()
+            {
+                requests.pending.store(true, Ordering::Release);
+            }
+        }
+    }
+
+    /// Creates the exception that unwinds a terminating execution.
+    pub(crate) fn throw_termination(&mut self, gc: NoGcScope) -> JsError {
+        self.throw_exception_with_static_message(ExceptionType::Error, "Execution terminated", gc)
+    }
+}
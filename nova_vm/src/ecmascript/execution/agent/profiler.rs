@@ -0,0 +1,601 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Sampling CPU profiler.
+//!
+//! Once profiling is started with [`Agent::start_profiling`], the interpreter
+//! records the execution context stack whenever a sample is due: after each
+//! interval of wall-clock time, or after each given number of executed
+//! bytecode instructions. Each frame of a sample is recorded with the
+//! function executing in it and the source position of its current
+//! instruction.
+//!
+//! Samples are taken before bytecode instructions. A sample that falls due
+//! while a builtin function is running is taken at the next instruction
+//! executed, which may be in a function the builtin calls.
+//!
+//! [`Agent::stop_profiling`] returns the samples as a [`CpuProfile`], which
+//! can be written in the V8 `.cpuprofile` JSON format or as collapsed stacks
+//! for flame graph tools.
+
+use std::{
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+
+use ahash::AHashMap;
+
+use super::{Agent, DebugSourceId, GcAgent};
+use crate::{
+    ecmascript::{
+        builtins::error::StackFrame,
+        execution::ExecutionContext,
+        scripts_and_modules::{
+            script::Script,
+            source_code::{LineStarts, SourceCode},
+        },
+        types::Function,
+    },
+    heap::{heap_snapshot::write_json_string, CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// When the profiler takes samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleInterval {
+    /// Take a sample after each interval of wall-clock time.
+    Time(Duration),
+    /// Take a sample after each given number of executed bytecode
+    /// instructions. Profiles taken this way are deterministic.
+    Instructions(u64),
+}
+
+/// A function executing in a sampled frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FrameKey {
+    /// Index of the function's name in the profiler's names.
+    name: u32,
+    /// Index of the function's source code in the profiler's sources, or
+    /// None for builtin functions.
+    source: Option<u32>,
+    /// Byte offset of the function's start in its source code.
+    function_offset: u32,
+}
+
+#[derive(Debug)]
+struct SampleNode {
+    key: FrameKey,
+    parent: u32,
+    children: Vec<u32>,
+}
+
+#[derive(Debug)]
+struct Sample {
+    node: u32,
+    /// Byte offset of the innermost frame's instruction in its source code.
+    offset: Option<u32>,
+    time: Duration,
+}
+
+/// Sets a flag after each interval until stopped.
+#[derive(Debug)]
+struct SampleTimer {
+    stop: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl SampleTimer {
+    fn start(interval: Duration, sample_due: Arc<AtomicBool>) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let thread = thread::spawn(move || loop {
+            thread::park_timeout(interval);
+            if thread_stop.load(Ordering::Acquire) {
+                break;
+            }
+            sample_due.store(true, Ordering::Release);
+        });
+        Self { stop, thread }
+    }
+
+    fn stop(self) {
+        self.stop.store(true, Ordering::Release);
+        self.thread.thread().unpark();
+        let _ = self.thread.join();
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct Profiler {
+    interval: SampleInterval,
+    start: Instant,
+    instructions_until_sample: u64,
+    /// Set by the timer when a time-based sample is due.
+    sample_due: Arc<AtomicBool>,
+    timer: Option<SampleTimer>,
+    names: Vec<std::string::String>,
+    name_lookup: AHashMap<std::string::String, u32>,
+    /// Source codes of the sampled functions, and the URLs set for them.
+    sources: Vec<(SourceCode, std::string::String)>,
+    /// Nodes of the sampled call tree. The first node is the root.
+    nodes: Vec<SampleNode>,
+    samples: Vec<Sample>,
+}
+
+impl Drop for Profiler {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            timer.stop();
+        }
+    }
+}
+
+impl HeapMarkAndSweep for Profiler {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        for (source, _) in self.sources.iter() {
+            source.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        for (source, _) in self.sources.iter_mut() {
+            source.sweep_values(compactions);
+        }
+    }
+}
+
+impl Profiler {
+    fn new(interval: SampleInterval) -> Self {
+        let sample_due = Arc::new(AtomicBool::new(false));
+        let timer = match interval {
+            SampleInterval::Time(interval) => {
+                Some(SampleTimer::start(interval, sample_due.clone()))
+            }
+            SampleInterval::Instructions(_) => None,
+        };
+        let mut profiler = Self {
+            interval,
+            start: Instant::now(),
+            instructions_until_sample: 0,
+            sample_due,
+            timer,
+            names: Vec::new(),
+            name_lookup: AHashMap::default(),
+            sources: Vec::new(),
+            nodes: Vec::new(),
+            samples: Vec::new(),
+        };
+        let root = profiler.name("(root)");
+        profiler.nodes.push(SampleNode {
+            key: FrameKey {
+                name: root,
+                source: None,
+                function_offset: 0,
+            },
+            parent: 0,
+            children: Vec::new(),
+        });
+        profiler.reset_instruction_count();
+        profiler
+    }
+
+    fn reset_instruction_count(&mut self) {
+        if let SampleInterval::Instructions(instructions) = self.interval {
+            self.instructions_until_sample = instructions.max(1);
+        }
+    }
+
+    /// Counts an executed instruction. Returns true if a sample is due.
+    #[inline]
+    fn is_sample_due(&mut self) -> bool {
+        match self.interval {
+            SampleInterval::Instructions(_) => {
+                self.instructions_until_sample -= 1;
+                if self.instructions_until_sample == 0 {
+                    self.reset_instruction_count();
+                    true
+                } else {
+                    false
+                }
+            }
+            SampleInterval::Time(_) => {
+                self.sample_due.load(Ordering::Relaxed)
+                    && self.sample_due.swap(false, Ordering::AcqRel)
+            }
+        }
+    }
+
+    fn name(&mut self, name: &str) -> u32 {
+        if let Some(&index) = self.name_lookup.get(name) {
+            return index;
+        }
+        let index = u32::try_from(self.names.len()).unwrap();
+        self.names.push(name.to_owned());
+        self.name_lookup.insert(name.to_owned(), index);
+        index
+    }
+
+    fn source(&mut self, source_code: SourceCode) -> u32 {
+        let index = match self
+            .sources
+            .iter()
+            .position(|&(source, _)| source == source_code)
+        {
+            Some(index) => index,
+            None => {
+                self.sources.push((source_code, std::string::String::new()));
+                self.sources.len() - 1
+            }
+        };
+        u32::try_from(index).unwrap()
+    }
+
+    fn child(&mut self, parent: u32, key: FrameKey) -> u32 {
+        let nodes = &self.nodes;
+        if let Some(&child) = nodes[parent as usize]
+            .children
+            .iter()
+            .find(|&&child| nodes[child as usize].key == key)
+        {
+            return child;
+        }
+        let child = u32::try_from(self.nodes.len()).unwrap();
+        self.nodes.push(SampleNode {
+            key,
+            parent,
+            children: Vec::new(),
+        });
+        self.nodes[parent as usize].children.push(child);
+        child
+    }
+}
+
+/// Byte offset of the start of the function executing in the execution
+/// context in its source code.
+fn function_offset(agent: &Agent, context: &ExecutionContext) -> u32 {
+    match context.function {
+        Some(Function::ECMAScriptFunction(f)) => agent[f].ecmascript_function.source_text.start,
+        Some(Function::BuiltinConstructorFunction(f)) => agent[f].source_text.start,
+        _ => 0,
+    }
+}
+
+impl Agent {
+    /// Starts the CPU profiler, discarding the samples of a profile that was
+    /// not stopped.
+    pub fn start_profiling(&mut self, interval: SampleInterval) {
+        self.profiler = Some(Box::new(Profiler::new(interval)));
+    }
+
+    /// Returns true if the CPU profiler is running.
+    pub fn is_profiling(&self) -> bool {
+        self.profiler.is_some()
+    }
+
+    /// Sets the URL that the CPU profile reports for the Script's code. Does
+    /// nothing if the profiler is not running.
+    pub fn set_profiled_script_url(&mut self, script: &Script, url: &str) {
+        let source_code = script.source_code;
+        if let Some(profiler) = self.profiler.as_deref_mut() {
+            let source = profiler.source(source_code);
+            profiler.sources[source as usize].1 = url.to_owned();
+        }
+    }
+
+    /// Counts an executed instruction, and takes a sample if one is due.
+    #[inline]
+    pub(crate) fn profile_instruction(&mut self) {
+        if let Some(profiler) = self.profiler.as_deref_mut() {
+            if profiler.is_sample_due() {
+                self.take_profile_sample();
+            }
+        }
+    }
+
+    #[cold]
+    fn take_profile_sample(&mut self) {
+        let mut profiler = self.profiler.take().unwrap();
+        let mut node = 0;
+        let mut offset = None;
+        for context in self.execution_context_stack.iter() {
+            let Some(frame) = StackFrame::from_execution_context(self, context) else {
+                continue;
+            };
+            let name = profiler.name(frame.function_name(self).unwrap_or_default());
+            let source = frame
+                .source_position
+                .map(|(source_code, _)| profiler.source(source_code));
+            let key = FrameKey {
+                name,
+                source,
+                function_offset: if source.is_some() {
+                    function_offset(self, context)
+                } else {
+                    0
+                },
+            };
+            node = profiler.child(node, key);
+            offset = frame.source_offset();
+        }
+        profiler.samples.push(Sample {
+            node,
+            offset,
+            time: profiler.start.elapsed(),
+        });
+        self.profiler = Some(profiler);
+    }
+
+    /// Stops the CPU profiler and returns the profile. Returns None if the
+    /// profiler is not running.
+    pub fn stop_profiling(&mut self) -> Option<CpuProfile> {
+        let mut profiler = self.profiler.take()?;
+        let duration = profiler.start.elapsed();
+        if let Some(timer) = profiler.timer.take() {
+            timer.stop();
+        }
+        let sources = profiler
+            .sources
+            .iter()
+            .map(|&(source_code, ref url)| {
+                let debug_source = self
+                    .debugger
+                    .as_deref_mut()
+                    .map(|debugger| debugger.source_id(source_code));
+                let line_starts = LineStarts::new(source_code.get_source_text(self));
+                (source_code, url, line_starts, debug_source)
+            })
+            .collect::<Vec<_>>();
+        let mut nodes = profiler
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let FrameKey {
+                    name,
+                    source,
+                    function_offset,
+                } = node.key;
+                let mut profile_node = ProfileNode {
+                    function_name: profiler.names[name as usize].clone(),
+                    url: std::string::String::new(),
+                    script_id: source,
+                    debug_source: None,
+                    line: 0,
+                    column: 0,
+                    parent: (index > 0).then_some(node.parent as usize),
+                    children: node.children.iter().map(|&child| child as usize).collect(),
+                    hit_count: 0,
+                    line_ticks: Vec::new(),
+                };
+                if let Some(source) = source {
+                    let (source_code, url, line_starts, debug_source) = &sources[source as usize];
+                    let (line, column) = line_starts
+                        .line_and_column(source_code.get_source_text(self), function_offset);
+                    profile_node.url = (*url).clone();
+                    profile_node.debug_source = *debug_source;
+                    profile_node.line = line;
+                    profile_node.column = column;
+                }
+                profile_node
+            })
+            .collect::<Vec<_>>();
+        let mut samples = Vec::with_capacity(profiler.samples.len());
+        for sample in profiler.samples.iter() {
+            let node = &mut nodes[sample.node as usize];
+            node.hit_count += 1;
+            let source = profiler.nodes[sample.node as usize].key.source;
+            if let (Some(source), Some(offset)) = (source, sample.offset) {
+                let line = sources[source as usize].2.line(offset);
+                match node
+                    .line_ticks
+                    .iter_mut()
+                    .find(|(tick_line, _)| *tick_line == line)
+                {
+                    Some((_, ticks)) => *ticks += 1,
+                    None => node.line_ticks.push((line, 1)),
+                }
+            }
+            samples.push(ProfileSample {
+                node: sample.node as usize,
+                timestamp: sample.time,
+            });
+        }
+        for node in nodes.iter_mut() {
+            node.line_ticks.sort_unstable();
+        }
+        Some(CpuProfile {
+            nodes,
+            samples,
+            duration,
+        })
+    }
+}
+
+impl GcAgent {
+    /// Starts the CPU profiler, discarding the samples of a profile that was
+    /// not stopped.
+    pub fn start_profiling(&mut self, interval: SampleInterval) {
+        self.agent.start_profiling(interval);
+    }
+
+    /// Stops the CPU profiler and returns the profile. Returns None if the
+    /// profiler is not running.
+    pub fn stop_profiling(&mut self) -> Option<CpuProfile> {
+        self.agent.stop_profiling()
+    }
+}
+
+/// A CPU profile recorded with [`Agent::start_profiling`].
+#[derive(Debug, Clone)]
+pub struct CpuProfile {
+    /// Nodes of the sampled call tree. The first node is the root, which
+    /// stands for the host calling into the Agent.
+    pub nodes: Vec<ProfileNode>,
+    /// The samples in the order they were taken.
+    pub samples: Vec<ProfileSample>,
+    /// Time from the start of profiling until it was stopped.
+    pub duration: Duration,
+}
+
+/// A function called through a path of the sampled call tree.
+#[derive(Debug, Clone)]
+pub struct ProfileNode {
+    /// Name of the function. Empty for Script and Module code and anonymous
+    /// functions.
+    pub function_name: std::string::String,
+    /// URL set with [`Agent::set_profiled_script_url`] for the function's
+    /// source code, or empty.
+    pub url: std::string::String,
+    /// Identifies the function's source code within the profile. None for
+    /// builtin functions and the root.
+    pub script_id: Option<u32>,
+    /// The debugger's identifier for the function's source code, if the
+    /// debugger was enabled when the profile was stopped.
+    pub debug_source: Option<DebugSourceId>,
+    /// The 1-based line and column where the function starts. Zero for
+    /// builtin functions and the root.
+    pub line: u32,
+    pub column: u32,
+    /// Index of the calling node. None for the root.
+    pub parent: Option<usize>,
+    /// Indexes of the called nodes.
+    pub children: Vec<usize>,
+    /// Number of samples taken while the function was the innermost frame.
+    pub hit_count: u32,
+    /// Number of samples taken on each 1-based line of the function, by
+    /// line.
+    pub line_ticks: Vec<(u32, u32)>,
+}
+
+/// A sample of a [`CpuProfile`].
+#[derive(Debug, Clone, Copy)]
+pub struct ProfileSample {
+    /// Index of the node of the innermost frame.
+    pub node: usize,
+    /// Time from the start of profiling until the sample was taken.
+    pub timestamp: Duration,
+}
+
+impl ProfileNode {
+    /// Describes the function for collapsed stacks, like call sites are in
+    /// the stacks of Errors.
+    fn label(&self) -> std::string::String {
+        let name = if self.function_name.is_empty() {
+            "<anonymous>"
+        } else {
+            &self.function_name
+        };
+        let label = if self.script_id.is_none() {
+            name.to_owned()
+        } else {
+            let url = if self.url.is_empty() {
+                "<anonymous>"
+            } else {
+                &self.url
+            };
+            format!("{} ({}:{}:{})", name, url, self.line, self.column)
+        };
+        // Semicolons separate the frames of collapsed stacks.
+        label.replace(';', ",")
+    }
+}
+
+impl CpuProfile {
+    /// Writes the profile in the V8 `.cpuprofile` JSON format. The profile
+    /// can be loaded into the Performance tab of Chrome DevTools.
+    pub fn write_cpuprofile(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(b"{\"nodes\":[")?;
+        for (index, node) in self.nodes.iter().enumerate() {
+            if index > 0 {
+                writer.write_all(b",\n")?;
+            }
+            write!(
+                writer,
+                "{{\"id\":{},\"callFrame\":{{\"functionName\":",
+                index + 1
+            )?;
+            write_json_string(writer, &node.function_name)?;
+            let script_id = node.script_id.map_or(0, |script_id| script_id + 1);
+            write!(writer, ",\"scriptId\":\"{}\",\"url\":", script_id)?;
+            write_json_string(writer, &node.url)?;
+            // Lines and columns are 0-based in call frames, but 1-based in
+            // position ticks.
+            write!(
+                writer,
+                ",\"lineNumber\":{},\"columnNumber\":{}}},\"hitCount\":{},\"children\":[",
+                i64::from(node.line) - 1,
+                i64::from(node.column) - 1,
+                node.hit_count
+            )?;
+            for (i, child) in node.children.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b",")?;
+                }
+                write!(writer, "{}", child + 1)?;
+            }
+            writer.write_all(b"],\"positionTicks\":[")?;
+            for (i, (line, ticks)) in node.line_ticks.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b",")?;
+                }
+                write!(writer, "{{\"line\":{},\"ticks\":{}}}", line, ticks)?;
+            }
+            writer.write_all(b"]}")?;
+        }
+        write!(
+            writer,
+            "],\n\"startTime\":0,\"endTime\":{},\n\"samples\":[",
+            self.duration.as_micros()
+        )?;
+        for (i, sample) in self.samples.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b",")?;
+            }
+            write!(writer, "{}", sample.node + 1)?;
+        }
+        writer.write_all(b"],\n\"timeDeltas\":[")?;
+        let mut previous = 0;
+        for (i, sample) in self.samples.iter().enumerate() {
+            if i > 0 {
+                writer.write_all(b",")?;
+            }
+            let time = sample.timestamp.as_micros();
+            write!(writer, "{}", time - previous)?;
+            previous = time;
+        }
+        writer.write_all(b"]}\n")
+    }
+
+    /// Writes the profile as collapsed stacks: One line per sampled stack,
+    /// with its frames from the outermost to the innermost separated by
+    /// semicolons, followed by the number of samples taken of it. This is
+    /// the input format of flame graph tools such as `flamegraph.pl` and
+    /// `inferno-flamegraph`.
+    pub fn write_collapsed_stacks(&self, writer: &mut impl Write) -> io::Result<()> {
+        let labels = self
+            .nodes
+            .iter()
+            .map(ProfileNode::label)
+            .collect::<Vec<_>>();
+        let mut stack = Vec::new();
+        for (index, node) in self.nodes.iter().enumerate().skip(1) {
+            if node.hit_count == 0 {
+                continue;
+            }
+            stack.clear();
+            let mut current = Some(index);
+            while let Some(index) = current.filter(|&index| index > 0) {
+                stack.push(labels[index].as_str());
+                current = self.nodes[index].parent;
+            }
+            stack.reverse();
+            writeln!(writer, "{} {}", stack.join(";"), node.hit_count)?;
+        }
+        Ok(())
+    }
+}
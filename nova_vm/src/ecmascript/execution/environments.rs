@@ -67,7 +67,7 @@ use super::{Agent, JsResult};
 pub(super) type OuterEnv = Option<EnvironmentIndex>;
 
 macro_rules! create_environment_index {
-    ($name: ident, $index: ident, $entry: ident) => {
+    ($name: ident, $index: ident, $entry: ident $(, $remembered: ident)?) => {
         /// An index used to access an environment from [`Environments`].
         /// Internally, we store the index in a [`NonZeroU32`] with the index
         /// plus one. This allows us to not use an empty value in storage for
@@ -127,6 +127,7 @@ macro_rules! create_environment_index {
 
         impl core::ops::IndexMut<$index> for Agent {
             fn index_mut(&mut self, index: $index) -> &mut Self::Output {
+                $(self.heap.remembered_sets.$remembered.remember(index.into_index());)?
                 &mut self.heap.environments.$entry[index]
             }
         }
@@ -156,11 +157,29 @@ macro_rules! create_environment_index {
 create_environment_index!(
     DeclarativeEnvironment,
     DeclarativeEnvironmentIndex,
-    declarative
+    declarative,
+    declarative_environments
 );
-create_environment_index!(FunctionEnvironment, FunctionEnvironmentIndex, function);
-create_environment_index!(GlobalEnvironment, GlobalEnvironmentIndex, global);
-create_environment_index!(ObjectEnvironment, ObjectEnvironmentIndex, object);
+create_environment_index!(
+    FunctionEnvironment,
+    FunctionEnvironmentIndex,
+    function,
+    function_environments
+);
+create_environment_index!(
+    GlobalEnvironment,
+    GlobalEnvironmentIndex,
+    global,
+    global_environments
+);
+create_environment_index!(
+    ObjectEnvironment,
+    ObjectEnvironmentIndex,
+    object,
+    object_environments
+);
+// Note: Private environments are not garbage collected and need no write
+// barrier.
 create_environment_index!(PrivateEnvironment, PrivateEnvironmentIndex, private);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -233,6 +252,28 @@ impl EnvironmentIndex {
         }
     }
 
+    /// Get the Declarative Environment Record holding the bindings of the
+    /// Environment Record `hops` steps out from this one.
+    ///
+    /// This is used by bytecode that has resolved bindings to slots at
+    /// compile time, and the Environment Record must be a Declarative or a
+    /// Function Environment Record.
+    pub(crate) fn get_scoped_declarative_environment(
+        self,
+        agent: &Agent,
+        hops: u32,
+    ) -> DeclarativeEnvironmentIndex {
+        let mut env = self;
+        for _ in 0..hops {
+            env = env.get_outer_env(agent).unwrap();
+        }
+        match env {
+            EnvironmentIndex::Declarative(index) => index,
+            EnvironmentIndex::Function(index) => agent[index].declarative_environment,
+            EnvironmentIndex::Global(_) | EnvironmentIndex::Object(_) => unreachable!(),
+        }
+    }
+
     /// ### Try [HasBinding(N)](https://tc39.es/ecma262/#table-abstract-methods-of-environment-records)
     ///
     /// Determine if an Environment Record has a binding for the String value
@@ -647,6 +688,9 @@ pub struct Environments {
     pub(crate) global: Vec<Option<GlobalEnvironment>>,
     pub(crate) object: Vec<Option<ObjectEnvironment>>,
     pub(crate) private: Vec<Option<PrivateEnvironment>>,
+    /// Number of bytes of environments allocated since the last garbage
+    /// collection.
+    pub(crate) alloc_counter: usize,
 }
 
 impl Default for Environments {
@@ -657,6 +701,7 @@ impl Default for Environments {
             global: Vec::with_capacity(1),
             object: Vec::with_capacity(1024),
             private: Vec::with_capacity(0),
+            alloc_counter: 0,
         }
     }
 }
@@ -791,6 +836,7 @@ impl Environments {
         env: DeclarativeEnvironment,
     ) -> DeclarativeEnvironmentIndex {
         self.declarative.push(Some(env));
+        self.alloc_counter += core::mem::size_of::<Option<DeclarativeEnvironment>>();
         DeclarativeEnvironmentIndex::from_u32(self.declarative.len() as u32)
     }
 
@@ -799,6 +845,7 @@ impl Environments {
         env: FunctionEnvironment,
     ) -> FunctionEnvironmentIndex {
         self.function.push(Some(env));
+        self.alloc_counter += core::mem::size_of::<Option<FunctionEnvironment>>();
         FunctionEnvironmentIndex::from_u32(self.function.len() as u32)
     }
 
@@ -807,6 +854,7 @@ impl Environments {
         env: GlobalEnvironment,
     ) -> GlobalEnvironmentIndex {
         self.global.push(Some(env));
+        self.alloc_counter += core::mem::size_of::<Option<GlobalEnvironment>>();
         GlobalEnvironmentIndex::from_u32(self.global.len() as u32)
     }
 
@@ -815,6 +863,7 @@ impl Environments {
         env: ObjectEnvironment,
     ) -> ObjectEnvironmentIndex {
         self.object.push(Some(env));
+        self.alloc_counter += core::mem::size_of::<Option<ObjectEnvironment>>();
         ObjectEnvironmentIndex::from_u32(self.object.len() as u32)
     }
 
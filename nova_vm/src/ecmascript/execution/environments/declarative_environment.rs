@@ -27,8 +27,13 @@ pub(crate) struct DeclarativeEnvironment {
     /// See [OuterEnv].
     pub(crate) outer_env: OuterEnv,
 
-    /// The environment's bindings.
-    pub(crate) bindings: AHashMap<String<'static>, Binding>,
+    /// The environment's bindings in order of creation. The index of a
+    /// binding is its slot, which bytecode resolved at compile time uses to
+    /// access the binding directly.
+    pub(crate) bindings: Vec<Binding>,
+
+    /// Slots of the environment's bindings by name.
+    pub(crate) names: AHashMap<String<'static>, u32>,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -52,7 +57,8 @@ impl DeclarativeEnvironment {
         // 3. Return env.
         DeclarativeEnvironment {
             outer_env,
-            bindings: AHashMap::default(),
+            bindings: Vec::new(),
+            names: AHashMap::default(),
         }
     }
 
@@ -60,7 +66,31 @@ impl DeclarativeEnvironment {
     pub(super) fn has_binding(&self, name: String) -> bool {
         // 1. If envRec has a binding for N, return true.
         // 2. Return false.
-        self.bindings.contains_key(&name)
+        self.names.contains_key(&name)
+    }
+
+    /// Get the slot of the binding for N, if envRec has a binding for N.
+    pub(super) fn get_slot(&self, name: String) -> Option<u32> {
+        self.names.get(&name).copied()
+    }
+
+    /// Get the name of the binding in a slot.
+    ///
+    /// This is only used for error messages, and is thus allowed to be slow.
+    pub(super) fn get_slot_name(&self, slot: u32) -> String<'static> {
+        *self
+            .names
+            .iter()
+            .find(|(_, binding_slot)| **binding_slot == slot)
+            .unwrap()
+            .0
+    }
+
+    /// Add a binding in the next slot.
+    fn push_binding(&mut self, name: String, binding: Binding) {
+        let slot = u32::try_from(self.bindings.len()).expect("Too many bindings");
+        self.bindings.push(binding);
+        self.names.insert(name.unbind(), slot);
     }
     /// ### [9.1.1.1.2 CreateMutableBinding ( N, D )](https://tc39.es/ecma262/#sec-declarative-environment-records-createmutablebinding-n-d)
     pub(super) fn create_mutable_binding(&mut self, name: String, is_deletable: bool) {
@@ -70,8 +100,8 @@ impl DeclarativeEnvironment {
         // 2. Create a mutable binding in envRec for N and record that it is
         // uninitialized. If D is true, record that the newly created binding
         // may be deleted by a subsequent DeleteBinding call.
-        self.bindings.insert(
-            name.unbind(),
+        self.push_binding(
+            name,
             Binding {
                 value: None,
                 // Strictness only seems to matter for immutable bindings.
@@ -91,8 +121,8 @@ impl DeclarativeEnvironment {
         // 2. Create an immutable binding in envRec for N and record that it is
         // uninitialized. If S is true, record that the newly created binding is
         // a strict binding.
-        self.bindings.insert(
-            name.unbind(),
+        self.push_binding(
+            name,
             Binding {
                 value: None,
                 strict: is_strict,
@@ -106,7 +136,8 @@ impl DeclarativeEnvironment {
     /// ### [9.1.1.1.4 InitializeBinding ( N, V )](https://tc39.es/ecma262/#sec-declarative-environment-records-initializebinding-n-v)
     pub(super) fn initialize_binding(&mut self, name: String, value: Value) {
         // 1. Assert: envRec must have an uninitialized binding for N.
-        let binding = self.bindings.get_mut(&name.unbind()).unwrap();
+        let slot = self.get_slot(name).unwrap();
+        let binding = &mut self.bindings[slot as usize];
 
         // 2. Set the bound value for N in envRec to V.
         // 3. Record that the binding for N in envRec has been initialized.
@@ -119,24 +150,28 @@ impl DeclarativeEnvironment {
     /// ### [9.1.1.1.6 GetBindingValue ( N, S )](https://tc39.es/ecma262/#sec-declarative-environment-records-getbindingvalue-n-s)
     pub(super) fn get_binding_value(&self, name: String, _is_strict: bool) -> Option<Value> {
         // 1. Assert: envRec has a binding for N.
-        let binding = self.bindings.get(&name).unwrap();
+        let slot = self.get_slot(name).unwrap();
 
         // 2. If the binding for N in envRec is an uninitialized binding, throw
         // a ReferenceError exception.
-        let Some(value) = binding.value else {
-            // Custom handling: Return None and let the caller handle throwing
-            // an error.
-            return None;
-        };
-
         // 3. Return the value currently bound to N in envRec.
-        Some(value)
+        self.get_slot_value(slot)
+    }
+
+    /// GetBindingValue for the binding in a slot.
+    ///
+    /// Returns None if the binding is uninitialized, and lets the caller
+    /// handle throwing an error.
+    pub(super) fn get_slot_value(&self, slot: u32) -> Option<Value> {
+        // Note: Initialization status is determined by the Some/None.
+        self.bindings[slot as usize].value
     }
 
     /// ### [9.1.1.1.7 DeleteBinding ( N )](https://tc39.es/ecma262/#sec-declarative-environment-records-deletebinding-n)
     pub(super) fn delete_binding(&mut self, name: String) -> bool {
         // 1. Assert: envRec has a binding for N.
-        let binding = self.bindings.get(&name).unwrap();
+        let slot = self.get_slot(name).unwrap();
+        let binding = &mut self.bindings[slot as usize];
 
         // 2. If the binding for N in envRec cannot be deleted, return false.
         if !binding.deletable {
@@ -144,7 +179,11 @@ impl DeclarativeEnvironment {
         }
 
         // 3. Remove the binding for N from envRec.
-        self.bindings.remove(&name.unbind());
+        // Note: The slot is left in place so that the slots of other bindings
+        // do not change. Deletable bindings are created by direct eval, and
+        // code containing a direct eval never accesses bindings by slot.
+        binding.value = None;
+        self.names.remove(&name.unbind());
 
         // 4. Return true.
         true
@@ -156,9 +195,10 @@ impl HeapMarkAndSweep for DeclarativeEnvironment {
         let Self {
             outer_env,
             bindings,
+            names: _,
         } = self;
         outer_env.mark_values(queues);
-        for binding in bindings.values() {
+        for binding in bindings.iter() {
             binding.value.mark_values(queues);
         }
     }
@@ -167,19 +207,19 @@ impl HeapMarkAndSweep for DeclarativeEnvironment {
         let Self {
             outer_env,
             bindings,
+            names,
         } = self;
         outer_env.sweep_values(compactions);
-        for binding in bindings.values_mut() {
+        for binding in bindings.iter_mut() {
             binding.value.sweep_values(compactions);
         }
-        let keys = bindings.keys().copied().collect::<Box<[_]>>();
+        let keys = names.keys().copied().collect::<Box<[_]>>();
         for key in keys.iter() {
             let mut new_key = *key;
             new_key.sweep_values(compactions);
             if *key != new_key {
-                let mut binding = bindings.remove(key).unwrap();
-                binding.value.sweep_values(compactions);
-                bindings.insert(new_key, binding);
+                let slot = names.remove(key).unwrap();
+                names.insert(new_key, slot);
             }
         }
     }
@@ -198,6 +238,11 @@ impl DeclarativeEnvironmentIndex {
         env_rec.has_binding(name)
     }
 
+    /// Get the slot of the binding for N, if envRec has a binding for N.
+    pub(crate) fn get_slot(self, agent: &Agent, name: String) -> Option<u32> {
+        agent[self].get_slot(name)
+    }
+
     /// ### [9.1.1.1.2 CreateMutableBinding ( N, D )](https://tc39.es/ecma262/#sec-declarative-environment-records-createmutablebinding-n-d)
     ///
     /// The CreateMutableBinding concrete method of a Declarative Environment
@@ -255,12 +300,12 @@ impl DeclarativeEnvironmentIndex {
         agent: &mut Agent,
         name: String,
         value: Value,
-        mut is_strict: bool,
+        is_strict: bool,
         gc: NoGcScope,
     ) -> JsResult<()> {
         let env_rec = &mut agent[self];
         // 1. If envRec does not have a binding for N, then
-        let Some(binding) = env_rec.bindings.get_mut(&name.unbind()) else {
+        let Some(slot) = env_rec.get_slot(name) else {
             // a. If S is true, throw a ReferenceError exception.
             if is_strict {
                 let error_message = format!("Identifier '{}' does not exist.", name.as_str(agent));
@@ -281,6 +326,20 @@ impl DeclarativeEnvironmentIndex {
             return Ok(());
         };
 
+        self.set_slot_value(agent, slot, value, is_strict, gc)
+    }
+
+    /// SetMutableBinding for the binding in a slot, starting from step 2.
+    pub(crate) fn set_slot_value(
+        self,
+        agent: &mut Agent,
+        slot: u32,
+        value: Value,
+        mut is_strict: bool,
+        gc: NoGcScope,
+    ) -> JsResult<()> {
+        let binding = &mut agent[self].bindings[slot as usize];
+
         // 2. If the binding for N in envRec is a strict binding, set S to true.
         if binding.strict {
             is_strict = true;
@@ -289,6 +348,7 @@ impl DeclarativeEnvironmentIndex {
         // 3. If the binding for N in envRec has not yet been initialized, then
         if binding.value.is_none() {
             // a. Throw a ReferenceError exception.
+            let name = agent[self].get_slot_name(slot);
             let error_message = format!(
                 "Identifier '{}' has not been initialized.",
                 name.as_str(agent)
@@ -308,6 +368,7 @@ impl DeclarativeEnvironmentIndex {
 
             // b. If S is true, throw a TypeError exception.
             if is_strict {
+                let name = agent[self].get_slot_name(slot);
                 let error_message = format!(
                     "Cannot assign to immutable identifier '{}' in strict mode.",
                     name.as_str(agent)
@@ -348,6 +409,23 @@ impl DeclarativeEnvironmentIndex {
         )
     }
 
+    /// GetBindingValue for the binding in a slot.
+    pub(crate) fn get_slot_value(
+        self,
+        agent: &mut Agent,
+        slot: u32,
+        gc: NoGcScope,
+    ) -> JsResult<Value> {
+        if let Some(value) = agent[self].get_slot_value(slot) {
+            return Ok(value);
+        }
+        // 2. If the binding for N in envRec is an uninitialized binding, throw
+        // a ReferenceError exception.
+        let name = agent[self].get_slot_name(slot);
+        let error_message = format!("Identifier '{}' does not exist.", name.as_str(agent));
+        Err(agent.throw_exception(ExceptionType::ReferenceError, error_message, gc))
+    }
+
     /// ### [9.1.1.1.7 DeleteBinding ( N )](https://tc39.es/ecma262/#sec-declarative-environment-records-deletebinding-n)
     ///
     /// The DeleteBinding concrete method of a Declarative Environment Record
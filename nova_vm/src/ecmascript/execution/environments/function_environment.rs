@@ -115,9 +115,10 @@ pub(crate) fn new_function_environment(
     let this_mode = ecmascript_function_object.this_mode;
     // 1. Let env be a new Function Environment Record containing no bindings.
     let dcl_env = DeclarativeEnvironment::new(Some(ecmascript_function_object.environment));
-    agent.heap.environments.declarative.push(Some(dcl_env));
-    let declarative_environment =
-        DeclarativeEnvironmentIndex::last(&agent.heap.environments.declarative);
+    let declarative_environment = agent
+        .heap
+        .environments
+        .push_declarative_environment(dcl_env);
     // 2. Set env.[[FunctionObject]] to F.
     let function_object = f.into_function().unbind();
     // 3. If F.[[ThisMode]] is LEXICAL, set env.[[ThisBindingStatus]] to LEXICAL.
@@ -165,9 +166,10 @@ pub(crate) fn new_class_static_element_environment(
             .unwrap()
             .lexical_environment,
     ));
-    agent.heap.environments.declarative.push(Some(dcl_env));
-    let declarative_environment =
-        DeclarativeEnvironmentIndex::last(&agent.heap.environments.declarative);
+    let declarative_environment = agent
+        .heap
+        .environments
+        .push_declarative_environment(dcl_env);
 
     let env = FunctionEnvironment {
         this_value: Some(class_constructor.into_value()),
@@ -192,13 +194,10 @@ pub(crate) fn new_class_field_initializer_environment(
     class_instance: Object,
     outer_env: EnvironmentIndex,
 ) -> FunctionEnvironmentIndex {
-    agent
+    let declarative_environment = agent
         .heap
         .environments
-        .declarative
-        .push(Some(DeclarativeEnvironment::new(Some(outer_env))));
-    let declarative_environment =
-        DeclarativeEnvironmentIndex::last(&agent.heap.environments.declarative);
+        .push_declarative_environment(DeclarativeEnvironment::new(Some(outer_env)));
     agent
         .heap
         .environments
@@ -273,7 +272,7 @@ impl FunctionEnvironmentIndex {
         agent: &mut Agent,
         name: String,
         value: Value,
-        mut is_strict: bool,
+        is_strict: bool,
         gc: NoGcScope,
     ) -> JsResult<()> {
         let env_rec = &agent[self];
@@ -300,45 +299,8 @@ impl FunctionEnvironmentIndex {
             return Ok(());
         };
 
-        let binding = agent[dcl_rec].bindings.get_mut(&name.unbind()).unwrap();
-
-        // 2. If the binding for N in envRec is a strict binding, set S to true.
-        if binding.strict {
-            is_strict = true;
-        }
-
-        // 3. If the binding for N in envRec has not yet been initialized, then
-        if binding.value.is_none() {
-            // a. Throw a ReferenceError exception.
-            let error_message = format!(
-                "Identifier '{}' has not been initialized.",
-                name.as_str(agent)
-            );
-            return Err(agent.throw_exception(ExceptionType::ReferenceError, error_message, gc));
-        }
-
-        // 4. Else if the binding for N in envRec is a mutable binding, then
-        if binding.mutable {
-            // a. Change its bound value to V.
-            binding.value = Some(value);
-        }
-        // 5. Else,
-        else {
-            // a. Assert: This is an attempt to change the value of an immutable binding.
-            debug_assert!(!binding.mutable);
-
-            // b. If S is true, throw a TypeError exception.
-            if is_strict {
-                let error_message = format!(
-                    "Cannot assign to immutable identifier '{}' in strict mode.",
-                    name.as_str(agent)
-                );
-                return Err(agent.throw_exception(ExceptionType::TypeError, error_message, gc));
-            }
-        }
-
-        // 6. Return UNUSED.
-        Ok(())
+        let slot = agent[dcl_rec].get_slot(name).unwrap();
+        dcl_rec.set_slot_value(agent, slot, value, is_strict, gc)
     }
 
     /// ### [9.1.1.1.6 GetBindingValue ( N, S )](https://tc39.es/ecma262/#sec-declarative-environment-records-getbindingvalue-n-s)
@@ -8,7 +8,7 @@ use crate::{
         scripts_and_modules::{source_code::SourceCode, ScriptOrModule},
         types::*,
     },
-    engine::context::NoGcScope,
+    engine::{context::NoGcScope, Executable},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
@@ -50,6 +50,11 @@ pub(crate) struct ECMAScriptCodeEvaluationState {
     /// defined for builtin functions: A builtin function's source code will
     /// point to the source code that called it.
     pub(crate) source_code: SourceCode,
+
+    /// Nova-specific piece of data that identifies the instruction currently
+    /// being executed in this execution context, and the Executable it is
+    /// in. This is used to map the call site back to its source position.
+    pub(crate) instruction: Option<(Executable, u32)>,
 }
 
 /// ### [9.4 Execution Contexts](https://tc39.es/ecma262/#sec-execution-contexts)
@@ -105,11 +110,15 @@ impl HeapMarkAndSweep for ECMAScriptCodeEvaluationState {
             private_environment,
             is_strict_mode: _,
             source_code,
+            instruction,
         } = self;
         lexical_environment.mark_values(queues);
         variable_environment.mark_values(queues);
         private_environment.mark_values(queues);
         source_code.mark_values(queues);
+        if let Some((executable, _)) = instruction {
+            executable.mark_values(queues);
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -119,11 +128,15 @@ impl HeapMarkAndSweep for ECMAScriptCodeEvaluationState {
             private_environment,
             is_strict_mode: _,
             source_code,
+            instruction,
         } = self;
         lexical_environment.sweep_values(compactions);
         variable_environment.sweep_values(compactions);
         private_environment.sweep_values(compactions);
         source_code.sweep_values(compactions);
+        if let Some((executable, _)) = instruction {
+            executable.sweep_values(compactions);
+        }
     }
 }
 
@@ -78,6 +78,10 @@ impl Index<RealmIdentifier> for Agent {
 
 impl IndexMut<RealmIdentifier> for Agent {
     fn index_mut(&mut self, index: RealmIdentifier) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .realms
+            .remember(index.into_index());
         &mut self.heap.realms[index]
     }
 }
@@ -503,6 +507,36 @@ pub(crate) fn set_default_global_bindings<'a>(
             ..Default::default()
         };
         define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
+
+        // B.2.1.1 escape ( string )
+        #[cfg(feature = "annex-b-global")]
+        {
+            let name = PropertyKey::from(BUILTIN_STRING_MEMORY.escape);
+            let value = agent.get_realm(realm_id).intrinsics().escape();
+            let desc = PropertyDescriptor {
+                value: Some(value.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            };
+            define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
+        }
+
+        // B.2.1.2 unescape ( string )
+        #[cfg(feature = "annex-b-global")]
+        {
+            let name = PropertyKey::from(BUILTIN_STRING_MEMORY.unescape);
+            let value = agent.get_realm(realm_id).intrinsics().unescape();
+            let desc = PropertyDescriptor {
+                value: Some(value.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            };
+            define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
+        }
     }
 
     // 19.3 Constructor Properties of the Global Object
@@ -545,6 +579,23 @@ pub(crate) fn set_default_global_bindings<'a>(
             };
             define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        {
+            // AsyncDisposableStack ( . . . )
+            let name = PropertyKey::from(BUILTIN_STRING_MEMORY.AsyncDisposableStack);
+            let value = agent
+                .get_realm(realm_id)
+                .intrinsics()
+                .async_disposable_stack();
+            let desc = PropertyDescriptor {
+                value: Some(value.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            };
+            define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
+        }
         // 19.3.4 BigInt ( . . . )
         let name = PropertyKey::from(BUILTIN_STRING_MEMORY.BigInt);
         let value = agent.get_realm(realm_id).intrinsics().big_int();
@@ -623,6 +674,20 @@ pub(crate) fn set_default_global_bindings<'a>(
             };
             define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        {
+            // DisposableStack ( . . . )
+            let name = PropertyKey::from(BUILTIN_STRING_MEMORY.DisposableStack);
+            let value = agent.get_realm(realm_id).intrinsics().disposable_stack();
+            let desc = PropertyDescriptor {
+                value: Some(value.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            };
+            define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
+        }
         // 19.3.10 Error ( . . . )
         let name = PropertyKey::from(BUILTIN_STRING_MEMORY.Error);
         let value = agent.get_realm(realm_id).intrinsics().error();
@@ -876,6 +941,20 @@ pub(crate) fn set_default_global_bindings<'a>(
         };
         define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
 
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        {
+            // SuppressedError ( . . . )
+            let name = PropertyKey::from(BUILTIN_STRING_MEMORY.SuppressedError);
+            let value = agent.get_realm(realm_id).intrinsics().suppressed_error();
+            let desc = PropertyDescriptor {
+                value: Some(value.into_value()),
+                writable: Some(true),
+                enumerable: Some(false),
+                configurable: Some(true),
+                ..Default::default()
+            };
+            define_property_or_throw(agent, global, name, desc, gc.reborrow())?;
+        }
         // 19.3.30 Symbol ( . . . )
         let name = PropertyKey::from(BUILTIN_STRING_MEMORY.Symbol);
         let value = agent.get_realm(realm_id).intrinsics().symbol();
@@ -3,6 +3,22 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use super::RealmIdentifier;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::control_abstraction_objects::{
+    async_disposable_stack_objects::{
+        async_disposable_stack_constructor::AsyncDisposableStackConstructor,
+        async_disposable_stack_prototype::AsyncDisposableStackPrototype,
+    },
+    disposable_stack_objects::{
+        disposable_stack_constructor::DisposableStackConstructor,
+        disposable_stack_prototype::DisposableStackPrototype,
+    },
+};
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::fundamental_objects::error_objects::{
+    suppressed_error_constructors::SuppressedErrorConstructor,
+    suppressed_error_prototypes::SuppressedErrorPrototype,
+};
 #[cfg(feature = "set")]
 use crate::ecmascript::builtins::keyed_collections::set_objects::{
     set_constructor::SetConstructor,
@@ -168,6 +184,8 @@ pub enum ProtoIntrinsics {
     #[cfg(feature = "array-buffer")]
     ArrayBuffer,
     ArrayIterator,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStack,
     AsyncFunction,
     AsyncGenerator,
     AsyncGeneratorFunction,
@@ -181,6 +199,8 @@ pub enum ProtoIntrinsics {
     DataView,
     #[cfg(feature = "date")]
     Date,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack,
     Error,
     EvalError,
     FinalizationRegistry,
@@ -215,6 +235,8 @@ pub enum ProtoIntrinsics {
     #[cfg(feature = "shared-array-buffer")]
     SharedArrayBuffer,
     String,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedError,
     Symbol,
     SyntaxError,
     TypeError,
@@ -283,6 +305,10 @@ impl Intrinsics {
         NativeErrorConstructors::create_intrinsic(agent, realm);
         AggregateErrorPrototype::create_intrinsic(agent, realm);
         AggregateErrorConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        SuppressedErrorPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        SuppressedErrorConstructor::create_intrinsic(agent, realm);
         NumberPrototype::create_intrinsic(agent, realm);
         NumberConstructor::create_intrinsic(agent, realm, gc);
         BigIntPrototype::create_intrinsic(agent, realm);
@@ -365,6 +391,14 @@ impl Intrinsics {
         AsyncGeneratorPrototype::create_intrinsic(agent, realm);
         AsyncFunctionPrototype::create_intrinsic(agent, realm);
         AsyncFunctionConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        DisposableStackPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        DisposableStackConstructor::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        AsyncDisposableStackPrototype::create_intrinsic(agent, realm);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        AsyncDisposableStackConstructor::create_intrinsic(agent, realm);
         ReflectObject::create_intrinsic(agent, realm);
         ProxyConstructor::create_intrinsic(agent, realm);
     }
@@ -397,7 +431,11 @@ impl Intrinsics {
             ProtoIntrinsics::SyntaxError => self.syntax_error_prototype().into(),
             ProtoIntrinsics::TypeError => self.type_error_prototype().into(),
             ProtoIntrinsics::UriError => self.uri_error_prototype().into(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::SuppressedError => self.suppressed_error_prototype().into(),
             ProtoIntrinsics::AggregateError => self.aggregate_error_prototype().into(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::AsyncDisposableStack => self.async_disposable_stack_prototype().into(),
             ProtoIntrinsics::AsyncFunction => self.async_function_prototype().into(),
             ProtoIntrinsics::AsyncGenerator => self.async_generator_prototype().into(),
             ProtoIntrinsics::AsyncGeneratorFunction => {
@@ -406,9 +444,11 @@ impl Intrinsics {
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::BigInt64Array => self.big_int64_array_prototype().into(),
             #[cfg(feature = "array-buffer")]
-            ProtoIntrinsics::BigUint64Array => self.big_int64_array_prototype().into(),
+            ProtoIntrinsics::BigUint64Array => self.big_uint64_array_prototype().into(),
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::DataView => self.data_view_prototype().into(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ProtoIntrinsics::DisposableStack => self.disposable_stack_prototype().into(),
             ProtoIntrinsics::FinalizationRegistry => self.finalization_registry_prototype().into(),
             #[cfg(feature = "proposal-float16array")]
             ProtoIntrinsics::Float16Array => self.float16_array_prototype().into(),
@@ -574,6 +614,37 @@ impl Intrinsics {
             .into()
     }
 
+    /// %AsyncDisposableStack.prototype%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::AsyncDisposableStackPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    /// %AsyncDisposableStack%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::AsyncDisposableStack
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::AsyncDisposableStack.get_object_index(self.object_index_base)
+    }
+
+    /// %AsyncDisposableStack.prototype.disposeAsync%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn async_disposable_stack_prototype_dispose_async(
+        &self,
+    ) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::AsyncDisposableStackPrototypeDisposeAsync
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
     /// %AsyncFunction.prototype%
     pub(crate) fn async_function_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::AsyncFunctionPrototype
@@ -785,6 +856,35 @@ impl Intrinsics {
             .into()
     }
 
+    /// %DisposableStack.prototype%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::DisposableStackPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    /// %DisposableStack%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::DisposableStack
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::DisposableStack.get_object_index(self.object_index_base)
+    }
+
+    /// %DisposableStack.prototype.dispose%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn disposable_stack_prototype_dispose(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::DisposableStackPrototypeDispose
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
     /// %encodeURI%
     pub(crate) fn encode_uri(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::EncodeURI
@@ -818,6 +918,7 @@ impl Intrinsics {
     }
 
     /// %escape%
+    #[cfg(feature = "annex-b-global")]
     pub(crate) fn escape(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::Escape
             .get_builtin_function_index(self.builtin_function_index_base)
@@ -1384,6 +1485,27 @@ impl Intrinsics {
             .into()
     }
 
+    /// %SuppressedError.prototype%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn suppressed_error_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::SuppressedErrorPrototype
+            .get_object_index(self.object_index_base)
+            .into()
+    }
+
+    /// %SuppressedError%
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn suppressed_error(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::SuppressedError
+            .get_builtin_function_index(self.builtin_function_index_base)
+            .into()
+    }
+
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) fn suppressed_error_base_object(&self) -> ObjectIndex<'static> {
+        IntrinsicConstructorIndexes::SuppressedError.get_object_index(self.object_index_base)
+    }
+
     /// %Symbol.prototype%
     pub(crate) fn symbol_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::SymbolPrototype
@@ -1555,6 +1677,7 @@ impl Intrinsics {
     }
 
     /// %unescape%
+    #[cfg(feature = "annex-b-global")]
     pub(crate) fn unescape(&self) -> BuiltinFunction<'static> {
         IntrinsicFunctionIndexes::Unescape
             .get_builtin_function_index(self.builtin_function_index_base)
@@ -1659,6 +1782,13 @@ impl HeapMarkAndSweep for Intrinsics {
         self.array_iterator_prototype().mark_values(queues);
         self.async_from_sync_iterator_prototype()
             .mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.async_disposable_stack_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.async_disposable_stack_prototype_dispose_async()
+            .mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.async_disposable_stack().mark_values(queues);
         self.async_function_prototype().mark_values(queues);
         self.async_function().mark_values(queues);
         self.async_generator_function_prototype()
@@ -1692,10 +1822,18 @@ impl HeapMarkAndSweep for Intrinsics {
         self.date().mark_values(queues);
         self.decode_uri().mark_values(queues);
         self.decode_uri_component().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.disposable_stack_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.disposable_stack_prototype_dispose()
+            .mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.disposable_stack().mark_values(queues);
         self.encode_uri().mark_values(queues);
         self.encode_uri_component().mark_values(queues);
         self.error_prototype().mark_values(queues);
         self.error().mark_values(queues);
+        #[cfg(feature = "annex-b-global")]
         self.escape().mark_values(queues);
         self.eval().mark_values(queues);
         self.eval_error_prototype().mark_values(queues);
@@ -1776,6 +1914,10 @@ impl HeapMarkAndSweep for Intrinsics {
         self.string_prototype().mark_values(queues);
         self.string().mark_values(queues);
         self.string_iterator_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.suppressed_error_prototype().mark_values(queues);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        self.suppressed_error().mark_values(queues);
         self.symbol_prototype().mark_values(queues);
         self.symbol().mark_values(queues);
         self.syntax_error_prototype().mark_values(queues);
@@ -1808,6 +1950,7 @@ impl HeapMarkAndSweep for Intrinsics {
         self.uint8_clamped_array().mark_values(queues);
         #[cfg(feature = "array-buffer")]
         self.uint8_clamped_array_prototype().mark_values(queues);
+        #[cfg(feature = "annex-b-global")]
         self.unescape().mark_values(queues);
         self.uri_error_prototype().mark_values(queues);
         self.uri_error().mark_values(queues);
@@ -2,7 +2,12 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use crate::heap::{CompactionLists, HeapMarkAndSweep, WorkQueues};
+use core::any::Any;
+
+use crate::{
+    ecmascript::execution::Agent,
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
 
 use self::script::ScriptIdentifier;
 
@@ -12,12 +17,31 @@ pub mod module;
 pub mod script;
 pub mod source_code;
 
+/// The Script or Module that ECMAScript code originates from.
 #[derive(Debug, Clone, Copy)]
-pub(crate) enum ScriptOrModule {
+pub enum ScriptOrModule {
     Script(ScriptIdentifier),
     Module(Module<'static>),
 }
 
+impl ScriptOrModule {
+    /// The name that the host gave to the Script or Module.
+    pub fn name(self, agent: &Agent) -> Option<&str> {
+        match self {
+            ScriptOrModule::Script(script) => agent[script].source_code.get_name(agent),
+            ScriptOrModule::Module(_) => None,
+        }
+    }
+
+    /// The \[\[HostDefined]] field of the Script or Module.
+    pub fn host_defined(self, agent: &Agent) -> Option<&dyn Any> {
+        match self {
+            ScriptOrModule::Script(script) => agent[script].host_defined.as_deref(),
+            ScriptOrModule::Module(_) => None,
+        }
+    }
+}
+
 impl HeapMarkAndSweep for ScriptOrModule {
     fn mark_values(&self, queues: &mut WorkQueues) {
         match self {
@@ -51,6 +51,10 @@ impl Index<ModuleIdentifier<'_>> for Agent {
 
 impl IndexMut<ModuleIdentifier<'_>> for Agent {
     fn index_mut(&mut self, index: ModuleIdentifier) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .modules
+            .remember(index.into_index());
         &mut self.heap.modules[index]
     }
 }
@@ -11,6 +11,7 @@ use crate::{
         },
         scripts_and_modules::ScriptOrModule,
         syntax_directed_operations::{
+            function_names::FunctionNames,
             miscellaneous::instantiate_function_object,
             scope_analysis::{
                 script_lexically_declared_names, script_lexically_scoped_declarations,
@@ -40,7 +41,7 @@ use super::source_code::SourceCode;
 pub type HostDefined = &'static mut dyn Any;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-pub(crate) struct ScriptIdentifier(u32, PhantomData<Script>);
+pub struct ScriptIdentifier(u32, PhantomData<Script>);
 
 impl ScriptIdentifier {
     /// Creates a script identififer from a usize.
@@ -81,6 +82,10 @@ impl Index<ScriptIdentifier> for Agent {
 
 impl IndexMut<ScriptIdentifier> for Agent {
     fn index_mut(&mut self, index: ScriptIdentifier) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .scripts
+            .remember(index.into_index());
         &mut self.heap.scripts[index]
     }
 }
@@ -161,6 +166,14 @@ pub struct Script {
 
 unsafe impl Send for Script {}
 
+impl Script {
+    /// Names the Script, for example with its file path or URL. The name is
+    /// shown in the stack traces of Errors thrown by the Script's code.
+    pub fn set_name(&self, agent: &mut Agent, name: &str) {
+        self.source_code.set_name(agent, name);
+    }
+}
+
 pub type ScriptOrErrors = Result<Script, Vec<OxcDiagnostic>>;
 
 impl HeapMarkAndSweep for Script {
@@ -282,6 +295,7 @@ pub fn script_evaluation(agent: &mut Agent, script: Script, mut gc: GcScope) ->
             is_strict_mode,
 
             source_code,
+            instruction: None,
         }),
     };
 
@@ -327,6 +341,28 @@ pub fn script_evaluation(agent: &mut Agent, script: Script, mut gc: GcScope) ->
     result
 }
 
+/// Compiles the Script without evaluating it, and disassembles its bytecode
+/// into readable text annotated with source lines. The disassembly includes
+/// the bytecode of the functions that the Script contains.
+///
+/// Compiling class definitions requires the current Realm to be the Script's
+/// Realm.
+pub fn disassemble_script(agent: &mut Agent, script: Script, gc: NoGcScope) -> std::string::String {
+    let is_strict_mode = script.ecmascript_code.source_type.is_strict();
+    let source_code = script.source_code;
+    let names = FunctionNames::new(&script.ecmascript_code);
+    let script = agent.heap.add_script(script);
+    let executable = Executable::compile_script(agent, script, gc);
+    executable.disassemble(
+        agent,
+        "script",
+        Some(source_code),
+        names,
+        is_strict_mode,
+        gc,
+    )
+}
+
 /// ### [16.1.7 GlobalDeclarationInstantiation ( script, env )](https://tc39.es/ecma262/#sec-globaldeclarationinstantiation)
 ///
 /// The abstract operation GlobalDeclarationInstantiation takes arguments
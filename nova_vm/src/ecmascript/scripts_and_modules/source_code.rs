@@ -7,7 +7,11 @@
 //! that the eval call defines functions. Those functions will refer to the
 //! SourceCode for their function source text.
 
-use core::{fmt::Debug, ops::Index, ptr::NonNull};
+use core::{
+    fmt::Debug,
+    ops::{Index, IndexMut},
+    ptr::NonNull,
+};
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
@@ -120,6 +124,7 @@ impl SourceCode {
         let source_code = agent.heap.create(SourceCodeHeapData {
             source: source.unbind(),
             allocator,
+            name: None,
         });
 
         Ok((program, source_code))
@@ -129,11 +134,70 @@ impl SourceCode {
         agent[agent[self].source].as_str()
     }
 
+    /// Get the name that the host gave to the source code, if any.
+    pub(crate) fn get_name(self, agent: &Agent) -> Option<&str> {
+        agent[self].name.as_deref()
+    }
+
+    pub(crate) fn set_name(self, agent: &mut Agent, name: &str) {
+        agent[self].name = Some(name.into());
+    }
+
+    /// Get the 1-based line and column of a byte offset into the source
+    /// text.
+    pub(crate) fn get_line_and_column(self, agent: &Agent, offset: u32) -> (u32, u32) {
+        let source_text = self.get_source_text(agent);
+        let before = &source_text[..(offset as usize).min(source_text.len())];
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        (line as u32, column as u32)
+    }
+
     pub(crate) fn get_index(self) -> usize {
         self.0.into_index()
     }
 }
 
+/// Byte offsets of the starts of the lines of a source text, for mapping
+/// many byte offsets into it to lines.
+#[derive(Debug)]
+pub(crate) struct LineStarts(Box<[usize]>);
+
+impl LineStarts {
+    pub(crate) fn new(source_text: &str) -> Self {
+        Self(
+            core::iter::once(0)
+                .chain(source_text.match_indices('\n').map(|(index, _)| index + 1))
+                .collect(),
+        )
+    }
+
+    /// Get the 1-based line of a byte offset into the source text.
+    pub(crate) fn line(&self, offset: u32) -> u32 {
+        self.0.partition_point(|&start| start <= offset as usize) as u32
+    }
+
+    /// Get the 1-based line and column of a byte offset into the source
+    /// text.
+    pub(crate) fn line_and_column(&self, source_text: &str, offset: u32) -> (u32, u32) {
+        let offset = (offset as usize).min(source_text.len());
+        let line = self.0.partition_point(|&start| start <= offset);
+        let column = source_text[self.0[line - 1]..offset].chars().count() + 1;
+        (line as u32, column as u32)
+    }
+
+    /// Get the text of a 1-based line without its line terminator.
+    pub(crate) fn line_text<'a>(&self, source_text: &'a str, line: u32) -> &'a str {
+        let start = self.0[line as usize - 1];
+        let end = self
+            .0
+            .get(line as usize)
+            .map_or(source_text.len(), |&next| next - 1);
+        source_text[start..end].trim_end_matches('\r')
+    }
+}
+
 pub(crate) struct SourceCodeHeapData {
     /// The source JavaScript string data the eval was called with. The string
     /// is known and required to be a HeapString because functions created
@@ -143,6 +207,9 @@ pub(crate) struct SourceCodeHeapData {
     source: HeapString<'static>,
     /// The arena that contains the parsed data of the eval source.
     allocator: NonNull<Allocator>,
+    /// Name of the source code given by the host, such as the file path or
+    /// URL of a Script. Used in stack traces.
+    name: Option<Box<str>>,
 }
 
 unsafe impl Send for SourceCodeHeapData {}
@@ -152,10 +219,19 @@ impl Debug for SourceCodeHeapData {
         f.debug_struct("SourceCodeHeapData")
             .field("source", &self.source)
             .field("allocator", &"[binary data]")
+            .field("name", &self.name)
             .finish()
     }
 }
 
+impl SourceCodeHeapData {
+    /// Returns the number of bytes allocated for the parsed source code.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        // SAFETY: The allocator is owned by this SourceCode.
+        unsafe { self.allocator.as_ref() }.capacity()
+    }
+}
+
 impl Drop for SourceCodeHeapData {
     fn drop(&mut self) {
         // SAFETY: All references to this SourceCode should have been dropped
@@ -177,9 +253,21 @@ impl Index<SourceCode> for Agent {
     }
 }
 
+impl IndexMut<SourceCode> for Agent {
+    fn index_mut(&mut self, index: SourceCode) -> &mut Self::Output {
+        self.heap
+            .source_codes
+            .get_mut(index.get_index())
+            .expect("SourceCode out of bounds")
+            .as_mut()
+            .expect("SourceCode slot empty")
+    }
+}
+
 impl CreateHeapData<SourceCodeHeapData, SourceCode> for Heap {
     fn create(&mut self, data: SourceCodeHeapData) -> SourceCode {
         self.source_codes.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<SourceCodeHeapData>>();
         SourceCode(SourceCodeIndex::last(&self.source_codes))
     }
 }
@@ -189,6 +277,7 @@ impl HeapMarkAndSweep for SourceCodeHeapData {
         let Self {
             source,
             allocator: _,
+            name: _,
         } = self;
         source.mark_values(queues);
     }
@@ -197,6 +286,7 @@ impl HeapMarkAndSweep for SourceCodeHeapData {
         let Self {
             source,
             allocator: _,
+            name: _,
         } = self;
         source.sweep_values(compactions);
     }
@@ -4,5 +4,6 @@
 
 pub(crate) mod class_definitions;
 pub(crate) mod function_definitions;
+pub(crate) mod function_names;
 pub(crate) mod miscellaneous;
 pub(crate) mod scope_analysis;
@@ -317,6 +317,7 @@ pub(crate) fn evaluate_async_function_body<'a>(
     let scoped_function_object = function_object.scope(agent, gc.nogc());
     // 1. Let promiseCapability be ! NewPromiseCapability(%Promise%).
     let promise_capability = PromiseCapability::new(agent);
+    let scoped_promise = promise_capability.promise().scope(agent, gc.nogc());
     // 2. Let declResult be Completion(FunctionDeclarationInstantiation(functionObject, argumentsList)).
     // 3. If declResult is an abrupt completion, then
     //if let Err(err) = function_declaration_instantiation(agent, function_object, arguments_list) {
@@ -336,7 +337,12 @@ pub(crate) fn evaluate_async_function_body<'a>(
 
     // AsyncFunctionStart will run the function until it returns, throws or gets suspended with
     // an await.
-    match Vm::execute(agent, exe, Some(arguments_list.0), gc.reborrow()) {
+    let execution_result = Vm::execute(agent, exe, Some(arguments_list.0), gc.reborrow());
+    let promise_capability = PromiseCapability::from_promise(
+        scoped_promise.get(agent),
+        promise_capability.must_be_unresolved(),
+    );
+    match execution_result {
         ExecutionResult::Return(result) => {
             // [27.7.5.2 AsyncBlockStart ( promiseCapability, asyncBody, asyncContext )](https://tc39.es/ecma262/#sec-asyncblockstart)
             // 2. e. If result is a normal completion, then
@@ -416,8 +422,15 @@ pub(crate) fn evaluate_generator_body(
 
     // 4. Perform GeneratorStart(G, FunctionBody).
     // SAFETY: We're alive so SourceCode must be too.
-    let data = CompileFunctionBodyData::new(agent, scoped_function_object.get(agent));
-    let executable = Executable::compile_function_body(agent, data, gc);
+    let function_object = scoped_function_object.get(agent).bind(gc);
+    let executable = if let Some(exe) = agent[function_object].compiled_bytecode {
+        exe
+    } else {
+        let data = CompileFunctionBodyData::new(agent, function_object);
+        let exe = Executable::compile_function_body(agent, data, gc);
+        agent[function_object].compiled_bytecode = Some(exe);
+        exe
+    };
     agent[generator].generator_state = Some(GeneratorState::Suspended(SuspendedGeneratorState {
         vm_or_args: VmOrArguments::Arguments(arguments_list.0.into()),
         executable,
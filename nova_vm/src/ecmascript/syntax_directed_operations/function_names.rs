@@ -0,0 +1,158 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! The names that functions and classes get when they are created, known from
+//! the source code before any of it runs.
+
+use ahash::AHashMap;
+use oxc_ast::{
+    ast::{
+        self, AssignmentExpression, AssignmentPattern, AssignmentTarget, BindingPatternKind, Class,
+        Function, MethodDefinitionKind, ObjectProperty, Program, PropertyKey, PropertyKind,
+        VariableDeclarator,
+    },
+    visit::walk,
+    Visit,
+};
+use oxc_span::GetSpan;
+use oxc_syntax::{operator::AssignmentOperator, scope::ScopeFlags};
+
+/// ### [8.4.3 Static Semantics: IsAnonymousFunctionDefinition ( expr )](https://tc39.es/ecma262/#sec-isanonymousfunctiondefinition)
+///
+/// The abstract operation IsAnonymousFunctionDefinition takes argument expr
+/// (an AssignmentExpression Parse Node, an Initializer Parse Node, or an
+/// Expression Parse Node) and returns a Boolean. It determines if its
+/// argument is a function definition that does not bind a name.
+pub(crate) fn is_anonymous_function_definition(expression: &ast::Expression) -> bool {
+    match expression {
+        ast::Expression::ArrowFunctionExpression(_) => true,
+        ast::Expression::FunctionExpression(f) => f.id.is_none(),
+        ast::Expression::ClassExpression(f) => f.id.is_none(),
+        ast::Expression::ParenthesizedExpression(x) => {
+            is_anonymous_function_definition(&x.expression)
+        }
+        _ => false,
+    }
+}
+
+/// Names of the functions, arrow functions and classes of a Program by their
+/// start offsets. Like in the bytecode compiler, a function gets the name it
+/// binds, the name given to it by NamedEvaluation, or the key of the method
+/// it defines. Class constructors get the name of their class. Functions
+/// whose names are only known at runtime, like those with computed keys, are
+/// missing.
+#[derive(Debug, Default)]
+pub(crate) struct FunctionNames {
+    names: AHashMap<u32, std::string::String>,
+}
+
+impl FunctionNames {
+    pub(crate) fn new(program: &Program) -> Self {
+        let mut function_names = Self::default();
+        function_names.visit_program(program);
+        function_names
+    }
+
+    /// Returns the name of the function, arrow function or class that starts
+    /// at the offset.
+    pub(crate) fn get(&self, start: u32) -> Option<&str> {
+        self.names.get(&start).map(|name| name.as_str())
+    }
+
+    /// Names the expression if it is an anonymous function definition.
+    fn named_evaluation(&mut self, expression: &ast::Expression, name: &str) {
+        if is_anonymous_function_definition(expression) {
+            let start = expression.without_parentheses().span().start;
+            self.names.insert(start, name.to_owned());
+        }
+    }
+}
+
+/// Returns the name that a method gets from its key, if the key is known
+/// statically.
+fn property_key_name(key: &PropertyKey) -> Option<std::string::String> {
+    match key {
+        PropertyKey::PrivateIdentifier(identifier) => Some(format!("#{}", identifier.name)),
+        key => key.static_name().map(|name| name.into_owned()),
+    }
+}
+
+impl<'a> Visit<'a> for FunctionNames {
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        if let Some(id) = &it.id {
+            self.names.insert(it.span.start, id.name.to_string());
+        }
+        walk::walk_function(self, it, flags);
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        if let Some(id) = &it.id {
+            self.names.insert(it.span.start, id.name.to_string());
+        }
+        let name = self.names.get(&it.span.start).cloned();
+        for element in it.body.body.iter() {
+            if let ast::ClassElement::MethodDefinition(method) = element {
+                let name = match method.kind {
+                    MethodDefinitionKind::Constructor => name.clone(),
+                    _ => property_key_name(&method.key),
+                };
+                if let Some(name) = name {
+                    self.names.insert(method.value.span.start, name);
+                }
+            }
+        }
+        walk::walk_class(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        let is_proto_setter = it.kind == PropertyKind::Init
+            && !it.computed
+            && !it.shorthand
+            && !it.method
+            && it.key.is_specific_static_name("__proto__");
+        if !is_proto_setter {
+            if let Some(name) = property_key_name(&it.key) {
+                match it.kind {
+                    PropertyKind::Init => self.named_evaluation(&it.value, &name),
+                    // Getters and setters are named by their keys.
+                    PropertyKind::Get | PropertyKind::Set => {
+                        self.names.insert(it.value.span().start, name);
+                    }
+                }
+            }
+        }
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let (BindingPatternKind::BindingIdentifier(identifier), Some(init)) =
+            (&it.id.kind, &it.init)
+        {
+            self.named_evaluation(init, &identifier.name);
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+
+    fn visit_assignment_pattern(&mut self, it: &AssignmentPattern<'a>) {
+        if let BindingPatternKind::BindingIdentifier(identifier) = &it.left.kind {
+            self.named_evaluation(&it.right, &identifier.name);
+        }
+        walk::walk_assignment_pattern(self, it);
+    }
+
+    fn visit_assignment_expression(&mut self, it: &AssignmentExpression<'a>) {
+        // Note: The bytecode compiler only performs NamedEvaluation for
+        // logical assignments.
+        if let (
+            AssignmentOperator::LogicalAnd
+            | AssignmentOperator::LogicalOr
+            | AssignmentOperator::LogicalNullish,
+            AssignmentTarget::AssignmentTargetIdentifier(identifier),
+        ) = (it.operator, &it.left)
+        {
+            self.named_evaluation(&it.right, &identifier.name);
+        }
+        walk::walk_assignment_expression(self, it);
+    }
+}
@@ -4,15 +4,24 @@
 
 use core::ops::Deref;
 
-use oxc_ast::ast::{
-    BindingIdentifier, BlockStatement, Class, Declaration, ExportDefaultDeclarationKind,
-    ForStatementInit, ForStatementLeft, Function, FunctionBody, LabeledStatement, Program,
-    Statement, StaticBlock, SwitchCase, SwitchStatement, VariableDeclaration,
-    VariableDeclarationKind, VariableDeclarator,
+use ahash::{AHashMap, AHashSet};
+use oxc_ast::{
+    ast::{
+        ArrowFunctionExpression, AssignmentTargetPropertyIdentifier, BindingIdentifier,
+        BindingPatternKind, BlockStatement, CallExpression, CatchClause, Class, Declaration,
+        ExportDefaultDeclarationKind, ForInStatement, ForOfStatement, ForStatement,
+        ForStatementInit, ForStatementLeft, FormalParameters, Function, FunctionBody,
+        IdentifierReference, LabeledStatement, Program, SimpleAssignmentTarget, Statement,
+        StaticBlock, SwitchCase, SwitchStatement, VariableDeclaration, VariableDeclarationKind,
+        VariableDeclarator, WithStatement,
+    },
+    visit::walk,
+    Visit,
 };
 use oxc_ecmascript::BoundNames;
 
-use oxc_span::Atom;
+use oxc_span::{Atom, GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
 
 /// ### [8.2.4 Static Semantics: LexicallyDeclaredNames](https://tc39.es/ecma262/#sec-static-semantics-lexicallydeclarednames)
 ///
@@ -211,6 +220,23 @@ pub(crate) enum LexicallyScopedDeclaration<'a> {
     DefaultExport,
 }
 
+impl LexicallyScopedDeclaration<'_> {
+    /// ### [8.2.3 Static Semantics: IsConstantDeclaration](https://tc39.es/ecma262/#sec-static-semantics-isconstantdeclaration)
+    pub(crate) fn is_constant_declaration(self) -> bool {
+        match self {
+            // LexicalDeclaration : LetOrConst BindingList ;
+            // 1. Return IsConstantDeclaration of LetOrConst.
+            // UsingDeclaration : using BindingList ;
+            // AwaitUsingDeclaration : CoverAwaitExpressionAndAwaitUsingDeclarationHead BindingList ;
+            // 1. Return true.
+            LexicallyScopedDeclaration::Variable(decl) => decl.kind != VariableDeclarationKind::Let,
+            // FunctionDeclaration, ClassDeclaration, ExportDeclaration
+            // 1. Return false.
+            _ => false,
+        }
+    }
+}
+
 /// ### [8.2.5 Static Semantics: LexicallyScopedDeclarations](https://tc39.es/ecma262/#sec-static-semantics-lexicallyscopeddeclarations)
 ///
 /// The syntax-directed operation LexicallyScopedDeclarations takes no
@@ -606,7 +632,7 @@ impl<'a> VarDeclaredNames<'a> for Statement<'a> {
             // 1. Return a new empty List.
             Statement::FunctionDeclaration(_) |
             Statement::ClassDeclaration(_) => {}
-            Statement::VariableDeclaration(decl) if decl.kind.is_lexical() => {}
+            Statement::VariableDeclaration(decl) if !decl.kind.is_var() => {}
             // VariableStatement : var VariableDeclarationList ;
             Statement::VariableDeclaration(decl) => {
                 // 1. Return the BoundNames of VariableDeclarationList
@@ -1240,7 +1266,7 @@ impl<'a> TopLevelVarDeclaredNames<'a> for Statement<'a> {
                 decl.bound_names(f)
             }
             // 2. Return a new empty List.
-            Statement::VariableDeclaration(decl) if decl.kind.is_lexical() => {
+            Statement::VariableDeclaration(decl) if !decl.kind.is_var() => {
                 // LexicalDeclaration : LetOrConst BindingList
             }
             Statement::ClassDeclaration(_)
@@ -1466,3 +1492,412 @@ impl<'a> TopLevelVarScopedDeclarations<'a> for LabeledStatement<'a> {
         }
     }
 }
+
+/// Finds the bindings of a function that do not escape the function's own
+/// code, and can thus be held in registers of its frame instead of in an
+/// Environment Record.
+///
+/// A binding escapes if a nested function or class refers to it, or if a
+/// direct `eval` call or a `with` statement anywhere in the function could
+/// observe it. Parameters escape through the `arguments` object. Lexical
+/// bindings are further required to be declared only once, outside of
+/// `switch` cases, and to never be referenced outside of their scope or
+/// before their declaration, so that their temporal dead zone can never be
+/// observed.
+///
+/// The bindings are returned in order of declaration.
+pub(crate) fn function_register_bindings<'a>(
+    params: &FormalParameters<'a>,
+    body: &FunctionBody<'a>,
+) -> Vec<Atom<'a>> {
+    let mut analysis = RegisterBindingAnalysis::default();
+    let is_simple_parameter_list = params.rest.is_none()
+        && params
+            .items
+            .iter()
+            .all(|param| param.pattern.kind.is_binding_identifier());
+    params.bound_names(&mut |identifier| {
+        analysis.declare(
+            identifier.name,
+            if is_simple_parameter_list {
+                LocalDeclaration::Parameter
+            } else {
+                LocalDeclaration::Other
+            },
+        );
+    });
+    analysis.visit_formal_parameters(params);
+    // Parameter expressions cannot see the bindings of the function body.
+    for (name, _) in analysis.references.drain() {
+        analysis.captured.insert(name);
+    }
+    analysis.scopes.push((body.span, false));
+    analysis.visit_function_body(body);
+    analysis.scopes.pop();
+    analysis.into_register_bindings()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LocalDeclaration {
+    Parameter,
+    Var,
+    Lexical {
+        scope: Span,
+        initialized_at: u32,
+        is_const: bool,
+    },
+    /// Function, class, catch parameter, destructuring and `using`
+    /// declarations.
+    Other,
+}
+
+#[derive(Default)]
+struct RegisterBindingAnalysis<'a> {
+    /// Number of functions and classes nested in the analysed function that
+    /// are being visited.
+    function_depth: u32,
+    /// Number of class static blocks being visited. Static blocks are
+    /// compiled as part of the analysed function.
+    static_block_depth: u32,
+    /// Spans of the scopes that lexical declarations being visited belong
+    /// to, and whether the scope is a `switch` statement's case block.
+    scopes: Vec<(Span, bool)>,
+    /// Declarations of the analysed function in source order.
+    declarations: Vec<(Atom<'a>, LocalDeclaration)>,
+    /// Start positions of the analysed function's references to each name,
+    /// and whether the reference is assigned to.
+    references: AHashMap<Atom<'a>, Vec<(u32, bool)>>,
+    /// Names referenced by nested functions and classes.
+    captured: AHashSet<Atom<'a>>,
+    references_arguments: bool,
+    has_direct_eval_or_with: bool,
+}
+
+impl<'a> RegisterBindingAnalysis<'a> {
+    fn declare(&mut self, name: Atom<'a>, declaration: LocalDeclaration) {
+        if self.function_depth == 0 {
+            self.declarations.push((name, declaration));
+        } else if self.static_block_depth > 0 {
+            // Static block declarations would be resolved to registers of
+            // the same name.
+            self.captured.insert(name);
+        }
+    }
+
+    fn declare_variables(&mut self, it: &VariableDeclaration<'a>, initialized_at: Option<u32>) {
+        for declarator in &it.declarations {
+            let declaration = match (&declarator.id.kind, it.kind) {
+                (BindingPatternKind::BindingIdentifier(_), VariableDeclarationKind::Var) => {
+                    LocalDeclaration::Var
+                }
+                (
+                    BindingPatternKind::BindingIdentifier(_),
+                    VariableDeclarationKind::Let | VariableDeclarationKind::Const,
+                ) => match self.scopes.last() {
+                    Some(&(scope, false)) => LocalDeclaration::Lexical {
+                        scope,
+                        initialized_at: initialized_at.unwrap_or(declarator.span.end),
+                        is_const: it.kind.is_const(),
+                    },
+                    _ => LocalDeclaration::Other,
+                },
+                _ => LocalDeclaration::Other,
+            };
+            declarator
+                .id
+                .bound_names(&mut |identifier| self.declare(identifier.name, declaration));
+        }
+    }
+
+    fn reference(&mut self, name: Atom<'a>, start: u32, is_write: bool) {
+        if name == "arguments" {
+            self.references_arguments = true;
+        }
+        if self.function_depth == 0 {
+            self.references
+                .entry(name)
+                .or_default()
+                .push((start, is_write));
+        } else {
+            self.captured.insert(name);
+        }
+    }
+
+    fn into_register_bindings(self) -> Vec<Atom<'a>> {
+        if self.has_direct_eval_or_with {
+            return vec![];
+        }
+        // Merge the declarations of each name: Only repeated var
+        // declarations refer to a single binding of a single kind.
+        let mut bindings: Vec<(Atom<'a>, LocalDeclaration)> = vec![];
+        let mut binding_indexes: AHashMap<Atom<'a>, usize> = AHashMap::default();
+        for (name, declaration) in self.declarations {
+            if let Some(&index) = binding_indexes.get(&name) {
+                if !(declaration == LocalDeclaration::Var
+                    && bindings[index].1 == LocalDeclaration::Var)
+                {
+                    bindings[index].1 = LocalDeclaration::Other;
+                }
+            } else {
+                binding_indexes.insert(name, bindings.len());
+                bindings.push((name, declaration));
+            }
+        }
+        bindings
+            .into_iter()
+            .filter(|(name, declaration)| {
+                if *name == "arguments" || self.captured.contains(name) {
+                    return false;
+                }
+                match *declaration {
+                    LocalDeclaration::Parameter => !self.references_arguments,
+                    LocalDeclaration::Var => true,
+                    LocalDeclaration::Lexical {
+                        scope,
+                        initialized_at,
+                        is_const,
+                    } => self.references.get(name).is_none_or(|references| {
+                        references.iter().all(|&(start, is_write)| {
+                            start >= initialized_at && start < scope.end && !(is_const && is_write)
+                        })
+                    }),
+                    LocalDeclaration::Other => false,
+                }
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+}
+
+impl<'a> Visit<'a> for RegisterBindingAnalysis<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.reference(it.name, it.span.start, false);
+    }
+
+    fn visit_simple_assignment_target(&mut self, it: &SimpleAssignmentTarget<'a>) {
+        if let SimpleAssignmentTarget::AssignmentTargetIdentifier(identifier) = it {
+            self.reference(identifier.name, identifier.span.start, true);
+        } else {
+            walk::walk_simple_assignment_target(self, it);
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.reference(it.binding.name, it.binding.span.start, true);
+        if let Some(init) = &it.init {
+            self.visit_expression(init);
+        }
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if it.callee.is_specific_id("eval") {
+            self.has_direct_eval_or_with = true;
+        }
+        walk::walk_call_expression(self, it);
+    }
+
+    fn visit_with_statement(&mut self, it: &WithStatement<'a>) {
+        self.has_direct_eval_or_with = true;
+        walk::walk_with_statement(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        if it.is_declaration() {
+            if let Some(id) = &it.id {
+                self.declare(id.name, LocalDeclaration::Other);
+            }
+        }
+        self.function_depth += 1;
+        walk::walk_function(self, it, flags);
+        self.function_depth -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.function_depth += 1;
+        walk::walk_arrow_function_expression(self, it);
+        self.function_depth -= 1;
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        // Note: Class expression names are bound in the class scope but
+        // compiled as part of the analysed function.
+        if let Some(id) = &it.id {
+            self.declare(id.name, LocalDeclaration::Other);
+        }
+        self.function_depth += 1;
+        walk::walk_class(self, it);
+        self.function_depth -= 1;
+    }
+
+    fn visit_static_block(&mut self, it: &StaticBlock<'a>) {
+        self.static_block_depth += 1;
+        walk::walk_static_block(self, it);
+        self.static_block_depth -= 1;
+    }
+
+    fn visit_variable_declaration(&mut self, it: &VariableDeclaration<'a>) {
+        self.declare_variables(it, None);
+        walk::walk_variable_declaration(self, it);
+    }
+
+    fn visit_catch_clause(&mut self, it: &CatchClause<'a>) {
+        if let Some(param) = &it.param {
+            param.pattern.bound_names(&mut |identifier| {
+                self.declare(identifier.name, LocalDeclaration::Other)
+            });
+        }
+        walk::walk_catch_clause(self, it);
+    }
+
+    fn visit_block_statement(&mut self, it: &BlockStatement<'a>) {
+        self.scopes.push((it.span, false));
+        walk::walk_block_statement(self, it);
+        self.scopes.pop();
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.scopes.push((it.span, false));
+        walk::walk_for_statement(self, it);
+        self.scopes.pop();
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.scopes.push((it.span, false));
+        self.visit_for_in_of_left(&it.left, it.right.span().end);
+        self.visit_expression(&it.right);
+        self.visit_statement(&it.body);
+        self.scopes.pop();
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.scopes.push((it.span, false));
+        self.visit_for_in_of_left(&it.left, it.right.span().end);
+        self.visit_expression(&it.right);
+        self.visit_statement(&it.body);
+        self.scopes.pop();
+    }
+
+    fn visit_switch_statement(&mut self, it: &SwitchStatement<'a>) {
+        self.visit_expression(&it.discriminant);
+        self.scopes.push((it.span, true));
+        self.visit_switch_cases(&it.cases);
+        self.scopes.pop();
+    }
+}
+
+impl<'a> RegisterBindingAnalysis<'a> {
+    /// The bindings of a for-in or for-of declaration are initialized only
+    /// after the iterated expression is evaluated.
+    fn visit_for_in_of_left(&mut self, it: &ForStatementLeft<'a>, initialized_at: u32) {
+        if let ForStatementLeft::VariableDeclaration(declaration) = it {
+            self.declare_variables(declaration, Some(initialized_at));
+            walk::walk_variable_declaration(self, declaration);
+        } else {
+            self.visit_for_statement_left(it);
+        }
+    }
+}
+
+/// Compile-time model of the Environment Records entered by the code being
+/// compiled. This is used to resolve identifier references to the (hops,
+/// slot) coordinates of their bindings: The binding is found in the
+/// Declarative Environment Record `hops` steps out from the running
+/// execution context's LexicalEnvironment, at index `slot` in the order of
+/// creation of the record's bindings.
+///
+/// References can only be resolved through Environment Records whose
+/// bindings are all created by the code being compiled. The body of a
+/// `with` statement is modelled as an opaque scope, so that references
+/// inside it are only resolved to bindings declared within the body. The
+/// scopes are disabled entirely for code containing a direct `eval` call, as
+/// it can introduce bindings that the compiler cannot see.
+#[derive(Debug, Default)]
+pub(crate) struct StaticScopes {
+    /// Names of the bindings of each entered Environment Record in order of
+    /// creation, innermost last. None for Environment Records whose bindings
+    /// are not known statically.
+    scopes: Vec<Option<Vec<Box<str>>>>,
+    disabled: bool,
+}
+
+impl StaticScopes {
+    /// Disable resolution if the code contains a direct `eval` call.
+    pub(crate) fn analyse_statements(&mut self, statements: &[Statement<'_>]) {
+        let mut finder = DirectEvalFinder::default();
+        for statement in statements {
+            finder.visit_statement(statement);
+        }
+        self.disabled |= finder.found;
+    }
+
+    /// Disable resolution if the function contains a direct `eval` call.
+    pub(crate) fn analyse_function(
+        &mut self,
+        params: &FormalParameters<'_>,
+        body: &FunctionBody<'_>,
+    ) {
+        let mut finder = DirectEvalFinder::default();
+        finder.visit_formal_parameters(params);
+        finder.visit_function_body(body);
+        self.disabled |= finder.found;
+    }
+
+    /// Model entering a new Environment Record with no bindings.
+    pub(crate) fn enter(&mut self) {
+        self.scopes.push(Some(vec![]));
+    }
+
+    /// Model entering an Environment Record whose bindings are not known
+    /// statically.
+    pub(crate) fn enter_opaque(&mut self) {
+        self.scopes.push(None);
+    }
+
+    /// Model leaving the innermost Environment Record.
+    pub(crate) fn exit(&mut self) {
+        self.scopes.pop().unwrap();
+    }
+
+    /// Model creating a binding in the innermost Environment Record.
+    pub(crate) fn declare(&mut self, name: &str) {
+        if let Some(Some(bindings)) = self.scopes.last_mut() {
+            bindings.push(name.into());
+        }
+    }
+
+    /// Resolve a name to the (hops, slot) coordinates of its binding, if the
+    /// binding is known statically.
+    pub(crate) fn resolve(&self, name: &str) -> Option<(u32, u32)> {
+        if self.disabled {
+            return None;
+        }
+        for (hops, scope) in self.scopes.iter().rev().enumerate() {
+            // Note: Any Environment Record that is not known statically may
+            // contain a binding for the name.
+            let bindings = scope.as_ref()?;
+            if let Some(slot) = bindings.iter().position(|binding| &**binding == name) {
+                return Some((hops as u32, slot as u32));
+            }
+        }
+        // Note: The Environment Records outside the code being compiled are
+        // not known statically.
+        None
+    }
+}
+
+#[derive(Default)]
+struct DirectEvalFinder {
+    found: bool,
+}
+
+impl<'a> Visit<'a> for DirectEvalFinder {
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if it.callee.is_specific_id("eval") {
+            self.found = true;
+        }
+        walk::walk_call_expression(self, it);
+    }
+}
@@ -7,6 +7,7 @@ mod function;
 mod into_numeric;
 mod into_primitive;
 mod into_value;
+mod language_value;
 mod number;
 mod numeric;
 mod object;
@@ -14,7 +15,6 @@ mod primitive;
 mod string;
 mod symbol;
 mod value;
-mod language_value;
 
 pub use bigint::{BigInt, BigIntHeapData};
 pub(crate) use function::{
@@ -35,6 +35,7 @@ pub use object::{
     bind_property_keys, scope_property_keys, unbind_property_keys, InternalMethods, InternalSlots,
     IntoObject, Object, ObjectHeapData, OrdinaryObject, PropertyKey,
 };
+pub(crate) use object::{sweep_shape_transitions, ObjectShape, ObjectShapeHeapData};
 pub(crate) use primitive::HeapPrimitive;
 pub use primitive::Primitive;
 pub use string::{HeapString, String, StringHeapData, BUILTIN_STRINGS_LIST, BUILTIN_STRING_MEMORY};
@@ -42,6 +43,8 @@ pub use symbol::{Symbol, SymbolHeapData};
 pub use value::Value;
 #[cfg(feature = "date")]
 pub(crate) use value::DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) use value::DISPOSABLE_STACK_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 pub(crate) use value::FLOAT_16_ARRAY_DISCRIMINANT;
 #[cfg(feature = "regexp")]
@@ -57,10 +60,10 @@ pub(crate) use value::{
     BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
     ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT,
     FINALIZATION_REGISTRY_DISCRIMINANT, FLOAT_DISCRIMINANT, GENERATOR_DISCRIMINANT,
-    INTEGER_DISCRIMINANT, ITERATOR_DISCRIMINANT, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT,
-    MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT,
-    PROXY_DISCRIMINANT, SMALL_BIGINT_DISCRIMINANT, SMALL_STRING_DISCRIMINANT, STRING_DISCRIMINANT,
-    SYMBOL_DISCRIMINANT,
+    INTEGER_DISCRIMINANT, ITERATOR_DISCRIMINANT, MAPPED_ARGUMENTS_DISCRIMINANT, MAP_DISCRIMINANT,
+    MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT,
+    PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, SMALL_BIGINT_DISCRIMINANT, SMALL_STRING_DISCRIMINANT,
+    STRING_DISCRIMINANT, SYMBOL_DISCRIMINANT,
 };
 #[cfg(feature = "array-buffer")]
 pub(crate) use value::{
@@ -14,6 +14,7 @@ use crate::{
     engine::{
         context::NoGcScope,
         rootable::{HeapRootData, HeapRootRef, Rootable},
+        Scoped,
     },
     heap::{
         indexes::BigIntIndex, CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep,
@@ -291,6 +292,14 @@ impl<'a> BigInt<'a> {
         unsafe { core::mem::transmute::<Self, BigInt<'gc>>(self) }
     }
 
+    pub fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> Scoped<'scope, BigInt<'static>> {
+        Scoped::new(agent, self.unbind(), gc)
+    }
+
     pub const fn zero() -> Self {
         Self::SmallBigInt(SmallBigInt::zero())
     }
@@ -645,6 +654,43 @@ impl<'a> BigInt<'a> {
         )
     }
 
+    // ### [6.1.6.2.21 BigInt::toString ( x, radix )](https://tc39.es/ecma262/#sec-numeric-types-bigint-tostring)
+    //
+    // Radix 10 should use to_string_radix_10 instead.
+    pub(crate) fn to_string_radix_n<'gc>(
+        agent: &mut Agent,
+        x: Self,
+        radix: u32,
+        gc: NoGcScope<'gc, '_>,
+    ) -> String<'gc> {
+        debug_assert!((2..=36).contains(&radix));
+        // 1. If x < 0ℤ, return the string-concatenation of "-" and
+        //    BigInt::toString(-x, radix).
+        // 2. Return the String value consisting of the representation of x
+        //    using radix radix.
+        let string = match x {
+            BigInt::SmallBigInt(x) => {
+                let x = x.into_i64();
+                let mut magnitude = x.unsigned_abs();
+                let mut digits = Vec::with_capacity(64);
+                loop {
+                    let digit = (magnitude % radix as u64) as u32;
+                    digits.push(char::from_digit(digit, radix).unwrap());
+                    magnitude /= radix as u64;
+                    if magnitude == 0 {
+                        break;
+                    }
+                }
+                if x < 0 {
+                    digits.push('-');
+                }
+                digits.iter().rev().collect()
+            }
+            BigInt::BigInt(x) => agent[x].data.to_str_radix(radix),
+        };
+        String::from_string(agent, string, gc)
+    }
+
     pub(crate) fn to_real(self, agent: &mut Agent) -> f64 {
         match self {
             BigInt::BigInt(heap_big_int) => {
@@ -793,6 +839,7 @@ impl IndexMut<HeapBigInt<'_>> for Vec<Option<BigIntHeapData>> {
 impl CreateHeapData<BigIntHeapData, BigInt<'static>> for Heap {
     fn create(&mut self, data: BigIntHeapData) -> BigInt<'static> {
         self.bigints.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<BigIntHeapData>>();
         BigInt::BigInt(HeapBigInt(BigIntIndex::last(&self.bigints)))
     }
 }
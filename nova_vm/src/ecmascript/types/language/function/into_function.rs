@@ -65,14 +65,13 @@ pub(crate) fn function_create_backing_object<'a>(
             configurable: true,
         },
     };
-    let (keys, values) = agent
+    let (shape, values) = agent
         .heap
-        .elements
         .create_object_entries(&[length_entry, name_entry]);
     let backing_object = agent.heap.create(ObjectHeapData {
         extensible: true,
         prototype,
-        keys,
+        shape,
         values,
     });
     func.set_backing_object(agent, backing_object);
@@ -15,7 +15,7 @@ pub enum SpecificationValue {
     Float,
     BigInt,
     SmallBigInt,
-    
+
     // Ordinary object
     Object,
 
@@ -126,18 +126,21 @@ pub enum SpecificationValue {
     ThrownBigUint64Array = SpecificationValue::BigUint64Array as u8 + 0x80,
     ThrownFloat32Array = SpecificationValue::Float32Array as u8 + 0x80,
     ThrownFloat64Array = SpecificationValue::Float64Array as u8 + 0x80,
-    ThrownAsyncGeneratorFunction = SpecificationValue::ECMAScriptAsyncGeneratorFunction as u8 + 0x80,
+    ThrownAsyncGeneratorFunction =
+        SpecificationValue::ECMAScriptAsyncGeneratorFunction as u8 + 0x80,
     ThrownBoundFunction = SpecificationValue::BoundFunction as u8 + 0x80,
     ThrownBuiltinFunction = SpecificationValue::BuiltinFunction as u8 + 0x80,
     ThrownECMASCriptAsyncFunction = SpecificationValue::ECMAScriptAsyncFunction as u8 + 0x80,
-    ThrownECMAScriptConstructorFunction = SpecificationValue::ECMAScriptConstructorFunction as u8 + 0x80,
+    ThrownECMAScriptConstructorFunction =
+        SpecificationValue::ECMAScriptConstructorFunction as u8 + 0x80,
     ThrownECMAScriptFunction = SpecificationValue::ECMAScriptFunction as u8 + 0x80,
-    ThrownECMAScriptGeneratorFunction = SpecificationValue::ECMAScriptGeneratorFunction as u8 + 0x80,
-    ThrownECMAScriptPromiseResolvingFunction = SpecificationValue::PromiseResolvingFunction as u8 + 0x80,
+    ThrownECMAScriptGeneratorFunction =
+        SpecificationValue::ECMAScriptGeneratorFunction as u8 + 0x80,
+    ThrownECMAScriptPromiseResolvingFunction =
+        SpecificationValue::PromiseResolvingFunction as u8 + 0x80,
     ThrownPromisesResolvingFunction = SpecificationValue::PromisesResolvingFunction as u8 + 0x80,
     ThrownAsyncFromSyncIterator = SpecificationValue::AsyncFromSyncIterator as u8 + 0x80,
     ThrownAsyncIterator = SpecificationValue::AsyncIterator as u8 + 0x80,
     ThrownIterator = SpecificationValue::Iterator as u8 + 0x80,
     ThrownModule = SpecificationValue::Module as u8 + 0x80,
-
 }
@@ -9,6 +9,7 @@ use core::ops::{Index, IndexMut};
 use super::{
     value::{FLOAT_DISCRIMINANT, INTEGER_DISCRIMINANT, NUMBER_DISCRIMINANT},
     IntoNumeric, IntoPrimitive, IntoValue, Numeric, Primitive, String, Value,
+    BUILTIN_STRING_MEMORY,
 };
 use crate::{
     ecmascript::abstract_operations::type_conversion::{to_int32_number, to_uint32_number},
@@ -1375,6 +1376,40 @@ impl<'a> Number<'a> {
         }
     }
 
+    // ### [6.1.6.1.20 Number::toString ( x, radix )](https://tc39.es/ecma262/#sec-numeric-types-number-tostring)
+    //
+    // Radix 10 should use to_string_radix_10 instead.
+    pub(crate) fn to_string_radix_n<'gc>(
+        agent: &mut Agent,
+        x: Self,
+        radix: u32,
+        gc: NoGcScope<'gc, '_>,
+    ) -> String<'gc> {
+        debug_assert!((2..=36).contains(&radix) && radix != 10);
+        let value = x.into_f64(agent);
+        // 1. If x is NaN, return "NaN".
+        if value.is_nan() {
+            return BUILTIN_STRING_MEMORY.NaN;
+        }
+        // 2. If x is either +0𝔽 or -0𝔽, return "0".
+        if value == 0.0 {
+            return String::from_small_string("0");
+        }
+        // 4. If x is +∞𝔽, return "Infinity".
+        if value == f64::INFINITY {
+            return BUILTIN_STRING_MEMORY.Infinity;
+        }
+        // 3. If x < -0𝔽, return the string-concatenation of "-" and
+        //    Number::toString(-x, radix).
+        if value == f64::NEG_INFINITY {
+            return String::from_static_str(agent, "-Infinity", gc);
+        }
+        // 5.-12. The digits are chosen such that the result round-trips back
+        //        to x: fractional digits are only produced up to the
+        //        precision of x.
+        String::from_string(agent, f64_to_radix_string(value, radix), gc)
+    }
+
     /// ### [ℝ](https://tc39.es/ecma262/#%E2%84%9D)
     pub(crate) fn to_real(self, agent: &impl Index<HeapNumber<'static>, Output = f64>) -> f64 {
         match self {
@@ -1529,3 +1564,82 @@ impl Rootable for Number<'static> {
         }
     }
 }
+
+/// Formats a finite, non-zero f64 in the given radix using the shortest
+/// digit sequence that still uniquely identifies the value.
+fn f64_to_radix_string(value: f64, radix: u32) -> std::string::String {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    let radix_f64 = radix as f64;
+    let negative = value < 0.0;
+    let value = value.abs();
+
+    let mut integer = value.floor();
+    let mut fraction = value - integer;
+    // We only compute fractional digits up to the precision of the input:
+    // delta is half the distance to the next representable f64.
+    let mut delta = (0.5 * (f64::from_bits(value.to_bits() + 1) - value)).max(f64::from_bits(1));
+    let mut fraction_digits = Vec::new();
+    if fraction >= delta {
+        loop {
+            // Shift up by one digit.
+            fraction *= radix_f64;
+            delta *= radix_f64;
+            let digit = fraction as u32;
+            fraction_digits.push(digit);
+            fraction -= digit as f64;
+            // Round to even.
+            if (fraction > 0.5 || (fraction == 0.5 && digit & 1 == 1)) && fraction + delta > 1.0 {
+                // Propagate the carry through the already written digits.
+                loop {
+                    match fraction_digits.pop() {
+                        Some(digit) if digit + 1 < radix => {
+                            fraction_digits.push(digit + 1);
+                            break;
+                        }
+                        Some(_) => continue,
+                        None => {
+                            // Carry over to the integer part.
+                            integer += 1.0;
+                            break;
+                        }
+                    }
+                }
+                break;
+            }
+            if fraction < delta {
+                break;
+            }
+        }
+    }
+
+    let mut integer_digits = Vec::new();
+    // Digits below the precision of the integer part are filled with zeroes.
+    while integer / radix_f64 >= 9007199254740992.0 {
+        integer /= radix_f64;
+        integer_digits.push(b'0');
+    }
+    loop {
+        let remainder = integer % radix_f64;
+        integer_digits.push(DIGITS[remainder as usize]);
+        integer = (integer - remainder) / radix_f64;
+        if integer <= 0.0 {
+            break;
+        }
+    }
+
+    let mut result =
+        std::string::String::with_capacity(integer_digits.len() + fraction_digits.len() + 2);
+    if negative {
+        result.push('-');
+    }
+    result.extend(integer_digits.iter().rev().map(|&digit| digit as char));
+    if !fraction_digits.is_empty() {
+        result.push('.');
+        result.extend(
+            fraction_digits
+                .iter()
+                .map(|&digit| DIGITS[digit as usize] as char),
+        );
+    }
+    result
+}
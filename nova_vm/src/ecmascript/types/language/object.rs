@@ -8,11 +8,14 @@ mod internal_slots;
 mod into_object;
 mod property_key;
 mod property_storage;
+mod shape;
 
 use core::hash::Hash;
 
 #[cfg(feature = "date")]
 use super::value::DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use super::value::DISPOSABLE_STACK_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 use super::value::FLOAT_16_ARRAY_DISCRIMINANT;
 #[cfg(feature = "regexp")]
@@ -39,13 +42,16 @@ use super::{
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
         ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT,
         FINALIZATION_REGISTRY_DISCRIMINANT, GENERATOR_DISCRIMINANT, ITERATOR_DISCRIMINANT,
-        MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, OBJECT_DISCRIMINANT,
-        PRIMITIVE_OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT,
+        MAPPED_ARGUMENTS_DISCRIMINANT, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT,
+        MODULE_DISCRIMINANT, OBJECT_DISCRIMINANT, PRIMITIVE_OBJECT_DISCRIMINANT,
+        PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT,
     },
     Function, IntoValue, Value,
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
@@ -68,6 +74,7 @@ use crate::{
 use crate::{
     ecmascript::{
         builtins::{
+            arguments::MappedArgumentsObject,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
             control_abstraction_objects::{
@@ -104,6 +111,7 @@ pub use property_key::{
     bind_property_keys, scope_property_keys, unbind_property_keys, PropertyKey,
 };
 pub use property_storage::PropertyStorage;
+pub(crate) use shape::{sweep_shape_transitions, ObjectShape, ObjectShapeHeapData};
 
 /// ### [6.1.7 The Object Type](https://tc39.es/ecma262/#sec-object-type)
 ///
@@ -124,6 +132,7 @@ pub enum Object<'a> {
     BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'a>) = PRIMITIVE_OBJECT_DISCRIMINANT,
     Arguments(OrdinaryObject<'a>) = ARGUMENTS_DISCRIMINANT,
+    MappedArguments(MappedArgumentsObject<'a>) = MAPPED_ARGUMENTS_DISCRIMINANT,
     Array(Array<'a>) = ARRAY_DISCRIMINANT,
     #[cfg(feature = "array-buffer")]
     ArrayBuffer(ArrayBuffer<'a>) = ARRAY_BUFFER_DISCRIMINANT,
@@ -131,6 +140,8 @@ pub enum Object<'a> {
     DataView(DataView<'a>) = DATA_VIEW_DISCRIMINANT,
     #[cfg(feature = "date")]
     Date(Date<'a>) = DATE_DISCRIMINANT,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack(DisposableStack<'a>) = DISPOSABLE_STACK_DISCRIMINANT,
     Error(Error<'a>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'a>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'a>) = MAP_DISCRIMINANT,
@@ -205,6 +216,7 @@ impl IntoValue for Object<'_> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => Value::PrimitiveObject(data.unbind()),
             Object::Arguments(data) => Value::Arguments(data.unbind()),
+            Object::MappedArguments(data) => Value::MappedArguments(data.unbind()),
             Object::Array(data) => Value::Array(data.unbind()),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => Value::ArrayBuffer(data.unbind()),
@@ -212,6 +224,8 @@ impl IntoValue for Object<'_> {
             Object::DataView(data) => Value::DataView(data.unbind()),
             #[cfg(feature = "date")]
             Object::Date(data) => Value::Date(data.unbind()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => Value::DisposableStack(data.unbind()),
             Object::Error(data) => Value::Error(data.unbind()),
             Object::FinalizationRegistry(data) => Value::FinalizationRegistry(data.unbind()),
             Object::Map(data) => Value::Map(data.unbind()),
@@ -439,6 +453,7 @@ impl From<Object<'_>> for Value {
             Object::BuiltinProxyRevokerFunction => Value::BuiltinProxyRevokerFunction,
             Object::PrimitiveObject(data) => Value::PrimitiveObject(data.unbind()),
             Object::Arguments(data) => Value::Arguments(data.unbind()),
+            Object::MappedArguments(data) => Value::MappedArguments(data.unbind()),
             Object::Array(data) => Value::Array(data.unbind()),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => Value::ArrayBuffer(data.unbind()),
@@ -446,6 +461,8 @@ impl From<Object<'_>> for Value {
             Object::DataView(data) => Value::DataView(data.unbind()),
             #[cfg(feature = "date")]
             Object::Date(data) => Value::Date(data.unbind()),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => Value::DisposableStack(data.unbind()),
             Object::Error(data) => Value::Error(data.unbind()),
             Object::FinalizationRegistry(data) => Value::FinalizationRegistry(data.unbind()),
             Object::Map(data) => Value::Map(data.unbind()),
@@ -520,6 +537,8 @@ impl TryFrom<Value> for Object<'_> {
             Value::Array(x) => Ok(Object::from(x)),
             #[cfg(feature = "date")]
             Value::Date(x) => Ok(Object::Date(x)),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => Ok(Object::DisposableStack(data)),
             Value::Error(x) => Ok(Object::from(x)),
             Value::BoundFunction(x) => Ok(Object::from(x)),
             Value::BuiltinFunction(x) => Ok(Object::from(x)),
@@ -533,6 +552,7 @@ impl TryFrom<Value> for Object<'_> {
             Value::BuiltinProxyRevokerFunction => Ok(Object::BuiltinProxyRevokerFunction),
             Value::PrimitiveObject(data) => Ok(Object::PrimitiveObject(data)),
             Value::Arguments(data) => Ok(Object::Arguments(data)),
+            Value::MappedArguments(data) => Ok(Object::MappedArguments(data)),
             #[cfg(feature = "array-buffer")]
             Value::ArrayBuffer(idx) => Ok(Object::ArrayBuffer(idx)),
             #[cfg(feature = "array-buffer")]
@@ -644,6 +664,7 @@ impl Hash for Object<'_> {
             Object::BuiltinProxyRevokerFunction => {}
             Object::PrimitiveObject(data) => data.get_index().hash(state),
             Object::Arguments(data) => data.get_index().hash(state),
+            Object::MappedArguments(data) => data.get_index().hash(state),
             Object::Array(data) => data.get_index().hash(state),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => data.get_index().hash(state),
@@ -651,6 +672,8 @@ impl Hash for Object<'_> {
             Object::DataView(data) => data.get_index().hash(state),
             #[cfg(feature = "date")]
             Object::Date(data) => data.get_index().hash(state),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.get_index().hash(state),
             Object::Error(data) => data.get_index().hash(state),
             Object::FinalizationRegistry(data) => data.get_index().hash(state),
             Object::Map(data) => data.get_index().hash(state),
@@ -727,6 +750,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_extensible(agent),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_extensible(agent),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_extensible(agent),
             Object::Error(data) => data.internal_extensible(agent),
             Object::BoundFunction(data) => data.internal_extensible(agent),
             Object::BuiltinFunction(data) => data.internal_extensible(agent),
@@ -738,6 +763,7 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_extensible(agent),
             Object::Arguments(data) => data.internal_extensible(agent),
+            Object::MappedArguments(data) => data.internal_extensible(agent),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_extensible(agent),
             Object::FinalizationRegistry(data) => data.internal_extensible(agent),
@@ -807,6 +833,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_extensible(agent, value),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_extensible(agent, value),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_set_extensible(agent, value),
             Object::Error(data) => data.internal_set_extensible(agent, value),
             Object::BoundFunction(data) => data.internal_set_extensible(agent, value),
             Object::BuiltinFunction(idx) => idx.internal_set_extensible(agent, value),
@@ -820,6 +848,7 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_set_extensible(agent, value),
             Object::Arguments(data) => data.internal_set_extensible(agent, value),
+            Object::MappedArguments(data) => data.internal_set_extensible(agent, value),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set_extensible(agent, value),
             Object::FinalizationRegistry(data) => data.internal_set_extensible(agent, value),
@@ -907,6 +936,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_prototype(agent),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_prototype(agent),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_prototype(agent),
             Object::Error(data) => data.internal_prototype(agent),
             Object::BoundFunction(data) => data.internal_prototype(agent),
             Object::BuiltinFunction(data) => data.internal_prototype(agent),
@@ -918,6 +949,7 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_prototype(agent),
             Object::Arguments(data) => data.internal_prototype(agent),
+            Object::MappedArguments(data) => data.internal_prototype(agent),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_prototype(agent),
             Object::FinalizationRegistry(data) => data.internal_prototype(agent),
@@ -987,6 +1019,8 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_prototype(agent, prototype),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_prototype(agent, prototype),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_set_prototype(agent, prototype),
             Object::Error(data) => data.internal_set_prototype(agent, prototype),
             Object::BoundFunction(data) => data.internal_set_prototype(agent, prototype),
             Object::BuiltinFunction(data) => data.internal_set_prototype(agent, prototype),
@@ -1002,6 +1036,7 @@ impl<'a> InternalSlots<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_set_prototype(agent, prototype),
             Object::Arguments(data) => data.internal_set_prototype(agent, prototype),
+            Object::MappedArguments(data) => data.internal_set_prototype(agent, prototype),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set_prototype(agent, prototype),
             Object::FinalizationRegistry(data) => data.internal_set_prototype(agent, prototype),
@@ -1095,6 +1130,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get_prototype_of(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_get_prototype_of(agent, gc),
             Object::Error(data) => data.try_get_prototype_of(agent, gc),
             Object::BoundFunction(data) => data.try_get_prototype_of(agent, gc),
             Object::BuiltinFunction(data) => data.try_get_prototype_of(agent, gc),
@@ -1106,6 +1143,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_get_prototype_of(agent, gc),
             Object::Arguments(data) => data.try_get_prototype_of(agent, gc),
+            Object::MappedArguments(data) => data.try_get_prototype_of(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_get_prototype_of(agent, gc),
             Object::FinalizationRegistry(data) => data.try_get_prototype_of(agent, gc),
@@ -1195,6 +1233,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get_prototype_of(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get_prototype_of(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_get_prototype_of(agent, gc),
             Object::Error(data) => data.internal_get_prototype_of(agent, gc),
             Object::BoundFunction(data) => data.internal_get_prototype_of(agent, gc),
             Object::BuiltinFunction(data) => data.internal_get_prototype_of(agent, gc),
@@ -1208,6 +1248,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_get_prototype_of(agent, gc),
             Object::Arguments(data) => data.internal_get_prototype_of(agent, gc),
+            Object::MappedArguments(data) => data.internal_get_prototype_of(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_get_prototype_of(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_get_prototype_of(agent, gc),
@@ -1300,6 +1341,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Error(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::BoundFunction(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::BuiltinFunction(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1315,6 +1358,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::Arguments(data) => data.try_set_prototype_of(agent, prototype, gc),
+            Object::MappedArguments(data) => data.try_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_set_prototype_of(agent, prototype, gc),
             Object::FinalizationRegistry(data) => data.try_set_prototype_of(agent, prototype, gc),
@@ -1407,6 +1451,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Error(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::BoundFunction(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::BuiltinFunction(data) => data.internal_set_prototype_of(agent, prototype, gc),
@@ -1424,6 +1470,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::Arguments(data) => data.internal_set_prototype_of(agent, prototype, gc),
+            Object::MappedArguments(data) => data.internal_set_prototype_of(agent, prototype, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set_prototype_of(agent, prototype, gc),
             Object::FinalizationRegistry(data) => {
@@ -1513,6 +1560,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_is_extensible(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_is_extensible(agent, gc),
             Object::Error(data) => data.try_is_extensible(agent, gc),
             Object::BoundFunction(data) => data.try_is_extensible(agent, gc),
             Object::BuiltinFunction(data) => data.try_is_extensible(agent, gc),
@@ -1524,6 +1573,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_is_extensible(agent, gc),
             Object::Arguments(data) => data.try_is_extensible(agent, gc),
+            Object::MappedArguments(data) => data.try_is_extensible(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_is_extensible(agent, gc),
             Object::FinalizationRegistry(data) => data.try_is_extensible(agent, gc),
@@ -1599,6 +1649,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_is_extensible(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_is_extensible(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_is_extensible(agent, gc),
             Object::Error(data) => data.internal_is_extensible(agent, gc),
             Object::BoundFunction(data) => data.internal_is_extensible(agent, gc),
             Object::BuiltinFunction(data) => data.internal_is_extensible(agent, gc),
@@ -1610,6 +1662,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_is_extensible(agent, gc),
             Object::Arguments(data) => data.internal_is_extensible(agent, gc),
+            Object::MappedArguments(data) => data.internal_is_extensible(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_is_extensible(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_is_extensible(agent, gc),
@@ -1697,6 +1750,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_prevent_extensions(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_prevent_extensions(agent, gc),
             Object::Error(data) => data.try_prevent_extensions(agent, gc),
             Object::BoundFunction(data) => data.try_prevent_extensions(agent, gc),
             Object::BuiltinFunction(data) => data.try_prevent_extensions(agent, gc),
@@ -1708,6 +1763,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_prevent_extensions(agent, gc),
             Object::Arguments(data) => data.try_prevent_extensions(agent, gc),
+            Object::MappedArguments(data) => data.try_prevent_extensions(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_prevent_extensions(agent, gc),
             Object::FinalizationRegistry(data) => data.try_prevent_extensions(agent, gc),
@@ -1795,6 +1851,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_prevent_extensions(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_prevent_extensions(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_prevent_extensions(agent, gc),
             Object::Error(data) => data.internal_prevent_extensions(agent, gc),
             Object::BoundFunction(data) => data.internal_prevent_extensions(agent, gc),
             Object::BuiltinFunction(data) => data.internal_prevent_extensions(agent, gc),
@@ -1808,6 +1866,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_prevent_extensions(agent, gc),
             Object::Arguments(data) => data.internal_prevent_extensions(agent, gc),
+            Object::MappedArguments(data) => data.internal_prevent_extensions(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_prevent_extensions(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_prevent_extensions(agent, gc),
@@ -1900,6 +1959,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get_own_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Error(data) => data.try_get_own_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_get_own_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_get_own_property(agent, property_key, gc),
@@ -1915,6 +1976,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_get_own_property(agent, property_key, gc),
             Object::Arguments(data) => data.try_get_own_property(agent, property_key, gc),
+            Object::MappedArguments(data) => data.try_get_own_property(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_get_own_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => {
@@ -2009,6 +2071,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get_own_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get_own_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             Object::Error(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => {
@@ -2030,6 +2096,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_get_own_property(agent, property_key, gc)
             }
             Object::Arguments(data) => data.internal_get_own_property(agent, property_key, gc),
+            Object::MappedArguments(data) => {
+                data.internal_get_own_property(agent, property_key, gc)
+            }
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_get_own_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => {
@@ -2134,6 +2203,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Date(idx) => {
                 idx.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Error(idx) => {
                 idx.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2161,6 +2234,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Arguments(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            Object::MappedArguments(data) => {
+                data.try_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => {
                 data.try_define_own_property(agent, property_key, property_descriptor, gc)
@@ -2326,6 +2402,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Date(idx) => {
                 idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             Object::Error(idx) => {
                 idx.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
@@ -2353,6 +2433,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::Arguments(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
             }
+            Object::MappedArguments(data) => {
+                data.internal_define_own_property(agent, property_key, property_descriptor, gc)
+            }
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => {
                 data.internal_define_own_property(agent, property_key, property_descriptor, gc)
@@ -2485,6 +2568,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_has_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_has_property(agent, property_key, gc),
             Object::Error(data) => data.try_has_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_has_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_has_property(agent, property_key, gc),
@@ -2500,6 +2585,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_has_property(agent, property_key, gc),
             Object::Arguments(data) => data.try_has_property(agent, property_key, gc),
+            Object::MappedArguments(data) => data.try_has_property(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_has_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => data.try_has_property(agent, property_key, gc),
@@ -2592,6 +2678,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_has_property(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_has_property(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_has_property(agent, property_key, gc),
             Object::Error(data) => data.internal_has_property(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_has_property(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.internal_has_property(agent, property_key, gc),
@@ -2607,6 +2695,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_has_property(agent, property_key, gc),
             Object::Arguments(data) => data.internal_has_property(agent, property_key, gc),
+            Object::MappedArguments(data) => data.internal_has_property(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_has_property(agent, property_key, gc),
             Object::FinalizationRegistry(data) => {
@@ -2702,6 +2791,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_get(agent, property_key, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Error(data) => data.try_get(agent, property_key, receiver, gc),
             Object::BoundFunction(data) => data.try_get(agent, property_key, receiver, gc),
             Object::BuiltinFunction(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2717,6 +2808,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_get(agent, property_key, receiver, gc),
             Object::Arguments(data) => data.try_get(agent, property_key, receiver, gc),
+            Object::MappedArguments(data) => data.try_get(agent, property_key, receiver, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_get(agent, property_key, receiver, gc),
             Object::FinalizationRegistry(data) => data.try_get(agent, property_key, receiver, gc),
@@ -2810,6 +2902,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_get(agent, property_key, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_get(agent, property_key, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Error(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::BoundFunction(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::BuiltinFunction(data) => data.internal_get(agent, property_key, receiver, gc),
@@ -2827,6 +2921,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::Arguments(data) => data.internal_get(agent, property_key, receiver, gc),
+            Object::MappedArguments(data) => data.internal_get(agent, property_key, receiver, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_get(agent, property_key, receiver, gc),
             Object::FinalizationRegistry(data) => {
@@ -2923,6 +3018,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_set(agent, property_key, value, receiver, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Error(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::BoundFunction(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::BuiltinFunction(data) => data.try_set(agent, property_key, value, receiver, gc),
@@ -2940,6 +3037,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::Arguments(data) => data.try_set(agent, property_key, value, receiver, gc),
+            Object::MappedArguments(data) => data.try_set(agent, property_key, value, receiver, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_set(agent, property_key, value, receiver, gc),
             Object::FinalizationRegistry(data) => {
@@ -3044,6 +3142,10 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             }
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             Object::Error(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::BoundFunction(data) => {
                 data.internal_set(agent, property_key, value, receiver, gc)
@@ -3067,6 +3169,9 @@ impl<'a> InternalMethods<'a> for Object<'a> {
                 data.internal_set(agent, property_key, value, receiver, gc)
             }
             Object::Arguments(data) => data.internal_set(agent, property_key, value, receiver, gc),
+            Object::MappedArguments(data) => {
+                data.internal_set(agent, property_key, value, receiver, gc)
+            }
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_set(agent, property_key, value, receiver, gc),
             Object::FinalizationRegistry(data) => {
@@ -3197,6 +3302,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_delete(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_delete(agent, property_key, gc),
             Object::Error(data) => data.try_delete(agent, property_key, gc),
             Object::BoundFunction(data) => data.try_delete(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.try_delete(agent, property_key, gc),
@@ -3210,6 +3317,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_delete(agent, property_key, gc),
             Object::Arguments(data) => data.try_delete(agent, property_key, gc),
+            Object::MappedArguments(data) => data.try_delete(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_delete(agent, property_key, gc),
             Object::FinalizationRegistry(data) => data.try_delete(agent, property_key, gc),
@@ -3302,6 +3410,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_delete(agent, property_key, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_delete(agent, property_key, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_delete(agent, property_key, gc),
             Object::Error(data) => data.internal_delete(agent, property_key, gc),
             Object::BoundFunction(data) => data.internal_delete(agent, property_key, gc),
             Object::BuiltinFunction(data) => data.internal_delete(agent, property_key, gc),
@@ -3317,6 +3427,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_delete(agent, property_key, gc),
             Object::Arguments(data) => data.internal_delete(agent, property_key, gc),
+            Object::MappedArguments(data) => data.internal_delete(agent, property_key, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_delete(agent, property_key, gc),
             Object::FinalizationRegistry(data) => data.internal_delete(agent, property_key, gc),
@@ -3408,6 +3519,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.try_own_property_keys(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.try_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.try_own_property_keys(agent, gc),
             Object::Error(data) => data.try_own_property_keys(agent, gc),
             Object::BoundFunction(data) => data.try_own_property_keys(agent, gc),
             Object::BuiltinFunction(data) => data.try_own_property_keys(agent, gc),
@@ -3419,6 +3532,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.try_own_property_keys(agent, gc),
             Object::Arguments(data) => data.try_own_property_keys(agent, gc),
+            Object::MappedArguments(data) => data.try_own_property_keys(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.try_own_property_keys(agent, gc),
             Object::FinalizationRegistry(data) => data.try_own_property_keys(agent, gc),
@@ -3508,6 +3622,8 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::ArrayBuffer(data) => data.internal_own_property_keys(agent, gc),
             #[cfg(feature = "date")]
             Object::Date(data) => data.internal_own_property_keys(agent, gc),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.internal_own_property_keys(agent, gc),
             Object::Error(data) => data.internal_own_property_keys(agent, gc),
             Object::BoundFunction(data) => data.internal_own_property_keys(agent, gc),
             Object::BuiltinFunction(data) => data.internal_own_property_keys(agent, gc),
@@ -3521,6 +3637,7 @@ impl<'a> InternalMethods<'a> for Object<'a> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.internal_own_property_keys(agent, gc),
             Object::Arguments(data) => data.internal_own_property_keys(agent, gc),
+            Object::MappedArguments(data) => data.internal_own_property_keys(agent, gc),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.internal_own_property_keys(agent, gc),
             Object::FinalizationRegistry(data) => data.internal_own_property_keys(agent, gc),
@@ -3653,6 +3770,8 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::ArrayBuffer(data) => data.mark_values(queues),
             #[cfg(feature = "date")]
             Object::Date(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.mark_values(queues),
             Object::Error(data) => data.mark_values(queues),
             Object::BoundFunction(data) => data.mark_values(queues),
             Object::BuiltinFunction(data) => data.mark_values(queues),
@@ -3664,6 +3783,7 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.mark_values(queues),
             Object::Arguments(data) => data.mark_values(queues),
+            Object::MappedArguments(data) => data.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             Object::DataView(data) => data.mark_values(queues),
             Object::FinalizationRegistry(data) => data.mark_values(queues),
@@ -3732,6 +3852,7 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::BuiltinProxyRevokerFunction => todo!(),
             Object::PrimitiveObject(data) => data.sweep_values(compactions),
             Object::Arguments(data) => data.sweep_values(compactions),
+            Object::MappedArguments(data) => data.sweep_values(compactions),
             Object::Array(data) => data.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             Object::ArrayBuffer(data) => data.sweep_values(compactions),
@@ -3739,6 +3860,8 @@ impl HeapMarkAndSweep for Object<'static> {
             Object::DataView(data) => data.sweep_values(compactions),
             #[cfg(feature = "date")]
             Object::Date(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(data) => data.sweep_values(compactions),
             Object::Error(data) => data.sweep_values(compactions),
             Object::FinalizationRegistry(data) => data.sweep_values(compactions),
             Object::Map(data) => data.sweep_values(compactions),
@@ -3797,6 +3920,7 @@ impl HeapMarkAndSweep for Object<'static> {
 impl CreateHeapData<ObjectHeapData, OrdinaryObject<'static>> for Heap {
     fn create(&mut self, data: ObjectHeapData) -> OrdinaryObject<'static> {
         self.objects.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<ObjectHeapData>>();
         OrdinaryObject(ObjectIndex::last(&self.objects))
     }
 }
@@ -3847,6 +3971,9 @@ impl TryFrom<HeapRootData> for Object<'_> {
                 Ok(Self::PrimitiveObject(primitive_object))
             }
             HeapRootData::Arguments(ordinary_object) => Ok(Self::Arguments(ordinary_object)),
+            HeapRootData::MappedArguments(mapped_arguments) => {
+                Ok(Self::MappedArguments(mapped_arguments))
+            }
             HeapRootData::Array(array) => Ok(Self::Array(array)),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => Ok(Self::ArrayBuffer(array_buffer)),
@@ -3854,6 +3981,10 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::DataView(data_view) => Ok(Self::DataView(data_view)),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => Ok(Self::Date(date)),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => {
+                Ok(Self::DisposableStack(disposable_stack))
+            }
             HeapRootData::Error(error) => Ok(Self::Error(error)),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 Ok(Self::FinalizationRegistry(finalization_registry))
@@ -3911,7 +4042,7 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::EmbedderObject(embedder_object) => {
                 Ok(Self::EmbedderObject(embedder_object))
             }
-            HeapRootData::PromiseReaction(_) => Err(()),
+            HeapRootData::PromiseReaction(_) | HeapRootData::ArrayFromAsyncReaction(_) => Err(()),
         }
     }
 }
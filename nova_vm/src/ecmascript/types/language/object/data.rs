@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::Object;
+use super::{Object, ObjectShape, PropertyKey};
 use crate::{
     ecmascript::{execution::Agent, types::Value},
     heap::{element_array::ElementsVector, CompactionLists, HeapMarkAndSweep, WorkQueues},
@@ -12,15 +12,17 @@ use crate::{
 pub struct ObjectHeapData {
     pub extensible: bool,
     pub prototype: Option<Object<'static>>,
-    pub keys: ElementsVector,
+    /// Shape of the object: The keys of the object's properties and their
+    /// offsets in the values vector.
+    pub(crate) shape: ObjectShape,
     pub values: ElementsVector,
 }
 
 impl ObjectHeapData {
-    pub fn new(
+    pub(crate) fn new(
         extensible: bool,
         prototype: Value,
-        keys: ElementsVector,
+        shape: ObjectShape,
         values: ElementsVector,
     ) -> Self {
         let prototype = if prototype.is_null() {
@@ -36,16 +38,17 @@ impl ObjectHeapData {
             // a [[PrimitiveValue]] field to objects: Normally this field is None
             // to signal that the object is its own primitive value. For
             // Number objects etc the field is Some(Value).
-            // TODO: Move prototype and key vector into shapes
+            // TODO: Move prototype into shapes
             prototype,
-            keys,
+            shape,
             values,
         }
     }
 
-    pub fn has(&self, agent: &Agent, key: Value) -> bool {
-        debug_assert!(key.is_string() || key.is_number() || key.is_symbol());
-        agent.heap.elements.has(self.keys, key)
+    pub fn has(&self, agent: &Agent, key: PropertyKey) -> bool {
+        self.shape
+            .get_offset(&agent.heap.object_shapes, key)
+            .is_some()
     }
 }
 
@@ -54,11 +57,11 @@ impl HeapMarkAndSweep for ObjectHeapData {
         let Self {
             extensible: _,
             prototype,
-            keys,
+            shape,
             values,
         } = self;
 
-        keys.mark_values(queues);
+        shape.mark_values(queues);
         values.mark_values(queues);
         prototype.mark_values(queues);
     }
@@ -67,10 +70,10 @@ impl HeapMarkAndSweep for ObjectHeapData {
         let Self {
             extensible: _,
             prototype,
-            keys,
+            shape,
             values,
         } = self;
-        keys.sweep_values(compactions);
+        shape.sweep_values(compactions);
         values.sweep_values(compactions);
         prototype.sweep_values(compactions);
     }
@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::{IntoObject, Object, ObjectHeapData, OrdinaryObject};
+use super::{IntoObject, Object, ObjectHeapData, ObjectShape, OrdinaryObject};
 use crate::{
     ecmascript::execution::{Agent, ProtoIntrinsics},
     heap::CreateHeapData,
@@ -43,7 +43,7 @@ where
         let backing_object = agent.heap.create(ObjectHeapData {
             extensible: true,
             prototype,
-            keys: Default::default(),
+            shape: ObjectShape::EMPTY,
             values: Default::default(),
         });
         self.set_backing_object(agent, backing_object);
@@ -7,7 +7,7 @@ use core::cell::Ref;
 use crate::{
     ecmascript::{
         execution::{Agent, Realm},
-        types::{PropertyDescriptor, Value, BUILTIN_STRING_MEMORY},
+        types::{PropertyDescriptor, BUILTIN_STRING_MEMORY},
     },
     heap::element_array::ElementDescriptor,
     Heap,
@@ -23,41 +23,31 @@ impl<'a> PropertyStorage<'a> {
         Self(object)
     }
 
-    fn into_object(self) -> Object<'a> {
-        self.0
-    }
-
-    fn into_value(self) -> Value {
-        self.into_object().into_value()
-    }
-
     pub fn has(self, agent: &Agent, key: PropertyKey) -> bool {
-        let object = self.into_value();
-
-        // SAFETY: Key is only used to compare with other keys.
-        let key = unsafe { key.into_value_unchecked() };
-        match object {
-            Value::Object(object) => agent.heap.elements.has(agent[object].keys, key),
-            Value::Array(array) => {
-                if key == BUILTIN_STRING_MEMORY.length.into_value() {
+        match self.0 {
+            Object::Object(object) => agent[object].has(agent, key),
+            Object::Array(array) => {
+                if key == BUILTIN_STRING_MEMORY.length.into() {
                     return true;
                 }
 
                 let array = &agent[array];
 
-                if key.is_integer() {
+                if key.is_array_index() {
+                    // SAFETY: Key is only used to compare with other keys.
+                    let key = unsafe { key.into_value_unchecked() };
                     return agent.heap.elements.has(array.elements.into(), key);
                 }
 
                 if let Some(object) = array.object_index {
-                    agent.heap.elements.has(agent[object].keys, key)
+                    agent[object].has(agent, key)
                 } else {
                     false
                 }
             }
-            Value::BoundFunction(_) => todo!(),
-            Value::BuiltinFunction(_) => todo!(),
-            Value::ECMAScriptFunction(_) => todo!(),
+            Object::BoundFunction(_) => todo!(),
+            Object::BuiltinFunction(_) => todo!(),
+            Object::ECMAScriptFunction(_) => todo!(),
             _ => unreachable!(),
         }
     }
@@ -65,22 +55,11 @@ impl<'a> PropertyStorage<'a> {
     pub fn get(self, agent: &Agent, key: PropertyKey) -> Option<PropertyDescriptor> {
         match self.0 {
             Object::Object(object) => {
-                let ObjectHeapData { keys, values, .. } = agent[object];
-                // SAFETY: Key is only used to compare with other keys.
-                let key = unsafe { key.into_value_unchecked() };
-                let result = agent
-                    .heap
-                    .elements
-                    .get(keys)
-                    .iter()
-                    .enumerate()
-                    .find(|(_, element_key)| element_key.unwrap() == key)
-                    .map(|res| res.0);
-                result.map(|index| {
-                    let value = *agent.heap.elements.get(values).get(index).unwrap();
-                    let descriptor = agent.heap.elements.get_descriptor(values, index);
-                    ElementDescriptor::to_property_descriptor(descriptor, value)
-                })
+                let ObjectHeapData { shape, values, .. } = agent[object];
+                let index = shape.get_offset(&agent.heap.object_shapes, key)? as usize;
+                let value = *agent.heap.elements.get(values).get(index).unwrap();
+                let descriptor = agent.heap.elements.get_descriptor(values, index);
+                Some(ElementDescriptor::to_property_descriptor(descriptor, value))
             }
             _ => todo!(),
         }
@@ -89,24 +68,14 @@ impl<'a> PropertyStorage<'a> {
     pub fn set(self, agent: &mut Agent, property_key: PropertyKey, descriptor: PropertyDescriptor) {
         match self.0 {
             Object::Object(object) => {
-                let ObjectHeapData { keys, values, .. } = agent[object];
-                // SAFETY: Key is only used to compare with other keys.
-                let property_key = unsafe { property_key.into_value_unchecked() };
+                let ObjectHeapData { shape, values, .. } = agent[object];
 
                 let value = descriptor.value;
                 let element_descriptor = ElementDescriptor::from_property_descriptor(descriptor);
 
-                let result = agent
-                    .heap
-                    .elements
-                    .get(keys)
-                    .iter()
-                    .enumerate()
-                    .find(|(_, element_key)| element_key.unwrap() == property_key)
-                    .map(|res| res.0);
+                let result = shape.get_offset(&agent.heap.object_shapes, property_key);
                 if let Some(index) = result {
-                    let key_entry = agent.heap.elements.get_mut(keys).get_mut(index).unwrap();
-                    *key_entry = Some(property_key);
+                    let index = index as usize;
                     let value_entry = agent.heap.elements.get_mut(values).get_mut(index).unwrap();
                     *value_entry = value;
                     agent
@@ -114,17 +83,28 @@ impl<'a> PropertyStorage<'a> {
                         .elements
                         .set_descriptor(values, index, element_descriptor);
                 } else {
+                    agent
+                        .heap
+                        .remembered_sets
+                        .objects
+                        .remember(object.get_index());
                     let Heap {
-                        elements, objects, ..
+                        elements,
+                        objects,
+                        object_shapes,
+                        remembered_sets,
+                        ..
                     } = &mut agent.heap;
                     let object_heap_data = objects
                         .get_mut(object.get_index())
                         .expect("Invalid ObjectIndex")
                         .as_mut()
                         .expect("Invalid ObjectIndex");
-                    object_heap_data
-                        .keys
-                        .push(elements, Some(property_key), None);
+                    object_heap_data.shape = shape.add_key(
+                        object_shapes,
+                        &mut remembered_sets.object_shapes,
+                        property_key,
+                    );
                     object_heap_data
                         .values
                         .push(elements, value, element_descriptor);
@@ -137,28 +117,29 @@ impl<'a> PropertyStorage<'a> {
     pub fn remove(self, agent: &mut Agent, property_key: PropertyKey) {
         match self.0 {
             Object::Object(object) => {
-                // SAFETY: Key is only used to compare with other keys.
-                let property_key = unsafe { property_key.into_value_unchecked() };
-
-                let result = agent
-                    .heap
-                    .elements
-                    .get(agent[object].keys)
-                    .iter()
-                    .enumerate()
-                    .find(|(_, element_key)| element_key.unwrap() == property_key)
-                    .map(|res| res.0);
+                let shape = agent[object].shape;
+                let result = shape.get_offset(&agent.heap.object_shapes, property_key);
                 if let Some(index) = result {
+                    agent
+                        .heap
+                        .remembered_sets
+                        .objects
+                        .remember(object.get_index());
                     let Heap {
-                        elements, objects, ..
+                        elements,
+                        objects,
+                        object_shapes,
+                        remembered_sets,
+                        ..
                     } = &mut agent.heap;
                     let object_heap_data = objects
                         .get_mut(object.get_index())
                         .expect("Invalid ObjectIndex")
                         .as_mut()
                         .expect("Invalid ObjectIndex");
-                    object_heap_data.keys.remove(elements, index);
-                    object_heap_data.values.remove(elements, index);
+                    object_heap_data.shape =
+                        shape.remove_key(object_shapes, &mut remembered_sets.object_shapes, index);
+                    object_heap_data.values.remove(elements, index as usize);
                 }
             }
             _ => todo!(),
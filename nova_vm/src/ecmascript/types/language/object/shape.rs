@@ -0,0 +1,309 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ObjectShape is a Nova-engine specific concept, otherwise known as a hidden
+//! class. Ordinary objects that have had the same keys added in the same order
+//! share a single Shape: the Shape holds the list of keys and maps each key to
+//! the slot offset of its value in the object's values vector.
+//!
+//! Shapes form a transition tree rooted at [`ObjectShape::EMPTY`]: adding a
+//! key to an object moves it to the child Shape reached by that key. Objects
+//! with many keys, or objects that have had keys removed, are given a
+//! dictionary Shape of their own that is mutated in place.
+//!
+//! Transitions are weak: A Shape is kept alive by the objects (and inline
+//! caches) using it and by its children, but not by its parent. When a Shape
+//! is collected, its transition is removed from its parent.
+
+use ahash::AHashMap;
+
+use crate::{
+    ecmascript::types::PropertyKey,
+    heap::{
+        indexes::BaseIndex, remembered_set::RememberedSet, CompactionLists, HeapMarkAndSweep,
+        MarkBits, WorkQueues,
+    },
+};
+
+type ObjectShapeIndex = BaseIndex<'static, ObjectShapeHeapData>;
+
+/// Maximum number of keys in a Shape that is part of the transition tree.
+/// Objects that grow past this get a dictionary Shape.
+const MAX_SHARED_SHAPE_KEYS: usize = 32;
+
+/// Shapes with more keys than this keep a hash table of key offsets;
+/// smaller Shapes are searched linearly.
+const LOOKUP_TABLE_THRESHOLD: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct ObjectShape(ObjectShapeIndex);
+
+#[derive(Debug, Default)]
+pub(crate) struct ObjectShapeHeapData {
+    /// Keys of the Shape in property creation order. The value of the key at
+    /// index `i` is stored at offset `i` of an object's values vector.
+    keys: Vec<PropertyKey<'static>>,
+    /// The Shape that this Shape was reached from by adding its last key.
+    /// None for the empty Shape and dictionary Shapes.
+    parent: Option<ObjectShape>,
+    /// Shapes reached from this Shape by adding a single key. The transitions
+    /// are weak and are removed when the Shapes they lead to are collected.
+    transitions: Vec<(PropertyKey<'static>, ObjectShape)>,
+    /// Key to offset lookup table for Shapes with many keys.
+    lookup_table: Option<AHashMap<PropertyKey<'static>, u32>>,
+    /// Dictionary Shapes are owned by a single object, are not part of the
+    /// transition tree, and are mutated in place.
+    dictionary: bool,
+}
+
+impl ObjectShapeHeapData {
+    fn new(keys: Vec<PropertyKey<'static>>, parent: Option<ObjectShape>, dictionary: bool) -> Self {
+        let mut data = Self {
+            keys,
+            parent,
+            transitions: Vec::new(),
+            lookup_table: None,
+            dictionary,
+        };
+        data.rebuild_lookup_table();
+        data
+    }
+
+    fn rebuild_lookup_table(&mut self) {
+        self.lookup_table = if self.keys.len() > LOOKUP_TABLE_THRESHOLD {
+            Some(
+                self.keys
+                    .iter()
+                    .enumerate()
+                    .map(|(offset, key)| (*key, offset as u32))
+                    .collect(),
+            )
+        } else {
+            None
+        };
+    }
+}
+
+impl ObjectShape {
+    /// The Shape of objects with no properties. This is the root of the
+    /// transition tree and is never garbage collected.
+    pub(crate) const EMPTY: Self = Self(ObjectShapeIndex::from_u32_index(0));
+
+    pub(crate) const fn get_index(self) -> usize {
+        self.0.into_index()
+    }
+
+    fn data(self, shapes: &[Option<ObjectShapeHeapData>]) -> &ObjectShapeHeapData {
+        shapes
+            .get(self.get_index())
+            .expect("ObjectShape out of bounds")
+            .as_ref()
+            .expect("ObjectShape slot empty")
+    }
+
+    fn data_mut(self, shapes: &mut [Option<ObjectShapeHeapData>]) -> &mut ObjectShapeHeapData {
+        shapes
+            .get_mut(self.get_index())
+            .expect("ObjectShape out of bounds")
+            .as_mut()
+            .expect("ObjectShape slot empty")
+    }
+
+    /// Returns the Shape's data for mutation and records the write in the
+    /// remembered set of Shapes.
+    fn write<'a>(
+        self,
+        shapes: &'a mut [Option<ObjectShapeHeapData>],
+        remembered: &mut RememberedSet,
+    ) -> &'a mut ObjectShapeHeapData {
+        remembered.remember(self.get_index());
+        self.data_mut(shapes)
+    }
+
+    fn create(shapes: &mut Vec<Option<ObjectShapeHeapData>>, data: ObjectShapeHeapData) -> Self {
+        shapes.push(Some(data));
+        Self(ObjectShapeIndex::last(shapes))
+    }
+
+    /// Returns the keys of the Shape in property creation order.
+    pub(crate) fn keys(self, shapes: &[Option<ObjectShapeHeapData>]) -> &[PropertyKey<'static>] {
+        &self.data(shapes).keys
+    }
+
+    /// Returns true if the Shape is a dictionary Shape. Dictionary Shapes are
+    /// mutated in place, so their identity does not identify their keys.
+    pub(crate) fn is_dictionary(self, shapes: &[Option<ObjectShapeHeapData>]) -> bool {
+        self.data(shapes).dictionary
+    }
+
+    /// Returns the offset of the key's value in the values vector of objects
+    /// with this Shape, or None if the Shape does not contain the key.
+    pub(crate) fn get_offset(
+        self,
+        shapes: &[Option<ObjectShapeHeapData>],
+        key: PropertyKey,
+    ) -> Option<u32> {
+        let data = self.data(shapes);
+        let key = key.unbind();
+        if let Some(lookup_table) = &data.lookup_table {
+            lookup_table.get(&key).copied()
+        } else {
+            data.keys
+                .iter()
+                .position(|element_key| *element_key == key)
+                .map(|offset| offset as u32)
+        }
+    }
+
+    /// Returns the Shape that results from adding the key to the end of this
+    /// Shape. The key must not already be in the Shape.
+    ///
+    /// Note: Dictionary Shapes are mutated in place and returned as-is.
+    pub(crate) fn add_key(
+        self,
+        shapes: &mut Vec<Option<ObjectShapeHeapData>>,
+        remembered: &mut RememberedSet,
+        key: PropertyKey,
+    ) -> Self {
+        let key = key.unbind();
+        debug_assert!(self.get_offset(shapes, key).is_none());
+        let data = self.data(shapes);
+        if data.dictionary {
+            let data = self.write(shapes, remembered);
+            let offset = data.keys.len() as u32;
+            data.keys.push(key);
+            if let Some(lookup_table) = &mut data.lookup_table {
+                lookup_table.insert(key, offset);
+            } else {
+                data.rebuild_lookup_table();
+            }
+            return self;
+        }
+        if let Some((_, next)) = data
+            .transitions
+            .iter()
+            .find(|(transition_key, _)| *transition_key == key)
+        {
+            return *next;
+        }
+        let mut keys = Vec::with_capacity(data.keys.len() + 1);
+        keys.extend_from_slice(&data.keys);
+        keys.push(key);
+        if keys.len() > MAX_SHARED_SHAPE_KEYS {
+            return Self::create(shapes, ObjectShapeHeapData::new(keys, None, true));
+        }
+        let next = Self::create(shapes, ObjectShapeHeapData::new(keys, Some(self), false));
+        self.write(shapes, remembered).transitions.push((key, next));
+        next
+    }
+
+    /// Returns the Shape that results from removing the key at the given
+    /// offset from this Shape. Offsets of the keys after it shift down by one.
+    ///
+    /// Note: Dictionary Shapes are mutated in place and returned as-is.
+    pub(crate) fn remove_key(
+        self,
+        shapes: &mut Vec<Option<ObjectShapeHeapData>>,
+        remembered: &mut RememberedSet,
+        offset: u32,
+    ) -> Self {
+        let data = self.data(shapes);
+        if data.dictionary {
+            let data = self.write(shapes, remembered);
+            data.keys.remove(offset as usize);
+            data.rebuild_lookup_table();
+            return self;
+        }
+        let mut keys = data.keys.clone();
+        keys.remove(offset as usize);
+        Self::create(shapes, ObjectShapeHeapData::new(keys, None, true))
+    }
+
+    /// Returns a Shape containing the given keys in order. The keys must not
+    /// contain duplicates.
+    pub(crate) fn from_keys<'a>(
+        shapes: &mut Vec<Option<ObjectShapeHeapData>>,
+        remembered: &mut RememberedSet,
+        keys: impl ExactSizeIterator<Item = PropertyKey<'a>>,
+    ) -> Self {
+        if keys.len() > MAX_SHARED_SHAPE_KEYS {
+            let keys = keys.map(|key| key.unbind()).collect();
+            return Self::create(shapes, ObjectShapeHeapData::new(keys, None, true));
+        }
+        keys.fold(Self::EMPTY, |shape, key| {
+            shape.add_key(shapes, remembered, key)
+        })
+    }
+}
+
+/// Removes the transitions to the Shapes that are about to be collected from
+/// their parents. Shapes in the old generation are not collected.
+pub(crate) fn sweep_shape_transitions(shapes: &mut [Option<ObjectShapeHeapData>], bits: &MarkBits) {
+    for (index, marked) in bits.iter_young() {
+        if marked {
+            continue;
+        }
+        let Some(parent) = shapes[index].as_ref().and_then(|data| data.parent) else {
+            continue;
+        };
+        // Note: A collected parent has its transitions dropped with it.
+        if !bits.is_marked(parent.get_index()) {
+            continue;
+        }
+        let shape = ObjectShape(ObjectShapeIndex::from_index(index));
+        parent
+            .data_mut(shapes)
+            .transitions
+            .retain(|(_, next)| *next != shape);
+    }
+}
+
+impl HeapMarkAndSweep for ObjectShapeHeapData {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            keys,
+            parent,
+            // Note: Transitions are weak. The key of a transition is the last
+            // key of the Shape it leads to, and is marked by that Shape.
+            transitions: _,
+            lookup_table: _,
+            dictionary: _,
+        } = self;
+        for key in keys.iter() {
+            key.mark_values(queues);
+        }
+        parent.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            keys,
+            parent,
+            transitions,
+            lookup_table,
+            dictionary: _,
+        } = self;
+        for key in keys.iter_mut() {
+            key.sweep_values(compactions);
+        }
+        parent.sweep_values(compactions);
+        for (key, shape) in transitions.iter_mut() {
+            key.sweep_values(compactions);
+            shape.sweep_values(compactions);
+        }
+        if lookup_table.is_some() {
+            self.rebuild_lookup_table();
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ObjectShape {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.object_shapes.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.object_shapes.shift_index(&mut self.0);
+    }
+}
@@ -583,11 +583,21 @@ impl Scoped<'_, String<'static>> {
 
 impl CreateHeapData<(StringHeapData, u64), String<'static>> for Heap {
     fn create(&mut self, (data, hash): (StringHeapData, u64)) -> String<'static> {
+        self.alloc_counter += core::mem::size_of::<Option<StringHeapData>>() + data.len();
         self.strings.push(Some(data));
         let index = StringIndex::last(&self.strings);
         let heap_string = HeapString(index);
-        self.string_lookup_table
-            .insert_unique(hash, heap_string, |_| hash);
+        let Heap {
+            strings,
+            string_lookup_table,
+            string_hasher,
+            ..
+        } = self;
+        // Note: The table may rehash its existing entries when inserting, so
+        // the hasher must hash the entry it is given.
+        string_lookup_table.insert_unique(hash, heap_string, |heap_string| {
+            string_hasher.hash_one(strings[heap_string.get_index()].as_ref().unwrap().as_wtf8())
+        });
         String::String(heap_string)
     }
 }
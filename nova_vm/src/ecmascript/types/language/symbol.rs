@@ -150,6 +150,10 @@ impl Index<Symbol<'_>> for Agent {
 
 impl IndexMut<Symbol<'_>> for Agent {
     fn index_mut(&mut self, index: Symbol<'_>) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .symbols
+            .remember(index.get_index());
         &mut self.heap.symbols[index]
     }
 }
@@ -187,6 +191,7 @@ impl HeapMarkAndSweep for Symbol<'static> {
 impl CreateHeapData<SymbolHeapData, Symbol<'static>> for Heap {
     fn create(&mut self, data: SymbolHeapData) -> Symbol<'static> {
         self.symbols.push(Some(data));
+        self.alloc_counter += core::mem::size_of::<Option<SymbolHeapData>>();
         Symbol(SymbolIndex::last(&self.symbols))
     }
 }
@@ -13,6 +13,8 @@ use super::{
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
@@ -35,6 +37,7 @@ use crate::{
             try_to_string,
         },
         builtins::{
+            arguments::MappedArgumentsObject,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
             control_abstraction_objects::{
@@ -156,7 +159,12 @@ pub enum Value {
     /// An unmapped arguments object is an ordinary object with an additional
     /// internal slot \[\[ParameterMap]] whose value is always **undefined**.
     Arguments(OrdinaryObject<'static>),
-    // TODO: MappedArguments(MappedArgumentsObject),
+    /// ### [10.4.4 Arguments Exotic Objects](https://tc39.es/ecma262/#sec-arguments-exotic-objects)
+    ///
+    /// A mapped arguments object is an arguments exotic object whose array
+    /// index properties alias the formal parameter bindings of a non-strict
+    /// function with a simple parameter list.
+    MappedArguments(MappedArgumentsObject<'static>),
     Array(Array<'static>),
     #[cfg(feature = "array-buffer")]
     ArrayBuffer(ArrayBuffer<'static>),
@@ -164,6 +172,8 @@ pub enum Value {
     DataView(DataView<'static>),
     #[cfg(feature = "date")]
     Date(Date<'static>),
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack(DisposableStack<'static>),
     Error(Error<'static>),
     FinalizationRegistry(FinalizationRegistry<'static>),
     Map(Map<'static>),
@@ -267,6 +277,9 @@ pub(crate) const ARRAY_BUFFER_DISCRIMINANT: u8 =
     value_discriminant(Value::ArrayBuffer(ArrayBuffer::_def()));
 #[cfg(feature = "date")]
 pub(crate) const DATE_DISCRIMINANT: u8 = value_discriminant(Value::Date(Date::_def()));
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub(crate) const DISPOSABLE_STACK_DISCRIMINANT: u8 =
+    value_discriminant(Value::DisposableStack(DisposableStack::_def()));
 pub(crate) const ERROR_DISCRIMINANT: u8 = value_discriminant(Value::Error(Error::_def()));
 pub(crate) const BUILTIN_FUNCTION_DISCRIMINANT: u8 =
     value_discriminant(Value::BuiltinFunction(BuiltinFunction::_def()));
@@ -293,6 +306,8 @@ pub(crate) const PRIMITIVE_OBJECT_DISCRIMINANT: u8 =
     value_discriminant(Value::PrimitiveObject(PrimitiveObject::_def()));
 pub(crate) const ARGUMENTS_DISCRIMINANT: u8 =
     value_discriminant(Value::Arguments(OrdinaryObject::_def()));
+pub(crate) const MAPPED_ARGUMENTS_DISCRIMINANT: u8 =
+    value_discriminant(Value::MappedArguments(MappedArgumentsObject::_def()));
 #[cfg(feature = "array-buffer")]
 pub(crate) const DATA_VIEW_DISCRIMINANT: u8 = value_discriminant(Value::DataView(DataView::_def()));
 pub(crate) const FINALIZATION_REGISTRY_DISCRIMINANT: u8 =
@@ -703,6 +718,10 @@ impl Value {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            Value::MappedArguments(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Array(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -722,6 +741,11 @@ impl Value {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Error(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -934,6 +958,10 @@ impl Value {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            Value::MappedArguments(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Array(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -953,6 +981,11 @@ impl Value {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => {
+                discriminant.hash(hasher);
+                data.get_index().hash(hasher);
+            }
             Value::Error(data) => {
                 discriminant.hash(hasher);
                 data.get_index().hash(hasher);
@@ -1225,6 +1258,9 @@ impl Rootable for Value {
                 Err(HeapRootData::PrimitiveObject(primitive_object))
             }
             Self::Arguments(ordinary_object) => Err(HeapRootData::Arguments(ordinary_object)),
+            Self::MappedArguments(mapped_arguments) => {
+                Err(HeapRootData::MappedArguments(mapped_arguments))
+            }
             Self::Array(array) => Err(HeapRootData::Array(array)),
             #[cfg(feature = "array-buffer")]
             Self::ArrayBuffer(array_buffer) => Err(HeapRootData::ArrayBuffer(array_buffer)),
@@ -1232,6 +1268,10 @@ impl Rootable for Value {
             Self::DataView(data_view) => Err(HeapRootData::DataView(data_view)),
             #[cfg(feature = "date")]
             Self::Date(date) => Err(HeapRootData::Date(date)),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Self::DisposableStack(disposable_stack) => {
+                Err(HeapRootData::DisposableStack(disposable_stack))
+            }
             Self::Error(error) => Err(HeapRootData::Error(error)),
             Self::FinalizationRegistry(finalization_registry) => {
                 Err(HeapRootData::FinalizationRegistry(finalization_registry))
@@ -1343,6 +1383,9 @@ impl Rootable for Value {
                 Some(Self::PrimitiveObject(primitive_object))
             }
             HeapRootData::Arguments(ordinary_object) => Some(Self::Arguments(ordinary_object)),
+            HeapRootData::MappedArguments(mapped_arguments) => {
+                Some(Self::MappedArguments(mapped_arguments))
+            }
             HeapRootData::Array(array) => Some(Self::Array(array)),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => Some(Self::ArrayBuffer(array_buffer)),
@@ -1350,6 +1393,10 @@ impl Rootable for Value {
             HeapRootData::DataView(data_view) => Some(Self::DataView(data_view)),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => Some(Self::Date(date)),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => {
+                Some(Self::DisposableStack(disposable_stack))
+            }
             HeapRootData::Error(error) => Some(Self::Error(error)),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 Some(Self::FinalizationRegistry(finalization_registry))
@@ -1411,7 +1458,7 @@ impl Rootable for Value {
             HeapRootData::EmbedderObject(embedder_object) => {
                 Some(Self::EmbedderObject(embedder_object))
             }
-            HeapRootData::PromiseReaction(_) => None,
+            HeapRootData::PromiseReaction(_) | HeapRootData::ArrayFromAsyncReaction(_) => None,
             // Note: Do not use _ => Err(()) to make sure any added
             // HeapRootData Value variants cause compile errors if not handled.
         }
@@ -1453,6 +1500,8 @@ impl HeapMarkAndSweep for Value {
             Value::ArrayBuffer(data) => data.mark_values(queues),
             #[cfg(feature = "date")]
             Value::Date(data) => data.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => data.mark_values(queues),
             Value::Error(data) => data.mark_values(queues),
             Value::BoundFunction(data) => data.mark_values(queues),
             Value::BuiltinFunction(data) => data.mark_values(queues),
@@ -1461,6 +1510,7 @@ impl HeapMarkAndSweep for Value {
             Value::RegExp(data) => data.mark_values(queues),
             Value::PrimitiveObject(data) => data.mark_values(queues),
             Value::Arguments(data) => data.mark_values(queues),
+            Value::MappedArguments(data) => data.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             Value::DataView(data) => data.mark_values(queues),
             Value::FinalizationRegistry(data) => data.mark_values(queues),
@@ -1540,6 +1590,8 @@ impl HeapMarkAndSweep for Value {
             Value::ArrayBuffer(data) => data.sweep_values(compactions),
             #[cfg(feature = "date")]
             Value::Date(data) => data.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Value::DisposableStack(data) => data.sweep_values(compactions),
             Value::Error(data) => data.sweep_values(compactions),
             Value::BoundFunction(data) => data.sweep_values(compactions),
             Value::BuiltinFunction(data) => data.sweep_values(compactions),
@@ -1548,6 +1600,7 @@ impl HeapMarkAndSweep for Value {
             Value::RegExp(data) => data.sweep_values(compactions),
             Value::PrimitiveObject(data) => data.sweep_values(compactions),
             Value::Arguments(data) => data.sweep_values(compactions),
+            Value::MappedArguments(data) => data.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             Value::DataView(data) => data.sweep_values(compactions),
             Value::FinalizationRegistry(data) => data.sweep_values(compactions),
@@ -98,6 +98,16 @@ pub trait Viewable: private::Sealed + Copy {
     fn into_le_value<'a>(self, agent: &mut Agent, gc: NoGcScope<'a, '_>) -> Numeric<'a>;
     fn from_le_value(agent: &mut Agent, value: Numeric) -> Self;
     fn from_be_value(agent: &mut Agent, value: Numeric) -> Self;
+
+    /// Compares two values as stored in a Data Block. The values are ordered
+    /// numerically, with NaN sorting last and -0 sorting before +0. This is
+    /// the order that %TypedArray%.prototype.sort uses when no comparator is
+    /// given.
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering;
+
+    /// Returns true if two values as stored in a Data Block are equal
+    /// according to SameValueZero.
+    fn is_same_value_zero(self, other: Self) -> bool;
 }
 
 impl Viewable for u8 {
@@ -125,6 +135,14 @@ impl Viewable for u8 {
         };
         to_uint8_number(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 impl Viewable for U8Clamped {
     #[cfg(feature = "array-buffer")]
@@ -151,6 +169,14 @@ impl Viewable for U8Clamped {
         };
         Self(to_uint8_clamp_number(agent, value).to_le())
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self.0 == other.0
+    }
 }
 impl Viewable for i8 {
     #[cfg(feature = "array-buffer")]
@@ -177,6 +203,14 @@ impl Viewable for i8 {
         };
         to_int8_number(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 impl Viewable for u16 {
     #[cfg(feature = "array-buffer")]
@@ -203,6 +237,14 @@ impl Viewable for u16 {
         };
         to_uint16_number(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 impl Viewable for i16 {
     #[cfg(feature = "array-buffer")]
@@ -229,6 +271,14 @@ impl Viewable for i16 {
         };
         to_int16_number(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 impl Viewable for u32 {
     #[cfg(feature = "array-buffer")]
@@ -255,6 +305,14 @@ impl Viewable for u32 {
         };
         to_uint32_number(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 impl Viewable for i32 {
     #[cfg(feature = "array-buffer")]
@@ -281,6 +339,14 @@ impl Viewable for i32 {
         };
         to_int32_number(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 impl Viewable for u64 {
     const IS_BIGINT: bool = true;
@@ -308,6 +374,14 @@ impl Viewable for u64 {
         };
         to_big_uint64_big_int(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 impl Viewable for i64 {
     const IS_BIGINT: bool = true;
@@ -335,6 +409,14 @@ impl Viewable for i64 {
         };
         to_big_int64_big_int(agent, value).to_le()
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        self.cmp(&other)
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other
+    }
 }
 #[cfg(feature = "proposal-float16array")]
 impl Viewable for f16 {
@@ -361,6 +443,19 @@ impl Viewable for f16 {
         };
         Self::from_ne_bytes((value.to_real(agent) as Self).to_le_bytes())
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => core::cmp::Ordering::Equal,
+            (true, false) => core::cmp::Ordering::Greater,
+            (false, true) => core::cmp::Ordering::Less,
+            (false, false) => self.total_cmp(&other),
+        }
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
 }
 impl Viewable for f32 {
     #[cfg(feature = "array-buffer")]
@@ -387,6 +482,19 @@ impl Viewable for f32 {
         };
         Self::from_ne_bytes((value.to_real(agent) as Self).to_le_bytes())
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => core::cmp::Ordering::Equal,
+            (true, false) => core::cmp::Ordering::Greater,
+            (false, true) => core::cmp::Ordering::Less,
+            (false, false) => self.total_cmp(&other),
+        }
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
 }
 impl Viewable for f64 {
     #[cfg(feature = "array-buffer")]
@@ -413,6 +521,19 @@ impl Viewable for f64 {
         };
         Self::from_ne_bytes((value.to_real(agent) as Self).to_le_bytes())
     }
+
+    fn ecmascript_cmp(self, other: Self) -> core::cmp::Ordering {
+        match (self.is_nan(), other.is_nan()) {
+            (true, true) => core::cmp::Ordering::Equal,
+            (true, false) => core::cmp::Ordering::Greater,
+            (false, true) => core::cmp::Ordering::Less,
+            (false, false) => self.total_cmp(&other),
+        }
+    }
+
+    fn is_same_value_zero(self, other: Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
 }
 
 impl DataBlock {
@@ -436,23 +557,55 @@ impl DataBlock {
     }
 
     fn new(len: usize) -> Self {
+        Self::try_new(len)
+            .unwrap_or_else(|| handle_alloc_error(Layout::from_size_align(len, 8).unwrap()))
+    }
+
+    /// Allocates a zeroed DataBlock, or returns None if the allocation fails.
+    fn try_new(len: usize) -> Option<Self> {
         let ptr = if len == 0 {
             None
         } else {
-            let layout = Layout::from_size_align(len, 8).unwrap();
+            let layout = Layout::from_size_align(len, 8).ok()?;
             // SAFETY: Size of allocation is non-zero.
             let data = unsafe { alloc_zeroed(layout) };
             if data.is_null() {
-                // TODO: Throw error?
-                handle_alloc_error(layout);
+                return None;
             }
             debug_assert_eq!(data.align_offset(8), 0);
             NonNull::new(data)
         };
-        Self {
+        Some(Self {
             ptr,
             byte_length: len,
+        })
+    }
+
+    /// Allocates a zeroed DataBlock of `size` bytes onto the heap of the
+    /// agent. Throws a RangeError if the DataBlock would be larger than
+    /// [`Options::max_array_buffer_byte_length`], if it would grow the heap
+    /// past [`Options::max_heap_bytes`], or if the allocation fails.
+    ///
+    /// [`Options::max_array_buffer_byte_length`]: crate::ecmascript::execution::agent::Options::max_array_buffer_byte_length
+    /// [`Options::max_heap_bytes`]: crate::ecmascript::execution::agent::Options::max_heap_bytes
+    fn allocate(agent: &mut Agent, size: usize, gc: NoGcScope) -> JsResult<Self> {
+        if agent
+            .options
+            .max_array_buffer_byte_length
+            .is_some_and(|max_byte_length| size > max_byte_length)
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "Data Block length exceeds the maximum byte length",
+                gc,
+            ));
         }
+        agent.reserve_heap_bytes(size, gc)?;
+        let Some(block) = Self::try_new(size) else {
+            return Err(agent.throw_out_of_memory(gc));
+        };
+        agent.heap.alloc_counter += size;
+        Ok(block)
     }
 
     #[inline]
@@ -609,7 +762,7 @@ impl DataBlock {
             // 2. Let db be a new Data Block value consisting of size bytes.
             // 3. Set all of the bytes of db to 0.
             // 4. Return db.
-            Ok(Self::new(size))
+            Self::allocate(agent, size, gc)
         } else {
             // 2. cont: If it is impossible to create such a Data Block, throw a RangeError exception.
             // TODO: throw a RangeError exception
@@ -638,7 +791,7 @@ impl DataBlock {
             // 4. Let zero be « 0 ».
             // 5. For each index i of db, do
             // a. Append WriteSharedMemory { [[Order]]: INIT, [[NoTear]]: true, [[Block]]: db, [[ByteIndex]]: i, [[ElementSize]]: 1, [[Payload]]: zero } to eventsRecord.[[EventList]].
-            Ok(Self::new(size))
+            Self::allocate(agent, size, gc)
         } else {
             Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
@@ -720,15 +873,17 @@ impl DataBlock {
     pub fn realloc(&mut self, new_byte_length: usize) {
         // Max byte length should be within safe integer length.
         debug_assert!(new_byte_length < 2usize.pow(53));
-        let ptr = self
-            .as_mut_ptr(0)
-            .expect("Tried to realloc a detached DataBlock");
+        assert!(!self.is_detached(), "Tried to realloc a detached DataBlock");
+        let Some(ptr) = self.ptr else {
+            // An empty DataBlock has no allocation to resize.
+            *self = Self::new(new_byte_length);
+            return;
+        };
+        let ptr = ptr.as_ptr();
         let layout = Layout::from_size_align(self.byte_length, 8).unwrap();
         if new_byte_length == 0 {
             // When resizing to zero, we just drop the data instead.
-            if let Some(ptr) = self.ptr {
-                unsafe { dealloc(ptr.as_ptr(), layout) };
-            }
+            unsafe { dealloc(ptr, layout) };
             self.ptr = None;
             self.byte_length = 0;
             return;
@@ -773,6 +928,24 @@ fn new_data_block() {
     }
 }
 
+#[test]
+fn data_block_realloc() {
+    let mut db = DataBlock::new(0);
+    db.realloc(4);
+    assert_eq!(db.len(), 4);
+    for i in 0..4 {
+        assert_eq!(db.get::<u8>(i), Some(0));
+    }
+    db.set::<u8>(3, 7);
+    db.realloc(8);
+    assert_eq!(db.get::<u8>(3), Some(7));
+    assert_eq!(db.get::<u8>(7), Some(0));
+    db.realloc(0);
+    assert_eq!(db.len(), 0);
+    db.realloc(2);
+    assert_eq!(db.get::<u8>(1), Some(0));
+}
+
 #[test]
 fn data_block_set() {
     let mut db = DataBlock::new(8);
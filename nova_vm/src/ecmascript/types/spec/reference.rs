@@ -4,7 +4,7 @@
 
 use crate::ecmascript::abstract_operations::operations_on_objects::try_set;
 use crate::engine::context::{GcScope, NoGcScope};
-use crate::engine::TryResult;
+use crate::engine::{IndexType, TryResult};
 use crate::{
     ecmascript::{
         abstract_operations::{operations_on_objects::set, type_conversion::to_object},
@@ -86,7 +86,7 @@ pub(crate) fn is_property_reference(reference: &Reference) -> bool {
         Base::Unresolvable => false,
 
         // 2. If V.[[Base]] is an Environment Record, return false; otherwise return true.
-        Base::Environment(_) => false,
+        Base::Environment(_) | Base::Register(_) => false,
         _ => true,
     }
 }
@@ -223,6 +223,7 @@ pub(crate) fn get_value(
             };
             Ok(env.get_binding_value(agent, referenced_name, reference.strict, gc.reborrow())?)
         }
+        Base::Register(_) => unreachable!(),
         Base::Unresolvable => {
             // 2. If IsUnresolvableReference(V) is true, throw a ReferenceError exception.
             let error_message = format!(
@@ -272,7 +273,7 @@ pub(crate) fn put_value(
         // a. Let baseObj be ? ToObject(V.[[Base]]).
         let base = match v.base {
             Base::Value(value) => value,
-            Base::Environment(_) | Base::Unresolvable => unreachable!(),
+            Base::Environment(_) | Base::Register(_) | Base::Unresolvable => unreachable!(),
         };
         let base_obj = to_object(agent, base, gc.nogc())?;
         // b. If IsPrivateReference(V) is true, then
@@ -363,7 +364,7 @@ pub(crate) fn try_put_value<'a>(
         // a. Let baseObj be ? ToObject(V.[[Base]]).
         let base = match v.base {
             Base::Value(value) => value,
-            Base::Environment(_) | Base::Unresolvable => unreachable!(),
+            Base::Environment(_) | Base::Register(_) | Base::Unresolvable => unreachable!(),
         };
         let base_obj = match to_object(agent, base, gc) {
             Ok(base_obj) => base_obj,
@@ -479,7 +480,7 @@ pub(crate) fn get_this_value(reference: &Reference) -> Value {
         .this_value
         .unwrap_or_else(|| match reference.base {
             Base::Value(value) => value,
-            Base::Environment(_) | Base::Unresolvable => unreachable!(),
+            Base::Environment(_) | Base::Register(_) | Base::Unresolvable => unreachable!(),
         })
 }
 
@@ -487,6 +488,10 @@ pub(crate) fn get_this_value(reference: &Reference) -> Value {
 pub(crate) enum Base {
     Value(Value),
     Environment(EnvironmentIndex),
+    /// A binding held in a register of the running Vm instead of in an
+    /// Environment Record. These references are resolved by the Vm and never
+    /// reach the abstract operations of this module.
+    Register(IndexType),
     Unresolvable,
 }
 
@@ -521,7 +526,7 @@ impl HeapMarkAndSweep for Base {
         match self {
             Base::Value(value) => value.mark_values(queues),
             Base::Environment(idx) => idx.mark_values(queues),
-            Base::Unresolvable => {}
+            Base::Register(_) | Base::Unresolvable => {}
         }
     }
 
@@ -529,7 +534,7 @@ impl HeapMarkAndSweep for Base {
         match self {
             Base::Value(value) => value.sweep_values(compactions),
             Base::Environment(idx) => idx.sweep_values(compactions),
-            Base::Unresolvable => {}
+            Base::Register(_) | Base::Unresolvable => {}
         }
     }
 }
@@ -3,9 +3,12 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod bytecode_compiler;
+mod disassembler;
 mod executable;
 mod instructions;
 pub(super) mod iterator;
+mod property_lookup_cache;
+mod source_positions;
 mod vm;
 
 pub(crate) use bytecode_compiler::{
@@ -15,4 +18,5 @@ pub(crate) use executable::{
     Executable, ExecutableHeapData, FunctionExpression, IndexType, SendableRef,
 };
 pub(crate) use instructions::{Instruction, InstructionIter};
+pub(crate) use property_lookup_cache::PropertyLookupCache;
 pub(crate) use vm::{instanceof_operator, ExecutionResult, SuspendedVm, Vm};
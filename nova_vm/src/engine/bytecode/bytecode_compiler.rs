@@ -6,10 +6,12 @@ mod block_declaration_instantiation;
 mod class_definition_evaluation;
 mod for_in_of_statement;
 mod function_declaration_instantiation;
+#[cfg(feature = "proposal-explicit-resource-management")]
+mod using_declaration;
 
 use super::{
-    executable::ArrowFunctionExpression, Executable, ExecutableHeapData, FunctionExpression,
-    Instruction, SendableRef,
+    executable::ArrowFunctionExpression, source_positions::SourcePositionTable, Executable,
+    ExecutableHeapData, FunctionExpression, Instruction, PropertyLookupCache, SendableRef,
 };
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::reg_exp_create_literal;
@@ -18,19 +20,24 @@ use crate::{
         execution::Agent,
         syntax_directed_operations::{
             function_definitions::{CompileFunctionBodyData, ContainsExpression},
-            scope_analysis::{LexicallyScopedDeclaration, LexicallyScopedDeclarations},
+            function_names::is_anonymous_function_definition,
+            scope_analysis::{
+                function_register_bindings, LexicallyScopedDeclaration,
+                LexicallyScopedDeclarations, StaticScopes,
+            },
         },
         types::{BigInt, IntoValue, Number, PropertyKey, String, Value, BUILTIN_STRING_MEMORY},
     },
     engine::context::NoGcScope,
     heap::CreateHeapData,
 };
+use ahash::AHashMap;
 use num_traits::Num;
 use oxc_ast::ast::{
     self, BindingPattern, BindingRestElement, CallExpression, NewExpression, Statement,
 };
 use oxc_ecmascript::BoundNames;
-use oxc_span::Atom;
+use oxc_span::{Atom, GetSpan, Span};
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 
 pub type IndexType = u16;
@@ -71,11 +78,55 @@ pub(crate) struct CompileContext<'agent, 'gc, 'scope> {
     current_continue: Option<Vec<JumpIndex>>,
     /// `break;` statement jumps that were present in the current loop.
     current_break: Option<Vec<JumpIndex>>,
+    /// Scopes containing `using` declarations that the instructions being
+    /// built are in.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    dispose_scopes: Vec<using_declaration::DisposeScope>,
+    /// Number of dispose scopes entered outside the current `break;` target.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    dispose_scopes_outside_break: usize,
+    /// Number of dispose scopes entered outside the current `continue;`
+    /// target.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    dispose_scopes_outside_continue: usize,
     /// `?.` chain jumps that were present in a chain expression.
     optional_chains: Option<Vec<JumpIndex>>,
     /// In a `(a?.b)?.()` chain the evaluation of `(a?.b)` must be considered a
     /// reference.
     is_call_optional_chain_this: bool,
+    /// Number of inline caches used by the property access instructions
+    /// being built.
+    property_lookup_cache_count: usize,
+    /// Registers holding the bindings of the function being built that do
+    /// not escape it.
+    local_registers: AHashMap<&'static str, IndexType>,
+    /// Number of registers currently in use. Temporary registers are
+    /// allocated after the local registers and released in reverse order.
+    register_count: IndexType,
+    /// Size of the register file needed by the instructions being built.
+    max_register_count: IndexType,
+    /// Position and register of the last ResolveRegister instruction, used
+    /// to turn a directly following GetValue into a GetRegister.
+    last_register_reference: Option<(usize, IndexType)>,
+    /// Environment Records entered by the instructions being built, used to
+    /// resolve bindings to slots.
+    pub(crate) static_scopes: StaticScopes,
+    /// Position, hops and slot of the last ResolveBinding instruction that
+    /// was resolved statically, used to turn a directly following GetValue
+    /// into a GetLocal or GetScoped.
+    last_scoped_reference: Option<(usize, u32, u32)>,
+    /// Source offset of the AST node being compiled.
+    source_position: Option<u32>,
+    /// Source offset of the AST node compiled last. GetValue instructions
+    /// following a reference use this, as they fail on behalf of the
+    /// reference's expression.
+    previous_source_position: Option<u32>,
+    /// Instruction offsets where the source offset changes, and the new
+    /// source offsets.
+    source_positions: Vec<(u32, u32)>,
+    /// Instruction offsets that start basic blocks: Jump targets and the
+    /// instructions following jumps.
+    block_starts: Vec<u32>,
 }
 
 impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
@@ -96,9 +147,90 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
             current_depth_of_loop_scope: None,
             current_continue: None,
             current_break: None,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            dispose_scopes: Vec::new(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            dispose_scopes_outside_break: 0,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            dispose_scopes_outside_continue: 0,
             optional_chains: None,
             is_call_optional_chain_this: false,
+            property_lookup_cache_count: 0,
+            local_registers: AHashMap::default(),
+            register_count: 0,
+            max_register_count: 0,
+            last_register_reference: None,
+            static_scopes: StaticScopes::default(),
+            last_scoped_reference: None,
+            source_position: None,
+            previous_source_position: None,
+            source_positions: Vec::new(),
+            block_starts: vec![0],
+        }
+    }
+
+    /// Allocate registers for the bindings of a function that do not escape
+    /// the function's own code.
+    pub(super) fn allocate_local_registers(
+        &mut self,
+        params: &ast::FormalParameters<'static>,
+        body: &ast::FunctionBody<'static>,
+    ) {
+        for name in function_register_bindings(params, body) {
+            let register = self.allocate_register();
+            self.local_registers.insert(name.as_str(), register);
+        }
+    }
+
+    /// Get the register holding a binding, if the binding is held in one.
+    pub(crate) fn local_register(&self, name: &str) -> Option<IndexType> {
+        self.local_registers.get(name).copied()
+    }
+
+    /// Get the (hops, slot) coordinates of a binding, if the binding is not
+    /// held in a register and its Environment Record is known statically.
+    pub(crate) fn scoped_binding(&self, name: &str) -> Option<(u32, u32)> {
+        if self.local_register(name).is_some() {
+            return None;
         }
+        self.static_scopes.resolve(name)
+    }
+
+    /// Make the dispose scopes entered so far outside the `break;` and
+    /// `continue;` targets of a loop being built. Returns the previous
+    /// counts to be restored by [`exit_loop_dispose_scopes`].
+    ///
+    /// [`exit_loop_dispose_scopes`]: CompileContext::exit_loop_dispose_scopes
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(super) fn enter_loop_dispose_scopes(&mut self) -> (usize, usize) {
+        let count = self.dispose_scopes.len();
+        (
+            core::mem::replace(&mut self.dispose_scopes_outside_break, count),
+            core::mem::replace(&mut self.dispose_scopes_outside_continue, count),
+        )
+    }
+
+    /// Restore the dispose scope counts of the enclosing loop.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(super) fn exit_loop_dispose_scopes(&mut self, previous: (usize, usize)) {
+        (
+            self.dispose_scopes_outside_break,
+            self.dispose_scopes_outside_continue,
+        ) = previous;
+    }
+
+    /// Allocate a temporary register. Temporary registers must be released
+    /// in the reverse order of allocation.
+    pub(crate) fn allocate_register(&mut self) -> IndexType {
+        let register = self.register_count;
+        self.register_count = register.checked_add(1).expect("Too many registers");
+        self.max_register_count = self.max_register_count.max(self.register_count);
+        register
+    }
+
+    pub(crate) fn release_register(&mut self, register: IndexType) {
+        debug_assert_eq!(register + 1, self.register_count);
+        self.register_count = register;
     }
 
     /// Compile a class static field with an optional initializer into the
@@ -124,7 +256,7 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         };
         // Turn the static name to a 'this' property access.
         self.add_instruction(Instruction::ResolveThisBinding);
-        self.add_instruction_with_identifier(
+        self.add_instruction_with_identifier_and_property_lookup_cache(
             Instruction::EvaluatePropertyAccessWithIdentifierKey,
             identifier,
         );
@@ -197,6 +329,10 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
             eprintln!();
         }
 
+        // The function's Environment Record is entered before its code runs.
+        self.static_scopes.analyse_function(data.params, data.body);
+        self.static_scopes.enter();
+
         function_declaration_instantiation::instantiation(
             self,
             data.params,
@@ -210,7 +346,11 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         // heap operations.
         let body: &[Statement] = unsafe { core::mem::transmute(data.body.statements.as_slice()) };
 
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let dispose_scope = using_declaration::enter(self, body);
         self.compile_statements(body);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::exit(self, dispose_scope);
     }
 
     pub(super) fn compile_statements(&mut self, body: &[Statement]) {
@@ -228,13 +368,23 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         }
     }
 
-    pub(super) fn finish(self) -> Executable {
+    pub(super) fn finish(mut self) -> Executable {
+        let instruction_count = self.instructions.len() as u32;
+        self.block_starts.retain(|&start| start < instruction_count);
+        self.block_starts.sort_unstable();
+        self.block_starts.dedup();
         self.agent.heap.create(ExecutableHeapData {
             instructions: self.instructions.into_boxed_slice(),
             constants: self.constants.into_boxed_slice(),
             function_expressions: self.function_expressions.into_boxed_slice(),
             arrow_function_expressions: self.arrow_function_expressions.into_boxed_slice(),
             class_initializer_bytecodes: self.class_initializer_bytecodes.into_boxed_slice(),
+            property_lookup_caches: (0..self.property_lookup_cache_count)
+                .map(|_| PropertyLookupCache::default())
+                .collect(),
+            register_count: self.max_register_count.into(),
+            source_positions: SourcePositionTable::new(&self.source_positions),
+            block_starts: self.block_starts.into_boxed_slice(),
         })
     }
 
@@ -268,7 +418,51 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         None
     }
 
+    /// Set the source position of the instructions added next to the start
+    /// of a span, returning the previous source position.
+    pub(crate) fn enter_source_position(&mut self, span: Span) -> Option<u32> {
+        // Note: References that are not compiled as expressions, such as
+        // assignment targets, must not take the position of an earlier
+        // sibling node.
+        self.previous_source_position = None;
+        self.source_position.replace(span.start)
+    }
+
+    /// Restore the source position returned by `enter_source_position`.
+    pub(crate) fn exit_source_position(&mut self, previous: Option<u32>) {
+        self.previous_source_position = self.source_position;
+        self.source_position = previous;
+    }
+
+    /// Remove the instructions from the given offset onwards.
+    fn truncate_instructions(&mut self, len: usize) {
+        self.instructions.truncate(len);
+        while self
+            .source_positions
+            .last()
+            .is_some_and(|&(ip, _)| ip as usize >= len)
+        {
+            self.source_positions.pop();
+        }
+    }
+
     fn _push_instruction(&mut self, instruction: Instruction) {
+        let position = match instruction {
+            Instruction::GetValue | Instruction::GetValueKeepReference => {
+                self.previous_source_position.or(self.source_position)
+            }
+            _ => self.source_position,
+        };
+        if let Some(position) = position {
+            if self
+                .source_positions
+                .last()
+                .is_none_or(|&(_, previous)| previous != position)
+            {
+                let ip = u32::try_from(self.instructions.len()).unwrap();
+                self.source_positions.push((ip, position));
+            }
+        }
         self.instructions
             .push(unsafe { core::mem::transmute::<Instruction, u8>(instruction) });
     }
@@ -280,14 +474,93 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
                 && !instruction.has_function_expression_index()
                 && !instruction.has_identifier_index()
         );
+        if instruction == Instruction::GetValue {
+            if let Some((position, register)) = self.last_register_reference.take() {
+                // ResolveRegister takes two immediates.
+                if position + 5 == self.instructions.len() {
+                    self.truncate_instructions(position);
+                    self.add_instruction_with_immediate(Instruction::GetRegister, register.into());
+                    return;
+                }
+            }
+            if let Some((position, hops, slot)) = self.last_scoped_reference.take() {
+                // ResolveBinding takes one immediate.
+                if position + 3 == self.instructions.len() {
+                    self.truncate_instructions(position);
+                    self.add_scoped_binding_access(Instruction::GetLocal, hops, slot);
+                    return;
+                }
+            }
+        }
+        match instruction {
+            Instruction::EnterDeclarativeEnvironment
+            | Instruction::EnterClassStaticElementEnvironment => self.static_scopes.enter(),
+            Instruction::EnterObjectEnvironment => self.static_scopes.enter_opaque(),
+            Instruction::ExitDeclarativeEnvironment => self.static_scopes.exit(),
+            _ => {}
+        }
         self._push_instruction(instruction);
     }
 
+    /// Add an ExitDeclarativeEnvironment instruction on a path that jumps
+    /// out of the Environment Record, such as a `break;` statement. Code
+    /// following the instruction is still compiled inside the Environment
+    /// Record.
+    fn add_jumping_exit_declarative_environment(&mut self) {
+        self._push_instruction(Instruction::ExitDeclarativeEnvironment);
+    }
+
+    /// Add a GetLocal or SetLocal instruction accessing a binding by slot,
+    /// or the corresponding GetScoped or SetScoped instruction if the
+    /// binding is in an outer Environment Record.
+    fn add_scoped_binding_access(&mut self, local_instruction: Instruction, hops: u32, slot: u32) {
+        if hops == 0 {
+            self.add_instruction_with_immediate(local_instruction, slot as usize);
+        } else {
+            let instruction = match local_instruction {
+                Instruction::GetLocal => Instruction::GetScoped,
+                Instruction::SetLocal => Instruction::SetScoped,
+                _ => unreachable!(),
+            };
+            self.add_instruction_with_immediate_and_immediate(
+                instruction,
+                hops as usize,
+                slot as usize,
+            );
+        }
+    }
+
+    /// Add an instruction taking a register as its immediate.
+    fn add_instruction_with_register(&mut self, instruction: Instruction, register: IndexType) {
+        self.add_instruction_with_immediate(instruction, register.into());
+    }
+
+    /// Add a ResolveBinding instruction, or a ResolveRegister instruction if
+    /// the binding is held in a register.
+    fn add_resolve_binding(&mut self, identifier: String<'gc>) {
+        if let Some(register) = self.local_register(identifier.as_str(self.agent)) {
+            self.last_register_reference = Some((self.instructions.len(), register));
+            self._push_instruction(Instruction::ResolveRegister);
+            let identifier = self.add_identifier(identifier);
+            self.add_index(identifier);
+            self.add_index(register.into());
+        } else {
+            if let Some((hops, slot)) = self.scoped_binding(identifier.as_str(self.agent)) {
+                self.last_scoped_reference = Some((self.instructions.len(), hops, slot));
+            }
+            self._push_instruction(Instruction::ResolveBinding);
+            let identifier = self.add_identifier(identifier);
+            self.add_index(identifier);
+        }
+    }
+
     fn add_instruction_with_jump_slot(&mut self, instruction: Instruction) -> JumpIndex {
         debug_assert_eq!(instruction.argument_count(), 1);
         debug_assert!(instruction.has_jump_slot());
         self._push_instruction(instruction);
-        self.add_jump_index()
+        let jump_index = self.add_jump_index();
+        self.block_starts.push(self.instructions.len() as u32);
+        jump_index
     }
 
     fn add_jump_instruction_to_index(&mut self, instruction: Instruction, jump_index: JumpIndex) {
@@ -295,9 +568,14 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
         debug_assert!(instruction.has_jump_slot());
         self._push_instruction(instruction);
         self.add_index(jump_index.index);
+        self.block_starts.push(jump_index.index as u32);
+        self.block_starts.push(self.instructions.len() as u32);
     }
 
-    fn get_jump_index_to_here(&self) -> JumpIndex {
+    fn get_jump_index_to_here(&mut self) -> JumpIndex {
+        // Instructions jumped to cannot be merged with the ones before them.
+        self.last_register_reference = None;
+        self.last_scoped_reference = None;
         JumpIndex {
             index: self.instructions.len(),
         }
@@ -358,9 +636,35 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
     ) {
         debug_assert_eq!(instruction.argument_count(), 1);
         debug_assert!(instruction.has_identifier_index());
+        if instruction == Instruction::ResolveBinding {
+            self.add_resolve_binding(identifier);
+            return;
+        }
+        if matches!(
+            instruction,
+            Instruction::CreateMutableBinding | Instruction::CreateImmutableBinding
+        ) {
+            self.static_scopes.declare(identifier.as_str(self.agent));
+        }
+        self._push_instruction(instruction);
+        let identifier = self.add_identifier(identifier);
+        self.add_index(identifier);
+    }
+
+    /// Add an instruction with an identifier and a new inline cache.
+    fn add_instruction_with_identifier_and_property_lookup_cache(
+        &mut self,
+        instruction: Instruction,
+        identifier: String<'gc>,
+    ) {
+        debug_assert_eq!(instruction.argument_count(), 2);
+        debug_assert!(instruction.has_identifier_index());
         self._push_instruction(instruction);
         let identifier = self.add_identifier(identifier);
         self.add_index(identifier);
+        let cache_index = self.property_lookup_cache_count;
+        self.property_lookup_cache_count += 1;
+        self.add_index(cache_index);
     }
 
     fn add_instruction_with_identifier_and_constant(
@@ -454,12 +758,16 @@ impl<'a, 'gc, 'scope> CompileContext<'a, 'gc, 'scope> {
 
     fn set_jump_target(&mut self, source: JumpIndex, target: JumpIndex) {
         assert!(target.index < IndexType::MAX as usize);
+        self.block_starts.push(target.index as u32);
         let bytes: [u8; 2] = (target.index as IndexType).to_ne_bytes();
         self.instructions[source.index] = bytes[0];
         self.instructions[source.index + 1] = bytes[1];
     }
 
     fn set_jump_target_here(&mut self, jump: JumpIndex) {
+        // Instructions jumped to cannot be merged with the ones before them.
+        self.last_register_reference = None;
+        self.last_scoped_reference = None;
         self.set_jump_target(
             jump,
             JumpIndex {
@@ -671,14 +979,24 @@ impl CompileEvaluation for ast::UnaryExpression<'_> {
 
 impl CompileEvaluation for ast::BinaryExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
-        // 1. Let lref be ? Evaluation of leftOperand.
-        self.left.compile(ctx);
+        // Optimisation: A left operand held in a register can be used
+        // directly if the right operand cannot assign to it.
+        let local_register =
+            local_register_of(ctx, &self.left).filter(|_| !may_assign_local_register(&self.right));
+        let lval = if let Some(register) = local_register {
+            register
+        } else {
+            // 1. Let lref be ? Evaluation of leftOperand.
+            self.left.compile(ctx);
 
-        // 2. Let lval be ? GetValue(lref).
-        if is_reference(&self.left) {
-            ctx.add_instruction(Instruction::GetValue);
-        }
-        ctx.add_instruction(Instruction::Load);
+            // 2. Let lval be ? GetValue(lref).
+            if is_reference(&self.left) {
+                ctx.add_instruction(Instruction::GetValue);
+            }
+            let register = ctx.allocate_register();
+            ctx.add_instruction_with_register(Instruction::SetRegister, register);
+            register
+        };
 
         // 3. Let rref be ? Evaluation of rightOperand.
         self.right.compile(ctx);
@@ -690,44 +1008,109 @@ impl CompileEvaluation for ast::BinaryExpression<'_> {
 
         match self.operator {
             BinaryOperator::LessThan => {
-                ctx.add_instruction(Instruction::LessThan);
+                ctx.add_instruction_with_register(Instruction::LessThan, lval);
             }
             BinaryOperator::LessEqualThan => {
-                ctx.add_instruction(Instruction::LessThanEquals);
+                ctx.add_instruction_with_register(Instruction::LessThanEquals, lval);
             }
             BinaryOperator::GreaterThan => {
-                ctx.add_instruction(Instruction::GreaterThan);
+                ctx.add_instruction_with_register(Instruction::GreaterThan, lval);
             }
             BinaryOperator::GreaterEqualThan => {
-                ctx.add_instruction(Instruction::GreaterThanEquals);
+                ctx.add_instruction_with_register(Instruction::GreaterThanEquals, lval);
             }
             BinaryOperator::StrictEquality => {
-                ctx.add_instruction(Instruction::IsStrictlyEqual);
+                ctx.add_instruction_with_register(Instruction::IsStrictlyEqual, lval);
             }
             BinaryOperator::StrictInequality => {
-                ctx.add_instruction(Instruction::IsStrictlyEqual);
+                ctx.add_instruction_with_register(Instruction::IsStrictlyEqual, lval);
                 ctx.add_instruction(Instruction::LogicalNot);
             }
             BinaryOperator::Equality => {
-                ctx.add_instruction(Instruction::IsLooselyEqual);
+                ctx.add_instruction_with_register(Instruction::IsLooselyEqual, lval);
             }
             BinaryOperator::Inequality => {
-                ctx.add_instruction(Instruction::IsLooselyEqual);
+                ctx.add_instruction_with_register(Instruction::IsLooselyEqual, lval);
                 ctx.add_instruction(Instruction::LogicalNot);
             }
             BinaryOperator::In => {
-                ctx.add_instruction(Instruction::HasProperty);
+                ctx.add_instruction_with_register(Instruction::HasProperty, lval);
             }
             BinaryOperator::Instanceof => {
-                ctx.add_instruction(Instruction::InstanceofOperator);
+                ctx.add_instruction_with_register(Instruction::InstanceofOperator, lval);
             }
             _ => {
                 // 5. Return ? ApplyStringOrNumericBinaryOperator(lval, opText, rval).
-                ctx.add_instruction(Instruction::ApplyStringOrNumericBinaryOperator(
-                    self.operator,
-                ));
+                ctx.add_instruction_with_register(
+                    Instruction::ApplyStringOrNumericBinaryOperator(self.operator),
+                    lval,
+                );
             }
         }
+
+        if local_register.is_none() {
+            ctx.release_register(lval);
+        }
+    }
+}
+
+/// Get the register holding the binding an expression refers to, if the
+/// expression is an identifier referring to a binding held in a register.
+fn local_register_of(ctx: &CompileContext, expression: &ast::Expression) -> Option<IndexType> {
+    match expression.get_inner_expression() {
+        ast::Expression::Identifier(identifier) => ctx.local_register(&identifier.name),
+        _ => None,
+    }
+}
+
+/// Returns false if evaluating the expression is known to not assign to any
+/// binding held in a register.
+///
+/// Function calls cannot assign to the caller's registers, so only
+/// assignment and update expressions in the expression itself need to be
+/// considered.
+fn may_assign_local_register(expression: &ast::Expression) -> bool {
+    match expression {
+        ast::Expression::BooleanLiteral(_)
+        | ast::Expression::NullLiteral(_)
+        | ast::Expression::NumericLiteral(_)
+        | ast::Expression::BigIntLiteral(_)
+        | ast::Expression::StringLiteral(_)
+        | ast::Expression::Identifier(_)
+        | ast::Expression::ThisExpression(_)
+        | ast::Expression::FunctionExpression(_)
+        | ast::Expression::ArrowFunctionExpression(_) => false,
+        ast::Expression::StaticMemberExpression(member) => {
+            may_assign_local_register(&member.object)
+        }
+        ast::Expression::ComputedMemberExpression(member) => {
+            may_assign_local_register(&member.object)
+                || may_assign_local_register(&member.expression)
+        }
+        ast::Expression::BinaryExpression(binary) => {
+            may_assign_local_register(&binary.left) || may_assign_local_register(&binary.right)
+        }
+        ast::Expression::LogicalExpression(logical) => {
+            may_assign_local_register(&logical.left) || may_assign_local_register(&logical.right)
+        }
+        ast::Expression::UnaryExpression(unary) => may_assign_local_register(&unary.argument),
+        ast::Expression::ConditionalExpression(conditional) => {
+            may_assign_local_register(&conditional.test)
+                || may_assign_local_register(&conditional.consequent)
+                || may_assign_local_register(&conditional.alternate)
+        }
+        ast::Expression::ParenthesizedExpression(parenthesized) => {
+            may_assign_local_register(&parenthesized.expression)
+        }
+        ast::Expression::CallExpression(call) => {
+            may_assign_local_register(&call.callee)
+                || call.arguments.iter().any(|argument| {
+                    argument
+                        .as_expression()
+                        .is_none_or(may_assign_local_register)
+                })
+        }
+        _ => true,
     }
 }
 
@@ -771,6 +1154,83 @@ impl CompileEvaluation for ast::LogicalExpression<'_> {
 
 impl CompileEvaluation for ast::AssignmentExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        let local_register = match &self.left {
+            ast::AssignmentTarget::AssignmentTargetIdentifier(identifier) => {
+                ctx.local_register(&identifier.name)
+            }
+            _ => None,
+        };
+        if let Some(register) = local_register {
+            if self.operator == oxc_syntax::operator::AssignmentOperator::Assign {
+                // Optimisation: Bindings held in registers are assigned to
+                // directly.
+                self.right.compile(ctx);
+                if is_reference(&self.right) {
+                    ctx.add_instruction(Instruction::GetValue);
+                }
+                ctx.add_instruction_with_register(Instruction::SetRegister, register);
+                return;
+            } else if let Some(op_text) = assignment_operator_to_binary_operator(self.operator) {
+                // Note: Unless the right operand may assign to the register,
+                // it can be used as the left operand directly.
+                let lval = if may_assign_local_register(&self.right) {
+                    let lval = ctx.allocate_register();
+                    ctx.add_instruction_with_register(Instruction::GetRegister, register);
+                    ctx.add_instruction_with_register(Instruction::SetRegister, lval);
+                    Some(lval)
+                } else {
+                    None
+                };
+                self.right.compile(ctx);
+                if is_reference(&self.right) {
+                    ctx.add_instruction(Instruction::GetValue);
+                }
+                ctx.add_instruction_with_register(
+                    Instruction::ApplyStringOrNumericBinaryOperator(op_text),
+                    lval.unwrap_or(register),
+                );
+                ctx.add_instruction_with_register(Instruction::SetRegister, register);
+                if let Some(lval) = lval {
+                    ctx.release_register(lval);
+                }
+                return;
+            }
+        }
+
+        let scoped_binding = match &self.left {
+            ast::AssignmentTarget::AssignmentTargetIdentifier(identifier) => {
+                ctx.scoped_binding(&identifier.name)
+            }
+            _ => None,
+        };
+        if let Some((hops, slot)) = scoped_binding {
+            if self.operator == oxc_syntax::operator::AssignmentOperator::Assign {
+                // Optimisation: Bindings resolved statically are assigned to
+                // by slot.
+                self.right.compile(ctx);
+                if is_reference(&self.right) {
+                    ctx.add_instruction(Instruction::GetValue);
+                }
+                ctx.add_scoped_binding_access(Instruction::SetLocal, hops, slot);
+                return;
+            } else if let Some(op_text) = assignment_operator_to_binary_operator(self.operator) {
+                ctx.add_scoped_binding_access(Instruction::GetLocal, hops, slot);
+                let lval = ctx.allocate_register();
+                ctx.add_instruction_with_register(Instruction::SetRegister, lval);
+                self.right.compile(ctx);
+                if is_reference(&self.right) {
+                    ctx.add_instruction(Instruction::GetValue);
+                }
+                ctx.add_instruction_with_register(
+                    Instruction::ApplyStringOrNumericBinaryOperator(op_text),
+                    lval,
+                );
+                ctx.release_register(lval);
+                ctx.add_scoped_binding_access(Instruction::SetLocal, hops, slot);
+                return;
+            }
+        }
+
         // 1. Let lref be ? Evaluation of LeftHandSideExpression.
         let is_identifier_ref = match &self.left {
             ast::AssignmentTarget::ArrayAssignmentTarget(_) => todo!(),
@@ -876,7 +1336,8 @@ impl CompileEvaluation for ast::AssignmentExpression<'_> {
         } else {
             // 2. let lval be ? GetValue(lref).
             ctx.add_instruction(Instruction::GetValueKeepReference);
-            ctx.add_instruction(Instruction::Load);
+            let lval = ctx.allocate_register();
+            ctx.add_instruction_with_register(Instruction::SetRegister, lval);
             ctx.add_instruction(Instruction::PushReference);
             // 3. Let rref be ? Evaluation of AssignmentExpression.
             self.right.compile(ctx);
@@ -888,31 +1349,13 @@ impl CompileEvaluation for ast::AssignmentExpression<'_> {
 
             // 5. Let assignmentOpText be the source text matched by AssignmentOperator.
             // 6. Let opText be the sequence of Unicode code points associated with assignmentOpText in the following table:
-            let op_text = match self.operator {
-                oxc_syntax::operator::AssignmentOperator::Addition => BinaryOperator::Addition,
-                oxc_syntax::operator::AssignmentOperator::Subtraction => {
-                    BinaryOperator::Subtraction
-                }
-                oxc_syntax::operator::AssignmentOperator::Multiplication => {
-                    BinaryOperator::Multiplication
-                }
-                oxc_syntax::operator::AssignmentOperator::Division => BinaryOperator::Division,
-                oxc_syntax::operator::AssignmentOperator::Remainder => BinaryOperator::Remainder,
-                oxc_syntax::operator::AssignmentOperator::ShiftLeft => BinaryOperator::ShiftLeft,
-                oxc_syntax::operator::AssignmentOperator::ShiftRight => BinaryOperator::ShiftRight,
-                oxc_syntax::operator::AssignmentOperator::ShiftRightZeroFill => {
-                    BinaryOperator::ShiftRightZeroFill
-                }
-                oxc_syntax::operator::AssignmentOperator::BitwiseOR => BinaryOperator::BitwiseOR,
-                oxc_syntax::operator::AssignmentOperator::BitwiseXOR => BinaryOperator::BitwiseXOR,
-                oxc_syntax::operator::AssignmentOperator::BitwiseAnd => BinaryOperator::BitwiseAnd,
-                oxc_syntax::operator::AssignmentOperator::Exponential => {
-                    BinaryOperator::Exponential
-                }
-                _ => unreachable!(),
-            };
+            let op_text = assignment_operator_to_binary_operator(self.operator).unwrap();
             // 7. Let r be ? ApplyStringOrNumericBinaryOperator(lval, opText, rval).
-            ctx.add_instruction(Instruction::ApplyStringOrNumericBinaryOperator(op_text));
+            ctx.add_instruction_with_register(
+                Instruction::ApplyStringOrNumericBinaryOperator(op_text),
+                lval,
+            );
+            ctx.release_register(lval);
             ctx.add_instruction(Instruction::LoadCopy);
             // 8. Perform ? PutValue(lref, r).
             ctx.add_instruction(Instruction::PopReference);
@@ -923,6 +1366,30 @@ impl CompileEvaluation for ast::AssignmentExpression<'_> {
     }
 }
 
+/// Get the binary operator of a compound assignment operator, or None if the
+/// assignment operator is not a compound assignment operator.
+fn assignment_operator_to_binary_operator(
+    operator: oxc_syntax::operator::AssignmentOperator,
+) -> Option<BinaryOperator> {
+    Some(match operator {
+        oxc_syntax::operator::AssignmentOperator::Addition => BinaryOperator::Addition,
+        oxc_syntax::operator::AssignmentOperator::Subtraction => BinaryOperator::Subtraction,
+        oxc_syntax::operator::AssignmentOperator::Multiplication => BinaryOperator::Multiplication,
+        oxc_syntax::operator::AssignmentOperator::Division => BinaryOperator::Division,
+        oxc_syntax::operator::AssignmentOperator::Remainder => BinaryOperator::Remainder,
+        oxc_syntax::operator::AssignmentOperator::ShiftLeft => BinaryOperator::ShiftLeft,
+        oxc_syntax::operator::AssignmentOperator::ShiftRight => BinaryOperator::ShiftRight,
+        oxc_syntax::operator::AssignmentOperator::ShiftRightZeroFill => {
+            BinaryOperator::ShiftRightZeroFill
+        }
+        oxc_syntax::operator::AssignmentOperator::BitwiseOR => BinaryOperator::BitwiseOR,
+        oxc_syntax::operator::AssignmentOperator::BitwiseXOR => BinaryOperator::BitwiseXOR,
+        oxc_syntax::operator::AssignmentOperator::BitwiseAnd => BinaryOperator::BitwiseAnd,
+        oxc_syntax::operator::AssignmentOperator::Exponential => BinaryOperator::Exponential,
+        _ => return None,
+    })
+}
+
 impl CompileEvaluation for ast::ParenthesizedExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         self.expression.compile(ctx);
@@ -1436,7 +1903,7 @@ impl CompileEvaluation for ast::StaticMemberExpression<'_> {
 
         // 4. Return EvaluatePropertyAccessWithIdentifierKey(baseValue, IdentifierName, strict).
         let identifier = String::from_str(ctx.agent, self.property.name.as_str(), ctx.gc);
-        ctx.add_instruction_with_identifier(
+        ctx.add_instruction_with_identifier_and_property_lookup_cache(
             Instruction::EvaluatePropertyAccessWithIdentifierKey,
             identifier,
         );
@@ -1689,6 +2156,7 @@ impl CompileEvaluation for ast::YieldExpression<'_> {
 
 impl CompileEvaluation for ast::Expression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        let previous_source_position = ctx.enter_source_position(self.span());
         match self {
             ast::Expression::ArrayExpression(x) => x.compile(ctx),
             ast::Expression::ArrowFunctionExpression(x) => x.compile(ctx),
@@ -1736,11 +2204,58 @@ impl CompileEvaluation for ast::Expression<'_> {
             | ast::Expression::TSNonNullExpression(_)
             | ast::Expression::TSInstantiationExpression(_) => unreachable!(),
         }
+        ctx.exit_source_position(previous_source_position);
     }
 }
 
 impl CompileEvaluation for ast::UpdateExpression<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        if let ast::SimpleAssignmentTarget::AssignmentTargetIdentifier(x) = &self.argument {
+            if let Some(register) = ctx.local_register(&x.name) {
+                // Optimisation: Bindings held in registers are updated
+                // directly.
+                ctx.add_instruction_with_register(Instruction::GetRegister, register);
+                if !self.prefix {
+                    ctx.add_instruction(Instruction::ToNumeric);
+                    ctx.add_instruction(Instruction::LoadCopy);
+                }
+                match self.operator {
+                    oxc_syntax::operator::UpdateOperator::Increment => {
+                        ctx.add_instruction(Instruction::Increment);
+                    }
+                    oxc_syntax::operator::UpdateOperator::Decrement => {
+                        ctx.add_instruction(Instruction::Decrement);
+                    }
+                }
+                ctx.add_instruction_with_register(Instruction::SetRegister, register);
+                if !self.prefix {
+                    ctx.add_instruction(Instruction::Store);
+                }
+                return;
+            }
+            if let Some((hops, slot)) = ctx.scoped_binding(&x.name) {
+                // Optimisation: Bindings resolved statically are updated by
+                // slot.
+                ctx.add_scoped_binding_access(Instruction::GetLocal, hops, slot);
+                if !self.prefix {
+                    ctx.add_instruction(Instruction::ToNumeric);
+                    ctx.add_instruction(Instruction::LoadCopy);
+                }
+                match self.operator {
+                    oxc_syntax::operator::UpdateOperator::Increment => {
+                        ctx.add_instruction(Instruction::Increment);
+                    }
+                    oxc_syntax::operator::UpdateOperator::Decrement => {
+                        ctx.add_instruction(Instruction::Decrement);
+                    }
+                }
+                ctx.add_scoped_binding_access(Instruction::SetLocal, hops, slot);
+                if !self.prefix {
+                    ctx.add_instruction(Instruction::Store);
+                }
+                return;
+            }
+        }
         match &self.argument {
             ast::SimpleAssignmentTarget::AssignmentTargetIdentifier(x) => x.compile(ctx),
             ast::SimpleAssignmentTarget::ComputedMemberExpression(x) => x.compile(ctx),
@@ -1798,6 +2313,8 @@ impl CompileEvaluation for ast::ReturnStatement<'_> {
         } else {
             ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::dispose_on_return(ctx);
         ctx.add_instruction(Instruction::Return);
     }
 }
@@ -1889,6 +2406,13 @@ fn simple_array_pattern<'a, 'b, I>(
         };
         match &ele.kind {
             ast::BindingPatternKind::BindingIdentifier(identifier) => {
+                if let Some(register) = ctx.local_register(&identifier.name) {
+                    ctx.add_instruction_with_register(
+                        Instruction::BindingPatternBindToRegister,
+                        register,
+                    );
+                    continue;
+                }
                 let identifier_string = ctx.create_identifier(&identifier.name);
                 ctx.add_instruction_with_identifier(
                     Instruction::BindingPatternBind,
@@ -2172,7 +2696,7 @@ fn complex_object_pattern(
                 ctx.add_instruction(Instruction::Store);
                 ctx.add_instruction(Instruction::LoadCopy);
                 let identifier_string = ctx.create_identifier(&identifier.name);
-                ctx.add_instruction_with_identifier(
+                ctx.add_instruction_with_identifier_and_property_lookup_cache(
                     Instruction::EvaluatePropertyAccessWithIdentifierKey,
                     identifier_string,
                 );
@@ -2304,6 +2828,11 @@ impl CompileEvaluation for ast::VariableDeclaration<'_> {
                         return;
                     };
 
+                    if let Some(register) = ctx.local_register(&identifier.name) {
+                        compile_register_binding_initializer(ctx, identifier, init, register);
+                        continue;
+                    }
+
                     // 1. Let bindingId be StringValue of BindingIdentifier.
                     // 2. Let lhs be ? ResolveBinding(bindingId).
                     let identifier_string =
@@ -2362,6 +2891,19 @@ impl CompileEvaluation for ast::VariableDeclaration<'_> {
                         return;
                     };
 
+                    if let Some(register) = ctx.local_register(&identifier.name) {
+                        if let Some(init) = &decl.init {
+                            compile_register_binding_initializer(ctx, identifier, init, register);
+                        } else {
+                            ctx.add_instruction_with_constant(
+                                Instruction::StoreConstant,
+                                Value::Undefined,
+                            );
+                            ctx.add_instruction_with_register(Instruction::SetRegister, register);
+                        }
+                        continue;
+                    }
+
                     // 1. Let lhs be ! ResolveBinding(StringValue of BindingIdentifier).
                     let identifier_string =
                         String::from_str(ctx.agent, identifier.name.as_str(), ctx.gc);
@@ -2409,12 +2951,87 @@ impl CompileEvaluation for ast::VariableDeclaration<'_> {
                     ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
                 }
             }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            ast::VariableDeclarationKind::Using | ast::VariableDeclarationKind::AwaitUsing => {
+                let hint = self.kind == ast::VariableDeclarationKind::AwaitUsing;
+                for decl in &self.declarations {
+                    let ast::BindingPatternKind::BindingIdentifier(identifier) = &decl.id.kind
+                    else {
+                        unreachable!()
+                    };
+                    let init = decl.init.as_ref().unwrap();
+
+                    //  LexicalBinding : BindingIdentifier Initializer
+                    // 1. Let bindingId be the StringValue of BindingIdentifier.
+                    // 2. Let lhs be ! ResolveBinding(bindingId).
+                    let identifier_string =
+                        String::from_str(ctx.agent, identifier.name.as_str(), ctx.gc);
+                    let identifier = ctx.add_identifier(identifier_string);
+                    ctx.add_instruction_with_immediate(Instruction::ResolveBinding, identifier);
+                    ctx.add_instruction(Instruction::PushReference);
+                    // 3. If IsAnonymousFunctionDefinition(Initializer) is true, then
+                    if is_anonymous_function_definition(init) {
+                        // a. Let value be ? NamedEvaluation of Initializer with argument bindingId.
+                        ctx.add_instruction_with_immediate(Instruction::LoadConstant, identifier);
+                        ctx.name_identifier = Some(NamedEvaluationParameter::Stack);
+                        init.compile(ctx);
+                    } else {
+                        // 4. Else,
+                        // a. Let rhs be ? Evaluation of Initializer.
+                        init.compile(ctx);
+                        // b. Let value be ? GetValue(rhs).
+                        if is_reference(init) {
+                            ctx.add_instruction(Instruction::GetValue);
+                        }
+                    }
+                    // 5. Perform ? InitializeReferencedBinding(lhs, value, hint).
+                    // NOTE: InitializeReferencedBinding adds the value to the
+                    // scope's DisposeCapability before initializing the
+                    // binding.
+                    let register = ctx.dispose_scopes.last().unwrap().register;
+                    ctx.add_instruction_with_immediate_and_immediate(
+                        Instruction::AddDisposableResource,
+                        register.into(),
+                        hint.into(),
+                    );
+                    ctx.add_instruction(Instruction::PopReference);
+                    ctx.add_instruction(Instruction::InitializeReferencedBinding);
+                    // 6. Return empty.
+                    ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+                }
+            }
+            #[cfg(not(feature = "proposal-explicit-resource-management"))]
             ast::VariableDeclarationKind::Using => todo!(),
+            #[cfg(not(feature = "proposal-explicit-resource-management"))]
             ast::VariableDeclarationKind::AwaitUsing => todo!(),
         }
     }
 }
 
+/// Compile the initialization of a variable or lexical binding held in a
+/// register.
+fn compile_register_binding_initializer(
+    ctx: &mut CompileContext,
+    identifier: &ast::BindingIdentifier,
+    init: &ast::Expression,
+    register: IndexType,
+) {
+    if is_anonymous_function_definition(init) {
+        let identifier_string = String::from_str(ctx.agent, identifier.name.as_str(), ctx.gc);
+        let identifier = ctx.add_identifier(identifier_string);
+        ctx.add_instruction_with_immediate(Instruction::LoadConstant, identifier);
+        ctx.name_identifier = Some(NamedEvaluationParameter::Stack);
+        init.compile(ctx);
+    } else {
+        init.compile(ctx);
+        if is_reference(init) {
+            ctx.add_instruction(Instruction::GetValue);
+        }
+    }
+    ctx.add_instruction_with_register(Instruction::SetRegister, register);
+    ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+}
+
 impl CompileEvaluation for ast::Declaration<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         match self {
@@ -2434,9 +3051,13 @@ impl CompileEvaluation for ast::BlockStatement<'_> {
         }
         let did_enter_declarative_environment =
             block_declaration_instantiation::instantiation(ctx, self);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let dispose_scope = using_declaration::enter(ctx, &self.body);
         for ele in &self.body {
             ele.compile(ctx);
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::exit(ctx, dispose_scope);
         if ctx.peek_last_instruction() != Some(Instruction::Return.as_u8()) {
             // Block did not end in a return so we overwrite the result with undefined.
             ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
@@ -2458,6 +3079,8 @@ impl CompileEvaluation for ast::ForStatement<'_> {
 
         let mut per_iteration_lets: Vec<String<'_>> = vec![];
         let mut is_lexical = false;
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let mut dispose_scope = None;
 
         if let Some(init) = &self.init {
             match init {
@@ -2499,7 +3122,12 @@ impl CompileEvaluation for ast::ForStatement<'_> {
                 ast::ForStatementInit::UnaryExpression(init) => init.compile(ctx),
                 ast::ForStatementInit::UpdateExpression(init) => init.compile(ctx),
                 ast::ForStatementInit::VariableDeclaration(init) => {
-                    is_lexical = init.kind.is_lexical();
+                    // Note: Bindings held in registers need no environment.
+                    let mut has_environment_bindings = false;
+                    init.bound_names(&mut |dn| {
+                        has_environment_bindings |= ctx.local_register(&dn.name).is_none();
+                    });
+                    is_lexical = !init.kind.is_var() && has_environment_bindings;
                     if is_lexical {
                         // 1. Let oldEnv be the running execution context's LexicalEnvironment.
                         // 2. Let loopEnv be NewDeclarativeEnvironment(oldEnv).
@@ -2508,12 +3136,15 @@ impl CompileEvaluation for ast::ForStatement<'_> {
                         // take care of tearing this one down.
                         ctx.add_instruction(Instruction::EnterDeclarativeEnvironment);
                         // 3. Let isConst be IsConstantDeclaration of LexicalDeclaration.
-                        let is_const = init.kind.is_const();
+                        let is_const = init.kind != ast::VariableDeclarationKind::Let;
                         // 4. Let boundNames be the BoundNames of LexicalDeclaration.
                         // 5. For each element dn of boundNames, do
                         // a. If isConst is true, then
                         if is_const {
                             init.bound_names(&mut |dn| {
+                                if ctx.local_register(&dn.name).is_some() {
+                                    return;
+                                }
                                 // i. Perform ! loopEnv.CreateImmutableBinding(dn, true).
                                 let identifier =
                                     String::from_str(ctx.agent, dn.name.as_str(), ctx.gc);
@@ -2526,6 +3157,9 @@ impl CompileEvaluation for ast::ForStatement<'_> {
                             // b. Else,
                             // i. Perform ! loopEnv.CreateMutableBinding(dn, false).
                             init.bound_names(&mut |dn| {
+                                if ctx.local_register(&dn.name).is_some() {
+                                    return;
+                                }
                                 let identifier =
                                     String::from_str(ctx.agent, dn.name.as_str(), ctx.gc);
                                 // 9. If isConst is false, let perIterationLets
@@ -2540,6 +3174,10 @@ impl CompileEvaluation for ast::ForStatement<'_> {
                         }
                         // 6. Set the running execution context's LexicalEnvironment to loopEnv.
                     }
+                    #[cfg(feature = "proposal-explicit-resource-management")]
+                    {
+                        dispose_scope = using_declaration::enter_for_declaration(ctx, init);
+                    }
                     init.compile(ctx);
                 }
                 ast::ForStatementInit::YieldExpression(init) => init.compile(ctx),
@@ -2552,6 +3190,11 @@ impl CompileEvaluation for ast::ForStatement<'_> {
                 | ast::ForStatementInit::TSInstantiationExpression(_) => unreachable!(),
             }
         }
+        // NOTE: The resources of a using declaration in the loop head are
+        // disposed of once the loop is done, so `break;` and `continue;`
+        // statements must not dispose of them.
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let previous_dispose_scopes = ctx.enter_loop_dispose_scopes();
         // 2. Perform ? CreatePerIterationEnvironment(perIterationBindings).
         let create_per_iteration_env = if !per_iteration_lets.is_empty() {
             Some(|ctx: &mut CompileContext<'_, 'gc, '_>| {
@@ -2585,8 +3228,12 @@ impl CompileEvaluation for ast::ForStatement<'_> {
                     }
                     ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
                     ctx.add_instruction(Instruction::EnterDeclarativeEnvironment);
-                    for bn in per_iteration_lets.iter().rev() {
+                    // Note: The bindings are created in the same order as in
+                    // the loop environment so that their slots match.
+                    for bn in &per_iteration_lets {
                         ctx.add_instruction_with_identifier(Instruction::CreateMutableBinding, *bn);
+                    }
+                    for bn in per_iteration_lets.iter().rev() {
                         ctx.add_instruction_with_identifier(Instruction::ResolveBinding, *bn);
                         ctx.add_instruction(Instruction::Store);
                         ctx.add_instruction(Instruction::InitializeReferencedBinding);
@@ -2632,6 +3279,8 @@ impl CompileEvaluation for ast::ForStatement<'_> {
         for break_entry in own_breaks {
             ctx.set_jump_target_here(break_entry);
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::exit(ctx, dispose_scope);
         if is_lexical {
             // Lexical binding loops have an extra declarative environment that
             // we need to exit from once we exit the loop.
@@ -2640,25 +3289,38 @@ impl CompileEvaluation for ast::ForStatement<'_> {
         ctx.current_break = previous_break;
         ctx.current_continue = previous_continue;
         ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ctx.exit_loop_dispose_scopes(previous_dispose_scopes);
     }
 }
 
 impl CompileEvaluation for ast::SwitchStatement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
         let previous_break = ctx.current_break.replace(vec![]);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let previous_dispose_scopes_outside_break = core::mem::replace(
+            &mut ctx.dispose_scopes_outside_break,
+            ctx.dispose_scopes.len(),
+        );
         // 1. Let exprRef be ? Evaluation of Expression.
         self.discriminant.compile(ctx);
         if is_reference(&self.discriminant) {
             // 2. Let switchValue be ? GetValue(exprRef).
             ctx.add_instruction(Instruction::GetValue);
         }
-        ctx.add_instruction(Instruction::Load);
+        let switch_value = ctx.allocate_register();
+        ctx.add_instruction_with_register(Instruction::SetRegister, switch_value);
         // 3. Let oldEnv be the running execution context's LexicalEnvironment.
         // 4. Let blockEnv be NewDeclarativeEnvironment(oldEnv).
         // 6. Set the running execution context's LexicalEnvironment to blockEnv.
         // 5. Perform BlockDeclarationInstantiation(CaseBlock, blockEnv).
         let did_enter_declarative_environment =
             block_declaration_instantiation::instantiation(ctx, self);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let dispose_scope = using_declaration::enter(
+            ctx,
+            self.cases.iter().flat_map(|case| case.consequent.iter()),
+        );
 
         // 7. Let R be Completion(CaseBlockEvaluation of CaseBlock with argument switchValue).
         let mut has_default = false;
@@ -2672,11 +3334,6 @@ impl CompileEvaluation for ast::SwitchStatement<'_> {
                 has_default = true;
                 continue;
             };
-            // Duplicate the switchValue on the stack. One will remain, one is
-            // used by the IsStrictlyEqual
-            ctx.add_instruction(Instruction::Store);
-            ctx.add_instruction(Instruction::LoadCopy);
-            ctx.add_instruction(Instruction::Load);
             // 2. Let exprRef be ? Evaluation of the Expression of C.
             test.compile(ctx);
             // 3. Let clauseSelector be ? GetValue(exprRef).
@@ -2684,7 +3341,7 @@ impl CompileEvaluation for ast::SwitchStatement<'_> {
                 ctx.add_instruction(Instruction::GetValue);
             }
             // 4. Return IsStrictlyEqual(input, clauseSelector).
-            ctx.add_instruction(Instruction::IsStrictlyEqual);
+            ctx.add_instruction_with_register(Instruction::IsStrictlyEqual, switch_value);
             // b. If found is true then [evaluate case]
             jump_indexes.push(ctx.add_instruction_with_jump_slot(Instruction::JumpIfTrue));
         }
@@ -2713,9 +3370,7 @@ impl CompileEvaluation for ast::SwitchStatement<'_> {
             };
             ctx.set_jump_target_here(jump_index.clone());
 
-            // Pop the switchValue from the stack.
-            ctx.add_instruction(Instruction::Store);
-            // And override it with undefined
+            // Override the comparison result with undefined.
             ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
 
             if let Some(fallthrough_jump) = fallthrough_jump {
@@ -2726,12 +3381,19 @@ impl CompileEvaluation for ast::SwitchStatement<'_> {
                 ele.compile(ctx);
             }
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::exit(ctx, dispose_scope);
 
         let own_breaks = ctx.current_break.take().unwrap();
         for break_entry in own_breaks {
             ctx.set_jump_target_here(break_entry);
         }
         ctx.current_break = previous_break;
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        {
+            ctx.dispose_scopes_outside_break = previous_dispose_scopes_outside_break;
+        }
+        ctx.release_register(switch_value);
 
         // 8. Set the running execution context's LexicalEnvironment to oldEnv.
         if did_enter_declarative_environment {
@@ -2830,6 +3492,8 @@ impl CompileEvaluation for ast::WhileStatement<'_> {
         let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
         let previous_continue = ctx.current_continue.replace(vec![]);
         let previous_break = ctx.current_break.replace(vec![]);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let previous_dispose_scopes = ctx.enter_loop_dispose_scopes();
 
         // 2. Repeat
         let start_jump = ctx.get_jump_index_to_here();
@@ -2865,6 +3529,8 @@ impl CompileEvaluation for ast::WhileStatement<'_> {
         ctx.current_break = previous_break;
         ctx.current_continue = previous_continue;
         ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ctx.exit_loop_dispose_scopes(previous_dispose_scopes);
     }
 }
 
@@ -2873,6 +3539,8 @@ impl CompileEvaluation for ast::DoWhileStatement<'_> {
         let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
         let previous_continue = ctx.current_continue.replace(vec![]);
         let previous_break = ctx.current_break.replace(vec![]);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let previous_dispose_scopes = ctx.enter_loop_dispose_scopes();
 
         let start_jump = ctx.get_jump_index_to_here();
         self.body.compile(ctx);
@@ -2898,6 +3566,36 @@ impl CompileEvaluation for ast::DoWhileStatement<'_> {
         ctx.current_break = previous_break;
         ctx.current_continue = previous_continue;
         ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        ctx.exit_loop_dispose_scopes(previous_dispose_scopes);
+    }
+}
+
+impl CompileEvaluation for ast::WithStatement<'_> {
+    /// ### [14.11.2 Runtime Semantics: Evaluation](https://tc39.es/ecma262/#sec-with-statement-runtime-semantics-evaluation)
+    fn compile(&self, ctx: &mut CompileContext) {
+        // 1. Let val be ? Evaluation of Expression.
+        self.object.compile(ctx);
+        // 2. Let obj be ? ToObject(? GetValue(val)).
+        if is_reference(&self.object) {
+            ctx.add_instruction(Instruction::GetValue);
+        }
+        ctx.add_instruction(Instruction::ToObject);
+        // 3. Let oldEnv be the running execution context's LexicalEnvironment.
+        // 4. Let newEnv be NewObjectEnvironment(obj, true, oldEnv).
+        // 5. Set the running execution context's LexicalEnvironment to newEnv.
+        ctx.add_instruction(Instruction::EnterObjectEnvironment);
+        if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
+            *i += 1;
+        }
+        // 6. Let C be Completion(Evaluation of Statement).
+        self.body.compile(ctx);
+        // 7. Set the running execution context's LexicalEnvironment to oldEnv.
+        ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+        if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
+            *i -= 1;
+        }
+        // 8. Return ? UpdateEmpty(C, undefined).
     }
 }
 
@@ -2907,10 +3605,12 @@ impl CompileEvaluation for ast::BreakStatement<'_> {
             let label = label.name.as_str();
             todo!("break {};", label);
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::dispose_on_jump(ctx, ctx.dispose_scopes_outside_break);
         if let Some(depth) = ctx.current_depth_of_loop_scope {
             for _ in 0..depth {
                 // We have to exit the declarative environments we've entered.
-                ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+                ctx.add_jumping_exit_declarative_environment();
             }
         }
         let break_jump = ctx.add_instruction_with_jump_slot(Instruction::Jump);
@@ -2924,10 +3624,12 @@ impl CompileEvaluation for ast::ContinueStatement<'_> {
             let label = label.name.as_str();
             todo!("continue {};", label);
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::dispose_on_jump(ctx, ctx.dispose_scopes_outside_continue);
         let depth = ctx.current_depth_of_loop_scope.unwrap();
         for _ in 0..depth {
             // We have to exit the declarative environments we've entered.
-            ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
+            ctx.add_jumping_exit_declarative_environment();
         }
         let continue_jump = ctx.add_instruction_with_jump_slot(Instruction::Jump);
         ctx.current_continue.as_mut().unwrap().push(continue_jump);
@@ -2936,6 +3638,7 @@ impl CompileEvaluation for ast::ContinueStatement<'_> {
 
 impl CompileEvaluation for ast::Statement<'_> {
     fn compile(&self, ctx: &mut CompileContext) {
+        let previous_source_position = ctx.enter_source_position(self.span());
         match self {
             ast::Statement::ExpressionStatement(x) => x.compile(ctx),
             ast::Statement::ReturnStatement(x) => x.compile(ctx),
@@ -2949,14 +3652,16 @@ impl CompileEvaluation for ast::Statement<'_> {
             ast::Statement::TryStatement(x) => x.compile(ctx),
             Statement::BreakStatement(statement) => statement.compile(ctx),
             Statement::ContinueStatement(statement) => statement.compile(ctx),
-            Statement::DebuggerStatement(_) => todo!(),
+            Statement::DebuggerStatement(_) => {
+                ctx.add_instruction(Instruction::Debug);
+            }
             Statement::DoWhileStatement(statement) => statement.compile(ctx),
             Statement::ForInStatement(statement) => statement.compile(ctx),
             Statement::ForOfStatement(statement) => statement.compile(ctx),
             Statement::LabeledStatement(_) => todo!(),
             Statement::SwitchStatement(statement) => statement.compile(ctx),
             Statement::WhileStatement(statement) => statement.compile(ctx),
-            Statement::WithStatement(_) => todo!(),
+            Statement::WithStatement(statement) => statement.compile(ctx),
             Statement::ClassDeclaration(x) => x.compile(ctx),
             Statement::ImportDeclaration(_) => todo!(),
             Statement::ExportAllDeclaration(_) => todo!(),
@@ -2974,17 +3679,6 @@ impl CompileEvaluation for ast::Statement<'_> {
             | Statement::TSModuleDeclaration(_)
             | Statement::TSNamespaceExportDeclaration(_) => unreachable!(),
         }
-    }
-}
-
-fn is_anonymous_function_definition(expression: &ast::Expression) -> bool {
-    match expression {
-        ast::Expression::ArrowFunctionExpression(_) => true,
-        ast::Expression::FunctionExpression(f) => f.id.is_none(),
-        ast::Expression::ClassExpression(f) => f.id.is_none(),
-        ast::Expression::ParenthesizedExpression(x) => {
-            is_anonymous_function_definition(&x.expression)
-        }
-        _ => false,
+        ctx.exit_source_position(previous_source_position);
     }
 }
@@ -33,6 +33,16 @@ pub(super) fn instantiation<'a>(
     // 2. Let privateEnv be the running execution context's PrivateEnvironment.
     // 3. For each element d of declarations, do
     code.lexically_scoped_declarations(&mut |d| {
+        if let LexicallyScopedDeclaration::Variable(decl) = d {
+            if decl
+                .id
+                .get_binding_identifier()
+                .is_some_and(|identifier| ctx.local_register(&identifier.name).is_some())
+            {
+                // Bindings held in registers need no environment.
+                return;
+            }
+        }
         if !did_enter_declarative_environment {
             did_enter_declarative_environment = true;
             ctx.add_instruction(Instruction::EnterDeclarativeEnvironment);
@@ -53,7 +63,7 @@ pub fn handle_block_lexically_scoped_declaration(
 ) {
     match d {
         // a. For each element dn of the BoundNames of d, do
-        LexicallyScopedDeclaration::Variable(decl) if decl.kind.is_const() => {
+        LexicallyScopedDeclaration::Variable(decl) if d.is_constant_declaration() => {
             // i. If IsConstantDeclaration of d is true, then
             decl.id.bound_names(&mut |identifier| {
                 let dn = String::from_str(ctx.agent, &identifier.name, ctx.gc);
@@ -24,6 +24,8 @@ use ahash::{AHashMap, AHashSet};
 use oxc_ast::ast::{self, MethodDefinitionKind};
 use oxc_ecmascript::{BoundNames, PrivateBoundIdentifiers, PropName};
 
+#[cfg(feature = "proposal-explicit-resource-management")]
+use super::using_declaration;
 use super::IndexType;
 
 impl CompileEvaluation for ast::Class<'_> {
@@ -164,7 +166,7 @@ impl CompileEvaluation for ast::Class<'_> {
                 ctx.set_jump_target_here(jump_over_throw);
                 // i. Let protoParent be ? Get(superclass, "prototype").
                 ctx.add_instruction(Instruction::StoreCopy);
-                ctx.add_instruction_with_identifier(
+                ctx.add_instruction_with_identifier_and_property_lookup_cache(
                     Instruction::EvaluatePropertyAccessWithIdentifierKey,
                     BUILTIN_STRING_MEMORY.prototype,
                 );
@@ -712,7 +714,7 @@ impl CompileEvaluation for ast::StaticBlock<'_> {
             // b. For each element dn of the BoundNames of d, do
             match d {
                 // i. If IsConstantDeclaration of d is true, then
-                LexicallyScopedDeclaration::Variable(decl) if decl.kind.is_const() => {
+                LexicallyScopedDeclaration::Variable(decl) if d.is_constant_declaration() => {
                     {
                         decl.id.bound_names(&mut |identifier| {
                             let dn = String::from_str(ctx.agent, &identifier.name, ctx.gc);
@@ -759,9 +761,13 @@ impl CompileEvaluation for ast::StaticBlock<'_> {
             ctx.add_instruction(Instruction::PutValue);
         }
 
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let dispose_scope = using_declaration::enter(ctx, &self.body);
         for statement in self.body.iter() {
             statement.compile(ctx);
         }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        using_declaration::exit(ctx, dispose_scope);
         ctx.add_instruction(Instruction::ExitDeclarativeEnvironment);
         if let Some(i) = ctx.current_depth_of_loop_scope.as_mut() {
             *i -= 1;
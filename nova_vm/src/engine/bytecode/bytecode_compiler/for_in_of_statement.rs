@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(feature = "proposal-explicit-resource-management")]
+use super::using_declaration;
 use super::{is_reference, CompileContext, CompileEvaluation, Instruction, JumpIndex};
 use crate::ecmascript::types::{String, Value};
 use oxc_ast::ast;
@@ -157,6 +159,8 @@ fn for_in_of_body_evaluation(
     let previous_depth_of_loop = ctx.current_depth_of_loop_scope.replace(0);
     let previous_continue = ctx.current_continue.replace(vec![]);
     let previous_break = ctx.current_break.replace(vec![]);
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    let previous_dispose_scopes = ctx.enter_loop_dispose_scopes();
 
     // 6. Repeat,
     let repeat_jump = ctx.get_jump_index_to_here();
@@ -168,6 +172,8 @@ fn for_in_of_body_evaluation(
     // f. Let nextValue be ? IteratorValue(nextResult).
     let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::IteratorStepValue);
     let mut entered_declarative_environment = false;
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    let mut dispose_scope = None;
     // g. If lhsKind is either ASSIGNMENT or VAR-BINDING, then
     match lhs_kind {
         LeftHandSideKind::Assignment | LeftHandSideKind::VarBinding => {
@@ -233,10 +239,14 @@ fn for_in_of_body_evaluation(
             let ast::ForStatementLeft::VariableDeclaration(lhs) = lhs else {
                 unreachable!()
             };
-            assert!(lhs.kind.is_lexical());
+            assert!(!lhs.kind.is_var());
             // iii. Let iterationEnv be NewDeclarativeEnvironment(oldEnv).
             // iv. Perform ForDeclarationBindingInstantiation of lhs with argument iterationEnv.
             lhs.bound_names(&mut |binding_identifier| {
+                if ctx.local_register(&binding_identifier.name).is_some() {
+                    // Bindings held in registers need no environment.
+                    return;
+                }
                 if !entered_declarative_environment {
                     // Optimization: Only enter declarative environment if
                     // bound names exist.
@@ -249,7 +259,7 @@ fn for_in_of_body_evaluation(
                 let identifier =
                     String::from_str(ctx.agent, binding_identifier.name.as_str(), ctx.gc);
                 ctx.add_instruction_with_identifier(
-                    if lhs.kind.is_const() {
+                    if lhs.kind != ast::VariableDeclarationKind::Let {
                         Instruction::CreateImmutableBinding
                     } else {
                         Instruction::CreateMutableBinding
@@ -264,6 +274,20 @@ fn for_in_of_body_evaluation(
                 todo!();
             } else {
                 // vii. Else,
+                #[cfg(feature = "proposal-explicit-resource-management")]
+                {
+                    // NOTE: The resources of a using declaration are
+                    // disposed of at the end of each iteration.
+                    dispose_scope = using_declaration::enter_for_declaration(ctx, lhs);
+                    if dispose_scope.is_some() {
+                        let scope = *ctx.dispose_scopes.last().unwrap();
+                        ctx.add_instruction_with_immediate_and_immediate(
+                            Instruction::AddDisposableResource,
+                            scope.register.into(),
+                            scope.is_async.into(),
+                        );
+                    }
+                }
                 // 1. Assert: lhs binds a single name.
                 let mut bound = false;
                 lhs.bound_names(&mut |binding_identifier| {
@@ -293,6 +317,8 @@ fn for_in_of_body_evaluation(
 
     // j. Let result be Completion(Evaluation of stmt).
     stmt.compile(ctx);
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    using_declaration::exit(ctx, dispose_scope);
 
     // k. Set the running execution context's LexicalEnvironment to oldEnv.
     // l. Corollary: If LoopContinues(result, labelSet) is true, then
@@ -325,6 +351,8 @@ fn for_in_of_body_evaluation(
         ctx.set_jump_target_here(break_entry);
     }
     ctx.current_depth_of_loop_scope = previous_depth_of_loop;
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    ctx.exit_loop_dispose_scopes(previous_dispose_scopes);
     // i. If iterationKind is ENUMERATE, then
     if iteration_kind == IterationKind::Enumerate {
         // 1. Return ? UpdateEmpty(result, V).
@@ -356,8 +384,13 @@ impl CompileEvaluation for ast::ForInStatement<'_> {
 
         let lhs_kind = match &self.left {
             ast::ForStatementLeft::VariableDeclaration(var_decl) => {
-                if var_decl.kind.is_lexical() {
+                if !var_decl.kind.is_var() {
                     var_decl.bound_names(&mut |binding_identifier| {
+                        // Bindings held in registers cannot be referenced
+                        // in the expression.
+                        if ctx.local_register(&binding_identifier.name).is_some() {
+                            return;
+                        }
                         uninitialized_bound_names.push(String::from_str(
                             ctx.agent,
                             binding_identifier.name.as_str(),
@@ -410,8 +443,13 @@ impl CompileEvaluation for ast::ForOfStatement<'_> {
 
         let lhs_kind = match &self.left {
             ast::ForStatementLeft::VariableDeclaration(var_decl) => {
-                if var_decl.kind.is_lexical() {
+                if !var_decl.kind.is_var() {
                     var_decl.bound_names(&mut |binding_identifier| {
+                        // Bindings held in registers cannot be referenced
+                        // in the expression.
+                        if ctx.local_register(&binding_identifier.name).is_some() {
+                            return;
+                        }
                         uninitialized_bound_names.push(String::from_str(
                             ctx.agent,
                             binding_identifier.name.as_str(),
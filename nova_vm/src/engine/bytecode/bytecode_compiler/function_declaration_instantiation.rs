@@ -4,7 +4,7 @@
 
 use ahash::{AHashMap, AHashSet};
 use oxc_ast::ast::{FormalParameters, FunctionBody};
-use oxc_ecmascript::BoundNames;
+use oxc_ecmascript::{BoundNames, IsSimpleParameterList};
 use oxc_span::Atom;
 
 use crate::{
@@ -107,6 +107,10 @@ pub(crate) fn instantiation(
         // b. NOTE: Early errors ensure that duplicate parameter names can only occur in non-strict functions that do not have parameter default values or rest parameters.
         // c. If alreadyDeclared is false, then
         // NOTE: Since `parameter_names` is a set, `alreadyDeclared` here should always be false.
+        if ctx.local_register(param_name).is_some() {
+            // Parameters held in registers need no environment binding.
+            continue;
+        }
 
         // i. Perform ! env.CreateMutableBinding(paramName, false).
         let param_name = String::from_str(ctx.agent, param_name, ctx.gc);
@@ -127,9 +131,20 @@ pub(crate) fn instantiation(
         // b. Else,
         //     i. NOTE: A mapped argument object is only provided for non-strict functions that don't have a rest parameter, any parameter default value initializers, or any destructured parameters.
         //     ii. Let ao be CreateMappedArgumentsObject(func, formals, argumentsList, env).
-        // TODO: Currently, we always create an unmapped arguments object, even
-        // in non-strict mode.
-        ctx.add_instruction(Instruction::CreateUnmappedArgumentsObject);
+        // NOTE: An unmapped arguments object is also created if parameters
+        // are held in registers. Parameters are only held in registers if the
+        // function never refers to its arguments object, so the mapping could
+        // not be observed.
+        if strict
+            || !formals.is_simple_parameter_list()
+            || parameter_names
+                .iter()
+                .any(|param_name| ctx.local_register(param_name).is_some())
+        {
+            ctx.add_instruction(Instruction::CreateUnmappedArgumentsObject);
+        } else {
+            ctx.add_instruction(Instruction::CreateMappedArgumentsObject);
+        }
 
         // c. If strict is true, then
         if strict {
@@ -196,6 +211,11 @@ pub(crate) fn instantiation(
             if instantiated_var_names.contains(&n) || parameter_names.contains(&n) {
                 continue;
             }
+            if ctx.local_register(&n).is_some() {
+                // Variables held in registers start out as undefined and need
+                // no environment binding.
+                continue;
+            }
             // 1. Append n to instantiatedVarNames.
             let n_string = String::from_str(ctx.agent, &n, ctx.gc);
             instantiated_var_names.insert(n);
@@ -232,13 +252,20 @@ pub(crate) fn instantiation(
 
         // d. Let instantiatedVarNames be a new empty List.
         let mut instantiated_var_names = AHashSet::new();
+        let mut var_names = Vec::new();
         // e. For each element n of varNames, do
         for n in function_body_var_declared_names(body) {
             // i. If instantiatedVarNames does not contain n, then
             if instantiated_var_names.contains(&n) {
                 continue;
             }
+            if ctx.local_register(&n).is_some() {
+                // Variables held in registers start out as undefined and need
+                // no environment binding.
+                continue;
+            }
             // 1. Append n to instantiatedVarNames.
+            var_names.push(n);
             instantiated_var_names.insert(n);
             // 3. If parameterBindings does not contain n, or if functionNames contains n, then
             let n_string = String::from_str(ctx.agent, &n, ctx.gc);
@@ -274,6 +301,15 @@ pub(crate) fn instantiation(
             instantiated_var_names.len(),
             strict.into(),
         );
+        // Note: The variables are popped from the stack, and thus created
+        // in reverse order.
+        ctx.static_scopes.enter();
+        for n in var_names.iter().rev() {
+            ctx.static_scopes.declare(n);
+        }
+        if !strict {
+            ctx.static_scopes.enter();
+        }
     }
 
     // 33. Let lexDeclarations be the LexicallyScopedDeclarations of code.
@@ -283,7 +319,13 @@ pub(crate) fn instantiation(
         // b. For each element dn of the BoundNames of d, do
         match d {
             // i. If IsConstantDeclaration of d is true, then
-            LexicallyScopedDeclaration::Variable(decl) if decl.kind.is_const() => {
+            // Bindings held in registers need no environment binding.
+            LexicallyScopedDeclaration::Variable(decl)
+                if decl
+                    .id
+                    .get_binding_identifier()
+                    .is_some_and(|identifier| ctx.local_register(&identifier.name).is_some()) => {}
+            LexicallyScopedDeclaration::Variable(decl) if d.is_constant_declaration() => {
                 decl.id.bound_names(&mut |identifier| {
                     let dn = String::from_str(ctx.agent, &identifier.name, ctx.gc);
                     // 1. Perform ! lexEnv.CreateImmutableBinding(dn, true).
@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [14.3.1 Let, Const, Using, and Await Using Declarations](https://tc39.es/proposal-explicit-resource-management/#sec-let-const-using-and-await-using-declarations)
+//!
+//! A scope containing `using` or `await using` declarations keeps its
+//! DisposeCapability in a register. The scope's code runs inside an
+//! exception jump target that disposes of the resources with the thrown
+//! value and rethrows, while normal completion of the scope and `break`,
+//! `continue` and `return` statements dispose of the resources before
+//! leaving the scope.
+
+use oxc_ast::ast;
+
+use crate::ecmascript::types::Value;
+
+use super::{CompileContext, IndexType, Instruction, JumpIndex};
+
+/// A scope containing `using` or `await using` declarations that the
+/// instructions being built are in.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct DisposeScope {
+    /// Register holding the DisposeCapability of the scope.
+    pub(super) register: IndexType,
+    /// True if the scope contains `await using` declarations.
+    pub(super) is_async: bool,
+}
+
+/// Handle to a dispose scope entered by [`enter`], to be passed to [`exit`].
+pub(super) struct DisposeScopeHandle {
+    handler: JumpIndex,
+}
+
+/// Returns the kind of the using declarations among the statements: None if
+/// there are none, Some(true) if any of them is an `await using`
+/// declaration, and Some(false) otherwise.
+fn using_declarations_kind<'a>(
+    statements: impl IntoIterator<Item = &'a ast::Statement<'a>>,
+) -> Option<bool> {
+    let mut kind = None;
+    for statement in statements {
+        if let ast::Statement::VariableDeclaration(decl) = statement {
+            match decl.kind {
+                ast::VariableDeclarationKind::AwaitUsing => return Some(true),
+                ast::VariableDeclarationKind::Using => kind = Some(false),
+                _ => {}
+            }
+        }
+    }
+    kind
+}
+
+/// Enter a dispose scope if the statements of a block, function body, case
+/// block or class static block contain using declarations.
+pub(super) fn enter<'a>(
+    ctx: &mut CompileContext,
+    statements: impl IntoIterator<Item = &'a ast::Statement<'a>>,
+) -> Option<DisposeScopeHandle> {
+    let is_async = using_declarations_kind(statements)?;
+    Some(enter_scope(ctx, is_async))
+}
+
+/// Enter a dispose scope if the declaration in the head of a `for`,
+/// `for-in` or `for-of` statement is a using declaration.
+pub(super) fn enter_for_declaration(
+    ctx: &mut CompileContext,
+    decl: &ast::VariableDeclaration,
+) -> Option<DisposeScopeHandle> {
+    match decl.kind {
+        ast::VariableDeclarationKind::Using => Some(enter_scope(ctx, false)),
+        ast::VariableDeclarationKind::AwaitUsing => Some(enter_scope(ctx, true)),
+        _ => None,
+    }
+}
+
+fn enter_scope(ctx: &mut CompileContext, is_async: bool) -> DisposeScopeHandle {
+    // Set env.[[DisposeCapability]] to NewDisposeCapability().
+    let register = ctx.allocate_register();
+    ctx.add_instruction_with_immediate_and_immediate(
+        Instruction::CreateDisposeCapability,
+        register.into(),
+        is_async.into(),
+    );
+    let handler = ctx.add_instruction_with_jump_slot(Instruction::PushExceptionJumpTarget);
+    ctx.dispose_scopes.push(DisposeScope { register, is_async });
+    DisposeScopeHandle { handler }
+}
+
+/// Exit a dispose scope at the end of its code.
+///
+/// > 1. Let result be Completion(Evaluation of the scope's code).
+/// > 2. Set result to Completion(DisposeResources(env.[[DisposeCapability]], result)).
+/// > 3. Return ? result.
+pub(super) fn exit(ctx: &mut CompileContext, handle: Option<DisposeScopeHandle>) {
+    let Some(DisposeScopeHandle { handler }) = handle else {
+        return;
+    };
+    let DisposeScope { register, is_async } = ctx.dispose_scopes.pop().unwrap();
+    // The code completed normally.
+    ctx.add_instruction(Instruction::PopExceptionJumpTarget);
+    add_dispose_resources(ctx, register, is_async, false);
+    let jump_to_end = ctx.add_instruction_with_jump_slot(Instruction::Jump);
+    // The code completed with a throw completion: DisposeResources rethrows
+    // the thrown value or a SuppressedError wrapping it.
+    ctx.set_jump_target_here(handler);
+    add_dispose_resources(ctx, register, is_async, true);
+    ctx.set_jump_target_here(jump_to_end);
+    ctx.release_register(register);
+}
+
+/// Dispose of the resources of the dispose scopes that a `break;` or
+/// `continue;` statement jumps out of. The scopes entered before the jump
+/// target's statement are kept.
+pub(super) fn dispose_on_jump(ctx: &mut CompileContext, outer_scope_count: usize) {
+    for index in (outer_scope_count..ctx.dispose_scopes.len()).rev() {
+        let DisposeScope { register, is_async } = ctx.dispose_scopes[index];
+        ctx.add_instruction(Instruction::PopExceptionJumpTarget);
+        add_dispose_resources(ctx, register, is_async, false);
+    }
+}
+
+/// Dispose of the resources of all dispose scopes before a `return`
+/// statement. The return value in the result value is kept.
+pub(super) fn dispose_on_return(ctx: &mut CompileContext) {
+    if ctx.dispose_scopes.is_empty() {
+        return;
+    }
+    // Await overwrites the result value, so the return value is kept in a
+    // register while asynchronous scopes are disposed of.
+    let return_value = if ctx.dispose_scopes.iter().any(|scope| scope.is_async) {
+        let register = ctx.allocate_register();
+        ctx.add_instruction_with_register(Instruction::SetRegister, register);
+        Some(register)
+    } else {
+        None
+    };
+    dispose_on_jump(ctx, 0);
+    if let Some(register) = return_value {
+        ctx.add_instruction_with_register(Instruction::GetRegister, register);
+        ctx.release_register(register);
+    }
+}
+
+/// Perform a DisposeResources with a normal completion or a throw
+/// completion of the result value, awaiting it if the scope is
+/// asynchronous.
+fn add_dispose_resources(
+    ctx: &mut CompileContext,
+    register: IndexType,
+    is_async: bool,
+    is_throw: bool,
+) {
+    ctx.add_instruction_with_immediate_and_immediate(
+        Instruction::DisposeResources,
+        register.into(),
+        is_throw.into(),
+    );
+    if is_async {
+        ctx.add_instruction(Instruction::Await);
+        if is_throw {
+            // The promise is always rejected, so Await throws.
+            ctx.add_instruction_with_constant(Instruction::StoreConstant, Value::Undefined);
+            ctx.add_instruction(Instruction::Throw);
+        }
+    }
+}
@@ -0,0 +1,338 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Disassembly of bytecode into readable text.
+//!
+//! Each Executable is listed with its constants and instructions, followed by
+//! the Executables of the functions, arrow functions and class field
+//! initializers it creates. Instructions are annotated with the source lines
+//! they were compiled from and marked when they are jump targets.
+//!
+//! Functions are compiled lazily when first called, so the disassembler
+//! compiles the functions that do not have bytecode yet. The bytecode is the
+//! same that calling the functions would compile.
+
+use core::fmt::Write;
+
+use oxc_ast::ast;
+
+use super::{
+    instructions::{debug_print_binding_pattern, debug_print_constant, Instr},
+    Executable, FunctionExpression, Instruction, InstructionIter,
+};
+use crate::{
+    ecmascript::{
+        execution::Agent,
+        scripts_and_modules::source_code::{LineStarts, SourceCode},
+        syntax_directed_operations::{
+            function_definitions::CompileFunctionBodyData, function_names::FunctionNames,
+        },
+    },
+    engine::context::NoGcScope,
+};
+
+/// Writes the disassembly of Executables compiled from a source code.
+struct Disassembler<'a, 'gc, 'scope> {
+    agent: &'a mut Agent,
+    source: Option<(SourceCode, LineStarts)>,
+    /// Names of the functions and classes of the source code.
+    names: FunctionNames,
+    gc: NoGcScope<'gc, 'scope>,
+    output: std::string::String,
+}
+
+impl<'a, 'gc, 'scope> Disassembler<'a, 'gc, 'scope> {
+    /// Creates a Disassembler. Instructions are annotated with source lines
+    /// if the source code is given.
+    fn new(
+        agent: &'a mut Agent,
+        source_code: Option<SourceCode>,
+        names: FunctionNames,
+        gc: NoGcScope<'gc, 'scope>,
+    ) -> Self {
+        let source = source_code.map(|source_code| {
+            let line_starts = LineStarts::new(source_code.get_source_text(agent));
+            (source_code, line_starts)
+        });
+        Self {
+            agent,
+            source,
+            names,
+            gc,
+            output: std::string::String::new(),
+        }
+    }
+
+    fn finish(self) -> std::string::String {
+        self.output
+    }
+
+    /// Writes the disassembly of the Executable and the Executables it
+    /// creates. Functions created by strict code are strict.
+    fn write_executable(&mut self, title: &str, executable: Executable, is_strict: bool) {
+        let agent = &*self.agent;
+        let instructions = executable.get_instructions(agent);
+        let constant_count = executable.get_constants(agent).len();
+        let _ = writeln!(self.output, "== {} ==", title);
+        let _ = writeln!(
+            self.output,
+            "registers: {}, constants: {}, instructions: {} bytes",
+            agent[executable].register_count,
+            constant_count,
+            instructions.len()
+        );
+        for index in 0..constant_count {
+            let constant = debug_print_constant(self.agent, executable, index, self.gc);
+            let _ = writeln!(self.output, "  #{}: {}", index, constant);
+        }
+
+        let mut jump_targets = InstructionIter::new(instructions)
+            .filter(|(_, instr)| instr.kind.has_jump_slot())
+            .map(|(_, instr)| instr.args[0].unwrap() as usize)
+            .collect::<Vec<_>>();
+        jump_targets.sort_unstable();
+        let mut line = None;
+        for (ip, instr) in InstructionIter::new(instructions) {
+            if let Some((source_code, line_starts)) = &self.source {
+                let instruction_line = executable
+                    .get_source_offset(self.agent, ip)
+                    .map(|offset| line_starts.line(offset));
+                if instruction_line.is_some() && instruction_line != line {
+                    line = instruction_line;
+                    let line = line.unwrap();
+                    let text = line_starts.line_text(source_code.get_source_text(self.agent), line);
+                    let _ = writeln!(self.output, "  ; {}: {}", line, text.trim());
+                }
+            }
+            let marker = if jump_targets.binary_search(&ip).is_ok() {
+                "->"
+            } else {
+                "  "
+            };
+            let args = self.print_args(ip, executable, &instr);
+            let _ = write!(
+                self.output,
+                "  {} {:>5}: {:?}{}",
+                marker, ip, instr.kind, args
+            );
+            // Point to the disassembly of the created Executables.
+            match instr.kind {
+                Instruction::InstantiateArrowFunctionExpression => {
+                    let _ = write!(self.output, "  ; arrow #{}", instr.args[0].unwrap());
+                }
+                Instruction::ClassDefineDefaultConstructor => {
+                    let _ = write!(self.output, "  ; initializer #{}", instr.args[0].unwrap());
+                }
+                kind if kind.has_function_expression_index() => {
+                    let _ = write!(self.output, "  ; function #{}", instr.args[0].unwrap());
+                }
+                _ => {}
+            }
+            self.output.push('\n');
+        }
+        self.output.push('\n');
+
+        let function_count = self.agent[executable].function_expressions.len();
+        for index in 0..function_count {
+            let FunctionExpression {
+                expression,
+                compiled_bytecode,
+                ..
+            } = executable.fetch_function_expression(self.agent, index);
+            let function = expression.get();
+            let compiled_bytecode = *compiled_bytecode;
+            let title = format!("{} > function #{}", title, index);
+            self.write_function(&title, function, compiled_bytecode, is_strict);
+        }
+        let arrow_function_count = self.agent[executable].arrow_function_expressions.len();
+        for index in 0..arrow_function_count {
+            let arrow_function = executable
+                .fetch_arrow_function_expression(self.agent, index)
+                .expression
+                .get();
+            let title = format!(
+                "{} > arrow #{} {}({}) =>{}",
+                title,
+                index,
+                self.names
+                    .get(arrow_function.span.start)
+                    .map_or(std::string::String::new(), |name| format!("{} ", name)),
+                arrow_function
+                    .params
+                    .iter_bindings()
+                    .map(debug_print_binding_pattern)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                self.line_suffix(arrow_function.span.start)
+            );
+            let is_strict = is_strict || arrow_function.body.has_use_strict_directive();
+            let executable = Executable::compile_function_body(
+                self.agent,
+                CompileFunctionBodyData {
+                    params: &arrow_function.params,
+                    body: &arrow_function.body,
+                    is_strict,
+                    is_lexical: true,
+                    is_concise_body: arrow_function.expression,
+                },
+                self.gc,
+            );
+            self.write_executable(&title, executable, is_strict);
+        }
+        let initializer_count = self.agent[executable].class_initializer_bytecodes.len();
+        for index in 0..initializer_count {
+            let (initializer, _) = executable.fetch_class_initializer_bytecode(self.agent, index);
+            if let Some(initializer) = initializer {
+                let title = format!("{} > initializer #{}", title, index);
+                // Class code is always strict.
+                self.write_executable(&title, initializer, true);
+            }
+        }
+    }
+
+    /// Writes the disassembly of the function's bytecode, compiling it if
+    /// it has none.
+    fn write_function(
+        &mut self,
+        title: &str,
+        function: &'static ast::Function<'static>,
+        compiled_bytecode: Option<Executable>,
+        is_strict: bool,
+    ) {
+        let Some(body) = function.body.as_deref() else {
+            return;
+        };
+        let title = format!(
+            "{} {}({}){}",
+            title,
+            self.function_name(function.span.start),
+            function
+                .params
+                .iter_bindings()
+                .map(debug_print_binding_pattern)
+                .collect::<Vec<_>>()
+                .join(", "),
+            self.line_suffix(function.span.start)
+        );
+        let is_strict = is_strict || body.has_use_strict_directive();
+        let executable = compiled_bytecode.unwrap_or_else(|| {
+            Executable::compile_function_body(
+                self.agent,
+                CompileFunctionBodyData {
+                    params: &function.params,
+                    body,
+                    is_strict,
+                    is_lexical: false,
+                    is_concise_body: false,
+                },
+                self.gc,
+            )
+        });
+        self.write_executable(&title, executable, is_strict);
+    }
+
+    /// Returns the name of the function or class that starts at the offset.
+    fn function_name(&self, start: u32) -> &str {
+        self.names.get(start).unwrap_or("anonymous")
+    }
+
+    /// Formats the name and parameters of the function at the index of the
+    /// Executable's function expressions.
+    fn function_signature(
+        &self,
+        executable: Executable,
+        index: usize,
+    ) -> (&str, std::string::String) {
+        let function = executable
+            .fetch_function_expression(self.agent, index)
+            .expression
+            .get();
+        let parameters = function
+            .params
+            .iter_bindings()
+            .map(debug_print_binding_pattern)
+            .collect::<Vec<_>>()
+            .join(", ");
+        (self.function_name(function.span.start), parameters)
+    }
+
+    /// Prints the instruction's arguments like [`Instr::print_args`], with
+    /// the names of the functions and classes that the instruction creates.
+    fn print_args(
+        &mut self,
+        ip: usize,
+        executable: Executable,
+        instr: &Instr,
+    ) -> std::string::String {
+        let index = instr.args[0].map(|arg| arg as usize);
+        match instr.kind {
+            Instruction::InstantiateOrdinaryFunctionExpression
+            | Instruction::ObjectDefineMethod
+            | Instruction::ObjectDefineGetter
+            | Instruction::ObjectDefineSetter => {
+                let prefix = match instr.kind {
+                    Instruction::ObjectDefineGetter => "get ",
+                    Instruction::ObjectDefineSetter => "set ",
+                    _ => "",
+                };
+                let (name, parameters) = self.function_signature(executable, index.unwrap());
+                format!("({}function {}({}))", prefix, name, parameters)
+            }
+            Instruction::ClassDefineConstructor => {
+                // The constructor is named by its class.
+                let (name, parameters) = self.function_signature(executable, index.unwrap());
+                let is_derived = instr.args[1] == Some(1);
+                format!(
+                    "(class {} constructor({}){})",
+                    name,
+                    parameters,
+                    if is_derived { " { super() }" } else { "" }
+                )
+            }
+            Instruction::ClassDefineDefaultConstructor => {
+                // Note: Default constructors have no source code of their
+                // own, but the instruction is at the start of its class.
+                let name = executable
+                    .get_source_offset(self.agent, ip)
+                    .map_or("anonymous", |offset| self.function_name(offset));
+                let (_, is_derived) =
+                    executable.fetch_class_initializer_bytecode(self.agent, index.unwrap());
+                format!(
+                    "(class {} {})",
+                    name,
+                    if is_derived { "{ super() }" } else { "{}" }
+                )
+            }
+            _ => instr.print_args(self.agent, executable, self.gc),
+        }
+    }
+
+    /// Formats the line of the source offset for titles.
+    fn line_suffix(&self, offset: u32) -> std::string::String {
+        match &self.source {
+            Some((_, line_starts)) => format!(" (line {})", line_starts.line(offset)),
+            None => std::string::String::new(),
+        }
+    }
+}
+
+impl Executable {
+    /// Disassembles the Executable and the Executables it creates into
+    /// readable text under the title, annotated with source lines if the
+    /// source code is given. Functions and classes are named with the names
+    /// of the source code. Functions created by strict code are strict.
+    pub(crate) fn disassemble(
+        self,
+        agent: &mut Agent,
+        title: &str,
+        source_code: Option<SourceCode>,
+        names: FunctionNames,
+        is_strict: bool,
+        gc: NoGcScope,
+    ) -> std::string::String {
+        let mut disassembler = Disassembler::new(agent, source_code, names, gc);
+        disassembler.write_executable(title, self, is_strict);
+        disassembler.finish()
+    }
+}
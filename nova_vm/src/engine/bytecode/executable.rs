@@ -8,12 +8,13 @@ use core::{
 };
 
 use super::{
-    instructions::Instr, CompileContext, CompileEvaluation, Instruction, NamedEvaluationParameter,
+    instructions::Instr, source_positions::SourcePositionTable, CompileContext, CompileEvaluation,
+    Instruction, NamedEvaluationParameter, PropertyLookupCache,
 };
 use crate::{
     ecmascript::{
         execution::Agent,
-        scripts_and_modules::script::ScriptIdentifier,
+        scripts_and_modules::{script::ScriptIdentifier, source_code::SourceCode},
         syntax_directed_operations::function_definitions::CompileFunctionBodyData,
         types::{String, Value},
     },
@@ -87,7 +88,7 @@ pub(crate) struct ArrowFunctionExpression {
 }
 
 /// Reference to a heap-allocated executable VM bytecode.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub(crate) struct Executable(NonZeroU32);
 
@@ -98,13 +99,21 @@ const EXECUTABLE_OPTION_SIZE_IS_U32: () =
 ///
 /// - This is inspired by and/or copied from Kiesel engine:
 ///   Copyright (c) 2023-2024 Linus Groh
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub(crate) struct ExecutableHeapData {
     pub instructions: Box<[u8]>,
     pub(crate) constants: Box<[Value]>,
     pub(crate) function_expressions: Box<[FunctionExpression]>,
     pub(crate) arrow_function_expressions: Box<[ArrowFunctionExpression]>,
     pub(crate) class_initializer_bytecodes: Box<[(Option<Executable>, bool)]>,
+    /// Inline caches of the property access instructions.
+    pub(crate) property_lookup_caches: Box<[PropertyLookupCache]>,
+    /// Size of the register file of the frames running this Executable.
+    pub(crate) register_count: usize,
+    /// Source offsets of the instructions.
+    pub(crate) source_positions: SourcePositionTable,
+    /// Sorted instruction offsets that start basic blocks.
+    pub(crate) block_starts: Box<[u32]>,
 }
 
 impl Executable {
@@ -123,6 +132,7 @@ impl Executable {
         let body: &[Statement] =
             unsafe { core::mem::transmute(agent[script].ecmascript_code.body.as_slice()) };
         let mut ctx = CompileContext::new(agent, gc);
+        ctx.static_scopes.analyse_statements(body);
 
         ctx.compile_statements(body);
         ctx.do_implicit_return();
@@ -134,7 +144,13 @@ impl Executable {
         data: CompileFunctionBodyData<'_>,
         gc: NoGcScope,
     ) -> Self {
+        // Note: The debugger inspects bindings through Environment Records,
+        // so functions compiled while it is enabled keep none in registers.
+        let debugger_enabled = agent.is_debugger_enabled();
         let mut ctx = CompileContext::new(agent, gc);
+        if !debugger_enabled {
+            ctx.allocate_local_registers(data.params, data.body);
+        }
 
         let is_concise = data.is_concise_body;
 
@@ -154,6 +170,7 @@ impl Executable {
             eprintln!();
         }
         let mut ctx = CompileContext::new(agent, gc);
+        ctx.static_scopes.analyse_statements(&program.body);
 
         // eval('"asd"') is parsed into an empty body with a single directive.
         // Multiple directives are also possible, but only the last one is
@@ -177,6 +194,10 @@ impl Executable {
     /// if the drop was performed.
     pub(crate) unsafe fn try_drop(self, agent: &mut Agent) {
         debug_assert!(!agent.heap.executables.is_empty());
+        if agent.coverage.is_some() {
+            // Code coverage keeps the counters of the Executable.
+            return;
+        }
         let index = self.get_index();
         let last_index = agent.heap.executables.len() - 1;
         if last_index == index {
@@ -194,7 +215,7 @@ impl Executable {
     /// SAFETY: The returned reference is valid until the Executable is garbage
     /// collected.
     #[inline]
-    pub(super) fn get_instructions(self, agent: &Agent) -> &'static [u8] {
+    pub(crate) fn get_instructions(self, agent: &Agent) -> &'static [u8] {
         // SAFETY: As long as we're alive the instructions Box lives, and it is
         // never accessed mutably.
         unsafe { core::mem::transmute(&agent[self].instructions[..]) }
@@ -207,6 +228,60 @@ impl Executable {
         get_instruction(&agent[self].instructions[..], ip)
     }
 
+    /// Get the sorted instruction offsets that start basic blocks: The first
+    /// instruction, jump targets and the instructions following jumps.
+    pub(crate) fn get_block_starts(self, agent: &Agent) -> &[u32] {
+        &agent[self].block_starts
+    }
+
+    /// Get the byte offset into the source code of the instruction at the
+    /// given instruction pointer.
+    pub(crate) fn get_source_offset(self, agent: &Agent, ip: usize) -> Option<u32> {
+        agent[self].source_positions.get(ip as u32)
+    }
+
+    /// Get the 1-based line and column of the instruction at the given
+    /// instruction pointer in the source code the Executable was compiled
+    /// from.
+    pub(crate) fn get_line_and_column(
+        self,
+        agent: &Agent,
+        source_code: SourceCode,
+        ip: usize,
+    ) -> Option<(u32, u32)> {
+        let offset = self.get_source_offset(agent, ip)?;
+        Some(source_code.get_line_and_column(agent, offset))
+    }
+
+    /// Get the 1-based source lines of the Executable's instructions as
+    /// (instruction offset, line) entries sorted by instruction offset. Each
+    /// entry marks the start of a run of instructions on the same line.
+    pub(crate) fn get_line_table(
+        self,
+        agent: &Agent,
+        source_code: SourceCode,
+    ) -> Box<[(u32, u32)]> {
+        let source_text = source_code.get_source_text(agent).as_bytes();
+        let mut entries = agent[self].source_positions.iter().collect::<Vec<_>>();
+        // Lines are counted in source order so that the source text is only
+        // scanned up to the last source offset once.
+        let mut order = (0..entries.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| entries[index].1);
+        let mut line = 1;
+        let mut counted = 0;
+        for index in order {
+            let offset = (entries[index].1 as usize).min(source_text.len());
+            line += source_text[counted..offset]
+                .iter()
+                .filter(|&&byte| byte == b'\n')
+                .count() as u32;
+            counted = offset;
+            entries[index].1 = line;
+        }
+        entries.dedup_by(|entry, previous| entry.1 == previous.1);
+        entries.into_boxed_slice()
+    }
+
     #[inline]
     pub(super) fn get_constants(self, agent: &Agent) -> &[Value] {
         &agent[self].constants[..]
@@ -321,6 +396,10 @@ impl Index<Executable> for Agent {
 
 impl IndexMut<Executable> for Agent {
     fn index_mut(&mut self, index: Executable) -> &mut Self::Output {
+        self.heap
+            .remembered_sets
+            .executables
+            .remember(index.get_index());
         self.heap
             .executables
             .get_mut(index.get_index())
@@ -331,6 +410,7 @@ impl IndexMut<Executable> for Agent {
 impl CreateHeapData<ExecutableHeapData, Executable> for Heap {
     fn create(&mut self, data: ExecutableHeapData) -> Executable {
         self.executables.push(data);
+        self.alloc_counter += core::mem::size_of::<ExecutableHeapData>();
         let index = u32::try_from(self.executables.len()).expect("Executables overflowed");
         // SAFETY: After pushing to executables, the vector cannot be empty.
         Executable(unsafe { NonZeroU32::new_unchecked(index) })
@@ -357,11 +437,18 @@ impl HeapMarkAndSweep for ExecutableHeapData {
             function_expressions: _,
             arrow_function_expressions: _,
             class_initializer_bytecodes,
+            property_lookup_caches,
+            register_count: _,
+            source_positions: _,
+            block_starts: _,
         } = self;
         constants.mark_values(queues);
         for ele in class_initializer_bytecodes {
             ele.0.mark_values(queues);
         }
+        for cache in property_lookup_caches.iter() {
+            cache.mark_values(queues);
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -371,11 +458,18 @@ impl HeapMarkAndSweep for ExecutableHeapData {
             function_expressions: _,
             arrow_function_expressions: _,
             class_initializer_bytecodes,
+            property_lookup_caches,
+            register_count: _,
+            source_positions: _,
+            block_starts: _,
         } = self;
         constants.sweep_values(compactions);
         for ele in class_initializer_bytecodes {
             ele.0.sweep_values(compactions);
         }
+        for cache in property_lookup_caches.iter_mut() {
+            cache.sweep_values(compactions);
+        }
     }
 }
 
@@ -19,7 +19,15 @@ use super::{Executable, IndexType};
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Instruction {
     Debug,
-    /// Store ApplyStringOrNumericBinaryOperator() as the result value.
+    /// Perform AddDisposableResource() with the result value on the
+    /// DisposeCapability in the register given as the first immediate,
+    /// without removing it as the result value. The second immediate is 1
+    /// for the async-dispose hint and 0 for the sync-dispose hint.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AddDisposableResource,
+    /// Store ApplyStringOrNumericBinaryOperator() as the result value, with
+    /// the left-hand side value being read from the register given as an
+    /// immediate and the right-hand side value being the result value.
     ApplyStringOrNumericBinaryOperator(BinaryOperator),
     /// Store ArrayCreate(0) as the result value.
     ///
@@ -40,10 +48,20 @@ pub enum Instruction {
     /// Create a catch binding for the given name and populate it with the
     /// stored exception.
     CreateCatchBinding,
+    /// Store NewDisposeCapability() in the register given as the first
+    /// immediate. The second immediate is 1 if the DisposeCapability belongs
+    /// to a scope containing `await using` declarations.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    CreateDisposeCapability,
     /// Performs CreateUnmappedArgumentsObject() on the arguments list present
     /// in the iterator stack, and stores the created arguments object as the
     /// result value.
     CreateUnmappedArgumentsObject,
+    /// Performs CreateMappedArgumentsObject() for the running function and its
+    /// Function Environment Record on the arguments list present in the
+    /// iterator stack, and stores the created arguments object as the result
+    /// value.
+    CreateMappedArgumentsObject,
     /// Performs CopyDataProperties() with the source being the result value and
     /// the target object being at the top of the stack. The excluded names list
     /// will be empty.
@@ -65,6 +83,16 @@ pub enum Instruction {
     /// This instruction has the number of argument values that need to be
     /// popped from the stack (last to first) as an argument.
     DirectEvalCall,
+    /// Perform DisposeResources() on the DisposeCapability in the register
+    /// given as the first immediate.
+    ///
+    /// If the second immediate is 1, the completion is a throw completion
+    /// with the result value as its value, and the instruction always
+    /// throws. Otherwise the completion is a normal completion and the result
+    /// value is kept. If the DisposeCapability is asynchronous, the result
+    /// value is instead set to a promise that is to be awaited.
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposeResources,
     /// Store EvaluateCall() as the result value.
     ///
     /// This instruction has the number of argument values that need to be
@@ -99,15 +127,32 @@ pub enum Instruction {
     /// property updates and function calls (where `this` comes from the
     /// reference).
     GetValueKeepReference,
-    /// Compare the last two values on the stack using the '>' operator rules.
+    /// Store the value of the register given as an immediate as the result
+    /// value.
+    GetRegister,
+    /// Store the value of a binding in the running execution context's
+    /// LexicalEnvironment as the result value. The immediate is the slot of
+    /// the binding.
+    GetLocal,
+    /// Store the value of a binding in an outer Environment Record of the
+    /// running execution context's LexicalEnvironment as the result value.
+    ///
+    /// The first immediate is the number of Environment Records to step out
+    /// of, the second immediate is the slot of the binding.
+    GetScoped,
+    /// Compare the register given as an immediate and the result value using
+    /// the '>' operator rules.
     GreaterThan,
-    /// Compare the last two values on the stack using the '>=' operator rules.
+    /// Compare the register given as an immediate and the result value using
+    /// the '>=' operator rules.
     GreaterThanEquals,
-    /// Store HasProperty() as the result value.
+    /// Store HasProperty() as the result value, with the property key being
+    /// read from the register given as an immediate.
     HasProperty,
     Increment,
     Decrement,
-    /// Store InstanceofOperator() as the result value.
+    /// Store InstanceofOperator() as the result value, with the value being
+    /// read from the register given as an immediate.
     InstanceofOperator,
     /// Store InstantiateArrowFunctionExpression() as the result value.
     InstantiateArrowFunctionExpression,
@@ -122,10 +167,11 @@ pub enum Instruction {
     /// Store CreateBuiltinFunction(defaultConstructor, 0, className) as the
     /// result value.
     ClassDefineDefaultConstructor,
-    /// Store IsLooselyEqual() as the result value.
+    /// Take the register given as an immediate and the result value, compare
+    /// them using IsLooselyEqual() and store the result as the result value.
     IsLooselyEqual,
-    /// Take the result value and the top stack value, compare them using
-    /// IsStrictlyEqual() and store the result as the result value.
+    /// Take the register given as an immediate and the result value, compare
+    /// them using IsStrictlyEqual() and store the result as the result value.
     IsStrictlyEqual,
     /// Store true as the result value if the current result value is null or
     /// undefined, false otherwise.
@@ -150,9 +196,11 @@ pub enum Instruction {
     /// Jump to another intrsuction by setting the instruction pointer if the
     /// current result is `true`.
     JumpIfTrue,
-    /// Compare the last two values on the stack using the '<' operator rules.
+    /// Compare the register given as an immediate and the result value using
+    /// the '<' operator rules.
     LessThan,
-    /// Compare the last two values on the stack using the '<=' operator rules.
+    /// Compare the register given as an immediate and the result value using
+    /// the '<=' operator rules.
     LessThanEquals,
     /// Load the result value and add it to the stack.
     Load,
@@ -198,12 +246,31 @@ pub enum Instruction {
     PutValue,
     /// Store ResolveBinding() as the result value.
     ResolveBinding,
+    /// Store a reference to a binding held in a register as the result value.
+    ///
+    /// The first immediate is the binding's identifier, the second immediate
+    /// is the register.
+    ResolveRegister,
     /// Store ResolveThisBinding() as the result value.
     ResolveThisBinding,
     /// Rethrow the stored exception, if any.
     RethrowExceptionIfAny,
     /// Stop bytecode execution, indicating a return from the current function.
     Return,
+    /// Copy the result value into the register given as an immediate,
+    /// without removing it as the result value.
+    SetRegister,
+    /// Call SetMutableBinding() on a binding in the running execution
+    /// context's LexicalEnvironment with the result value, without removing
+    /// it as the result value. The immediate is the slot of the binding.
+    SetLocal,
+    /// Call SetMutableBinding() on a binding in an outer Environment Record
+    /// of the running execution context's LexicalEnvironment with the result
+    /// value, without removing it as the result value.
+    ///
+    /// The first immediate is the number of Environment Records to step out
+    /// of, the second immediate is the slot of the binding.
+    SetScoped,
     /// Store the last value from the stack as the result value.
     Store,
     /// Store a copy of the last value from the stack as the result value.
@@ -265,6 +332,12 @@ pub enum Instruction {
     /// binding and \[\[FunctionObject]]. This is used for class static
     /// initializers.
     EnterClassStaticElementEnvironment,
+    /// Perform NewObjectEnvironment with the result value as the binding
+    /// object, true as the withEnvironment flag, and the running execution
+    /// context's LexicalEnvironment as the outer environment, and set it as
+    /// the running execution context's LexicalEnvironment. This is used for
+    /// `with` statements.
+    EnterObjectEnvironment,
     /// Reset the running execution context's LexicalEnvironment to its current
     /// value's \[\[OuterEnv]].
     ExitDeclarativeEnvironment,
@@ -282,6 +355,9 @@ pub enum Instruction {
     /// const [a] = x;
     /// ```
     BindingPatternBind,
+    /// In array binding patterns, bind the current result to the register
+    /// given as an immediate.
+    BindingPatternBindToRegister,
     /// Bind an object property to an identifier with a different name. The
     /// constant given as the second argument is the property key.
     ///
@@ -370,15 +446,25 @@ impl Instruction {
             Self::BeginSimpleArrayBindingPattern
             | Self::BindingPatternBindNamed
             | Self::ClassDefineConstructor
+            | Self::EvaluatePropertyAccessWithIdentifierKey
             | Self::InitializeVariableEnvironment
             | Self::ObjectDefineGetter
             | Self::ObjectDefineMethod
-            | Self::ObjectDefineSetter => 2,
-            Self::ArrayCreate
+            | Self::GetScoped
+            | Self::ObjectDefineSetter
+            | Self::ResolveRegister
+            | Self::SetScoped => 2,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Self::AddDisposableResource
+            | Self::CreateDisposeCapability
+            | Self::DisposeResources => 2,
+            Self::ApplyStringOrNumericBinaryOperator(_)
+            | Self::ArrayCreate
             | Self::ArraySetValue
             | Self::BeginSimpleObjectBindingPattern
             | Self::BindingPatternBind
             | Self::BindingPatternBindRest
+            | Self::BindingPatternBindToRegister
             | Self::BindingPatternGetValueNamed
             | Self::ClassDefineDefaultConstructor
             | Self::CopyDataPropertiesIntoObject
@@ -389,16 +475,27 @@ impl Instruction {
             | Self::EvaluateCall
             | Self::EvaluateNew
             | Self::EvaluateSuper
-            | Self::EvaluatePropertyAccessWithIdentifierKey
+            | Self::GetLocal
+            | Self::GetRegister
+            | Self::GreaterThan
+            | Self::GreaterThanEquals
+            | Self::HasProperty
+            | Self::InstanceofOperator
             | Self::InstantiateArrowFunctionExpression
             | Self::InstantiateOrdinaryFunctionExpression
+            | Self::IsLooselyEqual
+            | Self::IsStrictlyEqual
             | Self::IteratorStepValue
             | Self::Jump
             | Self::JumpIfNot
             | Self::JumpIfTrue
+            | Self::LessThan
+            | Self::LessThanEquals
             | Self::LoadConstant
             | Self::PushExceptionJumpTarget
             | Self::ResolveBinding
+            | Self::SetLocal
+            | Self::SetRegister
             | Self::StoreConstant
             | Self::StringConcat
             | Self::ThrowError => 1,
@@ -422,6 +519,7 @@ impl Instruction {
             Self::CreateCatchBinding
                 | Self::EvaluatePropertyAccessWithIdentifierKey
                 | Self::ResolveBinding
+                | Self::ResolveRegister
                 | Self::CreateImmutableBinding
                 | Self::CreateMutableBinding
                 | Self::BindingPatternBind
@@ -465,28 +563,38 @@ pub(crate) struct Instr {
 
 impl Instr {
     pub(crate) fn debug_print(&self, agent: &mut Agent, ip: usize, exe: Executable, gc: NoGcScope) {
+        eprintln!(
+            "  {}: {:?}{}",
+            ip,
+            self.kind,
+            self.print_args(agent, exe, gc)
+        );
+    }
+
+    /// Prints the instruction's arguments in parentheses, or nothing if it
+    /// has none.
+    pub(super) fn print_args(
+        &self,
+        agent: &mut Agent,
+        exe: Executable,
+        gc: NoGcScope,
+    ) -> std::string::String {
         match self.kind.argument_count() {
-            0 => {
-                eprintln!("  {}: {:?}", ip, self.kind);
-            }
+            0 => std::string::String::new(),
             1 => {
                 let arg0 = self.args.first().unwrap().unwrap();
-                eprintln!(
-                    "  {}: {:?}({})",
-                    ip,
-                    self.kind,
+                format!(
+                    "({})",
                     Self::print_single_arg(agent, self.kind, arg0, exe, gc)
-                );
+                )
             }
             2 => {
                 let arg0 = self.args.first().unwrap().unwrap();
                 let arg1 = self.args.last().unwrap().unwrap();
-                eprintln!(
-                    "  {}: {:?}({})",
-                    ip,
-                    self.kind,
+                format!(
+                    "({})",
                     Self::print_two_args(agent, self.kind, arg0, arg1, exe, gc)
-                );
+                )
             }
             _ => unreachable!(),
         }
@@ -574,18 +682,47 @@ impl Instr {
                     "constructor()".to_string()
                 }
             }
+            Instruction::EvaluatePropertyAccessWithIdentifierKey => {
+                format!(
+                    "{}, cache: {}",
+                    debug_print_identifier(agent, exe, index0, gc),
+                    arg1
+                )
+            }
             Instruction::InitializeVariableEnvironment => {
                 format!("{{ var count: {}, strict: {} }}", arg0, arg1 == 1)
             }
             Instruction::ObjectDefineGetter => "get function() {}".to_string(),
+            Instruction::ResolveRegister => {
+                format!(
+                    "{}, register: {}",
+                    debug_print_identifier(agent, exe, index0, gc),
+                    arg1
+                )
+            }
+            Instruction::GetScoped | Instruction::SetScoped => {
+                format!("{{ hops: {}, slot: {} }}", arg0, arg1)
+            }
             Instruction::ObjectDefineMethod => "function() {}".to_string(),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::AddDisposableResource => {
+                format!("register: {}, async: {}", arg0, arg1 == 1)
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::CreateDisposeCapability => {
+                format!("register: {}, async: {}", arg0, arg1 == 1)
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::DisposeResources => {
+                format!("register: {}, throw: {}", arg0, arg1 == 1)
+            }
             Instruction::ObjectDefineSetter => "set function() {}".to_string(),
             _ => unreachable!(),
         }
     }
 }
 
-fn debug_print_constant(
+pub(super) fn debug_print_constant(
     agent: &mut Agent,
     exe: Executable,
     index: usize,
@@ -612,7 +749,7 @@ fn debug_print_identifier(
     identifier.as_str(agent).to_string()
 }
 
-fn debug_print_binding_pattern(b: &BindingPattern) -> std::string::String {
+pub(super) fn debug_print_binding_pattern(b: &BindingPattern) -> std::string::String {
     match &b.kind {
         oxc_ast::ast::BindingPatternKind::BindingIdentifier(b) => b.name.to_string(),
         oxc_ast::ast::BindingPatternKind::ObjectPattern(b) => {
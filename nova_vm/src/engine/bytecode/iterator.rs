@@ -18,14 +18,15 @@ use crate::{
     heap::{CompactionLists, HeapMarkAndSweep, WellKnownSymbolIndexes, WorkQueues},
 };
 
-use super::executable::SendableRef;
-
 #[derive(Debug)]
 pub(super) enum VmIterator {
     ObjectProperties(ObjectPropertiesIterator),
     ArrayValues(ArrayValuesIterator),
     GenericIterator(IteratorRecord),
-    SliceIterator(SendableRef<[Value]>),
+    /// Iterator over a list of values, such as a function's arguments. The
+    /// values are owned by the iterator so that garbage collection can update
+    /// them.
+    SliceIterator(std::vec::IntoIter<Value>),
 }
 
 impl VmIterator {
@@ -113,16 +114,7 @@ impl VmIterator {
                     Ok(Some(value))
                 }
             }
-            VmIterator::SliceIterator(slice_ref) => {
-                let slice = slice_ref.get();
-                if slice.is_empty() {
-                    Ok(None)
-                } else {
-                    let ret = slice[0];
-                    *slice_ref = SendableRef::new(&slice[1..]);
-                    Ok(Some(ret))
-                }
-            }
+            VmIterator::SliceIterator(values) => Ok(values.next()),
         }
     }
 
@@ -133,7 +125,7 @@ impl VmIterator {
                 Some(iter.array.len(agent).saturating_sub(iter.index) as usize)
             }
             VmIterator::GenericIterator(_) => None,
-            VmIterator::SliceIterator(slice) => Some(slice.get().len()),
+            VmIterator::SliceIterator(values) => Some(values.len()),
         }
     }
 
@@ -340,7 +332,7 @@ impl HeapMarkAndSweep for VmIterator {
             VmIterator::ObjectProperties(iter) => iter.mark_values(queues),
             VmIterator::ArrayValues(iter) => iter.mark_values(queues),
             VmIterator::GenericIterator(iter) => iter.mark_values(queues),
-            VmIterator::SliceIterator(slice) => slice.get().mark_values(queues),
+            VmIterator::SliceIterator(values) => values.as_slice().mark_values(queues),
         }
     }
 
@@ -349,7 +341,7 @@ impl HeapMarkAndSweep for VmIterator {
             VmIterator::ObjectProperties(iter) => iter.sweep_values(compactions),
             VmIterator::ArrayValues(iter) => iter.sweep_values(compactions),
             VmIterator::GenericIterator(iter) => iter.sweep_values(compactions),
-            VmIterator::SliceIterator(slice) => slice.get().sweep_values(compactions),
+            VmIterator::SliceIterator(values) => values.as_mut_slice().sweep_values(compactions),
         }
     }
 }
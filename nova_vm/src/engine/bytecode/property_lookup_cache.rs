@@ -0,0 +1,262 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Inline caches for property access instructions.
+//!
+//! Each `EvaluatePropertyAccessWithIdentifierKey` instruction in an
+//! Executable owns a cache slot. The `GetValue` and `PutValue` instructions
+//! following it record the Shape of the objects they have seen, and where in
+//! those objects (or their prototypes) the property was found. A cache entry
+//! is validated against the object's current Shape and prototype on every
+//! use, so Shape and prototype changes invalidate entries implicitly.
+//!
+//! Dictionary Shapes are mutated in place when properties are added or
+//! removed, so entries for them would not be invalidated. Objects with a
+//! dictionary Shape, or a prototype with one, are never cached.
+
+use crate::{
+    ecmascript::{
+        execution::Agent,
+        types::{Object, ObjectShape, OrdinaryObject, PropertyKey, Value},
+    },
+    engine::Executable,
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// Maximum number of Shapes a single property access site records. Sites
+/// that see more Shapes than this are megamorphic and are no longer cached.
+const MAX_POLYMORPHIC_ENTRIES: usize = 4;
+
+#[derive(Debug, Clone, Copy)]
+enum PropertyLookupCacheEntry {
+    /// The property is an own property of objects with the Shape, stored at
+    /// the offset.
+    Own { shape: ObjectShape, offset: u32 },
+    /// The property is not an own property of objects with the Shape but is
+    /// an own property of the prototype, stored at the offset.
+    Prototype {
+        shape: ObjectShape,
+        prototype: OrdinaryObject<'static>,
+        prototype_shape: ObjectShape,
+        offset: u32,
+    },
+}
+
+impl PropertyLookupCacheEntry {
+    fn shape(self) -> ObjectShape {
+        match self {
+            Self::Own { shape, .. } | Self::Prototype { shape, .. } => shape,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct PropertyLookupCache {
+    /// The key the cache site was last filled for.
+    key: Option<PropertyKey<'static>>,
+    /// Entries of the cache site, most recently added last.
+    entries: Vec<PropertyLookupCacheEntry>,
+    /// If true, the site has seen too many Shapes to be worth caching.
+    megamorphic: bool,
+}
+
+impl PropertyLookupCache {
+    fn find(&self, key: PropertyKey, shape: ObjectShape) -> Option<PropertyLookupCacheEntry> {
+        if self.key != Some(key.unbind()) {
+            return None;
+        }
+        self.entries
+            .iter()
+            .find(|entry| entry.shape() == shape)
+            .copied()
+    }
+}
+
+/// Try to get the value of the property from the inline cache. Returns None
+/// if the cache does not contain a valid entry for the object, or if the
+/// property is not a data property.
+pub(crate) fn get_cached_property(
+    agent: &Agent,
+    executable: Executable,
+    cache_index: usize,
+    object: OrdinaryObject,
+    key: PropertyKey,
+) -> Option<Value> {
+    let object_data = &agent[object];
+    let entry =
+        agent[executable].property_lookup_caches[cache_index].find(key, object_data.shape)?;
+    let (values, offset) = match entry {
+        PropertyLookupCacheEntry::Own { offset, .. } => (object_data.values, offset),
+        PropertyLookupCacheEntry::Prototype {
+            prototype,
+            prototype_shape,
+            offset,
+            ..
+        } => {
+            if object_data.prototype != Some(Object::Object(prototype)) {
+                return None;
+            }
+            let prototype_data = &agent[prototype];
+            if prototype_data.shape != prototype_shape {
+                return None;
+            }
+            (prototype_data.values, offset)
+        }
+    };
+    // Note: Accessor properties have no value.
+    *agent.heap.elements.get(values).get(offset as usize)?
+}
+
+/// Try to set the value of the property through the inline cache. Returns
+/// false if the cache does not contain a valid entry for the object, or if
+/// the property is not a writable own data property.
+pub(crate) fn set_cached_property(
+    agent: &mut Agent,
+    executable: Executable,
+    cache_index: usize,
+    object: OrdinaryObject,
+    key: PropertyKey,
+    value: Value,
+) -> bool {
+    let object_data = &agent[object];
+    let Some(PropertyLookupCacheEntry::Own { offset, .. }) =
+        agent[executable].property_lookup_caches[cache_index].find(key, object_data.shape)
+    else {
+        return false;
+    };
+    let values = object_data.values;
+    let offset = offset as usize;
+    let is_writable_data = match agent.heap.elements.get_descriptor(values, offset) {
+        None => true,
+        Some(descriptor) => descriptor.is_writable() == Some(true),
+    };
+    if !is_writable_data {
+        return false;
+    }
+    let Some(slot) = agent.heap.elements.get_mut(values).get_mut(offset) else {
+        return false;
+    };
+    *slot = Some(value);
+    true
+}
+
+/// Record where the property was found for the object in the inline cache.
+/// If `own_only` is true, only own properties of the object are recorded.
+pub(crate) fn update_property_lookup_cache(
+    agent: &mut Agent,
+    executable: Executable,
+    cache_index: usize,
+    object: OrdinaryObject,
+    key: PropertyKey,
+    own_only: bool,
+) {
+    let key = key.unbind();
+    let object_data = &agent[object];
+    let shape = object_data.shape;
+    let shapes = &agent.heap.object_shapes;
+    if shape.is_dictionary(shapes) {
+        return;
+    }
+    let entry = if let Some(offset) = shape.get_offset(shapes, key) {
+        PropertyLookupCacheEntry::Own { shape, offset }
+    } else if own_only {
+        return;
+    } else if let Some(Object::Object(prototype)) = object_data.prototype {
+        let prototype_shape = agent[prototype].shape;
+        if prototype_shape.is_dictionary(shapes) {
+            return;
+        }
+        let Some(offset) = prototype_shape.get_offset(shapes, key) else {
+            return;
+        };
+        PropertyLookupCacheEntry::Prototype {
+            shape,
+            prototype: prototype.unbind(),
+            prototype_shape,
+            offset,
+        }
+    } else {
+        return;
+    };
+    let cache = &mut agent[executable].property_lookup_caches[cache_index];
+    if cache.megamorphic {
+        return;
+    }
+    if cache.key != Some(key) {
+        cache.key = Some(key);
+        cache.entries.clear();
+    }
+    if let Some(existing) = cache
+        .entries
+        .iter_mut()
+        .find(|existing| existing.shape() == shape)
+    {
+        *existing = entry;
+    } else if cache.entries.len() < MAX_POLYMORPHIC_ENTRIES {
+        cache.entries.push(entry);
+    } else {
+        cache.megamorphic = true;
+        cache.entries = Vec::new();
+    }
+}
+
+impl HeapMarkAndSweep for PropertyLookupCacheEntry {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Own { shape, offset: _ } => shape.mark_values(queues),
+            Self::Prototype {
+                shape,
+                prototype,
+                prototype_shape,
+                offset: _,
+            } => {
+                shape.mark_values(queues);
+                prototype.mark_values(queues);
+                prototype_shape.mark_values(queues);
+            }
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Own { shape, offset: _ } => shape.sweep_values(compactions),
+            Self::Prototype {
+                shape,
+                prototype,
+                prototype_shape,
+                offset: _,
+            } => {
+                shape.sweep_values(compactions);
+                prototype.sweep_values(compactions);
+                prototype_shape.sweep_values(compactions);
+            }
+        }
+    }
+}
+
+impl HeapMarkAndSweep for PropertyLookupCache {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            key,
+            entries,
+            megamorphic: _,
+        } = self;
+        key.mark_values(queues);
+        for entry in entries.iter() {
+            entry.mark_values(queues);
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            key,
+            entries,
+            megamorphic: _,
+        } = self;
+        key.sweep_values(compactions);
+        for entry in entries.iter_mut() {
+            entry.sweep_values(compactions);
+        }
+    }
+}
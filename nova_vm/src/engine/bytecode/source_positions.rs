@@ -0,0 +1,106 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Source position tables of Executables.
+//!
+//! The table maps instruction offsets back to byte offsets into the source
+//! code that the instructions were compiled from. Each entry marks the start
+//! of a run of instructions compiled from the same source position, and the
+//! entries are stored delta encoded against the previous entry: The
+//! instruction offset delta as an unsigned LEB128 number, followed by the
+//! source offset delta as a zigzag encoded LEB128 number.
+
+#[derive(Debug, Default)]
+pub(crate) struct SourcePositionTable {
+    data: Box<[u8]>,
+}
+
+impl SourcePositionTable {
+    /// Encode a table from (instruction offset, source offset) entries
+    /// sorted by instruction offset.
+    pub(crate) fn new(entries: &[(u32, u32)]) -> Self {
+        let mut data = Vec::with_capacity(entries.len() * 2);
+        let mut previous_ip = 0;
+        let mut previous_offset = 0;
+        for &(ip, offset) in entries {
+            debug_assert!(ip >= previous_ip);
+            write_unsigned(&mut data, ip - previous_ip);
+            let offset_delta = offset as i64 - previous_offset as i64;
+            // Zigzag encoding maps small negative deltas to small numbers.
+            write_unsigned(
+                &mut data,
+                ((offset_delta << 1) ^ (offset_delta >> 63)) as u32,
+            );
+            previous_ip = ip;
+            previous_offset = offset;
+        }
+        Self {
+            data: data.into_boxed_slice(),
+        }
+    }
+
+    /// Get the source offset of the instruction at the given offset. Returns
+    /// None if the instruction precedes all entries.
+    pub(crate) fn get(&self, ip: u32) -> Option<u32> {
+        let mut data = &self.data[..];
+        let mut current_ip = 0;
+        let mut current_offset = 0;
+        let mut result = None;
+        while !data.is_empty() {
+            current_ip += read_unsigned(&mut data);
+            let zigzag = read_unsigned(&mut data);
+            let offset_delta = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+            if current_ip > ip {
+                break;
+            }
+            current_offset = (current_offset as i64 + offset_delta) as u32;
+            result = Some(current_offset);
+        }
+        result
+    }
+
+    /// Iterate over the (instruction offset, source offset) entries of the
+    /// table in instruction order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        let mut data = &self.data[..];
+        let mut current_ip = 0;
+        let mut current_offset = 0;
+        core::iter::from_fn(move || {
+            if data.is_empty() {
+                return None;
+            }
+            current_ip += read_unsigned(&mut data);
+            let zigzag = read_unsigned(&mut data);
+            let offset_delta = (zigzag >> 1) as i64 ^ -((zigzag & 1) as i64);
+            current_offset = (current_offset as i64 + offset_delta) as u32;
+            Some((current_ip, current_offset))
+        })
+    }
+}
+
+fn write_unsigned(data: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            data.push(byte);
+            return;
+        }
+        data.push(byte | 0x80);
+    }
+}
+
+fn read_unsigned(data: &mut &[u8]) -> u32 {
+    let mut value = 0;
+    let mut shift = 0;
+    loop {
+        let byte = data[0];
+        *data = &data[1..];
+        value |= ((byte & 0x7F) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return value;
+        }
+        shift += 7;
+    }
+}
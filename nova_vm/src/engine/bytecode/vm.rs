@@ -13,8 +13,20 @@ use oxc_ast::ast;
 use oxc_span::Span;
 use oxc_syntax::operator::BinaryOperator;
 
-#[cfg(feature = "interleaved-gc")]
-use crate::{ecmascript::execution::RealmIdentifier, heap::heap_gc::heap_gc};
+use crate::heap::heap_gc::scheduled_heap_gc;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::{
+    ecmascript::{
+        abstract_operations::operations_on_disposable_objects::{
+            add_disposable_resource, dispose_resources, dispose_resources_async,
+        },
+        builtins::disposable_stack::{
+            data::{DisposableStackHeapData, DisposeHint},
+            DisposableStack,
+        },
+    },
+    heap::CreateHeapData,
+};
 use crate::{
     ecmascript::{
         abstract_operations::{
@@ -36,17 +48,18 @@ use crate::{
             },
         },
         builtins::{
-            array_create, create_builtin_constructor, create_unmapped_arguments_object,
-            global_object::perform_eval, make_constructor, make_method,
-            ordinary::ordinary_object_create_with_intrinsics, ordinary_function_create,
-            set_function_name, ArgumentsList, Array, BuiltinConstructorArgs, ConstructorStatus,
-            OrdinaryFunctionCreateParams,
+            array_create, create_builtin_constructor, create_mapped_arguments_object,
+            create_unmapped_arguments_object, global_object::perform_eval, make_constructor,
+            make_method, ordinary::ordinary_object_create_with_intrinsics,
+            ordinary_function_create, set_function_name, ArgumentsList, Array,
+            BuiltinConstructorArgs, ConstructorStatus, OrdinaryFunctionCreateParams,
         },
         execution::{
-            agent::{resolve_binding, ExceptionType, JsError},
+            agent::{resolve_binding, ExceptionType, JsError, PauseReason},
             get_this_environment, new_class_static_element_environment,
-            new_declarative_environment, Agent, ECMAScriptCodeEvaluationState, EnvironmentIndex,
-            JsResult, ProtoIntrinsics,
+            new_declarative_environment, Agent, DeclarativeEnvironmentIndex,
+            ECMAScriptCodeEvaluationState, EnvironmentIndex, JsResult, ObjectEnvironment,
+            ProtoIntrinsics,
         },
         types::{
             get_this_value, get_value, initialize_referenced_binding, is_private_reference,
@@ -57,7 +70,7 @@ use crate::{
     },
     engine::{
         bytecode::{
-            executable::{ArrowFunctionExpression, SendableRef},
+            executable::ArrowFunctionExpression,
             instructions::Instr,
             iterator::{ObjectPropertiesIterator, VmIterator},
             Executable, FunctionExpression, IndexType, Instruction, InstructionIter,
@@ -69,7 +82,12 @@ use crate::{
     heap::{CompactionLists, HeapMarkAndSweep, WellKnownSymbolIndexes, WorkQueues},
 };
 
-use super::executable::get_instruction;
+use super::{
+    executable::get_instruction,
+    property_lookup_cache::{
+        get_cached_property, set_cached_property, update_property_lookup_cache,
+    },
+};
 
 struct EmptyParametersList(ast::FormalParameters<'static>);
 unsafe impl Send for EmptyParametersList {}
@@ -115,6 +133,9 @@ struct ExceptionJumpTarget {
     ip: usize,
     /// The lexical environment which contains this exception jump target.
     lexical_environment: EnvironmentIndex,
+    /// Length of the value stack when the exception jump target was pushed.
+    /// Values pushed after it are dropped when an exception is caught.
+    stack_len: usize,
 }
 
 /// ## Notes
@@ -123,19 +144,30 @@ struct ExceptionJumpTarget {
 ///   Copyright (c) 2023-2024 Linus Groh
 #[derive(Debug)]
 pub(crate) struct Vm {
+    /// The Executable being executed. This is kept in the Vm so that garbage
+    /// collection can keep it alive and update it while the Vm is in the
+    /// Agent's VM stack.
+    executable: Executable,
     /// Instruction pointer.
     ip: usize,
+    /// Register file of the frame, sized by the Executable. Registers hold
+    /// temporary values and the bindings that do not escape the frame.
+    registers: Box<[Value]>,
     stack: Vec<Value>,
     reference_stack: Vec<Reference<'static>>,
     iterator_stack: Vec<VmIterator>,
     exception_jump_target_stack: Vec<ExceptionJumpTarget>,
     result: Option<Value>,
     reference: Option<Reference<'static>>,
+    /// Inline cache slot of the property access instruction that created the
+    /// reference in the reference register.
+    reference_cache_index: Option<usize>,
 }
 
 #[derive(Debug)]
 pub(crate) struct SuspendedVm {
     ip: usize,
+    registers: Box<[Value]>,
     /// Note: Stack is non-empty only if the code awaits inside a call
     /// expression. This is reasonably rare that we can expect the stack to
     /// usually be empty. In this case this Box is an empty dangling pointer
@@ -162,8 +194,8 @@ impl SuspendedVm {
         value: Value,
         gc: GcScope,
     ) -> ExecutionResult {
-        let vm = Vm::from_suspended(self);
-        vm.resume(agent, executable, value, gc)
+        let vm = Vm::from_suspended(self, executable);
+        vm.resume(agent, value, gc)
     }
 
     pub(crate) fn resume_throw(
@@ -179,27 +211,31 @@ impl SuspendedVm {
             let err = JsError::new(err);
             return ExecutionResult::Throw(err);
         }
-        let vm = Vm::from_suspended(self);
-        vm.resume_throw(agent, executable, err, gc)
+        let vm = Vm::from_suspended(self, executable);
+        vm.resume_throw(agent, err, gc)
     }
 }
 
 impl<'a> Vm {
-    fn new() -> Self {
+    fn new(executable: Executable, register_count: usize) -> Self {
         Self {
+            executable,
             ip: 0,
+            registers: vec![Value::Undefined; register_count].into_boxed_slice(),
             stack: Vec::with_capacity(32),
             reference_stack: Vec::new(),
             iterator_stack: Vec::new(),
             exception_jump_target_stack: Vec::new(),
             result: None,
             reference: None,
+            reference_cache_index: None,
         }
     }
 
     fn suspend(self) -> SuspendedVm {
         SuspendedVm {
             ip: self.ip,
+            registers: self.registers,
             stack: self.stack.into_boxed_slice(),
             reference_stack: unsafe {
                 core::mem::transmute::<Box<[Reference<'a>]>, Box<[Reference<'static>]>>(
@@ -211,15 +247,18 @@ impl<'a> Vm {
         }
     }
 
-    fn from_suspended(suspended: SuspendedVm) -> Self {
+    fn from_suspended(suspended: SuspendedVm, executable: Executable) -> Self {
         Self {
+            executable,
             ip: suspended.ip,
+            registers: suspended.registers,
             stack: suspended.stack.into_vec(),
             reference_stack: suspended.reference_stack.into_vec(),
             iterator_stack: suspended.iterator_stack.into_vec(),
             exception_jump_target_stack: suspended.exception_jump_target_stack.into_vec(),
             result: None,
             reference: None,
+            reference_cache_index: None,
         }
     }
 
@@ -230,14 +269,11 @@ impl<'a> Vm {
         arguments: Option<&[Value]>,
         gc: GcScope,
     ) -> ExecutionResult {
-        let mut vm = Vm::new();
+        let mut vm = Vm::new(executable, agent[executable].register_count);
 
         if let Some(arguments) = arguments {
-            // SAFETY: awaits and yields are invalid syntax inside an arguments
-            // list, so this reference shouldn't remain alive after this
-            // function returns.
-            let arguments = unsafe { SendableRef::new_as_static(arguments) };
-            vm.iterator_stack.push(VmIterator::SliceIterator(arguments));
+            vm.iterator_stack
+                .push(VmIterator::SliceIterator(Vec::from(arguments).into_iter()));
         }
 
         if agent.options.print_internals {
@@ -254,105 +290,158 @@ impl<'a> Vm {
             eprintln!();
         }
 
-        vm.inner_execute(agent, executable, gc)
+        vm.inner_execute(agent, gc)
     }
 
-    pub fn resume(
-        mut self,
-        agent: &mut Agent,
-        executable: Executable,
-        value: Value,
-        gc: GcScope,
-    ) -> ExecutionResult {
+    pub fn resume(mut self, agent: &mut Agent, value: Value, gc: GcScope) -> ExecutionResult {
         self.result = Some(value);
-        self.inner_execute(agent, executable, gc)
+        self.inner_execute(agent, gc)
     }
 
-    pub fn resume_throw(
-        mut self,
-        agent: &mut Agent,
-        executable: Executable,
-        err: Value,
-        gc: GcScope,
-    ) -> ExecutionResult {
+    pub fn resume_throw(mut self, agent: &mut Agent, err: Value, gc: GcScope) -> ExecutionResult {
         let err = JsError::new(err);
         if !self.handle_error(agent, err) {
             return ExecutionResult::Throw(err);
         }
-        self.inner_execute(agent, executable, gc)
+        self.inner_execute(agent, gc)
     }
 
-    fn inner_execute(
-        mut self,
-        agent: &mut Agent,
-        executable: Executable,
-        mut gc: GcScope<'a, '_>,
-    ) -> ExecutionResult {
-        #[cfg(feature = "interleaved-gc")]
-        let do_gc = !agent.options.disable_gc;
-        #[cfg(feature = "interleaved-gc")]
-        let mut instr_count = 0u8;
-
-        let instructions = executable.get_instructions(agent);
-        while let Some(instr) = get_instruction(instructions, &mut self.ip) {
-            #[cfg(feature = "interleaved-gc")]
-            if do_gc {
-                instr_count = instr_count.wrapping_add(1);
-                if instr_count == 0 {
-                    let mut root_realms = agent
-                        .heap
-                        .realms
-                        .iter()
-                        .enumerate()
-                        .map(|(i, _)| Some(RealmIdentifier::from_index(i)))
-                        .collect::<Vec<_>>();
-                    let vm = unsafe { NonNull::new_unchecked(&mut self) };
-                    agent
-                        .vm_stack
-                        // SAFETY: Pointer to self is never null.
-                        .push(vm);
-                    heap_gc(agent, &mut root_realms, gc.reborrow());
-                    let return_vm = agent.vm_stack.pop().unwrap();
-                    assert_eq!(vm, return_vm, "VM Stack was misused");
+    fn inner_execute(mut self, agent: &mut Agent, gc: GcScope<'a, '_>) -> ExecutionResult {
+        // The Vm is rooted for as long as it executes, so that garbage
+        // collection can happen at any point of the execution, including in
+        // the functions that the Vm calls.
+        let vm = NonNull::from(&mut self);
+        agent.vm_stack.push(vm);
+        let result = self.run(agent, gc);
+        let return_vm = agent.vm_stack.pop().unwrap();
+        assert_eq!(vm, return_vm, "VM Stack was misused");
+        match result {
+            Ok(ContinuationKind::Normal) => unreachable!(),
+            Ok(ContinuationKind::Return) => {
+                ExecutionResult::Return(self.result.unwrap_or(Value::Undefined))
+            }
+            Ok(ContinuationKind::Yield) => {
+                let yielded_value = self.result.take().unwrap();
+                ExecutionResult::Yield {
+                    vm: self.suspend(),
+                    yielded_value,
                 }
             }
-            let temp = &mut self;
-            let temp_self = unsafe { core::mem::transmute::<&mut Vm, &mut Vm>(temp) };
-            match Self::execute_instruction(agent, temp_self, executable, &instr, gc.reborrow()) {
-                Ok(ContinuationKind::Normal) => {}
-                Ok(ContinuationKind::Return) => {
-                    let result = self.result.unwrap_or(Value::Undefined);
-                    return ExecutionResult::Return(result);
+            Ok(ContinuationKind::Await) => {
+                let awaited_value = self.result.take().unwrap();
+                ExecutionResult::Await {
+                    vm: self.suspend(),
+                    awaited_value,
                 }
-                Ok(ContinuationKind::Yield) => {
-                    let yielded_value = self.result.take().unwrap();
-                    return ExecutionResult::Yield {
-                        vm: self.suspend(),
-                        yielded_value,
-                    };
+            }
+            Err(err) => ExecutionResult::Throw(err),
+        }
+    }
+
+    /// Executes instructions until the Vm returns, yields, awaits or throws.
+    /// The result of the execution is left in the Vm.
+    fn run(&mut self, agent: &mut Agent, mut gc: GcScope<'a, '_>) -> JsResult<ContinuationKind> {
+        // Note: The instructions are boxed and do not move when garbage
+        // collection compacts the Executables, but the Executable's index may
+        // change. It is thus reread from the Vm for each instruction.
+        let instructions = self.executable.get_instructions(agent);
+        // Function and script entry is a safe point for interrupts.
+        if agent.handle_interrupts() {
+            return Err(agent.throw_termination(gc.nogc()));
+        }
+        // Line tracking for the debugger, created once it is enabled.
+        let mut debug_frame = None;
+        // Index of the Executable's coverage counters, found once coverage
+        // is collected. Entering the Executable counts calls even if it has
+        // no instructions.
+        let mut coverage_frame = if agent.coverage.is_some() {
+            Some(agent.enter_covered_executable(self.executable, self.ip as u32))
+        } else {
+            None
+        };
+        // Values scoped by an instruction are not used after it, and are
+        // released so that they do not pile up as roots of the heap.
+        let stack_refs_len = agent.stack_refs.borrow().len();
+        loop {
+            let instruction_ip = self.ip;
+            let Some(instr) = get_instruction(instructions, &mut self.ip) else {
+                break;
+            };
+            let next_ip = self.ip;
+            // Record the instruction for mapping the call site back to its
+            // source position.
+            agent
+                .running_execution_context_mut()
+                .ecmascript_code
+                .as_mut()
+                .unwrap()
+                .instruction = Some((self.executable, instruction_ip as u32));
+            if !agent.consume_fuel(1) {
+                return Err(agent.throw_out_of_fuel(gc.nogc()));
+            }
+            agent.profile_instruction();
+            if agent.coverage.is_some() {
+                agent.cover_instruction(
+                    &mut coverage_frame,
+                    self.executable,
+                    instruction_ip as u32,
+                );
+            }
+            if agent.gc_is_due() {
+                scheduled_heap_gc(agent, gc.reborrow());
+                if !agent.can_allocate(0) {
+                    let err = agent.throw_out_of_memory(gc.nogc());
+                    if !self.handle_error(agent, err) {
+                        return Err(err);
+                    }
+                    continue;
                 }
-                Ok(ContinuationKind::Await) => {
-                    let awaited_value = self.result.take().unwrap();
-                    return ExecutionResult::Await {
-                        vm: self.suspend(),
-                        awaited_value,
-                    };
+            }
+            if agent.debugger.is_some() {
+                agent.debug_instruction(
+                    &mut debug_frame,
+                    self.executable,
+                    instruction_ip as u32,
+                    gc.reborrow(),
+                );
+            }
+            let temp_self = unsafe { core::mem::transmute::<&mut Vm, &mut Vm>(&mut *self) };
+            let executable = self.executable;
+            let result =
+                Self::execute_instruction(agent, temp_self, executable, &instr, gc.reborrow());
+            agent.stack_refs.borrow_mut().truncate(stack_refs_len);
+            match result {
+                Ok(ContinuationKind::Normal) => {
+                    // Loop back-edges are safe points for interrupts.
+                    if self.ip < next_ip && agent.handle_interrupts() {
+                        return Err(agent.throw_termination(gc.nogc()));
+                    }
                 }
-                Err(err) => {
+                Ok(kind) => return Ok(kind),
+                Err(mut err) => {
+                    if agent.debugger.is_some() {
+                        err = agent.debug_exception(err, gc.reborrow());
+                    }
                     if !self.handle_error(agent, err) {
-                        return ExecutionResult::Throw(err);
+                        return Err(err);
                     }
                 }
             }
         }
 
-        ExecutionResult::Return(Value::Undefined)
+        self.result = None;
+        Ok(ContinuationKind::Return)
     }
 
     #[must_use]
     fn handle_error(&mut self, agent: &mut Agent, err: JsError) -> bool {
+        if agent.is_terminating() {
+            // Termination cannot be caught.
+            return false;
+        }
         if let Some(ejt) = self.exception_jump_target_stack.pop() {
             self.ip = ejt.ip;
+            self.stack.truncate(ejt.stack_len);
             agent
                 .running_execution_context_mut()
                 .ecmascript_code
@@ -431,6 +520,9 @@ impl<'a> Vm {
                 if agent.options.print_internals {
                     eprintln!("Debug: {:#?}", vm);
                 }
+                if agent.debugger.is_some() {
+                    agent.debugger_pause(PauseReason::DebuggerStatement, gc);
+                }
             }
             Instruction::ResolveBinding => {
                 let identifier =
@@ -439,6 +531,47 @@ impl<'a> Vm {
                 let reference = resolve_binding(agent, identifier.unbind(), None, gc)?;
 
                 vm.reference = Some(reference.unbind());
+                vm.reference_cache_index = None;
+            }
+            Instruction::ResolveRegister => {
+                let identifier =
+                    executable.fetch_identifier(agent, instr.args[0].unwrap() as usize, gc.nogc());
+                vm.reference = Some(Reference {
+                    base: Base::Register(instr.args[1].unwrap()),
+                    referenced_name: identifier.unbind().into(),
+                    strict: true,
+                    this_value: None,
+                });
+                vm.reference_cache_index = None;
+            }
+            Instruction::GetRegister => {
+                vm.result = Some(vm.registers[instr.args[0].unwrap() as usize]);
+            }
+            Instruction::SetRegister => {
+                vm.registers[instr.args[0].unwrap() as usize] = vm.result.unwrap();
+            }
+            Instruction::GetLocal | Instruction::GetScoped => {
+                let (hops, slot) = if instr.kind == Instruction::GetLocal {
+                    (0, instr.args[0].unwrap())
+                } else {
+                    (instr.args[0].unwrap(), instr.args[1].unwrap())
+                };
+                let env = get_scoped_environment(agent, hops);
+                vm.result = Some(env.get_slot_value(agent, slot.into(), gc.nogc())?);
+            }
+            Instruction::SetLocal | Instruction::SetScoped => {
+                let (hops, slot) = if instr.kind == Instruction::SetLocal {
+                    (0, instr.args[0].unwrap())
+                } else {
+                    (instr.args[0].unwrap(), instr.args[1].unwrap())
+                };
+                let env = get_scoped_environment(agent, hops);
+                let is_strict = agent
+                    .running_execution_context()
+                    .ecmascript_code
+                    .unwrap()
+                    .is_strict_mode;
+                env.set_slot_value(agent, slot.into(), vm.result.unwrap(), is_strict, gc.nogc())?;
             }
             Instruction::ResolveThisBinding => {
                 // 1. Let envRec be GetThisEnvironment().
@@ -515,7 +648,7 @@ impl<'a> Vm {
                 vm.result = Some(to_object(agent, vm.result.unwrap(), gc.nogc())?.into_value());
             }
             Instruction::ApplyStringOrNumericBinaryOperator(op_text) => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 vm.result = Some(apply_string_or_numeric_binary_operator(
                     agent, lval, op_text, rval, gc,
@@ -804,26 +937,52 @@ impl<'a> Vm {
             }
             Instruction::PushReference => {
                 vm.reference_stack.push(vm.reference.take().unwrap());
+                vm.reference_cache_index = None;
             }
             Instruction::PopReference => {
                 vm.reference = Some(vm.reference_stack.pop().unwrap());
+                vm.reference_cache_index = None;
             }
             Instruction::PutValue => {
                 let value = vm.result.take().unwrap();
                 let reference = vm.reference.take().unwrap();
-                put_value(agent, &reference, value, gc.reborrow())?;
+                let cache_index = vm.reference_cache_index.take();
+                put_value_with_cache(
+                    agent,
+                    executable,
+                    &mut vm.registers,
+                    cache_index,
+                    &reference,
+                    value,
+                    gc.reborrow(),
+                )?;
             }
             Instruction::GetValue => {
                 // 1. If V is not a Reference Record, return V.
                 let reference = vm.reference.take().unwrap();
+                let cache_index = vm.reference_cache_index.take();
 
-                vm.result = Some(get_value(agent, &reference, gc.reborrow())?);
+                vm.result = Some(get_value_with_cache(
+                    agent,
+                    executable,
+                    &vm.registers,
+                    cache_index,
+                    &reference,
+                    gc.reborrow(),
+                )?);
             }
             Instruction::GetValueKeepReference => {
                 // 1. If V is not a Reference Record, return V.
                 let reference = vm.reference.as_ref().unwrap();
 
-                vm.result = Some(get_value(agent, reference, gc.reborrow())?);
+                vm.result = Some(get_value_with_cache(
+                    agent,
+                    executable,
+                    &vm.registers,
+                    vm.reference_cache_index,
+                    reference,
+                    gc.reborrow(),
+                )?);
             }
             Instruction::Typeof => {
                 // 2. If val is a Reference Record, then
@@ -831,6 +990,8 @@ impl<'a> Vm {
                     if reference.base == Base::Unresolvable {
                         // a. If IsUnresolvableReference(val) is true, return "undefined".
                         Value::Undefined
+                    } else if let Base::Register(register) = reference.base {
+                        vm.registers[register as usize]
                     } else {
                         // 3. Set val to ? GetValue(val).
                         get_value(agent, &reference, gc.reborrow())?
@@ -1260,29 +1421,6 @@ impl<'a> Vm {
                             gc.reborrow(),
                         )?);
                     }
-                } else if cfg!(feature = "interleaved-gc") {
-                    let mut vm = NonNull::from(vm);
-                    agent.vm_stack.push(vm);
-                    let result = call(
-                        agent,
-                        func,
-                        Value::Undefined,
-                        Some(ArgumentsList(&args)),
-                        gc,
-                    );
-                    let return_vm = agent.vm_stack.pop().unwrap();
-                    assert_eq!(vm, return_vm, "VM Stack was misused");
-                    // SAFETY: This is fairly bonkers-unsafe. We have an
-                    // exclusive reference to `Vm` so turning that to a NonNull
-                    // and making the `&mut Vm` unreachable here isn't wrong.
-                    // Passing that NonNull into a stack isn't wrong.
-                    // Popping from that stack isn't wrong.
-                    // Turning that back into a `&mut Vm` is probably wrong.
-                    // Even though we can't reach the `vm: &mut Vm` in this
-                    // scope anymore, it's still there. Hence we have two
-                    // exclusive references alive at the same time. That's not
-                    // a good look. I'm sorry.
-                    unsafe { vm.as_mut() }.result = Some(result?);
                 } else {
                     vm.result = Some(call(
                         agent,
@@ -1310,6 +1448,9 @@ impl<'a> Vm {
                                 .with_base_object(agent)
                                 .map_or(Value::Undefined, |object| object.into_value())
                         }
+                        // Bindings held in registers are never object
+                        // Environment Record bindings.
+                        Base::Register(_) => Value::Undefined,
                         // ii. Assert: refEnv is an Environment Record.
                         Base::Unresolvable => unreachable!(),
                     }
@@ -1319,23 +1460,13 @@ impl<'a> Vm {
                     Value::Undefined
                 };
                 let func = vm.stack.pop().unwrap();
-                if cfg!(feature = "interleaved-gc") {
-                    let mut vm = NonNull::from(vm);
-                    agent.vm_stack.push(vm);
-                    let result = call(agent, func, this_value, Some(ArgumentsList(&args)), gc);
-                    let return_vm = agent.vm_stack.pop().unwrap();
-                    assert_eq!(vm, return_vm, "VM Stack was misused");
-                    // SAFETY: This is fairly bonkers-unsafe. I'm sorry.
-                    unsafe { vm.as_mut() }.result = Some(result?);
-                } else {
-                    vm.result = Some(call(
-                        agent,
-                        func,
-                        this_value,
-                        Some(ArgumentsList(&args)),
-                        gc,
-                    )?);
-                }
+                vm.result = Some(call(
+                    agent,
+                    func,
+                    this_value,
+                    Some(ArgumentsList(&args)),
+                    gc,
+                )?);
             }
             Instruction::EvaluateNew => {
                 let args = vm.get_call_args(instr);
@@ -1352,33 +1483,16 @@ impl<'a> Vm {
                     ));
                 };
 
-                if cfg!(feature = "interleaved-gc") {
-                    let mut vm = NonNull::from(vm);
-                    agent.vm_stack.push(vm);
-                    let result = construct(
+                vm.result = Some(
+                    construct(
                         agent,
                         constructor,
                         Some(ArgumentsList(&args)),
                         None,
                         gc.reborrow(),
-                    )
-                    .map(|result| result.into_value());
-                    let return_vm = agent.vm_stack.pop().unwrap();
-                    assert_eq!(vm, return_vm, "VM Stack was misused");
-                    // SAFETY: This is fairly bonkers-unsafe. I'm sorry.
-                    unsafe { vm.as_mut() }.result = Some(result?);
-                } else {
-                    vm.result = Some(
-                        construct(
-                            agent,
-                            constructor,
-                            Some(ArgumentsList(&args)),
-                            None,
-                            gc.reborrow(),
-                        )?
-                        .into_value(),
-                    );
-                }
+                    )?
+                    .into_value(),
+                );
             }
             Instruction::EvaluateSuper => {
                 let EnvironmentIndex::Function(this_env) = get_this_environment(agent) else {
@@ -1455,6 +1569,7 @@ impl<'a> Vm {
                     strict,
                     this_value: None,
                 });
+                vm.reference_cache_index = None;
             }
             Instruction::EvaluatePropertyAccessWithIdentifierKey => {
                 let property_name_string =
@@ -1472,6 +1587,7 @@ impl<'a> Vm {
                     strict,
                     this_value: None,
                 });
+                vm.reference_cache_index = Some(instr.args[1].unwrap() as usize);
             }
             Instruction::Jump => {
                 let ip = instr.args[0].unwrap() as usize;
@@ -1519,31 +1635,31 @@ impl<'a> Vm {
                 vm.result = Some(new_value.into_value());
             }
             Instruction::LessThan => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 let result = is_less_than::<true>(agent, lval, rval, gc)? == Some(true);
                 vm.result = Some(result.into());
             }
             Instruction::LessThanEquals => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 let result = is_less_than::<false>(agent, rval, lval, gc)? == Some(false);
                 vm.result = Some(result.into());
             }
             Instruction::GreaterThan => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 let result = is_less_than::<false>(agent, rval, lval, gc)? == Some(true);
                 vm.result = Some(result.into());
             }
             Instruction::GreaterThanEquals => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 let result = is_less_than::<true>(agent, lval, rval, gc)? == Some(false);
                 vm.result = Some(result.into());
             }
             Instruction::HasProperty => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 // RelationalExpression : RelationalExpression in ShiftExpression
                 // 5. If rval is not an Object, throw a TypeError exception.
@@ -1570,13 +1686,13 @@ impl<'a> Vm {
                 };
             }
             Instruction::IsStrictlyEqual => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 let result = is_strictly_equal(agent, lval, rval);
                 vm.result = Some(result.into());
             }
             Instruction::IsLooselyEqual => {
-                let lval = vm.stack.pop().unwrap();
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
                 let rval = vm.result.take().unwrap();
                 let result = is_loosely_equal(agent, lval, rval, gc.reborrow())?;
                 vm.result = Some(result.into());
@@ -1621,7 +1737,11 @@ impl<'a> Vm {
             Instruction::InitializeReferencedBinding => {
                 let v = vm.reference.take().unwrap();
                 let w = vm.result.take().unwrap();
-                initialize_referenced_binding(agent, v, w, gc.reborrow())?;
+                if let Base::Register(register) = v.base {
+                    vm.registers[register as usize] = w;
+                } else {
+                    initialize_referenced_binding(agent, v, w, gc.reborrow())?;
+                }
             }
             Instruction::InitializeVariableEnvironment => {
                 let num_variables = instr.args[0].unwrap();
@@ -1701,6 +1821,30 @@ impl<'a> Vm {
                 current_context.lexical_environment = local_env;
                 current_context.variable_environment = local_env;
             }
+            Instruction::EnterObjectEnvironment => {
+                let binding_object = Object::try_from(vm.result.take().unwrap()).unwrap();
+                let outer_env = agent
+                    .running_execution_context()
+                    .ecmascript_code
+                    .as_ref()
+                    .unwrap()
+                    .lexical_environment;
+                let new_env =
+                    agent
+                        .heap
+                        .environments
+                        .push_object_environment(ObjectEnvironment::new(
+                            binding_object,
+                            true,
+                            Some(outer_env),
+                        ));
+                agent
+                    .running_execution_context_mut()
+                    .ecmascript_code
+                    .as_mut()
+                    .unwrap()
+                    .lexical_environment = EnvironmentIndex::Object(new_env);
+            }
             Instruction::ExitDeclarativeEnvironment => {
                 let old_env = agent
                     .running_execution_context()
@@ -1781,26 +1925,56 @@ impl<'a> Vm {
                         .as_ref()
                         .unwrap()
                         .lexical_environment,
+                    stack_len: vm.stack.len(),
                 });
             }
             Instruction::PopExceptionJumpTarget => {
                 vm.exception_jump_target_stack.pop().unwrap();
             }
-            Instruction::InstanceofOperator => {
-                let lval = vm.stack.pop().unwrap();
-                let rval = vm.result.take().unwrap();
-                if cfg!(feature = "interleaved-gc") {
-                    let mut vm = NonNull::from(vm);
-                    agent.vm_stack.push(vm);
-                    let result = instanceof_operator(agent, lval, rval, gc);
-                    let return_vm = agent.vm_stack.pop().unwrap();
-                    assert_eq!(vm, return_vm, "VM Stack was misused");
-                    // SAFETY: This is fairly bonkers-unsafe. I'm sorry.
-                    unsafe { vm.as_mut() }.result = Some(result?.into());
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::CreateDisposeCapability => {
+                let is_async = instr.args[1].unwrap() == 1;
+                let dispose_capability = agent.heap.create(DisposableStackHeapData::new(is_async));
+                vm.registers[instr.args[0].unwrap() as usize] = dispose_capability.into_value();
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::AddDisposableResource => {
+                let dispose_capability =
+                    DisposableStack::try_from(vm.registers[instr.args[0].unwrap() as usize])
+                        .unwrap();
+                let hint = if instr.args[1].unwrap() == 1 {
+                    DisposeHint::AsyncDispose
                 } else {
-                    vm.result = Some(instanceof_operator(agent, lval, rval, gc)?.into());
+                    DisposeHint::SyncDispose
+                };
+                let value = *vm.result.as_ref().unwrap();
+                add_disposable_resource(agent, dispose_capability, value, hint, None, gc)?;
+            }
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Instruction::DisposeResources => {
+                let dispose_capability =
+                    DisposableStack::try_from(vm.registers[instr.args[0].unwrap() as usize])
+                        .unwrap();
+                let completion = if instr.args[1].unwrap() == 1 {
+                    Some(vm.result.unwrap())
+                } else {
+                    None
+                };
+                let is_async = agent[dispose_capability].is_async;
+                if is_async {
+                    let promise =
+                        dispose_resources_async(agent, dispose_capability, completion, gc);
+                    vm.result = Some(promise.into_value());
+                } else {
+                    let completion = completion.map_or(Ok(()), |value| Err(JsError::new(value)));
+                    dispose_resources(agent, dispose_capability, completion, gc)?;
                 }
             }
+            Instruction::InstanceofOperator => {
+                let lval = vm.registers[instr.args[0].unwrap() as usize];
+                let rval = vm.result.take().unwrap();
+                vm.result = Some(instanceof_operator(agent, lval, rval, gc)?.into());
+            }
             Instruction::BeginSimpleArrayBindingPattern => {
                 let lexical = instr.args[1].unwrap() == 1;
                 let env = if lexical {
@@ -1931,6 +2105,10 @@ impl<'a> Vm {
                         // c. Return ? base.DeleteBinding(ref.[[ReferencedName]]).
                         vm.result = Some(base.delete_binding(agent, referenced_name, gc)?.into());
                     }
+                    // Bindings held in registers are never deletable.
+                    Base::Register(_) => {
+                        vm.result = Some(false.into());
+                    }
                 }
 
                 // Note 1
@@ -1993,7 +2171,7 @@ impl<'a> Vm {
                     if result.is_none() {
                         // We have exhausted the iterator; replace it with an empty VmIterator so
                         // further instructions aren't observable.
-                        *iterator = VmIterator::SliceIterator(SendableRef::new(&[]));
+                        *iterator = VmIterator::SliceIterator(Vec::new().into_iter());
                     }
                 } else {
                     vm.iterator_stack.pop();
@@ -2033,11 +2211,37 @@ impl<'a> Vm {
             }
             Instruction::Yield => return Ok(ContinuationKind::Yield),
             Instruction::CreateUnmappedArgumentsObject => {
-                let Some(VmIterator::SliceIterator(slice)) = vm.iterator_stack.last() else {
+                let Some(VmIterator::SliceIterator(arguments)) = vm.iterator_stack.last() else {
                     unreachable!()
                 };
                 vm.result = Some(
-                    create_unmapped_arguments_object(agent, slice.get(), gc.nogc()).into_value(),
+                    create_unmapped_arguments_object(agent, arguments.as_slice(), gc.nogc())
+                        .into_value(),
+                );
+            }
+            Instruction::CreateMappedArgumentsObject => {
+                let Some(VmIterator::SliceIterator(arguments)) = vm.iterator_stack.last() else {
+                    unreachable!()
+                };
+                let Some(Function::ECMAScriptFunction(func)) =
+                    agent.running_execution_context().function
+                else {
+                    unreachable!()
+                };
+                // SAFETY: The function is running, so its SourceCode and the
+                // Allocator it owns are alive.
+                let formals = unsafe { agent[func].ecmascript_function.formal_parameters.as_ref() };
+                let env = get_scoped_environment(agent, 0);
+                vm.result = Some(
+                    create_mapped_arguments_object(
+                        agent,
+                        func.into_function(),
+                        formals,
+                        arguments.as_slice(),
+                        env,
+                        gc.nogc(),
+                    )
+                    .into_value(),
                 );
             }
             other => todo!("{other:?}"),
@@ -2273,6 +2477,7 @@ fn typeof_operator(_: &mut Agent, val: Value) -> String {
         // 14. Return "object".
         Value::PrimitiveObject(_) |
         Value::Arguments(_) |
+        Value::MappedArguments(_) |
         Value::FinalizationRegistry(_) |
         Value::Map(_) |
         Value::Promise(_) |
@@ -2313,6 +2518,8 @@ fn typeof_operator(_: &mut Agent, val: Value) -> String {
         Value::Float16Array(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "date")]
         Value::Date(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        Value::DisposableStack(_) => BUILTIN_STRING_MEMORY.object,
         // 13. If val has a [[Call]] internal slot, return "function".
         Value::BoundFunction(_) | Value::BuiltinFunction(_) | Value::ECMAScriptFunction(_) |
         Value::BuiltinGeneratorFunction |
@@ -2325,6 +2532,76 @@ fn typeof_operator(_: &mut Agent, val: Value) -> String {
     }
 }
 
+/// Get the Declarative Environment Record `hops` steps out from the running
+/// execution context's LexicalEnvironment, for instructions accessing
+/// bindings by slot.
+fn get_scoped_environment(agent: &Agent, hops: IndexType) -> DeclarativeEnvironmentIndex {
+    agent
+        .running_execution_context()
+        .ecmascript_code
+        .as_ref()
+        .unwrap()
+        .lexical_environment
+        .get_scoped_declarative_environment(agent, hops.into())
+}
+
+/// GetValue for references created by property access instructions: Data
+/// properties of ordinary objects are read through the instruction's inline
+/// cache. Bindings held in registers are read from the register file.
+fn get_value_with_cache(
+    agent: &mut Agent,
+    executable: Executable,
+    registers: &[Value],
+    cache_index: Option<usize>,
+    reference: &Reference,
+    gc: GcScope,
+) -> JsResult<Value> {
+    if let Base::Register(register) = reference.base {
+        return Ok(registers[register as usize]);
+    }
+    if let (Some(cache_index), Base::Value(Value::Object(object)), None) =
+        (cache_index, &reference.base, reference.this_value)
+    {
+        let object = *object;
+        let key = reference.referenced_name;
+        if let Some(value) = get_cached_property(agent, executable, cache_index, object, key) {
+            return Ok(value);
+        }
+        update_property_lookup_cache(agent, executable, cache_index, object, key, false);
+    }
+    get_value(agent, reference, gc)
+}
+
+/// PutValue for references created by property access instructions: Writable
+/// data properties of ordinary objects are written through the instruction's
+/// inline cache. Bindings held in registers are written to the register file.
+#[allow(clippy::too_many_arguments)]
+fn put_value_with_cache(
+    agent: &mut Agent,
+    executable: Executable,
+    registers: &mut [Value],
+    cache_index: Option<usize>,
+    reference: &Reference,
+    value: Value,
+    gc: GcScope,
+) -> JsResult<()> {
+    if let Base::Register(register) = reference.base {
+        registers[register as usize] = value;
+        return Ok(());
+    }
+    if let (Some(cache_index), Base::Value(Value::Object(object)), None) =
+        (cache_index, &reference.base, reference.this_value)
+    {
+        let object = *object;
+        let key = reference.referenced_name;
+        if set_cached_property(agent, executable, cache_index, object, key, value) {
+            return Ok(());
+        }
+        update_property_lookup_cache(agent, executable, cache_index, object, key, true);
+    }
+    put_value(agent, reference, value, gc)
+}
+
 /// ### [13.10.2 InstanceofOperator ( V, target )](https://tc39.es/ecma262/#sec-instanceofoperator)
 ///
 /// The abstract operation InstanceofOperator takes arguments V (an ECMAScript
@@ -2397,6 +2674,7 @@ impl HeapMarkAndSweep for ExceptionJumpTarget {
         let Self {
             ip: _,
             lexical_environment,
+            stack_len: _,
         } = self;
         lexical_environment.mark_values(queues);
     }
@@ -2405,6 +2683,7 @@ impl HeapMarkAndSweep for ExceptionJumpTarget {
         let Self {
             ip: _,
             lexical_environment,
+            stack_len: _,
         } = self;
         lexical_environment.sweep_values(compactions);
     }
@@ -2413,14 +2692,19 @@ impl HeapMarkAndSweep for ExceptionJumpTarget {
 impl HeapMarkAndSweep for Vm {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Vm {
+            executable,
             ip: _,
+            registers,
             stack,
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
             result,
             reference,
+            reference_cache_index: _,
         } = self;
+        executable.mark_values(queues);
+        registers.mark_values(queues);
         stack.as_slice().mark_values(queues);
         reference_stack.as_slice().mark_values(queues);
         iterator_stack.as_slice().mark_values(queues);
@@ -2431,14 +2715,19 @@ impl HeapMarkAndSweep for Vm {
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Vm {
+            executable,
             ip: _,
+            registers,
             stack,
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
             result,
             reference,
+            reference_cache_index: _,
         } = self;
+        executable.sweep_values(compactions);
+        registers.sweep_values(compactions);
         stack.as_mut_slice().sweep_values(compactions);
         reference_stack.as_mut_slice().sweep_values(compactions);
         iterator_stack.as_mut_slice().sweep_values(compactions);
@@ -2454,11 +2743,13 @@ impl HeapMarkAndSweep for SuspendedVm {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
             ip: _,
+            registers,
             stack,
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
         } = self;
+        registers.mark_values(queues);
         stack.mark_values(queues);
         reference_stack.mark_values(queues);
         iterator_stack.mark_values(queues);
@@ -2468,11 +2759,13 @@ impl HeapMarkAndSweep for SuspendedVm {
     fn sweep_values(&mut self, compactions: &CompactionLists) {
         let Self {
             ip: _,
+            registers,
             stack,
             reference_stack,
             iterator_stack,
             exception_jump_target_stack,
         } = self;
+        registers.sweep_values(compactions);
         stack.sweep_values(compactions);
         reference_stack.sweep_values(compactions);
         iterator_stack.sweep_values(compactions);
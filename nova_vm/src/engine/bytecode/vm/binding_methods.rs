@@ -38,6 +38,7 @@ pub(super) fn execute_simple_array_binding(
 
         let value = match instr.kind {
             Instruction::BindingPatternBind
+            | Instruction::BindingPatternBindToRegister
             | Instruction::BindingPatternGetValue
             | Instruction::BindingPatternSkip => {
                 let result = iterator.step_value(agent, gc.reborrow())?;
@@ -95,6 +96,9 @@ pub(super) fn execute_simple_array_binding(
                     initialize_referenced_binding(agent, lhs.unbind(), value, gc.reborrow())?;
                 }
             }
+            Instruction::BindingPatternBindToRegister => {
+                vm.registers[instr.args[0].unwrap() as usize] = value;
+            }
             Instruction::BindingPatternGetValue | Instruction::BindingPatternGetRestValue => {
                 execute_nested_simple_binding(
                     agent,
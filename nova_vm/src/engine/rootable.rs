@@ -9,6 +9,8 @@ use private::RootableSealed;
 
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
@@ -19,6 +21,8 @@ use crate::ecmascript::builtins::{data_view::DataView, ArrayBuffer};
 use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
 #[cfg(feature = "date")]
 use crate::ecmascript::types::DATE_DISCRIMINANT;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::types::DISPOSABLE_STACK_DISCRIMINANT;
 #[cfg(feature = "proposal-float16array")]
 use crate::ecmascript::types::FLOAT_16_ARRAY_DISCRIMINANT;
 #[cfg(feature = "regexp")]
@@ -49,13 +53,17 @@ use crate::heap::indexes::TypedArrayIndex;
 use crate::{
     ecmascript::{
         builtins::{
+            arguments::MappedArgumentsObject,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
             embedder_object::EmbedderObject,
             error::Error,
             finalization_registry::FinalizationRegistry,
             generator_objects::Generator,
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+            indexed_collections::array_objects::{
+                array_from_async_reaction::ArrayFromAsyncReactionIdentifier,
+                array_iterator_objects::array_iterator::ArrayIterator,
+            },
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
             map::Map,
             module::Module,
@@ -79,9 +87,9 @@ use crate::{
             BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
             ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT,
             FINALIZATION_REGISTRY_DISCRIMINANT, GENERATOR_DISCRIMINANT, ITERATOR_DISCRIMINANT,
-            MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT,
-            OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, STRING_DISCRIMINANT,
-            SYMBOL_DISCRIMINANT,
+            MAPPED_ARGUMENTS_DISCRIMINANT, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT,
+            MODULE_DISCRIMINANT, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT,
+            PROXY_DISCRIMINANT, STRING_DISCRIMINANT, SYMBOL_DISCRIMINANT,
         },
     },
     heap::HeapMarkAndSweep,
@@ -90,6 +98,8 @@ use crate::{
 mod private {
     #[cfg(feature = "date")]
     use crate::ecmascript::builtins::date::Date;
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    use crate::ecmascript::builtins::disposable_stack::DisposableStack;
     #[cfg(feature = "regexp")]
     use crate::ecmascript::builtins::regexp::RegExp;
     #[cfg(feature = "shared-array-buffer")]
@@ -104,13 +114,17 @@ mod private {
     use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
     use crate::ecmascript::{
         builtins::{
+            arguments::MappedArgumentsObject,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
             embedder_object::EmbedderObject,
             error::Error,
             finalization_registry::FinalizationRegistry,
             generator_objects::Generator,
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+            indexed_collections::array_objects::{
+                array_from_async_reaction::ArrayFromAsyncReactionIdentifier,
+                array_iterator_objects::array_iterator::ArrayIterator,
+            },
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
             map::Map,
             module::Module,
@@ -145,6 +159,8 @@ mod private {
     impl RootableSealed for DataView<'_> {}
     #[cfg(feature = "date")]
     impl RootableSealed for Date<'_> {}
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    impl RootableSealed for DisposableStack<'_> {}
     impl RootableSealed for ECMAScriptFunction<'_> {}
     impl RootableSealed for EmbedderObject<'_> {}
     impl RootableSealed for Error<'_> {}
@@ -153,6 +169,7 @@ mod private {
     impl RootableSealed for Generator<'_> {}
     impl RootableSealed for Map<'_> {}
     impl RootableSealed for MapIterator<'_> {}
+    impl RootableSealed for MappedArgumentsObject<'_> {}
     impl RootableSealed for Module<'_> {}
     impl RootableSealed for Number<'_> {}
     impl RootableSealed for Numeric<'_> {}
@@ -162,6 +179,7 @@ mod private {
     impl RootableSealed for PrimitiveObject<'_> {}
     impl RootableSealed for Promise<'_> {}
     impl RootableSealed for PromiseReaction {}
+    impl RootableSealed for ArrayFromAsyncReactionIdentifier {}
     impl RootableSealed for PropertyKey<'_> {}
     impl RootableSealed for Proxy<'_> {}
     #[cfg(feature = "regexp")]
@@ -271,6 +289,7 @@ pub enum HeapRootData {
     BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
     PrimitiveObject(PrimitiveObject<'static>),
     Arguments(OrdinaryObject<'static>) = ARGUMENTS_DISCRIMINANT,
+    MappedArguments(MappedArgumentsObject<'static>) = MAPPED_ARGUMENTS_DISCRIMINANT,
     Array(Array<'static>) = ARRAY_DISCRIMINANT,
     #[cfg(feature = "array-buffer")]
     ArrayBuffer(ArrayBuffer<'static>) = ARRAY_BUFFER_DISCRIMINANT,
@@ -278,6 +297,8 @@ pub enum HeapRootData {
     DataView(DataView<'static>) = DATA_VIEW_DISCRIMINANT,
     #[cfg(feature = "date")]
     Date(Date<'static>) = DATE_DISCRIMINANT,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack(DisposableStack<'static>) = DISPOSABLE_STACK_DISCRIMINANT,
     Error(Error<'static>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'static>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'static>) = MAP_DISCRIMINANT,
@@ -337,6 +358,7 @@ pub enum HeapRootData {
     // The order here shouldn't be important at all, feel free to eg. keep
     // these in alphabetical order.
     PromiseReaction(PromiseReaction),
+    ArrayFromAsyncReaction(ArrayFromAsyncReactionIdentifier),
 }
 
 impl From<Object<'static>> for HeapRootData {
@@ -360,10 +382,13 @@ impl From<Object<'static>> for HeapRootData {
             Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
             Object::PrimitiveObject(primitive_object) => Self::PrimitiveObject(primitive_object),
             Object::Arguments(ordinary_object) => Self::Arguments(ordinary_object),
+            Object::MappedArguments(mapped_arguments) => Self::MappedArguments(mapped_arguments),
             Object::Array(array) => Self::Array(array),
             Object::ArrayBuffer(array_buffer) => Self::ArrayBuffer(array_buffer),
             Object::DataView(data_view) => Self::DataView(data_view),
             Object::Date(date) => Self::Date(date),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            Object::DisposableStack(disposable_stack) => Self::DisposableStack(disposable_stack),
             Object::Error(error) => Self::Error(error),
             Object::FinalizationRegistry(finalization_registry) => {
                 Self::FinalizationRegistry(finalization_registry)
@@ -468,6 +493,7 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::BuiltinProxyRevokerFunction => todo!(),
             HeapRootData::PrimitiveObject(primitive_object) => primitive_object.mark_values(queues),
             HeapRootData::Arguments(ordinary_object) => ordinary_object.mark_values(queues),
+            HeapRootData::MappedArguments(mapped_arguments) => mapped_arguments.mark_values(queues),
             HeapRootData::Array(array) => array.mark_values(queues),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => array_buffer.mark_values(queues),
@@ -475,6 +501,8 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::DataView(data_view) => data_view.mark_values(queues),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => date.mark_values(queues),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => disposable_stack.mark_values(queues),
             HeapRootData::Error(error) => error.mark_values(queues),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.mark_values(queues)
@@ -531,6 +559,9 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::Module(module) => module.mark_values(queues),
             HeapRootData::EmbedderObject(embedder_object) => embedder_object.mark_values(queues),
             HeapRootData::PromiseReaction(promise_reaction) => promise_reaction.mark_values(queues),
+            HeapRootData::ArrayFromAsyncReaction(array_from_async_reaction) => {
+                array_from_async_reaction.mark_values(queues)
+            }
         }
     }
 
@@ -561,6 +592,9 @@ impl HeapMarkAndSweep for HeapRootData {
                 primitive_object.sweep_values(compactions)
             }
             HeapRootData::Arguments(ordinary_object) => ordinary_object.sweep_values(compactions),
+            HeapRootData::MappedArguments(mapped_arguments) => {
+                mapped_arguments.sweep_values(compactions)
+            }
             HeapRootData::Array(array) => array.sweep_values(compactions),
             #[cfg(feature = "array-buffer")]
             HeapRootData::ArrayBuffer(array_buffer) => array_buffer.sweep_values(compactions),
@@ -568,6 +602,10 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::DataView(data_view) => data_view.sweep_values(compactions),
             #[cfg(feature = "date")]
             HeapRootData::Date(date) => date.sweep_values(compactions),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            HeapRootData::DisposableStack(disposable_stack) => {
+                disposable_stack.sweep_values(compactions)
+            }
             HeapRootData::Error(error) => error.sweep_values(compactions),
             HeapRootData::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.sweep_values(compactions)
@@ -628,6 +666,9 @@ impl HeapMarkAndSweep for HeapRootData {
             HeapRootData::PromiseReaction(promise_reaction) => {
                 promise_reaction.sweep_values(compactions)
             }
+            HeapRootData::ArrayFromAsyncReaction(array_from_async_reaction) => {
+                array_from_async_reaction.sweep_values(compactions)
+            }
         }
     }
 }
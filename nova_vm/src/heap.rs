@@ -6,8 +6,11 @@ pub mod element_array;
 mod heap_bits;
 mod heap_constants;
 pub(crate) mod heap_gc;
+pub(crate) mod heap_snapshot;
+mod heap_statistics;
 pub mod indexes;
 mod object_entry;
+pub(crate) mod remembered_set;
 
 use core::{cell::RefCell, ops::Index};
 
@@ -20,16 +23,22 @@ pub(crate) use self::heap_constants::{
 pub(crate) use self::heap_constants::{
     LAST_INTRINSIC_CONSTRUCTOR_INDEX, LAST_INTRINSIC_FUNCTION_INDEX, LAST_INTRINSIC_OBJECT_INDEX,
 };
+pub use self::heap_statistics::{HeapDataStatistics, HeapStatistics};
 pub(crate) use self::object_entry::{ObjectEntry, ObjectEntryPropertyDescriptor};
 use self::{
     element_array::{
         ElementArray2Pow10, ElementArray2Pow12, ElementArray2Pow16, ElementArray2Pow24,
         ElementArray2Pow32, ElementArray2Pow4, ElementArray2Pow6, ElementArray2Pow8, ElementArrays,
+        ElementDescriptor, ElementsVector,
     },
+    heap_gc::IncrementalMarking,
     indexes::{NumberIndex, ObjectIndex},
+    remembered_set::RememberedSets,
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::data::DateHeapData;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::data::DisposableStackHeapData;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
@@ -53,6 +62,7 @@ use crate::ecmascript::builtins::{
 use crate::{
     ecmascript::{
         builtins::{
+            arguments::data::MappedArgumentsHeapData,
             array_buffer::DetachKey,
             async_generator_objects::AsyncGeneratorHeapData,
             control_abstraction_objects::{
@@ -66,7 +76,10 @@ use crate::{
             embedder_object::data::EmbedderObjectHeapData,
             error::ErrorHeapData,
             finalization_registry::data::FinalizationRegistryHeapData,
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIteratorHeapData,
+            indexed_collections::array_objects::{
+                array_from_async_reaction::ArrayFromAsyncReaction,
+                array_iterator_objects::array_iterator::ArrayIteratorHeapData,
+            },
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIteratorHeapData,
             map::data::MapHeapData,
             module::data::ModuleHeapData,
@@ -84,8 +97,9 @@ use crate::{
         types::{
             bigint::HeapBigInt, BigIntHeapData, BoundFunctionHeapData, BuiltinConstructorHeapData,
             BuiltinFunctionHeapData, ECMAScriptFunctionHeapData, HeapNumber, HeapString,
-            NumberHeapData, Object, ObjectHeapData, OrdinaryObject, String, StringHeapData,
-            SymbolHeapData, BUILTIN_STRINGS_LIST,
+            NumberHeapData, Object, ObjectHeapData, ObjectShape, ObjectShapeHeapData,
+            OrdinaryObject, PropertyKey, String, StringHeapData, SymbolHeapData, Value,
+            BUILTIN_STRINGS_LIST,
         },
     },
     engine::{rootable::HeapRootData, ExecutableHeapData},
@@ -93,7 +107,7 @@ use crate::{
 #[cfg(feature = "array-buffer")]
 use ahash::AHashMap;
 use hashbrown::HashTable;
-pub(crate) use heap_bits::{CompactionLists, HeapMarkAndSweep, WorkQueues};
+pub(crate) use heap_bits::{CompactionLists, HeapMarkAndSweep, MarkBits, WorkQueues};
 use wtf8::Wtf8;
 
 #[derive(Debug)]
@@ -105,6 +119,7 @@ pub struct Heap {
     pub arrays: Vec<Option<ArrayHeapData>>,
     pub array_iterators: Vec<Option<ArrayIteratorHeapData>>,
     pub async_generators: Vec<Option<AsyncGeneratorHeapData>>,
+    pub(crate) array_from_async_reactions: Vec<Option<ArrayFromAsyncReaction>>,
     pub(crate) await_reactions: Vec<Option<AwaitReaction>>,
     pub bigints: Vec<Option<BigIntHeapData>>,
     pub bound_functions: Vec<Option<BoundFunctionHeapData>>,
@@ -118,6 +133,8 @@ pub struct Heap {
     pub data_view_byte_offsets: AHashMap<DataView<'static>, usize>,
     #[cfg(feature = "date")]
     pub dates: Vec<Option<DateHeapData>>,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: Vec<Option<DisposableStackHeapData>>,
     pub ecmascript_functions: Vec<Option<ECMAScriptFunctionHeapData>>,
     /// ElementsArrays is where all element arrays live;
     /// Element arrays are static arrays of Values plus
@@ -133,8 +150,10 @@ pub struct Heap {
     pub(crate) globals: RefCell<Vec<Option<HeapRootData>>>,
     pub maps: Vec<Option<MapHeapData>>,
     pub map_iterators: Vec<Option<MapIteratorHeapData>>,
+    pub mapped_arguments: Vec<Option<MappedArgumentsHeapData>>,
     pub numbers: Vec<Option<NumberHeapData>>,
     pub objects: Vec<Option<ObjectHeapData>>,
+    pub(crate) object_shapes: Vec<Option<ObjectShapeHeapData>>,
     pub primitive_objects: Vec<Option<PrimitiveObjectHeapData>>,
     pub promise_reaction_records: Vec<Option<PromiseReactionRecord>>,
     pub promise_resolving_functions: Vec<Option<PromiseResolvingFunctionHeapData>>,
@@ -174,6 +193,29 @@ pub struct Heap {
     pub strings: Vec<Option<StringHeapData>>,
     pub string_lookup_table: HashTable<HeapString<'static>>,
     pub string_hasher: ahash::RandomState,
+    /// Number of bytes allocated onto the heap since the last garbage
+    /// collection, not counting element arrays and environments which keep
+    /// their own counts.
+    pub(crate) alloc_counter: usize,
+    /// Number of bytes that can be allocated before the next garbage
+    /// collection is due.
+    pub(crate) gc_allocation_budget: usize,
+    /// Estimated heap size in bytes at or above which the next garbage
+    /// collection that is due is a full collection rather than a minor one.
+    pub(crate) gc_major_threshold: usize,
+    /// Estimated heap size in bytes after the last garbage collection. Only
+    /// full collections measure the heap: Minor collections add the bytes
+    /// allocated before them, as if all of it survived.
+    pub(crate) gc_live_bytes: usize,
+    /// Number of bytes that the heap may grow past the heap size limit after
+    /// an out of memory error was thrown, so that the program can handle the
+    /// error. This is reset once a full collection finds the heap within the
+    /// limit.
+    pub(crate) gc_heap_limit_grace: usize,
+    /// Old generations and write barrier records of the heap vectors.
+    pub(crate) remembered_sets: RememberedSets,
+    /// Marking state of the full garbage collection in progress, if any.
+    pub(crate) incremental_marking: Option<Box<IncrementalMarking>>,
 }
 
 pub trait CreateHeapData<T, F> {
@@ -214,6 +256,7 @@ impl Heap {
             arrays: Vec::with_capacity(1024),
             array_iterators: Vec::with_capacity(256),
             async_generators: Vec::with_capacity(0),
+            array_from_async_reactions: Vec::with_capacity(0),
             await_reactions: Vec::with_capacity(1024),
             bigints: Vec::with_capacity(1024),
             bound_functions: Vec::with_capacity(256),
@@ -227,6 +270,8 @@ impl Heap {
             data_view_byte_offsets: AHashMap::with_capacity(0),
             #[cfg(feature = "date")]
             dates: Vec::with_capacity(1024),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks: Vec::with_capacity(0),
             ecmascript_functions: Vec::with_capacity(1024),
             elements: ElementArrays {
                 e2pow4: ElementArray2Pow4::with_capacity(1024),
@@ -237,6 +282,7 @@ impl Heap {
                 e2pow16: ElementArray2Pow16::default(),
                 e2pow24: ElementArray2Pow24::default(),
                 e2pow32: ElementArray2Pow32::default(),
+                alloc_counter: 0,
             },
             embedder_objects: Vec::with_capacity(0),
             environments: Default::default(),
@@ -248,9 +294,11 @@ impl Heap {
             globals: RefCell::new(Vec::with_capacity(1024)),
             maps: Vec::with_capacity(128),
             map_iterators: Vec::with_capacity(128),
+            mapped_arguments: Vec::with_capacity(0),
             modules: Vec::with_capacity(0),
             numbers: Vec::with_capacity(1024),
             objects: Vec::with_capacity(1024),
+            object_shapes: vec![Some(ObjectShapeHeapData::default())],
             primitive_objects: Vec::with_capacity(0),
             promise_reaction_records: Vec::with_capacity(0),
             promise_resolving_functions: Vec::with_capacity(0),
@@ -284,6 +332,13 @@ impl Heap {
             weak_refs: Vec::with_capacity(0),
             #[cfg(feature = "weak-refs")]
             weak_sets: Vec::with_capacity(0),
+            alloc_counter: 0,
+            gc_allocation_budget: usize::MAX,
+            gc_major_threshold: usize::MAX,
+            gc_live_bytes: 0,
+            gc_heap_limit_grace: 0,
+            remembered_sets: Default::default(),
+            incremental_marking: None,
         };
 
         for builtin_string in BUILTIN_STRINGS_LIST {
@@ -293,6 +348,23 @@ impl Heap {
         heap
     }
 
+    /// Returns the number of bytes allocated since the last garbage
+    /// collection.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        self.alloc_counter + self.elements.alloc_counter + self.environments.alloc_counter
+    }
+
+    /// Returns an estimate of the number of bytes used by the heap, based on
+    /// its size after the last garbage collection.
+    pub(crate) fn used_bytes(&self) -> usize {
+        self.gc_live_bytes + self.allocated_bytes()
+    }
+
+    /// Returns an estimate of the number of bytes used by heap data.
+    pub(crate) fn estimated_size(&self) -> usize {
+        self.statistics().total_bytes()
+    }
+
     pub(crate) fn add_module(&mut self, module: ModuleHeapData) -> ModuleIdentifier<'static> {
         self.modules.push(Some(module));
         ModuleIdentifier::last(&self.modules)
@@ -404,6 +476,7 @@ impl Heap {
     pub unsafe fn alloc_number<'gc>(&mut self, number: f64) -> HeapNumber<'gc> {
         debug_assert!(number.fract() != 0.0 || number as f32 as f64 != number);
         self.numbers.push(Some(number.into()));
+        self.alloc_counter += core::mem::size_of::<Option<NumberHeapData>>();
         HeapNumber(NumberIndex::last(&self.numbers))
     }
 
@@ -411,14 +484,15 @@ impl Heap {
         &mut self,
         entries: &[ObjectEntry],
     ) -> OrdinaryObject<'static> {
-        let (keys, values) = self.elements.create_object_entries(entries);
+        let (shape, values) = self.create_object_entries(entries);
         let object_data = ObjectHeapData {
             extensible: true,
-            keys,
+            shape,
             values,
             prototype: None,
         };
         self.objects.push(Some(object_data));
+        self.alloc_counter += core::mem::size_of::<Option<ObjectHeapData>>();
         ObjectIndex::last(&self.objects).into()
     }
 
@@ -427,16 +501,51 @@ impl Heap {
         prototype: Object,
         entries: &[ObjectEntry],
     ) -> OrdinaryObject<'static> {
-        let (keys, values) = self.elements.create_object_entries(entries);
+        let (shape, values) = self.create_object_entries(entries);
         let object_data = ObjectHeapData {
             extensible: true,
-            keys,
+            shape,
             values,
             prototype: Some(prototype.unbind()),
         };
         self.objects.push(Some(object_data));
+        self.alloc_counter += core::mem::size_of::<Option<ObjectHeapData>>();
         ObjectIndex::last(&self.objects).into()
     }
+
+    /// Creates the Shape and values vector of an ordinary object with the
+    /// given properties.
+    pub(crate) fn create_object_entries(
+        &mut self,
+        entries: &[ObjectEntry],
+    ) -> (ObjectShape, ElementsVector) {
+        let shape = ObjectShape::from_keys(
+            &mut self.object_shapes,
+            &mut self.remembered_sets.object_shapes,
+            entries.iter().map(|entry| entry.key),
+        );
+        let values = self.elements.create_object_entries(entries);
+        (shape, values)
+    }
+
+    /// Creates the Shape and values vector of an ordinary object with the
+    /// given properties.
+    pub(crate) fn create_with_stuff(
+        &mut self,
+        entries: Vec<(
+            PropertyKey<'static>,
+            Option<ElementDescriptor>,
+            Option<Value>,
+        )>,
+    ) -> (ObjectShape, ElementsVector) {
+        let shape = ObjectShape::from_keys(
+            &mut self.object_shapes,
+            &mut self.remembered_sets.object_shapes,
+            entries.iter().map(|(key, _, _)| *key),
+        );
+        let values = self.elements.create_with_stuff(entries);
+        (shape, values)
+    }
 }
 
 impl Default for Heap {
@@ -7,6 +7,7 @@ use ahash::AHashMap;
 use super::{
     indexes::ElementIndex,
     object_entry::{ObjectEntry, ObjectEntryPropertyDescriptor},
+    remembered_set::RememberedElements,
     CompactionLists, HeapMarkAndSweep, WorkQueues,
 };
 use crate::{
@@ -176,6 +177,16 @@ impl ElementsVector {
         true
     }
 
+    /// Returns the number of bytes that reserving room for `new_len` elements
+    /// allocates onto the heap.
+    pub(crate) fn reserve_bytes(&self, new_len: u32) -> usize {
+        if new_len <= self.cap() {
+            0
+        } else {
+            ElementArrayKey::from(new_len).cap() as usize * core::mem::size_of::<Option<Value>>()
+        }
+    }
+
     pub fn reserve(&mut self, elements: &mut ElementArrays, new_len: u32) {
         if new_len <= self.cap() {
             // Enough capacity present already
@@ -240,6 +251,7 @@ impl ElementsVector {
                 .insert(self.len, descriptor);
         }
         self.len += 1;
+        elements.remember(*self);
     }
 
     pub fn remove(&mut self, elements: &mut ElementArrays, index: usize) {
@@ -991,6 +1003,7 @@ impl ElementDescriptor {
 pub struct ElementArray2Pow4 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 4)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow4 {
@@ -998,6 +1011,7 @@ impl ElementArray2Pow4 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1007,6 +1021,7 @@ impl ElementArray2Pow4 {
 pub struct ElementArray2Pow6 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 6)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow6 {
@@ -1014,6 +1029,7 @@ impl ElementArray2Pow6 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1023,6 +1039,7 @@ impl ElementArray2Pow6 {
 pub struct ElementArray2Pow8 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 8)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow8 {
@@ -1030,6 +1047,7 @@ impl ElementArray2Pow8 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1039,6 +1057,7 @@ impl ElementArray2Pow8 {
 pub struct ElementArray2Pow10 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 10)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow10 {
@@ -1046,6 +1065,7 @@ impl ElementArray2Pow10 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1055,6 +1075,7 @@ impl ElementArray2Pow10 {
 pub struct ElementArray2Pow12 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 12)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow12 {
@@ -1062,6 +1083,7 @@ impl ElementArray2Pow12 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1071,6 +1093,7 @@ impl ElementArray2Pow12 {
 pub struct ElementArray2Pow16 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 16)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow16 {
@@ -1078,6 +1101,7 @@ impl ElementArray2Pow16 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1087,6 +1111,7 @@ impl ElementArray2Pow16 {
 pub struct ElementArray2Pow24 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 24)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow24 {
@@ -1094,6 +1119,7 @@ impl ElementArray2Pow24 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1103,6 +1129,7 @@ impl ElementArray2Pow24 {
 pub struct ElementArray2Pow32 {
     pub values: Vec<Option<[Option<Value>; usize::pow(2, 32)]>>,
     pub descriptors: AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    pub(crate) remembered: RememberedElements,
 }
 
 impl ElementArray2Pow32 {
@@ -1110,6 +1137,7 @@ impl ElementArray2Pow32 {
         Self {
             values: Vec::with_capacity(cap),
             descriptors: Default::default(),
+            remembered: Default::default(),
         }
     }
 }
@@ -1132,6 +1160,9 @@ pub struct ElementArrays {
     pub e2pow24: ElementArray2Pow24,
     /// up to 4294967296 elements
     pub e2pow32: ElementArray2Pow32,
+    /// Number of bytes of element storage allocated since the last garbage
+    /// collection.
+    pub(crate) alloc_counter: usize,
 }
 
 impl Index<ElementsVector> for ElementArrays {
@@ -1202,6 +1233,7 @@ impl ElementArrays {
             core::mem::size_of::<[Option<Value>; 1]>()
         );
         let length = vector.len();
+        self.alloc_counter += key.cap() as usize * core::mem::size_of::<Option<Value>>();
         match key {
             ElementArrayKey::Empty => {
                 assert!(vector.is_empty() && descriptors.is_none());
@@ -1552,7 +1584,9 @@ impl ElementArrays {
             e2pow16,
             e2pow24,
             e2pow32,
+            alloc_counter,
         } = self;
+        *alloc_counter += new_key.cap() as usize * core::mem::size_of::<Option<Value>>();
         debug_assert_eq!(
             core::mem::size_of::<Option<[Option<Value>; 1]>>(),
             core::mem::size_of::<[Option<Value>; 1]>()
@@ -1847,6 +1881,22 @@ impl ElementArrays {
         elements_vector.elements_index = new_index;
     }
 
+    /// Records a write to the element array in its remembered set.
+    fn remember(&mut self, vector: ElementsVector) {
+        let index = vector.elements_index.into_index();
+        match vector.cap {
+            ElementArrayKey::Empty => {}
+            ElementArrayKey::E4 => self.e2pow4.remembered.remember(index, vector.len),
+            ElementArrayKey::E6 => self.e2pow6.remembered.remember(index, vector.len),
+            ElementArrayKey::E8 => self.e2pow8.remembered.remember(index, vector.len),
+            ElementArrayKey::E10 => self.e2pow10.remembered.remember(index, vector.len),
+            ElementArrayKey::E12 => self.e2pow12.remembered.remember(index, vector.len),
+            ElementArrayKey::E16 => self.e2pow16.remembered.remember(index, vector.len),
+            ElementArrayKey::E24 => self.e2pow24.remembered.remember(index, vector.len),
+            ElementArrayKey::E32 => self.e2pow32.remembered.remember(index, vector.len),
+        }
+    }
+
     pub fn allocate_elements_with_capacity(&mut self, capacity: usize) -> ElementsVector {
         let cap = ElementArrayKey::from(capacity);
         ElementsVector {
@@ -1863,20 +1913,12 @@ impl ElementArrays {
             Option<ElementDescriptor>,
             Option<Value>,
         )>,
-    ) -> (ElementsVector, ElementsVector) {
+    ) -> ElementsVector {
         let length = entries.len();
-        let mut keys: Vec<Option<Value>> = Vec::with_capacity(length);
         let mut values: Vec<Option<Value>> = Vec::with_capacity(length);
         let mut descriptors: Option<AHashMap<u32, ElementDescriptor>> = None;
         entries.drain(..).enumerate().for_each(|(index, entry)| {
-            let (key, maybe_descriptor, maybe_value) = entry;
-            let key = match key {
-                PropertyKey::Integer(data) => Value::Integer(data),
-                PropertyKey::SmallString(data) => Value::SmallString(data),
-                PropertyKey::String(data) => Value::String(data),
-                PropertyKey::Symbol(data) => Value::Symbol(data),
-            };
-            keys.push(Some(key));
+            let (_, maybe_descriptor, maybe_value) = entry;
             values.push(maybe_value);
             if let Some(descriptor) = maybe_descriptor {
                 if descriptors.is_none() {
@@ -1890,41 +1932,22 @@ impl ElementArrays {
         });
         let cap = ElementArrayKey::from(length);
         let len = length as u32;
-        let key_elements_index = self.push_with_key(cap, &keys, None);
         let value_elements_index = self.push_with_key(cap, &values, descriptors);
-        (
-            ElementsVector {
-                elements_index: key_elements_index,
-                cap,
-                len,
-            },
-            ElementsVector {
-                elements_index: value_elements_index,
-                cap,
-                len,
-            },
-        )
+        ElementsVector {
+            elements_index: value_elements_index,
+            cap,
+            len,
+        }
     }
 
-    pub(crate) fn create_object_entries(
-        &mut self,
-        entries: &[ObjectEntry<'_>],
-    ) -> (ElementsVector, ElementsVector) {
+    pub(crate) fn create_object_entries(&mut self, entries: &[ObjectEntry<'_>]) -> ElementsVector {
         let length = entries.len();
-        let mut keys: Vec<Option<Value>> = Vec::with_capacity(length);
         let mut values: Vec<Option<Value>> = Vec::with_capacity(length);
         let mut descriptors: Option<AHashMap<u32, ElementDescriptor>> = None;
         for (index, entry) in entries.iter().enumerate() {
-            let ObjectEntry { key, value } = entry;
+            let ObjectEntry { value, .. } = entry;
             let (maybe_descriptor, maybe_value) =
                 ElementDescriptor::from_object_entry_property_descriptor(value);
-            let key = match *key {
-                PropertyKey::Integer(data) => Value::Integer(data),
-                PropertyKey::SmallString(data) => Value::SmallString(data),
-                PropertyKey::String(data) => Value::String(data.unbind()),
-                PropertyKey::Symbol(data) => Value::Symbol(data.unbind()),
-            };
-            keys.push(Some(key));
             values.push(maybe_value);
             if let Some(descriptor) = maybe_descriptor {
                 if descriptors.is_none() {
@@ -1938,20 +1961,12 @@ impl ElementArrays {
         }
         let cap = ElementArrayKey::from(length);
         let len = length as u32;
-        let key_elements_index = self.push_with_key(cap, &keys, None);
         let value_elements_index = self.push_with_key(cap, &values, descriptors);
-        (
-            ElementsVector {
-                elements_index: key_elements_index,
-                cap,
-                len,
-            },
-            ElementsVector {
-                elements_index: value_elements_index,
-                cap,
-                len,
-            },
-        )
+        ElementsVector {
+            elements_index: value_elements_index,
+            cap,
+            len,
+        }
     }
 
     pub fn get(&self, vector: ElementsVector) -> &[Option<Value>] {
@@ -1985,6 +2000,7 @@ impl ElementArrays {
     }
 
     pub fn get_mut(&mut self, vector: ElementsVector) -> &mut [Option<Value>] {
+        self.remember(vector);
         match vector.cap {
             ElementArrayKey::Empty => &mut [],
             ElementArrayKey::E4 => &mut self.e2pow4.values[vector.elements_index].as_mut_slice()
@@ -2127,6 +2143,7 @@ impl ElementArrays {
         Option<&mut AHashMap<u32, ElementDescriptor>>,
         &mut [Option<Value>],
     ) {
+        self.remember(vector);
         let usize_index = vector.elements_index.into_index();
         match vector.cap {
             ElementArrayKey::Empty => (None, &mut []),
@@ -2270,6 +2287,7 @@ impl ElementArrays {
     ) {
         let index: u32 = index.try_into().unwrap();
         assert!(index < vector.len);
+        self.remember(vector);
         let descriptors = match vector.cap {
             ElementArrayKey::Empty => unreachable!(),
             ElementArrayKey::E4 => &mut self.e2pow4.descriptors,
@@ -2328,6 +2346,8 @@ impl ElementArrays {
     /// It does not do anything with descriptors and assumes there is a previous validation in place.
     pub fn shallow_clone(&mut self, elements_vector: ElementsVector) -> SealableElementsVector {
         let index = elements_vector.elements_index.into_index();
+        self.alloc_counter +=
+            elements_vector.cap.cap() as usize * core::mem::size_of::<Option<Value>>();
         let ElementArrays {
             e2pow4,
             e2pow6,
@@ -2337,6 +2357,7 @@ impl ElementArrays {
             e2pow16,
             e2pow24,
             e2pow32,
+            alloc_counter: _,
         } = self;
         let new_index = match elements_vector.cap {
             ElementArrayKey::Empty => ElementIndex::from_u32_index(0),
@@ -11,10 +11,13 @@ use super::indexes::TypedArrayIndex;
 use super::{
     element_array::{ElementArrayKey, ElementDescriptor, ElementsVector},
     indexes::{BaseIndex, ElementIndex, GetBaseIndexMut, IntoBaseIndex},
+    remembered_set::{RememberedElements, RememberedSet},
     Heap,
 };
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
@@ -29,6 +32,7 @@ use crate::ecmascript::builtins::{
 use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set::WeakSet};
 use crate::ecmascript::{
     builtins::{
+        arguments::MappedArgumentsObject,
         async_generator_objects::AsyncGenerator,
         bound_function::BoundFunction,
         control_abstraction_objects::{
@@ -42,7 +46,10 @@ use crate::ecmascript::{
         embedder_object::EmbedderObject,
         error::Error,
         finalization_registry::FinalizationRegistry,
-        indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+        indexed_collections::array_objects::{
+            array_from_async_reaction::ArrayFromAsyncReactionIdentifier,
+            array_iterator_objects::array_iterator::ArrayIterator,
+        },
         keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
         map::Map,
         module::Module,
@@ -57,77 +64,155 @@ use crate::ecmascript::{
     },
     scripts_and_modules::{script::ScriptIdentifier, source_code::SourceCode},
     types::{
-        bigint::HeapBigInt, HeapNumber, HeapString, OrdinaryObject, Symbol, Value,
+        bigint::HeapBigInt, HeapNumber, HeapString, ObjectShape, OrdinaryObject, Symbol, Value,
         BUILTIN_STRINGS_LIST,
     },
 };
 use crate::engine::Executable;
 
+/// Marks of an item in a heap vector.
+pub(crate) trait Mark: Copy {
+    fn is_marked(self) -> bool;
+}
+
+impl Mark for bool {
+    fn is_marked(self) -> bool {
+        self
+    }
+}
+
+impl<L: Copy> Mark for (bool, L) {
+    fn is_marked(self) -> bool {
+        self.0
+    }
+}
+
+/// Mark bits of a heap vector. Only the items from `old_len` onwards have
+/// bits: The items before it are the old generation of a minor garbage
+/// collection, which is considered marked and is not traced.
+#[derive(Debug)]
+pub struct MarkBits<T = bool> {
+    old_len: usize,
+    bits: Box<[T]>,
+}
+
+impl<T: Mark> MarkBits<T> {
+    fn new(old_len: usize, len: usize, unmarked: T) -> Self {
+        Self {
+            old_len,
+            bits: vec![unmarked; len - old_len].into_boxed_slice(),
+        }
+    }
+
+    /// Returns the mark of the young item at the index, or None if the item
+    /// is old or was allocated after the bits were created.
+    #[inline]
+    pub(crate) fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.bits.get_mut(index.checked_sub(self.old_len)?)
+    }
+
+    /// Returns the mark of the young item at the index, or None if the item
+    /// is old or was allocated after the bits were created.
+    #[inline]
+    pub(crate) fn get(&self, index: usize) -> Option<T> {
+        self.bits.get(index.checked_sub(self.old_len)?).copied()
+    }
+
+    /// Returns true if the item at the index is old or has been marked.
+    #[inline]
+    pub(crate) fn is_marked(&self, index: usize) -> bool {
+        index < self.old_len || self.bits[index - self.old_len].is_marked()
+    }
+
+    /// Number of items in the old generation.
+    pub(crate) fn old_len(&self) -> usize {
+        self.old_len
+    }
+
+    /// Number of items in the heap vector.
+    pub(crate) fn len(&self) -> usize {
+        self.old_len + self.bits.len()
+    }
+
+    /// Returns the indexes and marks of the young items.
+    pub(crate) fn iter_young(&self) -> impl Iterator<Item = (usize, T)> + '_ {
+        self.bits
+            .iter()
+            .enumerate()
+            .map(|(index, mark)| (self.old_len + index, *mark))
+    }
+}
+
 #[derive(Debug)]
 pub struct HeapBits {
     #[cfg(feature = "array-buffer")]
-    pub array_buffers: Box<[bool]>,
-    pub arrays: Box<[bool]>,
-    pub array_iterators: Box<[bool]>,
-    pub async_generators: Box<[bool]>,
-    pub await_reactions: Box<[bool]>,
-    pub bigints: Box<[bool]>,
-    pub bound_functions: Box<[bool]>,
-    pub builtin_constructors: Box<[bool]>,
-    pub builtin_functions: Box<[bool]>,
+    pub array_buffers: MarkBits,
+    pub arrays: MarkBits,
+    pub array_iterators: MarkBits,
+    pub async_generators: MarkBits,
+    pub array_from_async_reactions: MarkBits,
+    pub await_reactions: MarkBits,
+    pub bigints: MarkBits,
+    pub bound_functions: MarkBits,
+    pub builtin_constructors: MarkBits,
+    pub builtin_functions: MarkBits,
     #[cfg(feature = "array-buffer")]
-    pub data_views: Box<[bool]>,
+    pub data_views: MarkBits,
     #[cfg(feature = "date")]
-    pub dates: Box<[bool]>,
-    pub declarative_environments: Box<[bool]>,
-    pub e_2_10: Box<[(bool, u16)]>,
-    pub e_2_12: Box<[(bool, u16)]>,
-    pub e_2_16: Box<[(bool, u16)]>,
-    pub e_2_24: Box<[(bool, u32)]>,
-    pub e_2_32: Box<[(bool, u32)]>,
-    pub e_2_4: Box<[(bool, u8)]>,
-    pub e_2_6: Box<[(bool, u8)]>,
-    pub e_2_8: Box<[(bool, u8)]>,
-    pub ecmascript_functions: Box<[bool]>,
-    pub embedder_objects: Box<[bool]>,
-    pub errors: Box<[bool]>,
-    pub executables: Box<[bool]>,
-    pub source_codes: Box<[bool]>,
-    pub finalization_registrys: Box<[bool]>,
-    pub function_environments: Box<[bool]>,
-    pub generators: Box<[bool]>,
-    pub global_environments: Box<[bool]>,
-    pub maps: Box<[bool]>,
-    pub map_iterators: Box<[bool]>,
-    pub modules: Box<[bool]>,
-    pub numbers: Box<[bool]>,
-    pub object_environments: Box<[bool]>,
-    pub objects: Box<[bool]>,
-    pub primitive_objects: Box<[bool]>,
-    pub promise_reaction_records: Box<[bool]>,
-    pub promise_resolving_functions: Box<[bool]>,
-    pub promises: Box<[bool]>,
-    pub proxys: Box<[bool]>,
-    pub realms: Box<[bool]>,
+    pub dates: MarkBits,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: MarkBits,
+    pub declarative_environments: MarkBits,
+    pub e_2_10: MarkBits<(bool, u16)>,
+    pub e_2_12: MarkBits<(bool, u16)>,
+    pub e_2_16: MarkBits<(bool, u16)>,
+    pub e_2_24: MarkBits<(bool, u32)>,
+    pub e_2_32: MarkBits<(bool, u32)>,
+    pub e_2_4: MarkBits<(bool, u8)>,
+    pub e_2_6: MarkBits<(bool, u8)>,
+    pub e_2_8: MarkBits<(bool, u16)>,
+    pub ecmascript_functions: MarkBits,
+    pub embedder_objects: MarkBits,
+    pub errors: MarkBits,
+    pub executables: MarkBits,
+    pub source_codes: MarkBits,
+    pub finalization_registrys: MarkBits,
+    pub function_environments: MarkBits,
+    pub generators: MarkBits,
+    pub global_environments: MarkBits,
+    pub maps: MarkBits,
+    pub map_iterators: MarkBits,
+    pub mapped_arguments: MarkBits,
+    pub modules: MarkBits,
+    pub numbers: MarkBits,
+    pub object_environments: MarkBits,
+    pub objects: MarkBits,
+    pub object_shapes: MarkBits,
+    pub primitive_objects: MarkBits,
+    pub promise_reaction_records: MarkBits,
+    pub promise_resolving_functions: MarkBits,
+    pub promises: MarkBits,
+    pub proxys: MarkBits,
+    pub realms: MarkBits,
     #[cfg(feature = "regexp")]
-    pub regexps: Box<[bool]>,
-    pub scripts: Box<[bool]>,
+    pub regexps: MarkBits,
+    pub scripts: MarkBits,
     #[cfg(feature = "set")]
-    pub sets: Box<[bool]>,
+    pub sets: MarkBits,
     #[cfg(feature = "set")]
-    pub set_iterators: Box<[bool]>,
+    pub set_iterators: MarkBits,
     #[cfg(feature = "shared-array-buffer")]
-    pub shared_array_buffers: Box<[bool]>,
-    pub strings: Box<[bool]>,
-    pub symbols: Box<[bool]>,
+    pub shared_array_buffers: MarkBits,
+    pub strings: MarkBits,
+    pub symbols: MarkBits,
     #[cfg(feature = "array-buffer")]
-    pub typed_arrays: Box<[bool]>,
+    pub typed_arrays: MarkBits,
     #[cfg(feature = "weak-refs")]
-    pub weak_maps: Box<[bool]>,
+    pub weak_maps: MarkBits,
     #[cfg(feature = "weak-refs")]
-    pub weak_refs: Box<[bool]>,
+    pub weak_refs: MarkBits,
     #[cfg(feature = "weak-refs")]
-    pub weak_sets: Box<[bool]>,
+    pub weak_sets: MarkBits,
 }
 
 #[derive(Debug)]
@@ -137,6 +222,7 @@ pub(crate) struct WorkQueues {
     pub arrays: Vec<Array<'static>>,
     pub array_iterators: Vec<ArrayIterator<'static>>,
     pub async_generators: Vec<AsyncGenerator<'static>>,
+    pub array_from_async_reactions: Vec<ArrayFromAsyncReactionIdentifier>,
     pub await_reactions: Vec<AwaitReactionIdentifier>,
     pub bigints: Vec<HeapBigInt<'static>>,
     pub bound_functions: Vec<BoundFunction<'static>>,
@@ -146,6 +232,8 @@ pub(crate) struct WorkQueues {
     pub data_views: Vec<DataView<'static>>,
     #[cfg(feature = "date")]
     pub dates: Vec<Date<'static>>,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: Vec<DisposableStack<'static>>,
     pub declarative_environments: Vec<DeclarativeEnvironmentIndex>,
     pub e_2_10: Vec<(ElementIndex, u32)>,
     pub e_2_12: Vec<(ElementIndex, u32)>,
@@ -166,10 +254,12 @@ pub(crate) struct WorkQueues {
     pub global_environments: Vec<GlobalEnvironmentIndex>,
     pub maps: Vec<Map<'static>>,
     pub map_iterators: Vec<MapIterator<'static>>,
+    pub mapped_arguments: Vec<MappedArgumentsObject<'static>>,
     pub modules: Vec<Module<'static>>,
     pub numbers: Vec<HeapNumber<'static>>,
     pub object_environments: Vec<ObjectEnvironmentIndex>,
     pub objects: Vec<OrdinaryObject<'static>>,
+    pub object_shapes: Vec<ObjectShape>,
     pub primitive_objects: Vec<PrimitiveObject<'static>>,
     pub promises: Vec<Promise<'static>>,
     pub promise_reaction_records: Vec<PromiseReaction>,
@@ -198,136 +288,426 @@ pub(crate) struct WorkQueues {
 }
 
 impl HeapBits {
+    /// Creates unmarked bits for the entire heap.
     pub fn new(heap: &Heap) -> Self {
+        Self::with_old_generation(heap, false)
+    }
+
+    /// Creates unmarked bits for the young generation of the heap. The old
+    /// generation is considered marked.
+    pub(crate) fn new_young(heap: &Heap) -> Self {
+        Self::with_old_generation(heap, true)
+    }
+
+    fn with_old_generation(heap: &Heap, old_is_marked: bool) -> Self {
+        let remembered = &heap.remembered_sets;
+        let old = |old_len: usize| if old_is_marked { old_len } else { 0 };
         #[cfg(feature = "array-buffer")]
-        let array_buffers = vec![false; heap.array_buffers.len()];
-        let arrays = vec![false; heap.arrays.len()];
-        let array_iterators = vec![false; heap.array_iterators.len()];
-        let async_generators = vec![false; heap.async_generators.len()];
-        let await_reactions = vec![false; heap.await_reactions.len()];
-        let bigints = vec![false; heap.bigints.len()];
-        let bound_functions = vec![false; heap.bound_functions.len()];
-        let builtin_constructors = vec![false; heap.builtin_constructors.len()];
-        let builtin_functions = vec![false; heap.builtin_functions.len()];
+        let array_buffers = MarkBits::new(
+            old(remembered.array_buffers.old_len()),
+            heap.array_buffers.len(),
+            false,
+        );
+        let arrays = MarkBits::new(old(remembered.arrays.old_len()), heap.arrays.len(), false);
+        let array_iterators = MarkBits::new(
+            old(remembered.array_iterators.old_len()),
+            heap.array_iterators.len(),
+            false,
+        );
+        let async_generators = MarkBits::new(
+            old(remembered.async_generators.old_len()),
+            heap.async_generators.len(),
+            false,
+        );
+        let array_from_async_reactions = MarkBits::new(
+            old(remembered.array_from_async_reactions.old_len()),
+            heap.array_from_async_reactions.len(),
+            false,
+        );
+        let await_reactions = MarkBits::new(
+            old(remembered.await_reactions.old_len()),
+            heap.await_reactions.len(),
+            false,
+        );
+        let bigints = MarkBits::new(old(remembered.bigints.old_len()), heap.bigints.len(), false);
+        let bound_functions = MarkBits::new(
+            old(remembered.bound_functions.old_len()),
+            heap.bound_functions.len(),
+            false,
+        );
+        let builtin_constructors = MarkBits::new(
+            old(remembered.builtin_constructors.old_len()),
+            heap.builtin_constructors.len(),
+            false,
+        );
+        let builtin_functions = MarkBits::new(
+            old(remembered.builtin_functions.old_len()),
+            heap.builtin_functions.len(),
+            false,
+        );
         #[cfg(feature = "array-buffer")]
-        let data_views = vec![false; heap.data_views.len()];
+        let data_views = MarkBits::new(
+            old(remembered.data_views.old_len()),
+            heap.data_views.len(),
+            false,
+        );
         #[cfg(feature = "date")]
-        let dates = vec![false; heap.dates.len()];
-        let declarative_environments = vec![false; heap.environments.declarative.len()];
-        let e_2_10 = vec![(false, 0u16); heap.elements.e2pow10.values.len()];
-        let e_2_12 = vec![(false, 0u16); heap.elements.e2pow12.values.len()];
-        let e_2_16 = vec![(false, 0u16); heap.elements.e2pow16.values.len()];
-        let e_2_24 = vec![(false, 0u32); heap.elements.e2pow24.values.len()];
-        let e_2_32 = vec![(false, 0u32); heap.elements.e2pow32.values.len()];
-        let e_2_4 = vec![(false, 0u8); heap.elements.e2pow4.values.len()];
-        let e_2_6 = vec![(false, 0u8); heap.elements.e2pow6.values.len()];
-        let e_2_8 = vec![(false, 0u8); heap.elements.e2pow8.values.len()];
-        let ecmascript_functions = vec![false; heap.ecmascript_functions.len()];
-        let embedder_objects = vec![false; heap.embedder_objects.len()];
-        let errors = vec![false; heap.errors.len()];
-        let executables = vec![false; heap.executables.len()];
-        let source_codes = vec![false; heap.source_codes.len()];
-        let finalization_registrys = vec![false; heap.finalization_registrys.len()];
-        let function_environments = vec![false; heap.environments.function.len()];
-        let generators = vec![false; heap.generators.len()];
-        let global_environments = vec![false; heap.environments.global.len()];
-        let maps = vec![false; heap.maps.len()];
-        let map_iterators = vec![false; heap.map_iterators.len()];
-        let modules = vec![false; heap.modules.len()];
-        let numbers = vec![false; heap.numbers.len()];
-        let object_environments = vec![false; heap.environments.object.len()];
-        let objects = vec![false; heap.objects.len()];
-        let primitive_objects = vec![false; heap.primitive_objects.len()];
-        let promise_reaction_records = vec![false; heap.promise_reaction_records.len()];
-        let promise_resolving_functions = vec![false; heap.promise_resolving_functions.len()];
-        let promises = vec![false; heap.promises.len()];
-        let proxys = vec![false; heap.proxys.len()];
-        let realms = vec![false; heap.realms.len()];
+        let dates = MarkBits::new(old(remembered.dates.old_len()), heap.dates.len(), false);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        let disposable_stacks = MarkBits::new(
+            old(remembered.disposable_stacks.old_len()),
+            heap.disposable_stacks.len(),
+            false,
+        );
+        let declarative_environments = MarkBits::new(
+            old(remembered.declarative_environments.old_len()),
+            heap.environments.declarative.len(),
+            false,
+        );
+        let e_2_10 = MarkBits::new(
+            old(heap.elements.e2pow10.remembered.old_len()),
+            heap.elements.e2pow10.values.len(),
+            (false, 0u16),
+        );
+        let e_2_12 = MarkBits::new(
+            old(heap.elements.e2pow12.remembered.old_len()),
+            heap.elements.e2pow12.values.len(),
+            (false, 0u16),
+        );
+        let e_2_16 = MarkBits::new(
+            old(heap.elements.e2pow16.remembered.old_len()),
+            heap.elements.e2pow16.values.len(),
+            (false, 0u16),
+        );
+        let e_2_24 = MarkBits::new(
+            old(heap.elements.e2pow24.remembered.old_len()),
+            heap.elements.e2pow24.values.len(),
+            (false, 0u32),
+        );
+        let e_2_32 = MarkBits::new(
+            old(heap.elements.e2pow32.remembered.old_len()),
+            heap.elements.e2pow32.values.len(),
+            (false, 0u32),
+        );
+        let e_2_4 = MarkBits::new(
+            old(heap.elements.e2pow4.remembered.old_len()),
+            heap.elements.e2pow4.values.len(),
+            (false, 0u8),
+        );
+        let e_2_6 = MarkBits::new(
+            old(heap.elements.e2pow6.remembered.old_len()),
+            heap.elements.e2pow6.values.len(),
+            (false, 0u8),
+        );
+        let e_2_8 = MarkBits::new(
+            old(heap.elements.e2pow8.remembered.old_len()),
+            heap.elements.e2pow8.values.len(),
+            (false, 0u16),
+        );
+        let ecmascript_functions = MarkBits::new(
+            old(remembered.ecmascript_functions.old_len()),
+            heap.ecmascript_functions.len(),
+            false,
+        );
+        let embedder_objects = MarkBits::new(
+            old(remembered.embedder_objects.old_len()),
+            heap.embedder_objects.len(),
+            false,
+        );
+        let errors = MarkBits::new(old(remembered.errors.old_len()), heap.errors.len(), false);
+        let executables = MarkBits::new(
+            old(remembered.executables.old_len()),
+            heap.executables.len(),
+            false,
+        );
+        let source_codes = MarkBits::new(
+            old(remembered.source_codes.old_len()),
+            heap.source_codes.len(),
+            false,
+        );
+        let finalization_registrys = MarkBits::new(
+            old(remembered.finalization_registrys.old_len()),
+            heap.finalization_registrys.len(),
+            false,
+        );
+        let function_environments = MarkBits::new(
+            old(remembered.function_environments.old_len()),
+            heap.environments.function.len(),
+            false,
+        );
+        let generators = MarkBits::new(
+            old(remembered.generators.old_len()),
+            heap.generators.len(),
+            false,
+        );
+        let global_environments = MarkBits::new(
+            old(remembered.global_environments.old_len()),
+            heap.environments.global.len(),
+            false,
+        );
+        let maps = MarkBits::new(old(remembered.maps.old_len()), heap.maps.len(), false);
+        let map_iterators = MarkBits::new(
+            old(remembered.map_iterators.old_len()),
+            heap.map_iterators.len(),
+            false,
+        );
+        let mapped_arguments = MarkBits::new(
+            old(remembered.mapped_arguments.old_len()),
+            heap.mapped_arguments.len(),
+            false,
+        );
+        let modules = MarkBits::new(old(remembered.modules.old_len()), heap.modules.len(), false);
+        let numbers = MarkBits::new(old(remembered.numbers.old_len()), heap.numbers.len(), false);
+        let object_environments = MarkBits::new(
+            old(remembered.object_environments.old_len()),
+            heap.environments.object.len(),
+            false,
+        );
+        let objects = MarkBits::new(old(remembered.objects.old_len()), heap.objects.len(), false);
+        let object_shapes = MarkBits::new(
+            old(remembered.object_shapes.old_len()),
+            heap.object_shapes.len(),
+            false,
+        );
+        let primitive_objects = MarkBits::new(
+            old(remembered.primitive_objects.old_len()),
+            heap.primitive_objects.len(),
+            false,
+        );
+        let promise_reaction_records = MarkBits::new(
+            old(remembered.promise_reaction_records.old_len()),
+            heap.promise_reaction_records.len(),
+            false,
+        );
+        let promise_resolving_functions = MarkBits::new(
+            old(remembered.promise_resolving_functions.old_len()),
+            heap.promise_resolving_functions.len(),
+            false,
+        );
+        let promises = MarkBits::new(
+            old(remembered.promises.old_len()),
+            heap.promises.len(),
+            false,
+        );
+        let proxys = MarkBits::new(old(remembered.proxys.old_len()), heap.proxys.len(), false);
+        let realms = MarkBits::new(old(remembered.realms.old_len()), heap.realms.len(), false);
         #[cfg(feature = "regexp")]
-        let regexps = vec![false; heap.regexps.len()];
-        let scripts = vec![false; heap.scripts.len()];
+        let regexps = MarkBits::new(old(remembered.regexps.old_len()), heap.regexps.len(), false);
+        let scripts = MarkBits::new(old(remembered.scripts.old_len()), heap.scripts.len(), false);
         #[cfg(feature = "set")]
-        let sets = vec![false; heap.sets.len()];
+        let sets = MarkBits::new(old(remembered.sets.old_len()), heap.sets.len(), false);
         #[cfg(feature = "set")]
-        let set_iterators = vec![false; heap.set_iterators.len()];
+        let set_iterators = MarkBits::new(
+            old(remembered.set_iterators.old_len()),
+            heap.set_iterators.len(),
+            false,
+        );
         #[cfg(feature = "shared-array-buffer")]
-        let shared_array_buffers = vec![false; heap.shared_array_buffers.len()];
-        let strings = vec![false; heap.strings.len()];
-        let symbols = vec![false; heap.symbols.len()];
+        let shared_array_buffers = MarkBits::new(
+            old(remembered.shared_array_buffers.old_len()),
+            heap.shared_array_buffers.len(),
+            false,
+        );
+        let strings = MarkBits::new(old(remembered.strings.old_len()), heap.strings.len(), false);
+        let symbols = MarkBits::new(old(remembered.symbols.old_len()), heap.symbols.len(), false);
         #[cfg(feature = "array-buffer")]
-        let typed_arrays = vec![false; heap.typed_arrays.len()];
+        let typed_arrays = MarkBits::new(
+            old(remembered.typed_arrays.old_len()),
+            heap.typed_arrays.len(),
+            false,
+        );
         #[cfg(feature = "weak-refs")]
-        let weak_maps = vec![false; heap.weak_maps.len()];
+        let weak_maps = MarkBits::new(
+            old(remembered.weak_maps.old_len()),
+            heap.weak_maps.len(),
+            false,
+        );
         #[cfg(feature = "weak-refs")]
-        let weak_refs = vec![false; heap.weak_refs.len()];
+        let weak_refs = MarkBits::new(
+            old(remembered.weak_refs.old_len()),
+            heap.weak_refs.len(),
+            false,
+        );
         #[cfg(feature = "weak-refs")]
-        let weak_sets = vec![false; heap.weak_sets.len()];
+        let weak_sets = MarkBits::new(
+            old(remembered.weak_sets.old_len()),
+            heap.weak_sets.len(),
+            false,
+        );
         Self {
             #[cfg(feature = "array-buffer")]
-            array_buffers: array_buffers.into_boxed_slice(),
-            arrays: arrays.into_boxed_slice(),
-            array_iterators: array_iterators.into_boxed_slice(),
-            async_generators: async_generators.into_boxed_slice(),
-            await_reactions: await_reactions.into_boxed_slice(),
-            bigints: bigints.into_boxed_slice(),
-            bound_functions: bound_functions.into_boxed_slice(),
-            builtin_constructors: builtin_constructors.into_boxed_slice(),
-            builtin_functions: builtin_functions.into_boxed_slice(),
+            array_buffers,
+            arrays,
+            array_iterators,
+            async_generators,
+            array_from_async_reactions,
+            await_reactions,
+            bigints,
+            bound_functions,
+            builtin_constructors,
+            builtin_functions,
             #[cfg(feature = "array-buffer")]
-            data_views: data_views.into_boxed_slice(),
+            data_views,
             #[cfg(feature = "date")]
-            dates: dates.into_boxed_slice(),
-            declarative_environments: declarative_environments.into_boxed_slice(),
-            e_2_10: e_2_10.into_boxed_slice(),
-            e_2_12: e_2_12.into_boxed_slice(),
-            e_2_16: e_2_16.into_boxed_slice(),
-            e_2_24: e_2_24.into_boxed_slice(),
-            e_2_32: e_2_32.into_boxed_slice(),
-            e_2_4: e_2_4.into_boxed_slice(),
-            e_2_6: e_2_6.into_boxed_slice(),
-            e_2_8: e_2_8.into_boxed_slice(),
-            ecmascript_functions: ecmascript_functions.into_boxed_slice(),
-            embedder_objects: embedder_objects.into_boxed_slice(),
-            errors: errors.into_boxed_slice(),
-            executables: executables.into_boxed_slice(),
-            source_codes: source_codes.into_boxed_slice(),
-            finalization_registrys: finalization_registrys.into_boxed_slice(),
-            function_environments: function_environments.into_boxed_slice(),
-            generators: generators.into_boxed_slice(),
-            global_environments: global_environments.into_boxed_slice(),
-            maps: maps.into_boxed_slice(),
-            map_iterators: map_iterators.into_boxed_slice(),
-            modules: modules.into_boxed_slice(),
-            numbers: numbers.into_boxed_slice(),
-            object_environments: object_environments.into_boxed_slice(),
-            objects: objects.into_boxed_slice(),
-            primitive_objects: primitive_objects.into_boxed_slice(),
-            promise_reaction_records: promise_reaction_records.into_boxed_slice(),
-            promise_resolving_functions: promise_resolving_functions.into_boxed_slice(),
-            promises: promises.into_boxed_slice(),
-            proxys: proxys.into_boxed_slice(),
-            realms: realms.into_boxed_slice(),
+            dates,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks,
+            declarative_environments,
+            e_2_10,
+            e_2_12,
+            e_2_16,
+            e_2_24,
+            e_2_32,
+            e_2_4,
+            e_2_6,
+            e_2_8,
+            ecmascript_functions,
+            embedder_objects,
+            errors,
+            executables,
+            source_codes,
+            finalization_registrys,
+            function_environments,
+            generators,
+            global_environments,
+            maps,
+            map_iterators,
+            mapped_arguments,
+            modules,
+            numbers,
+            object_environments,
+            objects,
+            object_shapes,
+            primitive_objects,
+            promise_reaction_records,
+            promise_resolving_functions,
+            promises,
+            proxys,
+            realms,
             #[cfg(feature = "regexp")]
-            regexps: regexps.into_boxed_slice(),
-            scripts: scripts.into_boxed_slice(),
+            regexps,
+            scripts,
             #[cfg(feature = "set")]
-            sets: sets.into_boxed_slice(),
+            sets,
             #[cfg(feature = "set")]
-            set_iterators: set_iterators.into_boxed_slice(),
+            set_iterators,
             #[cfg(feature = "shared-array-buffer")]
-            shared_array_buffers: shared_array_buffers.into_boxed_slice(),
-            strings: strings.into_boxed_slice(),
-            symbols: symbols.into_boxed_slice(),
+            shared_array_buffers,
+            strings,
+            symbols,
             #[cfg(feature = "array-buffer")]
-            typed_arrays: typed_arrays.into_boxed_slice(),
+            typed_arrays,
             #[cfg(feature = "weak-refs")]
-            weak_maps: weak_maps.into_boxed_slice(),
+            weak_maps,
             #[cfg(feature = "weak-refs")]
-            weak_refs: weak_refs.into_boxed_slice(),
+            weak_refs,
             #[cfg(feature = "weak-refs")]
-            weak_sets: weak_sets.into_boxed_slice(),
+            weak_sets,
         }
     }
+
+    /// Returns a copy of the bits sized for the current lengths of the heap
+    /// vectors. Items allocated after these bits were created are unmarked.
+    pub(crate) fn resize(&self, heap: &Heap) -> Self {
+        let mut bits = Self::new(heap);
+        #[cfg(feature = "array-buffer")]
+        copy_marks(&mut bits.array_buffers, &self.array_buffers);
+        copy_marks(&mut bits.arrays, &self.arrays);
+        copy_marks(&mut bits.array_iterators, &self.array_iterators);
+        copy_marks(&mut bits.async_generators, &self.async_generators);
+        copy_marks(
+            &mut bits.array_from_async_reactions,
+            &self.array_from_async_reactions,
+        );
+        copy_marks(&mut bits.await_reactions, &self.await_reactions);
+        copy_marks(&mut bits.bigints, &self.bigints);
+        copy_marks(&mut bits.bound_functions, &self.bound_functions);
+        copy_marks(&mut bits.builtin_constructors, &self.builtin_constructors);
+        copy_marks(&mut bits.builtin_functions, &self.builtin_functions);
+        #[cfg(feature = "array-buffer")]
+        copy_marks(&mut bits.data_views, &self.data_views);
+        #[cfg(feature = "date")]
+        copy_marks(&mut bits.dates, &self.dates);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        copy_marks(&mut bits.disposable_stacks, &self.disposable_stacks);
+        copy_marks(
+            &mut bits.declarative_environments,
+            &self.declarative_environments,
+        );
+        copy_marks(&mut bits.e_2_10, &self.e_2_10);
+        copy_marks(&mut bits.e_2_12, &self.e_2_12);
+        copy_marks(&mut bits.e_2_16, &self.e_2_16);
+        copy_marks(&mut bits.e_2_24, &self.e_2_24);
+        copy_marks(&mut bits.e_2_32, &self.e_2_32);
+        copy_marks(&mut bits.e_2_4, &self.e_2_4);
+        copy_marks(&mut bits.e_2_6, &self.e_2_6);
+        copy_marks(&mut bits.e_2_8, &self.e_2_8);
+        copy_marks(&mut bits.ecmascript_functions, &self.ecmascript_functions);
+        copy_marks(&mut bits.embedder_objects, &self.embedder_objects);
+        copy_marks(&mut bits.errors, &self.errors);
+        copy_marks(&mut bits.executables, &self.executables);
+        copy_marks(&mut bits.source_codes, &self.source_codes);
+        copy_marks(
+            &mut bits.finalization_registrys,
+            &self.finalization_registrys,
+        );
+        copy_marks(&mut bits.function_environments, &self.function_environments);
+        copy_marks(&mut bits.generators, &self.generators);
+        copy_marks(&mut bits.global_environments, &self.global_environments);
+        copy_marks(&mut bits.maps, &self.maps);
+        copy_marks(&mut bits.map_iterators, &self.map_iterators);
+        copy_marks(&mut bits.mapped_arguments, &self.mapped_arguments);
+        copy_marks(&mut bits.modules, &self.modules);
+        copy_marks(&mut bits.numbers, &self.numbers);
+        copy_marks(&mut bits.object_environments, &self.object_environments);
+        copy_marks(&mut bits.objects, &self.objects);
+        copy_marks(&mut bits.object_shapes, &self.object_shapes);
+        copy_marks(&mut bits.primitive_objects, &self.primitive_objects);
+        copy_marks(
+            &mut bits.promise_reaction_records,
+            &self.promise_reaction_records,
+        );
+        copy_marks(
+            &mut bits.promise_resolving_functions,
+            &self.promise_resolving_functions,
+        );
+        copy_marks(&mut bits.promises, &self.promises);
+        copy_marks(&mut bits.proxys, &self.proxys);
+        copy_marks(&mut bits.realms, &self.realms);
+        #[cfg(feature = "regexp")]
+        copy_marks(&mut bits.regexps, &self.regexps);
+        copy_marks(&mut bits.scripts, &self.scripts);
+        #[cfg(feature = "set")]
+        copy_marks(&mut bits.sets, &self.sets);
+        #[cfg(feature = "set")]
+        copy_marks(&mut bits.set_iterators, &self.set_iterators);
+        #[cfg(feature = "shared-array-buffer")]
+        copy_marks(&mut bits.shared_array_buffers, &self.shared_array_buffers);
+        copy_marks(&mut bits.strings, &self.strings);
+        copy_marks(&mut bits.symbols, &self.symbols);
+        #[cfg(feature = "array-buffer")]
+        copy_marks(&mut bits.typed_arrays, &self.typed_arrays);
+        #[cfg(feature = "weak-refs")]
+        copy_marks(&mut bits.weak_maps, &self.weak_maps);
+        #[cfg(feature = "weak-refs")]
+        copy_marks(&mut bits.weak_refs, &self.weak_refs);
+        #[cfg(feature = "weak-refs")]
+        copy_marks(&mut bits.weak_sets, &self.weak_sets);
+        bits
+    }
+}
+
+fn copy_marks<T: Mark>(to: &mut MarkBits<T>, from: &MarkBits<T>) {
+    assert_eq!(to.old_len, from.old_len);
+    to.bits[..from.bits.len()].copy_from_slice(&from.bits);
+}
+
+/// Takes at most `budget` items from the end of the work queue, and reduces
+/// the budget by the number of items taken.
+pub(crate) fn take_marks<T>(queue: &mut Vec<T>, budget: &mut usize) -> Box<[T]> {
+    let count = queue.len().min(*budget);
+    *budget -= count;
+    queue.drain(queue.len() - count..).collect()
 }
 
 impl WorkQueues {
@@ -338,6 +718,9 @@ impl WorkQueues {
             arrays: Vec::with_capacity(heap.arrays.len() / 4),
             array_iterators: Vec::with_capacity(heap.array_iterators.len() / 4),
             async_generators: Vec::with_capacity(heap.async_generators.len() / 4),
+            array_from_async_reactions: Vec::with_capacity(
+                heap.array_from_async_reactions.len() / 4,
+            ),
             await_reactions: Vec::with_capacity(heap.await_reactions.len() / 4),
             bigints: Vec::with_capacity(heap.bigints.len() / 4),
             bound_functions: Vec::with_capacity(heap.bound_functions.len() / 4),
@@ -347,6 +730,8 @@ impl WorkQueues {
             data_views: Vec::with_capacity(heap.data_views.len() / 4),
             #[cfg(feature = "date")]
             dates: Vec::with_capacity(heap.dates.len() / 4),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks: Vec::with_capacity(heap.disposable_stacks.len() / 4),
             declarative_environments: Vec::with_capacity(heap.environments.declarative.len() / 4),
             e_2_10: Vec::with_capacity(heap.elements.e2pow10.values.len() / 4),
             e_2_12: Vec::with_capacity(heap.elements.e2pow12.values.len() / 4),
@@ -367,10 +752,12 @@ impl WorkQueues {
             global_environments: Vec::with_capacity(heap.environments.global.len() / 4),
             maps: Vec::with_capacity(heap.maps.len() / 4),
             map_iterators: Vec::with_capacity(heap.map_iterators.len() / 4),
+            mapped_arguments: Vec::with_capacity(heap.mapped_arguments.len() / 4),
             modules: Vec::with_capacity(heap.modules.len() / 4),
             numbers: Vec::with_capacity(heap.numbers.len() / 4),
             object_environments: Vec::with_capacity(heap.environments.object.len() / 4),
             objects: Vec::with_capacity(heap.objects.len() / 4),
+            object_shapes: Vec::with_capacity(heap.object_shapes.len() / 4),
             primitive_objects: Vec::with_capacity(heap.primitive_objects.len() / 4),
             promise_reaction_records: Vec::with_capacity(heap.promise_reaction_records.len() / 4),
             promise_resolving_functions: Vec::with_capacity(
@@ -431,6 +818,7 @@ impl WorkQueues {
             arrays,
             array_iterators,
             async_generators,
+            array_from_async_reactions,
             await_reactions,
             bigints,
             bound_functions,
@@ -440,6 +828,8 @@ impl WorkQueues {
             data_views,
             #[cfg(feature = "date")]
             dates,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks,
             declarative_environments,
             e_2_10,
             e_2_12,
@@ -460,10 +850,12 @@ impl WorkQueues {
             global_environments,
             maps,
             map_iterators,
+            mapped_arguments,
             modules,
             numbers,
             object_environments,
             objects,
+            object_shapes,
             primitive_objects,
             promises,
             promise_reaction_records,
@@ -493,6 +885,8 @@ impl WorkQueues {
 
         #[cfg(not(feature = "date"))]
         let dates: &[bool; 0] = &[];
+        #[cfg(not(feature = "proposal-explicit-resource-management"))]
+        let disposable_stacks: &[bool; 0] = &[];
         #[cfg(not(feature = "array-buffer"))]
         let data_views: &[bool; 0] = &[];
         #[cfg(not(feature = "array-buffer"))]
@@ -517,6 +911,7 @@ impl WorkQueues {
             && arrays.is_empty()
             && array_iterators.is_empty()
             && async_generators.is_empty()
+            && array_from_async_reactions.is_empty()
             && await_reactions.is_empty()
             && bigints.is_empty()
             && bound_functions.is_empty()
@@ -524,6 +919,7 @@ impl WorkQueues {
             && builtin_functions.is_empty()
             && data_views.is_empty()
             && dates.is_empty()
+            && disposable_stacks.is_empty()
             && declarative_environments.is_empty()
             && e_2_10.is_empty()
             && e_2_12.is_empty()
@@ -544,10 +940,12 @@ impl WorkQueues {
             && global_environments.is_empty()
             && maps.is_empty()
             && map_iterators.is_empty()
+            && mapped_arguments.is_empty()
             && modules.is_empty()
             && numbers.is_empty()
             && object_environments.is_empty()
             && objects.is_empty()
+            && object_shapes.is_empty()
             && primitive_objects.is_empty()
             && promise_reaction_records.is_empty()
             && promise_resolving_functions.is_empty()
@@ -576,13 +974,15 @@ pub(crate) struct CompactionList {
 
 impl CompactionList {
     pub fn get_shift_for_index(&self, index: u32) -> u32 {
-        self.indexes
-            .iter()
-            .enumerate()
-            .rev()
-            .find(|(_, candidate)| **candidate <= index)
-            .map(|(index, _)| *self.shifts.get(index).unwrap())
-            .unwrap_or(0)
+        // Note: Indexes are in ascending order, so the shift is that of the
+        // last index not greater than the given one.
+        match self
+            .indexes
+            .partition_point(|candidate| *candidate <= index)
+        {
+            0 => 0,
+            position => self.shifts[position - 1],
+        }
     }
 
     pub(crate) fn shift_index<T: ?Sized>(&self, index: &mut BaseIndex<T>) {
@@ -612,46 +1012,15 @@ impl CompactionList {
         }
     }
 
-    pub(crate) fn from_mark_bits(marks: &[bool]) -> Self {
-        let mut builder = CompactionListBuilder::default();
-        marks.iter().for_each(|bit| {
-            if *bit {
-                builder.mark_used();
-            } else {
-                builder.mark_unused();
-            }
-        });
-        builder.done()
-    }
-
-    pub(crate) fn from_mark_u8s(marks: &[(bool, u8)]) -> Self {
-        let mut builder = CompactionListBuilder::default();
-        marks.iter().for_each(|mark| {
-            if mark.0 {
-                builder.mark_used();
-            } else {
-                builder.mark_unused();
-            }
-        });
-        builder.done()
-    }
-
-    pub(crate) fn from_mark_u16s(marks: &[(bool, u16)]) -> Self {
-        let mut builder = CompactionListBuilder::default();
-        marks.iter().for_each(|mark| {
-            if mark.0 {
-                builder.mark_used();
-            } else {
-                builder.mark_unused();
-            }
-        });
-        builder.done()
-    }
-
-    pub(crate) fn from_mark_u32s(marks: &[(bool, u32)]) -> Self {
-        let mut builder = CompactionListBuilder::default();
-        marks.iter().for_each(|mark| {
-            if mark.0 {
+    /// Creates the compaction list of a heap vector from its marks. Only the
+    /// young generation is compacted.
+    pub(crate) fn from_marks<T: Mark>(marks: &MarkBits<T>) -> Self {
+        let mut builder = CompactionListBuilder {
+            current_index: marks.old_len() as u32,
+            ..Default::default()
+        };
+        marks.bits.iter().for_each(|mark| {
+            if mark.is_marked() {
                 builder.mark_used();
             } else {
                 builder.mark_unused();
@@ -726,6 +1095,7 @@ pub(crate) struct CompactionLists {
     pub arrays: CompactionList,
     pub array_iterators: CompactionList,
     pub async_generators: CompactionList,
+    pub array_from_async_reactions: CompactionList,
     pub await_reactions: CompactionList,
     pub bigints: CompactionList,
     pub bound_functions: CompactionList,
@@ -735,6 +1105,8 @@ pub(crate) struct CompactionLists {
     pub data_views: CompactionList,
     #[cfg(feature = "date")]
     pub dates: CompactionList,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub disposable_stacks: CompactionList,
     pub declarative_environments: CompactionList,
     pub e_2_10: CompactionList,
     pub e_2_12: CompactionList,
@@ -755,10 +1127,12 @@ pub(crate) struct CompactionLists {
     pub global_environments: CompactionList,
     pub maps: CompactionList,
     pub map_iterators: CompactionList,
+    pub mapped_arguments: CompactionList,
     pub modules: CompactionList,
     pub numbers: CompactionList,
     pub object_environments: CompactionList,
     pub objects: CompactionList,
+    pub object_shapes: CompactionList,
     pub primitive_objects: CompactionList,
     pub promise_reaction_records: CompactionList,
     pub promise_resolving_functions: CompactionList,
@@ -798,75 +1172,78 @@ impl CompactionLists {
         // areas can exist. We can use this mathematical bound to estimate a good
         // vector allocation.
         Self {
-            modules: CompactionList::from_mark_bits(&bits.modules),
-            scripts: CompactionList::from_mark_bits(&bits.scripts),
-            realms: CompactionList::from_mark_bits(&bits.realms),
-            declarative_environments: CompactionList::from_mark_bits(
-                &bits.declarative_environments,
-            ),
-            function_environments: CompactionList::from_mark_bits(&bits.function_environments),
-            global_environments: CompactionList::from_mark_bits(&bits.global_environments),
-            object_environments: CompactionList::from_mark_bits(&bits.object_environments),
-            e_2_4: CompactionList::from_mark_u8s(&bits.e_2_4),
-            e_2_6: CompactionList::from_mark_u8s(&bits.e_2_6),
-            e_2_8: CompactionList::from_mark_u8s(&bits.e_2_8),
-            e_2_10: CompactionList::from_mark_u16s(&bits.e_2_10),
-            e_2_12: CompactionList::from_mark_u16s(&bits.e_2_12),
-            e_2_16: CompactionList::from_mark_u16s(&bits.e_2_16),
-            e_2_24: CompactionList::from_mark_u32s(&bits.e_2_24),
-            e_2_32: CompactionList::from_mark_u32s(&bits.e_2_32),
-            arrays: CompactionList::from_mark_bits(&bits.arrays),
+            modules: CompactionList::from_marks(&bits.modules),
+            scripts: CompactionList::from_marks(&bits.scripts),
+            realms: CompactionList::from_marks(&bits.realms),
+            declarative_environments: CompactionList::from_marks(&bits.declarative_environments),
+            function_environments: CompactionList::from_marks(&bits.function_environments),
+            global_environments: CompactionList::from_marks(&bits.global_environments),
+            object_environments: CompactionList::from_marks(&bits.object_environments),
+            e_2_4: CompactionList::from_marks(&bits.e_2_4),
+            e_2_6: CompactionList::from_marks(&bits.e_2_6),
+            e_2_8: CompactionList::from_marks(&bits.e_2_8),
+            e_2_10: CompactionList::from_marks(&bits.e_2_10),
+            e_2_12: CompactionList::from_marks(&bits.e_2_12),
+            e_2_16: CompactionList::from_marks(&bits.e_2_16),
+            e_2_24: CompactionList::from_marks(&bits.e_2_24),
+            e_2_32: CompactionList::from_marks(&bits.e_2_32),
+            arrays: CompactionList::from_marks(&bits.arrays),
             #[cfg(feature = "array-buffer")]
-            array_buffers: CompactionList::from_mark_bits(&bits.array_buffers),
-            array_iterators: CompactionList::from_mark_bits(&bits.array_iterators),
-            async_generators: CompactionList::from_mark_bits(&bits.async_generators),
-            await_reactions: CompactionList::from_mark_bits(&bits.await_reactions),
-            bigints: CompactionList::from_mark_bits(&bits.bigints),
-            bound_functions: CompactionList::from_mark_bits(&bits.bound_functions),
-            builtin_constructors: CompactionList::from_mark_bits(&bits.builtin_constructors),
-            builtin_functions: CompactionList::from_mark_bits(&bits.builtin_functions),
-            ecmascript_functions: CompactionList::from_mark_bits(&bits.ecmascript_functions),
-            embedder_objects: CompactionList::from_mark_bits(&bits.embedder_objects),
-            generators: CompactionList::from_mark_bits(&bits.generators),
-            source_codes: CompactionList::from_mark_bits(&bits.source_codes),
-            #[cfg(feature = "date")]
-            dates: CompactionList::from_mark_bits(&bits.dates),
-            errors: CompactionList::from_mark_bits(&bits.errors),
-            executables: CompactionList::from_mark_bits(&bits.executables),
-            maps: CompactionList::from_mark_bits(&bits.maps),
-            map_iterators: CompactionList::from_mark_bits(&bits.map_iterators),
-            numbers: CompactionList::from_mark_bits(&bits.numbers),
-            objects: CompactionList::from_mark_bits(&bits.objects),
-            promise_reaction_records: CompactionList::from_mark_bits(
-                &bits.promise_reaction_records,
+            array_buffers: CompactionList::from_marks(&bits.array_buffers),
+            array_iterators: CompactionList::from_marks(&bits.array_iterators),
+            async_generators: CompactionList::from_marks(&bits.async_generators),
+            array_from_async_reactions: CompactionList::from_marks(
+                &bits.array_from_async_reactions,
             ),
-            promise_resolving_functions: CompactionList::from_mark_bits(
+            await_reactions: CompactionList::from_marks(&bits.await_reactions),
+            bigints: CompactionList::from_marks(&bits.bigints),
+            bound_functions: CompactionList::from_marks(&bits.bound_functions),
+            builtin_constructors: CompactionList::from_marks(&bits.builtin_constructors),
+            builtin_functions: CompactionList::from_marks(&bits.builtin_functions),
+            ecmascript_functions: CompactionList::from_marks(&bits.ecmascript_functions),
+            embedder_objects: CompactionList::from_marks(&bits.embedder_objects),
+            generators: CompactionList::from_marks(&bits.generators),
+            source_codes: CompactionList::from_marks(&bits.source_codes),
+            #[cfg(feature = "date")]
+            dates: CompactionList::from_marks(&bits.dates),
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks: CompactionList::from_marks(&bits.disposable_stacks),
+            errors: CompactionList::from_marks(&bits.errors),
+            executables: CompactionList::from_marks(&bits.executables),
+            maps: CompactionList::from_marks(&bits.maps),
+            map_iterators: CompactionList::from_marks(&bits.map_iterators),
+            mapped_arguments: CompactionList::from_marks(&bits.mapped_arguments),
+            numbers: CompactionList::from_marks(&bits.numbers),
+            objects: CompactionList::from_marks(&bits.objects),
+            object_shapes: CompactionList::from_marks(&bits.object_shapes),
+            promise_reaction_records: CompactionList::from_marks(&bits.promise_reaction_records),
+            promise_resolving_functions: CompactionList::from_marks(
                 &bits.promise_resolving_functions,
             ),
-            promises: CompactionList::from_mark_bits(&bits.promises),
-            primitive_objects: CompactionList::from_mark_bits(&bits.primitive_objects),
+            promises: CompactionList::from_marks(&bits.promises),
+            primitive_objects: CompactionList::from_marks(&bits.primitive_objects),
             #[cfg(feature = "regexp")]
-            regexps: CompactionList::from_mark_bits(&bits.regexps),
+            regexps: CompactionList::from_marks(&bits.regexps),
             #[cfg(feature = "set")]
-            sets: CompactionList::from_mark_bits(&bits.sets),
+            sets: CompactionList::from_marks(&bits.sets),
             #[cfg(feature = "set")]
-            set_iterators: CompactionList::from_mark_bits(&bits.set_iterators),
-            strings: CompactionList::from_mark_bits(&bits.strings),
+            set_iterators: CompactionList::from_marks(&bits.set_iterators),
+            strings: CompactionList::from_marks(&bits.strings),
             #[cfg(feature = "shared-array-buffer")]
-            shared_array_buffers: CompactionList::from_mark_bits(&bits.shared_array_buffers),
-            symbols: CompactionList::from_mark_bits(&bits.symbols),
+            shared_array_buffers: CompactionList::from_marks(&bits.shared_array_buffers),
+            symbols: CompactionList::from_marks(&bits.symbols),
             #[cfg(feature = "array-buffer")]
-            data_views: CompactionList::from_mark_bits(&bits.data_views),
-            finalization_registrys: CompactionList::from_mark_bits(&bits.finalization_registrys),
-            proxys: CompactionList::from_mark_bits(&bits.proxys),
+            data_views: CompactionList::from_marks(&bits.data_views),
+            finalization_registrys: CompactionList::from_marks(&bits.finalization_registrys),
+            proxys: CompactionList::from_marks(&bits.proxys),
             #[cfg(feature = "weak-refs")]
-            weak_maps: CompactionList::from_mark_bits(&bits.weak_maps),
+            weak_maps: CompactionList::from_marks(&bits.weak_maps),
             #[cfg(feature = "weak-refs")]
-            weak_refs: CompactionList::from_mark_bits(&bits.weak_refs),
+            weak_refs: CompactionList::from_marks(&bits.weak_refs),
             #[cfg(feature = "weak-refs")]
-            weak_sets: CompactionList::from_mark_bits(&bits.weak_sets),
+            weak_sets: CompactionList::from_marks(&bits.weak_sets),
             #[cfg(feature = "array-buffer")]
-            typed_arrays: CompactionList::from_mark_bits(&bits.typed_arrays),
+            typed_arrays: CompactionList::from_marks(&bits.typed_arrays),
         }
     }
 }
@@ -999,86 +1376,100 @@ fn sweep_array_with_u32_length<T: HeapMarkAndSweep, const N: usize>(
 pub(crate) fn sweep_heap_vector_values<T: HeapMarkAndSweep + core::fmt::Debug>(
     vec: &mut Vec<T>,
     compactions: &CompactionLists,
-    bits: &[bool],
+    bits: &MarkBits,
+    remembered: &RememberedSet,
 ) {
     assert_eq!(vec.len(), bits.len());
-    let mut iter = bits.iter();
-    vec.retain_mut(|item| {
-        let do_retain = iter.next().unwrap();
-        if *do_retain {
-            item.sweep_values(compactions);
-            true
-        } else {
-            false
-        }
-    });
-}
-
-pub(crate) fn sweep_heap_u8_elements_vector_values<const N: usize>(
-    vec: &mut Vec<Option<[Option<Value>; N]>>,
-    compactions: &CompactionLists,
-    u8s: &[(bool, u8)],
-) {
-    assert_eq!(vec.len(), u8s.len());
-    let mut iter = u8s.iter();
-    vec.retain_mut(|item| {
-        let (mark, length) = iter.next().unwrap();
-        if *mark {
-            sweep_array_with_u32_length(item, compactions, *length as u32);
-            true
-        } else {
-            false
-        }
-    });
-}
-
-pub(crate) fn sweep_heap_u16_elements_vector_values<const N: usize>(
-    vec: &mut Vec<Option<[Option<Value>; N]>>,
-    compactions: &CompactionLists,
-    u16s: &[(bool, u16)],
-) {
-    assert_eq!(vec.len(), u16s.len());
-    let mut iter = u16s.iter();
-    vec.retain_mut(|item| {
-        let (mark, length) = iter.next().unwrap();
-        if *mark {
-            sweep_array_with_u32_length(item, compactions, *length as u32);
-            true
-        } else {
-            false
+    debug_assert_eq!(bits.old_len(), remembered.old_len());
+    // Old items do not move, and only those that have been written to since
+    // the previous collection can refer to items that do.
+    for index in remembered.indexes() {
+        vec[index].sweep_values(compactions);
+    }
+    let mut retained = bits.old_len();
+    for (index, marked) in bits.iter_young() {
+        if marked {
+            vec.swap(retained, index);
+            vec[retained].sweep_values(compactions);
+            retained += 1;
         }
-    });
+    }
+    vec.truncate(retained);
 }
 
-pub(crate) fn sweep_heap_u32_elements_vector_values<const N: usize>(
+pub(crate) fn sweep_heap_elements_vector_values<const N: usize, L: Copy + Into<u32>>(
     vec: &mut Vec<Option<[Option<Value>; N]>>,
     compactions: &CompactionLists,
-    u32s: &[(bool, u32)],
+    marks: &MarkBits<(bool, L)>,
+    remembered: &RememberedElements,
 ) {
-    assert_eq!(vec.len(), u32s.len());
-    let mut iter = u32s.iter();
-    vec.retain_mut(|item| {
-        let (mark, length) = iter.next().unwrap();
-        if *mark {
-            sweep_array_with_u32_length(item, compactions, *length);
-            true
-        } else {
-            false
+    assert_eq!(vec.len(), marks.len());
+    debug_assert_eq!(marks.old_len(), remembered.old_len());
+    // Old element arrays do not move, and only those that have been written
+    // to since the previous collection can refer to items that do.
+    for (index, length) in remembered.iter() {
+        sweep_array_with_u32_length(&mut vec[index], compactions, length);
+    }
+    let mut retained = marks.old_len();
+    for (index, (mark, length)) in marks.iter_young() {
+        if mark {
+            if retained != index {
+                // Note: Element arrays can be too large to be moved through
+                // the stack, so they are copied in place.
+                vec.copy_within(index..index + 1, retained);
+            }
+            sweep_array_with_u32_length(&mut vec[retained], compactions, length.into());
+            retained += 1;
         }
-    });
+    }
+    vec.truncate(retained);
 }
 
-pub(crate) fn sweep_heap_elements_vector_descriptors<T>(
+pub(crate) fn sweep_heap_elements_vector_descriptors<T: Copy>(
     descriptors: &mut AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
     compactions: &CompactionLists,
     self_compactions: &CompactionList,
-    marks: &[(bool, T)],
+    marks: &MarkBits<(bool, T)>,
+    remembered: &RememberedElements,
 ) {
-    let mut keys_to_remove = Vec::with_capacity(marks.len() / 4);
-    let mut keys_to_reassign = Vec::with_capacity(marks.len() / 4);
+    if descriptors.is_empty() {
+        return;
+    }
+    let young_len = marks.len() - marks.old_len();
+    if marks.old_len() > 0 && young_len < descriptors.len() {
+        // Note: The descriptors of old element arrays do not move, and only
+        // those of the written ones can refer to items that do. The
+        // descriptors of the young element arrays are looked up one by one.
+        for (index, _) in remembered.iter() {
+            if let Some(descriptor) = descriptors.get_mut(&ElementIndex::from_index(index)) {
+                for descriptor in descriptor.values_mut() {
+                    descriptor.sweep_values(compactions);
+                }
+            }
+        }
+        let mut retained = Vec::new();
+        for (index, (mark, _)) in marks.iter_young() {
+            let key = ElementIndex::from_index(index);
+            let Some(mut descriptor) = descriptors.remove(&key) else {
+                continue;
+            };
+            if mark {
+                for descriptor in descriptor.values_mut() {
+                    descriptor.sweep_values(compactions);
+                }
+                let mut new_key = key;
+                self_compactions.shift_index(&mut new_key);
+                retained.push((new_key, descriptor));
+            }
+        }
+        descriptors.extend(retained);
+        return;
+    }
+    let mut keys_to_remove = Vec::new();
+    let mut keys_to_reassign = Vec::new();
     for (key, descriptor) in descriptors.iter_mut() {
         let old_key = *key;
-        if !marks.get(key.into_index()).unwrap().0 {
+        if !marks.is_marked(key.into_index()) {
             keys_to_remove.push(old_key);
         } else {
             for descriptor in descriptor.values_mut() {
@@ -1108,16 +1499,16 @@ pub(crate) fn sweep_heap_elements_vector_descriptors<T>(
 pub(crate) fn sweep_side_table_values<'a, T, K, V>(
     side_table: &mut AHashMap<K, V>,
     compactions: &CompactionList,
-    marks: &[bool],
+    marks: &MarkBits,
 ) where
     T: 'a + ?Sized,
     K: IntoBaseIndex<'a, T> + From<BaseIndex<'a, T>> + Copy + Ord + Hash,
 {
-    let mut keys_to_remove = Vec::with_capacity(marks.len() / 4);
-    let mut keys_to_reassign = Vec::with_capacity(marks.len() / 4);
+    let mut keys_to_remove = Vec::new();
+    let mut keys_to_reassign = Vec::new();
     for (key, _) in side_table.iter_mut() {
         let old_key = *key;
-        if !marks.get(key.into_base_index().into_index()).unwrap() {
+        if !marks.is_marked(key.into_base_index().into_index()) {
             keys_to_remove.push(old_key);
         } else {
             let mut new_key = old_key.into_base_index();
@@ -1142,22 +1533,41 @@ pub(crate) fn sweep_side_table_values<'a, T, K, V>(
     }
 }
 
-pub(crate) fn sweep_lookup_table<'a, T, U>(
-    lookup_table: &mut HashTable<T>,
+pub(crate) fn sweep_lookup_table(
+    lookup_table: &mut HashTable<HeapString<'static>>,
     compactions: &CompactionLists,
-    bits: &[bool],
-) where
-    T: GetBaseIndexMut<'a, U>,
-{
+    bits: &MarkBits,
+    hash: impl Fn(usize) -> u64,
+) {
     assert_eq!(lookup_table.len(), bits.len());
-    lookup_table.retain(|entry| {
-        let base_index = entry.get_base_index_mut();
-        let do_retain = bits[base_index.into_index()];
-        if do_retain {
-            compactions.strings.shift_index(base_index);
-            true
+    if bits.old_len() == 0 {
+        lookup_table.retain(|entry| {
+            let base_index = entry.get_base_index_mut();
+            let do_retain = bits.is_marked(base_index.into_index());
+            if do_retain {
+                compactions.strings.shift_index(base_index);
+                true
+            } else {
+                false
+            }
+        });
+        return;
+    }
+    // Note: Old entries do not move, so only the entries of young items are
+    // looked up. Shifted entries point below the young items still to be
+    // looked up, and cannot be mistaken for them.
+    for (index, marked) in bits.iter_young() {
+        let Ok(mut entry) =
+            lookup_table.find_entry(hash(index), |entry| entry.get_index() == index)
+        else {
+            unreachable!("String is missing from the lookup table");
+        };
+        if marked {
+            compactions
+                .strings
+                .shift_index(entry.get_mut().get_base_index_mut());
         } else {
-            false
+            entry.remove();
         }
-    });
+    }
 }
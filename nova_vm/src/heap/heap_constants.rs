@@ -105,6 +105,10 @@ pub(crate) enum IntrinsicObjectIndexes {
     GeneratorPrototype,
     AsyncGeneratorPrototype,
     AsyncFunctionPrototype,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStackPrototype,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStackPrototype,
 
     // Reflection
     ReflectObject,
@@ -114,6 +118,8 @@ pub(crate) enum IntrinsicObjectIndexes {
     EvalErrorPrototype,
     RangeErrorPrototype,
     ReferenceErrorPrototype,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedErrorPrototype,
     SyntaxErrorPrototype,
     TypeErrorPrototype,
 
@@ -213,6 +219,10 @@ pub(crate) enum IntrinsicConstructorIndexes {
     GeneratorFunction,
     AsyncGeneratorFunction,
     AsyncFunction,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStack,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStack,
 
     // Reflection
     Proxy,
@@ -222,6 +232,8 @@ pub(crate) enum IntrinsicConstructorIndexes {
     EvalError,
     RangeError,
     ReferenceError,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    SuppressedError,
     SyntaxError,
     TypeError,
 
@@ -240,12 +252,17 @@ pub(crate) enum IntrinsicFunctionIndexes {
     ArrayPrototypeSort,
     ArrayPrototypeToString,
     ArrayPrototypeValues,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    AsyncDisposableStackPrototypeDisposeAsync,
     #[cfg(feature = "date")]
     DatePrototypeToUTCString,
     DecodeURI,
     DecodeURIComponent,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStackPrototypeDispose,
     EncodeURI,
     EncodeURIComponent,
+    #[cfg(feature = "annex-b-global")]
     Escape,
     Eval,
     GeneratorFunctionPrototypePrototypeNext,
@@ -263,10 +280,18 @@ pub(crate) enum IntrinsicFunctionIndexes {
     ThrowTypeError,
     #[cfg(feature = "array-buffer")]
     TypedArrayPrototypeValues,
+    #[cfg(feature = "annex-b-global")]
     Unescape,
 }
+#[cfg(feature = "annex-b-global")]
 pub(crate) const LAST_INTRINSIC_FUNCTION_INDEX: IntrinsicFunctionIndexes =
     IntrinsicFunctionIndexes::Unescape;
+#[cfg(all(not(feature = "annex-b-global"), feature = "array-buffer"))]
+pub(crate) const LAST_INTRINSIC_FUNCTION_INDEX: IntrinsicFunctionIndexes =
+    IntrinsicFunctionIndexes::TypedArrayPrototypeValues;
+#[cfg(all(not(feature = "annex-b-global"), not(feature = "array-buffer")))]
+pub(crate) const LAST_INTRINSIC_FUNCTION_INDEX: IntrinsicFunctionIndexes =
+    IntrinsicFunctionIndexes::ThrowTypeError;
 
 impl IntrinsicObjectIndexes {
     const OBJECT_INDEX_OFFSET: u32 = 0;
@@ -351,7 +376,9 @@ pub(crate) const fn intrinsic_function_count() -> usize {
 #[derive(Debug, Clone, Copy)]
 #[repr(u32)]
 pub(crate) enum WellKnownSymbolIndexes {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
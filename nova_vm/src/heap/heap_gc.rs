@@ -8,17 +8,19 @@ use super::{
     element_array::ElementArrays,
     heap_bits::{
         mark_array_with_u32_length, mark_descriptors, sweep_heap_elements_vector_descriptors,
-        sweep_heap_u16_elements_vector_values, sweep_heap_u32_elements_vector_values,
-        sweep_heap_u8_elements_vector_values, sweep_heap_vector_values, sweep_lookup_table,
-        CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues,
+        sweep_heap_elements_vector_values, sweep_heap_vector_values, sweep_lookup_table,
+        take_marks, CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues,
     },
     indexes::{ElementIndex, StringIndex},
+    remembered_set::{mark_old_generation, retrace_remembered_sets, set_old_generation},
     Heap, WellKnownSymbolIndexes,
 };
 #[cfg(feature = "array-buffer")]
 use super::{heap_bits::sweep_side_table_values, indexes::TypedArrayIndex};
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::Date;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::DisposableStack;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExp;
 #[cfg(feature = "shared-array-buffer")]
@@ -34,6 +36,7 @@ use crate::ecmascript::builtins::{weak_map::WeakMap, weak_ref::WeakRef, weak_set
 use crate::{
     ecmascript::{
         builtins::{
+            arguments::MappedArgumentsObject,
             async_generator_objects::AsyncGenerator,
             bound_function::BoundFunction,
             control_abstraction_objects::{
@@ -47,7 +50,10 @@ use crate::{
             embedder_object::EmbedderObject,
             error::Error,
             finalization_registry::FinalizationRegistry,
-            indexed_collections::array_objects::array_iterator_objects::array_iterator::ArrayIterator,
+            indexed_collections::array_objects::{
+                array_from_async_reaction::ArrayFromAsyncReactionIdentifier,
+                array_iterator_objects::array_iterator::ArrayIterator,
+            },
             keyed_collections::map_objects::map_iterator_objects::map_iterator::MapIterator,
             map::Map,
             module::Module,
@@ -62,42 +68,174 @@ use crate::{
         },
         scripts_and_modules::{script::ScriptIdentifier, source_code::SourceCode},
         types::{
-            bigint::HeapBigInt, HeapNumber, HeapString, OrdinaryObject, Symbol,
-            BUILTIN_STRINGS_LIST,
+            bigint::HeapBigInt, sweep_shape_transitions, HeapNumber, HeapString, ObjectShape,
+            OrdinaryObject, Symbol, BUILTIN_STRINGS_LIST,
         },
     },
     engine::{context::GcScope, Executable},
 };
 
-pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], gc: GcScope) {
+/// State of the incremental marking of a full garbage collection.
+#[derive(Debug)]
+pub(crate) struct IncrementalMarking {
+    bits: HeapBits,
+    queues: WorkQueues,
+}
+
+/// Performs a full garbage collection of the entire heap.
+pub fn heap_gc(agent: &mut Agent, gc: GcScope) {
+    // Any incremental marking in progress is superseded.
+    agent.heap.incremental_marking = None;
+    set_old_generation(&mut agent.heap, false);
+    collect(agent, gc);
+    reset_allocation_budget(agent, true);
+}
+
+/// Performs a minor garbage collection: Only heap data allocated since the
+/// previous collection is traced and compacted, while older heap data is
+/// assumed to be live.
+pub(crate) fn minor_heap_gc(agent: &mut Agent, gc: GcScope) {
+    debug_assert!(agent.heap.incremental_marking.is_none());
+    collect(agent, gc);
+    reset_allocation_budget(agent, false);
+}
+
+/// Performs the garbage collection that is due: A full collection if the heap
+/// has grown past the threshold set by the previous full collection, and a
+/// minor collection otherwise. With incremental marking, a full collection is
+/// spread over many calls, each of which performs a step of the marking.
+///
+/// If the heap is larger than [`Options::max_heap_bytes`] after the
+/// collection, a full collection is performed immediately. After an out of
+/// memory error, all collections are full ones until the heap is within the
+/// limit again.
+///
+/// [`Options::max_heap_bytes`]: crate::ecmascript::execution::agent::Options::max_heap_bytes
+pub(crate) fn scheduled_heap_gc(agent: &mut Agent, mut gc: GcScope) {
+    if agent.heap.gc_heap_limit_grace > 0 {
+        heap_gc(agent, gc);
+        return;
+    }
+    if agent.heap.incremental_marking.is_some() {
+        incremental_marking_step(agent, gc.reborrow());
+    } else if agent.options.gc_nursery_size == 0
+        || agent.heap.used_bytes() >= agent.heap.gc_major_threshold
+    {
+        if agent.options.gc_incremental_step == 0 {
+            heap_gc(agent, gc);
+            return;
+        } else {
+            start_incremental_marking(agent);
+        }
+    } else {
+        minor_heap_gc(agent, gc.reborrow());
+    }
+    if !agent.can_allocate(0) {
+        heap_gc(agent, gc);
+    }
+}
+
+/// Starts the incremental marking of a full garbage collection by pushing the
+/// roots into the work queues. The heap is then traced a step at a time while
+/// the mutator keeps running.
+///
+/// Heap data that is written to after it has been traced may have come to
+/// refer to heap data that has not been traced. The write barrier records all
+/// writes to heap data that existed when marking started, and the written heap
+/// data is traced again when marking finishes. Heap data allocated during
+/// marking is not traced by the steps at all.
+fn start_incremental_marking(agent: &mut Agent) {
+    set_old_generation(&mut agent.heap, true);
+    let bits = HeapBits::new(&agent.heap);
+    let mut queues = WorkQueues::new(&agent.heap);
+    mark_roots(agent, &mut queues);
+    agent.heap.incremental_marking = Some(Box::new(IncrementalMarking { bits, queues }));
+    reset_allocation_budget(agent, false);
+}
+
+/// Performs a step of incremental marking, and finishes the full garbage
+/// collection once there is nothing left to trace.
+fn incremental_marking_step(agent: &mut Agent, gc: GcScope) {
+    let mut marking = agent.heap.incremental_marking.take().unwrap();
+    let IncrementalMarking { bits, queues } = &mut *marking;
+    if trace(&agent.heap, bits, queues, agent.options.gc_incremental_step) {
+        finish_incremental_marking(agent, *marking, gc);
+    } else {
+        agent.heap.incremental_marking = Some(marking);
+        reset_allocation_budget(agent, false);
+    }
+}
+
+/// Finishes incremental marking and sweeps the heap. This pauses the mutator
+/// for rescanning the roots, tracing the heap data written to or allocated
+/// during marking, and compacting the heap.
+fn finish_incremental_marking(agent: &mut Agent, marking: IncrementalMarking, gc: GcScope) {
+    let IncrementalMarking { bits, mut queues } = marking;
+    let mut bits = bits.resize(&agent.heap);
+    retrace_remembered_sets(&agent.heap, &mut bits, &mut queues);
+    mark_roots(agent, &mut queues);
+    trace(&agent.heap, &mut bits, &mut queues, usize::MAX);
+    // The whole heap is swept and compacted.
+    set_old_generation(&mut agent.heap, false);
+    sweep(agent, &bits, gc);
+    set_old_generation(&mut agent.heap, true);
+    reset_allocation_budget(agent, true);
+}
+
+fn collect(agent: &mut Agent, gc: GcScope) {
+    let mut bits = HeapBits::new_young(&agent.heap);
+    let mut queues = WorkQueues::new(&agent.heap);
+    mark_old_generation(&agent.heap, &mut bits, &mut queues);
+    mark_roots(agent, &mut queues);
+    trace(&agent.heap, &mut bits, &mut queues, usize::MAX);
+    sweep(agent, &bits, gc);
+    set_old_generation(&mut agent.heap, true);
+}
+
+/// Pushes the roots of the heap into the work queues.
+pub(crate) fn mark_roots(agent: &Agent, queues: &mut WorkQueues) {
     let Agent {
         heap,
         execution_context_stack,
         stack_refs,
         vm_stack,
+        realm_roots,
         options: _,
         symbol_id: _,
         global_symbol_registry: _,
         host_hooks: _,
+        interrupts: _,
+        terminating: _,
+        fuel: _,
+        debugger,
+        profiler,
+        coverage,
     } = agent;
-    let mut bits = HeapBits::new(heap);
-    let mut queues = WorkQueues::new(heap);
 
-    root_realms.iter().for_each(|realm| {
+    if let Some(debugger) = debugger {
+        debugger.mark_values(queues);
+    }
+    if let Some(profiler) = profiler {
+        profiler.mark_values(queues);
+    }
+    if let Some(coverage) = coverage {
+        coverage.mark_values(queues);
+    }
+    realm_roots.iter().for_each(|realm| {
         if let Some(realm) = realm {
             queues.realms.push(*realm);
         }
     });
 
     execution_context_stack.iter().for_each(|ctx| {
-        ctx.mark_values(&mut queues);
+        ctx.mark_values(queues);
     });
     stack_refs
         .borrow()
         .iter()
-        .for_each(|value| value.mark_values(&mut queues));
+        .for_each(|value| value.mark_values(queues));
     vm_stack.iter().for_each(|vm_ptr| {
-        unsafe { vm_ptr.as_ref() }.mark_values(&mut queues);
+        unsafe { vm_ptr.as_ref() }.mark_values(queues);
     });
     let mut last_filled_global_value = None;
     heap.globals
@@ -106,7 +244,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
         .enumerate()
         .for_each(|(i, &value)| {
             if let Some(value) = value {
-                value.mark_values(&mut queues);
+                value.mark_values(queues);
                 last_filled_global_value = Some(i);
             }
         });
@@ -117,11 +255,15 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
             .drain(last_filled_global_value + 1..);
     }
 
+    // The empty Shape is the root of the Shape transition tree.
+    queues.object_shapes.push(ObjectShape::EMPTY);
     queues.strings.extend(
         (0..BUILTIN_STRINGS_LIST.len()).map(|index| HeapString(StringIndex::from_index(index))),
     );
     queues.symbols.extend_from_slice(&[
+        WellKnownSymbolIndexes::AsyncDispose.into(),
         WellKnownSymbolIndexes::AsyncIterator.into(),
+        WellKnownSymbolIndexes::Dispose.into(),
         WellKnownSymbolIndexes::HasInstance.into(),
         WellKnownSymbolIndexes::IsConcatSpreadable.into(),
         WellKnownSymbolIndexes::Iterator.into(),
@@ -135,8 +277,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
         WellKnownSymbolIndexes::ToStringTag.into(),
         WellKnownSymbolIndexes::Unscopables.into(),
     ]);
+}
 
-    while !queues.is_empty() {
+/// Traces the heap from the work queues until they are empty, or until
+/// `budget` items have been taken from them. Returns true if the queues were
+/// emptied.
+fn trace(heap: &Heap, bits: &mut HeapBits, queues: &mut WorkQueues, mut budget: usize) -> bool {
+    while !queues.is_empty() && budget > 0 {
         let Heap {
             #[cfg(feature = "array-buffer")]
             array_buffers,
@@ -145,6 +292,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
             arrays,
             array_iterators,
             async_generators,
+            array_from_async_reactions,
             await_reactions,
             bigints,
             bound_functions,
@@ -158,6 +306,8 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 data_view_byte_offsets: _,
             #[cfg(feature = "date")]
             dates,
+            #[cfg(feature = "proposal-explicit-resource-management")]
+            disposable_stacks,
             ecmascript_functions,
             elements,
             embedder_objects,
@@ -170,9 +320,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
             globals: _,
             maps,
             map_iterators,
+            mapped_arguments,
             modules,
             numbers,
             objects,
+            object_shapes,
             primitive_objects,
             promise_reaction_records,
             promise_resolving_functions,
@@ -206,6 +358,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
             weak_refs,
             #[cfg(feature = "weak-refs")]
             weak_sets,
+            alloc_counter: _,
+            gc_allocation_budget: _,
+            gc_major_threshold: _,
+            gc_live_bytes: _,
+            gc_heap_limit_grace: _,
+            remembered_sets: _,
+            incremental_marking: _,
         } = heap;
         let Environments {
             declarative: declarative_environments,
@@ -213,6 +372,7 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
             global: global_environments,
             object: object_environments,
             private: _private_environments,
+            alloc_counter: _,
         } = environments;
         let ElementArrays {
             e2pow4,
@@ -223,8 +383,9 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
             e2pow16,
             e2pow24,
             e2pow32,
+            alloc_counter: _,
         } = elements;
-        let mut module_marks: Box<[Module]> = queues.modules.drain(..).collect();
+        let mut module_marks: Box<[Module]> = take_marks(&mut queues.modules, &mut budget);
         module_marks.sort();
         module_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -234,10 +395,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                modules.get(index).mark_values(&mut queues);
+                modules.get(index).mark_values(queues);
             }
         });
-        let mut script_marks: Box<[ScriptIdentifier]> = queues.scripts.drain(..).collect();
+        let mut script_marks: Box<[ScriptIdentifier]> =
+            take_marks(&mut queues.scripts, &mut budget);
         script_marks.sort();
         script_marks.iter().for_each(|&idx| {
             let index = idx.into_index();
@@ -247,10 +409,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                scripts.get(index).mark_values(&mut queues);
+                scripts.get(index).mark_values(queues);
             }
         });
-        let mut realm_marks: Box<[RealmIdentifier]> = queues.realms.drain(..).collect();
+        let mut realm_marks: Box<[RealmIdentifier]> = take_marks(&mut queues.realms, &mut budget);
         realm_marks.sort();
         realm_marks.iter().for_each(|&idx| {
             let index = idx.into_index();
@@ -260,12 +422,12 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                realms.get(index).mark_values(&mut queues);
+                realms.get(index).mark_values(queues);
             }
         });
 
         let mut declarative_environment_marks: Box<[DeclarativeEnvironmentIndex]> =
-            queues.declarative_environments.drain(..).collect();
+            take_marks(&mut queues.declarative_environments, &mut budget);
         declarative_environment_marks.sort();
         declarative_environment_marks.iter().for_each(|&idx| {
             let index = idx.into_index();
@@ -275,11 +437,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                declarative_environments.get(index).mark_values(&mut queues);
+                declarative_environments.get(index).mark_values(queues);
             }
         });
         let mut function_environment_marks: Box<[FunctionEnvironmentIndex]> =
-            queues.function_environments.drain(..).collect();
+            take_marks(&mut queues.function_environments, &mut budget);
         function_environment_marks.sort();
         function_environment_marks.iter().for_each(|&idx| {
             let index = idx.into_index();
@@ -289,11 +451,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                function_environments.get(index).mark_values(&mut queues);
+                function_environments.get(index).mark_values(queues);
             }
         });
         let mut global_environment_marks: Box<[GlobalEnvironmentIndex]> =
-            queues.global_environments.drain(..).collect();
+            take_marks(&mut queues.global_environments, &mut budget);
         global_environment_marks.sort();
         global_environment_marks.iter().for_each(|&idx| {
             let index = idx.into_index();
@@ -303,11 +465,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                global_environments.get(index).mark_values(&mut queues);
+                global_environments.get(index).mark_values(queues);
             }
         });
         let mut object_environment_marks: Box<[ObjectEnvironmentIndex]> =
-            queues.object_environments.drain(..).collect();
+            take_marks(&mut queues.object_environments, &mut budget);
         object_environment_marks.sort();
         object_environment_marks.iter().for_each(|&idx| {
             let index = idx.into_index();
@@ -317,11 +479,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                object_environments.get(index).mark_values(&mut queues);
+                object_environments.get(index).mark_values(queues);
             }
         });
 
-        let mut array_marks: Box<[Array]> = queues.arrays.drain(..).collect();
+        let mut array_marks: Box<[Array]> = take_marks(&mut queues.arrays, &mut budget);
         array_marks.sort();
         array_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -331,13 +493,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                arrays.get(index).mark_values(&mut queues);
+                arrays.get(index).mark_values(queues);
             }
         });
         #[cfg(feature = "array-buffer")]
         {
             let mut array_buffer_marks: Box<[ArrayBuffer]> =
-                queues.array_buffers.drain(..).collect();
+                take_marks(&mut queues.array_buffers, &mut budget);
             array_buffer_marks.sort();
             array_buffer_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -347,12 +509,12 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    array_buffers.get(index).mark_values(&mut queues);
+                    array_buffers.get(index).mark_values(queues);
                 }
             });
         }
         let mut array_iterator_marks: Box<[ArrayIterator]> =
-            queues.array_iterators.drain(..).collect();
+            take_marks(&mut queues.array_iterators, &mut budget);
         array_iterator_marks.sort();
         array_iterator_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -362,11 +524,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                array_iterators.get(index).mark_values(&mut queues);
+                array_iterators.get(index).mark_values(queues);
             }
         });
         let mut async_generator_marks: Box<[AsyncGenerator]> =
-            queues.async_generators.drain(..).collect();
+            take_marks(&mut queues.async_generators, &mut budget);
         async_generator_marks.sort();
         async_generator_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -376,11 +538,25 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                async_generators.get(index).mark_values(&mut queues);
+                async_generators.get(index).mark_values(queues);
+            }
+        });
+        let mut array_from_async_reaction_marks: Box<[ArrayFromAsyncReactionIdentifier]> =
+            take_marks(&mut queues.array_from_async_reactions, &mut budget);
+        array_from_async_reaction_marks.sort();
+        array_from_async_reaction_marks.iter().for_each(|&idx| {
+            let index = idx.into_index();
+            if let Some(marked) = bits.array_from_async_reactions.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                array_from_async_reactions.get(index).mark_values(queues);
             }
         });
         let mut await_reaction_marks: Box<[AwaitReactionIdentifier]> =
-            queues.await_reactions.drain(..).collect();
+            take_marks(&mut queues.await_reactions, &mut budget);
         await_reaction_marks.sort();
         await_reaction_marks.iter().for_each(|&idx| {
             let index = idx.into_index();
@@ -390,10 +566,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                await_reactions.get(index).mark_values(&mut queues);
+                await_reactions.get(index).mark_values(queues);
             }
         });
-        let mut bigint_marks: Box<[HeapBigInt]> = queues.bigints.drain(..).collect();
+        let mut bigint_marks: Box<[HeapBigInt]> = take_marks(&mut queues.bigints, &mut budget);
         bigint_marks.sort();
         bigint_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -403,11 +579,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                bigints.get(index).mark_values(&mut queues);
+                bigints.get(index).mark_values(queues);
             }
         });
         let mut bound_function_marks: Box<[BoundFunction]> =
-            queues.bound_functions.drain(..).collect();
+            take_marks(&mut queues.bound_functions, &mut budget);
         bound_function_marks.sort();
         bound_function_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -417,11 +593,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                bound_functions.get(index).mark_values(&mut queues);
+                bound_functions.get(index).mark_values(queues);
             }
         });
         let mut ecmascript_function_marks: Box<[ECMAScriptFunction]> =
-            queues.ecmascript_functions.drain(..).collect();
+            take_marks(&mut queues.ecmascript_functions, &mut budget);
         ecmascript_function_marks.sort();
         ecmascript_function_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -431,10 +607,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                ecmascript_functions.get(index).mark_values(&mut queues);
+                ecmascript_functions.get(index).mark_values(queues);
             }
         });
-        let mut error_marks: Box<[Error]> = queues.errors.drain(..).collect();
+        let mut error_marks: Box<[Error]> = take_marks(&mut queues.errors, &mut budget);
         error_marks.sort();
         error_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -444,10 +620,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                errors.get(index).mark_values(&mut queues);
+                errors.get(index).mark_values(queues);
             }
         });
-        let mut executable_marks: Box<[Executable]> = queues.executables.drain(..).collect();
+        let mut executable_marks: Box<[Executable]> =
+            take_marks(&mut queues.executables, &mut budget);
         executable_marks.sort();
         executable_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -457,10 +634,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                executables.get(index).mark_values(&mut queues);
+                executables.get(index).mark_values(queues);
             }
         });
-        let mut source_code_marks: Box<[SourceCode]> = queues.source_codes.drain(..).collect();
+        let mut source_code_marks: Box<[SourceCode]> =
+            take_marks(&mut queues.source_codes, &mut budget);
         source_code_marks.sort();
         source_code_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -470,11 +648,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                source_codes.get(index).mark_values(&mut queues);
+                source_codes.get(index).mark_values(queues);
             }
         });
         let mut builtin_constructors_marks: Box<[BuiltinConstructorFunction]> =
-            queues.builtin_constructors.drain(..).collect();
+            take_marks(&mut queues.builtin_constructors, &mut budget);
         builtin_constructors_marks.sort();
         builtin_constructors_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -484,11 +662,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                builtin_constructors.get(index).mark_values(&mut queues);
+                builtin_constructors.get(index).mark_values(queues);
             }
         });
         let mut builtin_functions_marks: Box<[BuiltinFunction]> =
-            queues.builtin_functions.drain(..).collect();
+            take_marks(&mut queues.builtin_functions, &mut budget);
         builtin_functions_marks.sort();
         builtin_functions_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -498,12 +676,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                builtin_functions.get(index).mark_values(&mut queues);
+                builtin_functions.get(index).mark_values(queues);
             }
         });
         #[cfg(feature = "array-buffer")]
         {
-            let mut data_view_marks: Box<[DataView]> = queues.data_views.drain(..).collect();
+            let mut data_view_marks: Box<[DataView]> =
+                take_marks(&mut queues.data_views, &mut budget);
             data_view_marks.sort();
             data_view_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -513,13 +692,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    data_views.get(index).mark_values(&mut queues);
+                    data_views.get(index).mark_values(queues);
                 }
             });
         }
         #[cfg(feature = "date")]
         {
-            let mut date_marks: Box<[Date]> = queues.dates.drain(..).collect();
+            let mut date_marks: Box<[Date]> = take_marks(&mut queues.dates, &mut budget);
             date_marks.sort();
             date_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -529,12 +708,29 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    dates.get(index).mark_values(&mut queues);
+                    dates.get(index).mark_values(queues);
+                }
+            });
+        }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        {
+            let mut disposable_stack_marks: Box<[DisposableStack]> =
+                take_marks(&mut queues.disposable_stacks, &mut budget);
+            disposable_stack_marks.sort();
+            disposable_stack_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if let Some(marked) = bits.disposable_stacks.get_mut(index) {
+                    if *marked {
+                        // Already marked, ignore
+                        return;
+                    }
+                    *marked = true;
+                    disposable_stacks.get(index).mark_values(queues);
                 }
             });
         }
         let mut embedder_object_marks: Box<[EmbedderObject]> =
-            queues.embedder_objects.drain(..).collect();
+            take_marks(&mut queues.embedder_objects, &mut budget);
         embedder_object_marks.sort();
         embedder_object_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -544,11 +740,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                embedder_objects.get(index).mark_values(&mut queues);
+                embedder_objects.get(index).mark_values(queues);
             }
         });
         let mut finalization_registry_marks: Box<[FinalizationRegistry]> =
-            queues.finalization_registrys.drain(..).collect();
+            take_marks(&mut queues.finalization_registrys, &mut budget);
         finalization_registry_marks.sort();
         finalization_registry_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -558,10 +754,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                finalization_registrys.get(index).mark_values(&mut queues);
+                finalization_registrys.get(index).mark_values(queues);
             }
         });
-        let mut generator_marks: Box<[Generator]> = queues.generators.drain(..).collect();
+        let mut generator_marks: Box<[Generator]> = take_marks(&mut queues.generators, &mut budget);
         generator_marks.sort();
         generator_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -571,10 +767,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                generators.get(index).mark_values(&mut queues);
+                generators.get(index).mark_values(queues);
             }
         });
-        let mut object_marks: Box<[OrdinaryObject]> = queues.objects.drain(..).collect();
+        let mut object_marks: Box<[OrdinaryObject]> = take_marks(&mut queues.objects, &mut budget);
         object_marks.sort();
         object_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -584,10 +780,24 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                objects.get(index).mark_values(&mut queues);
+                objects.get(index).mark_values(queues);
             }
         });
-        let mut promise_marks: Box<[Promise]> = queues.promises.drain(..).collect();
+        let mut object_shape_marks: Box<[ObjectShape]> =
+            take_marks(&mut queues.object_shapes, &mut budget);
+        object_shape_marks.sort();
+        object_shape_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.object_shapes.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                object_shapes.get(index).mark_values(queues);
+            }
+        });
+        let mut promise_marks: Box<[Promise]> = take_marks(&mut queues.promises, &mut budget);
         promise_marks.sort();
         promise_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -597,11 +807,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                promises.get(index).mark_values(&mut queues);
+                promises.get(index).mark_values(queues);
             }
         });
         let mut promise_reaction_record_marks: Box<[PromiseReaction]> =
-            queues.promise_reaction_records.drain(..).collect();
+            take_marks(&mut queues.promise_reaction_records, &mut budget);
         promise_reaction_record_marks.sort();
         promise_reaction_record_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -611,11 +821,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                promise_reaction_records.get(index).mark_values(&mut queues);
+                promise_reaction_records.get(index).mark_values(queues);
             }
         });
         let mut promise_resolving_function_marks: Box<[BuiltinPromiseResolvingFunction]> =
-            queues.promise_resolving_functions.drain(..).collect();
+            take_marks(&mut queues.promise_resolving_functions, &mut budget);
         promise_resolving_function_marks.sort();
         promise_resolving_function_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -625,12 +835,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                promise_resolving_functions
-                    .get(index)
-                    .mark_values(&mut queues);
+                promise_resolving_functions.get(index).mark_values(queues);
             }
         });
-        let mut proxy_marks: Box<[Proxy]> = queues.proxys.drain(..).collect();
+        let mut proxy_marks: Box<[Proxy]> = take_marks(&mut queues.proxys, &mut budget);
         proxy_marks.sort();
         proxy_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -640,10 +848,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                proxys.get(index).mark_values(&mut queues);
+                proxys.get(index).mark_values(queues);
             }
         });
-        let mut map_marks: Box<[Map]> = queues.maps.drain(..).collect();
+        let mut map_marks: Box<[Map]> = take_marks(&mut queues.maps, &mut budget);
         map_marks.sort();
         map_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -653,10 +861,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                maps.get(index).mark_values(&mut queues);
+                maps.get(index).mark_values(queues);
             }
         });
-        let mut map_iterator_marks: Box<[MapIterator]> = queues.map_iterators.drain(..).collect();
+        let mut map_iterator_marks: Box<[MapIterator]> =
+            take_marks(&mut queues.map_iterators, &mut budget);
         map_iterator_marks.sort();
         map_iterator_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -666,10 +875,24 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                map_iterators.get(index).mark_values(&mut queues);
+                map_iterators.get(index).mark_values(queues);
+            }
+        });
+        let mut mapped_arguments_marks: Box<[MappedArgumentsObject]> =
+            take_marks(&mut queues.mapped_arguments, &mut budget);
+        mapped_arguments_marks.sort();
+        mapped_arguments_marks.iter().for_each(|&idx| {
+            let index = idx.get_index();
+            if let Some(marked) = bits.mapped_arguments.get_mut(index) {
+                if *marked {
+                    // Already marked, ignore
+                    return;
+                }
+                *marked = true;
+                mapped_arguments.get(index).mark_values(queues);
             }
         });
-        let mut number_marks: Box<[HeapNumber]> = queues.numbers.drain(..).collect();
+        let mut number_marks: Box<[HeapNumber]> = take_marks(&mut queues.numbers, &mut budget);
         number_marks.sort();
         number_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -679,11 +902,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                numbers.get(index).mark_values(&mut queues);
+                numbers.get(index).mark_values(queues);
             }
         });
         let mut primitive_object_marks: Box<[PrimitiveObject]> =
-            queues.primitive_objects.drain(..).collect();
+            take_marks(&mut queues.primitive_objects, &mut budget);
         primitive_object_marks.sort();
         primitive_object_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -693,12 +916,12 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                primitive_objects.get(index).mark_values(&mut queues);
+                primitive_objects.get(index).mark_values(queues);
             }
         });
         #[cfg(feature = "regexp")]
         {
-            let mut regexp_marks: Box<[RegExp]> = queues.regexps.drain(..).collect();
+            let mut regexp_marks: Box<[RegExp]> = take_marks(&mut queues.regexps, &mut budget);
             regexp_marks.sort();
             regexp_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -708,13 +931,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    regexps.get(index).mark_values(&mut queues);
+                    regexps.get(index).mark_values(queues);
                 }
             });
         }
         #[cfg(feature = "set")]
         {
-            let mut set_marks: Box<[Set]> = queues.sets.drain(..).collect();
+            let mut set_marks: Box<[Set]> = take_marks(&mut queues.sets, &mut budget);
             set_marks.sort();
             set_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -724,12 +947,12 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    sets.get(index).mark_values(&mut queues);
+                    sets.get(index).mark_values(queues);
                 }
             });
 
             let mut set_iterator_marks: Box<[SetIterator]> =
-                queues.set_iterators.drain(..).collect();
+                take_marks(&mut queues.set_iterators, &mut budget);
             set_iterator_marks.sort();
             set_iterator_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -739,14 +962,14 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    set_iterators.get(index).mark_values(&mut queues);
+                    set_iterators.get(index).mark_values(queues);
                 }
             });
         }
         #[cfg(feature = "shared-array-buffer")]
         {
             let mut shared_array_buffer_marks: Box<[SharedArrayBuffer]> =
-                queues.shared_array_buffers.drain(..).collect();
+                take_marks(&mut queues.shared_array_buffers, &mut budget);
             shared_array_buffer_marks.sort();
             shared_array_buffer_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -756,11 +979,11 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    shared_array_buffers.get(index).mark_values(&mut queues);
+                    shared_array_buffers.get(index).mark_values(queues);
                 }
             });
         }
-        let mut string_marks: Box<[HeapString]> = queues.strings.drain(..).collect();
+        let mut string_marks: Box<[HeapString]> = take_marks(&mut queues.strings, &mut budget);
         string_marks.sort();
         string_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -770,10 +993,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                strings.get(index).mark_values(&mut queues);
+                strings.get(index).mark_values(queues);
             }
         });
-        let mut symbol_marks: Box<[Symbol]> = queues.symbols.drain(..).collect();
+        let mut symbol_marks: Box<[Symbol]> = take_marks(&mut queues.symbols, &mut budget);
         symbol_marks.sort();
         symbol_marks.iter().for_each(|&idx| {
             let index = idx.get_index();
@@ -783,13 +1006,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                     return;
                 }
                 *marked = true;
-                symbols.get(index).mark_values(&mut queues);
+                symbols.get(index).mark_values(queues);
             }
         });
         #[cfg(feature = "array-buffer")]
         {
             let mut typed_arrays_marks: Box<[TypedArrayIndex]> =
-                queues.typed_arrays.drain(..).collect();
+                take_marks(&mut queues.typed_arrays, &mut budget);
             typed_arrays_marks.sort();
             typed_arrays_marks.iter().for_each(|&idx| {
                 let index = idx.into_index();
@@ -799,13 +1022,13 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    typed_arrays.get(index).mark_values(&mut queues);
+                    typed_arrays.get(index).mark_values(queues);
                 }
             });
         }
         #[cfg(feature = "weak-refs")]
         {
-            let mut weak_map_marks: Box<[WeakMap]> = queues.weak_maps.drain(..).collect();
+            let mut weak_map_marks: Box<[WeakMap]> = take_marks(&mut queues.weak_maps, &mut budget);
             weak_map_marks.sort();
             weak_map_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -815,10 +1038,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    weak_maps.get(index).mark_values(&mut queues);
+                    weak_maps.get(index).mark_values(queues);
                 }
             });
-            let mut weak_ref_marks: Box<[WeakRef]> = queues.weak_refs.drain(..).collect();
+            let mut weak_ref_marks: Box<[WeakRef]> = take_marks(&mut queues.weak_refs, &mut budget);
             weak_ref_marks.sort();
             weak_ref_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -828,10 +1051,10 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    weak_refs.get(index).mark_values(&mut queues);
+                    weak_refs.get(index).mark_values(queues);
                 }
             });
-            let mut weak_set_marks: Box<[WeakSet]> = queues.weak_sets.drain(..).collect();
+            let mut weak_set_marks: Box<[WeakSet]> = take_marks(&mut queues.weak_sets, &mut budget);
             weak_set_marks.sort();
             weak_set_marks.iter().for_each(|&idx| {
                 let index = idx.get_index();
@@ -841,17 +1064,23 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                         return;
                     }
                     *marked = true;
-                    weak_sets.get(index).mark_values(&mut queues);
+                    weak_sets.get(index).mark_values(queues);
                 }
             });
         }
 
-        let mut e_2_4_marks: Box<[(ElementIndex, u32)]> = queues.e_2_4.drain(..).collect();
+        let mut e_2_4_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_4, &mut budget);
         e_2_4_marks.sort();
         e_2_4_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_4.get_mut(index) {
                 if *marked {
+                    if index < e2pow4.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
@@ -860,19 +1089,25 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 *marked = true;
                 *length = len as u8;
                 if let Some(descriptors) = e2pow4.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow4.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
-        let mut e_2_6_marks: Box<[(ElementIndex, u32)]> = queues.e_2_6.drain(..).collect();
+        let mut e_2_6_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_6, &mut budget);
         e_2_6_marks.sort();
         e_2_6_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_6.get_mut(index) {
                 if *marked {
+                    if index < e2pow6.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
@@ -881,40 +1116,52 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 *marked = true;
                 *length = len as u8;
                 if let Some(descriptors) = e2pow6.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow6.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
-        let mut e_2_8_marks: Box<[(ElementIndex, u32)]> = queues.e_2_8.drain(..).collect();
+        let mut e_2_8_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_8, &mut budget);
         e_2_8_marks.sort();
         e_2_8_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_8.get_mut(index) {
                 if *marked {
+                    if index < e2pow8.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
                     panic!("ElementsVector was not unique");
                 }
                 *marked = true;
-                *length = len as u8;
+                *length = len as u16;
                 if let Some(descriptors) = e2pow8.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow8.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
-        let mut e_2_10_marks: Box<[(ElementIndex, u32)]> = queues.e_2_10.drain(..).collect();
+        let mut e_2_10_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_10, &mut budget);
         e_2_10_marks.sort();
         e_2_10_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_10.get_mut(index) {
                 if *marked {
+                    if index < e2pow10.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
@@ -923,19 +1170,25 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 *marked = true;
                 *length = len as u16;
                 if let Some(descriptors) = e2pow10.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow10.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
-        let mut e_2_12_marks: Box<[(ElementIndex, u32)]> = queues.e_2_12.drain(..).collect();
+        let mut e_2_12_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_12, &mut budget);
         e_2_12_marks.sort();
         e_2_12_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_12.get_mut(index) {
                 if *marked {
+                    if index < e2pow12.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
@@ -944,19 +1197,25 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 *marked = true;
                 *length = len as u16;
                 if let Some(descriptors) = e2pow12.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow12.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
-        let mut e_2_16_marks: Box<[(ElementIndex, u32)]> = queues.e_2_16.drain(..).collect();
+        let mut e_2_16_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_16, &mut budget);
         e_2_16_marks.sort();
         e_2_16_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_16.get_mut(index) {
                 if *marked {
+                    if index < e2pow16.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
@@ -965,19 +1224,25 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 *marked = true;
                 *length = len as u16;
                 if let Some(descriptors) = e2pow16.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow16.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
-        let mut e_2_24_marks: Box<[(ElementIndex, u32)]> = queues.e_2_24.drain(..).collect();
+        let mut e_2_24_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_24, &mut budget);
         e_2_24_marks.sort();
         e_2_24_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_24.get_mut(index) {
                 if *marked {
+                    if index < e2pow24.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
@@ -986,19 +1251,25 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 *marked = true;
                 *length = len;
                 if let Some(descriptors) = e2pow24.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow24.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
-        let mut e_2_32_marks: Box<[(ElementIndex, u32)]> = queues.e_2_32.drain(..).collect();
+        let mut e_2_32_marks: Box<[(ElementIndex, u32)]> =
+            take_marks(&mut queues.e_2_32, &mut budget);
         e_2_32_marks.sort();
         e_2_32_marks.iter().for_each(|&(idx, len)| {
             let index = idx.into_index();
             if let Some((marked, length)) = bits.e_2_32.get_mut(index) {
                 if *marked {
+                    if index < e2pow32.remembered.old_len() {
+                        // Old element arrays are traced through their
+                        // remembered set.
+                        return;
+                    }
                     // Already marked, panic: Elements are uniquely owned
                     // and any other reference existing to this entry is a sign of
                     // a GC algorithm bug.
@@ -1007,39 +1278,87 @@ pub fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<RealmIdentifier>], g
                 *marked = true;
                 *length = len;
                 if let Some(descriptors) = e2pow32.descriptors.get(&idx) {
-                    mark_descriptors(descriptors, &mut queues);
+                    mark_descriptors(descriptors, queues);
                 }
                 if let Some(array) = e2pow32.values.get(index) {
-                    mark_array_with_u32_length(array, &mut queues, len);
+                    mark_array_with_u32_length(array, queues, len);
                 }
             }
         });
     }
 
-    sweep(agent, &bits, root_realms, gc);
+    queues.is_empty()
 }
 
-fn sweep(
-    agent: &mut Agent,
-    bits: &HeapBits,
-    root_realms: &mut [Option<RealmIdentifier>],
-    _: GcScope,
-) {
-    let compactions = CompactionLists::create_from_bits(bits);
+/// Performs garbage collection if enough has been allocated since the last
+/// collection that the next one is due.
+///
+/// This must only be called at points where all live values are reachable
+/// from the roots: The caller must have scoped its own values. Executing VMs
+/// further up the call stack are in the Agent's VM stack.
+pub(crate) fn gc_safepoint(agent: &mut Agent, gc: GcScope) {
+    if agent.gc_is_due() {
+        scheduled_heap_gc(agent, gc);
+    }
+}
 
-    for realm in root_realms {
-        realm.sweep_values(&compactions);
+/// Resets the allocation counters and sets the allocation budget until the
+/// next garbage collection. After a full collection, the heap size at which
+/// the next full collection is due is set based on the size of the heap that
+/// survived it, and the grace given after an out of memory error ends if the
+/// heap is within its size limit. The budget never extends past the limit.
+fn reset_allocation_budget(agent: &mut Agent, full: bool) {
+    let Agent { heap, options, .. } = agent;
+    // Note: Only a full collection measures the heap. Heap data that survives
+    // a minor collection is counted as live until the next full collection.
+    let size = if full {
+        heap.estimated_size()
+    } else {
+        heap.used_bytes()
+    };
+    heap.gc_live_bytes = size;
+    heap.alloc_counter = 0;
+    heap.elements.alloc_counter = 0;
+    heap.environments.alloc_counter = 0;
+    if full {
+        let threshold = (size as f64 * options.gc_growth_factor.max(1.0)) as usize;
+        heap.gc_major_threshold = threshold.max(options.gc_initial_threshold);
+    }
+    heap.gc_allocation_budget = if options.gc_nursery_size == 0 {
+        heap.gc_major_threshold
+            .saturating_sub(size)
+            .max(options.gc_initial_threshold)
+    } else {
+        options.gc_nursery_size
+    };
+    if let Some(max_heap_bytes) = options.max_heap_bytes {
+        if full && size <= max_heap_bytes {
+            heap.gc_heap_limit_grace = 0;
+        }
+        let limit = max_heap_bytes.saturating_add(heap.gc_heap_limit_grace);
+        heap.gc_allocation_budget = heap.gc_allocation_budget.min(limit.saturating_sub(size));
     }
+}
+
+fn sweep(agent: &mut Agent, bits: &HeapBits, _: GcScope) {
+    let compactions = CompactionLists::create_from_bits(bits);
 
     let Agent {
         heap,
         execution_context_stack,
         stack_refs,
         vm_stack,
+        realm_roots,
         options: _,
         symbol_id: _,
         global_symbol_registry: _,
         host_hooks: _,
+        interrupts: _,
+        terminating: _,
+        fuel: _,
+        debugger,
+        profiler,
+        coverage,
     } = agent;
 
     let Heap {
@@ -1050,6 +1369,7 @@ fn sweep(
         arrays,
         array_iterators,
         async_generators,
+        array_from_async_reactions,
         await_reactions,
         bigints,
         bound_functions,
@@ -1063,6 +1383,8 @@ fn sweep(
         data_view_byte_offsets,
         #[cfg(feature = "date")]
         dates,
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        disposable_stacks,
         ecmascript_functions,
         elements,
         embedder_objects,
@@ -1075,9 +1397,11 @@ fn sweep(
         globals,
         maps,
         map_iterators,
+        mapped_arguments,
         modules,
         numbers,
         objects,
+        object_shapes,
         primitive_objects,
         promise_reaction_records,
         promise_resolving_functions,
@@ -1095,7 +1419,7 @@ fn sweep(
         shared_array_buffers,
         strings,
         string_lookup_table,
-        string_hasher: _,
+        string_hasher,
         symbols,
         #[cfg(feature = "array-buffer")]
         typed_arrays,
@@ -1111,6 +1435,13 @@ fn sweep(
         weak_refs,
         #[cfg(feature = "weak-refs")]
         weak_sets,
+        alloc_counter: _,
+        gc_allocation_budget: _,
+        gc_major_threshold: _,
+        gc_live_bytes: _,
+        gc_heap_limit_grace: _,
+        remembered_sets,
+        incremental_marking: _,
     } = heap;
     let Environments {
         declarative,
@@ -1118,6 +1449,7 @@ fn sweep(
         global,
         object,
         private: _private_environments,
+        alloc_counter: _,
     } = environments;
     let ElementArrays {
         e2pow4,
@@ -1128,6 +1460,7 @@ fn sweep(
         e2pow16,
         e2pow24,
         e2pow32,
+        alloc_counter: _,
     } = elements;
 
     let mut globals = globals.borrow_mut();
@@ -1145,11 +1478,13 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_10,
                     &bits.e_2_10,
+                    &e2pow10.remembered,
                 );
-                sweep_heap_u16_elements_vector_values(
+                sweep_heap_elements_vector_values(
                     &mut e2pow10.values,
                     &compactions,
                     &bits.e_2_10,
+                    &e2pow10.remembered,
                 );
             });
         }
@@ -1160,11 +1495,13 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_12,
                     &bits.e_2_12,
+                    &e2pow12.remembered,
                 );
-                sweep_heap_u16_elements_vector_values(
+                sweep_heap_elements_vector_values(
                     &mut e2pow12.values,
                     &compactions,
                     &bits.e_2_12,
+                    &e2pow12.remembered,
                 );
             });
         }
@@ -1175,11 +1512,13 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_16,
                     &bits.e_2_16,
+                    &e2pow16.remembered,
                 );
-                sweep_heap_u16_elements_vector_values(
+                sweep_heap_elements_vector_values(
                     &mut e2pow16.values,
                     &compactions,
                     &bits.e_2_16,
+                    &e2pow16.remembered,
                 );
             });
         }
@@ -1190,11 +1529,13 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_24,
                     &bits.e_2_24,
+                    &e2pow24.remembered,
                 );
-                sweep_heap_u32_elements_vector_values(
+                sweep_heap_elements_vector_values(
                     &mut e2pow24.values,
                     &compactions,
                     &bits.e_2_24,
+                    &e2pow24.remembered,
                 );
             });
         }
@@ -1205,11 +1546,13 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_32,
                     &bits.e_2_32,
+                    &e2pow32.remembered,
                 );
-                sweep_heap_u32_elements_vector_values(
+                sweep_heap_elements_vector_values(
                     &mut e2pow32.values,
                     &compactions,
                     &bits.e_2_32,
+                    &e2pow32.remembered,
                 );
             });
         }
@@ -1220,8 +1563,14 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_4,
                     &bits.e_2_4,
+                    &e2pow4.remembered,
+                );
+                sweep_heap_elements_vector_values(
+                    &mut e2pow4.values,
+                    &compactions,
+                    &bits.e_2_4,
+                    &e2pow4.remembered,
                 );
-                sweep_heap_u8_elements_vector_values(&mut e2pow4.values, &compactions, &bits.e_2_4);
             });
         }
         if !e2pow6.values.is_empty() {
@@ -1231,8 +1580,14 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_6,
                     &bits.e_2_6,
+                    &e2pow6.remembered,
+                );
+                sweep_heap_elements_vector_values(
+                    &mut e2pow6.values,
+                    &compactions,
+                    &bits.e_2_6,
+                    &e2pow6.remembered,
                 );
-                sweep_heap_u8_elements_vector_values(&mut e2pow6.values, &compactions, &bits.e_2_6);
             });
         }
         if !e2pow8.values.is_empty() {
@@ -1242,14 +1597,25 @@ fn sweep(
                     &compactions,
                     &compactions.e_2_8,
                     &bits.e_2_8,
+                    &e2pow8.remembered,
+                );
+                sweep_heap_elements_vector_values(
+                    &mut e2pow8.values,
+                    &compactions,
+                    &bits.e_2_8,
+                    &e2pow8.remembered,
                 );
-                sweep_heap_u8_elements_vector_values(&mut e2pow8.values, &compactions, &bits.e_2_8);
             });
         }
         #[cfg(feature = "array-buffer")]
         if !array_buffers.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(array_buffers, &compactions, &bits.array_buffers);
+                sweep_heap_vector_values(
+                    array_buffers,
+                    &compactions,
+                    &bits.array_buffers,
+                    &remembered_sets.array_buffers,
+                );
                 sweep_side_table_values(
                     array_buffer_detach_keys,
                     &compactions.array_buffers,
@@ -1259,32 +1625,72 @@ fn sweep(
         }
         if !arrays.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(arrays, &compactions, &bits.arrays);
+                sweep_heap_vector_values(
+                    arrays,
+                    &compactions,
+                    &bits.arrays,
+                    &remembered_sets.arrays,
+                );
             });
         }
         if !array_iterators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(array_iterators, &compactions, &bits.array_iterators);
+                sweep_heap_vector_values(
+                    array_iterators,
+                    &compactions,
+                    &bits.array_iterators,
+                    &remembered_sets.array_iterators,
+                );
             });
         }
         if !async_generators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(async_generators, &compactions, &bits.async_generators);
+                sweep_heap_vector_values(
+                    async_generators,
+                    &compactions,
+                    &bits.async_generators,
+                    &remembered_sets.async_generators,
+                );
+            });
+        }
+        if !array_from_async_reactions.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    array_from_async_reactions,
+                    &compactions,
+                    &bits.array_from_async_reactions,
+                    &remembered_sets.array_from_async_reactions,
+                );
             });
         }
         if !await_reactions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(await_reactions, &compactions, &bits.await_reactions);
+                sweep_heap_vector_values(
+                    await_reactions,
+                    &compactions,
+                    &bits.await_reactions,
+                    &remembered_sets.await_reactions,
+                );
             });
         }
         if !bigints.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(bigints, &compactions, &bits.bigints);
+                sweep_heap_vector_values(
+                    bigints,
+                    &compactions,
+                    &bits.bigints,
+                    &remembered_sets.bigints,
+                );
             });
         }
         if !bound_functions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(bound_functions, &compactions, &bits.bound_functions);
+                sweep_heap_vector_values(
+                    bound_functions,
+                    &compactions,
+                    &bits.bound_functions,
+                    &remembered_sets.bound_functions,
+                );
             });
         }
         if !builtin_constructors.is_empty() {
@@ -1293,18 +1699,29 @@ fn sweep(
                     builtin_constructors,
                     &compactions,
                     &bits.builtin_constructors,
+                    &remembered_sets.builtin_constructors,
                 );
             });
         }
         if !builtin_functions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(builtin_functions, &compactions, &bits.builtin_functions);
+                sweep_heap_vector_values(
+                    builtin_functions,
+                    &compactions,
+                    &bits.builtin_functions,
+                    &remembered_sets.builtin_functions,
+                );
             });
         }
         #[cfg(feature = "array-buffer")]
         if !data_views.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(data_views, &compactions, &bits.data_views);
+                sweep_heap_vector_values(
+                    data_views,
+                    &compactions,
+                    &bits.data_views,
+                    &remembered_sets.data_views,
+                );
                 sweep_side_table_values(
                     data_view_byte_lengths,
                     &compactions.data_views,
@@ -1320,12 +1737,28 @@ fn sweep(
         #[cfg(feature = "date")]
         if !dates.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(dates, &compactions, &bits.dates);
+                sweep_heap_vector_values(dates, &compactions, &bits.dates, &remembered_sets.dates);
+            });
+        }
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        if !disposable_stacks.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    disposable_stacks,
+                    &compactions,
+                    &bits.disposable_stacks,
+                    &remembered_sets.disposable_stacks,
+                );
             });
         }
         if !declarative.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(declarative, &compactions, &bits.declarative_environments);
+                sweep_heap_vector_values(
+                    declarative,
+                    &compactions,
+                    &bits.declarative_environments,
+                    &remembered_sets.declarative_environments,
+                );
             });
         }
         if !ecmascript_functions.is_empty() {
@@ -1334,22 +1767,38 @@ fn sweep(
                     ecmascript_functions,
                     &compactions,
                     &bits.ecmascript_functions,
+                    &remembered_sets.ecmascript_functions,
                 );
             });
         }
         if !embedder_objects.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(embedder_objects, &compactions, &bits.embedder_objects);
+                sweep_heap_vector_values(
+                    embedder_objects,
+                    &compactions,
+                    &bits.embedder_objects,
+                    &remembered_sets.embedder_objects,
+                );
             });
         }
         if !errors.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(errors, &compactions, &bits.errors);
+                sweep_heap_vector_values(
+                    errors,
+                    &compactions,
+                    &bits.errors,
+                    &remembered_sets.errors,
+                );
             });
         }
         if !executables.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(executables, &compactions, &bits.executables);
+                sweep_heap_vector_values(
+                    executables,
+                    &compactions,
+                    &bits.executables,
+                    &remembered_sets.executables,
+                );
             });
         }
         if !finalization_registrys.is_empty() {
@@ -1358,57 +1807,124 @@ fn sweep(
                     finalization_registrys,
                     &compactions,
                     &bits.finalization_registrys,
+                    &remembered_sets.finalization_registrys,
                 );
             });
         }
         if !function.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(function, &compactions, &bits.function_environments);
+                sweep_heap_vector_values(
+                    function,
+                    &compactions,
+                    &bits.function_environments,
+                    &remembered_sets.function_environments,
+                );
             });
         }
         if !generators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(generators, &compactions, &bits.generators);
+                sweep_heap_vector_values(
+                    generators,
+                    &compactions,
+                    &bits.generators,
+                    &remembered_sets.generators,
+                );
             });
         }
         if !global.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(global, &compactions, &bits.global_environments);
+                sweep_heap_vector_values(
+                    global,
+                    &compactions,
+                    &bits.global_environments,
+                    &remembered_sets.global_environments,
+                );
             });
         }
         if !maps.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(maps, &compactions, &bits.maps);
+                sweep_heap_vector_values(maps, &compactions, &bits.maps, &remembered_sets.maps);
             });
         }
         if !map_iterators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(map_iterators, &compactions, &bits.map_iterators);
+                sweep_heap_vector_values(
+                    map_iterators,
+                    &compactions,
+                    &bits.map_iterators,
+                    &remembered_sets.map_iterators,
+                );
+            });
+        }
+        if !mapped_arguments.is_empty() {
+            s.spawn(|| {
+                sweep_heap_vector_values(
+                    mapped_arguments,
+                    &compactions,
+                    &bits.mapped_arguments,
+                    &remembered_sets.mapped_arguments,
+                );
             });
         }
         if !modules.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(modules, &compactions, &bits.modules);
+                sweep_heap_vector_values(
+                    modules,
+                    &compactions,
+                    &bits.modules,
+                    &remembered_sets.modules,
+                );
             });
         }
         if !numbers.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(numbers, &compactions, &bits.numbers);
+                sweep_heap_vector_values(
+                    numbers,
+                    &compactions,
+                    &bits.numbers,
+                    &remembered_sets.numbers,
+                );
             });
         }
         if !object.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(object, &compactions, &bits.object_environments);
+                sweep_heap_vector_values(
+                    object,
+                    &compactions,
+                    &bits.object_environments,
+                    &remembered_sets.object_environments,
+                );
             });
         }
         if !objects.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(objects, &compactions, &bits.objects);
+                sweep_heap_vector_values(
+                    objects,
+                    &compactions,
+                    &bits.objects,
+                    &remembered_sets.objects,
+                );
+            });
+        }
+        if !object_shapes.is_empty() {
+            s.spawn(|| {
+                sweep_shape_transitions(object_shapes, &bits.object_shapes);
+                sweep_heap_vector_values(
+                    object_shapes,
+                    &compactions,
+                    &bits.object_shapes,
+                    &remembered_sets.object_shapes,
+                );
             });
         }
         if !primitive_objects.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(primitive_objects, &compactions, &bits.primitive_objects);
+                sweep_heap_vector_values(
+                    primitive_objects,
+                    &compactions,
+                    &bits.primitive_objects,
+                    &remembered_sets.primitive_objects,
+                );
             });
         }
         if !promise_reaction_records.is_empty() {
@@ -1417,6 +1933,7 @@ fn sweep(
                     promise_reaction_records,
                     &compactions,
                     &bits.promise_reaction_records,
+                    &remembered_sets.promise_reaction_records,
                 );
             });
         }
@@ -1426,45 +1943,76 @@ fn sweep(
                     promise_resolving_functions,
                     &compactions,
                     &bits.promise_resolving_functions,
+                    &remembered_sets.promise_resolving_functions,
                 );
             });
         }
         if !promises.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(promises, &compactions, &bits.promises);
+                sweep_heap_vector_values(
+                    promises,
+                    &compactions,
+                    &bits.promises,
+                    &remembered_sets.promises,
+                );
             });
         }
         if !proxys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(proxys, &compactions, &bits.proxys);
+                sweep_heap_vector_values(
+                    proxys,
+                    &compactions,
+                    &bits.proxys,
+                    &remembered_sets.proxys,
+                );
             });
         }
         if !realms.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(realms, &compactions, &bits.realms);
+                sweep_heap_vector_values(
+                    realms,
+                    &compactions,
+                    &bits.realms,
+                    &remembered_sets.realms,
+                );
             });
         }
         #[cfg(feature = "regexp")]
         if !regexps.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(regexps, &compactions, &bits.regexps);
+                sweep_heap_vector_values(
+                    regexps,
+                    &compactions,
+                    &bits.regexps,
+                    &remembered_sets.regexps,
+                );
             });
         }
         if !scripts.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(scripts, &compactions, &bits.scripts);
+                sweep_heap_vector_values(
+                    scripts,
+                    &compactions,
+                    &bits.scripts,
+                    &remembered_sets.scripts,
+                );
             });
         }
         #[cfg(feature = "set")]
         if !sets.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(sets, &compactions, &bits.sets);
+                sweep_heap_vector_values(sets, &compactions, &bits.sets, &remembered_sets.sets);
             });
         }
         #[cfg(feature = "set")]
         if !set_iterators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(set_iterators, &compactions, &bits.set_iterators);
+                sweep_heap_vector_values(
+                    set_iterators,
+                    &compactions,
+                    &bits.set_iterators,
+                    &remembered_sets.set_iterators,
+                );
             });
         }
         #[cfg(feature = "shared-array-buffer")]
@@ -1474,29 +2022,54 @@ fn sweep(
                     shared_array_buffers,
                     &compactions,
                     &bits.shared_array_buffers,
+                    &remembered_sets.shared_array_buffers,
                 );
             });
         }
         if !source_codes.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(source_codes, &compactions, &bits.source_codes);
+                sweep_heap_vector_values(
+                    source_codes,
+                    &compactions,
+                    &bits.source_codes,
+                    &remembered_sets.source_codes,
+                );
             });
         }
         if !strings.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(strings, &compactions, &bits.strings);
-                sweep_lookup_table(string_lookup_table, &compactions, &bits.strings);
+                // Note: The lookup table is swept first, as the hashes of young
+                // Strings are computed from their data before it moves.
+                sweep_lookup_table(string_lookup_table, &compactions, &bits.strings, |index| {
+                    string_hasher.hash_one(strings[index].as_ref().unwrap().as_wtf8())
+                });
+                sweep_heap_vector_values(
+                    strings,
+                    &compactions,
+                    &bits.strings,
+                    &remembered_sets.strings,
+                );
             });
         }
         if !symbols.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(symbols, &compactions, &bits.symbols);
+                sweep_heap_vector_values(
+                    symbols,
+                    &compactions,
+                    &bits.symbols,
+                    &remembered_sets.symbols,
+                );
             });
         }
         #[cfg(feature = "array-buffer")]
         if !typed_arrays.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(typed_arrays, &compactions, &bits.typed_arrays);
+                sweep_heap_vector_values(
+                    typed_arrays,
+                    &compactions,
+                    &bits.typed_arrays,
+                    &remembered_sets.typed_arrays,
+                );
                 sweep_side_table_values(
                     typed_array_byte_lengths,
                     &compactions.typed_arrays,
@@ -1517,19 +2090,56 @@ fn sweep(
         #[cfg(feature = "weak-refs")]
         if !weak_maps.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(weak_maps, &compactions, &bits.weak_maps);
+                sweep_heap_vector_values(
+                    weak_maps,
+                    &compactions,
+                    &bits.weak_maps,
+                    &remembered_sets.weak_maps,
+                );
             });
         }
         #[cfg(feature = "weak-refs")]
         if !weak_refs.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(weak_refs, &compactions, &bits.weak_refs);
+                sweep_heap_vector_values(
+                    weak_refs,
+                    &compactions,
+                    &bits.weak_refs,
+                    &remembered_sets.weak_refs,
+                );
             });
         }
         #[cfg(feature = "weak-refs")]
         if !weak_sets.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(weak_sets, &compactions, &bits.weak_sets);
+                sweep_heap_vector_values(
+                    weak_sets,
+                    &compactions,
+                    &bits.weak_sets,
+                    &remembered_sets.weak_sets,
+                );
+            });
+        }
+        if !realm_roots.is_empty() {
+            s.spawn(|| {
+                realm_roots
+                    .iter_mut()
+                    .for_each(|realm| realm.sweep_values(&compactions));
+            });
+        }
+        if let Some(debugger) = debugger {
+            s.spawn(|| {
+                debugger.sweep_values(&compactions);
+            });
+        }
+        if let Some(profiler) = profiler {
+            s.spawn(|| {
+                profiler.sweep_values(&compactions);
+            });
+        }
+        if let Some(coverage) = coverage {
+            s.spawn(|| {
+                coverage.sweep_values(&compactions);
             });
         }
         if !execution_context_stack.is_empty() {
@@ -1569,7 +2179,7 @@ fn test_heap_gc() {
     let obj = HeapRootData::Object(agent.heap.create_null_object(&[]));
     println!("Object: {:#?}", obj);
     agent.heap.globals.borrow_mut().push(Some(obj));
-    heap_gc(&mut agent, &mut [], gc.reborrow());
+    heap_gc(&mut agent, gc.reborrow());
     println!("Objects: {:#?}", agent.heap.objects);
     assert_eq!(agent.heap.objects.len(), 1);
     assert_eq!(agent.heap.elements.e2pow4.values.len(), 0);
@@ -1579,3 +2189,88 @@ fn test_heap_gc() {
         agent.heap.globals.borrow().last().unwrap()
     );
 }
+
+#[test]
+fn test_minor_heap_gc() {
+    use crate::engine::context::GcScope;
+    use crate::{
+        ecmascript::{
+            execution::{agent::Options, DefaultHostHooks},
+            types::{IntoObject, Object},
+        },
+        engine::rootable::HeapRootData,
+    };
+
+    let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+
+    let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+    let mut gc = GcScope::new(&mut gc, &mut scope);
+    let old = agent.heap.create_null_object(&[]);
+    agent
+        .heap
+        .globals
+        .borrow_mut()
+        .push(Some(HeapRootData::Object(old)));
+    heap_gc(&mut agent, gc.reborrow());
+    assert_eq!(agent.heap.objects.len(), 1);
+
+    let _garbage = agent.heap.create_null_object(&[]);
+    // The young object is only reachable through the old object.
+    let young = agent.heap.create_null_object(&[]);
+    agent[old].prototype = Some(young.into_object());
+    assert_eq!(agent.heap.objects.len(), 3);
+    minor_heap_gc(&mut agent, gc.reborrow());
+    assert_eq!(agent.heap.objects.len(), 2);
+    let Some(Object::Object(young)) = agent[old].prototype else {
+        panic!("Prototype of old object was lost");
+    };
+    assert_eq!(young.get_index(), 1);
+    assert!(agent[young].prototype.is_none());
+}
+
+#[test]
+fn test_incremental_heap_gc() {
+    use crate::engine::context::GcScope;
+    use crate::{
+        ecmascript::{
+            execution::{agent::Options, DefaultHostHooks},
+            types::{IntoObject, Object},
+        },
+        engine::rootable::HeapRootData,
+    };
+
+    let mut agent = Agent::new(Options::default(), &DefaultHostHooks);
+
+    let (mut gc, mut scope) = unsafe { GcScope::create_root() };
+    let mut gc = GcScope::new(&mut gc, &mut scope);
+    let old = agent.heap.create_null_object(&[]);
+    agent
+        .heap
+        .globals
+        .borrow_mut()
+        .push(Some(HeapRootData::Object(old)));
+    heap_gc(&mut agent, gc.reborrow());
+
+    start_incremental_marking(&mut agent);
+    let mut marking = agent.heap.incremental_marking.take().unwrap();
+    assert!(trace(
+        &agent.heap,
+        &mut marking.bits,
+        &mut marking.queues,
+        usize::MAX
+    ));
+    agent.heap.incremental_marking = Some(marking);
+
+    // The old object is written to after it has been traced.
+    let _garbage = agent.heap.create_null_object(&[]);
+    let young = agent.heap.create_null_object(&[]);
+    agent[old].prototype = Some(young.into_object());
+    assert_eq!(agent.heap.objects.len(), 3);
+    incremental_marking_step(&mut agent, gc.reborrow());
+    assert!(agent.heap.incremental_marking.is_none());
+    assert_eq!(agent.heap.objects.len(), 2);
+    let Some(Object::Object(young)) = agent[old].prototype else {
+        panic!("Prototype of old object was lost");
+    };
+    assert_eq!(young.get_index(), 1);
+}
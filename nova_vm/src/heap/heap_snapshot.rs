@@ -0,0 +1,416 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Heap snapshots in the V8 `.heapsnapshot` format.
+//!
+//! A snapshot is built by tracing the heap from its roots exactly like the
+//! marking phase of the garbage collector does: each reachable heap data
+//! entry becomes a node, and each value it pushes into the [`WorkQueues`]
+//! becomes an edge. The result can be loaded into the Memory tab of Chrome
+//! DevTools to find out what keeps heap data alive.
+
+use core::mem::size_of_val;
+use std::io::{self, Write};
+
+use ahash::AHashMap;
+
+use super::{
+    element_array::ElementDescriptor,
+    heap_bits::{mark_array_with_u32_length, mark_descriptors, WorkQueues},
+    heap_gc::mark_roots,
+    indexes::ElementIndex,
+    Heap, HeapMarkAndSweep,
+};
+use crate::ecmascript::{execution::Agent, types::Value};
+
+/// Node types of the snapshot, in the order of the `node_types` meta field.
+const NODE_TYPES: &[&str] = &[
+    "hidden",
+    "array",
+    "string",
+    "object",
+    "code",
+    "closure",
+    "regexp",
+    "number",
+    "native",
+    "synthetic",
+    "concatenated string",
+    "sliced string",
+    "symbol",
+    "bigint",
+    "object shape",
+];
+const HIDDEN: usize = 0;
+const ARRAY: usize = 1;
+const STRING: usize = 2;
+const OBJECT: usize = 3;
+const CODE: usize = 4;
+const CLOSURE: usize = 5;
+#[cfg(feature = "regexp")]
+const REGEXP: usize = 6;
+const NUMBER: usize = 7;
+const NATIVE: usize = 8;
+const SYNTHETIC: usize = 9;
+const SYMBOL: usize = 12;
+const BIGINT: usize = 13;
+const OBJECT_SHAPE: usize = 14;
+
+/// Number of fields of a node in the `nodes` array.
+const NODE_FIELD_COUNT: u32 = 7;
+/// The `element` edge type.
+const ELEMENT_EDGE: u32 = 1;
+
+/// Maximum number of bytes of a string's contents used as its node name.
+const MAX_STRING_NAME_LENGTH: usize = 256;
+
+macro_rules! heap_data_kinds {
+    ($($(#[$attr:meta])? $kind:ident: $queue:ident in $($vec:ident).+ by $index:ident as $node_type:ident $name:literal;)*) => {
+        /// Type of a heap data entry in the snapshot.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum Kind {
+            /// The synthetic root node of the snapshot.
+            Root,
+            $($(#[$attr])? $kind,)*
+            E2pow4,
+            E2pow6,
+            E2pow8,
+            E2pow10,
+            E2pow12,
+            E2pow16,
+            E2pow24,
+            E2pow32,
+        }
+
+        impl Kind {
+            /// Returns the snapshot node type and default node name of the
+            /// kind.
+            fn node_type_and_name(self) -> (usize, &'static str) {
+                match self {
+                    Kind::Root => (SYNTHETIC, "(GC roots)"),
+                    $($(#[$attr])? Kind::$kind => ($node_type, $name),)*
+                    _ => (ARRAY, "(object elements)"),
+                }
+            }
+        }
+
+        /// Moves all heap data in the work queues into the list of nodes.
+        fn drain_queues(queues: &mut WorkQueues, nodes: &mut Vec<Node>) {
+            $(
+                $(#[$attr])?
+                nodes.extend(queues.$queue.drain(..).map(|item| Node {
+                    kind: Kind::$kind,
+                    index: item.$index() as u32,
+                    len: 0,
+                }));
+            )*
+            for (kind, queue) in [
+                (Kind::E2pow4, &mut queues.e_2_4),
+                (Kind::E2pow6, &mut queues.e_2_6),
+                (Kind::E2pow8, &mut queues.e_2_8),
+                (Kind::E2pow10, &mut queues.e_2_10),
+                (Kind::E2pow12, &mut queues.e_2_12),
+                (Kind::E2pow16, &mut queues.e_2_16),
+                (Kind::E2pow24, &mut queues.e_2_24),
+                (Kind::E2pow32, &mut queues.e_2_32),
+            ] {
+                nodes.extend(queue.drain(..).map(|(index, len)| Node {
+                    kind,
+                    index: index.into_index() as u32,
+                    len,
+                }));
+            }
+        }
+
+        impl Node {
+            /// Pushes the heap data referred to by the node into the work
+            /// queues.
+            fn mark_values(self, heap: &Heap, queues: &mut WorkQueues) {
+                let index = self.index as usize;
+                let elements = &heap.elements;
+                match self.kind {
+                    Kind::Root => unreachable!(),
+                    $($(#[$attr])? Kind::$kind => heap.$($vec).+.get(index).mark_values(queues),)*
+                    Kind::E2pow4 => mark_elements(&elements.e2pow4.values, &elements.e2pow4.descriptors, self, queues),
+                    Kind::E2pow6 => mark_elements(&elements.e2pow6.values, &elements.e2pow6.descriptors, self, queues),
+                    Kind::E2pow8 => mark_elements(&elements.e2pow8.values, &elements.e2pow8.descriptors, self, queues),
+                    Kind::E2pow10 => mark_elements(&elements.e2pow10.values, &elements.e2pow10.descriptors, self, queues),
+                    Kind::E2pow12 => mark_elements(&elements.e2pow12.values, &elements.e2pow12.descriptors, self, queues),
+                    Kind::E2pow16 => mark_elements(&elements.e2pow16.values, &elements.e2pow16.descriptors, self, queues),
+                    Kind::E2pow24 => mark_elements(&elements.e2pow24.values, &elements.e2pow24.descriptors, self, queues),
+                    Kind::E2pow32 => mark_elements(&elements.e2pow32.values, &elements.e2pow32.descriptors, self, queues),
+                }
+            }
+
+            /// Returns the estimated number of bytes used by the heap data
+            /// referred to by the node.
+            fn self_size(self, heap: &Heap) -> usize {
+                let index = self.index as usize;
+                let elements = &heap.elements;
+                let size = match self.kind {
+                    Kind::Root => 0,
+                    $($(#[$attr])? Kind::$kind => size_of_val(&heap.$($vec).+[index]),)*
+                    Kind::E2pow4 => size_of_val(&elements.e2pow4.values[index]),
+                    Kind::E2pow6 => size_of_val(&elements.e2pow6.values[index]),
+                    Kind::E2pow8 => size_of_val(&elements.e2pow8.values[index]),
+                    Kind::E2pow10 => size_of_val(&elements.e2pow10.values[index]),
+                    Kind::E2pow12 => size_of_val(&elements.e2pow12.values[index]),
+                    Kind::E2pow16 => size_of_val(&elements.e2pow16.values[index]),
+                    Kind::E2pow24 => size_of_val(&elements.e2pow24.values[index]),
+                    Kind::E2pow32 => size_of_val(&elements.e2pow32.values[index]),
+                };
+                if self.kind == Kind::Strings {
+                    size + heap.strings[index].as_ref().map_or(0, |string| string.len())
+                } else {
+                    size
+                }
+            }
+        }
+    };
+}
+
+heap_data_kinds! {
+    #[cfg(feature = "array-buffer")]
+    ArrayBuffers: array_buffers in array_buffers by get_index as OBJECT "ArrayBuffer";
+    Arrays: arrays in arrays by get_index as OBJECT "Array";
+    ArrayIterators: array_iterators in array_iterators by get_index as OBJECT "Array Iterator";
+    AsyncGenerators: async_generators in async_generators by get_index as OBJECT "AsyncGenerator";
+    ArrayFromAsyncReactions: array_from_async_reactions in array_from_async_reactions by into_index as HIDDEN "(Array.fromAsync reaction)";
+    AwaitReactions: await_reactions in await_reactions by into_index as HIDDEN "(await reaction)";
+    Bigints: bigints in bigints by get_index as BIGINT "bigint";
+    BoundFunctions: bound_functions in bound_functions by get_index as CLOSURE "(bound function)";
+    BuiltinConstructors: builtin_constructors in builtin_constructors by get_index as CLOSURE "(default constructor)";
+    BuiltinFunctions: builtin_functions in builtin_functions by get_index as CLOSURE "(builtin function)";
+    #[cfg(feature = "array-buffer")]
+    DataViews: data_views in data_views by get_index as OBJECT "DataView";
+    #[cfg(feature = "date")]
+    Dates: dates in dates by get_index as OBJECT "Date";
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    DisposableStacks: disposable_stacks in disposable_stacks by get_index as OBJECT "DisposableStack";
+    DeclarativeEnvironments: declarative_environments in environments.declarative by into_index as HIDDEN "(declarative environment)";
+    EcmascriptFunctions: ecmascript_functions in ecmascript_functions by get_index as CLOSURE "Function";
+    EmbedderObjects: embedder_objects in embedder_objects by get_index as NATIVE "(embedder object)";
+    Errors: errors in errors by get_index as OBJECT "Error";
+    Executables: executables in executables by get_index as CODE "(bytecode)";
+    FinalizationRegistrys: finalization_registrys in finalization_registrys by get_index as OBJECT "FinalizationRegistry";
+    FunctionEnvironments: function_environments in environments.function by into_index as HIDDEN "(function environment)";
+    Generators: generators in generators by get_index as OBJECT "Generator";
+    GlobalEnvironments: global_environments in environments.global by into_index as HIDDEN "(global environment)";
+    Maps: maps in maps by get_index as OBJECT "Map";
+    MapIterators: map_iterators in map_iterators by get_index as OBJECT "Map Iterator";
+    MappedArguments: mapped_arguments in mapped_arguments by get_index as OBJECT "Arguments";
+    Modules: modules in modules by get_index as HIDDEN "(module)";
+    Numbers: numbers in numbers by get_index as NUMBER "heap number";
+    ObjectEnvironments: object_environments in environments.object by into_index as HIDDEN "(object environment)";
+    Objects: objects in objects by get_index as OBJECT "Object";
+    ObjectShapes: object_shapes in object_shapes by get_index as OBJECT_SHAPE "(object shape)";
+    PrimitiveObjects: primitive_objects in primitive_objects by get_index as OBJECT "(primitive object)";
+    Promises: promises in promises by get_index as OBJECT "Promise";
+    PromiseReactionRecords: promise_reaction_records in promise_reaction_records by get_index as HIDDEN "(promise reaction)";
+    PromiseResolvingFunctions: promise_resolving_functions in promise_resolving_functions by get_index as CLOSURE "(promise resolving function)";
+    Proxys: proxys in proxys by get_index as OBJECT "Proxy";
+    Realms: realms in realms by into_index as HIDDEN "(realm)";
+    #[cfg(feature = "regexp")]
+    Regexps: regexps in regexps by get_index as REGEXP "RegExp";
+    Scripts: scripts in scripts by into_index as HIDDEN "(script)";
+    #[cfg(feature = "set")]
+    Sets: sets in sets by get_index as OBJECT "Set";
+    #[cfg(feature = "set")]
+    SetIterators: set_iterators in set_iterators by get_index as OBJECT "Set Iterator";
+    #[cfg(feature = "shared-array-buffer")]
+    SharedArrayBuffers: shared_array_buffers in shared_array_buffers by get_index as OBJECT "SharedArrayBuffer";
+    SourceCodes: source_codes in source_codes by get_index as CODE "(source code)";
+    Strings: strings in strings by get_index as STRING "(string)";
+    Symbols: symbols in symbols by get_index as SYMBOL "symbol";
+    #[cfg(feature = "array-buffer")]
+    TypedArrays: typed_arrays in typed_arrays by into_index as OBJECT "TypedArray";
+    #[cfg(feature = "weak-refs")]
+    WeakMaps: weak_maps in weak_maps by get_index as OBJECT "WeakMap";
+    #[cfg(feature = "weak-refs")]
+    WeakRefs: weak_refs in weak_refs by get_index as OBJECT "WeakRef";
+    #[cfg(feature = "weak-refs")]
+    WeakSets: weak_sets in weak_sets by get_index as OBJECT "WeakSet";
+}
+
+/// A heap data entry found while tracing the heap.
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    kind: Kind,
+    index: u32,
+    /// Length of an element array; unused for other kinds.
+    len: u32,
+}
+
+fn mark_elements<const N: usize>(
+    values: &[Option<[Option<Value>; N]>],
+    descriptors: &AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    node: Node,
+    queues: &mut WorkQueues,
+) {
+    if let Some(descriptors) = descriptors.get(&ElementIndex::from_u32_index(node.index)) {
+        mark_descriptors(descriptors, queues);
+    }
+    if let Some(array) = values.get(node.index as usize) {
+        mark_array_with_u32_length(array, queues, node.len);
+    }
+}
+
+/// Deduplicated table of the strings used in a snapshot.
+#[derive(Default)]
+struct StringTable {
+    strings: Vec<String>,
+    lookup: AHashMap<String, u32>,
+}
+
+impl StringTable {
+    fn add(&mut self, string: &str) -> u32 {
+        if let Some(&index) = self.lookup.get(string) {
+            return index;
+        }
+        let index = self.strings.len() as u32;
+        self.strings.push(string.to_owned());
+        self.lookup.insert(string.to_owned(), index);
+        index
+    }
+}
+
+/// Writes a JSON string literal.
+pub(crate) fn write_json_string(writer: &mut impl Write, string: &str) -> io::Result<()> {
+    writer.write_all(b"\"")?;
+    for char in string.chars() {
+        match char {
+            '"' => writer.write_all(b"\\\"")?,
+            '\\' => writer.write_all(b"\\\\")?,
+            '\n' => writer.write_all(b"\\n")?,
+            '\r' => writer.write_all(b"\\r")?,
+            '\t' => writer.write_all(b"\\t")?,
+            char if (char as u32) < 0x20 => write!(writer, "\\u{:04x}", char as u32)?,
+            char => write!(writer, "{}", char)?,
+        }
+    }
+    writer.write_all(b"\"")
+}
+
+/// Writes a snapshot of all heap data reachable from the roots of the agent
+/// in the V8 `.heapsnapshot` JSON format.
+pub(crate) fn write_heap_snapshot(agent: &Agent, writer: &mut impl Write) -> io::Result<()> {
+    let heap = &agent.heap;
+    let mut queues = WorkQueues::new(heap);
+    let mut ordinals: AHashMap<(Kind, u32), u32> = AHashMap::default();
+    let mut nodes = vec![Node {
+        kind: Kind::Root,
+        index: 0,
+        len: 0,
+    }];
+    // Outgoing edges of each node, as node ordinals.
+    let mut edges: Vec<Vec<u32>> = Vec::new();
+    let mut children = Vec::new();
+
+    mark_roots(agent, &mut queues);
+    let mut ordinal = 0;
+    loop {
+        drain_queues(&mut queues, &mut children);
+        let mut node_edges = Vec::with_capacity(children.len());
+        for child in children.drain(..) {
+            let next_ordinal = nodes.len() as u32;
+            let child_ordinal = *ordinals
+                .entry((child.kind, child.index))
+                .or_insert_with(|| {
+                    nodes.push(child);
+                    next_ordinal
+                });
+            node_edges.push(child_ordinal);
+        }
+        edges.push(node_edges);
+        ordinal += 1;
+        let Some(node) = nodes.get(ordinal) else {
+            break;
+        };
+        node.mark_values(heap, &mut queues);
+    }
+
+    let mut strings = StringTable::default();
+    let edge_count = edges.iter().map(|edges| edges.len()).sum::<usize>();
+    writer.write_all(b"{\"snapshot\":{\"meta\":{")?;
+    writer.write_all(b"\"node_fields\":[\"type\",\"name\",\"id\",\"self_size\",\"edge_count\",\"trace_node_id\",\"detachedness\"],")?;
+    writer.write_all(b"\"node_types\":[[")?;
+    for (i, node_type) in NODE_TYPES.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        write_json_string(writer, node_type)?;
+    }
+    writer.write_all(b"],\"string\",\"number\",\"number\",\"number\",\"number\",\"number\"],")?;
+    writer.write_all(b"\"edge_fields\":[\"type\",\"name_or_index\",\"to_node\"],")?;
+    writer.write_all(b"\"edge_types\":[[\"context\",\"element\",\"property\",\"internal\",\"hidden\",\"shortcut\",\"weak\"],\"string_or_number\",\"node\"],")?;
+    writer.write_all(b"\"trace_function_info_fields\":[\"function_id\",\"name\",\"script_name\",\"script_id\",\"line\",\"column\"],")?;
+    writer.write_all(
+        b"\"trace_node_fields\":[\"id\",\"function_info_index\",\"count\",\"size\",\"children\"],",
+    )?;
+    writer.write_all(b"\"sample_fields\":[\"timestamp_us\",\"last_assigned_id\"],")?;
+    writer
+        .write_all(b"\"location_fields\":[\"object_index\",\"script_id\",\"line\",\"column\"]},")?;
+    write!(
+        writer,
+        "\"node_count\":{},\"edge_count\":{},\"trace_function_count\":0}},",
+        nodes.len(),
+        edge_count
+    )?;
+
+    writer.write_all(b"\n\"nodes\":[")?;
+    for (ordinal, (node, node_edges)) in nodes.iter().zip(edges.iter()).enumerate() {
+        let (node_type, name) = node.kind.node_type_and_name();
+        let name = match node.kind {
+            Kind::Strings => match &heap.strings[node.index as usize] {
+                Some(string) => {
+                    let contents = string.as_wtf8().to_string_lossy();
+                    let mut end = contents.len().min(MAX_STRING_NAME_LENGTH);
+                    while !contents.is_char_boundary(end) {
+                        end -= 1;
+                    }
+                    strings.add(&contents[..end])
+                }
+                None => strings.add(name),
+            },
+            _ => strings.add(name),
+        };
+        if ordinal > 0 {
+            writer.write_all(b",\n")?;
+        }
+        write!(
+            writer,
+            "{},{},{},{},{},0,0",
+            node_type,
+            name,
+            ordinal * 2 + 1,
+            node.self_size(heap),
+            node_edges.len()
+        )?;
+    }
+    writer.write_all(b"],\n\"edges\":[")?;
+    let mut first = true;
+    for node_edges in edges.iter() {
+        for (index, to_node) in node_edges.iter().enumerate() {
+            if !first {
+                writer.write_all(b",\n")?;
+            }
+            first = false;
+            write!(
+                writer,
+                "{},{},{}",
+                ELEMENT_EDGE,
+                index,
+                to_node * NODE_FIELD_COUNT
+            )?;
+        }
+    }
+    writer.write_all(b"],\n\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\n\"strings\":[")?;
+    for (i, string) in strings.strings.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",\n")?;
+        }
+        write_json_string(writer, string)?;
+    }
+    writer.write_all(b"]}\n")
+}
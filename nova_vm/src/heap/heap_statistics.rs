@@ -0,0 +1,242 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Memory usage statistics of the heap.
+
+use core::mem::{size_of, size_of_val};
+
+use ahash::AHashMap;
+
+use super::{element_array::ElementDescriptor, indexes::ElementIndex, Heap};
+use crate::ecmascript::types::Value;
+
+/// Number of items of a single type of heap data and the memory they use.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct HeapDataStatistics {
+    /// Number of heap slots of the type. This includes slots reserved for
+    /// intrinsics that have not been created.
+    pub count: usize,
+    /// Estimated number of bytes used by the heap data of the type.
+    pub bytes: usize,
+}
+
+/// Memory usage of a heap by type of heap data.
+///
+/// Each type of heap data is named after the heap vector holding it, eg.
+/// `objects` or `strings`. Element arrays are named by their capacity:
+/// `elements_2pow4` holds element arrays of up to 16 elements.
+///
+/// Note: Byte sizes include the heap vectors themselves, string contents,
+/// ArrayBuffer data, element descriptors, bytecode and parsed source code.
+/// Other memory owned by individual heap data entries, such as Map entries,
+/// is not counted.
+#[derive(Debug, Default, Clone)]
+pub struct HeapStatistics {
+    types: Vec<(&'static str, HeapDataStatistics)>,
+}
+
+impl HeapStatistics {
+    /// Returns the name and statistics of each type of heap data.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, HeapDataStatistics)> + '_ {
+        self.types.iter().copied()
+    }
+
+    /// Returns the statistics of the named type of heap data.
+    pub fn get(&self, name: &str) -> Option<HeapDataStatistics> {
+        self.types
+            .iter()
+            .find(|(type_name, _)| *type_name == name)
+            .map(|(_, statistics)| *statistics)
+    }
+
+    /// Returns the total estimated number of bytes used by the heap.
+    pub fn total_bytes(&self) -> usize {
+        self.types
+            .iter()
+            .map(|(_, statistics)| statistics.bytes)
+            .sum()
+    }
+
+    fn push(&mut self, name: &'static str, count: usize, bytes: usize) {
+        self.types.push((name, HeapDataStatistics { count, bytes }));
+    }
+
+    fn push_vec<T>(&mut self, name: &'static str, vec: &[T]) {
+        self.push(name, vec.len(), size_of_val(vec));
+    }
+
+    fn push_elements<const N: usize>(
+        &mut self,
+        name: &'static str,
+        values: &[Option<[Option<Value>; N]>],
+        descriptors: &AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    ) {
+        let descriptor_count = descriptors
+            .values()
+            .map(|descriptors| descriptors.len())
+            .sum::<usize>();
+        self.push(
+            name,
+            values.len(),
+            size_of_val(values) + descriptor_count * size_of::<(u32, ElementDescriptor)>(),
+        );
+    }
+}
+
+impl Heap {
+    /// Returns the memory usage statistics of the heap.
+    pub(crate) fn statistics(&self) -> HeapStatistics {
+        let mut statistics = HeapStatistics::default();
+        #[cfg(feature = "array-buffer")]
+        statistics.push(
+            "array_buffers",
+            self.array_buffers.len(),
+            size_of_val(self.array_buffers.as_slice())
+                + self
+                    .array_buffers
+                    .iter()
+                    .flatten()
+                    .map(|array_buffer| array_buffer.byte_length())
+                    .sum::<usize>(),
+        );
+        statistics.push_vec("arrays", &self.arrays);
+        statistics.push_vec("array_iterators", &self.array_iterators);
+        statistics.push_vec("async_generators", &self.async_generators);
+        statistics.push_vec(
+            "array_from_async_reactions",
+            &self.array_from_async_reactions,
+        );
+        statistics.push_vec("await_reactions", &self.await_reactions);
+        statistics.push_vec("bigints", &self.bigints);
+        statistics.push_vec("bound_functions", &self.bound_functions);
+        statistics.push_vec("builtin_constructors", &self.builtin_constructors);
+        statistics.push_vec("builtin_functions", &self.builtin_functions);
+        #[cfg(feature = "array-buffer")]
+        statistics.push_vec("data_views", &self.data_views);
+        #[cfg(feature = "date")]
+        statistics.push_vec("dates", &self.dates);
+        #[cfg(feature = "proposal-explicit-resource-management")]
+        statistics.push_vec("disposable_stacks", &self.disposable_stacks);
+        statistics.push_vec("ecmascript_functions", &self.ecmascript_functions);
+        statistics.push_elements(
+            "elements_2pow4",
+            &self.elements.e2pow4.values,
+            &self.elements.e2pow4.descriptors,
+        );
+        statistics.push_elements(
+            "elements_2pow6",
+            &self.elements.e2pow6.values,
+            &self.elements.e2pow6.descriptors,
+        );
+        statistics.push_elements(
+            "elements_2pow8",
+            &self.elements.e2pow8.values,
+            &self.elements.e2pow8.descriptors,
+        );
+        statistics.push_elements(
+            "elements_2pow10",
+            &self.elements.e2pow10.values,
+            &self.elements.e2pow10.descriptors,
+        );
+        statistics.push_elements(
+            "elements_2pow12",
+            &self.elements.e2pow12.values,
+            &self.elements.e2pow12.descriptors,
+        );
+        statistics.push_elements(
+            "elements_2pow16",
+            &self.elements.e2pow16.values,
+            &self.elements.e2pow16.descriptors,
+        );
+        statistics.push_elements(
+            "elements_2pow24",
+            &self.elements.e2pow24.values,
+            &self.elements.e2pow24.descriptors,
+        );
+        statistics.push_elements(
+            "elements_2pow32",
+            &self.elements.e2pow32.values,
+            &self.elements.e2pow32.descriptors,
+        );
+        statistics.push_vec("embedder_objects", &self.embedder_objects);
+        statistics.push_vec("declarative_environments", &self.environments.declarative);
+        statistics.push_vec("function_environments", &self.environments.function);
+        statistics.push_vec("global_environments", &self.environments.global);
+        statistics.push_vec("object_environments", &self.environments.object);
+        statistics.push_vec("private_environments", &self.environments.private);
+        statistics.push_vec("errors", &self.errors);
+        statistics.push(
+            "executables",
+            self.executables.len(),
+            size_of_val(self.executables.as_slice())
+                + self
+                    .executables
+                    .iter()
+                    .map(|executable| {
+                        size_of_val(executable.instructions.as_ref())
+                            + size_of_val(executable.constants.as_ref())
+                    })
+                    .sum::<usize>(),
+        );
+        statistics.push_vec("finalization_registrys", &self.finalization_registrys);
+        statistics.push_vec("generators", &self.generators);
+        statistics.push_vec("maps", &self.maps);
+        statistics.push_vec("map_iterators", &self.map_iterators);
+        statistics.push_vec("mapped_arguments", &self.mapped_arguments);
+        statistics.push_vec("modules", &self.modules);
+        statistics.push_vec("numbers", &self.numbers);
+        statistics.push_vec("objects", &self.objects);
+        statistics.push_vec("object_shapes", &self.object_shapes);
+        statistics.push_vec("primitive_objects", &self.primitive_objects);
+        statistics.push_vec("promise_reaction_records", &self.promise_reaction_records);
+        statistics.push_vec(
+            "promise_resolving_functions",
+            &self.promise_resolving_functions,
+        );
+        statistics.push_vec("promises", &self.promises);
+        statistics.push_vec("proxys", &self.proxys);
+        statistics.push_vec("realms", &self.realms);
+        #[cfg(feature = "regexp")]
+        statistics.push_vec("regexps", &self.regexps);
+        statistics.push_vec("scripts", &self.scripts);
+        #[cfg(feature = "set")]
+        statistics.push_vec("sets", &self.sets);
+        #[cfg(feature = "set")]
+        statistics.push_vec("set_iterators", &self.set_iterators);
+        #[cfg(feature = "shared-array-buffer")]
+        statistics.push_vec("shared_array_buffers", &self.shared_array_buffers);
+        statistics.push(
+            "source_codes",
+            self.source_codes.len(),
+            size_of_val(self.source_codes.as_slice())
+                + self
+                    .source_codes
+                    .iter()
+                    .flatten()
+                    .map(|source_code| source_code.allocated_bytes())
+                    .sum::<usize>(),
+        );
+        statistics.push(
+            "strings",
+            self.strings.len(),
+            size_of_val(self.strings.as_slice())
+                + self
+                    .strings
+                    .iter()
+                    .flatten()
+                    .map(|string| string.len())
+                    .sum::<usize>(),
+        );
+        statistics.push_vec("symbols", &self.symbols);
+        #[cfg(feature = "array-buffer")]
+        statistics.push_vec("typed_arrays", &self.typed_arrays);
+        #[cfg(feature = "weak-refs")]
+        statistics.push_vec("weak_maps", &self.weak_maps);
+        #[cfg(feature = "weak-refs")]
+        statistics.push_vec("weak_refs", &self.weak_refs);
+        #[cfg(feature = "weak-refs")]
+        statistics.push_vec("weak_sets", &self.weak_sets);
+        statistics
+    }
+}
@@ -4,6 +4,8 @@
 
 #[cfg(feature = "date")]
 use crate::ecmascript::builtins::date::data::DateHeapData;
+#[cfg(feature = "proposal-explicit-resource-management")]
+use crate::ecmascript::builtins::disposable_stack::data::DisposableStackHeapData;
 #[cfg(feature = "regexp")]
 use crate::ecmascript::builtins::regexp::RegExpHeapData;
 #[cfg(feature = "shared-array-buffer")]
@@ -25,6 +27,7 @@ use crate::ecmascript::builtins::{
 use crate::{
     ecmascript::{
         builtins::{
+            arguments::data::MappedArgumentsHeapData,
             async_generator_objects::AsyncGeneratorHeapData,
             control_abstraction_objects::generator_objects::GeneratorHeapData,
             embedder_object::data::EmbedderObjectHeapData, error::ErrorHeapData,
@@ -200,6 +203,8 @@ pub type BuiltinConstructorIndex<'a> = BaseIndex<'a, BuiltinConstructorHeapData>
 pub type DataViewIndex<'a> = BaseIndex<'a, DataViewHeapData>;
 #[cfg(feature = "date")]
 pub type DateIndex<'a> = BaseIndex<'a, DateHeapData>;
+#[cfg(feature = "proposal-explicit-resource-management")]
+pub type DisposableStackIndex<'a> = BaseIndex<'a, DisposableStackHeapData>;
 pub type ECMAScriptFunctionIndex<'a> = BaseIndex<'a, ECMAScriptFunctionHeapData>;
 pub type ElementIndex = BaseIndex<'static, [Option<Value>]>;
 pub type EmbedderObjectIndex<'a> = BaseIndex<'a, EmbedderObjectHeapData>;
@@ -208,6 +213,7 @@ pub type FinalizationRegistryIndex<'a> = BaseIndex<'a, FinalizationRegistryHeapD
 pub type GeneratorIndex<'a> = BaseIndex<'a, GeneratorHeapData>;
 pub type MapIndex<'a> = BaseIndex<'a, MapHeapData>;
 pub type MapIteratorIndex<'a> = BaseIndex<'a, MapIteratorHeapData>;
+pub type MappedArgumentsIndex<'a> = BaseIndex<'a, MappedArgumentsHeapData>;
 pub type NumberIndex<'a> = BaseIndex<'a, NumberHeapData>;
 pub type ObjectIndex<'a> = BaseIndex<'a, ObjectHeapData>;
 pub type PrimitiveObjectIndex<'a> = BaseIndex<'a, PrimitiveObjectHeapData>;
@@ -0,0 +1,666 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Generational garbage collection support.
+//!
+//! Each heap vector is split into an old generation, the items that survived
+//! the previous garbage collection, and a young generation of the items
+//! allocated after it. Minor collections only trace and compact the young
+//! generation: Old items are assumed to be live and do not move, and are only
+//! traced if they have been written to since the previous collection as only
+//! those can refer to young items.
+//!
+//! Writes to old items are recorded in remembered sets by the write barrier:
+//! The `IndexMut` implementations handing out mutable access to heap data, and
+//! the [`ElementArrays`](super::element_array::ElementArrays) methods that
+//! write into element arrays, call [`RememberedSet::remember`]. Code that
+//! mutates heap vectors directly must do the same.
+//!
+//! Incremental marking uses the same write barrier: When marking starts, all
+//! heap data is made part of the old generation so that any heap data written
+//! to during marking, possibly after being traced, can be traced again before
+//! the heap is swept.
+
+use ahash::AHashMap;
+
+use super::{
+    element_array::ElementDescriptor,
+    heap_bits::{
+        mark_array_with_u32_length, mark_descriptors, HeapBits, HeapMarkAndSweep, MarkBits,
+        WorkQueues,
+    },
+    indexes::ElementIndex,
+    Heap,
+};
+use crate::ecmascript::types::Value;
+
+/// Remembered set of a single heap vector.
+#[derive(Debug, Default)]
+pub(crate) struct RememberedSet {
+    /// For each item in the old generation, true if it has been written to
+    /// since the previous garbage collection.
+    written: Vec<bool>,
+    /// Indexes of the old items that have been written to.
+    indexes: Vec<u32>,
+}
+
+impl RememberedSet {
+    /// Records a write to the item at the index. Writes to young items need
+    /// not be recorded and are ignored.
+    #[inline]
+    pub(crate) fn remember(&mut self, index: usize) {
+        if let Some(written) = self.written.get_mut(index) {
+            if !*written {
+                *written = true;
+                self.indexes.push(index as u32);
+            }
+        }
+    }
+
+    /// Number of items in the old generation.
+    pub(crate) fn old_len(&self) -> usize {
+        self.written.len()
+    }
+
+    /// Indexes of the old items that have been written to.
+    pub(crate) fn indexes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.indexes.iter().map(|index| *index as usize)
+    }
+
+    /// Makes the first `len` items the old generation and forgets all writes.
+    fn set_old_len(&mut self, len: usize) {
+        // Note: Only the written items and the newly promoted ones are
+        // touched, so that minor collections do not scan the old generation.
+        for index in self.indexes.drain(..) {
+            self.written[index as usize] = false;
+        }
+        self.written.resize(len, false);
+    }
+}
+
+/// Remembered set of an element array vector.
+///
+/// Element arrays do not know their own length, so the set records the
+/// length that each written element array had.
+#[derive(Debug, Default)]
+pub(crate) struct RememberedElements {
+    /// For each element array in the old generation, the largest length it
+    /// has been written to with since the previous garbage collection, or zero
+    /// if it has not been written to.
+    lengths: Vec<u32>,
+    /// Indexes of the old element arrays that have been written to.
+    indexes: Vec<u32>,
+}
+
+impl RememberedElements {
+    /// Records a write to the element array at the index, which has the given
+    /// length. Writes to young element arrays need not be recorded and are
+    /// ignored.
+    #[inline]
+    pub(crate) fn remember(&mut self, index: usize, len: u32) {
+        if let Some(length) = self.lengths.get_mut(index) {
+            if *length == 0 && len > 0 {
+                self.indexes.push(index as u32);
+            }
+            *length = (*length).max(len);
+        }
+    }
+
+    /// Number of element arrays in the old generation.
+    pub(crate) fn old_len(&self) -> usize {
+        self.lengths.len()
+    }
+
+    /// Indexes and lengths of the old element arrays that have been written
+    /// to.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (usize, u32)> + '_ {
+        self.indexes
+            .iter()
+            .map(|index| (*index as usize, self.lengths[*index as usize]))
+    }
+
+    /// Makes the first `len` element arrays the old generation and forgets
+    /// all writes.
+    pub(crate) fn set_old_len(&mut self, len: usize) {
+        for index in self.indexes.drain(..) {
+            self.lengths[index as usize] = 0;
+        }
+        self.lengths.resize(len, 0);
+    }
+}
+
+/// Remembered sets of the heap vectors. Element arrays keep their remembered
+/// sets next to their data in [`ElementArrays`](super::element_array::ElementArrays).
+#[derive(Debug, Default)]
+pub(crate) struct RememberedSets {
+    #[cfg(feature = "array-buffer")]
+    pub(crate) array_buffers: RememberedSet,
+    pub(crate) arrays: RememberedSet,
+    pub(crate) array_iterators: RememberedSet,
+    pub(crate) async_generators: RememberedSet,
+    pub(crate) array_from_async_reactions: RememberedSet,
+    pub(crate) await_reactions: RememberedSet,
+    pub(crate) bigints: RememberedSet,
+    pub(crate) bound_functions: RememberedSet,
+    pub(crate) builtin_constructors: RememberedSet,
+    pub(crate) builtin_functions: RememberedSet,
+    #[cfg(feature = "array-buffer")]
+    pub(crate) data_views: RememberedSet,
+    #[cfg(feature = "date")]
+    pub(crate) dates: RememberedSet,
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    pub(crate) disposable_stacks: RememberedSet,
+    pub(crate) declarative_environments: RememberedSet,
+    pub(crate) ecmascript_functions: RememberedSet,
+    pub(crate) embedder_objects: RememberedSet,
+    pub(crate) errors: RememberedSet,
+    pub(crate) executables: RememberedSet,
+    pub(crate) source_codes: RememberedSet,
+    pub(crate) finalization_registrys: RememberedSet,
+    pub(crate) function_environments: RememberedSet,
+    pub(crate) generators: RememberedSet,
+    pub(crate) global_environments: RememberedSet,
+    pub(crate) maps: RememberedSet,
+    pub(crate) map_iterators: RememberedSet,
+    pub(crate) mapped_arguments: RememberedSet,
+    pub(crate) modules: RememberedSet,
+    pub(crate) numbers: RememberedSet,
+    pub(crate) object_environments: RememberedSet,
+    pub(crate) objects: RememberedSet,
+    pub(crate) object_shapes: RememberedSet,
+    pub(crate) primitive_objects: RememberedSet,
+    pub(crate) promise_reaction_records: RememberedSet,
+    pub(crate) promise_resolving_functions: RememberedSet,
+    pub(crate) promises: RememberedSet,
+    pub(crate) proxys: RememberedSet,
+    pub(crate) realms: RememberedSet,
+    #[cfg(feature = "regexp")]
+    pub(crate) regexps: RememberedSet,
+    pub(crate) scripts: RememberedSet,
+    #[cfg(feature = "set")]
+    pub(crate) sets: RememberedSet,
+    #[cfg(feature = "set")]
+    pub(crate) set_iterators: RememberedSet,
+    #[cfg(feature = "shared-array-buffer")]
+    pub(crate) shared_array_buffers: RememberedSet,
+    pub(crate) strings: RememberedSet,
+    pub(crate) symbols: RememberedSet,
+    #[cfg(feature = "array-buffer")]
+    pub(crate) typed_arrays: RememberedSet,
+    #[cfg(feature = "weak-refs")]
+    pub(crate) weak_maps: RememberedSet,
+    #[cfg(feature = "weak-refs")]
+    pub(crate) weak_refs: RememberedSet,
+    #[cfg(feature = "weak-refs")]
+    pub(crate) weak_sets: RememberedSet,
+}
+
+/// Sets the old generation of each heap vector: With `promote` set, all
+/// current heap data becomes old. Otherwise all heap data becomes young, so
+/// that the next garbage collection traces the entire heap.
+pub(crate) fn set_old_generation(heap: &mut Heap, promote: bool) {
+    let len = |len: usize| if promote { len } else { 0 };
+    let sets = &mut heap.remembered_sets;
+    #[cfg(feature = "array-buffer")]
+    sets.array_buffers
+        .set_old_len(len(heap.array_buffers.len()));
+    sets.arrays.set_old_len(len(heap.arrays.len()));
+    sets.array_iterators
+        .set_old_len(len(heap.array_iterators.len()));
+    sets.async_generators
+        .set_old_len(len(heap.async_generators.len()));
+    sets.array_from_async_reactions
+        .set_old_len(len(heap.array_from_async_reactions.len()));
+    sets.await_reactions
+        .set_old_len(len(heap.await_reactions.len()));
+    sets.bigints.set_old_len(len(heap.bigints.len()));
+    sets.bound_functions
+        .set_old_len(len(heap.bound_functions.len()));
+    sets.builtin_constructors
+        .set_old_len(len(heap.builtin_constructors.len()));
+    sets.builtin_functions
+        .set_old_len(len(heap.builtin_functions.len()));
+    #[cfg(feature = "array-buffer")]
+    sets.data_views.set_old_len(len(heap.data_views.len()));
+    #[cfg(feature = "date")]
+    sets.dates.set_old_len(len(heap.dates.len()));
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    sets.disposable_stacks
+        .set_old_len(len(heap.disposable_stacks.len()));
+    sets.declarative_environments
+        .set_old_len(len(heap.environments.declarative.len()));
+    sets.ecmascript_functions
+        .set_old_len(len(heap.ecmascript_functions.len()));
+    sets.embedder_objects
+        .set_old_len(len(heap.embedder_objects.len()));
+    sets.errors.set_old_len(len(heap.errors.len()));
+    sets.executables.set_old_len(len(heap.executables.len()));
+    sets.source_codes.set_old_len(len(heap.source_codes.len()));
+    sets.finalization_registrys
+        .set_old_len(len(heap.finalization_registrys.len()));
+    sets.function_environments
+        .set_old_len(len(heap.environments.function.len()));
+    sets.generators.set_old_len(len(heap.generators.len()));
+    sets.global_environments
+        .set_old_len(len(heap.environments.global.len()));
+    sets.maps.set_old_len(len(heap.maps.len()));
+    sets.map_iterators
+        .set_old_len(len(heap.map_iterators.len()));
+    sets.mapped_arguments
+        .set_old_len(len(heap.mapped_arguments.len()));
+    sets.modules.set_old_len(len(heap.modules.len()));
+    sets.numbers.set_old_len(len(heap.numbers.len()));
+    sets.object_environments
+        .set_old_len(len(heap.environments.object.len()));
+    sets.objects.set_old_len(len(heap.objects.len()));
+    sets.object_shapes
+        .set_old_len(len(heap.object_shapes.len()));
+    sets.primitive_objects
+        .set_old_len(len(heap.primitive_objects.len()));
+    sets.promise_reaction_records
+        .set_old_len(len(heap.promise_reaction_records.len()));
+    sets.promise_resolving_functions
+        .set_old_len(len(heap.promise_resolving_functions.len()));
+    sets.promises.set_old_len(len(heap.promises.len()));
+    sets.proxys.set_old_len(len(heap.proxys.len()));
+    sets.realms.set_old_len(len(heap.realms.len()));
+    #[cfg(feature = "regexp")]
+    sets.regexps.set_old_len(len(heap.regexps.len()));
+    sets.scripts.set_old_len(len(heap.scripts.len()));
+    #[cfg(feature = "set")]
+    sets.sets.set_old_len(len(heap.sets.len()));
+    #[cfg(feature = "set")]
+    sets.set_iterators
+        .set_old_len(len(heap.set_iterators.len()));
+    #[cfg(feature = "shared-array-buffer")]
+    sets.shared_array_buffers
+        .set_old_len(len(heap.shared_array_buffers.len()));
+    sets.strings.set_old_len(len(heap.strings.len()));
+    sets.symbols.set_old_len(len(heap.symbols.len()));
+    #[cfg(feature = "array-buffer")]
+    sets.typed_arrays.set_old_len(len(heap.typed_arrays.len()));
+    #[cfg(feature = "weak-refs")]
+    sets.weak_maps.set_old_len(len(heap.weak_maps.len()));
+    #[cfg(feature = "weak-refs")]
+    sets.weak_refs.set_old_len(len(heap.weak_refs.len()));
+    #[cfg(feature = "weak-refs")]
+    sets.weak_sets.set_old_len(len(heap.weak_sets.len()));
+    heap.elements
+        .e2pow4
+        .remembered
+        .set_old_len(len(heap.elements.e2pow4.values.len()));
+    heap.elements
+        .e2pow6
+        .remembered
+        .set_old_len(len(heap.elements.e2pow6.values.len()));
+    heap.elements
+        .e2pow8
+        .remembered
+        .set_old_len(len(heap.elements.e2pow8.values.len()));
+    heap.elements
+        .e2pow10
+        .remembered
+        .set_old_len(len(heap.elements.e2pow10.values.len()));
+    heap.elements
+        .e2pow12
+        .remembered
+        .set_old_len(len(heap.elements.e2pow12.values.len()));
+    heap.elements
+        .e2pow16
+        .remembered
+        .set_old_len(len(heap.elements.e2pow16.values.len()));
+    heap.elements
+        .e2pow24
+        .remembered
+        .set_old_len(len(heap.elements.e2pow24.values.len()));
+    heap.elements
+        .e2pow32
+        .remembered
+        .set_old_len(len(heap.elements.e2pow32.values.len()));
+}
+
+/// Traces the old items that have been written to since the previous garbage
+/// collection. The old generation is considered marked by the bits of a minor
+/// garbage collection.
+pub(crate) fn mark_old_generation(heap: &Heap, bits: &mut HeapBits, queues: &mut WorkQueues) {
+    mark_remembered_sets(heap, bits, queues);
+}
+
+/// Traces again the already marked items that have been written to since
+/// incremental marking started, as they may have been written to after they
+/// were traced.
+pub(crate) fn retrace_remembered_sets(heap: &Heap, bits: &mut HeapBits, queues: &mut WorkQueues) {
+    mark_remembered_sets(heap, bits, queues);
+}
+
+fn mark_remembered_sets(heap: &Heap, bits: &mut HeapBits, queues: &mut WorkQueues) {
+    let sets = &heap.remembered_sets;
+    #[cfg(feature = "array-buffer")]
+    mark_old(
+        &heap.array_buffers,
+        &sets.array_buffers,
+        &mut bits.array_buffers,
+        queues,
+    );
+    mark_old(&heap.arrays, &sets.arrays, &mut bits.arrays, queues);
+    mark_old(
+        &heap.array_iterators,
+        &sets.array_iterators,
+        &mut bits.array_iterators,
+        queues,
+    );
+    mark_old(
+        &heap.async_generators,
+        &sets.async_generators,
+        &mut bits.async_generators,
+        queues,
+    );
+    mark_old(
+        &heap.array_from_async_reactions,
+        &sets.array_from_async_reactions,
+        &mut bits.array_from_async_reactions,
+        queues,
+    );
+    mark_old(
+        &heap.await_reactions,
+        &sets.await_reactions,
+        &mut bits.await_reactions,
+        queues,
+    );
+    mark_old(&heap.bigints, &sets.bigints, &mut bits.bigints, queues);
+    mark_old(
+        &heap.bound_functions,
+        &sets.bound_functions,
+        &mut bits.bound_functions,
+        queues,
+    );
+    mark_old(
+        &heap.builtin_constructors,
+        &sets.builtin_constructors,
+        &mut bits.builtin_constructors,
+        queues,
+    );
+    mark_old(
+        &heap.builtin_functions,
+        &sets.builtin_functions,
+        &mut bits.builtin_functions,
+        queues,
+    );
+    #[cfg(feature = "array-buffer")]
+    mark_old(
+        &heap.data_views,
+        &sets.data_views,
+        &mut bits.data_views,
+        queues,
+    );
+    #[cfg(feature = "date")]
+    mark_old(&heap.dates, &sets.dates, &mut bits.dates, queues);
+    #[cfg(feature = "proposal-explicit-resource-management")]
+    mark_old(
+        &heap.disposable_stacks,
+        &sets.disposable_stacks,
+        &mut bits.disposable_stacks,
+        queues,
+    );
+    mark_old(
+        &heap.environments.declarative,
+        &sets.declarative_environments,
+        &mut bits.declarative_environments,
+        queues,
+    );
+    mark_old(
+        &heap.ecmascript_functions,
+        &sets.ecmascript_functions,
+        &mut bits.ecmascript_functions,
+        queues,
+    );
+    mark_old(
+        &heap.embedder_objects,
+        &sets.embedder_objects,
+        &mut bits.embedder_objects,
+        queues,
+    );
+    mark_old(&heap.errors, &sets.errors, &mut bits.errors, queues);
+    mark_old(
+        &heap.executables,
+        &sets.executables,
+        &mut bits.executables,
+        queues,
+    );
+    mark_old(
+        &heap.source_codes,
+        &sets.source_codes,
+        &mut bits.source_codes,
+        queues,
+    );
+    mark_old(
+        &heap.finalization_registrys,
+        &sets.finalization_registrys,
+        &mut bits.finalization_registrys,
+        queues,
+    );
+    mark_old(
+        &heap.environments.function,
+        &sets.function_environments,
+        &mut bits.function_environments,
+        queues,
+    );
+    mark_old(
+        &heap.generators,
+        &sets.generators,
+        &mut bits.generators,
+        queues,
+    );
+    mark_old(
+        &heap.environments.global,
+        &sets.global_environments,
+        &mut bits.global_environments,
+        queues,
+    );
+    mark_old(&heap.maps, &sets.maps, &mut bits.maps, queues);
+    mark_old(
+        &heap.map_iterators,
+        &sets.map_iterators,
+        &mut bits.map_iterators,
+        queues,
+    );
+    mark_old(
+        &heap.mapped_arguments,
+        &sets.mapped_arguments,
+        &mut bits.mapped_arguments,
+        queues,
+    );
+    mark_old(&heap.modules, &sets.modules, &mut bits.modules, queues);
+    mark_old(&heap.numbers, &sets.numbers, &mut bits.numbers, queues);
+    mark_old(
+        &heap.environments.object,
+        &sets.object_environments,
+        &mut bits.object_environments,
+        queues,
+    );
+    mark_old(&heap.objects, &sets.objects, &mut bits.objects, queues);
+    mark_old(
+        &heap.object_shapes,
+        &sets.object_shapes,
+        &mut bits.object_shapes,
+        queues,
+    );
+    mark_old(
+        &heap.primitive_objects,
+        &sets.primitive_objects,
+        &mut bits.primitive_objects,
+        queues,
+    );
+    mark_old(
+        &heap.promise_reaction_records,
+        &sets.promise_reaction_records,
+        &mut bits.promise_reaction_records,
+        queues,
+    );
+    mark_old(
+        &heap.promise_resolving_functions,
+        &sets.promise_resolving_functions,
+        &mut bits.promise_resolving_functions,
+        queues,
+    );
+    mark_old(&heap.promises, &sets.promises, &mut bits.promises, queues);
+    mark_old(&heap.proxys, &sets.proxys, &mut bits.proxys, queues);
+    mark_old(&heap.realms, &sets.realms, &mut bits.realms, queues);
+    #[cfg(feature = "regexp")]
+    mark_old(&heap.regexps, &sets.regexps, &mut bits.regexps, queues);
+    mark_old(&heap.scripts, &sets.scripts, &mut bits.scripts, queues);
+    #[cfg(feature = "set")]
+    mark_old(&heap.sets, &sets.sets, &mut bits.sets, queues);
+    #[cfg(feature = "set")]
+    mark_old(
+        &heap.set_iterators,
+        &sets.set_iterators,
+        &mut bits.set_iterators,
+        queues,
+    );
+    #[cfg(feature = "shared-array-buffer")]
+    mark_old(
+        &heap.shared_array_buffers,
+        &sets.shared_array_buffers,
+        &mut bits.shared_array_buffers,
+        queues,
+    );
+    mark_old(&heap.strings, &sets.strings, &mut bits.strings, queues);
+    mark_old(&heap.symbols, &sets.symbols, &mut bits.symbols, queues);
+    #[cfg(feature = "array-buffer")]
+    mark_old(
+        &heap.typed_arrays,
+        &sets.typed_arrays,
+        &mut bits.typed_arrays,
+        queues,
+    );
+    #[cfg(feature = "weak-refs")]
+    mark_old(
+        &heap.weak_maps,
+        &sets.weak_maps,
+        &mut bits.weak_maps,
+        queues,
+    );
+    #[cfg(feature = "weak-refs")]
+    mark_old(
+        &heap.weak_refs,
+        &sets.weak_refs,
+        &mut bits.weak_refs,
+        queues,
+    );
+    #[cfg(feature = "weak-refs")]
+    mark_old(
+        &heap.weak_sets,
+        &sets.weak_sets,
+        &mut bits.weak_sets,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow4.values,
+        &heap.elements.e2pow4.descriptors,
+        &heap.elements.e2pow4.remembered,
+        &mut bits.e_2_4,
+        |len| len as u8,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow6.values,
+        &heap.elements.e2pow6.descriptors,
+        &heap.elements.e2pow6.remembered,
+        &mut bits.e_2_6,
+        |len| len as u8,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow8.values,
+        &heap.elements.e2pow8.descriptors,
+        &heap.elements.e2pow8.remembered,
+        &mut bits.e_2_8,
+        |len| len as u16,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow10.values,
+        &heap.elements.e2pow10.descriptors,
+        &heap.elements.e2pow10.remembered,
+        &mut bits.e_2_10,
+        |len| len as u16,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow12.values,
+        &heap.elements.e2pow12.descriptors,
+        &heap.elements.e2pow12.remembered,
+        &mut bits.e_2_12,
+        |len| len as u16,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow16.values,
+        &heap.elements.e2pow16.descriptors,
+        &heap.elements.e2pow16.remembered,
+        &mut bits.e_2_16,
+        |len| len as u16,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow24.values,
+        &heap.elements.e2pow24.descriptors,
+        &heap.elements.e2pow24.remembered,
+        &mut bits.e_2_24,
+        |len| len,
+        queues,
+    );
+    mark_old_elements(
+        &heap.elements.e2pow32.values,
+        &heap.elements.e2pow32.descriptors,
+        &heap.elements.e2pow32.remembered,
+        &mut bits.e_2_32,
+        |len| len,
+        queues,
+    );
+}
+
+fn mark_old<T: HeapMarkAndSweep>(
+    vec: &[T],
+    remembered: &RememberedSet,
+    bits: &mut MarkBits,
+    queues: &mut WorkQueues,
+) {
+    for index in remembered.indexes() {
+        // Note: Unmarked items are traced normally if they are reached.
+        if bits.is_marked(index) {
+            vec[index].mark_values(queues);
+        }
+    }
+}
+
+fn mark_old_elements<const N: usize, L: Copy + Into<u32>>(
+    values: &[Option<[Option<Value>; N]>],
+    descriptors: &AHashMap<ElementIndex, AHashMap<u32, ElementDescriptor>>,
+    remembered: &RememberedElements,
+    bits: &mut MarkBits<(bool, L)>,
+    length: impl Fn(u32) -> L,
+    queues: &mut WorkQueues,
+) {
+    for (index, len) in remembered.iter() {
+        // Note: Old element arrays without bits are live. Marked element
+        // arrays record the length to sweep their values with.
+        let len = match bits.get_mut(index) {
+            None => len,
+            Some((false, _)) => continue,
+            Some((_, marked_len)) => {
+                let len = len.max((*marked_len).into());
+                *marked_len = length(len);
+                len
+            }
+        };
+        if let Some(descriptors) = descriptors.get(&ElementIndex::from_index(index)) {
+            mark_descriptors(descriptors, queues);
+        }
+        if values[index].is_some() {
+            mark_array_with_u32_length(&values[index], queues, len);
+        }
+    }
+}
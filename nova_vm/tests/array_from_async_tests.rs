@@ -0,0 +1,57 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod common;
+
+use std::{fs, path::PathBuf};
+
+use common::JobQueueHostHooks;
+use nova_vm::ecmascript::{
+    execution::agent::{GcAgent, Options},
+    scripts_and_modules::script::{parse_script, script_evaluation},
+    types::String,
+};
+
+/// The test script reports the outcome of its promises through globals, since
+/// rejections do not surface as job errors.
+const CHECK_RESULT: &str = "if (fromAsyncError !== undefined) throw fromAsyncError;
+if (!fromAsyncDone) throw new Error('Array.fromAsync tests did not finish');";
+
+#[test]
+fn array_from_async_tests() {
+    let d: PathBuf = [
+        env!("CARGO_MANIFEST_DIR"),
+        "tests",
+        "sources",
+        "arrayFromAsync.test.js",
+    ]
+    .iter()
+    .collect();
+    let contents = fs::read_to_string(d.clone()).expect("Should have been able to read the file");
+
+    let host_hooks: &'static JobQueueHostHooks = Box::leak(Box::default());
+    let mut agent = GcAgent::new(Options::default(), host_hooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let realm = agent.current_realm_id();
+        let source_text = String::from_string(agent, contents, gc.nogc());
+        let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+        let mut result = script_evaluation(agent, script, gc.reborrow()).map(|_| ());
+        if result.is_ok() {
+            result = host_hooks.run_jobs(agent, gc.reborrow());
+        }
+        if result.is_ok() {
+            let source_text = String::from_static_str(agent, CHECK_RESULT, gc.nogc());
+            let script = parse_script(agent, source_text, realm, false, None, gc.nogc()).unwrap();
+            result = script_evaluation(agent, script, gc.reborrow()).map(|_| ());
+        }
+        if let Err(err) = result {
+            panic!(
+                "Test '{}' failed: {:?}",
+                d.display(),
+                err.to_string(agent, gc).as_str(agent)
+            )
+        }
+    });
+}
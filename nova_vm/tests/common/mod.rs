@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Helpers shared by the integration tests. Each test crate uses only some of
+//! them.
+#![allow(dead_code)]
+
+use std::{cell::RefCell, collections::VecDeque, fmt::Debug};
+
+use nova_vm::{
+    ecmascript::{
+        execution::{
+            agent::{GcAgent, HostHooks, Job, RealmRoot},
+            Agent, JsResult,
+        },
+        scripts_and_modules::script::{parse_script, script_evaluation, Script},
+        types::{Object, String, Value},
+    },
+    engine::context::{GcScope, NoGcScope},
+};
+
+/// Creates a Realm with the default global object.
+pub fn create_realm(agent: &mut GcAgent) -> RealmRoot {
+    let create_global_object: Option<for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>> = None;
+    let create_global_this_value: Option<for<'a> fn(&mut Agent, GcScope<'a, '_>) -> Object<'a>> =
+        None;
+    let initialize_global_object: Option<fn(&mut Agent, Object, GcScope)> = None;
+    agent.create_realm(
+        create_global_object,
+        create_global_this_value,
+        initialize_global_object,
+    )
+}
+
+/// Parses the source as sloppy mode Script code in the current Realm.
+pub fn parse_source(agent: &mut Agent, source: &'static str, gc: NoGcScope) -> Script {
+    let realm = agent.current_realm_id();
+    let source_text = String::from_static_str(agent, source, gc);
+    parse_script(agent, source_text, realm, false, None, gc).unwrap()
+}
+
+/// Evaluates the source in the Realm, and returns what `then` returns for
+/// the completion.
+pub fn evaluate_script<R>(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: &'static str,
+    then: impl FnOnce(&mut Agent, JsResult<Value>) -> R,
+) -> R {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let script = parse_source(agent, source, gc.nogc());
+        let result = script_evaluation(agent, script, gc.reborrow());
+        then(agent, result)
+    })
+}
+
+/// Host hooks that queue promise jobs for the test to run.
+#[derive(Default)]
+pub struct JobQueueHostHooks {
+    promise_job_queue: RefCell<VecDeque<Job>>,
+}
+
+// Job doesn't implement Debug
+impl Debug for JobQueueHostHooks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JobQueueHostHooks").finish()
+    }
+}
+
+impl HostHooks for JobQueueHostHooks {
+    fn enqueue_promise_job(&self, job: Job) {
+        self.promise_job_queue.borrow_mut().push_back(job);
+    }
+}
+
+impl JobQueueHostHooks {
+    /// Runs the queued jobs, and the jobs that they queue, until a job throws.
+    pub fn run_jobs(&self, agent: &mut Agent, mut gc: GcScope) -> JsResult<()> {
+        loop {
+            let Some(job) = self.promise_job_queue.borrow_mut().pop_front() else {
+                return Ok(());
+            };
+            job.run(agent, gc.reborrow())?;
+        }
+    }
+}
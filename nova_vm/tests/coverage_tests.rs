@@ -0,0 +1,227 @@
+mod common;
+
+use common::{create_realm, parse_source};
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{CoverageReport, GcAgent, Options, ScriptCoverage},
+        DefaultHostHooks,
+    },
+    scripts_and_modules::script::script_evaluation,
+};
+
+const SOURCE: &str = "function sign(n) {
+    if (n > 0) {
+        return 1;
+    } else {
+        return -1;
+    }
+}
+function uncalled() {
+    return 0;
+}
+const double = (x) => x * 2;
+for (let i = 0; i < 3; i++) {
+    sign(i);
+}
+double(1);";
+
+fn new_agent() -> (GcAgent, nova_vm::ecmascript::execution::agent::RealmRoot) {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    (agent, realm)
+}
+
+/// Evaluates the source while collecting coverage.
+fn cover_script(source: &'static str) -> CoverageReport {
+    let (mut agent, realm) = new_agent();
+    agent.start_coverage();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let script = parse_source(agent, source, gc.nogc());
+        agent.set_covered_script_url(&script, "file:///covered.js");
+        script_evaluation(agent, script, gc.reborrow()).unwrap();
+    });
+    // Collecting garbage keeps the counts of the collected code.
+    agent.gc();
+    agent.take_coverage().unwrap()
+}
+
+fn line_count(script: &ScriptCoverage, line: u32) -> Option<u64> {
+    script
+        .lines
+        .iter()
+        .find(|&&(l, _)| l == line)
+        .map(|&(_, count)| count)
+}
+
+#[test]
+fn function_and_block_counts() {
+    let coverage = cover_script(SOURCE);
+    assert_eq!(coverage.scripts.len(), 1);
+    let script = &coverage.scripts[0];
+    assert_eq!(script.url, "file:///covered.js");
+    let functions = script
+        .functions
+        .iter()
+        .map(|function| (function.name.as_str(), function.line, function.count))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        functions,
+        [
+            ("", 1, 1),
+            ("sign", 1, 3),
+            ("uncalled", 8, 0),
+            ("double", 11, 1)
+        ]
+    );
+    assert!(script.functions[2].blocks.is_empty());
+    let sign = &script.functions[1];
+    let block_source = |source: &str| {
+        sign.blocks
+            .iter()
+            .find(|block| &SOURCE[block.start_offset as usize..block.end_offset as usize] == source)
+            .unwrap()
+    };
+    // Blocks span whole statements.
+    assert_eq!(block_source("return 1;").count, 2);
+    assert_eq!(block_source("return -1;").count, 1);
+    // Blocks are sorted by start and then by descending end, and nest.
+    for function in script.functions.iter() {
+        let mut enclosing = Vec::<(u32, u32)>::new();
+        for block in function.blocks.iter() {
+            assert!(function.start_offset <= block.start_offset);
+            assert!(block.end_offset <= function.end_offset);
+            while enclosing
+                .last()
+                .is_some_and(|&(_, end)| end <= block.start_offset)
+            {
+                enclosing.pop();
+            }
+            if let Some(&(start, end)) = enclosing.last() {
+                assert!(start <= block.start_offset && block.end_offset <= end);
+            }
+            enclosing.push((block.start_offset, block.end_offset));
+        }
+    }
+
+    assert_eq!(line_count(script, 3), Some(2));
+    assert_eq!(line_count(script, 5), Some(1));
+    assert_eq!(line_count(script, 9), Some(0));
+    assert_eq!(line_count(script, 13), Some(3));
+    assert_eq!(line_count(script, 15), Some(1));
+}
+
+#[test]
+fn take_coverage_resets_counts() {
+    let (mut agent, realm) = new_agent();
+    assert!(agent.take_coverage().is_none());
+    agent.start_coverage();
+    let evaluate = |agent: &mut GcAgent| {
+        common::evaluate_script(agent, &realm, "function f() {} f(); f();", |_, result| {
+            result.unwrap();
+        });
+    };
+    evaluate(&mut agent);
+    let coverage = agent.take_coverage().unwrap();
+    assert_eq!(coverage.scripts[0].functions[1].count, 2);
+    let coverage = agent.take_coverage().unwrap();
+    assert_eq!(coverage.scripts[0].functions[1].count, 0);
+    agent.stop_coverage();
+    evaluate(&mut agent);
+    assert!(agent.take_coverage().is_none());
+}
+
+#[test]
+fn coverage_formats() {
+    let coverage = cover_script(SOURCE);
+
+    let mut lcov = Vec::new();
+    coverage.write_lcov(&mut lcov).unwrap();
+    let lcov = std::string::String::from_utf8(lcov).unwrap();
+    assert!(lcov.starts_with("TN:\nSF:file:///covered.js\nFN:1,sign@1:1\nFN:8,uncalled@8:1\n"));
+    assert!(
+        lcov.contains("FNDA:3,sign@1:1\nFNDA:0,uncalled@8:1\nFNDA:1,double@11:16\nFNF:3\nFNH:2\n")
+    );
+    assert!(lcov.contains("DA:3,2\n"));
+    assert!(lcov.contains("DA:9,0\n"));
+    assert!(lcov.ends_with("end_of_record\n"));
+
+    let mut json = Vec::new();
+    coverage.write_precise_coverage(&mut json).unwrap();
+    let json = std::string::String::from_utf8(json).unwrap();
+    assert!(json.starts_with(
+        "{\"result\":[{\"scriptId\":\"1\",\"url\":\"file:///covered.js\",\"functions\":[{\"functionName\":\"\",\"ranges\":[{\"startOffset\":0,\"endOffset\":216,\"count\":1}"
+    ));
+    assert!(json.contains(
+        "{\"functionName\":\"uncalled\",\"ranges\":[{\"startOffset\":94,\"endOffset\":131,\"count\":0}],\"isBlockCoverage\":true}"
+    ));
+    assert!(json.ends_with("]}]}\n"));
+}
+
+#[test]
+fn uncalled_functions_are_named_like_the_compiler_names_them() {
+    const SOURCE: &str = "const o = { meth() {}, get g() { return 1; }, prop: function () {} };
+class Q { other() {} }
+const arrow = () => {};
+let late;
+late ||= function () {};
+function outer(callback = () => {}) {}
+[].map((x) => x);";
+    let coverage = cover_script(SOURCE);
+    let script = &coverage.scripts[0];
+    let names = script.functions[1..]
+        .iter()
+        .map(|function| function.name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        ["meth", "g", "prop", "other", "arrow", "late", "outer", "callback", ""]
+    );
+
+    let mut lcov = Vec::new();
+    coverage.write_lcov(&mut lcov).unwrap();
+    let lcov = std::string::String::from_utf8(lcov).unwrap();
+    assert!(lcov.contains("FN:1,meth@1:17\n"));
+    assert!(lcov.contains("FN:2,other@2:16\n"));
+    assert!(lcov.contains("FN:7,(anonymous)@7:8\n"));
+}
+
+#[test]
+fn block_ranges() {
+    const SOURCE: &str = "function size(n) {
+    if (n > 10) {
+        return 'big';
+    }
+    const x = n > 5 ? 'mid' : 'small';
+    for (let i = 0; i < n; i++) {
+        if (i === 100) break;
+    }
+    return x;
+}
+size(1);
+size(7);";
+    let coverage = cover_script(SOURCE);
+    let size = &coverage.scripts[0].functions[1];
+    let blocks = size
+        .blocks
+        .iter()
+        .filter(|block| block.count != size.count)
+        .map(|block| {
+            (
+                &SOURCE[block.start_offset as usize..block.end_offset as usize],
+                block.count,
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        blocks,
+        [
+            ("return 'big';", 0),
+            ("'mid'", 1),
+            ("'small'", 1),
+            ("i < n", 10),
+            ("i++", 8),
+            ("if (i === 100) break;", 8),
+            ("break;", 0),
+        ]
+    );
+}
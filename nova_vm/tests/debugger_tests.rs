@@ -0,0 +1,252 @@
+mod common;
+
+use std::sync::Mutex;
+
+use common::{create_realm, parse_source};
+use nova_vm::{
+    ecmascript::{
+        execution::{
+            agent::{
+                DebugAction, DebugScopeKind, GcAgent, HostHooks, Job, Options, PauseReason,
+                RealmRoot,
+            },
+            Agent,
+        },
+        scripts_and_modules::script::script_evaluation,
+        types::Value,
+    },
+    engine::context::GcScope,
+};
+
+/// Evaluates the source with breakpoints on the given lines, and returns
+/// true if it completed normally.
+fn evaluate_script(
+    agent: &mut GcAgent,
+    realm: &RealmRoot,
+    source: &'static str,
+    breakpoints: &[u32],
+) -> bool {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let script = parse_source(agent, source, gc.nogc());
+        for &line in breakpoints {
+            agent.set_breakpoint(&script, line);
+        }
+        script_evaluation(agent, script, gc.reborrow()).is_ok()
+    })
+}
+
+/// State of the execution when it paused in the debugger.
+#[derive(Debug)]
+struct Pause {
+    reason: PauseReason,
+    /// Line of the innermost frame.
+    line: u32,
+    /// Function names of the frames, innermost first.
+    functions: Vec<Option<std::string::String>>,
+    /// Integer bindings of the innermost frame's scopes, innermost first.
+    bindings: Vec<(std::string::String, i64)>,
+    this_value: Option<Value>,
+    /// Result of evaluating `a * 10 + b` in the innermost frame.
+    evaluated: Option<i64>,
+}
+
+impl Pause {
+    fn binding(&self, name: &str) -> Option<i64> {
+        self.bindings
+            .iter()
+            .find(|(binding, _)| binding == name)
+            .map(|&(_, value)| value)
+    }
+}
+
+/// Records the pauses, and resumes with the queued actions.
+#[derive(Debug, Default)]
+struct DebuggerHostHooks {
+    actions: Mutex<Vec<DebugAction>>,
+    pauses: Mutex<Vec<Pause>>,
+}
+
+impl HostHooks for DebuggerHostHooks {
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn host_debugger_paused(
+        &self,
+        agent: &mut Agent,
+        reason: PauseReason,
+        gc: GcScope,
+    ) -> DebugAction {
+        let frames = agent.debug_call_frames();
+        let frame = frames[0];
+        let mut bindings = vec![];
+        for scope in frame.scopes(agent) {
+            if scope.kind == DebugScopeKind::Global {
+                continue;
+            }
+            for (name, value) in scope.bindings {
+                if let Some(Value::Integer(value)) = value {
+                    bindings.push((name.to_owned(), value.into_i64()));
+                }
+            }
+        }
+        let line = frame.line_and_column(agent).unwrap().0;
+        let functions = frames
+            .iter()
+            .map(|frame| frame.function_name(agent).map(str::to_owned))
+            .collect();
+        let this_value = frame.this_value(agent);
+        let evaluated = match frame.evaluate(agent, "a * 10 + b", gc) {
+            Ok(Value::Integer(value)) => Some(value.into_i64()),
+            _ => None,
+        };
+        self.pauses.lock().unwrap().push(Pause {
+            reason,
+            line,
+            functions,
+            bindings,
+            this_value,
+            evaluated,
+        });
+        let mut actions = self.actions.lock().unwrap();
+        if actions.is_empty() {
+            DebugAction::Continue
+        } else {
+            actions.remove(0)
+        }
+    }
+}
+
+fn create_agent(actions: Vec<DebugAction>) -> (GcAgent, &'static DebuggerHostHooks) {
+    let host_hooks: &'static DebuggerHostHooks = Box::leak(Box::new(DebuggerHostHooks {
+        actions: Mutex::new(actions),
+        ..Default::default()
+    }));
+    (GcAgent::new(Options::default(), host_hooks), host_hooks)
+}
+
+#[test]
+fn debugger_statement_is_ignored_without_debugger() {
+    let (mut agent, host_hooks) = create_agent(vec![]);
+    let realm = create_realm(&mut agent);
+    assert!(evaluate_script(&mut agent, &realm, "debugger; 1", &[]));
+    assert!(host_hooks.pauses.lock().unwrap().is_empty());
+}
+
+#[test]
+fn debugger_statement_pauses() {
+    let (mut agent, host_hooks) = create_agent(vec![]);
+    agent.enable_debugger();
+    let realm = create_realm(&mut agent);
+    assert!(evaluate_script(
+        &mut agent,
+        &realm,
+        "function f(a) {
+            let b = a + 1;
+            debugger;
+            return b;
+        }
+        new f(1);",
+        &[]
+    ));
+    let pauses = host_hooks.pauses.lock().unwrap();
+    assert_eq!(pauses.len(), 1);
+    let pause = &pauses[0];
+    assert!(matches!(pause.reason, PauseReason::DebuggerStatement));
+    assert_eq!(pause.line, 3);
+    assert_eq!(pause.functions, [Some("f".to_owned()), None]);
+    assert_eq!(pause.binding("a"), Some(1));
+    assert_eq!(pause.binding("b"), Some(2));
+    assert!(matches!(pause.this_value, Some(Value::Object(_))));
+    assert_eq!(pause.evaluated, Some(12));
+}
+
+#[test]
+fn breakpoints_pause_once_per_line() {
+    let (mut agent, host_hooks) = create_agent(vec![]);
+    let realm = create_realm(&mut agent);
+    assert!(evaluate_script(
+        &mut agent,
+        &realm,
+        "let sum = 0;
+        for (let i = 0; i < 3; i++) {
+            sum += i;
+        }
+        sum;",
+        &[3, 5]
+    ));
+    let pauses = host_hooks.pauses.lock().unwrap();
+    let lines: Vec<u32> = pauses.iter().map(|pause| pause.line).collect();
+    assert_eq!(lines, [3, 3, 3, 5]);
+    assert!(pauses
+        .iter()
+        .all(|pause| matches!(pause.reason, PauseReason::Breakpoint(_))));
+    assert_eq!(pauses[2].binding("i"), Some(2));
+    // The script has no bindings `a` and `b`, so the evaluation throws.
+    assert_eq!(pauses[2].evaluated, None);
+}
+
+#[test]
+fn step_over_in_and_out() {
+    const SOURCE: &str = "function f() {
+            let x = 1;
+            return x;
+        }
+        debugger;
+        let y = f();
+        y;";
+    let line_sequence = |actions: Vec<DebugAction>| {
+        let (mut agent, host_hooks) = create_agent(actions);
+        agent.enable_debugger();
+        let realm = create_realm(&mut agent);
+        assert!(evaluate_script(&mut agent, &realm, SOURCE, &[]));
+        let pauses = host_hooks.pauses.lock().unwrap();
+        pauses.iter().map(|pause| pause.line).collect::<Vec<_>>()
+    };
+    assert_eq!(
+        line_sequence(vec![DebugAction::StepOver, DebugAction::StepOver]),
+        [5, 6, 7]
+    );
+    assert_eq!(
+        line_sequence(vec![
+            DebugAction::StepOver,
+            DebugAction::StepIn,
+            DebugAction::StepIn
+        ]),
+        [5, 6, 2, 3]
+    );
+    assert_eq!(
+        line_sequence(vec![
+            DebugAction::StepOver,
+            DebugAction::StepIn,
+            DebugAction::StepOut
+        ]),
+        [5, 6, 2, 6]
+    );
+}
+
+#[test]
+fn pause_on_exceptions() {
+    let (mut agent, host_hooks) = create_agent(vec![]);
+    agent.set_pause_on_exceptions(true);
+    let realm = create_realm(&mut agent);
+    assert!(!evaluate_script(
+        &mut agent,
+        &realm,
+        "function f() {
+            throw 1;
+        }
+        try { f(); } catch {}
+        f();",
+        &[]
+    ));
+    let pauses = host_hooks.pauses.lock().unwrap();
+    // The uncaught exception pauses once as it propagates out of f.
+    assert_eq!(pauses.len(), 2);
+    for pause in pauses.iter() {
+        assert!(matches!(
+            pause.reason,
+            PauseReason::Exception(Value::Integer(value)) if value.into_i64() == 1
+        ));
+        assert_eq!(pause.line, 2);
+        assert_eq!(pause.functions, [Some("f".to_owned()), None]);
+    }
+}
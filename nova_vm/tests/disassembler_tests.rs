@@ -0,0 +1,112 @@
+mod common;
+
+use common::{create_realm, parse_source};
+use nova_vm::ecmascript::{
+    execution::{agent::GcAgent, agent::Options, DefaultHostHooks},
+    scripts_and_modules::script::disassemble_script,
+};
+
+fn disassemble(source: &'static str) -> std::string::String {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    agent.run_in_realm(&realm, |agent, gc| {
+        let script = parse_source(agent, source, gc.nogc());
+        disassemble_script(agent, script, gc.nogc())
+    })
+}
+
+#[test]
+fn disassembles_nested_executables() {
+    let disassembly = disassemble(
+        "function sign(n) {
+    if (n > 0) {
+        return 1;
+    }
+    return -1;
+}
+const double = (x) => x * 2;
+class Point {
+    x = 'field';
+}
+",
+    );
+    let titles = disassembly
+        .lines()
+        .filter(|line| line.starts_with("== "))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        [
+            "== script ==",
+            "== script > function #0 sign(n) (line 1) ==",
+            "== script > arrow #0 double (x) => (line 7) ==",
+            "== script > initializer #0 ==",
+        ]
+    );
+    assert!(disassembly
+        .contains("InstantiateOrdinaryFunctionExpression(function sign(n))  ; function #0"));
+    assert!(disassembly.contains("InstantiateArrowFunctionExpression((x) => {})  ; arrow #0"));
+    assert!(disassembly.contains("ClassDefineDefaultConstructor(class Point {})  ; initializer #0"));
+
+    // The function's section lists its constants, source lines and jump
+    // targets.
+    let sign = disassembly
+        .split("\n\n")
+        .find(|section| section.starts_with("== script > function #0"))
+        .unwrap();
+    assert!(sign.contains("\n  #0: \"arguments\"\n"));
+    assert!(sign.contains("\n  ; 2: if (n > 0) {\n"));
+    let jump = sign
+        .lines()
+        .find(|line| line.contains("JumpIfNot("))
+        .unwrap();
+    let target = jump
+        .rsplit_once("JumpIfNot(")
+        .unwrap()
+        .1
+        .trim_end_matches(')');
+    // The jump skips to the last return statement.
+    let target_ip = format!(" {}: ", target);
+    let (before_target, after_target) = sign.split_once(&target_ip).unwrap();
+    assert!(before_target.trim_end().ends_with("  ->"));
+    assert!(before_target.contains("  ; 5: return -1;\n"));
+    assert!(after_target.trim_end().ends_with("Return"));
+
+    let initializer = disassembly
+        .split("\n\n")
+        .find(|section| section.starts_with("== script > initializer #0"))
+        .unwrap();
+    assert!(initializer.contains("  ; 9: x = 'field';\n"));
+    assert!(initializer.contains("StoreConstant(\"field\")"));
+}
+
+#[test]
+fn names_methods_and_constructors() {
+    let disassembly = disassemble(
+        "const o = { meth(a) { return a; }, get g() { return 1; } };
+class Q { other() {} }
+const K = class extends Q { constructor(x) { super(); } };
+",
+    );
+    let titles = disassembly
+        .lines()
+        .filter(|line| line.starts_with("== "))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        titles,
+        [
+            "== script ==",
+            "== script > function #0 meth(a) (line 1) ==",
+            "== script > function #1 g() (line 1) ==",
+            "== script > function #2 other() (line 2) ==",
+            "== script > function #3 K(x) (line 3) ==",
+        ]
+    );
+    assert!(disassembly
+        .contains("InstantiateOrdinaryFunctionExpression(function meth(a))  ; function #0"));
+    assert!(disassembly.contains("ObjectDefineGetter(get function g())  ; function #1"));
+    assert!(disassembly.contains("ClassDefineDefaultConstructor(class Q {})  ; initializer #0"));
+    assert!(disassembly.contains("ObjectDefineMethod(function other())  ; function #2"));
+    assert!(disassembly
+        .contains("ClassDefineConstructor(class K constructor(x) { super() })  ; function #3"));
+}
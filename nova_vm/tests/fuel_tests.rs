@@ -0,0 +1,154 @@
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::create_realm;
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{GcAgent, HostHooks, Job, Options, RealmRoot},
+        Agent, DefaultHostHooks,
+    },
+    types::Value,
+};
+
+/// Evaluates the source and returns its result, or None if the evaluation
+/// ran out of fuel.
+fn evaluate_script(agent: &mut GcAgent, realm: &RealmRoot, source: &'static str) -> Option<Value> {
+    common::evaluate_script(agent, realm, source, |agent, result| {
+        assert_eq!(result.is_err(), agent.is_terminating());
+        result.ok().map(|value| value.unbind())
+    })
+}
+
+const LOOP: &str = "let sum = 0; for (let i = 0; i < 1000; i++) { sum += i; } sum";
+
+fn fuel_consumed(source: &'static str) -> u64 {
+    let mut agent = GcAgent::new(
+        Options {
+            fuel: Some(u64::MAX),
+            ..Default::default()
+        },
+        &DefaultHostHooks,
+    );
+    let realm = create_realm(&mut agent);
+    evaluate_script(&mut agent, &realm, source).unwrap();
+    u64::MAX - agent.fuel().unwrap()
+}
+
+#[test]
+fn fuel_is_deterministic() {
+    let consumed = fuel_consumed(LOOP);
+    assert!(consumed > 1000);
+    assert_eq!(consumed, fuel_consumed(LOOP));
+
+    // Builtins consume fuel for the work they do.
+    let repeat = fuel_consumed("'abc'.repeat(1000)");
+    assert!(repeat >= 3000);
+    let sort = fuel_consumed("[5, 3, 1, 4, 2].sort()");
+    let long_sort = fuel_consumed("[5, 3, 1, 4, 2, 9, 7, 8, 6, 0].sort()");
+    assert!(long_sort > sort);
+}
+
+#[test]
+fn builtins_consume_fuel_per_element() {
+    // Each case is a call of a builtin, and its setup without the call.
+    let cases: [(&'static str, &'static str); 6] = [
+        (
+            "const a = Array(1000); a.fill(0)",
+            "const a = Array(1000); a",
+        ),
+        (
+            "const a = Array(1000).fill(0); a.includes(1)",
+            "const a = Array(1000).fill(0); a",
+        ),
+        (
+            "const a = Array(1000).fill(0); a.indexOf(1)",
+            "const a = Array(1000).fill(0); a",
+        ),
+        (
+            "const a = Array(1000).fill(0); a.join()",
+            "const a = Array(1000).fill(0); a",
+        ),
+        (
+            "const a = Array(1000).fill(0); a.splice(0, 1000)",
+            "const a = Array(1000).fill(0); a",
+        ),
+        (
+            "JSON.parse(' '.repeat(1000) + '0')",
+            "' '.repeat(1000) + '0'",
+        ),
+    ];
+    for (call, setup) in cases {
+        assert!(fuel_consumed(call) - fuel_consumed(setup) >= 1000, "{call}");
+    }
+}
+
+#[test]
+fn out_of_fuel_is_not_catchable() {
+    let mut agent = GcAgent::new(
+        Options {
+            fuel: Some(10_000),
+            ..Default::default()
+        },
+        &DefaultHostHooks,
+    );
+    let realm = create_realm(&mut agent);
+    assert!(evaluate_script(
+        &mut agent,
+        &realm,
+        "globalThis.caught = false;
+        while (true) {
+            try { while (true) {} } catch { caught = true; }
+        }"
+    )
+    .is_none());
+    assert_eq!(agent.fuel(), Some(0));
+
+    agent.set_fuel(10_000);
+    assert_eq!(
+        evaluate_script(&mut agent, &realm, "caught"),
+        Some(Value::Boolean(false))
+    );
+}
+
+#[derive(Debug)]
+struct RefuelingHostHooks {
+    refuels: AtomicUsize,
+}
+
+impl HostHooks for RefuelingHostHooks {
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn host_out_of_fuel(&self, agent: &mut Agent) -> bool {
+        if self.refuels.fetch_add(1, Ordering::Relaxed) < 10 {
+            agent.set_fuel(Some(5_000));
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[test]
+fn refuel_from_host_hook() {
+    let host_hooks: &'static RefuelingHostHooks = Box::leak(Box::new(RefuelingHostHooks {
+        refuels: AtomicUsize::new(0),
+    }));
+    let mut agent = GcAgent::new(
+        Options {
+            fuel: Some(1_000),
+            ..Default::default()
+        },
+        host_hooks,
+    );
+    let realm = create_realm(&mut agent);
+    assert_eq!(
+        evaluate_script(&mut agent, &realm, LOOP),
+        Some(Value::Integer(499500.into()))
+    );
+    let refuels = host_hooks.refuels.load(Ordering::Relaxed);
+    assert!(refuels > 0 && refuels < 10);
+
+    assert!(evaluate_script(&mut agent, &realm, "while (true) {}").is_none());
+    assert_eq!(host_hooks.refuels.load(Ordering::Relaxed), 11);
+}
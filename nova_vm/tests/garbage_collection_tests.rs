@@ -1,5 +1,8 @@
+mod common;
+
 use std::{fs, path::PathBuf};
 
+use common::{create_realm, parse_source, JobQueueHostHooks};
 use nova_vm::{
     ecmascript::{
         execution::{
@@ -112,3 +115,125 @@ fn garbage_collection_tests() {
         agent.gc();
     }
 }
+
+#[test]
+fn long_running_scripts_collect_garbage() {
+    let mut agent = GcAgent::new(
+        Options {
+            gc_initial_threshold: 1024 * 1024,
+            gc_nursery_size: 256 * 1024,
+            ..Default::default()
+        },
+        &DefaultHostHooks,
+    );
+    let realm = create_realm(&mut agent);
+    let result = common::evaluate_script(
+        &mut agent,
+        &realm,
+        "const kept = [];
+        function make(i) { return { i, values: [i, i + 1, i + 2] }; }
+        for (let i = 0; i < 50000; i++) {
+            const garbage = make(i);
+            if (i % 1000 === 0) kept.push(garbage);
+        }
+        kept.length === 50 && kept[49].values[2] === 49002",
+        |_, result| result.unwrap() == Value::Boolean(true),
+    );
+    assert!(result);
+    // The garbage was collected while the script ran.
+    assert!(agent.heap_statistics().total_bytes() < 4 * 1024 * 1024);
+}
+
+#[test]
+fn strings_stay_interned_across_collections() {
+    let mut agent = GcAgent::new(
+        Options {
+            gc_initial_threshold: 256 * 1024,
+            gc_nursery_size: 64 * 1024,
+            ..Default::default()
+        },
+        &DefaultHostHooks,
+    );
+    let realm = create_realm(&mut agent);
+    let result = common::evaluate_script(
+        &mut agent,
+        &realm,
+        "const kept = [];
+        let ok = true;
+        for (let i = 0; i < 20000; i++) {
+            const garbage = { ['garbage key ' + i]: i };
+            if (i % 4 === 0) kept.push('kept key ' + i);
+            ok = ok && Object['proto' + 'type'] === Object.prototype;
+        }
+        ok",
+        |_, result| result.unwrap() == Value::Boolean(true),
+    );
+    assert!(result);
+}
+
+#[test]
+fn large_arrays_survive_collections() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    let run = |agent: &mut GcAgent, source: &'static str| {
+        common::evaluate_script(agent, &realm, source, |_, result| {
+            result.unwrap() == Value::Boolean(true)
+        })
+    };
+    // The element arrays of both Arrays are in the largest size class, and
+    // the kept one is moved over the collected one.
+    assert!(run(
+        &mut agent,
+        "var garbage = new Array(70000).fill(0);
+        garbage = null;
+        var big = new Array(70000).fill(1);
+        true",
+    ));
+    agent.gc();
+    assert!(run(
+        &mut agent,
+        "big.length === 70000 && big[0] === 1 && big[69999] === 1",
+    ));
+}
+
+#[test]
+fn pending_promise_reactions_survive_collections() {
+    let host_hooks: &'static JobQueueHostHooks = Box::leak(Box::default());
+    let mut agent = GcAgent::new(
+        Options {
+            gc_initial_threshold: 256 * 1024,
+            gc_nursery_size: 64 * 1024,
+            ..Default::default()
+        },
+        host_hooks,
+    );
+    let realm = create_realm(&mut agent);
+    // The reactions of the pending promise are only reachable through it
+    // while the script allocates past the collection threshold.
+    common::evaluate_script(
+        &mut agent,
+        &realm,
+        "var resolve;
+        var results = [];
+        const promise = new Promise((r) => resolve = r);
+        promise.then((value) => results.push('then ' + value));
+        (async () => results.push('await ' + await promise))();
+        const garbage = [];
+        for (let i = 0; i < 20000; i++) garbage.push({ i, s: 'x' + i });",
+        |_, result| {
+            result.unwrap();
+        },
+    );
+    agent.gc();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let script = parse_source(agent, "resolve(1)", gc.nogc());
+        script_evaluation(agent, script, gc.reborrow()).unwrap();
+        host_hooks.run_jobs(agent, gc).unwrap();
+    });
+    assert!(common::evaluate_script(
+        &mut agent,
+        &realm,
+        "results.join() === 'then 1,await 1'",
+        |_, result| result.unwrap() == Value::Boolean(true),
+    ));
+}
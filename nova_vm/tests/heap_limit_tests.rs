@@ -0,0 +1,99 @@
+mod common;
+
+use common::create_realm;
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{GcAgent, Options},
+        DefaultHostHooks,
+    },
+    types::Value,
+};
+
+const MAX_HEAP_BYTES: usize = 64 * 1024 * 1024;
+
+fn evaluate_script(agent: &mut GcAgent, source: &'static str) -> bool {
+    let realm = create_realm(agent);
+    common::evaluate_script(agent, &realm, source, |_, result| {
+        result.unwrap() == Value::Boolean(true)
+    })
+}
+
+fn limited_agent(max_heap_bytes: usize) -> GcAgent {
+    GcAgent::new(
+        Options {
+            max_heap_bytes: Some(max_heap_bytes),
+            max_array_buffer_byte_length: Some(1024 * 1024),
+            ..Default::default()
+        },
+        &DefaultHostHooks,
+    )
+}
+
+#[test]
+fn heap_limit_throws_range_error() {
+    let mut agent = limited_agent(MAX_HEAP_BYTES);
+    assert!(evaluate_script(
+        &mut agent,
+        "let leak = [];
+        let caught = false;
+        try {
+            while (true) leak.push(new Array(1000));
+        } catch (err) {
+            caught = err instanceof RangeError && err.message === 'Out of memory';
+        }
+        leak = null;
+        caught",
+    ));
+    agent.gc();
+    assert!(agent.heap_statistics().total_bytes() < MAX_HEAP_BYTES);
+    // The heap is usable again once the leaking data is released.
+    assert!(evaluate_script(
+        &mut agent,
+        "const values = [];
+        for (let i = 0; i < 100; i++) values.push({ i });
+        values.length === 100",
+    ));
+}
+
+#[test]
+fn catch_handler_recovers_from_out_of_memory() {
+    let mut agent = limited_agent(8 * 1024 * 1024);
+    assert!(evaluate_script(
+        &mut agent,
+        "let list = null;
+        let caught = false;
+        try {
+            while (true) list = { next: list };
+        } catch (err) {
+            list = null;
+            caught = err instanceof RangeError;
+        }
+        // The list is collected without returning to the host.
+        const values = [];
+        for (let i = 0; i < 10000; i++) values.push({ i });
+        caught && values.length === 10000",
+    ));
+}
+
+#[test]
+fn large_allocations_throw_range_error() {
+    let mut agent = limited_agent(MAX_HEAP_BYTES);
+    assert!(evaluate_script(
+        &mut agent,
+        "function throwsRangeError(f) {
+            try {
+                f();
+            } catch (err) {
+                return err instanceof RangeError;
+            }
+            return false;
+        }
+        throwsRangeError(() => new ArrayBuffer(2 * 1024 * 1024)) &&
+            throwsRangeError(() => new ArrayBuffer(0, { maxByteLength: 2 * 1024 * 1024 })) &&
+            throwsRangeError(() => 'abcd'.repeat(64 * 1024 * 1024)) &&
+            throwsRangeError(() => new Array(2 ** 32 - 1)) &&
+            throwsRangeError(() => { [].length = 2 ** 32 - 1; }) &&
+            throwsRangeError(() => { [][2 ** 32 - 2] = 1; }) &&
+            new ArrayBuffer(1024).byteLength === 1024",
+    ));
+}
@@ -0,0 +1,85 @@
+mod common;
+
+use common::create_realm;
+use nova_vm::ecmascript::execution::{
+    agent::{GcAgent, Options},
+    DefaultHostHooks,
+};
+
+fn evaluate_script(agent: &mut GcAgent, source: &'static str) {
+    let realm = create_realm(agent);
+    common::evaluate_script(agent, &realm, source, |_, result| {
+        result.unwrap();
+    });
+}
+
+#[test]
+fn heap_statistics() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    evaluate_script(&mut agent, "");
+    agent.gc();
+    let before = agent.heap_statistics().get("objects").unwrap();
+
+    evaluate_script(
+        &mut agent,
+        "globalThis.leak = []; for (let i = 0; i < 100; i++) leak.push({ i });",
+    );
+    agent.gc();
+    let statistics = agent.heap_statistics();
+    let after = statistics.get("objects").unwrap();
+    assert!(after.count >= before.count + 100);
+    assert!(after.bytes > before.bytes);
+    assert!(statistics.get("arrays").unwrap().count > 0);
+    assert!(statistics.get("elements_2pow8").unwrap().count > 0);
+    assert_eq!(
+        statistics.total_bytes(),
+        statistics.iter().map(|(_, data)| data.bytes).sum::<usize>()
+    );
+}
+
+#[test]
+fn heap_snapshot() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    evaluate_script(
+        &mut agent,
+        "globalThis.leak = []; for (let i = 0; i < 10; i++) leak.push('leaky string ' + i);",
+    );
+    let mut snapshot = Vec::new();
+    agent.write_heap_snapshot(&mut snapshot).unwrap();
+    let snapshot = std::string::String::from_utf8(snapshot).unwrap();
+
+    assert!(snapshot.starts_with("{\"snapshot\":{\"meta\":{\"node_fields\":"));
+    assert!(snapshot.ends_with("]}\n"));
+    assert!(snapshot.contains("\"(GC roots)\""));
+    assert!(snapshot.contains("\"leaky string 7\""));
+
+    let count = |field: &str| -> usize {
+        let start = snapshot.find(field).unwrap() + field.len();
+        let end = start + snapshot[start..].find(',').unwrap();
+        snapshot[start..end].parse().unwrap()
+    };
+    let section = |name: &str| -> Vec<usize> {
+        let start = snapshot.find(name).unwrap() + name.len();
+        let end = start + snapshot[start..].find(']').unwrap();
+        snapshot[start..end]
+            .split([',', '\n'])
+            .filter(|value| !value.is_empty())
+            .map(|value| value.parse().unwrap())
+            .collect()
+    };
+    let node_count = count("\"node_count\":");
+    let edge_count = count("\"edge_count\":");
+    let nodes = section("\"nodes\":[");
+    let edges = section("\"edges\":[");
+    assert_eq!(nodes.len(), node_count * 7);
+    assert_eq!(edges.len(), edge_count * 3);
+    // Each node's edge count adds up to the total number of edges, and each
+    // edge points to the start of a node.
+    assert_eq!(
+        nodes.chunks(7).map(|node| node[4]).sum::<usize>(),
+        edge_count
+    );
+    assert!(edges
+        .chunks(3)
+        .all(|edge| edge[2] % 7 == 0 && edge[2] < nodes.len()));
+}
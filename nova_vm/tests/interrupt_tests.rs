@@ -0,0 +1,105 @@
+mod common;
+
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use common::create_realm;
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{GcAgent, InterruptHandle, Options, RealmRoot},
+        Agent, DefaultHostHooks,
+    },
+    types::Value,
+};
+
+/// Evaluates the source and returns true if it completed normally, and false
+/// if the evaluation was terminated.
+fn evaluate_script(agent: &mut GcAgent, realm: &RealmRoot, source: &'static str) -> bool {
+    common::evaluate_script(agent, realm, source, |agent, result| {
+        assert_eq!(result.is_err(), agent.is_terminating());
+        result.is_ok()
+    })
+}
+
+fn terminate_after(handle: InterruptHandle, duration: Duration) {
+    std::thread::spawn(move || {
+        std::thread::sleep(duration);
+        handle.terminate_execution();
+    });
+}
+
+#[test]
+fn terminate_infinite_loop() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    terminate_after(agent.interrupt_handle(), Duration::from_millis(50));
+    assert!(!evaluate_script(&mut agent, &realm, "while (true) {}"));
+
+    // The agent is usable again after the termination.
+    assert!(evaluate_script(
+        &mut agent,
+        &realm,
+        "globalThis.done = true;"
+    ));
+}
+
+#[test]
+fn termination_is_not_catchable() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    terminate_after(agent.interrupt_handle(), Duration::from_millis(50));
+    assert!(!evaluate_script(
+        &mut agent,
+        &realm,
+        "globalThis.caught = false;
+        function spin() { while (true) {} }
+        while (true) {
+            try { spin(); } catch { caught = true; }
+        }"
+    ));
+
+    common::evaluate_script(&mut agent, &realm, "caught", |_, result| {
+        assert_eq!(result.unwrap(), Value::Boolean(false));
+    });
+}
+
+#[test]
+fn terminate_before_execution() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    let handle = agent.interrupt_handle();
+    handle.terminate_execution();
+    handle.cancel_termination();
+    assert!(evaluate_script(&mut agent, &realm, "1 + 1"));
+    handle.terminate_execution();
+    assert!(!evaluate_script(&mut agent, &realm, "1 + 1"));
+    assert!(evaluate_script(&mut agent, &realm, "1 + 1"));
+}
+
+#[test]
+fn request_interrupt() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    let handle = agent.interrupt_handle();
+    let interrupted = Arc::new(AtomicBool::new(false));
+    {
+        let interrupted = interrupted.clone();
+        let handle = handle.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            let terminate = handle.clone();
+            handle.request_interrupt(move |agent: &mut Agent| {
+                assert!(!agent.is_terminating());
+                interrupted.store(true, Ordering::Release);
+                terminate.terminate_execution();
+            });
+        });
+    }
+    assert!(!evaluate_script(&mut agent, &realm, "while (true) {}"));
+    assert!(interrupted.load(Ordering::Acquire));
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::create_realm;
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{GcAgent, Options, RealmRoot},
+        DefaultHostHooks,
+    },
+    types::Value,
+};
+
+/// Evaluates the source, which must evaluate to a boolean, in the Realm.
+fn evaluate(agent: &mut GcAgent, realm: &RealmRoot, source: &'static str) -> bool {
+    common::evaluate_script(agent, realm, source, |_, result| match result.unwrap() {
+        Value::Boolean(result) => result,
+        result => panic!("expected a boolean, got {:?}", result),
+    })
+}
+
+fn shape_count(agent: &GcAgent) -> usize {
+    agent.heap_statistics().get("object_shapes").unwrap().count
+}
+
+#[test]
+fn objects_with_the_same_keys_share_shapes() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    evaluate(&mut agent, &realm, "globalThis.points = []; true");
+    agent.gc();
+    let before = shape_count(&agent);
+    assert!(evaluate(
+        &mut agent,
+        &realm,
+        "for (let i = 0; i < 100; i++) points.push({ x: i, y: -i }); points[99].y === -99"
+    ));
+    agent.gc();
+    // The shapes { x } and { x, y }.
+    assert_eq!(shape_count(&agent), before + 2);
+}
+
+#[test]
+fn unused_shapes_are_collected() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    evaluate(&mut agent, &realm, "globalThis.kept = { a: 1, b: 2 }; true");
+    agent.gc();
+    let before = shape_count(&agent);
+    assert!(evaluate(
+        &mut agent,
+        &realm,
+        "for (let i = 0; i < 1000; i++) { const o = {}; o['key' + i] = i; o.b = i; } true"
+    ));
+    assert!(shape_count(&agent) >= before + 2000);
+    agent.gc();
+    assert_eq!(shape_count(&agent), before);
+}
+
+#[test]
+fn transitions_survive_collection_of_sibling_shapes() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    evaluate(
+        &mut agent,
+        &realm,
+        "globalThis.kept = { a: 1, b: 2, c: 3 }; for (let i = 0; i < 100; i++) ({ a: 1, ['k' + i]: 2 }); true",
+    );
+    agent.gc();
+    let before = shape_count(&agent);
+    // New objects with the kept keys reuse the kept Shapes, and property
+    // lookups through them still find the right values.
+    assert!(evaluate(
+        &mut agent,
+        &realm,
+        "const o = { a: 4, b: 5, c: 6 }; o.a + o.b + o.c === 15 && kept.c === 3"
+    ));
+    agent.gc();
+    assert_eq!(shape_count(&agent), before);
+}
+
+#[test]
+fn dictionary_shapes_survive_collection() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    evaluate(
+        &mut agent,
+        &realm,
+        "globalThis.dict = { a: 1, b: 2, c: 3 }; delete dict.a; true",
+    );
+    agent.gc();
+    assert!(evaluate(
+        &mut agent,
+        &realm,
+        "dict.d = 4; delete dict.b; Object.keys(dict).join() === 'c,d' && dict.c + dict.d === 7"
+    ));
+    agent.gc();
+    assert!(evaluate(
+        &mut agent,
+        &realm,
+        "Object.keys(dict).join() === 'c,d' && dict.d === 4"
+    ));
+}
@@ -0,0 +1,155 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{create_realm, parse_source};
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{CpuProfile, GcAgent, Options, SampleInterval},
+        DefaultHostHooks,
+    },
+    scripts_and_modules::script::script_evaluation,
+};
+
+const SOURCE: &str = "function hot(n) {
+    let sum = 0;
+    for (let i = 0; i < n; i++) {
+        sum += i;
+    }
+    return sum;
+}
+function cold() {
+    return 1;
+}
+function main() {
+    let result = cold();
+    for (let i = 0; i < 20; i++) {
+        result += [100].map(hot)[0];
+    }
+    return result;
+}
+main();";
+
+/// Evaluates the source while profiling with the interval.
+fn profile_script(source: &'static str, interval: SampleInterval) -> CpuProfile {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    agent.start_profiling(interval);
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let script = parse_source(agent, source, gc.nogc());
+        agent.set_profiled_script_url(&script, "file:///profiled.js");
+        script_evaluation(agent, script, gc.reborrow()).unwrap();
+    });
+    // Collecting garbage during profiling keeps the profile intact.
+    agent.gc();
+    agent.stop_profiling().unwrap()
+}
+
+fn node_path(profile: &CpuProfile, mut index: usize) -> Vec<&str> {
+    let mut path = vec![];
+    while let Some(parent) = profile.nodes[index].parent {
+        path.push(profile.nodes[index].function_name.as_str());
+        index = parent;
+    }
+    path.reverse();
+    path
+}
+
+#[test]
+fn instruction_sampling_profile() {
+    let profile = profile_script(SOURCE, SampleInterval::Instructions(10));
+    assert_eq!(profile.nodes[0].function_name, "(root)");
+    assert!(profile.samples.len() > 100);
+    assert_eq!(
+        profile.nodes.iter().map(|node| node.hit_count).sum::<u32>() as usize,
+        profile.samples.len()
+    );
+
+    let hot = profile
+        .nodes
+        .iter()
+        .position(|node| node.function_name == "hot")
+        .unwrap();
+    assert_eq!(node_path(&profile, hot), ["", "main", "map", "hot"]);
+    let hot = &profile.nodes[hot];
+    assert_eq!(hot.url, "file:///profiled.js");
+    assert_eq!((hot.line, hot.column), (1, 1));
+    // The loop dominates the function's samples.
+    let loop_ticks: u32 = hot
+        .line_ticks
+        .iter()
+        .filter(|(line, _)| (3..=4).contains(line))
+        .map(|(_, ticks)| ticks)
+        .sum();
+    assert!(loop_ticks * 2 > hot.hit_count);
+    // The hot function dominates the profile.
+    assert!(hot.hit_count as usize * 2 > profile.samples.len());
+
+    let map = profile
+        .nodes
+        .iter()
+        .find(|node| node.function_name == "map")
+        .unwrap();
+    assert_eq!(map.script_id, None);
+
+    // Instruction sampling is deterministic.
+    let again = profile_script(SOURCE, SampleInterval::Instructions(10));
+    assert_eq!(again.samples.len(), profile.samples.len());
+}
+
+#[test]
+fn profile_formats() {
+    let profile = profile_script(SOURCE, SampleInterval::Instructions(10));
+
+    let mut collapsed = Vec::new();
+    profile.write_collapsed_stacks(&mut collapsed).unwrap();
+    let collapsed = std::string::String::from_utf8(collapsed).unwrap();
+    let hot_stack = "<anonymous> (file:///profiled.js:1:1);main (file:///profiled.js:11:1);map;hot (file:///profiled.js:1:1) ";
+    let hot_line = collapsed
+        .lines()
+        .find(|line| line.starts_with(hot_stack))
+        .unwrap();
+    let count: u32 = hot_line[hot_stack.len()..].parse().unwrap();
+    assert!(count > 0);
+    let total: usize = collapsed
+        .lines()
+        .map(|line| line.rsplit_once(' ').unwrap().1.parse::<usize>().unwrap())
+        .sum();
+    assert_eq!(total, profile.samples.len());
+
+    let mut cpuprofile = Vec::new();
+    profile.write_cpuprofile(&mut cpuprofile).unwrap();
+    let cpuprofile = std::string::String::from_utf8(cpuprofile).unwrap();
+    assert!(cpuprofile.starts_with(
+        "{\"nodes\":[{\"id\":1,\"callFrame\":{\"functionName\":\"(root)\",\"scriptId\":\"0\",\"url\":\"\",\"lineNumber\":-1,\"columnNumber\":-1}"
+    ));
+    assert!(cpuprofile.contains(
+        "\"callFrame\":{\"functionName\":\"hot\",\"scriptId\":\"1\",\"url\":\"file:///profiled.js\",\"lineNumber\":0,\"columnNumber\":0}"
+    ));
+    assert!(cpuprofile.contains("\"samples\":["));
+    assert!(cpuprofile.contains("\"timeDeltas\":["));
+    assert!(cpuprofile.ends_with("]}\n"));
+}
+
+#[test]
+fn time_sampling_profile() {
+    let profile = profile_script(
+        "function spin() {
+            let x = 0;
+            for (let i = 0; i < 300000; i++) x += i;
+            return x;
+        }
+        spin();",
+        SampleInterval::Time(Duration::from_micros(100)),
+    );
+    assert!(!profile.samples.is_empty());
+    assert!(profile
+        .samples
+        .windows(2)
+        .all(|samples| samples[0].timestamp <= samples[1].timestamp));
+    assert!(profile.samples.last().unwrap().timestamp <= profile.duration);
+    assert!(profile
+        .nodes
+        .iter()
+        .any(|node| node.function_name == "spin" && node.hit_count > 0));
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use common::create_realm;
+use nova_vm::ecmascript::{
+    execution::{
+        agent::{GcAgent, Options},
+        DefaultHostHooks,
+    },
+    types::String,
+};
+
+/// Evaluates the source, which must evaluate to a string, and returns the
+/// string.
+fn evaluate(source: &'static str) -> std::string::String {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    common::evaluate_script(&mut agent, &realm, source, |agent, result| {
+        let result = result.unwrap();
+        let Ok(result) = String::try_from(result) else {
+            panic!("expected a string, got {:?}", result);
+        };
+        result.as_str(agent).to_owned()
+    })
+}
+
+#[test]
+fn cached_loads_see_changed_shapes() {
+    assert_eq!(
+        evaluate(
+            "const a = { x: 'a' }; const b = { y: 0, x: 'b' };
+            function f(p) { return p.x; }
+            let result = '';
+            for (let i = 0; i < 3; i++) result += f(a) + f(b);
+            a.z = 1;
+            delete b.y;
+            result + f(a) + f(b);"
+        ),
+        "abababab"
+    );
+}
+
+#[test]
+fn cached_loads_see_deletions_from_dictionary_objects() {
+    assert_eq!(
+        evaluate(
+            "const o = {};
+            for (let i = 0; i < 40; i++) o['k' + i] = i;
+            o.x = 'X';
+            o.y = 'Y';
+            function f(p) { return p.x; }
+            for (let i = 0; i < 10; i++) f(o);
+            delete o.k0;
+            f(o);"
+        ),
+        "X"
+    );
+}
+
+#[test]
+fn cached_prototype_loads_see_shadowing_properties() {
+    assert_eq!(
+        evaluate(
+            "const proto = { m: 'proto' };
+            const o = Object.create(proto);
+            for (let i = 0; i < 40; i++) o['k' + i] = i;
+            function f(p) { return p.m; }
+            let result = '';
+            for (let i = 0; i < 3; i++) result = f(o);
+            o.m = 'own';
+            result + ' ' + f(o);"
+        ),
+        "proto own"
+    );
+}
+
+#[test]
+fn cached_prototype_loads_see_deletions_from_dictionary_prototypes() {
+    assert_eq!(
+        evaluate(
+            "const proto = {};
+            for (let i = 0; i < 40; i++) proto['k' + i] = i;
+            proto.m = 'M';
+            proto.n = 'N';
+            const o = Object.create(proto);
+            function f(p) { return p.m; }
+            for (let i = 0; i < 10; i++) f(o);
+            delete proto.k0;
+            f(o);"
+        ),
+        "M"
+    );
+}
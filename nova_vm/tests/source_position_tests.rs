@@ -0,0 +1,72 @@
+mod common;
+
+use common::{create_realm, parse_source};
+use nova_vm::ecmascript::{
+    execution::{agent::GcAgent, agent::Options, DefaultHostHooks},
+    scripts_and_modules::script::script_evaluation,
+};
+
+/// Evaluates the source, which must throw an Error, and returns the line and
+/// column of each call site in the Error's stack.
+fn thrown_error_positions(source: &'static str) -> Vec<Option<(u32, u32)>> {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    common::evaluate_script(&mut agent, &realm, source, |agent, result| {
+        result
+            .unwrap_err()
+            .stack_frames(agent)
+            .iter()
+            .map(|frame| frame.line_and_column(agent))
+            .collect()
+    })
+}
+
+#[test]
+fn errors_point_at_the_faulting_expression() {
+    let positions = thrown_error_positions(
+        "function inner(x) {\n  return x.y.z;\n}\nfunction outer() {\n  return inner({});\n}\nouter();\n",
+    );
+    assert_eq!(positions, vec![Some((2, 10)), Some((5, 10)), Some((7, 1))]);
+}
+
+#[test]
+fn errors_thrown_by_builtins_point_at_the_call() {
+    let positions = thrown_error_positions("let a = 1;\n\nlet b = [].reduce((x, y) => x);\n");
+    // The innermost call site is the builtin itself.
+    assert_eq!(positions, vec![None, Some((3, 9))]);
+}
+
+#[test]
+fn throw_statements_point_at_the_thrown_expression() {
+    let positions = thrown_error_positions(
+        "function f() {\n  if (true) {\n    throw new Error('f');\n  }\n}\nf();\n",
+    );
+    assert_eq!(positions[0], Some((3, 11)));
+    assert_eq!(positions[1], Some((6, 1)));
+}
+
+#[test]
+fn stack_traces_name_the_script() {
+    let mut agent = GcAgent::new(Options::default(), &DefaultHostHooks);
+    let realm = create_realm(&mut agent);
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let script = parse_source(
+            agent,
+            "function f() {\n  throw new Error('f');\n}\nf();\n",
+            gc.nogc(),
+        );
+        script.set_name(agent, "file:///named.js");
+        let error = script_evaluation(agent, script, gc.reborrow()).unwrap_err();
+        let frames = error.stack_frames(agent);
+        assert_eq!(frames[0].script_name(agent), Some("file:///named.js"));
+        assert_eq!(
+            frames[0].script_or_module().unwrap().name(agent),
+            Some("file:///named.js")
+        );
+        let stack = error.stack(agent, gc.nogc()).unwrap();
+        assert_eq!(
+            stack,
+            "Error: f\n    at f (file:///named.js:2:9)\n    at file:///named.js:4:1"
+        );
+    });
+}
@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+var fromAsyncDone = false;
+var fromAsyncError = undefined;
+
+function assertEquals(actual, expected, message) {
+  if (actual !== expected) {
+    throw new Error(`${message}: expected ${expected}, got ${actual}`);
+  }
+}
+
+async function assertRejects(errorType, fn, message) {
+  try {
+    await fn();
+  } catch (err) {
+    if (err instanceof errorType) {
+      return;
+    }
+    throw new Error(`${message}: expected ${errorType.name}, got ${err}`);
+  }
+  throw new Error(`${message}: expected ${errorType.name}`);
+}
+
+function asyncIterable(values) {
+  const state = { index: 0, closed: false };
+  state[Symbol.asyncIterator] = () => ({
+    next() {
+      return Promise.resolve(
+        state.index < values.length
+          ? { value: values[state.index++], done: false }
+          : { value: undefined, done: true },
+      );
+    },
+    return() {
+      state.closed = true;
+      return Promise.resolve({ value: undefined, done: true });
+    },
+  });
+  return state;
+}
+
+async function test() {
+  assertEquals(Array.fromAsync.length, 1, "length");
+  assertEquals(Array.fromAsync.name, "fromAsync", "name");
+  assertEquals(Array.fromAsync([]) instanceof Promise, true, "returns promise");
+
+  let result = await Array.fromAsync([1, Promise.resolve(2), 3]);
+  assertEquals(result.join(), "1,2,3", "sync iterable");
+
+  const promise = Promise.resolve(2);
+  result = await Array.fromAsync(asyncIterable([1, promise]));
+  assertEquals(result[1], promise, "async iterable values are not awaited");
+
+  result = await Array.fromAsync({ length: 3, 0: 1, 1: Promise.resolve(2) });
+  assertEquals(result.join(), "1,2,", "array-like");
+
+  result = await Array.fromAsync([1, 2], async (value, index) => value + index);
+  assertEquals(result.join(), "1,3", "async mapping function");
+
+  let thenCalls = 0;
+  const thenable = {
+    then(resolve) {
+      thenCalls++;
+      resolve(7);
+    },
+  };
+  result = await Array.fromAsync([thenable], (value) => value * 2);
+  assertEquals(result[0], 14, "thenable element");
+  assertEquals(thenCalls, 1, "thenable element is awaited once");
+
+  function Constructor(length) {
+    this.args = arguments.length;
+  }
+  result = await Array.fromAsync.call(Constructor, { length: 1, 0: "a" });
+  assertEquals(result instanceof Constructor, true, "this constructor");
+  assertEquals(result.args, 1, "array-like constructed with length");
+  assertEquals(result.length, 1, "length is set");
+  result = await Array.fromAsync.call({}, [1]);
+  assertEquals(Array.isArray(result), true, "non-constructor this");
+
+  const iterable = asyncIterable([1, 2]);
+  await assertRejects(
+    RangeError,
+    () =>
+      Array.fromAsync(iterable, () => {
+        throw new RangeError();
+      }),
+    "mapping function throws",
+  );
+  assertEquals(iterable.closed, true, "async iterator is closed");
+
+  await assertRejects(TypeError, () => Array.fromAsync(null), "null items");
+  await assertRejects(
+    TypeError,
+    () => Array.fromAsync([], {}),
+    "non-callable mapping function",
+  );
+  await assertRejects(
+    RangeError,
+    () => Array.fromAsync.call({}, { length: 2 ** 32 }),
+    "array-like too long",
+  );
+}
+
+test().then(
+  () => {
+    fromAsyncDone = true;
+  },
+  (err) => {
+    fromAsyncError = err;
+  },
+);
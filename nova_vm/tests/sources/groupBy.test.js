@@ -0,0 +1,66 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEquals(actual, expected, message) {
+  if (actual !== expected) {
+    throw new Error(`${message}: expected ${expected}, got ${actual}`);
+  }
+}
+
+assertEquals(Object.groupBy.length, 2, "Object.groupBy.length");
+assertEquals(Map.groupBy.length, 2, "Map.groupBy.length");
+
+const words = ["hello", "test", "world"];
+const byLength = Map.groupBy(words, (word) => word.length);
+assertEquals([...byLength.keys()].join(), "5,4", "Map.groupBy keys");
+assertEquals(byLength.get(5).join(), "hello,world", "Map.groupBy group");
+
+const zeros = Map.groupBy([1, 2], () => -0);
+assertEquals(Object.is([...zeros.keys()][0], 0), true, "Map.groupBy -0 key");
+assertEquals(zeros.get(0).length, 2, "Map.groupBy -0 group");
+
+const objectKey = {};
+assertEquals(
+  Map.groupBy([1], () => objectKey).get(objectKey).length,
+  1,
+  "Map.groupBy object key",
+);
+
+const parity = Object.groupBy([1, 2, 3, 4], (value, index) =>
+  index % 2 ? "odd" : "even",
+);
+assertEquals(Object.getPrototypeOf(parity), null, "Object.groupBy prototype");
+assertEquals(parity.even.join(), "1,3", "Object.groupBy even");
+assertEquals(parity.odd.join(), "2,4", "Object.groupBy odd");
+
+// The dense Array fast path must observe mutation during iteration.
+let array = [1, 2, 3, 4];
+let groups = Object.groupBy(array, (value) => {
+  if (value === 1) {
+    array.pop();
+  }
+  return "k";
+});
+assertEquals(groups.k.join(), "1,2,3", "shrunk during grouping");
+array = [1, 2, 3];
+groups = Object.groupBy(array, (value) => {
+  if (value === 1) {
+    array.push(4);
+    array[1] = 5;
+  }
+  return "k";
+});
+assertEquals(groups.k.join(), "1,5,3,4", "grown during grouping");
+array = [1, , 3];
+Object.defineProperty(array, 2, { get: () => 6 });
+groups = Map.groupBy(array, () => "k");
+assertEquals(groups.get("k").join(), "1,,6", "holes and accessors");
+
+const values = Array.prototype[Symbol.iterator];
+Array.prototype[Symbol.iterator] = function* () {
+  yield "patched";
+};
+groups = Object.groupBy([1, 2], () => "k");
+Array.prototype[Symbol.iterator] = values;
+assertEquals(groups.k.join(), "patched", "patched Array iterator");
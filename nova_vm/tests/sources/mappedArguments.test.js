@@ -0,0 +1,101 @@
+function assert(condition, message) {
+  if (!condition) {
+    throw new Error(message);
+  }
+}
+
+function aliasesParameters(a, b) {
+  arguments[0] = 10;
+  b = 20;
+  return [a, arguments[1], arguments.length, arguments.callee];
+}
+var result = aliasesParameters(1, 2);
+assert(result[0] === 10, "writing to arguments should update the parameter");
+assert(result[1] === 20, "writing to the parameter should update arguments");
+assert(result[2] === 2, "length should be the number of arguments");
+assert(result[3] === aliasesParameters, "callee should be the function");
+
+function deleteBreaksMapping(a) {
+  delete arguments[0];
+  arguments[0] = 5;
+  return a;
+}
+assert(deleteBreaksMapping(1) === 1, "delete should unmap the index");
+
+function nonWritableBreaksMapping(a) {
+  Object.defineProperty(arguments, "0", { writable: false });
+  a = 3;
+  return arguments[0];
+}
+assert(nonWritableBreaksMapping(1) === 1, "making the index non-writable should unmap it");
+
+function missingArgumentsAreNotMapped(a, b) {
+  b = 7;
+  return arguments[1];
+}
+assert(missingArgumentsAreNotMapped(1) === undefined, "indexes past the arguments should not be mapped");
+
+function duplicateParametersMapTheLastOne(a, a) {
+  arguments[1] = 9;
+  return a;
+}
+assert(duplicateParametersMapTheLastOne(1, 2) === 9, "the last duplicate parameter should be mapped");
+
+function descriptorsReflectParameters(a) {
+  a = 8;
+  return Object.getOwnPropertyDescriptor(arguments, "0").value;
+}
+assert(descriptorsReflectParameters(1) === 8, "property descriptors should reflect the parameter");
+assert(
+  Object.prototype.toString.call((function () { return arguments; })()) === "[object Arguments]",
+  "mapped arguments should be tagged as Arguments"
+);
+
+function closuresSeeTheMapping(a) {
+  (function () { a = 3; })();
+  return arguments[0];
+}
+assert(closuresSeeTheMapping(1) === 3, "parameters assigned in closures should update arguments");
+
+function evalSeesTheMapping(a) {
+  eval("arguments[0] = 6");
+  return a;
+}
+assert(evalSeesTheMapping(1) === 6, "arguments written in eval should update the parameter");
+
+function strictIsUnmapped(a) {
+  "use strict";
+  arguments[0] = 2;
+  return a;
+}
+assert(strictIsUnmapped(1) === 1, "strict functions should not map arguments");
+
+function nonSimpleIsUnmapped(a = 0) {
+  arguments[0] = 2;
+  return a;
+}
+assert(nonSimpleIsUnmapped(1) === 1, "functions with non-simple parameters should not map arguments");
+
+function nonConfigurableKeepsMapping(a) {
+  Object.defineProperty(arguments, "0", { configurable: false });
+  a = 2;
+  var aliased = arguments[0];
+  var deleted = delete arguments[0];
+  arguments[0] = 3;
+  return [aliased, deleted, a];
+}
+result = nonConfigurableKeepsMapping(1);
+assert(result[0] === 2, "non-configurable indexes should stay mapped");
+assert(result[1] === false, "non-configurable indexes should not be deletable");
+assert(result[2] === 3, "non-configurable indexes should still write to the parameter");
+
+function nonConfigurableDefineValue(a) {
+  Object.defineProperty(arguments, "0", { configurable: false });
+  Object.defineProperty(arguments, "0", { value: 2, writable: false });
+  var defined = a;
+  a = 3;
+  return [defined, arguments[0]];
+}
+result = nonConfigurableDefineValue(1);
+assert(result[0] === 2, "defining a value should write it to the parameter before unmapping");
+assert(result[1] === 2, "defining the index non-writable should unmap it");
@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEquals(actual, expected, message) {
+  if (actual !== expected) {
+    throw new Error(`${message}: expected ${expected}, got ${actual}`);
+  }
+}
+
+function assertThrowsRangeError(fn, message) {
+  try {
+    fn();
+  } catch (err) {
+    if (err instanceof RangeError) {
+      return;
+    }
+    throw new Error(`${message}: expected RangeError, got ${err}`);
+  }
+  throw new Error(`${message}: expected RangeError`);
+}
+
+assertEquals((255).toString(16), "ff", "Number in radix 16");
+assertEquals((-255).toString(2), "-11111111", "negative Number in radix 2");
+assertEquals((35).toString(36), "z", "Number in radix 36");
+assertEquals((0.5).toString(2), "0.1", "fraction in radix 2");
+assertEquals((-0.125).toString(8), "-0.1", "negative fraction in radix 8");
+assertEquals((2 ** 53).toString(36), "2gosa7pa2gw", "large Number in radix 36");
+assertEquals((-0).toString(2), "0", "negative zero");
+assertEquals(NaN.toString(2), "NaN", "NaN");
+assertEquals((-Infinity).toString(16), "-Infinity", "negative infinity");
+assertEquals((10).toString(undefined), "10", "default radix");
+assertEquals((10).toString(2.9), "1010", "fractional radix");
+
+assertEquals((255n).toString(16), "ff", "BigInt in radix 16");
+assertEquals((-255n).toString(2), "-11111111", "negative BigInt in radix 2");
+assertEquals(
+  (2n ** 64n).toString(36),
+  "3w5e11264sgsg",
+  "large BigInt in radix 36",
+);
+assertEquals((0n).toString(7), "0", "BigInt zero");
+assertEquals((10n).toString(), "10", "BigInt default radix");
+
+for (const radix of [0, 1, 37, null, Infinity]) {
+  assertThrowsRangeError(() => (10).toString(radix), `Number radix ${radix}`);
+  assertThrowsRangeError(() => (10n).toString(radix), `BigInt radix ${radix}`);
+}
@@ -0,0 +1,94 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEquals(actual, expected, message) {
+  if (actual !== expected) {
+    throw new Error(`${message}: expected ${expected}, got ${actual}`);
+  }
+}
+
+function assertThrowsTypeError(fn, message) {
+  try {
+    fn();
+  } catch (err) {
+    if (err instanceof TypeError) {
+      return;
+    }
+    throw new Error(`${message}: expected TypeError, got ${err}`);
+  }
+  throw new Error(`${message}: expected TypeError`);
+}
+
+assertEquals(
+  Object.getPrototypeOf(new BigUint64Array(1)),
+  BigUint64Array.prototype,
+  "BigUint64Array prototype",
+);
+assertEquals(
+  Object.getPrototypeOf(BigUint64Array.prototype),
+  Object.getPrototypeOf(Int8Array.prototype),
+  "BigUint64Array.prototype prototype",
+);
+
+// Length-tracking views follow a resizable buffer through zero length.
+const rab = new ArrayBuffer(4, { maxByteLength: 16 });
+const tracking = new Uint16Array(rab);
+rab.resize(0);
+assertEquals(tracking.length, 0, "shrunk to zero");
+rab.resize(8);
+assertEquals(tracking.length, 4, "grown from zero");
+assertEquals(tracking.join(), "0,0,0,0", "grown from zero is zeroed");
+tracking.set([1, 2, 3, 4]);
+assertEquals([...tracking].join(), "1,2,3,4", "iterates by element size");
+assertEquals([...tracking.entries()].join(";"), "0,1;1,2;2,3;3,4", "entries");
+
+// An explicit undefined length is length-tracking too.
+const explicit = new Uint16Array(rab, 2, undefined);
+rab.resize(10);
+assertEquals(explicit.length, 4, "explicit undefined length tracks");
+assertEquals(tracking.subarray(1).length, 4, "subarray tracks");
+rab.resize(8);
+assertEquals(tracking.subarray(1).length, 3, "subarray tracks after shrink");
+
+// Callbacks that shrink the buffer.
+const shrinking = {
+  toString() {
+    rab.resize(2);
+    return "-";
+  },
+};
+assertEquals(tracking.join(shrinking), "1---", "join after shrink");
+rab.resize(8);
+tracking.set([5, 6, 7, 8]);
+class Shrink extends Uint16Array {
+  static get [Symbol.species]() {
+    return function (length) {
+      rab.resize(4);
+      return new Uint16Array(length);
+    };
+  }
+}
+const shrinkable = new Shrink(rab, 2);
+assertEquals(shrinkable.slice(1).join(), "0,0", "slice past shrunk end");
+
+assertEquals(
+  new Int8Array(new BigInt64Array([1n, -1n]).buffer).length,
+  16,
+  "Int8Array over BigInt64Array buffer",
+);
+assertThrowsTypeError(
+  () => new BigInt64Array(new Int8Array(1)),
+  "BigInt64Array from Int8Array",
+);
+assertThrowsTypeError(
+  () => new Int8Array(new BigInt64Array(1)),
+  "Int8Array from BigInt64Array",
+);
+
+assertEquals(Int8Array.of(1, 2, 3).join(), "1,2,3", "of");
+assertEquals(
+  Float64Array.from([1, 2], (v, k) => v * 10 + k).join(),
+  "10,21",
+  "from with mapper",
+);
@@ -0,0 +1,63 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+function assertEquals(actual, expected, message) {
+  if (actual !== expected) {
+    throw new Error(`${message}: expected ${expected}, got ${actual}`);
+  }
+}
+
+function assertThrowsURIError(fn, message) {
+  try {
+    fn();
+  } catch (err) {
+    if (err instanceof URIError) {
+      return;
+    }
+    throw new Error(`${message}: expected URIError, got ${err}`);
+  }
+  throw new Error(`${message}: expected URIError`);
+}
+
+const uri = "http://example.com/a b?q=ä&r=#€";
+assertEquals(
+  encodeURI(uri),
+  "http://example.com/a%20b?q=%C3%A4&r=#%E2%82%AC",
+  "encodeURI",
+);
+assertEquals(
+  encodeURIComponent(uri),
+  "http%3A%2F%2Fexample.com%2Fa%20b%3Fq%3D%C3%A4%26r%3D%23%E2%82%AC",
+  "encodeURIComponent",
+);
+assertEquals(encodeURI("😀"), "%F0%9F%98%80", "encodeURI of a surrogate pair");
+assertEquals(decodeURI(encodeURI(uri)), uri, "decodeURI");
+assertEquals(
+  decodeURIComponent(encodeURIComponent(uri)),
+  uri,
+  "decodeURIComponent",
+);
+assertEquals(
+  decodeURI("%23%24%26%2B%2C%2F%3A%3B%3D%3F%40%25"),
+  "%23%24%26%2B%2C%2F%3A%3B%3D%3F%40%",
+  "decodeURI keeps reserved characters escaped",
+);
+assertEquals(
+  decodeURIComponent("%23%24%26%2b"),
+  "#$&+",
+  "decodeURIComponent decodes reserved characters",
+);
+assertEquals(decodeURI("%f0%9f%98%80"), "😀", "decodeURI of four octets");
+
+assertThrowsURIError(() => decodeURI("%"), "truncated escape");
+assertThrowsURIError(() => decodeURI("%G0"), "invalid hex digit");
+assertThrowsURIError(() => decodeURI("%80"), "continuation octet first");
+assertThrowsURIError(() => decodeURI("%C0%80"), "overlong encoding");
+assertThrowsURIError(() => decodeURI("%E0%A0"), "truncated sequence");
+assertThrowsURIError(() => decodeURI("%ED%A0%80"), "encoded surrogate");
+assertThrowsURIError(() => decodeURI("%F4%90%80%80"), "code point too large");
+
+assertEquals(escape("a b+ä€"), "a%20b+%E4%u20AC", "escape");
+assertEquals(unescape("a%20b+%E4%u20AC"), "a b+ä€", "unescape");
+assertEquals(unescape("%u20%zz%"), "%u20%zz%", "unescape keeps invalid escapes");
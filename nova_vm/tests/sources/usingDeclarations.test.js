@@ -0,0 +1,147 @@
+function assert(condition, message) {
+  if (!condition) {
+    throw new Error(message);
+  }
+}
+
+var log = [];
+function resource(name, fail) {
+  return {
+    [Symbol.dispose]() {
+      log.push(name);
+      if (fail) {
+        throw new Error(name);
+      }
+    },
+  };
+}
+
+{
+  using a = resource("a");
+  using b = resource("b");
+  log.push("body");
+}
+assert(log.join() === "body,b,a", "resources should be disposed in reverse order");
+
+log = [];
+{
+  using a = null, b = undefined;
+}
+assert(log.length === 0, "null and undefined should be allowed");
+
+var caught;
+try {
+  using bad = {};
+} catch (e) {
+  caught = e;
+}
+assert(caught instanceof TypeError, "values without @@dispose should throw");
+
+log = [];
+caught = undefined;
+try {
+  using a = resource("a", true);
+  using b = resource("b");
+  throw new Error("body");
+} catch (e) {
+  caught = e;
+}
+assert(log.join() === "b,a", "resources should be disposed when the block throws");
+assert(caught instanceof SuppressedError, "dispose errors should suppress the thrown value");
+assert(caught.error.message === "a", "the dispose error should be the error");
+assert(caught.suppressed.message === "body", "the thrown value should be suppressed");
+
+log = [];
+caught = undefined;
+try {
+  using a = resource("a", true);
+  using b = resource("b", true);
+} catch (e) {
+  caught = e;
+}
+assert(caught instanceof SuppressedError, "dispose errors should be chained");
+assert(caught.error.message === "a", "the last dispose error should be the error");
+assert(caught.suppressed.message === "b", "earlier dispose errors should be suppressed");
+
+log = [];
+function returns() {
+  using a = resource("a");
+  {
+    using b = resource("b");
+    return "value";
+  }
+}
+assert(returns() === "value", "the return value should be kept");
+assert(log.join() === "b,a", "return should dispose all scopes");
+
+log = [];
+for (let i = 0; i < 3; i++) {
+  using a = resource("loop" + i);
+  if (i === 0) {
+    continue;
+  }
+  if (i === 1) {
+    break;
+  }
+}
+assert(log.join() === "loop0,loop1", "break and continue should dispose the loop body");
+
+log = [];
+for (using a = resource("head"), i = { [Symbol.dispose]() {}, n: 0 }; i.n < 2; i.n++) {
+  log.push("body");
+}
+assert(log.join() === "body,body,head", "for head resources should be disposed after the loop");
+
+log = [];
+for (using x of [resource("x1"), resource("x2")]) {
+  log.push("body");
+}
+assert(log.join() === "body,x1,body,x2", "for-of resources should be disposed per iteration");
+
+log = [];
+switch (1) {
+  case 1:
+    using s = resource("s");
+    log.push("case");
+    break;
+}
+assert(log.join() === "case,s", "switch cases should dispose resources");
+
+log = [];
+var stack = new DisposableStack();
+stack.use(resource("use"));
+stack.defer(() => log.push("defer"));
+stack.adopt("value", (v) => log.push("adopt " + v));
+assert(stack.disposed === false, "a new stack should not be disposed");
+var moved = stack.move();
+assert(stack.disposed === true, "move should dispose the original stack");
+moved.dispose();
+assert(log.join() === "adopt value,defer,use", "stack resources should be disposed in reverse order");
+assert(moved.disposed === true, "dispose should mark the stack as disposed");
+moved.dispose();
+caught = undefined;
+try {
+  moved.defer(() => {});
+} catch (e) {
+  caught = e;
+}
+assert(caught instanceof ReferenceError, "a disposed stack should reject new resources");
+
+assert(
+  DisposableStack.prototype[Symbol.dispose] === DisposableStack.prototype.dispose,
+  "@@dispose should be dispose",
+);
+assert(
+  AsyncDisposableStack.prototype[Symbol.asyncDispose] ===
+    AsyncDisposableStack.prototype.disposeAsync,
+  "@@asyncDispose should be disposeAsync",
+);
+assert(
+  Object.prototype.toString.call(new AsyncDisposableStack()) === "[object AsyncDisposableStack]",
+  "AsyncDisposableStack should have a @@toStringTag",
+);
+
+var suppressed = new SuppressedError(1, 2, "message");
+assert(suppressed.error === 1 && suppressed.suppressed === 2, "SuppressedError should keep its values");
+assert(suppressed.message === "message", "SuppressedError should have a message");
+assert(suppressed instanceof Error, "SuppressedError should be an Error");
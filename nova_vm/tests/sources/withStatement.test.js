@@ -0,0 +1,171 @@
+function assert(condition, message) {
+  if (!condition) {
+    throw new Error(message);
+  }
+}
+
+var outer = "outer";
+var point = { x: 1, y: 2 };
+with (point) {
+  assert(x === 1, "properties should be visible as bindings");
+  assert(y === 2, "properties should be visible as bindings");
+  assert(outer === "outer", "outer bindings should be visible");
+  x = 10;
+}
+assert(point.x === 10, "assignment should write to the binding object");
+assert(typeof x === "undefined", "properties should not leak out of the with body");
+
+function readsLocals(obj) {
+  var a = "local";
+  var b = "local";
+  with (obj) {
+    return [a, b];
+  }
+}
+var locals = readsLocals({ a: "property" });
+assert(locals[0] === "property", "properties should shadow locals");
+assert(locals[1] === "local", "locals should be visible through the object");
+
+function varInsideWith(obj) {
+  with (obj) {
+    var v = 3;
+  }
+  return v;
+}
+var shadowing = { v: 1 };
+assert(varInsideWith(shadowing) === undefined, "var initializer should assign to the property");
+assert(shadowing.v === 3, "var initializer should assign to the property");
+assert(varInsideWith({}) === 3, "var initializer should assign to the local");
+
+function lexicalInsideWith(obj) {
+  with (obj) {
+    let l = 1;
+    l += 1;
+    return l;
+  }
+}
+assert(lexicalInsideWith({ l: 10 }) === 2, "lexical bindings should shadow properties");
+
+var unscopable = { hidden: "property", visible: "property" };
+unscopable[Symbol.unscopables] = { hidden: true };
+var hidden = "outer";
+with (unscopable) {
+  assert(hidden === "outer", "@@unscopables should hide properties");
+  assert(visible === "property", "other properties should be visible");
+}
+
+var receiver = {
+  value: 42,
+  method: function () {
+    return this;
+  },
+};
+with (receiver) {
+  assert(method() === receiver, "calls should use the binding object as this value");
+}
+
+with (1) {
+  assert(toFixed(1) === "1.0", "primitives should be converted to objects");
+}
+
+var threw = false;
+try {
+  with (undefined) {
+  }
+} catch (e) {
+  threw = e instanceof TypeError;
+}
+assert(threw, "undefined should throw a TypeError");
+
+var iterations = 0;
+var counter = { count: 0 };
+for (var i = 0; i < 5; i++) {
+  with (counter) {
+    count++;
+    if (count === 2) {
+      continue;
+    }
+    if (count === 4) {
+      break;
+    }
+  }
+  iterations++;
+}
+assert(counter.count === 4, "break should leave the loop");
+assert(iterations === 2, "continue should skip the rest of the iteration");
+assert(typeof count === "undefined", "break and continue should exit the with environment");
+
+function closureInsideWith(obj) {
+  with (obj) {
+    return function () {
+      return name;
+    };
+  }
+}
+var named = { name: "first" };
+var closure = closureInsideWith(named);
+named.name = "second";
+assert(closure() === "second", "closures should capture the object environment");
+
+var caught;
+var thrower = { message: "property" };
+try {
+  with (thrower) {
+    throw message;
+  }
+} catch (e) {
+  caught = e;
+}
+assert(caught === "property", "exceptions should propagate from the with body");
+assert(typeof message === "undefined", "exceptions should exit the with environment");
+
+var traps = [];
+var proxyEnv = new Proxy(
+  { value: 1 },
+  {
+    has(target, key) {
+      traps.push("has:" + String(key));
+      return key in target;
+    },
+    get(target, key) {
+      traps.push("get:" + String(key));
+      return target[key];
+    },
+    set(target, key, value) {
+      traps.push("set:" + String(key));
+      target[key] = value;
+      return true;
+    },
+  }
+);
+with (proxyEnv) {
+  value += 1;
+}
+assert(
+  traps.join() ===
+    "has:value,get:Symbol(Symbol.unscopables),has:value,get:value,has:value,set:value",
+  "compound assignment should resolve the binding once and recheck it on get and set"
+);
+
+var shrinking = {
+  binding: 0,
+  get [Symbol.unscopables]() {
+    delete shrinking.binding;
+    return null;
+  },
+};
+var strictError;
+with (shrinking) {
+  (function () {
+    "use strict";
+    try {
+      binding = 1;
+    } catch (e) {
+      strictError = e;
+    }
+  })();
+}
+assert(
+  strictError instanceof ReferenceError,
+  "strict assignment to a binding deleted after resolution should throw"
+);